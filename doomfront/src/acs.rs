@@ -0,0 +1,37 @@
+//! Parser and syntax trees for [ACS](https://zdoom.org/wiki/ACS),
+//! the scripting language of Hexen and the ZDoom family of source ports.
+//!
+//! The accepted dialect is that of ACC, plus the few extensions of BCC and
+//! GDCC-ACS which do not change the language's shape (e.g. `fixed` and `bool`
+//! variables, and the ternary operator). Files are parsed as written;
+//! `#include` and `#import` directives are not followed by [`parse`] or
+//! [`parse_recov`], and `#define` constants are left unexpanded.
+//! Use [`preprocess`] first to get source as ACC would see it, along with a
+//! map for pointing diagnostics back to the files the source came from.
+//! [`compile`] turns a tree into a BEHAVIOR object as ACC would.
+//! [`behavior`] reads compiled objects back, whether written by ACC or not,
+//! and can decompile them into source that [`parse`] accepts.
+
+pub mod ast;
+pub mod behavior;
+mod compile;
+mod internal;
+mod parse;
+pub mod pcode;
+mod preproc;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use compile::*;
+pub use parse::*;
+pub use preproc::*;
+pub use syn::*;
+
+pub type FileParseTree = crate::repr::FileParseTree<Syn>;
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type IncludeTree = crate::repr::IncludeTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
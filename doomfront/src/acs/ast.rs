@@ -0,0 +1,524 @@
+//! Abstract syntax tree nodes.
+
+mod expr;
+mod lit;
+mod stat;
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+pub use self::{expr::*, lit::*, stat::*};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Define(DefineDirective),
+	Function(FuncDef),
+	Include(IncludeDirective),
+	Library(LibraryDirective),
+	Script(ScriptDef),
+	SimpleDirective(SimpleDirective),
+	Special(SpecialDecl),
+	Var(VarDecl),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::DefineDirective
+				| Syn::FuncDef
+				| Syn::IncludeDirective
+				| Syn::LibraryDirective
+				| Syn::ScriptDef
+				| Syn::SimpleDirective
+				| Syn::SpecialDecl
+				| Syn::VarDecl
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::DefineDirective => Some(Self::Define(DefineDirective(node))),
+			Syn::FuncDef => Some(Self::Function(FuncDef(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::LibraryDirective => Some(Self::Library(LibraryDirective(node))),
+			Syn::ScriptDef => Some(Self::Script(ScriptDef(node))),
+			Syn::SimpleDirective => Some(Self::SimpleDirective(SimpleDirective(node))),
+			Syn::SpecialDecl => Some(Self::Special(SpecialDecl(node))),
+			Syn::VarDecl => Some(Self::Var(VarDecl(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Define(inner) => &inner.0,
+			Self::Function(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::Library(inner) => &inner.0,
+			Self::Script(inner) => &inner.0,
+			Self::SimpleDirective(inner) => &inner.0,
+			Self::Special(inner) => &inner.0,
+			Self::Var(inner) => &inner.0,
+		}
+	}
+}
+
+/// Finds the first token directly beneath `node` tagged `syn`.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens().find_map(|n_or_t| {
+		if n_or_t.kind() == syn {
+			n_or_t.into_token()
+		} else {
+			None
+		}
+	})
+}
+
+/// Yields every token directly beneath `node` matching `predicate`.
+fn tokens<F>(node: &SyntaxNode, predicate: F) -> impl Iterator<Item = SyntaxToken>
+where
+	F: Fn(Syn) -> bool,
+{
+	node.children_with_tokens().filter_map(move |n_or_t| {
+		if predicate(n_or_t.kind()) {
+			n_or_t.into_token()
+		} else {
+			None
+		}
+	})
+}
+
+/// The first token directly beneath `node` which is one of the type keywords.
+fn type_token(node: &SyntaxNode) -> SyntaxToken {
+	tokens(node, |syn| syn.is_type()).next().unwrap()
+}
+
+/// Strips the delimiting quotation marks from a string literal token's text.
+fn unquote(token: &SyntaxToken) -> String {
+	let text = token.text();
+	text[1..(text.len() - 1)].to_string()
+}
+
+// Directives //////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::DefineDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefineDirective(SyntaxNode);
+
+simple_astnode!(Syn, DefineDirective, Syn::DefineDirective);
+
+impl DefineDirective {
+	/// Returns `true` if this is a `#libdefine` rather than a `#define`;
+	/// such constants are visible to files which `#import` this one.
+	#[must_use]
+	pub fn is_lib(&self) -> bool {
+		self.0.first_token().unwrap().kind() == Syn::KwLibDefine
+	}
+
+	/// The token tagged [`Syn::Ident`].
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn value(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	/// Returns `true` if this is an `#import` rather than an `#include`.
+	/// Imported files contribute only their library's definitions.
+	#[must_use]
+	pub fn is_import(&self) -> bool {
+		self.0.first_token().unwrap().kind() == Syn::KwImport
+	}
+
+	/// The string literal token holding the path, with quotation marks.
+	#[must_use]
+	pub fn path_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The included path with the delimiting quotation marks stripped away.
+	#[must_use]
+	pub fn path(&self) -> String {
+		unquote(&self.path_token())
+	}
+}
+
+/// Wraps a node tagged [`Syn::LibraryDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LibraryDirective(SyntaxNode);
+
+simple_astnode!(Syn, LibraryDirective, Syn::LibraryDirective);
+
+impl LibraryDirective {
+	/// The string literal token holding the name, with quotation marks.
+	#[must_use]
+	pub fn name_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The library's name with the delimiting quotation marks stripped away.
+	#[must_use]
+	pub fn name(&self) -> String {
+		unquote(&self.name_token())
+	}
+}
+
+/// Wraps a node tagged [`Syn::SimpleDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SimpleDirective(SyntaxNode);
+
+simple_astnode!(Syn, SimpleDirective, Syn::SimpleDirective);
+
+impl SimpleDirective {
+	/// e.g. [`Syn::KwNoCompact`] or [`Syn::KwWadAuthor`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+}
+
+// Scripts, functions, specials ////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::ScriptDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ScriptDef(SyntaxNode);
+
+simple_astnode!(Syn, ScriptDef, Syn::ScriptDef);
+
+impl ScriptDef {
+	/// Either an integer literal, a string literal for named scripts,
+	/// or an identifier which should name a constant.
+	#[must_use]
+	pub fn number(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	/// Returns `None` if this script has a type and takes no parameter list.
+	#[must_use]
+	pub fn params(&self) -> Option<ParamList> {
+		self.0.children().find_map(ParamList::cast)
+	}
+
+	/// Returns `None` for closed scripts, which only run when called.
+	/// Otherwise yields a token for which [`Syn::is_script_type`] holds.
+	#[must_use]
+	pub fn kind(&self) -> Option<SyntaxToken> {
+		tokens(&self.0, |syn| syn.is_script_type()).next()
+	}
+
+	/// Yields tokens tagged [`Syn::KwNet`] and [`Syn::KwClientSide`].
+	pub fn flags(&self) -> impl Iterator<Item = SyntaxToken> {
+		tokens(&self.0, |syn| matches!(syn, Syn::KwNet | Syn::KwClientSide))
+	}
+
+	#[must_use]
+	pub fn is_net(&self) -> bool {
+		self.flags().any(|t| t.kind() == Syn::KwNet)
+	}
+
+	#[must_use]
+	pub fn is_clientside(&self) -> bool {
+		self.flags().any(|t| t.kind() == Syn::KwClientSide)
+	}
+
+	#[must_use]
+	pub fn body(&self) -> StatCompound {
+		self.0.children().find_map(StatCompound::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::FuncDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FuncDef(SyntaxNode);
+
+simple_astnode!(Syn, FuncDef, Syn::FuncDef);
+
+impl FuncDef {
+	/// A token for which [`Syn::is_type`] holds.
+	#[must_use]
+	pub fn return_type(&self) -> SyntaxToken {
+		type_token(&self.0)
+	}
+
+	/// The token tagged [`Syn::Ident`].
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn params(&self) -> ParamList {
+		self.0.children().find_map(ParamList::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> StatCompound {
+		self.0.children().find_map(StatCompound::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ParamList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParamList(SyntaxNode);
+
+simple_astnode!(Syn, ParamList, Syn::ParamList);
+
+impl ParamList {
+	pub fn iter(&self) -> impl Iterator<Item = Parameter> {
+		self.0.children().filter_map(Parameter::cast)
+	}
+
+	/// Returns `true` if this is written as `(void)`.
+	#[must_use]
+	pub fn is_void(&self) -> bool {
+		token(&self.0, Syn::KwVoid).is_some()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Parameter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Parameter(SyntaxNode);
+
+simple_astnode!(Syn, Parameter, Syn::Parameter);
+
+impl Parameter {
+	/// A token for which [`Syn::is_type`] holds.
+	#[must_use]
+	pub fn type_spec(&self) -> SyntaxToken {
+		type_token(&self.0)
+	}
+
+	/// The token tagged [`Syn::Ident`].
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::SpecialDecl`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SpecialDecl(SyntaxNode);
+
+simple_astnode!(Syn, SpecialDecl, Syn::SpecialDecl);
+
+impl SpecialDecl {
+	pub fn defs(&self) -> impl Iterator<Item = SpecialDef> {
+		self.0.children().filter_map(SpecialDef::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::SpecialDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SpecialDef(SyntaxNode);
+
+simple_astnode!(Syn, SpecialDef, Syn::SpecialDef);
+
+impl SpecialDef {
+	/// Returns `true` if the number is negated, in which case this declares
+	/// one of ZDoom's built-in functions (called via `PCD_CALLFUNC`)
+	/// rather than a line special.
+	#[must_use]
+	pub fn is_builtin(&self) -> bool {
+		self.0.first_token().unwrap().kind() == Syn::Minus
+	}
+
+	/// The special or function number, negated if [`Self::is_builtin`].
+	/// Returns `None` if the number does not fit into an `i32`.
+	#[must_use]
+	pub fn number(&self) -> Option<i32> {
+		let num = LitToken(self.int_tokens().next().unwrap())
+			.int()?
+			.ok()
+			.and_then(|n| i32::try_from(n).ok())?;
+
+		Some(if self.is_builtin() { -num } else { num })
+	}
+
+	/// The token tagged [`Syn::Ident`].
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The fewest arguments this special can be called with.
+	#[must_use]
+	pub fn min_args(&self) -> usize {
+		self.int_tokens()
+			.nth(1)
+			.and_then(|t| t.text().parse().ok())
+			.unwrap_or_default()
+	}
+
+	/// The most arguments this special can be called with. This is the same as
+	/// [`Self::min_args`] unless a range like `(1, 3)` was declared.
+	#[must_use]
+	pub fn max_args(&self) -> usize {
+		self.int_tokens()
+			.last()
+			.and_then(|t| t.text().parse().ok())
+			.unwrap_or_default()
+	}
+
+	fn int_tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+		tokens(&self.0, |syn| syn == Syn::LitInt)
+	}
+}
+
+// Variables ///////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::VarDecl`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VarDecl(SyntaxNode);
+
+simple_astnode!(Syn, VarDecl, Syn::VarDecl);
+
+impl VarDecl {
+	/// Returns a token tagged [`Syn::KwWorld`] or [`Syn::KwGlobal`], or `None`
+	/// if this declares map-scope variables.
+	#[must_use]
+	pub fn scope(&self) -> Option<SyntaxToken> {
+		tokens(&self.0, |syn| matches!(syn, Syn::KwWorld | Syn::KwGlobal)).next()
+	}
+
+	/// A token for which [`Syn::is_type`] holds.
+	#[must_use]
+	pub fn type_spec(&self) -> SyntaxToken {
+		type_token(&self.0)
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = VarName> {
+		self.0.children().filter_map(VarName::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::VarName`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VarName(SyntaxNode);
+
+simple_astnode!(Syn, VarName, Syn::VarName);
+
+impl VarName {
+	/// The integer literal in `index:name`, which world and global variables
+	/// use to claim one of the engine's variable slots.
+	#[must_use]
+	pub fn index(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::LitInt)
+	}
+
+	/// The token tagged [`Syn::Ident`].
+	#[must_use]
+	pub fn ident(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// One for each dimension, if this is an array.
+	pub fn array_lens(&self) -> impl Iterator<Item = ArrayLen> {
+		self.0.children().filter_map(ArrayLen::cast)
+	}
+
+	#[must_use]
+	pub fn init(&self) -> Option<Initializer> {
+		self.0.children().find_map(Initializer::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ArrayLen`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArrayLen(SyntaxNode);
+
+simple_astnode!(Syn, ArrayLen, Syn::ArrayLen);
+
+impl ArrayLen {
+	/// Returns `None` for `[]`, as in world and global arrays.
+	#[must_use]
+	pub fn expr(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+}
+
+/// The value assigned to a variable at its declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Initializer {
+	Expr(Expression),
+	List(InitList),
+}
+
+impl AstNode for Initializer {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		kind == Syn::InitList || Expression::can_cast(kind)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		if node.kind() == Syn::InitList {
+			Some(Self::List(InitList(node)))
+		} else {
+			Expression::cast(node).map(Self::Expr)
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Expr(inner) => inner.syntax(),
+			Self::List(inner) => &inner.0,
+		}
+	}
+}
+
+/// Wraps a node tagged [`Syn::InitList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct InitList(SyntaxNode);
+
+simple_astnode!(Syn, InitList, Syn::InitList);
+
+impl InitList {
+	/// Nested lists initialize the inner dimensions of multi-dimensional arrays.
+	pub fn elements(&self) -> impl Iterator<Item = Initializer> {
+		self.0.children().filter_map(Initializer::cast)
+	}
+}
@@ -0,0 +1,365 @@
+//! AST nodes for representing expressions.
+
+use rowan::ast::AstNode;
+
+use crate::{
+	acs::{Syn, SyntaxNode, SyntaxToken},
+	simple_astnode,
+};
+
+use super::Literal;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+	Binary(ExprBinary),
+	Call(ExprCall),
+	Group(ExprGroup),
+	Ident(ExprIdent),
+	Index(ExprIndex),
+	Literal(Literal),
+	Postfix(ExprPostfix),
+	Prefix(ExprPrefix),
+	Ternary(ExprTernary),
+}
+
+impl AstNode for Expression {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::ExprBinary
+				| Syn::ExprCall
+				| Syn::ExprGroup
+				| Syn::ExprIdent
+				| Syn::ExprIndex
+				| Syn::Literal
+				| Syn::ExprPostfix
+				| Syn::ExprPrefix
+				| Syn::ExprTernary
+		)
+	}
+
+	fn cast(node: SyntaxNode) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::ExprBinary => Some(Self::Binary(ExprBinary(node))),
+			Syn::ExprCall => Some(Self::Call(ExprCall(node))),
+			Syn::ExprGroup => Some(Self::Group(ExprGroup(node))),
+			Syn::ExprIdent => Some(Self::Ident(ExprIdent(node))),
+			Syn::ExprIndex => Some(Self::Index(ExprIndex(node))),
+			Syn::Literal => Some(Self::Literal(Literal(node))),
+			Syn::ExprPostfix => Some(Self::Postfix(ExprPostfix(node))),
+			Syn::ExprPrefix => Some(Self::Prefix(ExprPrefix(node))),
+			Syn::ExprTernary => Some(Self::Ternary(ExprTernary(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Binary(inner) => &inner.0,
+			Self::Call(inner) => &inner.0,
+			Self::Group(inner) => &inner.0,
+			Self::Ident(inner) => &inner.0,
+			Self::Index(inner) => &inner.0,
+			Self::Literal(inner) => &inner.0,
+			Self::Postfix(inner) => &inner.0,
+			Self::Prefix(inner) => &inner.0,
+			Self::Ternary(inner) => &inner.0,
+		}
+	}
+}
+
+impl Expression {
+	#[must_use]
+	pub fn into_literal(self) -> Option<Literal> {
+		match self {
+			Self::Literal(lit) => Some(lit),
+			_ => None,
+		}
+	}
+}
+
+/// The first token which is not trivia and not inside a child node.
+fn operator(node: &SyntaxNode) -> SyntaxToken {
+	node.children_with_tokens()
+		.find_map(|n_or_t| {
+			if n_or_t.kind().is_trivia() {
+				None
+			} else {
+				n_or_t.into_token()
+			}
+		})
+		.unwrap()
+}
+
+/// Wraps a node tagged [`Syn::ExprBinary`].
+/// Note that assignments and compound assignments use this node too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprBinary(SyntaxNode);
+
+simple_astnode!(Syn, ExprBinary, Syn::ExprBinary);
+
+impl ExprBinary {
+	#[must_use]
+	pub fn left(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		operator(&self.0)
+	}
+
+	#[must_use]
+	pub fn right(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+
+	/// Is the operator `=` or a compound assignment operator like `+=`?
+	#[must_use]
+	pub fn is_assign(&self) -> bool {
+		matches!(
+			self.operator().kind(),
+			Syn::Eq
+				| Syn::PlusEq
+				| Syn::MinusEq
+				| Syn::AsteriskEq
+				| Syn::SlashEq
+				| Syn::PercentEq
+				| Syn::AngleL2Eq
+				| Syn::AngleR2Eq
+				| Syn::PipeEq
+				| Syn::AmpersandEq
+				| Syn::CaretEq
+		)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprCall`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprCall(SyntaxNode);
+
+simple_astnode!(Syn, ExprCall, Syn::ExprCall);
+
+impl ExprCall {
+	#[must_use]
+	pub fn callee(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	/// The callee's identifier, if the callee is a bare name
+	/// (which ACC requires).
+	#[must_use]
+	pub fn name(&self) -> Option<SyntaxToken> {
+		match self.callee() {
+			Expression::Ident(e_id) => Some(e_id.token()),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	pub fn args(&self) -> ArgList {
+		self.0.children().find_map(ArgList::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprGroup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprGroup(SyntaxNode);
+
+simple_astnode!(Syn, ExprGroup, Syn::ExprGroup);
+
+impl ExprGroup {
+	#[must_use]
+	pub fn inner(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprIdent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprIdent(SyntaxNode);
+
+simple_astnode!(Syn, ExprIdent, Syn::ExprIdent);
+
+impl ExprIdent {
+	#[must_use]
+	pub fn token(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprIndex(SyntaxNode);
+
+simple_astnode!(Syn, ExprIndex, Syn::ExprIndex);
+
+impl ExprIndex {
+	#[must_use]
+	pub fn indexed(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn index(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprPostfix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprPostfix(SyntaxNode);
+
+simple_astnode!(Syn, ExprPostfix, Syn::ExprPostfix);
+
+impl ExprPostfix {
+	#[must_use]
+	pub fn operand(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		self.0.last_token().unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprPrefix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprPrefix(SyntaxNode);
+
+simple_astnode!(Syn, ExprPrefix, Syn::ExprPrefix);
+
+impl ExprPrefix {
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn operand(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprTernary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprTernary(SyntaxNode);
+
+simple_astnode!(Syn, ExprTernary, Syn::ExprTernary);
+
+impl ExprTernary {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn if_true(&self) -> Expression {
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.nth(1)
+			.unwrap()
+	}
+
+	#[must_use]
+	pub fn if_false(&self) -> Expression {
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.nth(2)
+			.unwrap()
+	}
+}
+
+// Arguments ///////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::ArgList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArgList(SyntaxNode);
+
+simple_astnode!(Syn, ArgList, Syn::ArgList);
+
+impl ArgList {
+	/// Returns `true` if the arguments are preceded by `const:`, which forces
+	/// a line special's arguments to be compiled as immediates.
+	#[must_use]
+	pub fn is_const(&self) -> bool {
+		self.0
+			.children_with_tokens()
+			.any(|n_or_t| n_or_t.kind() == Syn::KwConst)
+	}
+
+	/// Every argument, including those after a semicolon.
+	pub fn iter(&self) -> impl Iterator<Item = Argument> {
+		self.0.children().filter_map(Argument::cast)
+	}
+
+	/// The arguments before the semicolon, or all of them if there is none.
+	/// For print-like functions, these make up the message.
+	pub fn head(&self) -> impl Iterator<Item = Argument> {
+		let semicolon = self.semicolon_pos();
+
+		self.iter()
+			.filter(move |arg| semicolon.is_none_or(|pos| arg.0.text_range().start() < pos))
+	}
+
+	/// The arguments after the semicolon, such as the extra parameters
+	/// of `HudMessage`.
+	pub fn tail(&self) -> impl Iterator<Item = Argument> {
+		let semicolon = self.semicolon_pos();
+
+		self.iter()
+			.filter(move |arg| semicolon.is_some_and(|pos| arg.0.text_range().start() > pos))
+	}
+
+	fn semicolon_pos(&self) -> Option<rowan::TextSize> {
+		self.0.children_with_tokens().find_map(|n_or_t| {
+			(n_or_t.kind() == Syn::Semicolon).then(|| n_or_t.text_range().start())
+		})
+	}
+}
+
+/// Wraps a node tagged [`Syn::Argument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Argument(SyntaxNode);
+
+simple_astnode!(Syn, Argument, Syn::Argument);
+
+impl Argument {
+	/// The identifier before the colon in e.g. `s:` or `d:`.
+	#[must_use]
+	pub fn cast_spec(&self) -> Option<SyntaxToken> {
+		self.0.children_with_tokens().find_map(|n_or_t| {
+			if n_or_t.kind() == Syn::Ident {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+	}
+
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
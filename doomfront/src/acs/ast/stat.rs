@@ -0,0 +1,394 @@
+//! AST nodes for representing statements.
+
+use rowan::ast::AstNode;
+
+use crate::{
+	acs::{Syn, SyntaxNode, SyntaxToken},
+	simple_astnode,
+};
+
+use super::{Expression, VarName};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+	Break(StatBreak),
+	Case(StatCase),
+	CaseDefault(StatCaseDefault),
+	Compound(StatCompound),
+	Continue(StatContinue),
+	DoWhile(StatDoWhile),
+	Empty(StatEmpty),
+	Expr(StatExpr),
+	For(StatFor),
+	If(StatIf),
+	LocalVar(StatLocalVar),
+	Restart(StatRestart),
+	Return(StatReturn),
+	Suspend(StatSuspend),
+	Switch(StatSwitch),
+	Terminate(StatTerminate),
+	Until(StatUntil),
+	While(StatWhile),
+}
+
+impl AstNode for Statement {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::StatBreak
+				| Syn::StatCase
+				| Syn::StatCaseDefault
+				| Syn::StatCompound
+				| Syn::StatContinue
+				| Syn::StatDoWhile
+				| Syn::StatEmpty
+				| Syn::StatExpr
+				| Syn::StatFor
+				| Syn::StatIf
+				| Syn::StatLocalVar
+				| Syn::StatRestart
+				| Syn::StatReturn
+				| Syn::StatSuspend
+				| Syn::StatSwitch
+				| Syn::StatTerminate
+				| Syn::StatUntil
+				| Syn::StatWhile
+		)
+	}
+
+	fn cast(node: SyntaxNode) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::StatBreak => Some(Self::Break(StatBreak(node))),
+			Syn::StatCase => Some(Self::Case(StatCase(node))),
+			Syn::StatCaseDefault => Some(Self::CaseDefault(StatCaseDefault(node))),
+			Syn::StatCompound => Some(Self::Compound(StatCompound(node))),
+			Syn::StatContinue => Some(Self::Continue(StatContinue(node))),
+			Syn::StatDoWhile => Some(Self::DoWhile(StatDoWhile(node))),
+			Syn::StatEmpty => Some(Self::Empty(StatEmpty(node))),
+			Syn::StatExpr => Some(Self::Expr(StatExpr(node))),
+			Syn::StatFor => Some(Self::For(StatFor(node))),
+			Syn::StatIf => Some(Self::If(StatIf(node))),
+			Syn::StatLocalVar => Some(Self::LocalVar(StatLocalVar(node))),
+			Syn::StatRestart => Some(Self::Restart(StatRestart(node))),
+			Syn::StatReturn => Some(Self::Return(StatReturn(node))),
+			Syn::StatSuspend => Some(Self::Suspend(StatSuspend(node))),
+			Syn::StatSwitch => Some(Self::Switch(StatSwitch(node))),
+			Syn::StatTerminate => Some(Self::Terminate(StatTerminate(node))),
+			Syn::StatUntil => Some(Self::Until(StatUntil(node))),
+			Syn::StatWhile => Some(Self::While(StatWhile(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Break(inner) => &inner.0,
+			Self::Case(inner) => &inner.0,
+			Self::CaseDefault(inner) => &inner.0,
+			Self::Compound(inner) => &inner.0,
+			Self::Continue(inner) => &inner.0,
+			Self::DoWhile(inner) => &inner.0,
+			Self::Empty(inner) => &inner.0,
+			Self::Expr(inner) => &inner.0,
+			Self::For(inner) => &inner.0,
+			Self::If(inner) => &inner.0,
+			Self::LocalVar(inner) => &inner.0,
+			Self::Restart(inner) => &inner.0,
+			Self::Return(inner) => &inner.0,
+			Self::Suspend(inner) => &inner.0,
+			Self::Switch(inner) => &inner.0,
+			Self::Terminate(inner) => &inner.0,
+			Self::Until(inner) => &inner.0,
+			Self::While(inner) => &inner.0,
+		}
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatBreak`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatBreak(SyntaxNode);
+
+simple_astnode!(Syn, StatBreak, Syn::StatBreak);
+
+/// Wraps a node tagged [`Syn::StatCase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCase(SyntaxNode);
+
+simple_astnode!(Syn, StatCase, Syn::StatCase);
+
+impl StatCase {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatCaseDefault`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCaseDefault(SyntaxNode);
+
+simple_astnode!(Syn, StatCaseDefault, Syn::StatCaseDefault);
+
+/// Wraps a node tagged [`Syn::StatCompound`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCompound(pub(super) SyntaxNode);
+
+simple_astnode!(Syn, StatCompound, Syn::StatCompound);
+
+impl StatCompound {
+	pub fn statements(&self) -> impl Iterator<Item = Statement> {
+		self.0.children().filter_map(Statement::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatContinue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatContinue(SyntaxNode);
+
+simple_astnode!(Syn, StatContinue, Syn::StatContinue);
+
+/// Wraps a node tagged [`Syn::StatDoWhile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatDoWhile(SyntaxNode);
+
+simple_astnode!(Syn, StatDoWhile, Syn::StatDoWhile);
+
+impl StatDoWhile {
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+
+	/// Returns `true` if this loop ends with `until` instead of `while`.
+	#[must_use]
+	pub fn is_until(&self) -> bool {
+		self.0
+			.children_with_tokens()
+			.any(|n_or_t| n_or_t.kind() == Syn::KwUntil)
+	}
+
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatEmpty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatEmpty(SyntaxNode);
+
+simple_astnode!(Syn, StatEmpty, Syn::StatEmpty);
+
+/// Wraps a node tagged [`Syn::StatExpr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatExpr(SyntaxNode);
+
+simple_astnode!(Syn, StatExpr, Syn::StatExpr);
+
+impl StatExpr {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatFor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatFor(SyntaxNode);
+
+simple_astnode!(Syn, StatFor, Syn::StatFor);
+
+impl StatFor {
+	/// The initializer, which may be a local variable declaration
+	/// (i.e. a type keyword followed by [`VarName`] nodes) or expressions.
+	#[must_use]
+	pub fn init(&self) -> SyntaxNode {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForInit)
+			.unwrap()
+	}
+
+	/// The [`VarName`] nodes declared by the initializer, if any.
+	pub fn init_vars(&self) -> impl Iterator<Item = VarName> {
+		self.init().children().filter_map(VarName::cast)
+	}
+
+	/// The expressions in the initializer, if it is not a declaration.
+	pub fn init_exprs(&self) -> impl Iterator<Item = Expression> {
+		self.init().children().filter_map(Expression::cast)
+	}
+
+	#[must_use]
+	pub fn condition(&self) -> Option<Expression> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForCond)
+			.and_then(|node| node.children().find_map(Expression::cast))
+	}
+
+	pub fn iter_exprs(&self) -> impl Iterator<Item = Expression> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForIter)
+			.into_iter()
+			.flat_map(|node| node.children().filter_map(Expression::cast))
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatIf`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatIf(SyntaxNode);
+
+simple_astnode!(Syn, StatIf, Syn::StatIf);
+
+impl StatIf {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn then_branch(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn else_branch(&self) -> Option<Statement> {
+		self.0.children().filter_map(Statement::cast).nth(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatLocalVar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatLocalVar(SyntaxNode);
+
+simple_astnode!(Syn, StatLocalVar, Syn::StatLocalVar);
+
+impl StatLocalVar {
+	/// A token for which [`Syn::is_type`] holds.
+	#[must_use]
+	pub fn type_spec(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = VarName> {
+		self.0.children().filter_map(VarName::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatRestart`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatRestart(SyntaxNode);
+
+simple_astnode!(Syn, StatRestart, Syn::StatRestart);
+
+/// Wraps a node tagged [`Syn::StatReturn`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatReturn(SyntaxNode);
+
+simple_astnode!(Syn, StatReturn, Syn::StatReturn);
+
+impl StatReturn {
+	#[must_use]
+	pub fn expr(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatSuspend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatSuspend(SyntaxNode);
+
+simple_astnode!(Syn, StatSuspend, Syn::StatSuspend);
+
+/// Wraps a node tagged [`Syn::StatSwitch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatSwitch(SyntaxNode);
+
+simple_astnode!(Syn, StatSwitch, Syn::StatSwitch);
+
+impl StatSwitch {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatTerminate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatTerminate(SyntaxNode);
+
+simple_astnode!(Syn, StatTerminate, Syn::StatTerminate);
+
+/// Wraps a node tagged [`Syn::StatUntil`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatUntil(SyntaxNode);
+
+simple_astnode!(Syn, StatUntil, Syn::StatUntil);
+
+impl StatUntil {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatWhile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatWhile(SyntaxNode);
+
+simple_astnode!(Syn, StatWhile, Syn::StatWhile);
+
+impl StatWhile {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
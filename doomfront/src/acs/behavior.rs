@@ -0,0 +1,775 @@
+//! A reader for compiled ACS objects, i.e. the `BEHAVIOR` lumps of Hexen-format
+//! maps and the libraries loaded via `LOADACS`.
+//!
+//! All three formats understood by ZDoom are supported: Hexen's `ACS\0`, and
+//! the chunked `ACSE` and `ACSe` formats of ZDoom's ACC (including objects
+//! which wrap the latter in an `ACS\0` header for compatibility). [`Object`]
+//! lists what an object contains, disassembles its p-code, and can
+//! [decompile](Object::decompile) it back into ACS source.
+
+mod decompile;
+
+use std::fmt::Write;
+
+use super::pcode::{Operands, Pcode};
+
+/// A parsed ACS object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Object {
+	pub format: Format,
+	/// In order of appearance in the script directory or `SPTR` chunk.
+	pub scripts: Vec<Script>,
+	/// Indexed by function number.
+	pub functions: Vec<Function>,
+	pub strings: Vec<String>,
+	/// Empty for [`Format::Hexen`] objects.
+	pub chunks: Vec<Chunk>,
+	/// Sorted by index. Only variables which the object's chunks mention
+	/// (via initializers, array sizes, names, or string flags) are listed.
+	pub map_vars: Vec<MapVar>,
+	/// The names of the libraries this object imports, from its `LOAD` chunk.
+	pub libraries: Vec<String>,
+	data: Vec<u8>,
+	/// Where p-code ends; everything from here on is chunks or directory.
+	code_end: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+	/// `ACS\0`; the original format used by Hexen.
+	Hexen,
+	/// `ACSE`; every pcode and most operands are 32 bits wide.
+	Enhanced,
+	/// `ACSe`; pcodes below 240 and most operands are one byte wide.
+	Compact,
+}
+
+impl Format {
+	#[must_use]
+	pub fn magic(self) -> &'static [u8; 4] {
+		match self {
+			Self::Hexen => b"ACS\0",
+			Self::Enhanced => b"ACSE",
+			Self::Compact => b"ACSe",
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+	/// Negative for named scripts, which are numbered -1, -2, and so on
+	/// in the order of their names in the `SNAM` chunk.
+	pub number: i32,
+	pub name: Option<String>,
+	/// ZDoom's script type number; 0 for closed scripts, 1 for `OPEN`, etc.
+	pub kind: u16,
+	pub args: u32,
+	pub address: u32,
+	/// From `SFLG`. Bit 0 is `NET`, and bit 1 is `CLIENTSIDE`.
+	pub flags: u16,
+	/// From `SVCT`; otherwise ZDoom gives the script 20 variables.
+	pub vars: Option<u16>,
+	/// From `SARY`.
+	pub arrays: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+	/// From `FNAM`.
+	pub name: Option<String>,
+	pub args: u8,
+	pub locals: u8,
+	pub returns: bool,
+	/// Non-zero if the function is defined in a library this object imports,
+	/// in which case it has no address.
+	pub import: u8,
+	pub address: u32,
+	/// From `FARY`.
+	pub arrays: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+	pub id: [u8; 4],
+	/// Of the chunk's data, which follows its 8-byte header.
+	pub offset: u32,
+	pub data: Vec<u8>,
+}
+
+impl Chunk {
+	#[must_use]
+	pub fn id_str(&self) -> String {
+		self.id.iter().map(|&b| b as char).collect()
+	}
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MapVar {
+	pub index: u32,
+	/// From `MEXP`, or `MIMP`/`AIMP` for imported variables.
+	pub name: Option<String>,
+	/// For arrays, the element count from `ARAY` or `AIMP`.
+	pub size: Option<u32>,
+	/// From `MINI` or `AINI`.
+	pub init: Vec<i32>,
+	/// From `MSTR` or `ASTR`.
+	pub is_str: bool,
+	pub imported: bool,
+}
+
+/// One disassembled instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+	pub offset: u32,
+	pub pcode: Pcode,
+	/// Decoded as described by [`Pcode::operands`]. For
+	/// [`Pcode::CaseGotoSorted`], these are value-address pairs.
+	pub operands: Vec<i32>,
+	/// The offset of the next instruction.
+	pub end: u32,
+}
+
+impl std::fmt::Display for Instruction {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}: {}", self.offset, self.pcode.name())?;
+
+		for operand in &self.operands {
+			write!(f, " {operand}")?;
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadError {
+	/// The data does not start with any known magic number.
+	UnknownFormat,
+	/// Something points past the end of the data.
+	Truncated {
+		offset: usize,
+	},
+	UnknownPcode {
+		offset: u32,
+		number: u32,
+	},
+	/// A count or index is too large to be valid.
+	Overflow {
+		offset: usize,
+	},
+}
+
+impl std::fmt::Display for ReadError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::UnknownFormat => write!(f, "not an ACS object"),
+			Self::Truncated { offset } => write!(f, "data ends unexpectedly at offset {offset}"),
+			Self::UnknownPcode { offset, number } => {
+				write!(f, "unknown pcode {number} at offset {offset}")
+			}
+			Self::Overflow { offset } => {
+				write!(f, "out-of-range count or index at offset {offset}")
+			}
+		}
+	}
+}
+
+impl std::error::Error for ReadError {}
+
+impl Object {
+	pub fn read(data: &[u8]) -> Result<Self, ReadError> {
+		let magic = data.get(0..4).ok_or(ReadError::Truncated { offset: 0 })?;
+		let offset = read_u32(data, 4)?;
+
+		let mut format = match magic {
+			b"ACS\0" => Format::Hexen,
+			b"ACSE" => Format::Enhanced,
+			b"ACSe" => Format::Compact,
+			_ => return Err(ReadError::UnknownFormat),
+		};
+
+		let mut chunks_start = offset as usize;
+		let mut chunks_end = data.len();
+
+		// ACC can wrap an enhanced object in a Hexen header, leaving the real
+		// format's magic number and chunk offset just before the directory.
+		if format == Format::Hexen && offset >= 24 {
+			let dir = offset as usize;
+
+			let inner = match data.get((dir - 4)..dir) {
+				Some(b"ACSE") => Some(Format::Enhanced),
+				Some(b"ACSe") => Some(Format::Compact),
+				_ => None,
+			};
+
+			if let Some(inner) = inner {
+				format = inner;
+				chunks_start = read_u32(data, dir - 8)? as usize;
+				chunks_end = dir - 8;
+			}
+		}
+
+		let mut obj = Self {
+			format,
+			scripts: vec![],
+			functions: vec![],
+			strings: vec![],
+			chunks: vec![],
+			map_vars: vec![],
+			libraries: vec![],
+			data: data.to_vec(),
+			code_end: chunks_start as u32,
+		};
+
+		if format == Format::Hexen {
+			obj.read_directory(offset as usize)?;
+		} else {
+			obj.read_chunks(chunks_start, chunks_end)?;
+		}
+
+		Ok(obj)
+	}
+
+	/// The script directory and string table of an `ACS\0` object.
+	fn read_directory(&mut self, dir: usize) -> Result<(), ReadError> {
+		let count = read_u32(&self.data, dir)? as usize;
+		let mut pos = dir + 4;
+
+		for _ in 0..count {
+			let number = read_u32(&self.data, pos)?;
+
+			self.scripts.push(Script {
+				number: (number % 1000) as i32,
+				name: None,
+				kind: (number / 1000) as u16,
+				args: read_u32(&self.data, pos + 8)?,
+				address: read_u32(&self.data, pos + 4)?,
+				flags: 0,
+				vars: None,
+				arrays: vec![],
+			});
+
+			pos += 12;
+		}
+
+		let count = read_u32(&self.data, pos)? as usize;
+
+		for i in 0..count {
+			let offset = read_u32(&self.data, pos + 4 + i * 4)? as usize;
+			self.strings.push(read_cstr(&self.data, offset)?);
+		}
+
+		Ok(())
+	}
+
+	fn read_chunks(&mut self, start: usize, end: usize) -> Result<(), ReadError> {
+		let mut pos = start;
+
+		while pos + 8 <= end {
+			let id: [u8; 4] = self.data[pos..(pos + 4)].try_into().unwrap();
+			let len = read_u32(&self.data, pos + 4)? as usize;
+
+			let data = self
+				.data
+				.get((pos + 8)..(pos + 8 + len))
+				.ok_or(ReadError::Truncated { offset: pos + 8 })?
+				.to_vec();
+
+			self.chunks.push(Chunk {
+				id,
+				offset: (pos + 8) as u32,
+				data,
+			});
+
+			pos += 8 + len;
+		}
+
+		let chunks = std::mem::take(&mut self.chunks);
+
+		for chunk in &chunks {
+			self.read_chunk(chunk)?;
+		}
+
+		self.chunks = chunks;
+		self.map_vars.sort_by_key(|var| var.index);
+		Ok(())
+	}
+
+	fn read_chunk(&mut self, chunk: &Chunk) -> Result<(), ReadError> {
+		let data = chunk.data.as_slice();
+		let at = |pos: usize| read_u32(data, pos);
+
+		match &chunk.id {
+			b"SPTR" => {
+				let size = if self.format == Format::Compact {
+					8
+				} else {
+					12
+				};
+
+				for entry in data.chunks_exact(size) {
+					let number = i32::from(i16::from_le_bytes([entry[0], entry[1]]));
+
+					let (kind, args, address) = if size == 8 {
+						(
+							u16::from(entry[2]),
+							u32::from(entry[3]),
+							read_u32(entry, 4)?,
+						)
+					} else {
+						(
+							u16::from_le_bytes([entry[2], entry[3]]),
+							read_u32(entry, 8)?,
+							read_u32(entry, 4)?,
+						)
+					};
+
+					self.scripts.push(Script {
+						number,
+						name: None,
+						kind,
+						args,
+						address,
+						flags: 0,
+						vars: None,
+						arrays: vec![],
+					});
+				}
+			}
+			b"SFLG" | b"SVCT" => {
+				for entry in data.chunks_exact(4) {
+					let number = i32::from(i16::from_le_bytes([entry[0], entry[1]]));
+					let value = u16::from_le_bytes([entry[2], entry[3]]);
+
+					if let Some(script) = self.script_mut(number) {
+						if &chunk.id == b"SFLG" {
+							script.flags = value;
+						} else {
+							script.vars = Some(value);
+						}
+					}
+				}
+			}
+			b"SNAM" => {
+				for (i, name) in read_name_table(data)?.into_iter().enumerate() {
+					if let Some(script) = self.script_mut(-(i as i32) - 1) {
+						script.name = Some(name);
+					}
+				}
+			}
+			b"SARY" | b"FARY" if data.len() >= 2 => {
+				let owner = u16::from_le_bytes([data[0], data[1]]);
+				let sizes = data[2..]
+					.chunks_exact(4)
+					.map(|s| u32::from_le_bytes(s.try_into().unwrap()))
+					.collect();
+
+				if &chunk.id == b"FARY" {
+					if let Some(func) = self.functions.get_mut(owner as usize) {
+						func.arrays = sizes;
+					}
+				} else if let Some(script) = self.script_mut(i32::from(owner as i16)) {
+					script.arrays = sizes;
+				}
+			}
+			b"FUNC" => {
+				for entry in data.chunks_exact(8) {
+					self.functions.push(Function {
+						name: None,
+						args: entry[0],
+						locals: entry[1],
+						returns: entry[2] != 0,
+						import: entry[3],
+						address: read_u32(entry, 4)?,
+						arrays: vec![],
+					});
+				}
+			}
+			b"FNAM" => {
+				for (func, name) in self.functions.iter_mut().zip(read_name_table(data)?) {
+					func.name = Some(name);
+				}
+			}
+			b"STRL" | b"STRE" => {
+				let count = at(4)? as usize;
+
+				for i in 0..count {
+					let offset = at(12 + i * 4)? as usize;
+
+					let string = if &chunk.id == b"STRE" {
+						read_encrypted(data, offset)?
+					} else {
+						read_cstr(data, offset)?
+					};
+
+					self.strings.push(string);
+				}
+			}
+			b"MINI" => {
+				let first = at(0)?;
+				let values = data.get(4..).ok_or(ReadError::Truncated {
+					offset: chunk.offset as usize,
+				})?;
+
+				for (i, value) in values.chunks_exact(4).enumerate() {
+					let index = first.checked_add(i as u32).ok_or(ReadError::Overflow {
+						offset: chunk.offset as usize,
+					})?;
+
+					self.map_var(index).init = vec![i32::from_le_bytes(value.try_into().unwrap())];
+				}
+			}
+			b"ARAY" => {
+				for entry in data.chunks_exact(8) {
+					let size = read_u32(entry, 4)?;
+					self.map_var(read_u32(entry, 0)?).size = Some(size);
+				}
+			}
+			b"AINI" => {
+				let index = at(0)?;
+
+				let init = data
+					.get(4..)
+					.ok_or(ReadError::Truncated {
+						offset: chunk.offset as usize,
+					})?
+					.chunks_exact(4)
+					.map(|v| i32::from_le_bytes(v.try_into().unwrap()))
+					.collect();
+
+				self.map_var(index).init = init;
+			}
+			b"MSTR" | b"ASTR" => {
+				for index in data.chunks_exact(4) {
+					self.map_var(u32::from_le_bytes(index.try_into().unwrap()))
+						.is_str = true;
+				}
+			}
+			b"MEXP" => {
+				for (i, name) in read_name_table(data)?.into_iter().enumerate() {
+					self.map_var(i as u32).name = Some(name);
+				}
+			}
+			b"MIMP" => {
+				let mut pos = 0;
+
+				while pos + 4 < data.len() {
+					let name = read_cstr(data, pos + 4)?;
+					let var = self.map_var(at(pos)?);
+					pos += 4 + name.len() + 1;
+					var.name = Some(name);
+					var.imported = true;
+				}
+			}
+			b"AIMP" => {
+				let mut pos = 4;
+
+				for _ in 0..at(0)? {
+					let (index, size) = (at(pos)?, at(pos + 4)?);
+					let name = read_cstr(data, pos + 8)?;
+					pos += 8 + name.len() + 1;
+
+					let var = self.map_var(index);
+					var.name = Some(name);
+					var.size = Some(size);
+					var.imported = true;
+				}
+			}
+			b"LOAD" => {
+				self.libraries = data
+					.split(|&b| b == 0)
+					.filter(|name| !name.is_empty())
+					.map(|name| String::from_utf8_lossy(name).into_owned())
+					.collect();
+			}
+			_ => {}
+		}
+
+		Ok(())
+	}
+
+	fn script_mut(&mut self, number: i32) -> Option<&mut Script> {
+		self.scripts.iter_mut().find(|s| s.number == number)
+	}
+
+	fn map_var(&mut self, index: u32) -> &mut MapVar {
+		let pos = match self.map_vars.iter().position(|var| var.index == index) {
+			Some(pos) => pos,
+			None => {
+				self.map_vars.push(MapVar {
+					index,
+					..Default::default()
+				});
+
+				self.map_vars.len() - 1
+			}
+		};
+
+		&mut self.map_vars[pos]
+	}
+
+	#[must_use]
+	pub fn chunk(&self, id: &[u8; 4]) -> Option<&Chunk> {
+		self.chunks.iter().find(|chunk| &chunk.id == id)
+	}
+
+	/// Disassembles the script or function starting at `address`, up to the
+	/// start of the next one or the end of the p-code.
+	pub fn disassemble(&self, address: u32) -> Result<Vec<Instruction>, ReadError> {
+		let end = self
+			.scripts
+			.iter()
+			.map(|s| s.address)
+			.chain(self.functions.iter().map(|f| f.address))
+			.filter(|&a| a > address)
+			.min()
+			.unwrap_or(self.code_end)
+			.min(self.code_end);
+
+		let mut ret = vec![];
+		let mut pos = address;
+
+		while pos < end {
+			let instr = self.instruction(pos)?;
+			pos = instr.end;
+			ret.push(instr);
+		}
+
+		Ok(ret)
+	}
+
+	/// Decodes the instruction at `offset`.
+	pub fn instruction(&self, offset: u32) -> Result<Instruction, ReadError> {
+		let compact = self.format == Format::Compact;
+		let data = self.data.as_slice();
+		let mut pos = offset as usize;
+
+		let byte = |pos: &mut usize| -> Result<u8, ReadError> {
+			*pos += 1;
+			data.get(*pos - 1)
+				.copied()
+				.ok_or(ReadError::Truncated { offset: *pos - 1 })
+		};
+
+		let int = |pos: &mut usize| -> Result<i32, ReadError> {
+			*pos += 4;
+			read_u32(data, *pos - 4).map(|n| n as i32)
+		};
+
+		let index = |pos: &mut usize| -> Result<i32, ReadError> {
+			if compact {
+				byte(pos).map(i32::from)
+			} else {
+				int(pos)
+			}
+		};
+
+		let number = if !compact {
+			int(&mut pos)? as u32
+		} else {
+			let first = byte(&mut pos)?;
+
+			if first < 240 {
+				u32::from(first)
+			} else {
+				240 + ((u32::from(first) - 240) << 8) + u32::from(byte(&mut pos)?)
+			}
+		};
+
+		let pcode = u16::try_from(number)
+			.ok()
+			.and_then(Pcode::from_u16)
+			.ok_or(ReadError::UnknownPcode { offset, number })?;
+
+		let mut operands = vec![];
+
+		match pcode.operands() {
+			Operands::None => {}
+			Operands::Ints(n) => {
+				for _ in 0..n {
+					operands.push(int(&mut pos)?);
+				}
+			}
+			Operands::Jump => operands.push(int(&mut pos)?),
+			Operands::Case => {
+				operands.push(int(&mut pos)?);
+				operands.push(int(&mut pos)?);
+			}
+			Operands::SortedCases => {
+				pos = pos.next_multiple_of(4);
+
+				let count = int(&mut pos)?
+					.checked_mul(2)
+					.ok_or(ReadError::Overflow { offset: pos - 4 })?;
+
+				for _ in 0..count {
+					operands.push(int(&mut pos)?);
+				}
+			}
+			Operands::Index => operands.push(index(&mut pos)?),
+			Operands::IndexInts(n) => {
+				operands.push(index(&mut pos)?);
+
+				for _ in 0..n {
+					operands.push(int(&mut pos)?);
+				}
+			}
+			Operands::Bytes(n) => {
+				for _ in 0..n {
+					operands.push(i32::from(byte(&mut pos)?));
+				}
+			}
+			Operands::ByteList => {
+				for _ in 0..byte(&mut pos)? {
+					operands.push(i32::from(byte(&mut pos)?));
+				}
+			}
+			Operands::CallFunc => {
+				if compact {
+					operands.push(i32::from(byte(&mut pos)?));
+					let lo = byte(&mut pos)?;
+					let hi = byte(&mut pos)?;
+					operands.push(i32::from(u16::from_le_bytes([lo, hi])));
+				} else {
+					operands.push(int(&mut pos)?);
+					operands.push(int(&mut pos)?);
+				}
+			}
+		}
+
+		Ok(Instruction {
+			offset,
+			pcode,
+			operands,
+			end: pos as u32,
+		})
+	}
+
+	/// A human-readable summary of the whole object: its chunks, strings,
+	/// and the disassembly of every script and function.
+	pub fn listing(&self) -> Result<String, ReadError> {
+		let mut out = String::new();
+
+		let _ = writeln!(
+			out,
+			"; {} object: {} script(s), {} function(s), {} string(s)",
+			String::from_utf8_lossy(self.format.magic()).trim_end_matches('\0'),
+			self.scripts.len(),
+			self.functions.len(),
+			self.strings.len(),
+		);
+
+		for chunk in &self.chunks {
+			let _ = writeln!(
+				out,
+				"; chunk {} at {}, {} byte(s)",
+				chunk.id_str(),
+				chunk.offset,
+				chunk.data.len()
+			);
+		}
+
+		for lib in &self.libraries {
+			let _ = writeln!(out, "; loads {lib:?}");
+		}
+
+		for (i, string) in self.strings.iter().enumerate() {
+			let _ = writeln!(out, "; string {i}: {string:?}");
+		}
+
+		for script in &self.scripts {
+			let _ = write!(out, "\nscript ");
+
+			match &script.name {
+				Some(name) => {
+					let _ = write!(out, "{name:?}");
+				}
+				None => {
+					let _ = write!(out, "{}", script.number);
+				}
+			}
+
+			let _ = writeln!(
+				out,
+				" (type {}, {} arg(s), flags {})",
+				script.kind, script.args, script.flags
+			);
+
+			for instr in self.disassemble(script.address)? {
+				let _ = writeln!(out, "\t{instr}");
+			}
+		}
+
+		for (i, func) in self.functions.iter().enumerate() {
+			let _ = writeln!(
+				out,
+				"\nfunction {i} {} ({} arg(s), {} local(s){})",
+				func.name.as_deref().unwrap_or("?"),
+				func.args,
+				func.locals,
+				if func.returns { ", returns" } else { "" }
+			);
+
+			if func.import != 0 {
+				let _ = writeln!(out, "\t; imported");
+				continue;
+			}
+
+			for instr in self.disassemble(func.address)? {
+				let _ = writeln!(out, "\t{instr}");
+			}
+		}
+
+		Ok(out)
+	}
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Result<u32, ReadError> {
+	data.get(pos..(pos + 4))
+		.map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+		.ok_or(ReadError::Truncated { offset: pos })
+}
+
+fn read_cstr(data: &[u8], pos: usize) -> Result<String, ReadError> {
+	let rest = data
+		.get(pos..)
+		.ok_or(ReadError::Truncated { offset: pos })?;
+
+	let len = rest
+		.iter()
+		.position(|&b| b == 0)
+		.ok_or(ReadError::Truncated { offset: data.len() })?;
+
+	Ok(String::from_utf8_lossy(&rest[..len]).into_owned())
+}
+
+/// `STRE` chunks XOR each string with a key derived from its offset.
+fn read_encrypted(data: &[u8], pos: usize) -> Result<String, ReadError> {
+	let key = (pos as u32).wrapping_mul(157_135);
+	let mut bytes = vec![];
+
+	for j in 0.. {
+		let byte = *data
+			.get(pos + j)
+			.ok_or(ReadError::Truncated { offset: pos + j })?;
+
+		let byte = byte ^ key.wrapping_add(j as u32 / 2) as u8;
+
+		if byte == 0 {
+			break;
+		}
+
+		bytes.push(byte);
+	}
+
+	Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// The layout of `FNAM`, `SNAM`, and `MEXP`.
+fn read_name_table(data: &[u8]) -> Result<Vec<String>, ReadError> {
+	let count = read_u32(data, 0)? as usize;
+
+	(0..count)
+		.map(|i| read_cstr(data, read_u32(data, 4 + i * 4)? as usize))
+		.collect()
+}
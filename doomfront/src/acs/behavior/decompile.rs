@@ -0,0 +1,1397 @@
+//! Turning p-code back into ACS source.
+//!
+//! Each body is decompiled in two passes. The first symbolically executes its
+//! instructions, rebuilding expressions from what gets pushed onto the stack
+//! and cutting the result into [`Item`]s: statements, and the jumps between
+//! them. The second matches the jumps against the shapes in which ACC emits
+//! each control flow statement, and nests the items accordingly.
+
+use std::{
+	cell::Cell,
+	collections::{BTreeMap, BTreeSet},
+	fmt::Write,
+};
+
+use crate::acs::{
+	internal,
+	pcode::{Pcode, VarKind, VarOp},
+};
+
+use super::{Format, Instruction, Object, ReadError};
+
+impl Object {
+	/// Reconstructs ACS source for every function and script.
+	///
+	/// Compilation discards names, so unless the object records them,
+	/// variables and functions are named after what they are and their index
+	/// (e.g. `map3`, `local1`, `func0`). Line specials and extension functions
+	/// are declared at the top of the output under placeholder names such as
+	/// `Special80`. Any body using a pcode the decompiler does not understand,
+	/// or whose control flow does not match a statement ACC could have emitted,
+	/// is left as its disassembly in a comment.
+	///
+	/// The output can be parsed by [`parse`](crate::acs::parse).
+	pub fn decompile(&self) -> Result<String, ReadError> {
+		let mut dc = Decompiler {
+			obj: self,
+			specials: BTreeMap::new(),
+			map_used: BTreeSet::new(),
+			scoped_used: BTreeSet::new(),
+		};
+
+		let mut bodies = String::new();
+
+		for (i, func) in self.functions.iter().enumerate() {
+			let name = func_name(self, i);
+
+			if func.import != 0 {
+				let _ = writeln!(bodies, "// `{name}` is imported from a library.\n");
+				continue;
+			}
+
+			let params = if func.args == 0 {
+				"void".to_string()
+			} else {
+				(0..func.args)
+					.map(|a| format!("int arg{a}"))
+					.collect::<Vec<_>>()
+					.join(", ")
+			};
+
+			let _ = writeln!(
+				bodies,
+				"function {} {name}({params}) {{",
+				if func.returns { "int" } else { "void" },
+			);
+
+			let body = dc.body(func.address, u32::from(func.args), &func.arrays, true)?;
+			let _ = writeln!(bodies, "{body}}}\n");
+		}
+
+		for script in &self.scripts {
+			let number = match &script.name {
+				Some(name) => quote(name),
+				None => script.number.to_string(),
+			};
+
+			let params = if script.args == 0 {
+				if script.kind == 0 {
+					" (void)".to_string()
+				} else {
+					String::new()
+				}
+			} else {
+				format!(
+					" ({})",
+					(0..script.args)
+						.map(|a| format!("int arg{a}"))
+						.collect::<Vec<_>>()
+						.join(", ")
+				)
+			};
+
+			let mut header = format!("script {number}{params}");
+
+			if let Some(kw) = script_type(script.kind) {
+				header.push(' ');
+				header.push_str(kw);
+			}
+
+			if script.flags & 1 != 0 {
+				header.push_str(" NET");
+			}
+
+			if script.flags & 2 != 0 {
+				header.push_str(" CLIENTSIDE");
+			}
+
+			let _ = writeln!(bodies, "{header} {{");
+			let body = dc.body(script.address, script.args, &script.arrays, false)?;
+			let _ = writeln!(bodies, "{body}}}\n");
+		}
+
+		Ok(dc.declarations() + &bodies)
+	}
+}
+
+// Details /////////////////////////////////////////////////////////////////////
+
+struct Decompiler<'o> {
+	obj: &'o Object,
+	/// Keys are special numbers; negative for extension functions.
+	/// Values are the fewest and most arguments each is called with.
+	specials: BTreeMap<i32, (usize, usize)>,
+	map_used: BTreeSet<u32>,
+	/// World and global variables.
+	scoped_used: BTreeSet<(VarKind, u32)>,
+}
+
+#[derive(Debug, Clone)]
+struct Expr {
+	text: String,
+	prec: u8,
+	/// Set if this is an integer literal, which might be a string index.
+	lit: Option<i32>,
+}
+
+const PREC_TERNARY: u8 = 1;
+const PREC_UNARY: u8 = 12;
+const PREC_PRIMARY: u8 = 13;
+
+impl Expr {
+	fn primary(text: String) -> Self {
+		Self {
+			text,
+			prec: PREC_PRIMARY,
+			lit: None,
+		}
+	}
+
+	fn lit(value: i32) -> Self {
+		Self {
+			text: value.to_string(),
+			prec: if value < 0 { PREC_UNARY } else { PREC_PRIMARY },
+			lit: Some(value),
+		}
+	}
+
+	fn unary(op: &str, operand: Self) -> Self {
+		let text = if operand.prec < PREC_UNARY || operand.text.starts_with(['-', '+']) {
+			format!("{op}({})", operand.text)
+		} else {
+			format!("{op}{}", operand.text)
+		};
+
+		Self {
+			text,
+			prec: PREC_UNARY,
+			lit: None,
+		}
+	}
+
+	/// All binary operators are left-associative.
+	fn binary(op: &str, prec: u8, lhs: Self, rhs: Self) -> Self {
+		Self {
+			text: format!("{} {op} {}", lhs.wrapped(prec), rhs.wrapped(prec + 1)),
+			prec,
+			lit: None,
+		}
+	}
+
+	/// Parenthesized if it binds less tightly than `prec`.
+	fn wrapped(&self, prec: u8) -> String {
+		if self.prec < prec {
+			format!("({})", self.text)
+		} else {
+			self.text.clone()
+		}
+	}
+}
+
+#[derive(Debug)]
+enum Node {
+	/// An expression statement.
+	Expr(String),
+	/// Any other statement.
+	Stat(String),
+	Goto(u32),
+	If {
+		cond: Expr,
+		/// `true` for `IFGOTO`, `false` for `IFNOTGOTO`.
+		jump_if: bool,
+		target: u32,
+	},
+	/// The jump to a `switch`'s case table.
+	Switch {
+		expr: Expr,
+		table: u32,
+	},
+	/// A case table, including the `DROP` and the jump to `default`.
+	Cases {
+		cases: Cases,
+		default: u32,
+	},
+}
+
+#[derive(Debug)]
+struct Item {
+	/// Where the first instruction contributing to this item starts.
+	offset: u32,
+	/// Where the next item starts.
+	end: u32,
+	node: Node,
+}
+
+#[derive(Debug, Default, Clone)]
+struct State {
+	stack: Vec<Expr>,
+	/// One entry per `BEGINPRINT` not yet ended.
+	prints: Vec<Vec<String>>,
+	/// The stack height at each `MOREHUDMESSAGE` not yet ended.
+	hud_marks: Vec<usize>,
+}
+
+/// Raised when a body cannot be decompiled.
+#[derive(Debug)]
+struct Unstructured;
+
+type Res<T> = Result<T, Unstructured>;
+/// Case values paired with the addresses they jump to.
+type Cases = Vec<(i32, u32)>;
+
+/// The body currently being decompiled.
+#[derive(Debug, Default)]
+struct Body {
+	params: u32,
+	locals: BTreeSet<u32>,
+	arrays: BTreeSet<u32>,
+}
+
+impl Decompiler<'_> {
+	/// Returns the body's statements, indented and with trailing newlines,
+	/// preceded by declarations of its local variables.
+	fn body(
+		&mut self,
+		address: u32,
+		params: u32,
+		arrays: &[u32],
+		func: bool,
+	) -> Result<String, ReadError> {
+		let instrs = self.obj.disassemble(address)?;
+
+		let mut body = Body {
+			params,
+			..Default::default()
+		};
+
+		// Declarations are only kept if the whole body decompiles.
+		let (specials, map_used, scoped_used) = (
+			self.specials.clone(),
+			self.map_used.clone(),
+			self.scoped_used.clone(),
+		);
+
+		let structured = self
+			.items(&instrs, &mut body)
+			.and_then(|items| self.structure(&items, &instrs, func));
+
+		let Ok(text) = structured else {
+			self.specials = specials;
+			self.map_used = map_used;
+			self.scoped_used = scoped_used;
+
+			let mut out =
+				"\t// Could not decompile this body; its disassembly follows.\n".to_string();
+
+			for instr in instrs {
+				let _ = writeln!(out, "\t// {instr}");
+			}
+
+			return Ok(out);
+		};
+
+		let mut out = String::new();
+
+		for local in &body.locals {
+			let _ = writeln!(out, "\tint local{local};");
+		}
+
+		for &array in &body.arrays {
+			let size = arrays.get(array as usize).copied().unwrap_or_default();
+			let _ = writeln!(out, "\tint localarr{array}[{size}];");
+		}
+
+		if !out.is_empty() {
+			out.push('\n');
+		}
+
+		Ok(out + &text)
+	}
+
+	// Pass 1 //////////////////////////////////////////////////////////////////
+
+	fn items(&mut self, instrs: &[Instruction], body: &mut Body) -> Res<Vec<Item>> {
+		let mut items = vec![];
+		let mut state = State::default();
+		let mut start = instrs.first().map_or(0, |i| i.offset);
+		let mut i = 0;
+
+		while i < instrs.len() {
+			let instr = &instrs[i];
+
+			if instr.pcode == Pcode::IfNotGoto {
+				if let Some((expr, next)) = self.ternary(instrs, i, &state, body) {
+					state.stack.pop();
+					state.stack.push(expr);
+					i = next;
+					continue;
+				}
+			}
+
+			if matches!(instr.pcode, Pcode::CaseGoto | Pcode::CaseGotoSorted)
+				&& state.stack.is_empty()
+			{
+				let (cases, default, next) = case_table(instrs, i)?;
+
+				items.push(Item {
+					offset: start,
+					end: instrs[next - 1].end,
+					node: Node::Cases { cases, default },
+				});
+
+				start = instrs[next - 1].end;
+				i = next;
+				continue;
+			}
+
+			if let Some(node) = self.step(instr, &mut state, body)? {
+				if !state.stack.is_empty() || !state.prints.is_empty() {
+					return Err(Unstructured);
+				}
+
+				items.push(Item {
+					offset: start,
+					end: instr.end,
+					node,
+				});
+
+				start = instr.end;
+			}
+
+			i += 1;
+		}
+
+		if !state.stack.is_empty() {
+			return Err(Unstructured);
+		}
+
+		Ok(items)
+	}
+
+	/// If the `IFNOTGOTO` at `instrs[i]` starts `cond ? a : b`, returns the
+	/// whole expression and the index of the instruction after it.
+	fn ternary(
+		&mut self,
+		instrs: &[Instruction],
+		i: usize,
+		state: &State,
+		body: &mut Body,
+	) -> Option<(Expr, usize)> {
+		let to_else = instrs[i].operands[0] as u32;
+		let else_at = instrs.iter().position(|instr| instr.offset == to_else)?;
+
+		if else_at <= i + 1 || instrs[else_at - 1].pcode != Pcode::Goto {
+			return None;
+		}
+
+		let to_end = instrs[else_at - 1].operands[0] as u32;
+
+		let end_at = if to_end == instrs.last()?.end {
+			instrs.len()
+		} else {
+			instrs.iter().position(|instr| instr.offset == to_end)?
+		};
+
+		if end_at <= else_at {
+			return None;
+		}
+
+		let mut state = state.clone();
+		let cond = state.stack.pop()?;
+		let if_true = self.pure(&instrs[(i + 1)..(else_at - 1)], &state, body)?;
+		let if_false = self.pure(&instrs[else_at..end_at], &state, body)?;
+
+		let expr = Expr {
+			text: format!(
+				"{} ? {} : {}",
+				cond.wrapped(PREC_TERNARY + 1),
+				if_true.wrapped(PREC_TERNARY + 1),
+				if_false.wrapped(PREC_TERNARY)
+			),
+			prec: PREC_TERNARY,
+			lit: None,
+		};
+
+		Some((expr, end_at))
+	}
+
+	/// The value of a run of instructions which only push one value.
+	fn pure(&mut self, instrs: &[Instruction], state: &State, body: &mut Body) -> Option<Expr> {
+		let mut state = state.clone();
+		let height = state.stack.len();
+
+		for instr in instrs {
+			if matches!(
+				instr.pcode,
+				Pcode::Goto | Pcode::IfGoto | Pcode::IfNotGoto | Pcode::CaseGoto
+			) || self.step(instr, &mut state, body).ok()?.is_some()
+			{
+				return None;
+			}
+		}
+
+		if state.stack.len() != height + 1 {
+			return None;
+		}
+
+		state.stack.pop()
+	}
+
+	/// Symbolically executes one instruction, returning a node if it
+	/// completes a statement or jumps.
+	fn step(
+		&mut self,
+		instr: &Instruction,
+		state: &mut State,
+		body: &mut Body,
+	) -> Res<Option<Node>> {
+		let ops = &instr.operands;
+		let stack = &mut state.stack;
+
+		macro_rules! pop {
+			() => {
+				stack.pop().ok_or(Unstructured)?
+			};
+			($n:expr) => {{
+				let n = $n;
+
+				if stack.len() < n {
+					return Err(Unstructured);
+				}
+
+				stack.split_off(stack.len() - n)
+			}};
+		}
+
+		if let Some((op, kind)) = instr.pcode.var_op() {
+			let name = self.var_name(kind, ops[0] as u32, body);
+
+			let target = if kind.is_array() {
+				let value = if matches!(op, VarOp::Push | VarOp::Inc | VarOp::Dec) {
+					None
+				} else {
+					Some(pop!())
+				};
+
+				let index = pop!();
+				stack.extend(value);
+				format!("{name}[{}]", index.text)
+			} else {
+				name
+			};
+
+			return Ok(match op {
+				VarOp::Push => {
+					stack.push(Expr::primary(target));
+					None
+				}
+				VarOp::Inc | VarOp::Dec => Some(Node::Expr(format!("{target}{}", op.operator()))),
+				_ => {
+					let value = pop!();
+					Some(Node::Expr(format!(
+						"{target} {} {}",
+						op.operator(),
+						value.text
+					)))
+				}
+			});
+		}
+
+		if let Some(op) = binary(instr.pcode) {
+			let rhs = pop!();
+			let lhs = pop!();
+			stack.push(Expr::binary(op.0, op.1, lhs, rhs));
+			return Ok(None);
+		}
+
+		if let Some((internal, direct)) = internal::by_pcode(instr.pcode) {
+			let args = if direct {
+				ops.iter().map(|&v| Expr::lit(v)).collect()
+			} else {
+				pop!(internal.max_args)
+			};
+
+			let call = Expr::primary(call_text(internal.name, &args));
+
+			if internal.returns {
+				stack.push(call);
+				return Ok(None);
+			}
+
+			return Ok(Some(Node::Expr(call.text)));
+		}
+
+		let node = match instr.pcode {
+			Pcode::Nop | Pcode::OptHudMessage => return Ok(None),
+			Pcode::Terminate => Node::Stat("terminate".to_string()),
+			Pcode::Suspend => Node::Stat("suspend".to_string()),
+			Pcode::Restart => Node::Stat("restart".to_string()),
+			Pcode::PushNumber
+			| Pcode::PushByte
+			| Pcode::Push2Bytes
+			| Pcode::Push3Bytes
+			| Pcode::Push4Bytes
+			| Pcode::Push5Bytes
+			| Pcode::PushBytes => {
+				stack.extend(ops.iter().map(|&v| Expr::lit(v)));
+				return Ok(None);
+			}
+			Pcode::Dup => {
+				let top = stack.last().ok_or(Unstructured)?.clone();
+				stack.push(top);
+				return Ok(None);
+			}
+			Pcode::Swap => {
+				let len = stack.len();
+
+				if len < 2 {
+					return Err(Unstructured);
+				}
+
+				stack.swap(len - 1, len - 2);
+				return Ok(None);
+			}
+			Pcode::Drop => Node::Expr(pop!().text),
+			Pcode::UnaryMinus => {
+				let operand = pop!();
+
+				stack.push(match operand.lit {
+					Some(v) if v > 0 => Expr::lit(-v),
+					_ => Expr::unary("-", operand),
+				});
+
+				return Ok(None);
+			}
+			Pcode::NegateLogical | Pcode::NegateBinary => {
+				let op = if instr.pcode == Pcode::NegateLogical {
+					"!"
+				} else {
+					"~"
+				};
+
+				let operand = pop!();
+				stack.push(Expr::unary(op, operand));
+				return Ok(None);
+			}
+			Pcode::TagString => {
+				let top = pop!();
+				stack.push(self.string(&top));
+				return Ok(None);
+			}
+			Pcode::Goto => {
+				let target = ops[0] as u32;
+
+				match stack.len() {
+					0 => Node::Goto(target),
+					1 => Node::Switch {
+						expr: pop!(),
+						table: target,
+					},
+					_ => return Err(Unstructured),
+				}
+			}
+			Pcode::IfGoto | Pcode::IfNotGoto => Node::If {
+				cond: pop!(),
+				jump_if: instr.pcode == Pcode::IfGoto,
+				target: ops[0] as u32,
+			},
+			Pcode::LSpec1
+			| Pcode::LSpec2
+			| Pcode::LSpec3
+			| Pcode::LSpec4
+			| Pcode::LSpec5
+			| Pcode::LSpec6
+			| Pcode::LSpec5Ex => {
+				let argc = match instr.pcode {
+					Pcode::LSpec1 => 1,
+					Pcode::LSpec2 => 2,
+					Pcode::LSpec3 => 3,
+					Pcode::LSpec4 => 4,
+					Pcode::LSpec6 => 6,
+					_ => 5,
+				};
+
+				let args = pop!(argc);
+				Node::Expr(self.special(ops[0], &args))
+			}
+			Pcode::LSpec5Result | Pcode::LSpec5ExResult => {
+				let mut args = pop!(5);
+
+				while args.last().is_some_and(|arg| arg.lit == Some(0)) {
+					args.pop();
+				}
+
+				let call = self.special(ops[0], &args);
+				stack.push(Expr::primary(call));
+				return Ok(None);
+			}
+			Pcode::LSpec1Direct
+			| Pcode::LSpec2Direct
+			| Pcode::LSpec3Direct
+			| Pcode::LSpec4Direct
+			| Pcode::LSpec5Direct
+			| Pcode::LSpec6Direct
+			| Pcode::LSpec1DirectB
+			| Pcode::LSpec2DirectB
+			| Pcode::LSpec3DirectB
+			| Pcode::LSpec4DirectB
+			| Pcode::LSpec5DirectB => {
+				let args = ops[1..].iter().map(|&v| Expr::lit(v)).collect::<Vec<_>>();
+				Node::Expr(self.special(ops[0], &args))
+			}
+			Pcode::DelayDirectB => Node::Expr(format!("Delay({})", ops[0])),
+			Pcode::RandomDirectB => {
+				stack.push(Expr::primary(format!("Random({}, {})", ops[0], ops[1])));
+				return Ok(None);
+			}
+			Pcode::CallFunc => {
+				let args = pop!(ops[0] as usize);
+				let call = self.special(-ops[1], &args);
+				stack.push(Expr::primary(call));
+				return Ok(None);
+			}
+			Pcode::Call | Pcode::CallDiscard => {
+				let index = ops[0] as usize;
+				let func = self.obj.functions.get(index).ok_or(Unstructured)?;
+				let args = pop!(usize::from(func.args));
+				let call = call_text(&func_name(self.obj, index), &args);
+
+				if instr.pcode == Pcode::CallDiscard {
+					Node::Expr(call)
+				} else {
+					stack.push(Expr::primary(call));
+					return Ok(None);
+				}
+			}
+			Pcode::ReturnVoid => Node::Stat("return".to_string()),
+			Pcode::ReturnVal => Node::Stat(format!("return {}", pop!().text)),
+			Pcode::BeginPrint => {
+				state.prints.push(vec![]);
+				return Ok(None);
+			}
+			Pcode::PrintString
+			| Pcode::PrintNumber
+			| Pcode::PrintCharacter
+			| Pcode::PrintName
+			| Pcode::PrintFixed
+			| Pcode::PrintLocalized
+			| Pcode::PrintBind
+			| Pcode::PrintBinary
+			| Pcode::PrintHex => {
+				let value = pop!();
+
+				let arg = match instr.pcode {
+					Pcode::PrintString => format!("s: {}", self.string(&value).text),
+					Pcode::PrintNumber => format!("d: {}", value.text),
+					Pcode::PrintCharacter => format!("c: {}", value.text),
+					Pcode::PrintName => format!("n: {}", value.text),
+					Pcode::PrintFixed => format!("f: {}", value.text),
+					Pcode::PrintLocalized => format!("l: {}", self.string(&value).text),
+					Pcode::PrintBind => format!("k: {}", self.string(&value).text),
+					Pcode::PrintBinary => format!("b: {}", value.text),
+					_ => format!("x: {}", value.text),
+				};
+
+				state.prints.last_mut().ok_or(Unstructured)?.push(arg);
+				return Ok(None);
+			}
+			Pcode::PrintScriptCharArray
+			| Pcode::PrintMapCharArray
+			| Pcode::PrintWorldCharArray
+			| Pcode::PrintGlobalCharArray => {
+				let array = pop!();
+				let offset = pop!();
+
+				let (Some(array), Some(0)) = (array.lit, offset.lit) else {
+					return Err(Unstructured);
+				};
+
+				let kind = match instr.pcode {
+					Pcode::PrintScriptCharArray => VarKind::ScriptArray,
+					Pcode::PrintMapCharArray => VarKind::MapArray,
+					Pcode::PrintWorldCharArray => VarKind::WorldArray,
+					_ => VarKind::GlobalArray,
+				};
+
+				let name = self.var_name(kind, array as u32, body);
+				state
+					.prints
+					.last_mut()
+					.ok_or(Unstructured)?
+					.push(format!("a: {name}"));
+				return Ok(None);
+			}
+			Pcode::MoreHudMessage => {
+				state.hud_marks.push(stack.len());
+				return Ok(None);
+			}
+			Pcode::EndPrint
+			| Pcode::EndPrintBold
+			| Pcode::EndLog
+			| Pcode::EndHudMessage
+			| Pcode::EndHudMessageBold
+			| Pcode::SaveString => {
+				let mut args = state.prints.pop().ok_or(Unstructured)?.join(", ");
+
+				if matches!(instr.pcode, Pcode::EndHudMessage | Pcode::EndHudMessageBold) {
+					let mark = state.hud_marks.pop().ok_or(Unstructured)?;
+
+					if mark > stack.len() {
+						return Err(Unstructured);
+					}
+
+					let tail = stack
+						.split_off(mark)
+						.into_iter()
+						.map(|arg| arg.text)
+						.collect::<Vec<_>>();
+
+					args.push_str("; ");
+					args.push_str(&tail.join(", "));
+				}
+
+				let name = match instr.pcode {
+					Pcode::EndPrint => "Print",
+					Pcode::EndPrintBold => "PrintBold",
+					Pcode::EndLog => "Log",
+					Pcode::EndHudMessage => "HudMessage",
+					Pcode::EndHudMessageBold => "HudMessageBold",
+					_ => {
+						stack.push(Expr::primary(format!("StrParam({args})")));
+						return Ok(None);
+					}
+				};
+
+				Node::Expr(format!("{name}({args})"))
+			}
+			_ => return Err(Unstructured),
+		};
+
+		Ok(Some(node))
+	}
+
+	fn var_name(&mut self, kind: VarKind, index: u32, body: &mut Body) -> String {
+		match kind {
+			VarKind::Script if index < body.params => format!("arg{index}"),
+			VarKind::Script => {
+				body.locals.insert(index);
+				format!("local{index}")
+			}
+			VarKind::ScriptArray => {
+				body.arrays.insert(index);
+				format!("localarr{index}")
+			}
+			VarKind::Map | VarKind::MapArray => {
+				self.map_used.insert(index);
+				map_var_name(self.obj, index)
+			}
+			VarKind::World | VarKind::Global | VarKind::WorldArray | VarKind::GlobalArray => {
+				self.scoped_used.insert((kind, index));
+				scoped_var_name(kind, index)
+			}
+		}
+	}
+
+	/// Renders `expr` as a string literal if it is a valid string index.
+	fn string(&self, expr: &Expr) -> Expr {
+		match expr.lit {
+			Some(index) if (index as usize) < self.obj.strings.len() && index >= 0 => {
+				Expr::primary(quote(&self.obj.strings[index as usize]))
+			}
+			_ => expr.clone(),
+		}
+	}
+
+	fn special(&mut self, number: i32, args: &[Expr]) -> String {
+		let entry = self
+			.specials
+			.entry(number)
+			.or_insert((args.len(), args.len()));
+
+		entry.0 = entry.0.min(args.len());
+		entry.1 = entry.1.max(args.len());
+
+		call_text(&special_name(number), args)
+	}
+
+	// Pass 2 //////////////////////////////////////////////////////////////////
+
+	fn structure(&self, items: &[Item], instrs: &[Instruction], func: bool) -> Res<String> {
+		let st = Structurer {
+			items,
+			end: instrs.last().map_or(0, |i| i.end),
+			labels_emitted: Cell::new(0),
+		};
+
+		// Drop the `TERMINATE` or `RETURNVOID` which ends every body.
+		let implicit = if func { "return" } else { "terminate" };
+
+		let hi = match items.last() {
+			Some(Item {
+				node: Node::Stat(stat),
+				..
+			}) if stat == implicit => items.len() - 1,
+			_ => items.len(),
+		};
+
+		let mut out = String::new();
+		st.block(0, hi, &Ctx::default(), 1, &mut out, false)?;
+		Ok(out)
+	}
+
+	fn declarations(&self) -> String {
+		let mut out = "// Decompiled from a BEHAVIOR lump.\n\n".to_string();
+
+		if self.obj.format == Format::Enhanced {
+			out.push_str("#nocompact\n\n");
+		}
+
+		for lib in &self.obj.libraries {
+			let _ = writeln!(out, "// Imports `{lib}`.");
+		}
+
+		if !self.specials.is_empty() {
+			let specials = self
+				.specials
+				.iter()
+				.map(|(&number, &(min, max))| {
+					let args = if min == max {
+						min.to_string()
+					} else {
+						format!("{min}, {max}")
+					};
+
+					format!("\t{number}:{}({args})", special_name(number))
+				})
+				.collect::<Vec<_>>();
+
+			let _ = writeln!(out, "special\n{};\n", specials.join(",\n"));
+		}
+
+		for (kind, index) in &self.scoped_used {
+			let (scope, array) = match kind {
+				VarKind::World => ("world", ""),
+				VarKind::Global => ("global", ""),
+				VarKind::WorldArray => ("world", "[]"),
+				_ => ("global", "[]"),
+			};
+
+			let name = scoped_var_name(*kind, *index);
+			let _ = writeln!(out, "{scope} int {index}:{name}{array};");
+		}
+
+		let mut map_vars = self.map_used.clone();
+		map_vars.extend(self.obj.map_vars.iter().map(|var| var.index));
+
+		for index in map_vars {
+			let var = self.obj.map_vars.iter().find(|var| var.index == index);
+			let is_str = var.is_some_and(|var| var.is_str);
+			let name = map_var_name(self.obj, index);
+
+			let value = |v: i32| {
+				if is_str {
+					self.string(&Expr::lit(v)).text
+				} else {
+					v.to_string()
+				}
+			};
+
+			let decl = match var {
+				Some(var) if var.size.is_some() => {
+					let size = var.size.unwrap();
+
+					if var.init.is_empty() {
+						format!("{name}[{size}]")
+					} else {
+						let init = var.init.iter().map(|&v| value(v)).collect::<Vec<_>>();
+						format!("{name}[{size}] = {{ {} }}", init.join(", "))
+					}
+				}
+				Some(var) if !var.init.is_empty() => format!("{name} = {}", value(var.init[0])),
+				_ => name,
+			};
+
+			let _ = writeln!(out, "{} {decl};", if is_str { "str" } else { "int" });
+		}
+
+		if !self.scoped_used.is_empty()
+			|| !self.map_used.is_empty()
+			|| !self.obj.map_vars.is_empty()
+		{
+			out.push('\n');
+		}
+
+		out
+	}
+}
+
+/// Break and continue targets.
+#[derive(Debug, Default, Clone, Copy)]
+struct Ctx<'l> {
+	brk: Option<u32>,
+	cont: Option<u32>,
+	/// `case` and `default` labels to emit before the items at their offsets,
+	/// when emitting the body of a `switch`.
+	labels: Option<&'l BTreeMap<u32, Vec<String>>>,
+}
+
+impl Ctx<'_> {
+	/// For blocks nested in the current one, which can not hold its labels.
+	fn nested(&self) -> Self {
+		Self {
+			labels: None,
+			..*self
+		}
+	}
+}
+
+struct Structurer<'i> {
+	items: &'i [Item],
+	/// The end of the body.
+	end: u32,
+	labels_emitted: Cell<usize>,
+}
+
+impl Structurer<'_> {
+	/// `hi` may be `items.len()`, which stands for the end of the body.
+	fn index_of(&self, offset: u32) -> Option<usize> {
+		if offset == self.end {
+			return Some(self.items.len());
+		}
+
+		self.items.iter().position(|item| item.offset == offset)
+	}
+
+	fn offset_of(&self, index: usize) -> u32 {
+		self.items.get(index).map_or(self.end, |item| item.offset)
+	}
+
+	/// Where `item` jumps backwards to, if it does.
+	fn back_jump(item: &Item) -> Option<u32> {
+		match &item.node {
+			Node::Goto(target) | Node::If { target, .. } if *target < item.offset => Some(*target),
+			_ => None,
+		}
+	}
+
+	/// Emits the items in `lo..hi`. If `loop_at_lo` is set, the item at `lo`
+	/// is already known to start a loop.
+	fn block(
+		&self,
+		lo: usize,
+		hi: usize,
+		ctx: &Ctx,
+		depth: usize,
+		out: &mut String,
+		loop_at_lo: bool,
+	) -> Res<()> {
+		let indent = "\t".repeat(depth);
+		let items = self.items;
+		let mut i = lo;
+
+		let emit_labels = |offset: u32, out: &mut String| {
+			let Some(labels) = ctx.labels.and_then(|l| l.get(&offset)) else {
+				return;
+			};
+
+			for label in labels {
+				let _ = writeln!(out, "{}{label}", "\t".repeat(depth - 1));
+				self.labels_emitted.set(self.labels_emitted.get() + 1);
+			}
+		};
+
+		while i < hi {
+			let item = &items[i];
+			emit_labels(item.offset, out);
+
+			let closer = if i == lo && loop_at_lo {
+				None
+			} else {
+				(i..hi)
+					.rev()
+					.find(|&k| Self::back_jump(&items[k]) == Some(item.offset))
+			};
+
+			if let Some(k) = closer {
+				i = self.block_loop(i, k, depth, out)?;
+				continue;
+			}
+
+			match &item.node {
+				Node::Expr(text) | Node::Stat(text) => {
+					let _ = writeln!(out, "{indent}{text};");
+					i += 1;
+				}
+				Node::Goto(target) => {
+					let _ = writeln!(out, "{indent}{};", jump_stat(*target, ctx)?);
+					i += 1;
+				}
+				Node::If {
+					cond,
+					jump_if,
+					target,
+				} => {
+					let jumps_out = cond_text(cond, *jump_if);
+
+					if ctx.brk == Some(*target) || ctx.cont == Some(*target) {
+						let stat = jump_stat(*target, ctx)?;
+						let _ = writeln!(out, "{indent}if ({jumps_out}) {stat};");
+						i += 1;
+						continue;
+					}
+
+					let t = self.index_of(*target).ok_or(Unstructured)?;
+
+					if t <= i || t > hi {
+						return Err(Unstructured);
+					}
+
+					let enters = cond_text(cond, !jump_if);
+					let _ = writeln!(out, "{indent}if ({enters}) {{");
+
+					let else_end = match items.get(t.wrapping_sub(1)) {
+						Some(Item {
+							node: Node::Goto(e),
+							..
+						}) if t > i + 1
+							&& *e > *target && ctx.brk != Some(*e)
+							&& ctx.cont != Some(*e) =>
+						{
+							self.index_of(*e).filter(|&e| e <= hi)
+						}
+						_ => None,
+					};
+
+					if let Some(e) = else_end {
+						self.block(i + 1, t - 1, &ctx.nested(), depth + 1, out, false)?;
+						let _ = writeln!(out, "{indent}}} else {{");
+						self.block(t, e, &ctx.nested(), depth + 1, out, false)?;
+						i = e;
+					} else {
+						self.block(i + 1, t, &ctx.nested(), depth + 1, out, false)?;
+						i = t;
+					}
+
+					let _ = writeln!(out, "{indent}}}");
+				}
+				Node::Switch { expr, table } => {
+					let t = self.index_of(*table).ok_or(Unstructured)?;
+
+					let Some(Item {
+						node: Node::Cases { cases, default },
+						end,
+						..
+					}) = items.get(t)
+					else {
+						return Err(Unstructured);
+					};
+
+					if t <= i || t >= hi {
+						return Err(Unstructured);
+					}
+
+					let mut labels = BTreeMap::<u32, Vec<String>>::new();
+
+					for (value, address) in cases {
+						labels
+							.entry(*address)
+							.or_default()
+							.push(format!("case {value}:"));
+					}
+
+					if default != end {
+						labels
+							.entry(*default)
+							.or_default()
+							.push("default:".to_string());
+					}
+
+					let body_hi = match &items[t - 1].node {
+						Node::Goto(target) if target == end && t > i + 1 => t - 1,
+						_ => t,
+					};
+
+					let ctx = Ctx {
+						brk: Some(*end),
+						cont: ctx.cont,
+						labels: Some(&labels),
+					};
+
+					let _ = writeln!(out, "{indent}switch ({}) {{", expr.text);
+					let before = self.labels_emitted.get();
+					self.block(i + 1, body_hi, &ctx, depth + 1, out, false)?;
+
+					let count = labels.values().map(Vec::len).sum::<usize>();
+
+					if self.labels_emitted.get() - before != count {
+						return Err(Unstructured);
+					}
+
+					let _ = writeln!(out, "{indent}}}");
+					i = t + 1;
+				}
+				Node::Cases { .. } => return Err(Unstructured),
+			}
+		}
+
+		emit_labels(self.offset_of(hi), out);
+		Ok(())
+	}
+
+	/// Emits the loop spanning `i..=k`, where `items[k]` jumps back to
+	/// `items[i]`. Returns the index of the item after the loop.
+	fn block_loop(&self, i: usize, k: usize, depth: usize, out: &mut String) -> Res<usize> {
+		let indent = "\t".repeat(depth);
+		let items = self.items;
+		let brk = items[k].end;
+
+		match &items[k].node {
+			Node::If { cond, jump_if, .. } => {
+				let ctx = Ctx {
+					brk: Some(brk),
+					cont: Some(items[k].offset),
+					labels: None,
+				};
+
+				let _ = writeln!(out, "{indent}do {{");
+				self.block(i, k, &ctx, depth + 1, out, true)?;
+
+				let kw = if *jump_if { "while" } else { "until" };
+				let _ = writeln!(out, "{indent}}} {kw} ({});", cond.text);
+			}
+			_ => {
+				let (head, body_lo) = match &items[i].node {
+					Node::If {
+						cond,
+						jump_if,
+						target,
+					} if *target == brk => {
+						let kw = if *jump_if { "until" } else { "while" };
+						(format!("{kw} ({})", cond.text), i + 1)
+					}
+					_ => ("while (1)".to_string(), i),
+				};
+
+				if let Some((c, iters)) = self
+					.for_iters(items[i].offset, body_lo, k)
+					.filter(|_| head.starts_with("while ("))
+				{
+					let cond = &head["while (".len()..(head.len() - 1)];
+					let cond = if body_lo == i { "" } else { cond };
+
+					let ctx = Ctx {
+						brk: Some(brk),
+						cont: Some(items[c].offset),
+						labels: None,
+					};
+
+					let _ = writeln!(out, "{indent}for (; {cond}; {}) {{", iters.join(", "));
+					self.block(body_lo, c, &ctx, depth + 1, out, body_lo == i)?;
+				} else {
+					let ctx = Ctx {
+						brk: Some(brk),
+						cont: Some(items[i].offset),
+						labels: None,
+					};
+
+					let _ = writeln!(out, "{indent}{head} {{");
+					self.block(body_lo, k, &ctx, depth + 1, out, body_lo == i)?;
+				}
+
+				let _ = writeln!(out, "{indent}}}");
+			}
+		}
+
+		Ok(k + 1)
+	}
+
+	/// `for` loops are `while` loops whose `continue` statements jump to
+	/// trailing expression statements rather than to the condition.
+	/// `top` is where the loop starts.
+	fn for_iters(&self, top: u32, lo: usize, k: usize) -> Option<(usize, Vec<String>)> {
+		let items = self.items;
+
+		if items[lo..k]
+			.iter()
+			.any(|item| Self::back_jump(item) == Some(top))
+		{
+			return None;
+		}
+
+		// Skip the jumps over `else` blocks, which follow the jump itself.
+		let is_else = |pos: usize| {
+			items.get(pos + 1).is_some_and(|next| {
+				items[lo..k].iter().any(
+					|item| matches!(item.node, Node::If { target, .. } if target == next.offset),
+				)
+			})
+		};
+
+		let c = (lo..k).find_map(|pos| match items[pos].node {
+			Node::Goto(target) if !is_else(pos) => {
+				self.index_of(target).filter(|&c| c > lo && c < k)
+			}
+			_ => None,
+		})?;
+
+		let iters = items[c..k]
+			.iter()
+			.map(|item| match &item.node {
+				Node::Expr(text) => Some(text.clone()),
+				_ => None,
+			})
+			.collect::<Option<Vec<_>>>()?;
+
+		Some((c, iters))
+	}
+}
+
+fn jump_stat(target: u32, ctx: &Ctx) -> Res<&'static str> {
+	if ctx.brk == Some(target) {
+		Ok("break")
+	} else if ctx.cont == Some(target) {
+		Ok("continue")
+	} else {
+		Err(Unstructured)
+	}
+}
+
+/// The condition under which a conditional jump is taken (`jump` is `true`)
+/// or not taken.
+fn cond_text(cond: &Expr, jump: bool) -> String {
+	if jump {
+		cond.text.clone()
+	} else {
+		Expr::unary("!", cond.clone()).text
+	}
+}
+
+/// Collects the case table starting at `instrs[i]`, which must end with
+/// `DROP` and a `GOTO` to the default case (or the end of the `switch`).
+/// Returns the cases, the default address, and the index after the table.
+fn case_table(instrs: &[Instruction], mut i: usize) -> Res<(Cases, u32, usize)> {
+	let mut cases = vec![];
+
+	while let Some(instr) = instrs.get(i) {
+		match instr.pcode {
+			Pcode::CaseGoto => cases.push((instr.operands[0], instr.operands[1] as u32)),
+			Pcode::CaseGotoSorted => {
+				for pair in instr.operands.chunks_exact(2) {
+					cases.push((pair[0], pair[1] as u32));
+				}
+			}
+			_ => break,
+		}
+
+		i += 1;
+	}
+
+	match (instrs.get(i), instrs.get(i + 1)) {
+		(Some(drop), Some(goto)) if drop.pcode == Pcode::Drop && goto.pcode == Pcode::Goto => {
+			Ok((cases, goto.operands[0] as u32, i + 2))
+		}
+		_ => Err(Unstructured),
+	}
+}
+
+fn binary(pcode: Pcode) -> Option<(&'static str, u8)> {
+	Some(match pcode {
+		Pcode::OrLogical => ("||", 2),
+		Pcode::AndLogical => ("&&", 3),
+		Pcode::OrBitwise => ("|", 4),
+		Pcode::EorBitwise => ("^", 5),
+		Pcode::AndBitwise => ("&", 6),
+		Pcode::Eq => ("==", 7),
+		Pcode::Ne => ("!=", 7),
+		Pcode::Lt => ("<", 8),
+		Pcode::Gt => (">", 8),
+		Pcode::Le => ("<=", 8),
+		Pcode::Ge => (">=", 8),
+		Pcode::LShift => ("<<", 9),
+		Pcode::RShift => (">>", 9),
+		Pcode::Add => ("+", 10),
+		Pcode::Subtract => ("-", 10),
+		Pcode::Multiply => ("*", 11),
+		Pcode::Divide => ("/", 11),
+		Pcode::Modulus => ("%", 11),
+		_ => return None,
+	})
+}
+
+fn call_text(name: &str, args: &[Expr]) -> String {
+	let args = args.iter().map(|arg| arg.text.as_str()).collect::<Vec<_>>();
+
+	format!("{name}({})", args.join(", "))
+}
+
+fn special_name(number: i32) -> String {
+	if number < 0 {
+		format!("ExtFunc{}", number.unsigned_abs())
+	} else {
+		format!("Special{number}")
+	}
+}
+
+fn func_name(obj: &Object, index: usize) -> String {
+	obj.functions
+		.get(index)
+		.and_then(|func| func.name.clone())
+		.unwrap_or_else(|| format!("func{index}"))
+}
+
+fn map_var_name(obj: &Object, index: u32) -> String {
+	obj.map_vars
+		.iter()
+		.find(|var| var.index == index)
+		.and_then(|var| var.name.clone())
+		.unwrap_or_else(|| format!("map{index}"))
+}
+
+fn scoped_var_name(kind: VarKind, index: u32) -> String {
+	match kind {
+		VarKind::World => format!("world{index}"),
+		VarKind::Global => format!("global{index}"),
+		VarKind::WorldArray => format!("worldarr{index}"),
+		_ => format!("globalarr{index}"),
+	}
+}
+
+fn quote(string: &str) -> String {
+	let mut ret = String::with_capacity(string.len() + 2);
+	ret.push('"');
+
+	for c in string.chars() {
+		match c {
+			'"' => ret.push_str("\\\""),
+			'\\' => ret.push_str("\\\\"),
+			'\n' => ret.push_str("\\n"),
+			'\t' => ret.push_str("\\t"),
+			c => ret.push(c),
+		}
+	}
+
+	ret.push('"');
+	ret
+}
+
+/// The keyword for one of ZDoom's script type numbers.
+fn script_type(kind: u16) -> Option<&'static str> {
+	Some(match kind {
+		1 => "OPEN",
+		2 => "RESPAWN",
+		3 => "DEATH",
+		4 => "ENTER",
+		5 => "PICKUP",
+		6 => "BLUERETURN",
+		7 => "REDRETURN",
+		8 => "WHITERETURN",
+		12 => "LIGHTNING",
+		13 => "UNLOADING",
+		14 => "DISCONNECT",
+		15 => "RETURN",
+		16 => "EVENT",
+		17 => "KILL",
+		18 => "REOPEN",
+		_ => return None,
+	})
+}
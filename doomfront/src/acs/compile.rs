@@ -0,0 +1,956 @@
+//! A compiler from ACS syntax trees to ZDoom's BEHAVIOR object format.
+//!
+//! Output is what ACC produces when targeting ZDoom: an `ACSe` object, or an
+//! `ACSE` object if the source includes `#nocompact`, with the code of every
+//! script and function first and every chunk after it. This is not a port
+//! of ACC, so the bytecode is not identical to ACC's, but it uses no pcodes
+//! or chunks that ACC-compiled objects do not.
+
+mod expr;
+mod object;
+mod stat;
+
+use std::collections::HashMap;
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::Issue;
+
+use super::{
+	ast,
+	pcode::{Pcode, VarKind, VarOp},
+	ParseTree, Syn, SyntaxToken,
+};
+
+/// Compiles the scripts and functions in `tree` to a BEHAVIOR object.
+///
+/// `tree` should have been parsed from the output of [`preprocess`] so that
+/// the line specials declared by `zspecial.acs` are available, although any
+/// `#define` and `#libdefine` directives which remain in it are honored.
+/// `libraries` holds the trees of the files `tree` pulls in via `#import`
+/// (see [`SourceFile::imported`]). Their functions, map variables, and
+/// `#libdefine` constants become usable by `tree`, and their `#library` names
+/// get written to the object's `LOAD` chunk so that ZDoom links against them.
+///
+/// Returns every error found if any are found.
+///
+/// [`preprocess`]: super::preprocess
+/// [`SourceFile::imported`]: super::SourceFile::imported
+pub fn compile(tree: &ParseTree, libraries: &[&ParseTree]) -> Result<Vec<u8>, Vec<Issue>> {
+	let mut compiler = Compiler::default();
+
+	for lib in libraries {
+		compiler.import(lib);
+	}
+
+	compiler.declare(tree);
+	compiler.emit_bodies();
+
+	if compiler.issues.iter().any(Issue::is_error) {
+		return Err(compiler.issues);
+	}
+
+	Ok(compiler.finish())
+}
+
+// Details /////////////////////////////////////////////////////////////////////
+
+/// ZDoom gives every script this many local variables unless told otherwise
+/// by an `SVCT` chunk.
+const DEFAULT_SCRIPT_VARS: u32 = 20;
+const MAX_MAP_VARS: usize = 128;
+const MAX_WORLD_VARS: u32 = 256;
+const MAX_GLOBAL_VARS: u32 = 64;
+const MAX_SCRIPT_ARGS: usize = 4;
+
+#[derive(Debug)]
+struct Compiler {
+	compact: bool,
+	/// From `#library`.
+	library: Option<String>,
+	encrypt: bool,
+	/// The object being built, starting with its header.
+	code: Vec<u8>,
+	strings: Vec<String>,
+	string_map: HashMap<String, usize>,
+	/// Keys are lowercased.
+	consts: HashMap<String, ast::Expression>,
+	/// Names of constants currently being expanded, to catch cycles.
+	const_stack: Vec<String>,
+	/// Keys are lowercased.
+	specials: HashMap<String, Special>,
+	funcs: Vec<Function>,
+	/// Keys are lowercased. Values are indices into `funcs`.
+	func_map: HashMap<String, usize>,
+	map_vars: Vec<MapVar>,
+	/// Map, world, and global variables. Keys are lowercased.
+	vars: HashMap<String, Var>,
+	scripts: Vec<Script>,
+	script_names: Vec<String>,
+	/// The names of imported libraries.
+	loads: Vec<String>,
+	issues: Vec<Issue>,
+}
+
+impl Default for Compiler {
+	fn default() -> Self {
+		Self {
+			compact: true,
+			library: None,
+			encrypt: false,
+			code: vec![],
+			strings: vec![],
+			string_map: HashMap::new(),
+			consts: HashMap::new(),
+			const_stack: vec![],
+			specials: HashMap::new(),
+			funcs: vec![],
+			func_map: HashMap::new(),
+			map_vars: vec![],
+			vars: HashMap::new(),
+			scripts: vec![],
+			script_names: vec![],
+			loads: vec![],
+			issues: vec![],
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Special {
+	/// Negative for ZDoom's extension functions, called via `CALLFUNC`.
+	number: i32,
+	min_args: usize,
+	max_args: usize,
+}
+
+#[derive(Debug)]
+struct Function {
+	name: String,
+	params: usize,
+	returns: bool,
+	/// Not counting parameters.
+	locals: u32,
+	arrays: Vec<u32>,
+	/// Zero if imported, since ZDoom resolves these by name.
+	address: u32,
+	node: Option<ast::FuncDef>,
+}
+
+#[derive(Debug)]
+struct MapVar {
+	name: String,
+	is_str: bool,
+	/// Empty for scalars.
+	dims: Vec<u32>,
+	/// One value for scalars, or one per element for arrays;
+	/// empty if there is no initializer.
+	init: Vec<i32>,
+	imported: bool,
+}
+
+#[derive(Debug)]
+struct Script {
+	number: i32,
+	kind: u8,
+	flags: u16,
+	args: usize,
+	vars: u32,
+	arrays: Vec<u32>,
+	address: u32,
+	node: ast::ScriptDef,
+}
+
+/// A resolved variable.
+#[derive(Debug, Clone)]
+struct Var {
+	/// One of the array kinds if `dims` is not empty.
+	kind: VarKind,
+	index: u32,
+	/// Array dimensions; empty for scalars. World and global arrays have
+	/// a single dimension of unknown length, represented as 0.
+	dims: Vec<u32>,
+}
+
+impl Var {
+	fn scalar(kind: VarKind, index: u32) -> Self {
+		Self {
+			kind,
+			index,
+			dims: vec![],
+		}
+	}
+}
+
+/// State for compiling one script or function body.
+#[derive(Debug, Default)]
+struct Body {
+	/// Innermost last. Keys are lowercased.
+	scopes: Vec<HashMap<String, Var>>,
+	/// Scalar variables declared so far, including parameters.
+	vars: u32,
+	/// The size of each local array.
+	arrays: Vec<u32>,
+	/// `Some` inside functions, holding whether the function returns a value.
+	func: Option<bool>,
+	/// Innermost last.
+	jumps: Vec<Jumps>,
+	/// Innermost last.
+	switches: Vec<Switch>,
+}
+
+/// Where `break` and `continue` statements must go.
+#[derive(Debug, Default)]
+struct Jumps {
+	/// Offsets of `GOTO` operands to patch once the target is known.
+	breaks: Vec<usize>,
+	/// `None` for `switch` statements, which `continue` passes through.
+	continues: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Default)]
+struct Switch {
+	cases: Vec<(i32, u32)>,
+	default: Option<u32>,
+}
+
+impl Compiler {
+	fn error(&mut self, range: TextRange, msg: impl Into<String>) {
+		self.issues.push(Issue::error(range, msg));
+	}
+
+	/// Reads the declarations of an `#import`ed library.
+	fn import(&mut self, tree: &ParseTree) {
+		let mut name = None;
+
+		for root in tree.ast() {
+			match root {
+				ast::Root::Library(lib) => name = Some(lib.name()),
+				ast::Root::Define(define) => {
+					if define.is_lib() {
+						self.consts
+							.insert(define.name().text().to_ascii_lowercase(), define.value());
+					}
+				}
+				ast::Root::Special(decl) => self.declare_specials(&decl),
+				ast::Root::Function(fndef) => {
+					let key = fndef.name().text().to_ascii_lowercase();
+
+					if self.func_map.contains_key(&key) {
+						continue;
+					}
+
+					self.func_map.insert(key, self.funcs.len());
+
+					self.funcs.push(Function {
+						name: fndef.name().text().to_string(),
+						params: fndef.params().iter().count(),
+						returns: fndef.return_type().kind() != Syn::KwVoid,
+						locals: 0,
+						arrays: vec![],
+						address: 0,
+						node: None,
+					});
+				}
+				ast::Root::Var(decl) => {
+					let is_str = decl.type_spec().kind() == Syn::KwStr;
+
+					for var_name in decl.names() {
+						if decl.scope().is_some() {
+							self.declare_scoped_var(&decl, &var_name, false);
+							continue;
+						}
+
+						let key = var_name.ident().text().to_ascii_lowercase();
+
+						if self.vars.contains_key(&key) {
+							continue;
+						}
+
+						let dims = var_name
+							.array_lens()
+							.map(|len| {
+								len.expr()
+									.and_then(|e| self.eval_quiet(&e))
+									.map_or(0, |n| n.max(0) as u32)
+							})
+							.collect::<Vec<_>>();
+
+						self.add_map_var(
+							key,
+							MapVar {
+								name: var_name.ident().text().to_string(),
+								is_str,
+								dims,
+								init: vec![],
+								imported: true,
+							},
+						);
+					}
+				}
+				ast::Root::Include(_) | ast::Root::Script(_) | ast::Root::SimpleDirective(_) => {}
+			}
+		}
+
+		match name {
+			Some(name) => self.loads.push(name),
+			None => self.error(
+				TextRange::default(),
+				"an imported file has no `#library` directive",
+			),
+		}
+	}
+
+	/// Handles directives and declares every special, variable, function,
+	/// and script in `tree`, so that bodies can refer to anything in the file
+	/// regardless of declaration order.
+	fn declare(&mut self, tree: &ParseTree) {
+		for root in tree.ast() {
+			match root {
+				ast::Root::Library(lib) => self.library = Some(lib.name()),
+				ast::Root::SimpleDirective(directive) => match directive.keyword().kind() {
+					Syn::KwNoCompact => self.compact = false,
+					Syn::KwEncryptStrings => self.encrypt = true,
+					_ => {}
+				},
+				ast::Root::Define(define) => {
+					let key = define.name().text().to_ascii_lowercase();
+
+					if self.consts.insert(key, define.value()).is_some() {
+						self.error(
+							define.name().text_range(),
+							format!("`{}` is already defined", define.name().text()),
+						);
+					}
+				}
+				ast::Root::Include(_) => {}
+				ast::Root::Special(decl) => self.declare_specials(&decl),
+				ast::Root::Var(decl) => self.declare_var(&decl),
+				ast::Root::Function(fndef) => self.declare_function(fndef),
+				ast::Root::Script(script) => self.declare_script(script),
+			}
+		}
+	}
+
+	fn declare_specials(&mut self, decl: &ast::SpecialDecl) {
+		for def in decl.defs() {
+			let Some(number) = def.number() else {
+				continue;
+			};
+
+			self.specials.insert(
+				def.name().text().to_ascii_lowercase(),
+				Special {
+					number,
+					min_args: def.min_args(),
+					max_args: def.max_args(),
+				},
+			);
+		}
+	}
+
+	fn declare_var(&mut self, decl: &ast::VarDecl) {
+		let is_str = decl.type_spec().kind() == Syn::KwStr;
+
+		for var_name in decl.names() {
+			if decl.scope().is_some() {
+				self.declare_scoped_var(decl, &var_name, true);
+				continue;
+			}
+
+			let ident = var_name.ident();
+			let key = ident.text().to_ascii_lowercase();
+
+			if self.vars.contains_key(&key) {
+				self.error(
+					ident.text_range(),
+					format!("`{}` is already declared", ident.text()),
+				);
+				continue;
+			}
+
+			if let Some(index) = var_name.index() {
+				self.error(
+					index.text_range(),
+					"only `world` and `global` variables can be given an index",
+				);
+			}
+
+			let (dims, init) = self.map_var_init(&var_name);
+
+			if self.map_vars.len() >= MAX_MAP_VARS {
+				self.error(
+					ident.text_range(),
+					format!("too many map variables (the limit is {MAX_MAP_VARS})"),
+				);
+			}
+
+			self.add_map_var(
+				key,
+				MapVar {
+					name: ident.text().to_string(),
+					is_str,
+					dims,
+					init,
+					imported: false,
+				},
+			);
+		}
+	}
+
+	fn add_map_var(&mut self, key: String, var: MapVar) {
+		let index = self.map_vars.len() as u32;
+
+		let kind = if var.dims.is_empty() {
+			VarKind::Map
+		} else {
+			VarKind::MapArray
+		};
+
+		self.vars.insert(
+			key,
+			Var {
+				kind,
+				index,
+				dims: var.dims.clone(),
+			},
+		);
+
+		self.map_vars.push(var);
+	}
+
+	/// Evaluates the dimensions and initializer of a map variable.
+	fn map_var_init(&mut self, var_name: &ast::VarName) -> (Vec<u32>, Vec<i32>) {
+		let init = var_name.init();
+		let dims = self.array_dims(var_name, init.as_ref());
+
+		let Some(init) = init else {
+			return (dims, vec![]);
+		};
+
+		let values = if dims.is_empty() {
+			match init {
+				ast::Initializer::Expr(expr) => match self.eval(&expr) {
+					Some(value) => vec![value],
+					None => vec![],
+				},
+				ast::Initializer::List(list) => {
+					self.error(
+						list.syntax().text_range(),
+						"only arrays can be initialized with a list",
+					);
+					vec![]
+				}
+			}
+		} else {
+			let len = dims.iter().product::<u32>() as usize;
+			let mut values = vec![0; len];
+
+			for (pos, expr) in self.flatten_init(&init, &dims) {
+				if let Some(value) = self.eval(&expr) {
+					values[pos as usize] = value;
+				}
+			}
+
+			values
+		};
+
+		(dims, values)
+	}
+
+	/// Evaluates the `[]` lengths after a variable's name. If the first length
+	/// is omitted, it is inferred from the initializer list, if there is one.
+	fn array_dims(&mut self, var_name: &ast::VarName, init: Option<&ast::Initializer>) -> Vec<u32> {
+		let mut dims = vec![];
+
+		for (i, len) in var_name.array_lens().enumerate() {
+			let value = match len.expr() {
+				Some(expr) => self.eval(&expr).unwrap_or(1),
+				None => match init {
+					Some(ast::Initializer::List(list)) if i == 0 => list.elements().count() as i32,
+					_ => {
+						self.error(
+							len.syntax().text_range(),
+							"array length can only be omitted if there is an initializer list",
+						);
+						1
+					}
+				},
+			};
+
+			if value <= 0 {
+				self.error(
+					len.syntax().text_range(),
+					"array length must be greater than zero",
+				);
+			}
+
+			dims.push(value.max(1) as u32);
+		}
+
+		dims
+	}
+
+	/// Pairs each expression in an initializer with the flattened index of the
+	/// array element it initializes, reporting elements that do not fit.
+	fn flatten_init(
+		&mut self,
+		init: &ast::Initializer,
+		dims: &[u32],
+	) -> Vec<(u32, ast::Expression)> {
+		let mut ret = vec![];
+		self.flatten_init_at(init, dims, 0, &mut ret);
+		ret
+	}
+
+	fn flatten_init_at(
+		&mut self,
+		init: &ast::Initializer,
+		dims: &[u32],
+		base: u32,
+		out: &mut Vec<(u32, ast::Expression)>,
+	) {
+		match init {
+			ast::Initializer::Expr(expr) => {
+				if dims.is_empty() {
+					out.push((base, expr.clone()));
+				} else {
+					self.error(
+						expr.syntax().text_range(),
+						"expected an initializer list for this array",
+					);
+				}
+			}
+			ast::Initializer::List(list) => {
+				let Some((&len, inner)) = dims.split_first() else {
+					self.error(
+						list.syntax().text_range(),
+						"too many nested initializer lists",
+					);
+					return;
+				};
+
+				let stride = inner.iter().product::<u32>();
+
+				for (i, elem) in list.elements().enumerate() {
+					if i as u32 >= len {
+						self.error(
+							elem.syntax().text_range(),
+							format!("too many initializers for an array of length {len}"),
+						);
+						break;
+					}
+
+					self.flatten_init_at(&elem, inner, base + (i as u32 * stride), out);
+				}
+			}
+		}
+	}
+
+	/// Declares a `world` or `global` variable. Errors are only reported
+	/// if `report` is `true`, since imported libraries are not ours to check.
+	fn declare_scoped_var(&mut self, decl: &ast::VarDecl, var_name: &ast::VarName, report: bool) {
+		let global = decl.scope().is_some_and(|t| t.kind() == Syn::KwGlobal);
+		let ident = var_name.ident();
+		let key = ident.text().to_ascii_lowercase();
+		let mut errors = vec![];
+
+		let index = match var_name.index().and_then(|t| t.text().parse::<u32>().ok()) {
+			Some(index) => index,
+			None => {
+				errors.push((
+					ident.text_range(),
+					"`world` and `global` variables need an index, e.g. `world int 1:name;`"
+						.to_string(),
+				));
+				0
+			}
+		};
+
+		let limit = if global {
+			MAX_GLOBAL_VARS
+		} else {
+			MAX_WORLD_VARS
+		};
+
+		if index >= limit {
+			errors.push((
+				ident.text_range(),
+				format!("variable index must be less than {limit}"),
+			));
+		}
+
+		let lens = var_name.array_lens().collect::<Vec<_>>();
+
+		if lens.len() > 1 || lens.iter().any(|len| len.expr().is_some()) {
+			errors.push((
+				ident.text_range(),
+				"`world` and `global` arrays must be declared with one empty `[]`".to_string(),
+			));
+		}
+
+		if let Some(init) = var_name.init() {
+			errors.push((
+				init.syntax().text_range(),
+				"`world` and `global` variables cannot be initialized".to_string(),
+			));
+		}
+
+		if self.vars.contains_key(&key) {
+			errors.push((
+				ident.text_range(),
+				format!("`{}` is already declared", ident.text()),
+			));
+		}
+
+		if report {
+			for (range, msg) in errors {
+				self.error(range, msg);
+			}
+		}
+
+		let var = match (global, lens.is_empty()) {
+			(false, true) => Var::scalar(VarKind::World, index),
+			(true, true) => Var::scalar(VarKind::Global, index),
+			(false, false) => Var {
+				kind: VarKind::WorldArray,
+				index,
+				dims: vec![0],
+			},
+			(true, false) => Var {
+				kind: VarKind::GlobalArray,
+				index,
+				dims: vec![0],
+			},
+		};
+
+		self.vars.entry(key).or_insert(var);
+	}
+
+	fn declare_function(&mut self, fndef: ast::FuncDef) {
+		let name = fndef.name();
+		let key = name.text().to_ascii_lowercase();
+
+		if self.func_map.contains_key(&key) {
+			self.error(
+				name.text_range(),
+				format!("function `{}` is already defined", name.text()),
+			);
+			return;
+		}
+
+		self.func_map.insert(key, self.funcs.len());
+
+		self.funcs.push(Function {
+			name: name.text().to_string(),
+			params: fndef.params().iter().count(),
+			returns: fndef.return_type().kind() != Syn::KwVoid,
+			locals: 0,
+			arrays: vec![],
+			address: 0,
+			node: Some(fndef),
+		});
+	}
+
+	fn declare_script(&mut self, script: ast::ScriptDef) {
+		let number_expr = script.number();
+		let range = number_expr.syntax().text_range();
+
+		let number = if let Some(name) = self.script_name(&number_expr) {
+			if self
+				.script_names
+				.iter()
+				.any(|n| n.eq_ignore_ascii_case(&name))
+			{
+				self.error(range, format!("script `\"{name}\"` is already defined"));
+				return;
+			}
+
+			self.script_names.push(name);
+			-(self.script_names.len() as i32)
+		} else {
+			let Some(number) = self.eval(&number_expr) else {
+				return;
+			};
+
+			if !(1..=32767).contains(&number) {
+				self.error(range, "script numbers must be between 1 and 32767");
+				return;
+			}
+
+			if self.scripts.iter().any(|s| s.number == number) {
+				self.error(range, format!("script {number} is already defined"));
+				return;
+			}
+
+			number
+		};
+
+		let args = script.params().map_or(0, |params| params.iter().count());
+		let kind_tok = script.kind();
+		let kind = kind_tok.as_ref().map_or(0, |tok| script_type(tok.kind()));
+
+		let expected = match kind_tok.as_ref().map(SyntaxToken::kind) {
+			None => None,
+			Some(Syn::KwDisconnect) => Some(1),
+			Some(Syn::KwEvent) => Some(3),
+			Some(_) => Some(0),
+		};
+
+		if let Some(expected) = expected {
+			if args != expected {
+				let kind_tok = kind_tok.unwrap();
+
+				self.error(
+					kind_tok.text_range(),
+					format!(
+						"`{}` scripts take {expected} argument(s), not {args}",
+						kind_tok.text().to_ascii_uppercase()
+					),
+				);
+			}
+		} else if args > MAX_SCRIPT_ARGS {
+			self.error(
+				range,
+				format!("scripts can take at most {MAX_SCRIPT_ARGS} arguments"),
+			);
+		}
+
+		let mut flags = 0;
+
+		if script.is_net() {
+			flags |= 1;
+		}
+
+		if script.is_clientside() {
+			flags |= 2;
+		}
+
+		self.scripts.push(Script {
+			number,
+			kind,
+			flags,
+			args,
+			vars: 0,
+			arrays: vec![],
+			address: 0,
+			node: script,
+		});
+	}
+
+	/// If `expr` is a string literal, or a constant expanding to one,
+	/// this is a named script.
+	fn script_name(&mut self, expr: &ast::Expression) -> Option<String> {
+		match expr {
+			ast::Expression::Literal(lit) => lit.concat_string(),
+			ast::Expression::Ident(e_id) => {
+				let value = self
+					.consts
+					.get(&e_id.token().text().to_ascii_lowercase())?
+					.clone();
+
+				self.script_name(&value)
+			}
+			ast::Expression::Group(group) => self.script_name(&group.inner()),
+			_ => None,
+		}
+	}
+
+	/// Compiles every script, then every function, in order of appearance.
+	fn emit_bodies(&mut self) {
+		self.code
+			.extend_from_slice(if self.compact { b"ACSe" } else { b"ACSE" });
+		self.code.extend_from_slice(&[0; 4]);
+
+		for i in 0..self.scripts.len() {
+			self.scripts[i].address = self.pos();
+			let node = self.scripts[i].node.clone();
+
+			let mut body = Body {
+				scopes: vec![HashMap::new()],
+				..Default::default()
+			};
+
+			if let Some(params) = node.params() {
+				for param in params.iter() {
+					self.declare_local(&mut body, &param.name(), &[]);
+				}
+			}
+
+			self.stat_compound(&mut body, &node.body());
+			self.op(Pcode::Terminate);
+
+			self.scripts[i].vars = body.vars;
+			self.scripts[i].arrays = body.arrays;
+		}
+
+		for i in 0..self.funcs.len() {
+			let Some(node) = self.funcs[i].node.clone() else {
+				continue;
+			};
+
+			self.funcs[i].address = self.pos();
+
+			let mut body = Body {
+				scopes: vec![HashMap::new()],
+				func: Some(self.funcs[i].returns),
+				..Default::default()
+			};
+
+			for param in node.params().iter() {
+				self.declare_local(&mut body, &param.name(), &[]);
+			}
+
+			self.stat_compound(&mut body, &node.body());
+			self.op(Pcode::ReturnVoid);
+
+			self.funcs[i].locals = body.vars - self.funcs[i].params as u32;
+			self.funcs[i].arrays = body.arrays;
+		}
+	}
+
+	fn declare_local(&mut self, body: &mut Body, ident: &SyntaxToken, dims: &[u32]) -> Var {
+		let key = ident.text().to_ascii_lowercase();
+
+		if body.scopes.last().unwrap().contains_key(&key) {
+			self.error(
+				ident.text_range(),
+				format!("`{}` is already declared", ident.text()),
+			);
+		}
+
+		let var = if dims.is_empty() {
+			body.vars += 1;
+			Var::scalar(VarKind::Script, body.vars - 1)
+		} else {
+			body.arrays.push(dims.iter().product());
+
+			Var {
+				kind: VarKind::ScriptArray,
+				index: body.arrays.len() as u32 - 1,
+				dims: dims.to_vec(),
+			}
+		};
+
+		if self.compact && var.index > u32::from(u8::MAX) {
+			self.error(
+				ident.text_range(),
+				"too many local variables for a compact object; use `#nocompact`",
+			);
+		}
+
+		body.scopes.last_mut().unwrap().insert(key, var.clone());
+		var
+	}
+
+	/// Looks up a variable by name, innermost scope first.
+	fn lookup(&self, body: &Body, name: &str) -> Option<Var> {
+		let key = name.to_ascii_lowercase();
+
+		body.scopes
+			.iter()
+			.rev()
+			.find_map(|scope| scope.get(&key))
+			.or_else(|| self.vars.get(&key))
+			.cloned()
+	}
+
+	fn string(&mut self, string: String) -> i32 {
+		if let Some(&index) = self.string_map.get(&string) {
+			return index as i32;
+		}
+
+		let index = self.strings.len();
+		self.string_map.insert(string.clone(), index);
+		self.strings.push(string);
+		index as i32
+	}
+
+	// Emission ////////////////////////////////////////////////////////////////
+
+	fn pos(&self) -> u32 {
+		self.code.len() as u32
+	}
+
+	fn op(&mut self, pcode: Pcode) {
+		let n = pcode as u16;
+
+		if !self.compact {
+			self.int(i32::from(n));
+		} else if n < 240 {
+			self.code.push(n as u8);
+		} else {
+			self.code.push((240 + ((n - 240) >> 8)) as u8);
+			self.code.push(((n - 240) & 0xFF) as u8);
+		}
+	}
+
+	fn int(&mut self, value: i32) {
+		self.code.extend_from_slice(&value.to_le_bytes());
+	}
+
+	/// See [`super::pcode::Operands::Index`].
+	fn index(&mut self, value: u32) {
+		if self.compact {
+			self.code.push(value as u8);
+		} else {
+			self.int(value as i32);
+		}
+	}
+
+	fn push(&mut self, value: i32) {
+		if self.compact && (0..=255).contains(&value) {
+			self.op(Pcode::PushByte);
+			self.code.push(value as u8);
+		} else {
+			self.op(Pcode::PushNumber);
+			self.int(value);
+		}
+	}
+
+	/// Emits a jump instruction and returns the position of its
+	/// operand, to be filled in later via [`Self::patch`].
+	fn jump(&mut self, pcode: Pcode) -> usize {
+		self.op(pcode);
+		let at = self.code.len();
+		self.int(0);
+		at
+	}
+
+	fn jump_to(&mut self, pcode: Pcode, target: u32) {
+		self.op(pcode);
+		self.int(target as i32);
+	}
+
+	fn patch(&mut self, at: usize, target: u32) {
+		self.code[at..(at + 4)].copy_from_slice(&target.to_le_bytes());
+	}
+
+	fn var_op(&mut self, op: VarOp, var: &Var) {
+		let pcode = Pcode::for_var(op, var.kind).unwrap();
+		self.op(pcode);
+		self.index(var.index);
+	}
+}
+
+/// The type number ZDoom uses for a script type keyword.
+fn script_type(kw: Syn) -> u8 {
+	match kw {
+		Syn::KwOpen => 1,
+		Syn::KwRespawn => 2,
+		Syn::KwDeath => 3,
+		Syn::KwEnter => 4,
+		Syn::KwPickup => 5,
+		Syn::KwBlueReturn => 6,
+		Syn::KwRedReturn => 7,
+		Syn::KwWhiteReturn => 8,
+		Syn::KwLightning => 12,
+		Syn::KwUnloading => 13,
+		Syn::KwDisconnect => 14,
+		Syn::KwReturn => 15,
+		Syn::KwEvent => 16,
+		Syn::KwKill => 17,
+		Syn::KwReopen => 18,
+		_ => 0,
+	}
+}
@@ -0,0 +1,856 @@
+//! Code generation for expressions, assignments, and calls.
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::acs::{
+	ast, internal,
+	pcode::{Pcode, VarKind, VarOp},
+	Syn,
+};
+
+use super::{Body, Compiler, Var};
+
+impl Compiler {
+	/// Evaluates a constant expression, reporting an error if it is not one.
+	pub(super) fn eval(&mut self, expr: &ast::Expression) -> Option<i32> {
+		self.eval_inner(expr, true)
+	}
+
+	/// Like [`Self::eval`], but without reporting anything.
+	pub(super) fn eval_quiet(&mut self, expr: &ast::Expression) -> Option<i32> {
+		self.eval_inner(expr, false)
+	}
+
+	fn eval_inner(&mut self, expr: &ast::Expression, report: bool) -> Option<i32> {
+		let range = expr.syntax().text_range();
+
+		let ret = match expr {
+			ast::Expression::Literal(lit) => self.literal(lit, report),
+			ast::Expression::Group(group) => self.eval_inner(&group.inner(), report),
+			ast::Expression::Ident(e_id) => {
+				let token = e_id.token();
+				let key = token.text().to_ascii_lowercase();
+
+				let Some(value) = self.consts.get(&key).cloned() else {
+					if report {
+						self.error(range, format!("`{}` is not a constant", token.text()));
+					}
+
+					return None;
+				};
+
+				if self.const_stack.contains(&key) {
+					if report {
+						self.error(
+							range,
+							format!("`{}` is defined in terms of itself", token.text()),
+						);
+					}
+
+					return None;
+				}
+
+				self.const_stack.push(key);
+				let ret = self.eval_inner(&value, report);
+				self.const_stack.pop();
+				return ret;
+			}
+			ast::Expression::Prefix(prefix) => {
+				let operand = self.eval_inner(&prefix.operand(), report)?;
+
+				match prefix.operator().kind() {
+					Syn::Minus => Some(operand.wrapping_neg()),
+					Syn::Plus => Some(operand),
+					Syn::Bang => Some(i32::from(operand == 0)),
+					Syn::Tilde => Some(!operand),
+					_ => None,
+				}
+			}
+			ast::Expression::Binary(binary) if !binary.is_assign() => {
+				let lhs = self.eval_inner(&binary.left(), report)?;
+				let rhs = self.eval_inner(&binary.right(), report)?;
+
+				match binary.operator().kind() {
+					Syn::Slash | Syn::Percent if rhs == 0 => {
+						if report {
+							self.error(range, "division by zero");
+						}
+
+						return None;
+					}
+					op => binary_op(op, lhs, rhs),
+				}
+			}
+			ast::Expression::Ternary(ternary) => {
+				let condition = self.eval_inner(&ternary.condition(), report)?;
+
+				if condition != 0 {
+					self.eval_inner(&ternary.if_true(), report)
+				} else {
+					self.eval_inner(&ternary.if_false(), report)
+				}
+			}
+			_ => None,
+		};
+
+		if ret.is_none() && report {
+			self.error(range, "expected a constant expression");
+		}
+
+		ret
+	}
+
+	/// The value of a literal. Strings are added to the string table,
+	/// and their index is returned.
+	fn literal(&mut self, lit: &ast::Literal, report: bool) -> Option<i32> {
+		let token = lit.token();
+		let range = token.syntax().text_range();
+
+		match token.syntax().kind() {
+			Syn::LitInt => match token.int().unwrap() {
+				Ok(int) if int <= u64::from(u32::MAX) => Some(int as u32 as i32),
+				_ => {
+					if report {
+						self.error(range, "integer literal is too large");
+					}
+
+					None
+				}
+			},
+			Syn::LitFixed => token.fixed_bits(),
+			Syn::LitChar => token.char().map(|c| c as i32),
+			Syn::LitString => lit.concat_string().map(|s| self.string(s)),
+			_ => None,
+		}
+	}
+
+	/// Emits code which pushes the value of `expr` onto the stack.
+	pub(super) fn expr(&mut self, body: &mut Body, expr: &ast::Expression) {
+		let range = expr.syntax().text_range();
+
+		match expr {
+			ast::Expression::Literal(lit) => {
+				let value = self.literal(lit, true).unwrap_or_default();
+				self.push(value);
+
+				if lit.token().syntax().kind() == Syn::LitString && self.library.is_some() {
+					self.op(Pcode::TagString);
+				}
+			}
+			ast::Expression::Group(group) => self.expr(body, &group.inner()),
+			ast::Expression::Ident(e_id) => {
+				let token = e_id.token();
+
+				if let Some(var) = self.lookup(body, token.text()) {
+					if var.dims.is_empty() {
+						self.var_op(VarOp::Push, &var);
+					} else {
+						self.error(
+							range,
+							format!("`{}` is an array, and needs an index", token.text()),
+						);
+					}
+
+					return;
+				}
+
+				let key = token.text().to_ascii_lowercase();
+
+				let Some(value) = self.consts.get(&key).cloned() else {
+					self.error(range, format!("`{}` is not declared", token.text()));
+					return;
+				};
+
+				if self.const_stack.contains(&key) {
+					self.error(
+						range,
+						format!("`{}` is defined in terms of itself", token.text()),
+					);
+					return;
+				}
+
+				self.const_stack.push(key);
+				self.expr(body, &value);
+				self.const_stack.pop();
+			}
+			ast::Expression::Index(_) => {
+				if let Some(var) = self.place(body, expr) {
+					self.var_op(VarOp::Push, &var);
+				}
+			}
+			ast::Expression::Prefix(prefix) => {
+				let operand = prefix.operand();
+
+				match prefix.operator().kind() {
+					Syn::Minus => {
+						if let ast::Expression::Literal(lit) = &operand {
+							let value = self.literal(lit, true).unwrap_or_default();
+							self.push(value.wrapping_neg());
+						} else {
+							self.expr(body, &operand);
+							self.op(Pcode::UnaryMinus);
+						}
+					}
+					Syn::Plus => self.expr(body, &operand),
+					Syn::Bang => {
+						self.expr(body, &operand);
+						self.op(Pcode::NegateLogical);
+					}
+					Syn::Tilde => {
+						self.expr(body, &operand);
+						self.op(Pcode::NegateBinary);
+					}
+					_ => self.error(range, "`++` and `--` can only be used as statements"),
+				}
+			}
+			ast::Expression::Postfix(_) => {
+				self.error(range, "`++` and `--` can only be used as statements");
+			}
+			ast::Expression::Binary(binary) => {
+				if binary.is_assign() {
+					self.error(range, "assignments can only be used as statements");
+					return;
+				}
+
+				self.expr(body, &binary.left());
+				self.expr(body, &binary.right());
+				self.op(binary_pcode(binary.operator().kind()));
+			}
+			ast::Expression::Ternary(ternary) => {
+				self.expr(body, &ternary.condition());
+				let to_else = self.jump(Pcode::IfNotGoto);
+				self.expr(body, &ternary.if_true());
+				let to_end = self.jump(Pcode::Goto);
+				self.patch(to_else, self.pos());
+				self.expr(body, &ternary.if_false());
+				self.patch(to_end, self.pos());
+			}
+			ast::Expression::Call(call) => {
+				self.call(body, call, true);
+			}
+		}
+	}
+
+	/// Emits code for an expression whose value goes unused.
+	/// This is the only place assignments and increments are allowed.
+	pub(super) fn stat_expr(&mut self, body: &mut Body, expr: &ast::Expression) {
+		match expr {
+			ast::Expression::Binary(binary) if binary.is_assign() => {
+				let op = match binary.operator().kind() {
+					Syn::Eq => VarOp::Assign,
+					Syn::PlusEq => VarOp::Add,
+					Syn::MinusEq => VarOp::Sub,
+					Syn::AsteriskEq => VarOp::Mul,
+					Syn::SlashEq => VarOp::Div,
+					Syn::PercentEq => VarOp::Mod,
+					Syn::AngleL2Eq => VarOp::LShift,
+					Syn::AngleR2Eq => VarOp::RShift,
+					Syn::PipeEq => VarOp::Or,
+					Syn::AmpersandEq => VarOp::And,
+					Syn::CaretEq => VarOp::Eor,
+					_ => unreachable!(),
+				};
+
+				if let Some(var) = self.place(body, &binary.left()) {
+					self.expr(body, &binary.right());
+					self.var_op(op, &var);
+				}
+			}
+			ast::Expression::Prefix(prefix)
+				if matches!(prefix.operator().kind(), Syn::Plus2 | Syn::Minus2) =>
+			{
+				self.increment(body, &prefix.operand(), prefix.operator().kind());
+			}
+			ast::Expression::Postfix(postfix) => {
+				self.increment(body, &postfix.operand(), postfix.operator().kind());
+			}
+			ast::Expression::Call(call) => {
+				self.call(body, call, false);
+			}
+			ast::Expression::Group(group) => self.stat_expr(body, &group.inner()),
+			other => {
+				self.expr(body, other);
+				self.op(Pcode::Drop);
+			}
+		}
+	}
+
+	fn increment(&mut self, body: &mut Body, operand: &ast::Expression, operator: Syn) {
+		let op = if operator == Syn::Plus2 {
+			VarOp::Inc
+		} else {
+			VarOp::Dec
+		};
+
+		if let Some(var) = self.place(body, operand) {
+			self.var_op(op, &var);
+		}
+	}
+
+	/// Resolves an assignable expression to a variable. For array elements,
+	/// this also emits code pushing the flattened element index.
+	pub(super) fn place(&mut self, body: &mut Body, expr: &ast::Expression) -> Option<Var> {
+		let mut indices = vec![];
+		let mut base = expr.clone();
+
+		while let ast::Expression::Index(index) = base {
+			indices.push(index.index());
+			base = index.indexed();
+		}
+
+		while let ast::Expression::Group(group) = base {
+			base = group.inner();
+		}
+
+		indices.reverse();
+
+		let ast::Expression::Ident(e_id) = &base else {
+			self.error(
+				expr.syntax().text_range(),
+				"expected a variable or array element",
+			);
+			return None;
+		};
+
+		let token = e_id.token();
+
+		let Some(var) = self.lookup(body, token.text()) else {
+			let msg = if self.consts.contains_key(&token.text().to_ascii_lowercase()) {
+				format!("`{}` is a constant, not a variable", token.text())
+			} else {
+				format!("`{}` is not declared", token.text())
+			};
+
+			self.error(token.text_range(), msg);
+			return None;
+		};
+
+		if indices.len() != var.dims.len() {
+			let msg = if var.dims.is_empty() {
+				format!("`{}` is not an array", token.text())
+			} else {
+				format!(
+					"`{}` has {} dimension(s), but is indexed with {}",
+					token.text(),
+					var.dims.len(),
+					indices.len()
+				)
+			};
+
+			self.error(expr.syntax().text_range(), msg);
+			return None;
+		}
+
+		for (i, index) in indices.iter().enumerate() {
+			if i > 0 {
+				self.push(var.dims[i] as i32);
+				self.op(Pcode::Multiply);
+			}
+
+			self.expr(body, index);
+
+			if i > 0 {
+				self.op(Pcode::Add);
+			}
+		}
+
+		Some(var)
+	}
+
+	/// If `value` is `false`, any return value is dropped.
+	fn call(&mut self, body: &mut Body, call: &ast::ExprCall, value: bool) {
+		let range = call.syntax().text_range();
+
+		let Some(name) = call.name() else {
+			self.error(
+				call.callee().syntax().text_range(),
+				"only functions can be called, by name",
+			);
+			return;
+		};
+
+		let key = name.text().to_ascii_lowercase();
+		let args = call.args();
+
+		if let Some(print) = PrintFunc::from_name(&key) {
+			self.print(body, print, &args, value, range);
+			return;
+		}
+
+		if let Some(arg) = args.iter().find(|arg| arg.cast_spec().is_some()) {
+			self.error(
+				arg.syntax().text_range(),
+				"only print-like functions take arguments with cast specifiers",
+			);
+		}
+
+		if let Some(arg) = args.tail().next() {
+			self.error(
+				arg.syntax().text_range(),
+				"only `HudMessage` and `HudMessageBold` take arguments after a `;`",
+			);
+		}
+
+		let exprs = args.iter().map(|arg| arg.expr()).collect::<Vec<_>>();
+
+		let returns = if let Some(&index) = self.func_map.get(&key) {
+			self.call_function(body, index, &exprs, value, args.is_const(), range)
+		} else if let Some(&special) = self.specials.get(&key) {
+			self.call_special(body, special, &exprs, value, args.is_const(), range)
+		} else if let Some(internal) = internal::find(&key) {
+			self.call_internal(body, internal, &exprs, value, args.is_const(), range)
+		} else {
+			self.error(
+				name.text_range(),
+				format!("`{}` is not a function", name.text()),
+			);
+			return;
+		};
+
+		if value && !returns {
+			self.error(range, format!("`{}` does not return a value", name.text()));
+		}
+	}
+
+	/// Returns `true` if the function returns a value.
+	fn call_function(
+		&mut self,
+		body: &mut Body,
+		index: usize,
+		args: &[ast::Expression],
+		value: bool,
+		is_const: bool,
+		range: TextRange,
+	) -> bool {
+		let func = &self.funcs[index];
+		let (params, returns) = (func.params, func.returns);
+
+		if args.len() != params {
+			let msg = format!(
+				"`{}` takes {params} argument(s), not {}",
+				func.name,
+				args.len()
+			);
+
+			self.error(range, msg);
+		}
+
+		if is_const {
+			self.error(range, "`const:` can only be used with line specials");
+		}
+
+		if self.compact && index > usize::from(u8::MAX) {
+			self.error(
+				range,
+				"too many functions for a compact object; use `#nocompact`",
+			);
+		}
+
+		for arg in args {
+			self.expr(body, arg);
+		}
+
+		self.op(if value && returns {
+			Pcode::Call
+		} else {
+			Pcode::CallDiscard
+		});
+
+		self.index(index as u32);
+		returns
+	}
+
+	/// Returns `true` if this usage produces a value.
+	fn call_special(
+		&mut self,
+		body: &mut Body,
+		special: super::Special,
+		args: &[ast::Expression],
+		value: bool,
+		is_const: bool,
+		range: TextRange,
+	) -> bool {
+		if args.len() < special.min_args || args.len() > special.max_args {
+			let msg = if special.min_args == special.max_args {
+				format!(
+					"expected {} argument(s), not {}",
+					special.min_args,
+					args.len()
+				)
+			} else {
+				format!(
+					"expected between {} and {} arguments, not {}",
+					special.min_args,
+					special.max_args,
+					args.len()
+				)
+			};
+
+			self.error(range, msg);
+		}
+
+		if special.number < 0 {
+			if is_const {
+				self.error(range, "`const:` can only be used with line specials");
+			}
+
+			for arg in args {
+				self.expr(body, arg);
+			}
+
+			self.op(Pcode::CallFunc);
+
+			if self.compact {
+				self.code.push(args.len() as u8);
+				self.code
+					.extend_from_slice(&(special.number.unsigned_abs() as u16).to_le_bytes());
+			} else {
+				self.int(args.len() as i32);
+				self.int(special.number.wrapping_neg());
+			}
+
+			if !value {
+				self.op(Pcode::Drop);
+			}
+
+			return true;
+		}
+
+		if is_const {
+			if value {
+				self.error(range, "line specials called with `const:` return no value");
+			}
+
+			let pcode = match args.len() {
+				0 | 1 => Pcode::LSpec1Direct,
+				2 => Pcode::LSpec2Direct,
+				3 => Pcode::LSpec3Direct,
+				4 => Pcode::LSpec4Direct,
+				5 => Pcode::LSpec5Direct,
+				_ => Pcode::LSpec6Direct,
+			};
+
+			let values = args
+				.iter()
+				.map(|arg| self.eval(arg).unwrap_or_default())
+				.collect::<Vec<_>>();
+
+			self.op(pcode);
+			self.index(special.number as u32);
+
+			for value in values {
+				self.int(value);
+			}
+
+			if args.is_empty() {
+				self.int(0);
+			}
+
+			return false;
+		}
+
+		if value {
+			if args.len() > 5 {
+				self.error(range, "line specials with 6 arguments return no value");
+			}
+
+			for arg in args {
+				self.expr(body, arg);
+			}
+
+			for _ in args.len()..5 {
+				self.push(0);
+			}
+
+			self.op(Pcode::LSpec5Result);
+			self.index(special.number as u32);
+			return true;
+		}
+
+		for arg in args {
+			self.expr(body, arg);
+		}
+
+		if args.is_empty() {
+			self.push(0);
+		}
+
+		self.op(match args.len() {
+			0 | 1 => Pcode::LSpec1,
+			2 => Pcode::LSpec2,
+			3 => Pcode::LSpec3,
+			4 => Pcode::LSpec4,
+			5 => Pcode::LSpec5,
+			_ => Pcode::LSpec6,
+		});
+
+		self.index(special.number as u32);
+		false
+	}
+
+	/// Returns `true` if the function returns a value.
+	fn call_internal(
+		&mut self,
+		body: &mut Body,
+		internal: &internal::Internal,
+		args: &[ast::Expression],
+		value: bool,
+		is_const: bool,
+		range: TextRange,
+	) -> bool {
+		if args.len() < internal.min_args || args.len() > internal.max_args {
+			let msg = if internal.min_args == internal.max_args {
+				format!(
+					"`{}` takes {} argument(s), not {}",
+					internal.name,
+					internal.min_args,
+					args.len()
+				)
+			} else {
+				format!(
+					"`{}` takes between {} and {} arguments, not {}",
+					internal.name,
+					internal.min_args,
+					internal.max_args,
+					args.len()
+				)
+			};
+
+			self.error(range, msg);
+		}
+
+		if is_const {
+			let Some(direct) = internal.direct else {
+				self.error(
+					range,
+					format!("`{}` cannot be called with `const:`", internal.name),
+				);
+				return internal.returns;
+			};
+
+			let values = args
+				.iter()
+				.map(|arg| self.eval(arg).unwrap_or_default())
+				.collect::<Vec<_>>();
+
+			self.op(direct);
+
+			for i in 0..internal.max_args {
+				self.int(values.get(i).copied().unwrap_or_default());
+			}
+		} else {
+			for arg in args {
+				self.expr(body, arg);
+			}
+
+			for _ in args.len()..internal.max_args {
+				self.push(0);
+			}
+
+			self.op(internal.pcode);
+		}
+
+		if internal.returns && !value {
+			self.op(Pcode::Drop);
+		}
+
+		internal.returns
+	}
+
+	fn print(
+		&mut self,
+		body: &mut Body,
+		print: PrintFunc,
+		args: &ast::ArgList,
+		value: bool,
+		range: TextRange,
+	) {
+		if value && print != PrintFunc::StrParam {
+			self.error(range, "only `StrParam` returns a value");
+		}
+
+		if !value && print == PrintFunc::StrParam {
+			self.error(range, "the value of `StrParam` must be used");
+		}
+
+		if args.is_const() {
+			self.error(range, "`const:` can only be used with line specials");
+		}
+
+		self.op(Pcode::BeginPrint);
+
+		for arg in args.head() {
+			let Some(cast) = arg.cast_spec() else {
+				self.error(
+					arg.syntax().text_range(),
+					"expected a cast specifier, e.g. `s:` or `d:`",
+				);
+				continue;
+			};
+
+			let pcode = match cast.text().to_ascii_lowercase().as_str() {
+				"s" => Pcode::PrintString,
+				"d" | "i" => Pcode::PrintNumber,
+				"c" => Pcode::PrintCharacter,
+				"n" => Pcode::PrintName,
+				"f" => Pcode::PrintFixed,
+				"l" => Pcode::PrintLocalized,
+				"k" => Pcode::PrintBind,
+				"b" => Pcode::PrintBinary,
+				"x" => Pcode::PrintHex,
+				"a" => {
+					self.print_array(body, &arg.expr());
+					continue;
+				}
+				other => {
+					self.error(
+						cast.text_range(),
+						format!("unknown cast specifier `{other}:`"),
+					);
+					continue;
+				}
+			};
+
+			self.expr(body, &arg.expr());
+			self.op(pcode);
+		}
+
+		let tail = args.tail().collect::<Vec<_>>();
+
+		if matches!(print, PrintFunc::HudMessage | PrintFunc::HudMessageBold) {
+			if tail.len() < 6 {
+				self.error(
+					range,
+					"`HudMessage` needs at least 6 arguments after the `;`",
+				);
+			}
+
+			self.op(Pcode::MoreHudMessage);
+
+			for (i, arg) in tail.iter().enumerate() {
+				if i == 6 {
+					self.op(Pcode::OptHudMessage);
+				}
+
+				self.expr(body, &arg.expr());
+			}
+
+			if tail.len() <= 6 {
+				self.op(Pcode::OptHudMessage);
+			}
+		} else if let Some(arg) = tail.first() {
+			self.error(
+				arg.syntax().text_range(),
+				"only `HudMessage` and `HudMessageBold` take arguments after a `;`",
+			);
+		}
+
+		self.op(match print {
+			PrintFunc::Print => Pcode::EndPrint,
+			PrintFunc::PrintBold => Pcode::EndPrintBold,
+			PrintFunc::Log => Pcode::EndLog,
+			PrintFunc::HudMessage => Pcode::EndHudMessage,
+			PrintFunc::HudMessageBold => Pcode::EndHudMessageBold,
+			PrintFunc::StrParam => Pcode::SaveString,
+		});
+	}
+
+	/// `a:` prints a character array, starting from its first element.
+	fn print_array(&mut self, body: &mut Body, expr: &ast::Expression) {
+		let range = expr.syntax().text_range();
+
+		let var = match expr {
+			ast::Expression::Ident(e_id) => self.lookup(body, e_id.token().text()),
+			_ => None,
+		};
+
+		let pcode = match var.as_ref().map(|var| var.kind) {
+			Some(VarKind::ScriptArray) => Pcode::PrintScriptCharArray,
+			Some(VarKind::MapArray) => Pcode::PrintMapCharArray,
+			Some(VarKind::WorldArray) => Pcode::PrintWorldCharArray,
+			Some(VarKind::GlobalArray) => Pcode::PrintGlobalCharArray,
+			_ => {
+				self.error(range, "`a:` must be followed by the name of an array");
+				return;
+			}
+		};
+
+		self.push(0);
+		self.push(var.unwrap().index as i32);
+		self.op(pcode);
+	}
+}
+
+/// ACC's built-in functions which take cast-specified arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrintFunc {
+	Print,
+	PrintBold,
+	Log,
+	HudMessage,
+	HudMessageBold,
+	StrParam,
+}
+
+impl PrintFunc {
+	/// `name` must be lowercase.
+	fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"print" => Some(Self::Print),
+			"printbold" => Some(Self::PrintBold),
+			"log" => Some(Self::Log),
+			"hudmessage" => Some(Self::HudMessage),
+			"hudmessagebold" => Some(Self::HudMessageBold),
+			"strparam" => Some(Self::StrParam),
+			_ => None,
+		}
+	}
+}
+
+fn binary_pcode(op: Syn) -> Pcode {
+	match op {
+		Syn::Asterisk => Pcode::Multiply,
+		Syn::Slash => Pcode::Divide,
+		Syn::Percent => Pcode::Modulus,
+		Syn::Plus => Pcode::Add,
+		Syn::Minus => Pcode::Subtract,
+		Syn::AngleL2 => Pcode::LShift,
+		Syn::AngleR2 => Pcode::RShift,
+		Syn::AngleL => Pcode::Lt,
+		Syn::AngleR => Pcode::Gt,
+		Syn::AngleLEq => Pcode::Le,
+		Syn::AngleREq => Pcode::Ge,
+		Syn::Eq2 => Pcode::Eq,
+		Syn::BangEq => Pcode::Ne,
+		Syn::Ampersand => Pcode::AndBitwise,
+		Syn::Caret => Pcode::EorBitwise,
+		Syn::Pipe => Pcode::OrBitwise,
+		Syn::Ampersand2 => Pcode::AndLogical,
+		Syn::Pipe2 => Pcode::OrLogical,
+		_ => unreachable!(),
+	}
+}
+
+fn binary_op(op: Syn, lhs: i32, rhs: i32) -> Option<i32> {
+	Some(match op {
+		Syn::Asterisk => lhs.wrapping_mul(rhs),
+		Syn::Slash => lhs.wrapping_div(rhs),
+		Syn::Percent => lhs.wrapping_rem(rhs),
+		Syn::Plus => lhs.wrapping_add(rhs),
+		Syn::Minus => lhs.wrapping_sub(rhs),
+		Syn::AngleL2 => lhs.wrapping_shl(rhs as u32),
+		Syn::AngleR2 => lhs.wrapping_shr(rhs as u32),
+		Syn::AngleL => i32::from(lhs < rhs),
+		Syn::AngleR => i32::from(lhs > rhs),
+		Syn::AngleLEq => i32::from(lhs <= rhs),
+		Syn::AngleREq => i32::from(lhs >= rhs),
+		Syn::Eq2 => i32::from(lhs == rhs),
+		Syn::BangEq => i32::from(lhs != rhs),
+		Syn::Ampersand => lhs & rhs,
+		Syn::Caret => lhs ^ rhs,
+		Syn::Pipe => lhs | rhs,
+		Syn::Ampersand2 => i32::from(lhs != 0 && rhs != 0),
+		Syn::Pipe2 => i32::from(lhs != 0 || rhs != 0),
+		_ => return None,
+	})
+}
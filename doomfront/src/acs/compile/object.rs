@@ -0,0 +1,365 @@
+//! Writing the chunks which follow an object's code.
+
+use super::{Compiler, DEFAULT_SCRIPT_VARS};
+
+impl Compiler {
+	/// Appends every chunk to the code and points the header at the first.
+	pub(super) fn finish(mut self) -> Vec<u8> {
+		let chunks_start = self.pos();
+		self.code[4..8].copy_from_slice(&chunks_start.to_le_bytes());
+
+		self.chunk_sptr();
+		self.chunk_sflg();
+		self.chunk_svct();
+		self.chunk_func();
+		self.chunk_strings();
+		self.chunk_mini();
+		self.chunk_aray();
+		self.chunk_aini();
+		self.chunk_mstr();
+		self.chunk_astr();
+		self.chunk_mexp();
+		self.chunk_mimp();
+		self.chunk_aimp();
+		self.chunk_load();
+		self.chunk_snam();
+		self.chunk_sary();
+		self.chunk_fary();
+
+		self.code
+	}
+
+	/// Writes a chunk unless `data` is empty.
+	fn chunk(&mut self, id: &[u8; 4], data: &[u8]) {
+		if data.is_empty() {
+			return;
+		}
+
+		self.code.extend_from_slice(id);
+		self.code
+			.extend_from_slice(&(data.len() as u32).to_le_bytes());
+		self.code.extend_from_slice(data);
+	}
+
+	fn chunk_sptr(&mut self) {
+		let mut data = vec![];
+
+		for script in &self.scripts {
+			data.extend_from_slice(&(script.number as i16).to_le_bytes());
+
+			if self.compact {
+				data.push(script.kind);
+				data.push(script.args as u8);
+				data.extend_from_slice(&script.address.to_le_bytes());
+			} else {
+				data.extend_from_slice(&u16::from(script.kind).to_le_bytes());
+				data.extend_from_slice(&script.address.to_le_bytes());
+				data.extend_from_slice(&(script.args as u32).to_le_bytes());
+			}
+		}
+
+		self.chunk(b"SPTR", &data);
+	}
+
+	fn chunk_sflg(&mut self) {
+		let mut data = vec![];
+
+		for script in self.scripts.iter().filter(|s| s.flags != 0) {
+			data.extend_from_slice(&(script.number as i16).to_le_bytes());
+			data.extend_from_slice(&script.flags.to_le_bytes());
+		}
+
+		self.chunk(b"SFLG", &data);
+	}
+
+	fn chunk_svct(&mut self) {
+		let mut data = vec![];
+
+		for script in self.scripts.iter().filter(|s| s.vars > DEFAULT_SCRIPT_VARS) {
+			data.extend_from_slice(&(script.number as i16).to_le_bytes());
+			data.extend_from_slice(&(script.vars as u16).to_le_bytes());
+		}
+
+		self.chunk(b"SVCT", &data);
+	}
+
+	/// `FUNC` and `FNAM`. Imported functions are listed too, with a non-zero
+	/// import number and no address, so that ZDoom can link them by name.
+	fn chunk_func(&mut self) {
+		let mut data = vec![];
+
+		for func in &self.funcs {
+			data.push(func.params as u8);
+			data.push(func.locals as u8);
+			data.push(u8::from(func.returns));
+			data.push(u8::from(func.node.is_none()));
+			data.extend_from_slice(&func.address.to_le_bytes());
+		}
+
+		self.chunk(b"FUNC", &data);
+
+		let names = name_table(self.funcs.iter().map(|f| f.name.as_str()));
+		self.chunk(b"FNAM", &names);
+	}
+
+	/// `STRL`, or `STRE` if `#encryptstrings` was used.
+	fn chunk_strings(&mut self) {
+		if self.strings.is_empty() {
+			return;
+		}
+
+		let count = self.strings.len() as u32;
+		let mut data = vec![];
+		data.extend_from_slice(&0_u32.to_le_bytes());
+		data.extend_from_slice(&count.to_le_bytes());
+		data.extend_from_slice(&0_u32.to_le_bytes());
+
+		let mut offset = 12 + count * 4;
+		let mut strings = vec![];
+
+		for string in &self.strings {
+			data.extend_from_slice(&offset.to_le_bytes());
+
+			let mut bytes = string.as_bytes().to_vec();
+			bytes.push(0);
+
+			if self.encrypt {
+				let key = offset.wrapping_mul(157_135);
+
+				for (j, byte) in bytes.iter_mut().enumerate() {
+					*byte ^= key.wrapping_add(j as u32 / 2) as u8;
+				}
+			}
+
+			offset += bytes.len() as u32;
+			strings.extend(bytes);
+		}
+
+		data.extend(strings);
+		self.chunk(if self.encrypt { b"STRE" } else { b"STRL" }, &data);
+	}
+
+	/// One chunk per run of consecutive map variables with initializers.
+	fn chunk_mini(&mut self) {
+		let mut runs: Vec<(u32, Vec<i32>)> = vec![];
+
+		for (i, var) in self.map_vars.iter().enumerate() {
+			if var.imported || !var.dims.is_empty() || var.init.is_empty() {
+				continue;
+			}
+
+			match runs.last_mut() {
+				Some((first, values)) if *first as usize + values.len() == i => {
+					values.push(var.init[0]);
+				}
+				_ => runs.push((i as u32, vec![var.init[0]])),
+			}
+		}
+
+		for (first, values) in runs {
+			let mut data = first.to_le_bytes().to_vec();
+
+			for value in values {
+				data.extend_from_slice(&value.to_le_bytes());
+			}
+
+			self.chunk(b"MINI", &data);
+		}
+	}
+
+	fn chunk_aray(&mut self) {
+		let mut data = vec![];
+
+		for (i, var) in self.own_arrays() {
+			data.extend_from_slice(&(i as u32).to_le_bytes());
+			data.extend_from_slice(&var.dims.iter().product::<u32>().to_le_bytes());
+		}
+
+		self.chunk(b"ARAY", &data);
+	}
+
+	/// One chunk per initialized map array.
+	fn chunk_aini(&mut self) {
+		let inits = self
+			.own_arrays()
+			.filter(|(_, var)| var.init.iter().any(|&v| v != 0))
+			.map(|(i, var)| {
+				let mut data = (i as u32).to_le_bytes().to_vec();
+
+				for value in &var.init {
+					data.extend_from_slice(&value.to_le_bytes());
+				}
+
+				data
+			})
+			.collect::<Vec<_>>();
+
+		for data in inits {
+			self.chunk(b"AINI", &data);
+		}
+	}
+
+	fn chunk_mstr(&mut self) {
+		let mut data = vec![];
+
+		for (i, var) in self.map_vars.iter().enumerate() {
+			if !var.imported && var.is_str && var.dims.is_empty() {
+				data.extend_from_slice(&(i as u32).to_le_bytes());
+			}
+		}
+
+		self.chunk(b"MSTR", &data);
+	}
+
+	fn chunk_astr(&mut self) {
+		let mut data = vec![];
+
+		for (i, var) in self.own_arrays() {
+			if var.is_str {
+				data.extend_from_slice(&(i as u32).to_le_bytes());
+			}
+		}
+
+		self.chunk(b"ASTR", &data);
+	}
+
+	/// Libraries export every map variable by name.
+	fn chunk_mexp(&mut self) {
+		if self.library.is_none() {
+			return;
+		}
+
+		let names = name_table(
+			self.map_vars
+				.iter()
+				.filter(|var| !var.imported)
+				.map(|var| var.name.as_str()),
+		);
+
+		self.chunk(b"MEXP", &names);
+	}
+
+	fn chunk_mimp(&mut self) {
+		let mut data = vec![];
+
+		for (i, var) in self.map_vars.iter().enumerate() {
+			if var.imported && var.dims.is_empty() {
+				data.extend_from_slice(&(i as u32).to_le_bytes());
+				data.extend_from_slice(var.name.as_bytes());
+				data.push(0);
+			}
+		}
+
+		self.chunk(b"MIMP", &data);
+	}
+
+	fn chunk_aimp(&mut self) {
+		let arrays = self
+			.map_vars
+			.iter()
+			.enumerate()
+			.filter(|(_, var)| var.imported && !var.dims.is_empty())
+			.collect::<Vec<_>>();
+
+		if arrays.is_empty() {
+			return;
+		}
+
+		let mut data = (arrays.len() as u32).to_le_bytes().to_vec();
+
+		for (i, var) in arrays {
+			data.extend_from_slice(&(i as u32).to_le_bytes());
+			data.extend_from_slice(&var.dims.iter().product::<u32>().to_le_bytes());
+			data.extend_from_slice(var.name.as_bytes());
+			data.push(0);
+		}
+
+		self.chunk(b"AIMP", &data);
+	}
+
+	fn chunk_load(&mut self) {
+		let mut data = vec![];
+
+		for name in &self.loads {
+			data.extend_from_slice(name.as_bytes());
+			data.push(0);
+		}
+
+		self.chunk(b"LOAD", &data);
+	}
+
+	fn chunk_snam(&mut self) {
+		let names = name_table(self.script_names.iter().map(String::as_str));
+		self.chunk(b"SNAM", &names);
+	}
+
+	fn chunk_sary(&mut self) {
+		let arrays = self
+			.scripts
+			.iter()
+			.filter(|s| !s.arrays.is_empty())
+			.map(|s| array_sizes(s.number as u16, &s.arrays))
+			.collect::<Vec<_>>();
+
+		for data in arrays {
+			self.chunk(b"SARY", &data);
+		}
+	}
+
+	fn chunk_fary(&mut self) {
+		let arrays = self
+			.funcs
+			.iter()
+			.enumerate()
+			.filter(|(_, f)| !f.arrays.is_empty())
+			.map(|(i, f)| array_sizes(i as u16, &f.arrays))
+			.collect::<Vec<_>>();
+
+		for data in arrays {
+			self.chunk(b"FARY", &data);
+		}
+	}
+
+	fn own_arrays(&self) -> impl Iterator<Item = (usize, &super::MapVar)> {
+		self.map_vars
+			.iter()
+			.enumerate()
+			.filter(|(_, var)| !var.imported && !var.dims.is_empty())
+	}
+}
+
+/// The layout shared by `FNAM`, `SNAM`, and `MEXP`: a count, then offsets
+/// relative to the start of the chunk's data, then null-terminated names.
+fn name_table<'s>(names: impl Iterator<Item = &'s str>) -> Vec<u8> {
+	let names = names.collect::<Vec<_>>();
+
+	if names.is_empty() {
+		return vec![];
+	}
+
+	let mut data = (names.len() as u32).to_le_bytes().to_vec();
+	let mut offset = 4 + names.len() as u32 * 4;
+
+	for name in &names {
+		data.extend_from_slice(&offset.to_le_bytes());
+		offset += name.len() as u32 + 1;
+	}
+
+	for name in names {
+		data.extend_from_slice(name.as_bytes());
+		data.push(0);
+	}
+
+	data
+}
+
+/// The layout shared by `SARY` and `FARY`.
+fn array_sizes(owner: u16, sizes: &[u32]) -> Vec<u8> {
+	let mut data = owner.to_le_bytes().to_vec();
+
+	for size in sizes {
+		data.extend_from_slice(&size.to_le_bytes());
+	}
+
+	data
+}
@@ -0,0 +1,320 @@
+//! Code generation for statements and control flow.
+
+use std::collections::HashMap;
+
+use rowan::ast::AstNode;
+
+use crate::acs::{
+	ast,
+	pcode::{Pcode, VarOp},
+};
+
+use super::{Body, Compiler, Jumps, Switch};
+
+impl Compiler {
+	pub(super) fn stat_compound(&mut self, body: &mut Body, compound: &ast::StatCompound) {
+		body.scopes.push(HashMap::new());
+
+		for stat in compound.statements() {
+			self.stat(body, &stat);
+		}
+
+		body.scopes.pop();
+	}
+
+	fn stat(&mut self, body: &mut Body, stat: &ast::Statement) {
+		let range = stat.syntax().text_range();
+
+		match stat {
+			ast::Statement::Compound(compound) => self.stat_compound(body, compound),
+			ast::Statement::Empty(_) => {}
+			ast::Statement::Expr(e) => self.stat_expr(body, &e.expr()),
+			ast::Statement::LocalVar(local) => {
+				for var_name in local.names() {
+					self.local_var(body, &var_name);
+				}
+			}
+			ast::Statement::If(stat_if) => {
+				self.expr(body, &stat_if.condition());
+				let to_else = self.jump(Pcode::IfNotGoto);
+				self.stat(body, &stat_if.then_branch());
+
+				if let Some(else_branch) = stat_if.else_branch() {
+					let to_end = self.jump(Pcode::Goto);
+					self.patch(to_else, self.pos());
+					self.stat(body, &else_branch);
+					self.patch(to_end, self.pos());
+				} else {
+					self.patch(to_else, self.pos());
+				}
+			}
+			ast::Statement::While(stat_while) => {
+				self.stat_loop(body, &stat_while.condition(), &stat_while.body(), false);
+			}
+			ast::Statement::Until(stat_until) => {
+				self.stat_loop(body, &stat_until.condition(), &stat_until.body(), true);
+			}
+			ast::Statement::DoWhile(stat_do) => {
+				let top = self.pos();
+				body.jumps.push(Jumps {
+					breaks: vec![],
+					continues: Some(vec![]),
+				});
+
+				self.stat(body, &stat_do.body());
+
+				let cont = self.pos();
+				self.expr(body, &stat_do.condition());
+
+				self.jump_to(
+					if stat_do.is_until() {
+						Pcode::IfNotGoto
+					} else {
+						Pcode::IfGoto
+					},
+					top,
+				);
+
+				self.end_jumps(body, cont);
+			}
+			ast::Statement::For(stat_for) => {
+				body.scopes.push(HashMap::new());
+
+				for var_name in stat_for.init_vars() {
+					self.local_var(body, &var_name);
+				}
+
+				for expr in stat_for.init_exprs() {
+					self.stat_expr(body, &expr);
+				}
+
+				let top = self.pos();
+
+				let to_end = stat_for.condition().map(|condition| {
+					self.expr(body, &condition);
+					self.jump(Pcode::IfNotGoto)
+				});
+
+				body.jumps.push(Jumps {
+					breaks: to_end.into_iter().collect(),
+					continues: Some(vec![]),
+				});
+
+				self.stat(body, &stat_for.body());
+
+				let cont = self.pos();
+
+				for expr in stat_for.iter_exprs() {
+					self.stat_expr(body, &expr);
+				}
+
+				self.jump_to(Pcode::Goto, top);
+				self.end_jumps(body, cont);
+				body.scopes.pop();
+			}
+			ast::Statement::Switch(stat_switch) => {
+				self.expr(body, &stat_switch.expr());
+				let to_table = self.jump(Pcode::Goto);
+
+				body.jumps.push(Jumps::default());
+				body.switches.push(Switch::default());
+
+				self.stat(body, &stat_switch.body());
+
+				let to_end = self.jump(Pcode::Goto);
+				self.patch(to_table, self.pos());
+
+				let switch = body.switches.pop().unwrap();
+
+				for (value, address) in switch.cases {
+					self.op(Pcode::CaseGoto);
+					self.int(value);
+					self.int(address as i32);
+				}
+
+				self.op(Pcode::Drop);
+
+				match switch.default {
+					Some(address) => self.jump_to(Pcode::Goto, address),
+					None => body
+						.jumps
+						.last_mut()
+						.unwrap()
+						.breaks
+						.push(self.jump(Pcode::Goto)),
+				}
+
+				body.jumps.last_mut().unwrap().breaks.push(to_end);
+				self.end_jumps(body, 0);
+			}
+			ast::Statement::Case(case) => {
+				let value = self.eval(&case.expr());
+				let address = self.pos();
+
+				let Some(switch) = body.switches.last_mut() else {
+					self.error(range, "`case` outside of a `switch`");
+					return;
+				};
+
+				let Some(value) = value else {
+					return;
+				};
+
+				if switch.cases.iter().any(|&(v, _)| v == value) {
+					self.error(range, format!("duplicate `case {value}`"));
+				} else {
+					switch.cases.push((value, address));
+				}
+			}
+			ast::Statement::CaseDefault(_) => {
+				let address = self.pos();
+
+				let Some(switch) = body.switches.last_mut() else {
+					self.error(range, "`default` outside of a `switch`");
+					return;
+				};
+
+				if switch.default.is_some() {
+					self.error(range, "duplicate `default`");
+				} else {
+					switch.default = Some(address);
+				}
+			}
+			ast::Statement::Break(_) => {
+				if body.jumps.is_empty() {
+					self.error(range, "`break` outside of a loop or `switch`");
+					return;
+				}
+
+				let at = self.jump(Pcode::Goto);
+				body.jumps.last_mut().unwrap().breaks.push(at);
+			}
+			ast::Statement::Continue(_) => {
+				if !body.jumps.iter().any(|j| j.continues.is_some()) {
+					self.error(range, "`continue` outside of a loop");
+					return;
+				}
+
+				let at = self.jump(Pcode::Goto);
+
+				body.jumps
+					.iter_mut()
+					.rev()
+					.find_map(|j| j.continues.as_mut())
+					.unwrap()
+					.push(at);
+			}
+			ast::Statement::Return(ret) => match (body.func, ret.expr()) {
+				(None, _) => self.error(
+					range,
+					"`return` can only be used in functions; use `terminate` in scripts",
+				),
+				(Some(true), Some(expr)) => {
+					self.expr(body, &expr);
+					self.op(Pcode::ReturnVal);
+				}
+				(Some(true), None) => self.error(range, "this function must return a value"),
+				(Some(false), Some(expr)) => self.error(
+					expr.syntax().text_range(),
+					"`void` functions cannot return a value",
+				),
+				(Some(false), None) => self.op(Pcode::ReturnVoid),
+			},
+			ast::Statement::Restart(_) => {
+				if body.func.is_some() {
+					self.error(range, "`restart` can only be used in scripts");
+				}
+
+				self.op(Pcode::Restart);
+			}
+			ast::Statement::Suspend(_) => {
+				if body.func.is_some() {
+					self.error(range, "`suspend` can only be used in scripts");
+				}
+
+				self.op(Pcode::Suspend);
+			}
+			ast::Statement::Terminate(_) => self.op(Pcode::Terminate),
+		}
+	}
+
+	/// `while` and `until` loops.
+	fn stat_loop(
+		&mut self,
+		body: &mut Body,
+		condition: &ast::Expression,
+		loop_body: &ast::Statement,
+		until: bool,
+	) {
+		let top = self.pos();
+		self.expr(body, condition);
+
+		let to_end = self.jump(if until {
+			Pcode::IfGoto
+		} else {
+			Pcode::IfNotGoto
+		});
+
+		body.jumps.push(Jumps {
+			breaks: vec![to_end],
+			continues: Some(vec![]),
+		});
+
+		self.stat(body, loop_body);
+		self.jump_to(Pcode::Goto, top);
+		self.end_jumps(body, top);
+	}
+
+	/// Pops the innermost set of pending jumps, pointing breaks to the
+	/// current position and continues to `cont`.
+	fn end_jumps(&mut self, body: &mut Body, cont: u32) {
+		let jumps = body.jumps.pop().unwrap();
+		let end = self.pos();
+
+		for at in jumps.breaks {
+			self.patch(at, end);
+		}
+
+		for at in jumps.continues.unwrap_or_default() {
+			self.patch(at, cont);
+		}
+	}
+
+	fn local_var(&mut self, body: &mut Body, var_name: &ast::VarName) {
+		if let Some(index) = var_name.index() {
+			self.error(
+				index.text_range(),
+				"only `world` and `global` variables can be given an index",
+			);
+		}
+
+		let init = var_name.init();
+		let dims = self.array_dims(var_name, init.as_ref());
+		let var = self.declare_local(body, &var_name.ident(), &dims);
+
+		let Some(init) = init else {
+			return;
+		};
+
+		if dims.is_empty() {
+			match init {
+				ast::Initializer::Expr(expr) => {
+					self.expr(body, &expr);
+					self.var_op(VarOp::Assign, &var);
+				}
+				ast::Initializer::List(list) => self.error(
+					list.syntax().text_range(),
+					"only arrays can be initialized with a list",
+				),
+			}
+
+			return;
+		}
+
+		for (pos, expr) in self.flatten_init(&init, &dims) {
+			self.push(pos as i32);
+			self.expr(body, &expr);
+			self.var_op(VarOp::Assign, &var);
+		}
+	}
+}
@@ -0,0 +1,481 @@
+//! ACC's "internal functions", which compile to dedicated pcodes
+//! rather than calls to line specials or ZDoom's extension functions.
+
+use crate::acs::pcode::Pcode;
+
+#[derive(Debug)]
+pub(super) struct Internal {
+	pub(super) name: &'static str,
+	pub(super) pcode: Pcode,
+	pub(super) min_args: usize,
+	/// Omitted optional arguments are passed as zero.
+	pub(super) max_args: usize,
+	pub(super) returns: bool,
+	/// The variant of [`Self::pcode`] taking its arguments as immediates,
+	/// for calls with `const:`.
+	pub(super) direct: Option<Pcode>,
+}
+
+impl Internal {
+	const fn new(
+		name: &'static str,
+		pcode: Pcode,
+		min_args: usize,
+		max_args: usize,
+		returns: bool,
+		direct: Option<Pcode>,
+	) -> Self {
+		Self {
+			name,
+			pcode,
+			min_args,
+			max_args,
+			returns,
+			direct,
+		}
+	}
+}
+
+/// Case-insensitive.
+#[must_use]
+pub(super) fn find(name: &str) -> Option<&'static Internal> {
+	INTERNALS
+		.iter()
+		.find(|internal| internal.name.eq_ignore_ascii_case(name))
+}
+
+/// The function which compiles to `pcode`, and whether `pcode` is the
+/// variant taking immediates.
+#[must_use]
+pub(super) fn by_pcode(pcode: Pcode) -> Option<(&'static Internal, bool)> {
+	INTERNALS.iter().find_map(|internal| {
+		if internal.pcode == pcode {
+			Some((internal, false))
+		} else if internal.direct == Some(pcode) {
+			Some((internal, true))
+		} else {
+			None
+		}
+	})
+}
+
+const INTERNALS: &[Internal] = &[
+	Internal::new("Delay", Pcode::Delay, 1, 1, false, Some(Pcode::DelayDirect)),
+	Internal::new(
+		"Random",
+		Pcode::Random,
+		2,
+		2,
+		true,
+		Some(Pcode::RandomDirect),
+	),
+	Internal::new(
+		"ThingCount",
+		Pcode::ThingCount,
+		1,
+		2,
+		true,
+		Some(Pcode::ThingCountDirect),
+	),
+	Internal::new(
+		"TagWait",
+		Pcode::TagWait,
+		1,
+		1,
+		false,
+		Some(Pcode::TagWaitDirect),
+	),
+	Internal::new(
+		"PolyWait",
+		Pcode::PolyWait,
+		1,
+		1,
+		false,
+		Some(Pcode::PolyWaitDirect),
+	),
+	Internal::new(
+		"ChangeFloor",
+		Pcode::ChangeFloor,
+		2,
+		2,
+		false,
+		Some(Pcode::ChangeFloorDirect),
+	),
+	Internal::new(
+		"ChangeCeiling",
+		Pcode::ChangeCeiling,
+		2,
+		2,
+		false,
+		Some(Pcode::ChangeCeilingDirect),
+	),
+	Internal::new("LineSide", Pcode::LineSide, 0, 0, true, None),
+	Internal::new(
+		"ScriptWait",
+		Pcode::ScriptWait,
+		1,
+		1,
+		false,
+		Some(Pcode::ScriptWaitDirect),
+	),
+	Internal::new(
+		"ClearLineSpecial",
+		Pcode::ClearLineSpecial,
+		0,
+		0,
+		false,
+		None,
+	),
+	Internal::new("PlayerCount", Pcode::PlayerCount, 0, 0, true, None),
+	Internal::new("GameType", Pcode::GameType, 0, 0, true, None),
+	Internal::new("GameSkill", Pcode::GameSkill, 0, 0, true, None),
+	Internal::new("Timer", Pcode::Timer, 0, 0, true, None),
+	Internal::new("SectorSound", Pcode::SectorSound, 2, 2, false, None),
+	Internal::new("AmbientSound", Pcode::AmbientSound, 2, 2, false, None),
+	Internal::new("SoundSequence", Pcode::SoundSequence, 1, 1, false, None),
+	Internal::new("SetLineTexture", Pcode::SetLineTexture, 4, 4, false, None),
+	Internal::new("SetLineBlocking", Pcode::SetLineBlocking, 2, 2, false, None),
+	Internal::new("SetLineSpecial", Pcode::SetLineSpecial, 2, 7, false, None),
+	Internal::new("ThingSound", Pcode::ThingSound, 3, 3, false, None),
+	Internal::new("ActivatorSound", Pcode::ActivatorSound, 2, 2, false, None),
+	Internal::new(
+		"LocalAmbientSound",
+		Pcode::LocalAmbientSound,
+		2,
+		2,
+		false,
+		None,
+	),
+	Internal::new(
+		"SetLineMonsterBlocking",
+		Pcode::SetLineMonsterBlocking,
+		2,
+		2,
+		false,
+		None,
+	),
+	Internal::new("IsNetworkGame", Pcode::IsNetworkGame, 0, 0, true, None),
+	Internal::new("PlayerTeam", Pcode::PlayerTeam, 0, 0, true, None),
+	Internal::new("PlayerHealth", Pcode::PlayerHealth, 0, 0, true, None),
+	Internal::new(
+		"PlayerArmorPoints",
+		Pcode::PlayerArmorPoints,
+		0,
+		0,
+		true,
+		None,
+	),
+	Internal::new("PlayerFrags", Pcode::PlayerFrags, 0, 0, true, None),
+	Internal::new("BlueTeamCount", Pcode::BlueTeamCount, 0, 0, true, None),
+	Internal::new("RedTeamCount", Pcode::RedTeamCount, 0, 0, true, None),
+	Internal::new("BlueTeamScore", Pcode::BlueTeamScore, 0, 0, true, None),
+	Internal::new("RedTeamScore", Pcode::RedTeamScore, 0, 0, true, None),
+	Internal::new("IsOneFlagCTF", Pcode::IsOneFlagCtf, 0, 0, true, None),
+	Internal::new("MusicChange", Pcode::MusicChange, 2, 2, false, None),
+	Internal::new(
+		"ConsoleCommand",
+		Pcode::ConsoleCommand,
+		1,
+		3,
+		false,
+		Some(Pcode::ConsoleCommandDirect),
+	),
+	Internal::new("SinglePlayer", Pcode::SinglePlayer, 0, 0, true, None),
+	Internal::new("FixedMul", Pcode::FixedMul, 2, 2, true, None),
+	Internal::new("FixedDiv", Pcode::FixedDiv, 2, 2, true, None),
+	Internal::new(
+		"SetGravity",
+		Pcode::SetGravity,
+		1,
+		1,
+		false,
+		Some(Pcode::SetGravityDirect),
+	),
+	Internal::new(
+		"SetAirControl",
+		Pcode::SetAirControl,
+		1,
+		1,
+		false,
+		Some(Pcode::SetAirControlDirect),
+	),
+	Internal::new("ClearInventory", Pcode::ClearInventory, 0, 0, false, None),
+	Internal::new(
+		"GiveInventory",
+		Pcode::GiveInventory,
+		2,
+		2,
+		false,
+		Some(Pcode::GiveInventoryDirect),
+	),
+	Internal::new(
+		"TakeInventory",
+		Pcode::TakeInventory,
+		2,
+		2,
+		false,
+		Some(Pcode::TakeInventoryDirect),
+	),
+	Internal::new(
+		"CheckInventory",
+		Pcode::CheckInventory,
+		1,
+		1,
+		true,
+		Some(Pcode::CheckInventoryDirect),
+	),
+	Internal::new("Spawn", Pcode::Spawn, 4, 6, true, Some(Pcode::SpawnDirect)),
+	Internal::new(
+		"SpawnSpot",
+		Pcode::SpawnSpot,
+		2,
+		4,
+		true,
+		Some(Pcode::SpawnSpotDirect),
+	),
+	Internal::new(
+		"SetMusic",
+		Pcode::SetMusic,
+		1,
+		3,
+		false,
+		Some(Pcode::SetMusicDirect),
+	),
+	Internal::new(
+		"LocalSetMusic",
+		Pcode::LocalSetMusic,
+		1,
+		3,
+		false,
+		Some(Pcode::LocalSetMusicDirect),
+	),
+	Internal::new(
+		"SetFont",
+		Pcode::SetFont,
+		1,
+		1,
+		false,
+		Some(Pcode::SetFontDirect),
+	),
+	Internal::new("SetThingSpecial", Pcode::SetThingSpecial, 2, 7, false, None),
+	Internal::new("FadeTo", Pcode::FadeTo, 5, 5, false, None),
+	Internal::new("FadeRange", Pcode::FadeRange, 9, 9, false, None),
+	Internal::new("CancelFade", Pcode::CancelFade, 0, 0, false, None),
+	Internal::new("PlayMovie", Pcode::PlayMovie, 1, 1, true, None),
+	Internal::new("SetFloorTrigger", Pcode::SetFloorTrigger, 3, 8, false, None),
+	Internal::new(
+		"SetCeilingTrigger",
+		Pcode::SetCeilingTrigger,
+		3,
+		8,
+		false,
+		None,
+	),
+	Internal::new("GetActorX", Pcode::GetActorX, 1, 1, true, None),
+	Internal::new("GetActorY", Pcode::GetActorY, 1, 1, true, None),
+	Internal::new("GetActorZ", Pcode::GetActorZ, 1, 1, true, None),
+	Internal::new("Sin", Pcode::Sin, 1, 1, true, None),
+	Internal::new("Cos", Pcode::Cos, 1, 1, true, None),
+	Internal::new("VectorAngle", Pcode::VectorAngle, 2, 2, true, None),
+	Internal::new("CheckWeapon", Pcode::CheckWeapon, 1, 1, true, None),
+	Internal::new("SetWeapon", Pcode::SetWeapon, 1, 1, true, None),
+	Internal::new("SetMarineWeapon", Pcode::SetMarineWeapon, 2, 2, false, None),
+	Internal::new(
+		"SetActorProperty",
+		Pcode::SetActorProperty,
+		3,
+		3,
+		false,
+		None,
+	),
+	Internal::new(
+		"GetActorProperty",
+		Pcode::GetActorProperty,
+		2,
+		2,
+		true,
+		None,
+	),
+	Internal::new("PlayerNumber", Pcode::PlayerNumber, 0, 0, true, None),
+	Internal::new("ActivatorTID", Pcode::ActivatorTid, 0, 0, true, None),
+	Internal::new("SetMarineSprite", Pcode::SetMarineSprite, 2, 2, false, None),
+	Internal::new("GetScreenWidth", Pcode::GetScreenWidth, 0, 0, true, None),
+	Internal::new("GetScreenHeight", Pcode::GetScreenHeight, 0, 0, true, None),
+	Internal::new(
+		"Thing_Projectile2",
+		Pcode::ThingProjectile2,
+		7,
+		7,
+		false,
+		None,
+	),
+	Internal::new("StrLen", Pcode::StrLen, 1, 1, true, None),
+	Internal::new("SetHudSize", Pcode::SetHudSize, 3, 3, false, None),
+	Internal::new("GetCVar", Pcode::GetCvar, 1, 1, true, None),
+	Internal::new("SetResultValue", Pcode::SetResultValue, 1, 1, false, None),
+	Internal::new(
+		"GetLineRowOffset",
+		Pcode::GetLineRowOffset,
+		0,
+		0,
+		true,
+		None,
+	),
+	Internal::new("GetActorFloorZ", Pcode::GetActorFloorZ, 1, 1, true, None),
+	Internal::new("GetActorAngle", Pcode::GetActorAngle, 1, 1, true, None),
+	Internal::new("GetSectorFloorZ", Pcode::GetSectorFloorZ, 3, 3, true, None),
+	Internal::new(
+		"GetSectorCeilingZ",
+		Pcode::GetSectorCeilingZ,
+		3,
+		3,
+		true,
+		None,
+	),
+	Internal::new("GetSigilPieces", Pcode::GetSigilPieces, 0, 0, true, None),
+	Internal::new("GetLevelInfo", Pcode::GetLevelInfo, 1, 1, true, None),
+	Internal::new("ChangeSky", Pcode::ChangeSky, 2, 2, false, None),
+	Internal::new("PlayerInGame", Pcode::PlayerInGame, 1, 1, true, None),
+	Internal::new("PlayerIsBot", Pcode::PlayerIsBot, 1, 1, true, None),
+	Internal::new(
+		"SetCameraToTexture",
+		Pcode::SetCameraToTexture,
+		3,
+		3,
+		false,
+		None,
+	),
+	Internal::new("GetAmmoCapacity", Pcode::GetAmmoCapacity, 1, 1, true, None),
+	Internal::new("SetAmmoCapacity", Pcode::SetAmmoCapacity, 2, 2, false, None),
+	Internal::new("SetActorAngle", Pcode::SetActorAngle, 2, 2, false, None),
+	Internal::new("SpawnProjectile", Pcode::SpawnProjectile, 7, 7, false, None),
+	Internal::new(
+		"GetSectorLightLevel",
+		Pcode::GetSectorLightLevel,
+		1,
+		1,
+		true,
+		None,
+	),
+	Internal::new(
+		"GetActorCeilingZ",
+		Pcode::GetActorCeilingZ,
+		1,
+		1,
+		true,
+		None,
+	),
+	Internal::new(
+		"SetActorPosition",
+		Pcode::SetActorPosition,
+		5,
+		5,
+		true,
+		None,
+	),
+	Internal::new(
+		"ClearActorInventory",
+		Pcode::ClearActorInventory,
+		1,
+		1,
+		false,
+		None,
+	),
+	Internal::new(
+		"GiveActorInventory",
+		Pcode::GiveActorInventory,
+		3,
+		3,
+		false,
+		None,
+	),
+	Internal::new(
+		"TakeActorInventory",
+		Pcode::TakeActorInventory,
+		3,
+		3,
+		false,
+		None,
+	),
+	Internal::new(
+		"CheckActorInventory",
+		Pcode::CheckActorInventory,
+		2,
+		2,
+		true,
+		None,
+	),
+	Internal::new("ThingCountName", Pcode::ThingCountName, 2, 2, true, None),
+	Internal::new("SpawnSpotFacing", Pcode::SpawnSpotFacing, 2, 3, true, None),
+	Internal::new("PlayerClass", Pcode::PlayerClass, 1, 1, true, None),
+	Internal::new("GetPlayerInfo", Pcode::GetPlayerInfo, 2, 2, true, None),
+	Internal::new("ChangeLevel", Pcode::ChangeLevel, 2, 4, false, None),
+	Internal::new("SectorDamage", Pcode::SectorDamage, 5, 5, false, None),
+	Internal::new("ReplaceTextures", Pcode::ReplaceTextures, 2, 3, false, None),
+	Internal::new("GetActorPitch", Pcode::GetActorPitch, 1, 1, true, None),
+	Internal::new("SetActorPitch", Pcode::SetActorPitch, 2, 2, false, None),
+	Internal::new("SetActorState", Pcode::SetActorState, 2, 3, true, None),
+	Internal::new("Thing_Damage2", Pcode::ThingDamage2, 3, 3, true, None),
+	Internal::new("UseInventory", Pcode::UseInventory, 1, 1, true, None),
+	Internal::new(
+		"UseActorInventory",
+		Pcode::UseActorInventory,
+		2,
+		2,
+		true,
+		None,
+	),
+	Internal::new(
+		"CheckActorCeilingTexture",
+		Pcode::CheckActorCeilingTexture,
+		2,
+		2,
+		true,
+		None,
+	),
+	Internal::new(
+		"CheckActorFloorTexture",
+		Pcode::CheckActorFloorTexture,
+		2,
+		2,
+		true,
+		None,
+	),
+	Internal::new(
+		"GetActorLightLevel",
+		Pcode::GetActorLightLevel,
+		1,
+		1,
+		true,
+		None,
+	),
+	Internal::new("SetMugShotState", Pcode::SetMugShotState, 1, 1, false, None),
+	Internal::new(
+		"ThingCountSector",
+		Pcode::ThingCountSector,
+		3,
+		3,
+		true,
+		None,
+	),
+	Internal::new(
+		"ThingCountNameSector",
+		Pcode::ThingCountNameSector,
+		3,
+		3,
+		true,
+		None,
+	),
+	Internal::new(
+		"CheckPlayerCamera",
+		Pcode::CheckPlayerCamera,
+		1,
+		1,
+		true,
+		None,
+	),
+	Internal::new("MorphActor", Pcode::MorphActor, 1, 7, true, None),
+	Internal::new("UnMorphActor", Pcode::UnMorphActor, 1, 2, true, None),
+	Internal::new("GetPlayerInput", Pcode::GetPlayerInput, 2, 2, true, None),
+	Internal::new("ClassifyActor", Pcode::ClassifyActor, 1, 1, true, None),
+];
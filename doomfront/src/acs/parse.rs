@@ -0,0 +1,215 @@
+mod expr;
+mod stat;
+mod top;
+
+use chumsky::{primitive, recovery, text, Error, Parser};
+use rowan::{ast::AstNode, GreenNode, SyntaxKind};
+
+use crate::{comb, ext::ParserVec, help, ParseError, ParseOut};
+
+use super::{ast, IncludeTree, RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), top::item(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then tries
+/// to skip ahead to the next semicolon, carriage return, or newline. This is
+/// done at the granularity of top-level definitions and statements, so one
+/// broken line does not hide the rest of its script. All input between the
+/// error location and the next valid thing gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		top::item(source).recover_with(recovery::skip_parser(recover_top(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Parses `root` (typically a map's or library's main script) with
+/// [`parse_recov`], and then every file it includes or imports. `fetch`
+/// retrieves a file's source given a path as written in an `#include` or
+/// `#import` directive; see [`IncludeTree::new`] for details.
+///
+/// Returns `None` if `fetch` fails to retrieve `root`.
+///
+/// [`IncludeTree::new`]: crate::repr::IncludeTree::new
+#[must_use]
+pub fn parse_include_tree<F>(root: &str, fetch: F) -> Option<IncludeTree>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	IncludeTree::new(
+		root,
+		fetch,
+		|source| {
+			parse_recov(source)
+				.unwrap_or_else(|| RawParseTree::new(GreenNode::new(Syn::Root.into(), []), vec![]))
+		},
+		|zipper| {
+			zipper
+				.children()
+				.filter_map(ast::IncludeDirective::cast)
+				.map(|directive| (directive.path(), directive.syntax().text_range()))
+				.collect()
+		},
+	)
+}
+
+// Common combinators /////////////////////////////////////////////////////////
+
+fn node(syn: Syn, elems: Vec<ParseOut>) -> ParseOut {
+	ParseOut::Node(GreenNode::new(SyntaxKind::from(syn), elems))
+}
+
+/// Keywords which can never be used as identifiers.
+/// Script types (e.g. `open`, `death`) are only keywords after `script`.
+const RESERVED: &[&str] = &[
+	"bool",
+	"break",
+	"case",
+	"const",
+	"continue",
+	"default",
+	"do",
+	"else",
+	"for",
+	"function",
+	"global",
+	"if",
+	"int",
+	"restart",
+	"return",
+	"script",
+	"special",
+	"str",
+	"suspend",
+	"switch",
+	"terminate",
+	"until",
+	"void",
+	"while",
+	"world",
+];
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Wraps the output of `parser` in a `Vec` if it succeeds, or returns an
+/// empty `Vec` otherwise. For optional sequences of syntax elements.
+fn opt<'s, P>(parser: P) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's,
+{
+	parser.or_not().map(Option::unwrap_or_default)
+}
+
+/// A case-insensitive keyword prefixed with `#`, e.g. `#include`.
+fn directive_kw<'i>(
+	src: &'i str,
+	string: &'static str,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	primitive::just('#')
+		.then(comb::just_nc(string))
+		.map_with_span(help::map_tok::<Syn, _>(src, syn))
+}
+
+/// A single- or multi-character glyph.
+fn glyph<'i>(
+	src: &'i str,
+	string: &'static str,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	comb::just::<Syn, _>(string, syn, src)
+}
+
+/// An identifier which is not one of the [reserved keywords](RESERVED).
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident()
+		.try_map(|s: String, span| {
+			if RESERVED.iter().any(|r| s.eq_ignore_ascii_case(r)) {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(())
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::c_int::<Syn>(src, Syn::LitInt).labelled("integer literal")
+}
+
+fn char_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('\'')
+		.then(
+			primitive::just('\\')
+				.then(primitive::any())
+				.ignored()
+				.or(primitive::none_of("\\'\r\n").ignored()),
+		)
+		.then(primitive::just('\''))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitChar))
+		.labelled("character literal")
+}
+
+/// `int`, `str`, `bool`, `fixed`, or `void`.
+fn type_kw(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::kw::<Syn>("int", Syn::KwInt, src),
+		comb::kw::<Syn>("str", Syn::KwStr, src),
+		comb::kw::<Syn>("bool", Syn::KwBool, src),
+		comb::kw::<Syn>("fixed", Syn::KwFixed, src),
+		comb::kw::<Syn>("void", Syn::KwVoid, src),
+	))
+	.labelled("type")
+}
+
+/// Skips input up to and including the next semicolon, or up to the next
+/// newline or closing brace, whichever comes first.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("};\r\n")
+		.repeated()
+		.at_least(1)
+		.then(primitive::just(';').or_not())
+		.ignored()
+		.or(primitive::just(';').ignored())
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
+
+/// Like [`recover`], but also skips stray closing braces.
+fn recover_top(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,283 @@
+//! Expression parsers, from primaries up through assignments.
+//!
+//! Precedence and associativity follow ACC's grammar, which is C's
+//! without the comma operator.
+
+use chumsky::{primitive, recursive::recursive, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	ParseError, ParseOut,
+};
+
+use super::{char_lit, glyph, ident, int_lit, node, opt, trivia, Syn};
+
+pub(super) fn expr(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive(|expr| {
+		let group = glyph(src, "(", Syn::ParenL)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ")", Syn::ParenR))
+			.collect_n::<Syn, { Syn::ExprGroup as u16 }>();
+
+		let primary = primitive::choice((
+			literal(src),
+			group,
+			ident(src).remap::<Syn, { Syn::ExprIdent as u16 }>(),
+		))
+		.labelled("expression")
+		.boxed();
+
+		let postfix = primary
+			.then(postfix_suffix(src, expr.clone()).repeated())
+			.foldl(|lhs, (syn, mut elems)| {
+				elems.insert(0, lhs);
+				node(syn, elems)
+			})
+			.boxed();
+
+		let prefix_op = primitive::choice((
+			glyph(src, "++", Syn::Plus2),
+			glyph(src, "--", Syn::Minus2),
+			glyph(src, "+", Syn::Plus),
+			glyph(src, "-", Syn::Minus),
+			glyph(src, "!", Syn::Bang),
+			glyph(src, "~", Syn::Tilde),
+		));
+
+		let prefix = prefix_op
+			.start_vec()
+			.chain_append(trivia(src))
+			.repeated()
+			.then(postfix)
+			.foldr(|mut elems, rhs| {
+				elems.push(rhs);
+				node(Syn::ExprPrefix, elems)
+			})
+			.boxed();
+
+		let mul = binary(
+			src,
+			prefix,
+			primitive::choice((
+				glyph(src, "*", Syn::Asterisk),
+				glyph(src, "/", Syn::Slash),
+				glyph(src, "%", Syn::Percent),
+			)),
+		);
+
+		let add = binary(
+			src,
+			mul,
+			primitive::choice((glyph(src, "+", Syn::Plus), glyph(src, "-", Syn::Minus))),
+		);
+
+		let shift = binary(
+			src,
+			add,
+			primitive::choice((
+				glyph(src, ">>", Syn::AngleR2),
+				glyph(src, "<<", Syn::AngleL2),
+			)),
+		);
+
+		let relational = binary(
+			src,
+			shift,
+			primitive::choice((
+				glyph(src, "<=", Syn::AngleLEq),
+				glyph(src, ">=", Syn::AngleREq),
+				glyph(src, "<", Syn::AngleL),
+				glyph(src, ">", Syn::AngleR),
+			)),
+		);
+
+		let equality = binary(
+			src,
+			relational,
+			primitive::choice((glyph(src, "==", Syn::Eq2), glyph(src, "!=", Syn::BangEq))),
+		);
+
+		let bit_and = binary(src, equality, glyph(src, "&", Syn::Ampersand));
+		let bit_xor = binary(src, bit_and, glyph(src, "^", Syn::Caret));
+		let bit_or = binary(src, bit_xor, glyph(src, "|", Syn::Pipe));
+		let log_and = binary(src, bit_or, glyph(src, "&&", Syn::Ampersand2));
+		let log_or = binary(src, log_and, glyph(src, "||", Syn::Pipe2));
+
+		let ternary = log_or
+			.then(opt(trivia(src)
+				.chain_push(glyph(src, "?", Syn::Question))
+				.chain_append(trivia(src))
+				.chain_push(expr.clone())
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, ":", Syn::Colon))
+				.chain_append(trivia(src))
+				.chain_push(expr.clone())))
+			.map(|(lhs, mut rest)| {
+				if rest.is_empty() {
+					lhs
+				} else {
+					rest.insert(0, lhs);
+					node(Syn::ExprTernary, rest)
+				}
+			})
+			.boxed();
+
+		let assign_op = primitive::choice((
+			glyph(src, "=", Syn::Eq),
+			glyph(src, "+=", Syn::PlusEq),
+			glyph(src, "-=", Syn::MinusEq),
+			glyph(src, "*=", Syn::AsteriskEq),
+			glyph(src, "/=", Syn::SlashEq),
+			glyph(src, "%=", Syn::PercentEq),
+			glyph(src, "<<=", Syn::AngleL2Eq),
+			glyph(src, ">>=", Syn::AngleR2Eq),
+			glyph(src, "|=", Syn::PipeEq),
+			glyph(src, "&=", Syn::AmpersandEq),
+			glyph(src, "^=", Syn::CaretEq),
+		));
+
+		ternary
+			.then(opt(trivia(src)
+				.chain_push(assign_op)
+				.chain_append(trivia(src))
+				.chain_push(expr)))
+			.map(|(lhs, mut rest)| {
+				if rest.is_empty() {
+					lhs
+				} else {
+					rest.insert(0, lhs);
+					node(Syn::ExprBinary, rest)
+				}
+			})
+			.boxed()
+	})
+}
+
+/// `(const: expr, expr)` or `(s: expr, d: expr; expr, expr)`.
+///
+/// ACC only accepts cast specifiers in the arguments to print-like functions,
+/// and `const:` only before the arguments to line specials, but that is left
+/// to semantic checks so that every call has the same shape.
+pub(super) fn arg_list<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	let arg = opt(ident(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.chain_append(trivia(src)))
+	.chain_push(expr)
+	.collect_n::<Syn, { Syn::Argument as u16 }>();
+
+	let separator =
+		primitive::choice((glyph(src, ",", Syn::Comma), glyph(src, ";", Syn::Semicolon)));
+
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(comb::kw::<Syn>("const", Syn::KwConst, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ":", Syn::Colon))
+			.chain_append(trivia(src))))
+		.chain_append(opt(arg
+			.clone()
+			.start_vec()
+			.chain_append(
+				trivia(src)
+					.chain_push(separator)
+					.chain_append(trivia(src))
+					.chain_push(arg)
+					.repeated()
+					.flatten(),
+			)
+			.chain_append(trivia(src))))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::ArgList as u16 }>()
+		.boxed()
+}
+
+pub(super) fn literal(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let strings = comb::string_lit::<Syn>(src, Syn::LitString)
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+				.repeated()
+				.flatten(),
+		);
+
+	primitive::choice((
+		comb::c_float::<Syn>(src, Syn::LitFixed)
+			.labelled("fixed-point literal")
+			.start_vec(),
+		int_lit(src).start_vec(),
+		strings,
+		char_lit(src).start_vec(),
+	))
+	.collect_n::<Syn, { Syn::Literal as u16 }>()
+	.boxed()
+}
+
+/// Call argument lists, index operators, and postfix increments/decrements.
+fn postfix_suffix<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, (Syn, Vec<ParseOut>), Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	primitive::choice((
+		trivia(src)
+			.chain_push(arg_list(src, expr.clone()))
+			.map(|elems| (Syn::ExprCall, elems)),
+		trivia(src)
+			.chain_push(glyph(src, "[", Syn::BracketL))
+			.chain_append(trivia(src))
+			.chain_push(expr)
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, "]", Syn::BracketR))
+			.map(|elems| (Syn::ExprIndex, elems)),
+		trivia(src)
+			.chain_push(primitive::choice((
+				glyph(src, "++", Syn::Plus2),
+				glyph(src, "--", Syn::Minus2),
+			)))
+			.map(|elems| (Syn::ExprPostfix, elems)),
+	))
+	.boxed()
+}
+
+/// A left-associative binary operator precedence level.
+fn binary<'s, P, O>(
+	src: &'s str,
+	operand: P,
+	op: O,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+	O: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	operand
+		.clone()
+		.then(
+			trivia(src)
+				.chain_push(op)
+				.chain_append(trivia(src))
+				.chain_push(operand)
+				.repeated(),
+		)
+		.foldl(|lhs, mut elems| {
+			elems.insert(0, lhs);
+			node(Syn::ExprBinary, elems)
+		})
+		.boxed()
+}
@@ -0,0 +1,229 @@
+//! Statement parsers.
+
+use chumsky::{primitive, recovery, recursive::recursive, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	ParseError, ParseOut,
+};
+
+use super::{glyph, node, opt, recover, top, trivia, type_kw, wsp_ext, Syn};
+
+pub(super) fn statement<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	recursive(|stat| {
+		let paren_expr = glyph(src, "(", Syn::ParenL)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ")", Syn::ParenR))
+			.boxed();
+
+		let stat_if = comb::kw::<Syn>("if", Syn::KwIf, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_append(paren_expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.chain_append(opt(trivia(src)
+				.chain_push(comb::kw::<Syn>("else", Syn::KwElse, src))
+				.chain_append(trivia(src))
+				.chain_push(stat.clone())))
+			.collect_n::<Syn, { Syn::StatIf as u16 }>();
+
+		let stat_while = comb::kw::<Syn>("while", Syn::KwWhile, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_append(paren_expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.collect_n::<Syn, { Syn::StatWhile as u16 }>();
+
+		let stat_until = comb::kw::<Syn>("until", Syn::KwUntil, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_append(paren_expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.collect_n::<Syn, { Syn::StatUntil as u16 }>();
+
+		let stat_do = comb::kw::<Syn>("do", Syn::KwDo, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.chain_append(trivia(src))
+			.chain_push(primitive::choice((
+				comb::kw::<Syn>("while", Syn::KwWhile, src),
+				comb::kw::<Syn>("until", Syn::KwUntil, src),
+			)))
+			.chain_append(trivia(src))
+			.chain_append(paren_expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.collect_n::<Syn, { Syn::StatDoWhile as u16 }>();
+
+		let expr_list = expr
+			.clone()
+			.start_vec()
+			.chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(expr.clone())
+					.repeated()
+					.flatten(),
+			)
+			.boxed();
+
+		let stat_for = comb::kw::<Syn>("for", Syn::KwFor, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, "(", Syn::ParenL))
+			.chain_append(trivia(src))
+			.chain_push(
+				opt(
+					primitive::choice((local_vars(src, expr.clone()), expr_list.clone()))
+						.chain_append(trivia(src)),
+				)
+				.collect_n::<Syn, { Syn::ForInit as u16 }>(),
+			)
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.chain_append(trivia(src))
+			.chain_push(
+				opt(expr.clone().start_vec().chain_append(trivia(src)))
+					.collect_n::<Syn, { Syn::ForCond as u16 }>(),
+			)
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.chain_append(trivia(src))
+			.chain_push(
+				opt(expr_list.chain_append(trivia(src)))
+					.collect_n::<Syn, { Syn::ForIter as u16 }>(),
+			)
+			.chain_push(glyph(src, ")", Syn::ParenR))
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.collect_n::<Syn, { Syn::StatFor as u16 }>();
+
+		let stat_switch = comb::kw::<Syn>("switch", Syn::KwSwitch, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_append(paren_expr)
+			.chain_append(trivia(src))
+			.chain_push(stat.clone())
+			.collect_n::<Syn, { Syn::StatSwitch as u16 }>();
+
+		let stat_case = comb::kw::<Syn>("case", Syn::KwCase, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ":", Syn::Colon))
+			.collect_n::<Syn, { Syn::StatCase as u16 }>();
+
+		let stat_case_default = comb::kw::<Syn>("default", Syn::KwDefault, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ":", Syn::Colon))
+			.collect_n::<Syn, { Syn::StatCaseDefault as u16 }>();
+
+		let stat_return = comb::kw::<Syn>("return", Syn::KwReturn, src)
+			.start_vec()
+			.chain_append(opt(trivia(src).chain_push(expr.clone())))
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.collect_n::<Syn, { Syn::StatReturn as u16 }>();
+
+		let stat_local = local_vars(src, expr.clone())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.collect_n::<Syn, { Syn::StatLocalVar as u16 }>();
+
+		let stat_expr = expr
+			.clone()
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ";", Syn::Semicolon))
+			.collect_n::<Syn, { Syn::StatExpr as u16 }>();
+
+		primitive::choice((
+			compound(src, stat.clone()),
+			glyph(src, ";", Syn::Semicolon).remap::<Syn, { Syn::StatEmpty as u16 }>(),
+			stat_if,
+			stat_while,
+			stat_until,
+			stat_do,
+			stat_for,
+			stat_switch,
+			stat_case,
+			stat_case_default,
+			keyword_stat(src, "break", Syn::KwBreak, Syn::StatBreak),
+			keyword_stat(src, "continue", Syn::KwContinue, Syn::StatContinue),
+			keyword_stat(src, "restart", Syn::KwRestart, Syn::StatRestart),
+			keyword_stat(src, "suspend", Syn::KwSuspend, Syn::StatSuspend),
+			keyword_stat(src, "terminate", Syn::KwTerminate, Syn::StatTerminate),
+			stat_return,
+			stat_local,
+			stat_expr,
+		))
+		.labelled("statement")
+		.boxed()
+	})
+}
+
+/// `{ ... }`, with recovery at the granularity of each inner statement.
+pub(super) fn compound<'s, P>(
+	src: &'s str,
+	stat: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	glyph(src, "{", Syn::BraceL)
+		.start_vec()
+		.chain_append(
+			primitive::choice((
+				wsp_ext(src),
+				stat.recover_with(recovery::skip_parser(recover(src))),
+			))
+			.repeated(),
+		)
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.collect_n::<Syn, { Syn::StatCompound as u16 }>()
+		.boxed()
+}
+
+/// A statement consisting of only a keyword and a semicolon, e.g. `terminate;`.
+fn keyword_stat<'s>(
+	src: &'s str,
+	string: &'static str,
+	kw_syn: Syn,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's {
+	comb::kw::<Syn>(string, kw_syn, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.map(move |elems| node(syn, elems))
+}
+
+/// `type name = expr, name[len]`, not including a terminating semicolon.
+fn local_vars<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	type_kw(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(top::var_names(src, expr))
+		.boxed()
+}
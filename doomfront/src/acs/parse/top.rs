@@ -0,0 +1,296 @@
+//! Parsers for directives and top-level definitions.
+
+use chumsky::{primitive, recursive::recursive, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	ParseError, ParseOut,
+};
+
+use super::{directive_kw, expr::expr, glyph, ident, int_lit, opt, stat, trivia, type_kw, Syn};
+
+/// One of the top-level elements of a file.
+pub(super) fn item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		include_directive(src),
+		library_directive(src),
+		define_directive(src),
+		simple_directive(src),
+		script_def(src),
+		func_def(src),
+		special_decl(src),
+		var_decl(src),
+	))
+	.boxed()
+}
+
+/// `#include "path"` or `#import "path"`
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		directive_kw(src, "include", Syn::KwInclude),
+		directive_kw(src, "import", Syn::KwImport),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+	.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+}
+
+/// `#library "name"`
+fn library_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	directive_kw(src, "library", Syn::KwLibrary)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::LibraryDirective as u16 }>()
+}
+
+/// `#define NAME expr` or `#libdefine NAME expr`
+fn define_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		directive_kw(src, "define", Syn::KwDefine),
+		directive_kw(src, "libdefine", Syn::KwLibDefine),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(ident(src))
+	.chain_append(trivia(src))
+	.chain_push(expr(src))
+	.collect_n::<Syn, { Syn::DefineDirective as u16 }>()
+	.boxed()
+}
+
+/// `#nocompact`, `#wadauthor`, `#nowadauthor`, or `#encryptstrings`.
+fn simple_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		directive_kw(src, "nocompact", Syn::KwNoCompact),
+		directive_kw(src, "wadauthor", Syn::KwWadAuthor),
+		directive_kw(src, "nowadauthor", Syn::KwNoWadAuthor),
+		directive_kw(src, "encryptstrings", Syn::KwEncryptStrings),
+	))
+	.remap::<Syn, { Syn::SimpleDirective as u16 }>()
+}
+
+/// `script 1 (void) { ... }`, `script "Name" ENTER NET { ... }`, et cetera.
+fn script_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let number = primitive::choice((
+		int_lit(src).remap::<Syn, { Syn::Literal as u16 }>(),
+		comb::string_lit::<Syn>(src, Syn::LitString).remap::<Syn, { Syn::Literal as u16 }>(),
+		ident(src).remap::<Syn, { Syn::ExprIdent as u16 }>(),
+	))
+	.labelled("script number or name");
+
+	let script_kw = primitive::choice((
+		comb::kw::<Syn>("open", Syn::KwOpen, src),
+		comb::kw::<Syn>("enter", Syn::KwEnter, src),
+		comb::kw::<Syn>("return", Syn::KwReturn, src),
+		comb::kw::<Syn>("respawn", Syn::KwRespawn, src),
+		comb::kw::<Syn>("death", Syn::KwDeath, src),
+		comb::kw::<Syn>("lightning", Syn::KwLightning, src),
+		comb::kw::<Syn>("unloading", Syn::KwUnloading, src),
+		comb::kw::<Syn>("disconnect", Syn::KwDisconnect, src),
+		comb::kw::<Syn>("kill", Syn::KwKill, src),
+		comb::kw::<Syn>("reopen", Syn::KwReopen, src),
+		comb::kw::<Syn>("event", Syn::KwEvent, src),
+		comb::kw::<Syn>("pickup", Syn::KwPickup, src),
+		comb::kw::<Syn>("bluereturn", Syn::KwBlueReturn, src),
+		comb::kw::<Syn>("redreturn", Syn::KwRedReturn, src),
+		comb::kw::<Syn>("whitereturn", Syn::KwWhiteReturn, src),
+		comb::kw::<Syn>("net", Syn::KwNet, src),
+		comb::kw::<Syn>("clientside", Syn::KwClientSide, src),
+	));
+
+	comb::kw::<Syn>("script", Syn::KwScript, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(number)
+		.chain_append(opt(trivia(src).chain_push(param_list(src))))
+		.chain_append(trivia(src).chain_push(script_kw).repeated().flatten())
+		.chain_append(trivia(src))
+		.chain_push(body(src))
+		.collect_n::<Syn, { Syn::ScriptDef as u16 }>()
+		.boxed()
+}
+
+/// `function type name(params) { ... }`
+fn func_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("function", Syn::KwFunction, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(type_kw(src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(param_list(src))
+		.chain_append(trivia(src))
+		.chain_push(body(src))
+		.collect_n::<Syn, { Syn::FuncDef as u16 }>()
+		.boxed()
+}
+
+/// `special 80:ACS_Execute(2, 5), -1:GetLineUDMFInt(2);`
+fn special_decl(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let def = opt(glyph(src, "-", Syn::Minus)
+		.start_vec()
+		.chain_append(trivia(src)))
+	.chain_push(int_lit(src))
+	.chain_append(trivia(src))
+	.chain_push(glyph(src, ":", Syn::Colon))
+	.chain_append(trivia(src))
+	.chain_push(ident(src))
+	.chain_append(trivia(src))
+	.chain_push(glyph(src, "(", Syn::ParenL))
+	.chain_append(trivia(src))
+	.chain_push(int_lit(src))
+	.chain_append(opt(trivia(src)
+		.chain_push(glyph(src, ",", Syn::Comma))
+		.chain_append(trivia(src))
+		.chain_push(int_lit(src))))
+	.chain_append(trivia(src))
+	.chain_push(glyph(src, ")", Syn::ParenR))
+	.collect_n::<Syn, { Syn::SpecialDef as u16 }>();
+
+	comb::kw::<Syn>("special", Syn::KwSpecial, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(def.clone())
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(def)
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::SpecialDecl as u16 }>()
+		.boxed()
+}
+
+/// `world int 1:name[];`, `global str 2:name[];`, or `int name = expr;`
+fn var_decl(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	opt(primitive::choice((
+		comb::kw::<Syn>("world", Syn::KwWorld, src),
+		comb::kw::<Syn>("global", Syn::KwGlobal, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src)))
+	.chain_push(type_kw(src))
+	.chain_append(trivia(src))
+	.chain_append(var_names(src, expr(src)))
+	.chain_append(trivia(src))
+	.chain_push(glyph(src, ";", Syn::Semicolon))
+	.collect_n::<Syn, { Syn::VarDecl as u16 }>()
+	.boxed()
+}
+
+/// One or more comma-separated [`Syn::VarName`] nodes.
+pub(super) fn var_names<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	let array_len = glyph(src, "[", Syn::BracketL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(expr.clone().start_vec().chain_append(trivia(src))))
+		.chain_push(glyph(src, "]", Syn::BracketR))
+		.collect_n::<Syn, { Syn::ArrayLen as u16 }>();
+
+	let var_name = opt(int_lit(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.chain_append(trivia(src)))
+	.chain_push(ident(src))
+	.chain_append(trivia(src).chain_push(array_len).repeated().flatten())
+	.chain_append(opt(trivia(src)
+		.chain_push(glyph(src, "=", Syn::Eq))
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((init_list(src, expr.clone()), expr)))))
+	.collect_n::<Syn, { Syn::VarName as u16 }>();
+
+	var_name
+		.clone()
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(var_name)
+				.repeated()
+				.flatten(),
+		)
+		.boxed()
+}
+
+/// `{ expr, { expr, expr }, ... }`, nested for multi-dimensional arrays.
+fn init_list<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	recursive(move |init_list| {
+		let elem = primitive::choice((init_list, expr.clone()));
+
+		glyph(src, "{", Syn::BraceL)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_append(opt(elem
+				.clone()
+				.start_vec()
+				.chain_append(
+					trivia(src)
+						.chain_push(glyph(src, ",", Syn::Comma))
+						.chain_append(trivia(src))
+						.chain_push(elem)
+						.repeated()
+						.flatten(),
+				)
+				.chain_append(opt(trivia(src).chain_push(glyph(src, ",", Syn::Comma))))
+				.chain_append(trivia(src))))
+			.chain_push(glyph(src, "}", Syn::BraceR))
+			.collect_n::<Syn, { Syn::InitList as u16 }>()
+			.boxed()
+	})
+}
+
+/// `(void)` or `(type name, type name, ...)`
+fn param_list(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let param = type_kw(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.collect_n::<Syn, { Syn::Parameter as u16 }>();
+
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(primitive::choice((
+			param.clone().start_vec().chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(param)
+					.repeated()
+					.flatten(),
+			),
+			comb::kw::<Syn>("void", Syn::KwVoid, src).start_vec(),
+		))
+		.chain_append(trivia(src))))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::ParamList as u16 }>()
+		.boxed()
+}
+
+/// The compound statement forming a script's or function's body.
+fn body(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let expr = expr(src);
+	stat::compound(src, stat::statement(src, expr))
+}
@@ -0,0 +1,676 @@
+//! The instruction set of ZDoom's ACS virtual machine.
+
+/// The arguments encoded in an instruction stream after a [`Pcode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operands {
+	None,
+	/// This many 32-bit integers.
+	Ints(u8),
+	/// A 32-bit address to jump to.
+	Jump,
+	/// A 32-bit value to compare against, and a 32-bit address to jump to.
+	Case,
+	/// A count (aligned to 4 bytes), followed by that many value-address pairs,
+	/// sorted by value.
+	SortedCases,
+	/// A line special number, variable index, or function index.
+	/// One byte in compact objects, and 32 bits otherwise.
+	Index,
+	/// An [`Operands::Index`] followed by this many 32-bit integers.
+	IndexInts(u8),
+	/// This many bytes.
+	Bytes(u8),
+	/// A one-byte count followed by that many bytes.
+	ByteList,
+	/// An argument count and a function index. A byte and 16 bits
+	/// respectively in compact objects, and 32 bits each otherwise.
+	CallFunc,
+}
+
+macro_rules! pcodes {
+	($($variant:ident = $name:literal),+ $(,)?) => {
+		/// Numbered as in ZDoom's `p_acs.h`; see [`Pcode::name`] for the
+		/// names ACC and ZDoom use.
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		#[repr(u16)]
+		pub enum Pcode {
+			$($variant),+
+		}
+
+		impl Pcode {
+			const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+			/// In uppercase and without the `PCD_` prefix, e.g. `PUSHNUMBER`.
+			#[must_use]
+			pub fn name(self) -> &'static str {
+				match self {
+					$(Self::$variant => $name),+
+				}
+			}
+		}
+	};
+}
+
+pcodes! {
+Nop = "NOP",
+Terminate = "TERMINATE",
+Suspend = "SUSPEND",
+PushNumber = "PUSHNUMBER",
+LSpec1 = "LSPEC1",
+LSpec2 = "LSPEC2",
+LSpec3 = "LSPEC3",
+LSpec4 = "LSPEC4",
+LSpec5 = "LSPEC5",
+LSpec1Direct = "LSPEC1DIRECT",
+LSpec2Direct = "LSPEC2DIRECT",
+LSpec3Direct = "LSPEC3DIRECT",
+LSpec4Direct = "LSPEC4DIRECT",
+LSpec5Direct = "LSPEC5DIRECT",
+Add = "ADD",
+Subtract = "SUBTRACT",
+Multiply = "MULTIPLY",
+Divide = "DIVIDE",
+Modulus = "MODULUS",
+Eq = "EQ",
+Ne = "NE",
+Lt = "LT",
+Gt = "GT",
+Le = "LE",
+Ge = "GE",
+AssignScriptVar = "ASSIGNSCRIPTVAR",
+AssignMapVar = "ASSIGNMAPVAR",
+AssignWorldVar = "ASSIGNWORLDVAR",
+PushScriptVar = "PUSHSCRIPTVAR",
+PushMapVar = "PUSHMAPVAR",
+PushWorldVar = "PUSHWORLDVAR",
+AddScriptVar = "ADDSCRIPTVAR",
+AddMapVar = "ADDMAPVAR",
+AddWorldVar = "ADDWORLDVAR",
+SubScriptVar = "SUBSCRIPTVAR",
+SubMapVar = "SUBMAPVAR",
+SubWorldVar = "SUBWORLDVAR",
+MulScriptVar = "MULSCRIPTVAR",
+MulMapVar = "MULMAPVAR",
+MulWorldVar = "MULWORLDVAR",
+DivScriptVar = "DIVSCRIPTVAR",
+DivMapVar = "DIVMAPVAR",
+DivWorldVar = "DIVWORLDVAR",
+ModScriptVar = "MODSCRIPTVAR",
+ModMapVar = "MODMAPVAR",
+ModWorldVar = "MODWORLDVAR",
+IncScriptVar = "INCSCRIPTVAR",
+IncMapVar = "INCMAPVAR",
+IncWorldVar = "INCWORLDVAR",
+DecScriptVar = "DECSCRIPTVAR",
+DecMapVar = "DECMAPVAR",
+DecWorldVar = "DECWORLDVAR",
+Goto = "GOTO",
+IfGoto = "IFGOTO",
+Drop = "DROP",
+Delay = "DELAY",
+DelayDirect = "DELAYDIRECT",
+Random = "RANDOM",
+RandomDirect = "RANDOMDIRECT",
+ThingCount = "THINGCOUNT",
+ThingCountDirect = "THINGCOUNTDIRECT",
+TagWait = "TAGWAIT",
+TagWaitDirect = "TAGWAITDIRECT",
+PolyWait = "POLYWAIT",
+PolyWaitDirect = "POLYWAITDIRECT",
+ChangeFloor = "CHANGEFLOOR",
+ChangeFloorDirect = "CHANGEFLOORDIRECT",
+ChangeCeiling = "CHANGECEILING",
+ChangeCeilingDirect = "CHANGECEILINGDIRECT",
+Restart = "RESTART",
+AndLogical = "ANDLOGICAL",
+OrLogical = "ORLOGICAL",
+AndBitwise = "ANDBITWISE",
+OrBitwise = "ORBITWISE",
+EorBitwise = "EORBITWISE",
+NegateLogical = "NEGATELOGICAL",
+LShift = "LSHIFT",
+RShift = "RSHIFT",
+UnaryMinus = "UNARYMINUS",
+IfNotGoto = "IFNOTGOTO",
+LineSide = "LINESIDE",
+ScriptWait = "SCRIPTWAIT",
+ScriptWaitDirect = "SCRIPTWAITDIRECT",
+ClearLineSpecial = "CLEARLINESPECIAL",
+CaseGoto = "CASEGOTO",
+BeginPrint = "BEGINPRINT",
+EndPrint = "ENDPRINT",
+PrintString = "PRINTSTRING",
+PrintNumber = "PRINTNUMBER",
+PrintCharacter = "PRINTCHARACTER",
+PlayerCount = "PLAYERCOUNT",
+GameType = "GAMETYPE",
+GameSkill = "GAMESKILL",
+Timer = "TIMER",
+SectorSound = "SECTORSOUND",
+AmbientSound = "AMBIENTSOUND",
+SoundSequence = "SOUNDSEQUENCE",
+SetLineTexture = "SETLINETEXTURE",
+SetLineBlocking = "SETLINEBLOCKING",
+SetLineSpecial = "SETLINESPECIAL",
+ThingSound = "THINGSOUND",
+EndPrintBold = "ENDPRINTBOLD",
+ActivatorSound = "ACTIVATORSOUND",
+LocalAmbientSound = "LOCALAMBIENTSOUND",
+SetLineMonsterBlocking = "SETLINEMONSTERBLOCKING",
+PlayerBlueSkull = "PLAYERBLUESKULL",
+PlayerRedSkull = "PLAYERREDSKULL",
+PlayerYellowSkull = "PLAYERYELLOWSKULL",
+PlayerMasterSkull = "PLAYERMASTERSKULL",
+PlayerBlueCard = "PLAYERBLUECARD",
+PlayerRedCard = "PLAYERREDCARD",
+PlayerYellowCard = "PLAYERYELLOWCARD",
+PlayerMasterCard = "PLAYERMASTERCARD",
+PlayerBlackSkull = "PLAYERBLACKSKULL",
+PlayerSilverSkull = "PLAYERSILVERSKULL",
+PlayerGoldSkull = "PLAYERGOLDSKULL",
+PlayerBlackCard = "PLAYERBLACKCARD",
+PlayerSilverCard = "PLAYERSILVERCARD",
+IsNetworkGame = "ISNETWORKGAME",
+PlayerTeam = "PLAYERTEAM",
+PlayerHealth = "PLAYERHEALTH",
+PlayerArmorPoints = "PLAYERARMORPOINTS",
+PlayerFrags = "PLAYERFRAGS",
+PlayerExpert = "PLAYEREXPERT",
+BlueTeamCount = "BLUETEAMCOUNT",
+RedTeamCount = "REDTEAMCOUNT",
+BlueTeamScore = "BLUETEAMSCORE",
+RedTeamScore = "REDTEAMSCORE",
+IsOneFlagCtf = "ISONEFLAGCTF",
+LSpec6 = "LSPEC6",
+LSpec6Direct = "LSPEC6DIRECT",
+PrintName = "PRINTNAME",
+MusicChange = "MUSICCHANGE",
+ConsoleCommandDirect = "CONSOLECOMMANDDIRECT",
+ConsoleCommand = "CONSOLECOMMAND",
+SinglePlayer = "SINGLEPLAYER",
+FixedMul = "FIXEDMUL",
+FixedDiv = "FIXEDDIV",
+SetGravity = "SETGRAVITY",
+SetGravityDirect = "SETGRAVITYDIRECT",
+SetAirControl = "SETAIRCONTROL",
+SetAirControlDirect = "SETAIRCONTROLDIRECT",
+ClearInventory = "CLEARINVENTORY",
+GiveInventory = "GIVEINVENTORY",
+GiveInventoryDirect = "GIVEINVENTORYDIRECT",
+TakeInventory = "TAKEINVENTORY",
+TakeInventoryDirect = "TAKEINVENTORYDIRECT",
+CheckInventory = "CHECKINVENTORY",
+CheckInventoryDirect = "CHECKINVENTORYDIRECT",
+Spawn = "SPAWN",
+SpawnDirect = "SPAWNDIRECT",
+SpawnSpot = "SPAWNSPOT",
+SpawnSpotDirect = "SPAWNSPOTDIRECT",
+SetMusic = "SETMUSIC",
+SetMusicDirect = "SETMUSICDIRECT",
+LocalSetMusic = "LOCALSETMUSIC",
+LocalSetMusicDirect = "LOCALSETMUSICDIRECT",
+PrintFixed = "PRINTFIXED",
+PrintLocalized = "PRINTLOCALIZED",
+MoreHudMessage = "MOREHUDMESSAGE",
+OptHudMessage = "OPTHUDMESSAGE",
+EndHudMessage = "ENDHUDMESSAGE",
+EndHudMessageBold = "ENDHUDMESSAGEBOLD",
+SetStyle = "SETSTYLE",
+SetStyleDirect = "SETSTYLEDIRECT",
+SetFont = "SETFONT",
+SetFontDirect = "SETFONTDIRECT",
+PushByte = "PUSHBYTE",
+LSpec1DirectB = "LSPEC1DIRECTB",
+LSpec2DirectB = "LSPEC2DIRECTB",
+LSpec3DirectB = "LSPEC3DIRECTB",
+LSpec4DirectB = "LSPEC4DIRECTB",
+LSpec5DirectB = "LSPEC5DIRECTB",
+DelayDirectB = "DELAYDIRECTB",
+RandomDirectB = "RANDOMDIRECTB",
+PushBytes = "PUSHBYTES",
+Push2Bytes = "PUSH2BYTES",
+Push3Bytes = "PUSH3BYTES",
+Push4Bytes = "PUSH4BYTES",
+Push5Bytes = "PUSH5BYTES",
+SetThingSpecial = "SETTHINGSPECIAL",
+AssignGlobalVar = "ASSIGNGLOBALVAR",
+PushGlobalVar = "PUSHGLOBALVAR",
+AddGlobalVar = "ADDGLOBALVAR",
+SubGlobalVar = "SUBGLOBALVAR",
+MulGlobalVar = "MULGLOBALVAR",
+DivGlobalVar = "DIVGLOBALVAR",
+ModGlobalVar = "MODGLOBALVAR",
+IncGlobalVar = "INCGLOBALVAR",
+DecGlobalVar = "DECGLOBALVAR",
+FadeTo = "FADETO",
+FadeRange = "FADERANGE",
+CancelFade = "CANCELFADE",
+PlayMovie = "PLAYMOVIE",
+SetFloorTrigger = "SETFLOORTRIGGER",
+SetCeilingTrigger = "SETCEILINGTRIGGER",
+GetActorX = "GETACTORX",
+GetActorY = "GETACTORY",
+GetActorZ = "GETACTORZ",
+StartTranslation = "STARTTRANSLATION",
+TranslationRange1 = "TRANSLATIONRANGE1",
+TranslationRange2 = "TRANSLATIONRANGE2",
+EndTranslation = "ENDTRANSLATION",
+Call = "CALL",
+CallDiscard = "CALLDISCARD",
+ReturnVoid = "RETURNVOID",
+ReturnVal = "RETURNVAL",
+PushMapArray = "PUSHMAPARRAY",
+AssignMapArray = "ASSIGNMAPARRAY",
+AddMapArray = "ADDMAPARRAY",
+SubMapArray = "SUBMAPARRAY",
+MulMapArray = "MULMAPARRAY",
+DivMapArray = "DIVMAPARRAY",
+ModMapArray = "MODMAPARRAY",
+IncMapArray = "INCMAPARRAY",
+DecMapArray = "DECMAPARRAY",
+Dup = "DUP",
+Swap = "SWAP",
+WriteToIni = "WRITETOINI",
+GetFromIni = "GETFROMINI",
+Sin = "SIN",
+Cos = "COS",
+VectorAngle = "VECTORANGLE",
+CheckWeapon = "CHECKWEAPON",
+SetWeapon = "SETWEAPON",
+TagString = "TAGSTRING",
+PushWorldArray = "PUSHWORLDARRAY",
+AssignWorldArray = "ASSIGNWORLDARRAY",
+AddWorldArray = "ADDWORLDARRAY",
+SubWorldArray = "SUBWORLDARRAY",
+MulWorldArray = "MULWORLDARRAY",
+DivWorldArray = "DIVWORLDARRAY",
+ModWorldArray = "MODWORLDARRAY",
+IncWorldArray = "INCWORLDARRAY",
+DecWorldArray = "DECWORLDARRAY",
+PushGlobalArray = "PUSHGLOBALARRAY",
+AssignGlobalArray = "ASSIGNGLOBALARRAY",
+AddGlobalArray = "ADDGLOBALARRAY",
+SubGlobalArray = "SUBGLOBALARRAY",
+MulGlobalArray = "MULGLOBALARRAY",
+DivGlobalArray = "DIVGLOBALARRAY",
+ModGlobalArray = "MODGLOBALARRAY",
+IncGlobalArray = "INCGLOBALARRAY",
+DecGlobalArray = "DECGLOBALARRAY",
+SetMarineWeapon = "SETMARINEWEAPON",
+SetActorProperty = "SETACTORPROPERTY",
+GetActorProperty = "GETACTORPROPERTY",
+PlayerNumber = "PLAYERNUMBER",
+ActivatorTid = "ACTIVATORTID",
+SetMarineSprite = "SETMARINESPRITE",
+GetScreenWidth = "GETSCREENWIDTH",
+GetScreenHeight = "GETSCREENHEIGHT",
+ThingProjectile2 = "THING_PROJECTILE2",
+StrLen = "STRLEN",
+SetHudSize = "SETHUDSIZE",
+GetCvar = "GETCVAR",
+CaseGotoSorted = "CASEGOTOSORTED",
+SetResultValue = "SETRESULTVALUE",
+GetLineRowOffset = "GETLINEROWOFFSET",
+GetActorFloorZ = "GETACTORFLOORZ",
+GetActorAngle = "GETACTORANGLE",
+GetSectorFloorZ = "GETSECTORFLOORZ",
+GetSectorCeilingZ = "GETSECTORCEILINGZ",
+LSpec5Result = "LSPEC5RESULT",
+GetSigilPieces = "GETSIGILPIECES",
+GetLevelInfo = "GETLEVELINFO",
+ChangeSky = "CHANGESKY",
+PlayerInGame = "PLAYERINGAME",
+PlayerIsBot = "PLAYERISBOT",
+SetCameraToTexture = "SETCAMERATOTEXTURE",
+EndLog = "ENDLOG",
+GetAmmoCapacity = "GETAMMOCAPACITY",
+SetAmmoCapacity = "SETAMMOCAPACITY",
+PrintMapCharArray = "PRINTMAPCHARARRAY",
+PrintWorldCharArray = "PRINTWORLDCHARARRAY",
+PrintGlobalCharArray = "PRINTGLOBALCHARARRAY",
+SetActorAngle = "SETACTORANGLE",
+GrabInput = "GRABINPUT",
+SetMousePointer = "SETMOUSEPOINTER",
+MoveMousePointer = "MOVEMOUSEPOINTER",
+SpawnProjectile = "SPAWNPROJECTILE",
+GetSectorLightLevel = "GETSECTORLIGHTLEVEL",
+GetActorCeilingZ = "GETACTORCEILINGZ",
+SetActorPosition = "SETACTORPOSITION",
+ClearActorInventory = "CLEARACTORINVENTORY",
+GiveActorInventory = "GIVEACTORINVENTORY",
+TakeActorInventory = "TAKEACTORINVENTORY",
+CheckActorInventory = "CHECKACTORINVENTORY",
+ThingCountName = "THINGCOUNTNAME",
+SpawnSpotFacing = "SPAWNSPOTFACING",
+PlayerClass = "PLAYERCLASS",
+AndScriptVar = "ANDSCRIPTVAR",
+AndMapVar = "ANDMAPVAR",
+AndWorldVar = "ANDWORLDVAR",
+AndGlobalVar = "ANDGLOBALVAR",
+AndMapArray = "ANDMAPARRAY",
+AndWorldArray = "ANDWORLDARRAY",
+AndGlobalArray = "ANDGLOBALARRAY",
+EorScriptVar = "EORSCRIPTVAR",
+EorMapVar = "EORMAPVAR",
+EorWorldVar = "EORWORLDVAR",
+EorGlobalVar = "EORGLOBALVAR",
+EorMapArray = "EORMAPARRAY",
+EorWorldArray = "EORWORLDARRAY",
+EorGlobalArray = "EORGLOBALARRAY",
+OrScriptVar = "ORSCRIPTVAR",
+OrMapVar = "ORMAPVAR",
+OrWorldVar = "ORWORLDVAR",
+OrGlobalVar = "ORGLOBALVAR",
+OrMapArray = "ORMAPARRAY",
+OrWorldArray = "ORWORLDARRAY",
+OrGlobalArray = "ORGLOBALARRAY",
+LsScriptVar = "LSSCRIPTVAR",
+LsMapVar = "LSMAPVAR",
+LsWorldVar = "LSWORLDVAR",
+LsGlobalVar = "LSGLOBALVAR",
+LsMapArray = "LSMAPARRAY",
+LsWorldArray = "LSWORLDARRAY",
+LsGlobalArray = "LSGLOBALARRAY",
+RsScriptVar = "RSSCRIPTVAR",
+RsMapVar = "RSMAPVAR",
+RsWorldVar = "RSWORLDVAR",
+RsGlobalVar = "RSGLOBALVAR",
+RsMapArray = "RSMAPARRAY",
+RsWorldArray = "RSWORLDARRAY",
+RsGlobalArray = "RSGLOBALARRAY",
+GetPlayerInfo = "GETPLAYERINFO",
+ChangeLevel = "CHANGELEVEL",
+SectorDamage = "SECTORDAMAGE",
+ReplaceTextures = "REPLACETEXTURES",
+NegateBinary = "NEGATEBINARY",
+GetActorPitch = "GETACTORPITCH",
+SetActorPitch = "SETACTORPITCH",
+PrintBind = "PRINTBIND",
+SetActorState = "SETACTORSTATE",
+ThingDamage2 = "THINGDAMAGE2",
+UseInventory = "USEINVENTORY",
+UseActorInventory = "USEACTORINVENTORY",
+CheckActorCeilingTexture = "CHECKACTORCEILINGTEXTURE",
+CheckActorFloorTexture = "CHECKACTORFLOORTEXTURE",
+GetActorLightLevel = "GETACTORLIGHTLEVEL",
+SetMugShotState = "SETMUGSHOTSTATE",
+ThingCountSector = "THINGCOUNTSECTOR",
+ThingCountNameSector = "THINGCOUNTNAMESECTOR",
+CheckPlayerCamera = "CHECKPLAYERCAMERA",
+MorphActor = "MORPHACTOR",
+UnMorphActor = "UNMORPHACTOR",
+GetPlayerInput = "GETPLAYERINPUT",
+ClassifyActor = "CLASSIFYACTOR",
+PrintBinary = "PRINTBINARY",
+PrintHex = "PRINTHEX",
+CallFunc = "CALLFUNC",
+SaveString = "SAVESTRING",
+PrintMapChRange = "PRINTMAPCHRANGE",
+PrintWorldChRange = "PRINTWORLDCHRANGE",
+PrintGlobalChRange = "PRINTGLOBALCHRANGE",
+StrCpyToMapChRange = "STRCPYTOMAPCHRANGE",
+StrCpyToWorldChRange = "STRCPYTOWORLDCHRANGE",
+StrCpyToGlobalChRange = "STRCPYTOGLOBALCHRANGE",
+PushFunction = "PUSHFUNCTION",
+CallStack = "CALLSTACK",
+ScriptWaitNamed = "SCRIPTWAITNAMED",
+TranslationRange3 = "TRANSLATIONRANGE3",
+GotoStack = "GOTOSTACK",
+AssignScriptArray = "ASSIGNSCRIPTARRAY",
+PushScriptArray = "PUSHSCRIPTARRAY",
+AddScriptArray = "ADDSCRIPTARRAY",
+SubScriptArray = "SUBSCRIPTARRAY",
+MulScriptArray = "MULSCRIPTARRAY",
+DivScriptArray = "DIVSCRIPTARRAY",
+ModScriptArray = "MODSCRIPTARRAY",
+IncScriptArray = "INCSCRIPTARRAY",
+DecScriptArray = "DECSCRIPTARRAY",
+AndScriptArray = "ANDSCRIPTARRAY",
+EorScriptArray = "EORSCRIPTARRAY",
+OrScriptArray = "ORSCRIPTARRAY",
+LsScriptArray = "LSSCRIPTARRAY",
+RsScriptArray = "RSSCRIPTARRAY",
+PrintScriptCharArray = "PRINTSCRIPTCHARARRAY",
+PrintScriptChRange = "PRINTSCRIPTCHRANGE",
+StrCpyToScriptChRange = "STRCPYTOSCRIPTCHRANGE",
+LSpec5Ex = "LSPEC5EX",
+LSpec5ExResult = "LSPEC5EXRESULT",
+TranslationRange4 = "TRANSLATIONRANGE4",
+TranslationRange5 = "TRANSLATIONRANGE5",}
+
+impl Pcode {
+	#[must_use]
+	pub fn from_u16(number: u16) -> Option<Self> {
+		Self::ALL.get(number as usize).copied()
+	}
+
+	/// Case-insensitive, and with or without the `PCD_` prefix.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = name
+			.strip_prefix("PCD_")
+			.or_else(|| name.strip_prefix("pcd_"))
+			.unwrap_or(name);
+
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|pcode| pcode.name().eq_ignore_ascii_case(name))
+	}
+
+	#[must_use]
+	pub fn operands(self) -> Operands {
+		match self {
+			Self::PushNumber
+			| Self::DelayDirect
+			| Self::TagWaitDirect
+			| Self::PolyWaitDirect
+			| Self::ScriptWaitDirect
+			| Self::SetGravityDirect
+			| Self::SetAirControlDirect
+			| Self::CheckInventoryDirect
+			| Self::SetStyleDirect
+			| Self::SetFontDirect
+			| Self::LSpec5Ex
+			| Self::LSpec5ExResult => Operands::Ints(1),
+			Self::RandomDirect
+			| Self::ThingCountDirect
+			| Self::ChangeFloorDirect
+			| Self::ChangeCeilingDirect
+			| Self::GiveInventoryDirect
+			| Self::TakeInventoryDirect => Operands::Ints(2),
+			Self::ConsoleCommandDirect | Self::SetMusicDirect | Self::LocalSetMusicDirect => {
+				Operands::Ints(3)
+			}
+			Self::SpawnSpotDirect => Operands::Ints(4),
+			Self::SpawnDirect => Operands::Ints(6),
+			Self::Goto | Self::IfGoto | Self::IfNotGoto => Operands::Jump,
+			Self::CaseGoto => Operands::Case,
+			Self::CaseGotoSorted => Operands::SortedCases,
+			Self::LSpec1
+			| Self::LSpec2
+			| Self::LSpec3
+			| Self::LSpec4
+			| Self::LSpec5
+			| Self::LSpec6
+			| Self::LSpec5Result
+			| Self::Call
+			| Self::CallDiscard
+			| Self::PushFunction => Operands::Index,
+			Self::LSpec1Direct => Operands::IndexInts(1),
+			Self::LSpec2Direct => Operands::IndexInts(2),
+			Self::LSpec3Direct => Operands::IndexInts(3),
+			Self::LSpec4Direct => Operands::IndexInts(4),
+			Self::LSpec5Direct => Operands::IndexInts(5),
+			Self::LSpec6Direct => Operands::IndexInts(6),
+			Self::PushByte | Self::DelayDirectB => Operands::Bytes(1),
+			Self::LSpec1DirectB | Self::RandomDirectB | Self::Push2Bytes => Operands::Bytes(2),
+			Self::LSpec2DirectB | Self::Push3Bytes => Operands::Bytes(3),
+			Self::LSpec3DirectB | Self::Push4Bytes => Operands::Bytes(4),
+			Self::LSpec4DirectB | Self::Push5Bytes => Operands::Bytes(5),
+			Self::LSpec5DirectB => Operands::Bytes(6),
+			Self::PushBytes => Operands::ByteList,
+			Self::CallFunc => Operands::CallFunc,
+			other if other.var_op().is_some() => Operands::Index,
+			_ => Operands::None,
+		}
+	}
+
+	/// If this pcode operates on a variable, returns what it does
+	/// and what kind of variable it does it to.
+	#[must_use]
+	pub fn var_op(self) -> Option<(VarOp, VarKind)> {
+		let n = self as u16;
+
+		let (op, kind) = match n {
+			25..=51 => (
+				VarOp::BASIC[(n as usize - 25) / 3],
+				VarKind::BASIC[(n as usize - 25) % 3],
+			),
+			181..=189 => (VarOp::BASIC[n as usize - 181], VarKind::Global),
+			207..=215 => (VarOp::ARRAY[n as usize - 207], VarKind::MapArray),
+			226..=234 => (VarOp::ARRAY[n as usize - 226], VarKind::WorldArray),
+			235..=243 => (VarOp::ARRAY[n as usize - 235], VarKind::GlobalArray),
+			291..=325 => (
+				VarOp::BITWISE[(n as usize - 291) / 7],
+				VarKind::BITWISE[(n as usize - 291) % 7],
+			),
+			364..=377 => (VarOp::SCRIPT_ARRAY[n as usize - 364], VarKind::ScriptArray),
+			_ => return None,
+		};
+
+		Some((op, kind))
+	}
+
+	/// The inverse of [`Self::var_op`]. Returns `None` for combinations
+	/// which the virtual machine lacks an instruction for.
+	#[must_use]
+	pub fn for_var(op: VarOp, kind: VarKind) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|pcode| pcode.var_op() == Some((op, kind)))
+	}
+}
+
+/// What a variable instruction does to its variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VarOp {
+	Assign,
+	Push,
+	Add,
+	Sub,
+	Mul,
+	Div,
+	Mod,
+	Inc,
+	Dec,
+	And,
+	Eor,
+	Or,
+	LShift,
+	RShift,
+}
+
+impl VarOp {
+	const BASIC: [Self; 9] = [
+		Self::Assign,
+		Self::Push,
+		Self::Add,
+		Self::Sub,
+		Self::Mul,
+		Self::Div,
+		Self::Mod,
+		Self::Inc,
+		Self::Dec,
+	];
+
+	const ARRAY: [Self; 9] = [
+		Self::Push,
+		Self::Assign,
+		Self::Add,
+		Self::Sub,
+		Self::Mul,
+		Self::Div,
+		Self::Mod,
+		Self::Inc,
+		Self::Dec,
+	];
+
+	const BITWISE: [Self; 5] = [Self::And, Self::Eor, Self::Or, Self::LShift, Self::RShift];
+
+	const SCRIPT_ARRAY: [Self; 14] = [
+		Self::Assign,
+		Self::Push,
+		Self::Add,
+		Self::Sub,
+		Self::Mul,
+		Self::Div,
+		Self::Mod,
+		Self::Inc,
+		Self::Dec,
+		Self::And,
+		Self::Eor,
+		Self::Or,
+		Self::LShift,
+		Self::RShift,
+	];
+
+	/// The compound assignment operator this corresponds to, e.g. `+=`.
+	/// `Push` yields an empty string.
+	#[must_use]
+	pub fn operator(self) -> &'static str {
+		match self {
+			Self::Assign => "=",
+			Self::Push => "",
+			Self::Add => "+=",
+			Self::Sub => "-=",
+			Self::Mul => "*=",
+			Self::Div => "/=",
+			Self::Mod => "%=",
+			Self::Inc => "++",
+			Self::Dec => "--",
+			Self::And => "&=",
+			Self::Eor => "^=",
+			Self::Or => "|=",
+			Self::LShift => "<<=",
+			Self::RShift => ">>=",
+		}
+	}
+}
+
+/// The storage a variable instruction operates on.
+/// Array instructions additionally take an element index from the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VarKind {
+	Script,
+	Map,
+	World,
+	Global,
+	ScriptArray,
+	MapArray,
+	WorldArray,
+	GlobalArray,
+}
+
+impl VarKind {
+	const BASIC: [Self; 3] = [Self::Script, Self::Map, Self::World];
+
+	const BITWISE: [Self; 7] = [
+		Self::Script,
+		Self::Map,
+		Self::World,
+		Self::Global,
+		Self::MapArray,
+		Self::WorldArray,
+		Self::GlobalArray,
+	];
+
+	#[must_use]
+	pub fn is_array(self) -> bool {
+		matches!(
+			self,
+			Self::ScriptArray | Self::MapArray | Self::WorldArray | Self::GlobalArray
+		)
+	}
+}
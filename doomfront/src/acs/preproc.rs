@@ -0,0 +1,718 @@
+//! A preprocessor which mimics that of ACC, making ACS source ready for
+//! compilation by following includes and expanding constants.
+
+use std::{collections::HashMap, ops::Range};
+
+use rowan::{TextRange, TextSize};
+
+use crate::Issue;
+
+use super::{parse_recov, RawParseTree};
+
+/// Resolves every `#include` and `#import` reachable from `root`, and expands
+/// every `#define` and `#libdefine` constant, yielding one source string which
+/// can then be passed to [`parse_recov`] (or use [`Preprocessed::parse`]).
+///
+/// - `include_dirs` is searched in order for any path which cannot be found
+///   relative to the directory of the file which includes it, as with ACC's
+///   `-i` option. This is typically where `zcommon.acs` lives.
+/// - `fetch` retrieves a file's source given a path, or returns `None` if no
+///   such file exists.
+///
+/// `#include`d files are pasted in place of the directive, and each file is
+/// only included once. `#import`ed files contribute only their `#libdefine`
+/// constants, as their code belongs to another library; they are recorded in
+/// [`Preprocessed::files`] so that their declarations can still be read.
+///
+/// Like ACC, each constant expands as though it were parenthesized, so that
+/// `#define A 1 + 2` makes `A * 3` evaluate to 9 and not 7.
+///
+/// Returns `None` if `fetch` fails to retrieve `root`.
+#[must_use]
+pub fn preprocess<F>(root: &str, include_dirs: &[&str], fetch: F) -> Option<Preprocessed>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	let mut fetch = fetch;
+	let source = fetch(root)?;
+
+	let mut ctx = Context {
+		include_dirs,
+		fetch,
+		files: vec![SourceFile {
+			path: root.to_string(),
+			source,
+			imported: false,
+		}],
+		visited: vec![normalize(root)],
+		defines: vec![],
+		issues: vec![],
+		out: String::new(),
+		segments: vec![],
+	};
+
+	let mut scope = HashMap::new();
+	ctx.file(0, true, &mut scope);
+
+	Some(Preprocessed {
+		source: ctx.out,
+		files: ctx.files,
+		defines: ctx.defines,
+		issues: ctx.issues,
+		segments: ctx.segments,
+	})
+}
+
+/// The output of [`preprocess`].
+#[derive(Debug)]
+pub struct Preprocessed {
+	/// All included code with constants expanded, ready for parsing.
+	pub source: String,
+	/// The root file is always first, followed by every included or imported
+	/// file in order of first appearance.
+	pub files: Vec<SourceFile>,
+	/// Every constant defined by the files in [`Self::files`], in order.
+	pub defines: Vec<Define>,
+	/// Problems with directives and constants. Each issue is paired with the
+	/// index of the file in [`Self::files`] it was found in, and its range
+	/// is relative to that file's source.
+	pub issues: Vec<(usize, Issue)>,
+	segments: Vec<Segment>,
+}
+
+/// A file read by [`preprocess`].
+#[derive(Debug)]
+pub struct SourceFile {
+	pub path: String,
+	pub source: String,
+	/// `true` if this file was reached through `#import` rather than `#include`,
+	/// in which case its code is absent from [`Preprocessed::source`].
+	pub imported: bool,
+}
+
+/// A `#define` or `#libdefine` constant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Define {
+	pub name: String,
+	/// As written, with surrounding whitespace and trailing comments removed.
+	pub value: String,
+	/// `true` if this came from `#libdefine`.
+	pub lib: bool,
+	/// Index into [`Preprocessed::files`].
+	pub file: usize,
+	/// The span of the constant's name in its file.
+	pub range: TextRange,
+}
+
+/// Where some part of [`Preprocessed::source`] was written originally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+	/// Index into [`Preprocessed::files`].
+	pub file: usize,
+	/// Relative to the source of the file at [`Self::file`].
+	/// Spans within an expanded constant cover the constant's name.
+	pub range: TextRange,
+	/// One-based.
+	pub line: usize,
+	/// One-based, counted in characters.
+	pub column: usize,
+}
+
+impl Preprocessed {
+	/// Shorthand for [`parse_recov`] on [`Self::source`].
+	#[must_use]
+	pub fn parse(&self) -> Option<RawParseTree> {
+		parse_recov(&self.source)
+	}
+
+	/// Maps a span of [`Self::source`] back to the file it came from.
+	/// A span which begins in one file and ends in another gets truncated.
+	///
+	/// Returns `None` if `range` lies outside of [`Self::source`].
+	#[must_use]
+	pub fn resolve(&self, range: TextRange) -> Option<(usize, TextRange)> {
+		let start = usize::from(range.start());
+
+		if start > self.source.len() {
+			return None;
+		}
+
+		let index = self
+			.segments
+			.partition_point(|seg| seg.out.end <= start)
+			.min(self.segments.len().checked_sub(1)?);
+
+		let seg = &self.segments[index];
+
+		if seg.expanded {
+			return Some((seg.file, text_range(seg.src.clone())));
+		}
+
+		let start = start.clamp(seg.out.start, seg.out.end);
+		let end = usize::from(range.end()).clamp(start, seg.out.end);
+		let src_start = seg.src.start + (start - seg.out.start);
+		let src_end = seg.src.start + (end - seg.out.start);
+
+		Some((seg.file, text_range(src_start..src_end)))
+	}
+
+	/// Like [`Self::resolve`], but also computes a line and column number.
+	#[must_use]
+	pub fn locate(&self, range: TextRange) -> Option<Location> {
+		let (file, range) = self.resolve(range)?;
+		let source = &self.files[file].source;
+		let before = &source[..usize::from(range.start())];
+		let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+		Some(Location {
+			file,
+			range,
+			line: before.matches('\n').count() + 1,
+			column: before[line_start..].chars().count() + 1,
+		})
+	}
+
+	/// Re-targets an issue raised against [`Self::source`]
+	/// (e.g. by a semantic checker) to the file it came from.
+	#[must_use]
+	pub fn map_issue(&self, issue: Issue) -> Option<(usize, Issue)> {
+		let (file, range) = self.resolve(issue.range)?;
+		Some((file, Issue { range, ..issue }))
+	}
+
+	/// Converts the errors of a tree parsed from [`Self::source`] into issues
+	/// re-targeted to the files they came from.
+	#[must_use]
+	pub fn map_parse_errors(&self, tree: &RawParseTree) -> Vec<(usize, Issue)> {
+		tree.errors()
+			.iter()
+			.filter_map(|err| self.map_issue(Issue::error(text_range(err.span()), err.to_string())))
+			.collect()
+	}
+}
+
+// Details /////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+struct Segment {
+	out: Range<usize>,
+	file: usize,
+	src: Range<usize>,
+	/// If `true`, `out` is the expansion of the constant named at `src`.
+	expanded: bool,
+}
+
+/// Maps case-folded constant names to indices into [`Context::defines`].
+type Scope = HashMap<String, usize>;
+
+struct Context<'i, F> {
+	include_dirs: &'i [&'i str],
+	fetch: F,
+	files: Vec<SourceFile>,
+	visited: Vec<String>,
+	defines: Vec<Define>,
+	issues: Vec<(usize, Issue)>,
+	out: String,
+	segments: Vec<Segment>,
+}
+
+impl<F> Context<'_, F>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	/// If `emit` is `false`, the file's directives are processed
+	/// but none of its code is output.
+	fn file(&mut self, index: usize, emit: bool, scope: &mut Scope) {
+		let source = self.files[index].source.clone();
+		let bytes = source.as_bytes();
+		let mut i = 0;
+		let mut copied = 0;
+		let mut line_start = true;
+
+		while i < bytes.len() {
+			let c = bytes[i];
+
+			if source[i..].starts_with("//") {
+				i = line_end(&source, i);
+				line_start = false;
+			} else if source[i..].starts_with("/*") {
+				i = source[(i + 2)..]
+					.find("*/")
+					.map_or(source.len(), |j| i + 2 + j + 2);
+				line_start = false;
+			} else if c == b'"' || c == b'\'' {
+				i = quoted_end(bytes, i);
+				line_start = false;
+			} else if c == b'#' && line_start {
+				let end = line_end(&source, i);
+
+				if self.directive(index, emit, scope, &source, i..end) {
+					if emit {
+						self.verbatim(index, &source, copied..i);
+					}
+
+					copied = end;
+				}
+
+				i = end;
+				line_start = false;
+			} else if c.is_ascii_alphabetic() || c == b'_' {
+				let end = ident_end(bytes, i);
+				let name = &source[i..end];
+
+				if let Some(&def) = scope.get(&name.to_ascii_lowercase()) {
+					if emit {
+						self.verbatim(index, &source, copied..i);
+						let mut stack = vec![];
+						let text = self.expand(index, i..end, def, scope, &mut stack);
+						self.expansion(index, i..end, &text);
+					}
+
+					copied = end;
+				}
+
+				i = end;
+				line_start = false;
+			} else if c.is_ascii_digit() {
+				i = ident_end(bytes, i);
+				line_start = false;
+			} else {
+				if c == b'\n' {
+					line_start = true;
+				} else if !c.is_ascii_whitespace() {
+					line_start = false;
+				}
+
+				i += source[i..].chars().next().map_or(1, char::len_utf8);
+			}
+		}
+
+		if emit {
+			self.verbatim(index, &source, copied..source.len());
+		}
+	}
+
+	/// Returns `true` if the directive spanning `span` was handled
+	/// and should be left out of the output.
+	fn directive(
+		&mut self,
+		file: usize,
+		emit: bool,
+		scope: &mut Scope,
+		source: &str,
+		span: Range<usize>,
+	) -> bool {
+		let name_end = ident_end(source.as_bytes(), span.start + 1);
+		let name = &source[(span.start + 1)..name_end];
+
+		if name.eq_ignore_ascii_case("include") || name.eq_ignore_ascii_case("import") {
+			let import = name.eq_ignore_ascii_case("import");
+			self.include(
+				file,
+				emit && !import,
+				import,
+				scope,
+				source,
+				name_end..span.end,
+			);
+			true
+		} else if name.eq_ignore_ascii_case("define") || name.eq_ignore_ascii_case("libdefine") {
+			let lib = name.eq_ignore_ascii_case("libdefine");
+			self.define(
+				file,
+				lib,
+				scope,
+				source,
+				span.start..name_end,
+				name_end..span.end,
+			);
+			true
+		} else {
+			false
+		}
+	}
+
+	fn include(
+		&mut self,
+		file: usize,
+		emit: bool,
+		import: bool,
+		scope: &mut Scope,
+		source: &str,
+		rest: Range<usize>,
+	) {
+		let text = &source[rest.clone()];
+
+		let Some(open) = text.find('"') else {
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(rest),
+					"expected a quoted path after this directive",
+				),
+			));
+			return;
+		};
+
+		let close = text[(open + 1)..]
+			.find('"')
+			.map_or(text.len(), |j| open + 1 + j);
+		let lit_range = (rest.start + open)..(rest.start + (close + 1).min(text.len()));
+		let written = &text[(open + 1)..close];
+
+		let Some((path, inc_src)) = self.find(&self.files[file].path.clone(), written) else {
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(lit_range),
+					format!("failed to find included file: `{written}`"),
+				),
+			));
+			return;
+		};
+
+		let normalized = normalize(&path);
+
+		if self.visited.contains(&normalized) {
+			return;
+		}
+
+		self.visited.push(normalized);
+
+		let index = self.files.len();
+
+		self.files.push(SourceFile {
+			path,
+			source: inc_src,
+			imported: import || self.files[file].imported,
+		});
+
+		if !import {
+			self.file(index, emit, scope);
+			return;
+		}
+
+		let mut lib_scope = Scope::new();
+		self.file(index, false, &mut lib_scope);
+
+		for (key, def) in lib_scope {
+			if self.defines[def].lib {
+				scope.entry(key).or_insert(def);
+			}
+		}
+	}
+
+	fn define(
+		&mut self,
+		file: usize,
+		lib: bool,
+		scope: &mut Scope,
+		source: &str,
+		directive: Range<usize>,
+		rest: Range<usize>,
+	) {
+		let bytes = source.as_bytes();
+		let mut start = rest.start;
+
+		while start < rest.end && bytes[start].is_ascii_whitespace() {
+			start += 1;
+		}
+
+		if start >= rest.end || !(bytes[start].is_ascii_alphabetic() || bytes[start] == b'_') {
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(directive),
+					"expected a constant name after this directive",
+				),
+			));
+			return;
+		}
+
+		let name_end = ident_end(bytes, start);
+		let name = &source[start..name_end];
+		let value = strip_comment(&source[name_end..rest.end]).trim();
+		let key = name.to_ascii_lowercase();
+
+		if let Some(&prev) = scope.get(&key) {
+			let prev = &self.defines[prev];
+
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(start..name_end),
+					format!(
+						"`{name}` is already defined (in `{}`)",
+						self.files[prev.file].path
+					),
+				),
+			));
+
+			return;
+		}
+
+		if value.is_empty() {
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(start..name_end),
+					format!("`{name}` is defined without a value"),
+				),
+			));
+		}
+
+		scope.insert(key, self.defines.len());
+
+		self.defines.push(Define {
+			name: name.to_string(),
+			value: value.to_string(),
+			lib,
+			file,
+			range: text_range(start..name_end),
+		});
+	}
+
+	/// Expands the constant at index `def` of [`Self::defines`], and any
+	/// constants used in its value, recursively. `stack` holds the constants
+	/// currently undergoing expansion, for catching cycles.
+	fn expand(
+		&mut self,
+		file: usize,
+		call: Range<usize>,
+		def: usize,
+		scope: &Scope,
+		stack: &mut Vec<usize>,
+	) -> String {
+		if stack.contains(&def) {
+			let name = self.defines[def].name.clone();
+
+			self.issues.push((
+				file,
+				Issue::error(
+					text_range(call),
+					format!("`{name}` is defined in terms of itself"),
+				),
+			));
+
+			return name;
+		}
+
+		stack.push(def);
+
+		let value = self.defines[def].value.clone();
+		let bytes = value.as_bytes();
+		let mut ret = String::with_capacity(value.len());
+		let mut i = 0;
+
+		while i < bytes.len() {
+			let c = bytes[i];
+
+			let end = if c == b'"' || c == b'\'' {
+				quoted_end(bytes, i)
+			} else if c.is_ascii_alphanumeric() || c == b'_' {
+				ident_end(bytes, i)
+			} else {
+				i + value[i..].chars().next().map_or(1, char::len_utf8)
+			};
+
+			let token = &value[i..end];
+
+			match scope.get(&token.to_ascii_lowercase()) {
+				Some(&inner) if c.is_ascii_alphabetic() || c == b'_' => {
+					let expanded = self.expand(file, call.clone(), inner, scope, stack);
+					ret.push_str(&expanded);
+				}
+				_ => ret.push_str(token),
+			}
+
+			i = end;
+		}
+
+		stack.pop();
+
+		if is_atom(&ret) {
+			ret
+		} else {
+			format!("({ret})")
+		}
+	}
+
+	/// Tries the directory of `includer`, then each of [`Self::include_dirs`].
+	fn find(&mut self, includer: &str, written: &str) -> Option<(String, String)> {
+		let written = written.replace('\\', "/");
+		let mut candidates = vec![];
+
+		if let Some(slash) = includer.replace('\\', "/").rfind('/') {
+			candidates.push(format!("{}/{written}", &includer[..slash]));
+		}
+
+		candidates.push(written.clone());
+
+		for dir in self.include_dirs {
+			candidates.push(format!("{}/{written}", dir.trim_end_matches(['/', '\\'])));
+		}
+
+		candidates
+			.into_iter()
+			.find_map(|path| (self.fetch)(&path).map(|src| (path, src)))
+	}
+
+	fn verbatim(&mut self, file: usize, source: &str, span: Range<usize>) {
+		if span.is_empty() {
+			return;
+		}
+
+		let out_start = self.out.len();
+		self.out.push_str(&source[span.clone()]);
+
+		if let Some(last) = self.segments.last_mut() {
+			if !last.expanded
+				&& last.file == file
+				&& last.src.end == span.start
+				&& last.out.end == out_start
+			{
+				last.out.end = self.out.len();
+				last.src.end = span.end;
+				return;
+			}
+		}
+
+		self.segments.push(Segment {
+			out: out_start..self.out.len(),
+			file,
+			src: span,
+			expanded: false,
+		});
+	}
+
+	fn expansion(&mut self, file: usize, call: Range<usize>, text: &str) {
+		let out_start = self.out.len();
+		self.out.push_str(text);
+
+		self.segments.push(Segment {
+			out: out_start..self.out.len(),
+			file,
+			src: call,
+			expanded: true,
+		});
+	}
+}
+
+fn normalize(path: &str) -> String {
+	path.replace('\\', "/").to_ascii_lowercase()
+}
+
+fn text_range(range: Range<usize>) -> TextRange {
+	TextRange::new(
+		TextSize::from(range.start as u32),
+		TextSize::from(range.end as u32),
+	)
+}
+
+/// The index of the next newline at or after `start`, or the end of `source`.
+fn line_end(source: &str, start: usize) -> usize {
+	source[start..]
+		.find('\n')
+		.map_or(source.len(), |j| start + j)
+}
+
+/// The end of the identifier or number beginning at `start`.
+fn ident_end(bytes: &[u8], start: usize) -> usize {
+	let mut end = start;
+
+	while end < bytes.len() && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_') {
+		end += 1;
+	}
+
+	// Fixed-point literals.
+	if end < bytes.len() && bytes[start].is_ascii_digit() && bytes[end] == b'.' {
+		end += 1;
+
+		while end < bytes.len() && bytes[end].is_ascii_alphanumeric() {
+			end += 1;
+		}
+	}
+
+	end
+}
+
+/// The end of the string or character literal beginning at `start`, just past
+/// the closing delimiter, or at the end of the line if it is unterminated.
+fn quoted_end(bytes: &[u8], start: usize) -> usize {
+	let delim = bytes[start];
+	let mut end = start + 1;
+
+	while end < bytes.len() && bytes[end] != b'\n' {
+		match bytes[end] {
+			b'\\' => end += 2,
+			c if c == delim => return end + 1,
+			_ => end += 1,
+		}
+	}
+
+	end.min(bytes.len())
+}
+
+/// Cuts off any comment following a constant's value.
+fn strip_comment(text: &str) -> &str {
+	let bytes = text.as_bytes();
+	let mut i = 0;
+
+	while i < bytes.len() {
+		if bytes[i] == b'"' || bytes[i] == b'\'' {
+			i = quoted_end(bytes, i);
+		} else if text[i..].starts_with("//") || text[i..].starts_with("/*") {
+			return &text[..i];
+		} else {
+			i += 1;
+		}
+	}
+
+	text
+}
+
+/// Does this expanded value need no parentheses to keep its meaning?
+fn is_atom(text: &str) -> bool {
+	let ident_like = !text.is_empty()
+		&& text
+			.bytes()
+			.all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'.');
+
+	let string =
+		text.len() >= 2 && text.starts_with('"') && quoted_end(text.as_bytes(), 0) == text.len();
+
+	ident_like || string || parenthesized(text)
+}
+
+/// Is all of `text` enclosed by one pair of parentheses?
+fn parenthesized(text: &str) -> bool {
+	if !text.starts_with('(') {
+		return false;
+	}
+
+	let bytes = text.as_bytes();
+	let mut depth = 0;
+	let mut i = 0;
+
+	while i < bytes.len() {
+		match bytes[i] {
+			b'"' | b'\'' => {
+				i = quoted_end(bytes, i);
+				continue;
+			}
+			b'(' => depth += 1,
+			b')' => {
+				depth -= 1;
+
+				if depth == 0 {
+					return i == bytes.len() - 1;
+				}
+			}
+			_ => {}
+		}
+
+		i += 1;
+	}
+
+	false
+}
@@ -0,0 +1,369 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast;
+
+/// ACS syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Directives //////////////////////////////////////////////////////////////
+	/// `#define NAME expr` or `#libdefine NAME expr`
+	DefineDirective,
+	/// `#include "path"` or `#import "path"`
+	IncludeDirective,
+	/// `#library "name"`
+	LibraryDirective,
+	/// A directive which takes no arguments, such as `#nocompact`.
+	SimpleDirective,
+
+	// Top-level definitions ///////////////////////////////////////////////////
+	/// `(expr, expr; expr)`; the arguments passed to a function call.
+	/// May begin with `const:`, and print-like functions separate the message
+	/// from any further arguments with a semicolon.
+	ArgList,
+	/// An expression in an [`Syn::ArgList`], optionally prefixed with a
+	/// cast specifier such as `s:` or `d:`.
+	Argument,
+	/// `[expr]` following a variable name. The expression is optional.
+	ArrayLen,
+	/// `function type name(params) { ... }`
+	FuncDef,
+	/// `{ expr, expr, ... }`; used to initialize arrays.
+	InitList,
+	/// `type name` within a [`Syn::ParamList`].
+	Parameter,
+	/// `(void)` or `(param, param, ...)`, part of a [`Syn::ScriptDef`] or
+	/// [`Syn::FuncDef`].
+	ParamList,
+	/// `script number (params) type flags { ... }`
+	ScriptDef,
+	/// `special number:name(args), number:name(min, max);`
+	SpecialDecl,
+	/// `number:name(args)` or `-number:name(min, max)` in a [`Syn::SpecialDecl`].
+	SpecialDef,
+	/// `world type index:name[], name;`, `global ...`, or `type name = expr;`
+	/// outside of any script or function.
+	VarDecl,
+	/// `name`, `name = expr`, `index:name`, or `name[len]` in a
+	/// [`Syn::VarDecl`] or [`Syn::StatLocalVar`].
+	VarName,
+
+	// Statements //////////////////////////////////////////////////////////////
+	/// `break;`
+	StatBreak,
+	/// `case expr:` inside a switch statement's block.
+	StatCase,
+	/// `default:` inside a switch statement's block.
+	StatCaseDefault,
+	/// `{ ... }`
+	StatCompound,
+	/// `continue;`
+	StatContinue,
+	/// `do stat while (expr);` or `do stat until (expr);`
+	StatDoWhile,
+	/// `;`
+	StatEmpty,
+	/// `expr;`
+	StatExpr,
+	/// `for (init; cond; iter) stat`
+	StatFor,
+	/// `if (expr) stat` with an optional `else stat`.
+	StatIf,
+	/// `type name = expr, name;`
+	StatLocalVar,
+	/// `restart;`
+	StatRestart,
+	/// `return;` or `return expr;`
+	StatReturn,
+	/// `suspend;`
+	StatSuspend,
+	/// `switch (expr) stat`
+	StatSwitch,
+	/// `terminate;`
+	StatTerminate,
+	/// `until (expr) stat`
+	StatUntil,
+	/// `while (expr) stat`
+	StatWhile,
+	/// The portion of a [`Syn::StatFor`] before the first semicolon.
+	ForInit,
+	/// The portion of a [`Syn::StatFor`] between the two semicolons.
+	ForCond,
+	/// The portion of a [`Syn::StatFor`] after the second semicolon.
+	ForIter,
+
+	// Expressions /////////////////////////////////////////////////////////////
+	/// e.g. `expr + expr` or `expr = expr`.
+	ExprBinary,
+	/// `name(args)`
+	ExprCall,
+	/// `(expr)`
+	ExprGroup,
+	/// Syntax node with just a [`Syn::Ident`] token as a child.
+	ExprIdent,
+	/// `expr[expr]`; array element access.
+	ExprIndex,
+	/// e.g. `expr++` or `expr--`
+	ExprPostfix,
+	/// e.g. `-expr`, `!expr`, or `++expr`
+	ExprPrefix,
+	/// `expr ? expr : expr`, which GDCC-ACS accepts but ACC does not.
+	ExprTernary,
+	/// Wraps one of the `Lit`- tokens. Adjacent string literals get concatenated,
+	/// so a literal node may hold multiple [`Syn::LitString`] tokens.
+	Literal,
+
+	// Keywords ////////////////////////////////////////////////////////////////
+	KwBlueReturn,
+	KwBool,
+	KwBreak,
+	KwCase,
+	KwClientSide,
+	KwConst,
+	KwContinue,
+	KwDeath,
+	KwDefault,
+	/// `#define`
+	KwDefine,
+	KwDisconnect,
+	KwDo,
+	KwElse,
+	/// `#encryptstrings`
+	KwEncryptStrings,
+	KwEnter,
+	KwEvent,
+	KwFixed,
+	KwFor,
+	KwFunction,
+	KwGlobal,
+	KwIf,
+	/// `#import`
+	KwImport,
+	/// `#include`
+	KwInclude,
+	KwInt,
+	KwKill,
+	/// `#libdefine`
+	KwLibDefine,
+	/// `#library`
+	KwLibrary,
+	KwLightning,
+	KwNet,
+	/// `#nocompact`
+	KwNoCompact,
+	/// `#nowadauthor`
+	KwNoWadAuthor,
+	KwOpen,
+	KwPickup,
+	KwRedReturn,
+	KwReopen,
+	KwRespawn,
+	KwRestart,
+	KwReturn,
+	KwScript,
+	KwSpecial,
+	KwStr,
+	KwSuspend,
+	KwSwitch,
+	KwTerminate,
+	KwUnloading,
+	KwUntil,
+	KwVoid,
+	/// `#wadauthor`
+	KwWadAuthor,
+	KwWhile,
+	KwWhiteReturn,
+	KwWorld,
+
+	// Literals ////////////////////////////////////////////////////////////////
+	/// A single character delimited by single-quotes (`'`), e.g. `'a'` or `'\n'`.
+	LitChar,
+	/// A number with a decimal point, which ACS converts to 16.16 fixed-point.
+	LitFixed,
+	/// A C-style integer literal.
+	LitInt,
+	/// A string delimited by double-quotes (`"`).
+	LitString,
+
+	// Glyphs, composite glyphs, glyph-adjacent ////////////////////////////////
+	/// `&`
+	Ampersand,
+	/// `&&`
+	Ampersand2,
+	/// `&=`
+	AmpersandEq,
+	/// `<`
+	AngleL,
+	/// `<<`
+	AngleL2,
+	/// `<<=`
+	AngleL2Eq,
+	/// `<=`
+	AngleLEq,
+	/// `>`
+	AngleR,
+	/// `>>`
+	AngleR2,
+	/// `>>=`
+	AngleR2Eq,
+	/// `>=`
+	AngleREq,
+	/// `*`
+	Asterisk,
+	/// `*=`
+	AsteriskEq,
+	/// `!`
+	Bang,
+	/// `!=`
+	BangEq,
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `[`
+	BracketL,
+	/// `]`
+	BracketR,
+	/// `^`
+	Caret,
+	/// `^=`
+	CaretEq,
+	/// `:`
+	Colon,
+	/// `,`
+	Comma,
+	/// `=`
+	Eq,
+	/// `==`
+	Eq2,
+	/// `-`
+	Minus,
+	/// `--`
+	Minus2,
+	/// `-=`
+	MinusEq,
+	/// `(`
+	ParenL,
+	/// `)`
+	ParenR,
+	/// `%`
+	Percent,
+	/// `%=`
+	PercentEq,
+	/// `|`
+	Pipe,
+	/// `||`
+	Pipe2,
+	/// `|=`
+	PipeEq,
+	/// `+`
+	Plus,
+	/// `++`
+	Plus2,
+	/// `+=`
+	PlusEq,
+	/// `?`
+	Question,
+	/// `;`
+	Semicolon,
+	/// `/`
+	Slash,
+	/// `/=`
+	SlashEq,
+	/// `~`
+	Tilde,
+
+	// Miscellaneous ///////////////////////////////////////////////////////////
+	/// ACS comments use C++ syntax and are treated like whitespace.
+	Comment,
+	/// C-style; an ASCII letter or underscore, then any number of ASCII letters,
+	/// ASCII digits, or underscores. Assigned only to tokens.
+	Ident,
+	/// Input that the lexer considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by ACS.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = ast::Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
+
+impl Syn {
+	/// Alternatively "is whitespace or comment".
+	#[must_use]
+	pub fn is_trivia(&self) -> bool {
+		matches!(self, Syn::Comment | Syn::Whitespace)
+	}
+
+	/// Is this one of the `Lit`- tokens?
+	#[must_use]
+	pub fn is_literal(&self) -> bool {
+		matches!(
+			self,
+			Syn::LitChar | Syn::LitFixed | Syn::LitInt | Syn::LitString
+		)
+	}
+
+	/// Is this one of the keywords which can follow a script's number
+	/// to determine when it runs?
+	#[must_use]
+	pub fn is_script_type(&self) -> bool {
+		matches!(
+			self,
+			Syn::KwBlueReturn
+				| Syn::KwDeath
+				| Syn::KwDisconnect
+				| Syn::KwEnter
+				| Syn::KwEvent
+				| Syn::KwKill
+				| Syn::KwLightning
+				| Syn::KwOpen
+				| Syn::KwPickup
+				| Syn::KwRedReturn
+				| Syn::KwReopen
+				| Syn::KwRespawn
+				| Syn::KwReturn
+				| Syn::KwUnloading
+				| Syn::KwWhiteReturn
+		)
+	}
+
+	/// Is this one of the type keywords (`int`, `str`, `bool`, `fixed`, `void`)?
+	#[must_use]
+	pub fn is_type(&self) -> bool {
+		matches!(
+			self,
+			Syn::KwBool | Syn::KwFixed | Syn::KwInt | Syn::KwStr | Syn::KwVoid
+		)
+	}
+}
@@ -0,0 +1,776 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::{ast, *};
+
+#[test]
+fn smoke() {
+	const SOURCE: &str = r#"
+#library "acacias"
+#include "zcommon.acs"
+#nocompact
+
+#define MAX_EGGHEADS 16
+#libdefine ROUNDABOUT (MAX_EGGHEADS * 2)
+
+special 80:ACS_Execute(2, 5), -100:GetActorX(1);
+
+world int 1:WorldVar;
+global str 2:GlobalArr[];
+int mapvar = 5, table[2][3] = { { 1, 2, 3 }, { 4, 5, 6 } };
+str greeting = "Hello, " "world";
+
+/* Block comment. */
+script 1 OPEN {
+	int i;
+
+	for (i = 0; i < MAX_EGGHEADS; i++) {
+		if (i % 2 == 0) continue;
+		else break;
+	}
+
+	while (false) {}
+
+	until (i <= 0) i--;
+
+	do {
+		delay(35);
+	} while (CheckInventory("Clip") < 10);
+
+	switch (i) {
+	case 1:
+	default:
+		break;
+	}
+
+	print(s: "Name: ", n: 0, d: i, l: "LOCALIZED");
+	hudmessage(s: greeting; HUDMSG_PLAIN, 0, CR_RED, 0.5, 0.5, 3.0);
+	Door_Open(const: 1, 16);
+	restart;
+}
+
+script "Named" (int a, str b) ENTER NET CLIENTSIDE {
+	suspend;
+	terminate;
+}
+
+script 3 (void) {
+	fixed f = 1.5;
+	bool b = f > 0.0 && !mapvar;
+	int c = 'a';
+	mapvar += table[1][2];
+}
+
+function int Double(int x) {
+	return x << 1;
+}
+
+function void Nothing(void) {
+	return;
+}
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	let roots: Vec<_> = pt.ast().collect();
+
+	assert_eq!(roots.len(), 15);
+
+	let ast::Root::Library(library) = &roots[0] else {
+		panic!("Expected a library directive, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(library.name(), "acacias");
+
+	let ast::Root::Define(define) = &roots[4] else {
+		panic!("Expected a define directive, found: {:#?}", roots[4]);
+	};
+
+	assert!(define.is_lib());
+	assert_eq!(define.name().text(), "ROUNDABOUT");
+
+	let ast::Root::Special(special) = &roots[5] else {
+		panic!("Expected a special declaration, found: {:#?}", roots[5]);
+	};
+
+	let defs: Vec<_> = special.defs().collect();
+	assert_eq!(defs[0].number(), Some(80));
+	assert_eq!(defs[0].name().text(), "ACS_Execute");
+	assert_eq!((defs[0].min_args(), defs[0].max_args()), (2, 5));
+	assert!(defs[1].is_builtin());
+	assert_eq!(defs[1].number(), Some(-100));
+
+	let ast::Root::Var(global) = &roots[7] else {
+		panic!("Expected a variable declaration, found: {:#?}", roots[7]);
+	};
+
+	assert_eq!(global.scope().unwrap().kind(), Syn::KwGlobal);
+	assert_eq!(global.type_spec().kind(), Syn::KwStr);
+	let name = global.names().next().unwrap();
+	assert_eq!(name.index().unwrap().text(), "2");
+	assert!(name.array_lens().next().unwrap().expr().is_none());
+
+	let ast::Root::Var(map_vars) = &roots[8] else {
+		panic!("Expected a variable declaration, found: {:#?}", roots[8]);
+	};
+
+	let names: Vec<_> = map_vars.names().collect();
+	assert_eq!(names[1].array_lens().count(), 2);
+
+	let Some(ast::Initializer::List(list)) = names[1].init() else {
+		panic!("Expected an initializer list.");
+	};
+
+	assert!(list
+		.elements()
+		.all(|elem| matches!(elem, ast::Initializer::List(_))));
+
+	let ast::Root::Script(script) = &roots[10] else {
+		panic!("Expected a script, found: {:#?}", roots[10]);
+	};
+
+	assert_eq!(script.kind().unwrap().kind(), Syn::KwOpen);
+	assert!(script.params().is_none());
+
+	let stats: Vec<_> = script.body().statements().collect();
+	assert_eq!(stats.len(), 10);
+
+	let ast::Statement::Expr(stat) = &stats[6] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[6]);
+	};
+
+	let ast::Expression::Call(print) = stat.expr() else {
+		panic!()
+	};
+
+	let casts: Vec<_> = print
+		.args()
+		.iter()
+		.map(|arg| arg.cast_spec().unwrap().text().to_string())
+		.collect();
+
+	assert_eq!(casts, ["s", "n", "d", "l"]);
+
+	let ast::Statement::Expr(stat) = &stats[7] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[7]);
+	};
+
+	let ast::Expression::Call(hudmessage) = stat.expr() else {
+		panic!()
+	};
+
+	assert_eq!(hudmessage.args().head().count(), 1);
+	assert_eq!(hudmessage.args().tail().count(), 6);
+
+	let ast::Statement::Expr(stat) = &stats[8] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[8]);
+	};
+
+	let ast::Expression::Call(door_open) = stat.expr() else {
+		panic!()
+	};
+
+	assert!(door_open.args().is_const());
+	assert!(matches!(stats[9], ast::Statement::Restart(_)));
+
+	let ast::Root::Script(script) = &roots[11] else {
+		panic!("Expected a script, found: {:#?}", roots[11]);
+	};
+
+	let ast::Expression::Literal(lit) = script.number() else {
+		panic!()
+	};
+
+	assert_eq!(lit.token().string(), Some("Named"));
+	assert_eq!(script.kind().unwrap().kind(), Syn::KwEnter);
+	assert!(script.is_net());
+	assert!(script.is_clientside());
+	assert_eq!(script.params().unwrap().iter().count(), 2);
+
+	let ast::Root::Function(func) = &roots[13] else {
+		panic!("Expected a function, found: {:#?}", roots[13]);
+	};
+
+	assert_eq!(func.name().text(), "Double");
+	assert_eq!(func.return_type().kind(), Syn::KwInt);
+
+	let ast::Root::Function(func) = &roots[14] else {
+		panic!("Expected a function, found: {:#?}", roots[14]);
+	};
+
+	assert!(func.params().is_void());
+}
+
+#[test]
+fn expr_precedence() {
+	const SOURCE: &str = r#"
+script 1 (void) {
+	x = a + b * c << 1 | d & e;
+	y = cond ? -1 : 'z';
+}
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+
+	let ast::Root::Script(script) = pt.ast().next().unwrap() else {
+		unreachable!()
+	};
+
+	let stats: Vec<_> = script.body().statements().collect();
+
+	let ast::Statement::Expr(stat) = &stats[0] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[0]);
+	};
+
+	let ast::Expression::Binary(assign) = stat.expr() else {
+		panic!()
+	};
+
+	assert!(assign.is_assign());
+
+	let ast::Expression::Binary(or) = assign.right() else {
+		panic!()
+	};
+
+	assert_eq!(or.operator().kind(), Syn::Pipe);
+
+	let ast::Expression::Binary(shift) = or.left() else {
+		panic!()
+	};
+
+	assert_eq!(shift.operator().kind(), Syn::AngleL2);
+
+	let ast::Expression::Binary(add) = shift.left() else {
+		panic!()
+	};
+
+	assert_eq!(add.operator().kind(), Syn::Plus);
+
+	let ast::Statement::Expr(stat) = &stats[1] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[1]);
+	};
+
+	let ast::Expression::Binary(assign) = stat.expr() else {
+		panic!()
+	};
+
+	let ast::Expression::Ternary(ternary) = assign.right() else {
+		panic!()
+	};
+
+	let ast::Expression::Literal(lit) = ternary.if_false() else {
+		panic!()
+	};
+
+	assert_eq!(lit.token().char(), Some('z'));
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+script 1 (void) {
+	int x = ;
+	x = 1;
+}
+
+this is not valid;
+
+function void Fine(void) {}
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert!(pt.any_errors());
+	let pt = ParseTree::new(pt);
+	let roots: Vec<_> = pt.ast().collect();
+
+	assert_eq!(roots.len(), 2);
+	assert!(matches!(roots[1], ast::Root::Function(_)));
+	assert!(pt.zipper().text().to_string().contains("this is not valid"));
+}
+
+#[test]
+fn include_tree() {
+	const ROOT: &str = r#"
+#include "zcommon.acs"
+#import "lib.acs"
+"#;
+
+	const LIB: &str = r#"
+#library "lib"
+#include "zcommon.acs"
+"#;
+
+	let tree = parse_include_tree("map01.acs", |path| match path {
+		"map01.acs" => Some(ROOT.to_string()),
+		"zcommon.acs" => Some("#define TRUE 1\n".to_string()),
+		"lib.acs" => Some(LIB.to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	let paths: Vec<_> = tree.files.iter().map(|file| file.path()).collect();
+	assert_eq!(paths, ["map01.acs", "zcommon.acs", "lib.acs"]);
+	assert!(tree.files.iter().all(|file| !file.any_errors()));
+}
+
+#[test]
+fn preprocess() {
+	const ROOT: &str = r#"#include "zcommon.acs"
+#import "lib.acs"
+#define LOOP_MAX (ROUNDABOUT + 1) // Comment.
+
+script 1 ENTER {
+	for (int i = 0; i < LOOP_MAX; i++) {
+		Print(s: GREETING);
+	}
+
+	Delay(TICRATE * LIB_PRIVATE);
+}
+"#;
+
+	const ZCOMMON: &str = "#include \"zdefs.acs\"\n";
+
+	const ZDEFS: &str = "#define TICRATE 35\n#define GREETING \"Hello\"\n";
+
+	const LIB: &str = r#"#library "lib"
+#include "zcommon.acs"
+#libdefine ROUNDABOUT 4 * 2
+#define LIB_PRIVATE 3
+"#;
+
+	let pp = super::preprocess("maps/map01.acs", &["acs"], |path| match path {
+		"maps/map01.acs" => Some(ROOT.to_string()),
+		"acs/zcommon.acs" => Some(ZCOMMON.to_string()),
+		"acs/zdefs.acs" => Some(ZDEFS.to_string()),
+		"maps/lib.acs" => Some(LIB.to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	let paths: Vec<_> = pp.files.iter().map(|file| file.path.as_str()).collect();
+	assert_eq!(
+		paths,
+		[
+			"maps/map01.acs",
+			"acs/zcommon.acs",
+			"acs/zdefs.acs",
+			"maps/lib.acs"
+		]
+	);
+	assert!(pp.files[3].imported);
+	assert_eq!(pp.defines.len(), 5);
+	assert!(pp.issues.is_empty(), "{:#?}", pp.issues);
+
+	assert!(pp.source.contains("i < ((4 * 2) + 1);"));
+	assert!(pp.source.contains("Print(s: \"Hello\");"));
+	// `#define`s in imported libraries are not visible to their importers.
+	assert!(pp.source.contains("Delay(35 * LIB_PRIVATE);"));
+	assert!(!pp.source.contains("#define"));
+
+	let tree = pp.parse().unwrap();
+	assert_no_errors(&tree);
+
+	// Spans in expanded code point back to the constant's name.
+	let offs = pp.source.find("((4 * 2) + 1)").unwrap();
+	let loc = pp
+		.locate(rowan::TextRange::at(
+			rowan::TextSize::from(offs as u32),
+			rowan::TextSize::from(3),
+		))
+		.unwrap();
+	assert_eq!(loc.file, 0);
+	assert_eq!((loc.line, loc.column), (6, 22));
+	assert_eq!(&ROOT[loc.range], "LOOP_MAX");
+}
+
+#[test]
+fn preprocess_diagnostics() {
+	const ROOT: &str = r#"#include "defs.acs"
+#include "missing.acs"
+#define CYCLE_A CYCLE_B
+#define CYCLE_B CYCLE_A
+
+script 1 OPEN {
+	int x = CYCLE_A;
+}
+"#;
+
+	const DEFS: &str = r#"#define ONE 1
+
+function void broken(void) {
+	int y = ONE +;
+}
+"#;
+
+	let pp = super::preprocess("root.acs", &[], |path| match path {
+		"root.acs" => Some(ROOT.to_string()),
+		"defs.acs" => Some(DEFS.to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	assert_eq!(pp.issues.len(), 2, "{:#?}", pp.issues);
+	let (file, missing) = &pp.issues[0];
+	assert_eq!(*file, 0);
+	assert_eq!(&ROOT[missing.range], "\"missing.acs\"");
+	let (file, cycle) = &pp.issues[1];
+	assert_eq!(*file, 0);
+	assert_eq!(&ROOT[cycle.range], "CYCLE_A");
+
+	let tree = pp.parse().unwrap();
+	let errors = pp.map_parse_errors(&tree);
+	assert_eq!(errors.len(), 1, "{errors:#?}");
+	let (file, err) = &errors[0];
+	assert_eq!(pp.files[*file].path, "defs.acs");
+
+	// The error is on line 4 of the included file.
+	let line = DEFS[..usize::from(err.range.start())].matches('\n').count() + 1;
+	assert_eq!(line, 4);
+}
+
+#[test]
+fn compile() {
+	const LIB: &str = r#"#library "mylib"
+function int Twice(int x) { return x * 2; }
+int libvar;
+"#;
+
+	const SOURCE: &str = r#"
+special 80:ACS_Execute(2, 5);
+#define ANSWER 42
+
+int counter = 7, limit = ANSWER;
+int table[3] = { 1, 2, 3 };
+str greeting = "hello";
+
+function void Greet(int who) {
+	Print(s: greeting, d: who);
+}
+
+script 1 OPEN {
+	for (int i = 0; i < 3; i++) {
+		counter += table[i];
+	}
+
+	Greet(Twice(limit));
+	ACS_Execute(2, 0);
+	Delay(const: 35);
+}
+
+script "named" (int a) NET CLIENTSIDE {
+	switch (a) {
+	case 1:
+		terminate;
+	default:
+		break;
+	}
+
+	libvar = a;
+}
+"#;
+
+	let lib = ParseTree::new(parse(LIB).unwrap());
+	let tree = ParseTree::new(parse(SOURCE).unwrap());
+	let object = super::compile(&tree, &[&lib]).unwrap();
+	let obj = behavior::Object::read(&object).unwrap();
+
+	assert_eq!(&object[0..4], b"ACSe");
+
+	let listing: Vec<_> = obj
+		.disassemble(8)
+		.unwrap()
+		.into_iter()
+		.chain(obj.disassemble(53).unwrap())
+		.chain(obj.disassemble(91).unwrap())
+		.map(|instr| instr.to_string())
+		.collect();
+
+	assert_eq!(
+		listing,
+		[
+			// Script 1.
+			"8: PUSHBYTE 0",
+			"10: ASSIGNSCRIPTVAR 0",
+			"12: PUSHSCRIPTVAR 0",
+			"14: PUSHBYTE 3",
+			"16: LT",
+			"17: IFNOTGOTO 35",
+			"22: PUSHSCRIPTVAR 0",
+			"24: PUSHMAPARRAY 3",
+			"26: ADDMAPVAR 1",
+			"28: INCSCRIPTVAR 0",
+			"30: GOTO 12",
+			"35: PUSHMAPVAR 2",
+			"37: CALL 0",
+			"39: CALLDISCARD 1",
+			"41: PUSHBYTE 2",
+			"43: PUSHBYTE 0",
+			"45: LSPEC2 80",
+			"47: DELAYDIRECT 35",
+			"52: TERMINATE",
+			// Script "named".
+			"53: PUSHSCRIPTVAR 0",
+			"55: GOTO 71",
+			"60: TERMINATE",
+			"61: GOTO 86",
+			"66: GOTO 86",
+			"71: CASEGOTO 1 60",
+			"80: DROP",
+			"81: GOTO 61",
+			"86: PUSHSCRIPTVAR 0",
+			"88: ASSIGNMAPVAR 0",
+			"90: TERMINATE",
+			// Function `Greet`.
+			"91: BEGINPRINT",
+			"92: PUSHMAPVAR 4",
+			"94: PRINTSTRING",
+			"95: PUSHSCRIPTVAR 0",
+			"97: PRINTNUMBER",
+			"98: ENDPRINT",
+			"99: RETURNVOID",
+		]
+	);
+
+	assert_eq!(
+		obj.chunk(b"SPTR").unwrap().data,
+		[1, 0, 1, 0, 8, 0, 0, 0, 0xFF, 0xFF, 0, 1, 53, 0, 0, 0]
+	);
+	assert_eq!(obj.chunk(b"SFLG").unwrap().data, [0xFF, 0xFF, 3, 0]);
+	assert_eq!(
+		obj.chunk(b"FUNC").unwrap().data,
+		[1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 91, 0, 0, 0]
+	);
+	assert!(obj
+		.chunk(b"FNAM")
+		.unwrap()
+		.data
+		.ends_with(b"Twice\0Greet\0"));
+	assert!(obj.chunk(b"STRL").unwrap().data.ends_with(b"hello\0"));
+	assert!(obj.chunk(b"SNAM").unwrap().data.ends_with(b"named\0"));
+	assert_eq!(
+		obj.chunk(b"MINI").unwrap().data,
+		[1, 0, 0, 0, 7, 0, 0, 0, 42, 0, 0, 0]
+	);
+	assert_eq!(obj.chunk(b"ARAY").unwrap().data, [3, 0, 0, 0, 3, 0, 0, 0]);
+	assert_eq!(obj.chunk(b"MSTR").unwrap().data, [4, 0, 0, 0]);
+	assert_eq!(obj.chunk(b"MIMP").unwrap().data, b"\0\0\0\0libvar\0");
+	assert_eq!(obj.chunk(b"LOAD").unwrap().data, b"mylib\0");
+}
+
+#[test]
+fn compile_errors() {
+	const SOURCE: &str = r#"
+int x;
+
+function void Nothing(void) {
+	return 1;
+}
+
+script 1 (void) {
+	int x = y;
+	Nothing(1);
+	break;
+}
+
+script 1 (void) {}
+"#;
+
+	let tree = ParseTree::new(parse(SOURCE).unwrap());
+	let issues = super::compile(&tree, &[]).unwrap_err();
+
+	let found: Vec<_> = issues
+		.iter()
+		.map(|issue| (&SOURCE[issue.range], issue.message.as_str()))
+		.collect();
+
+	assert_eq!(
+		found,
+		[
+			("1", "script 1 is already defined"),
+			("y", "`y` is not declared"),
+			("Nothing(1)", "`Nothing` takes 0 argument(s), not 1"),
+			("break;", "`break` outside of a loop or `switch`"),
+			// Functions are compiled after scripts.
+			("1", "`void` functions cannot return a value"),
+		]
+	);
+}
+
+#[test]
+fn behavior() {
+	const SOURCE: &str = r#"
+#nocompact
+special 80:ACS_Execute(2, 5), -19:CheckActorClass(2);
+
+int counter = 7;
+str names[2] = { "alpha", "beta" };
+
+function int Sum(int a, int b) {
+	return a + b;
+}
+
+script 1 OPEN {
+	int i = 0;
+
+	while (i < 10) {
+		if (i % 2) {
+			i++;
+			continue;
+		} else if (i == 4) {
+			break;
+		}
+
+		counter += Sum(i, 1);
+		i++;
+	}
+
+	do {
+		i--;
+	} until (i <= 0);
+
+	for (int j = 0; j < 3; j++) {
+		if (j == 1) continue;
+		Print(s: names[j], d: j);
+	}
+
+	switch (counter) {
+	case 1:
+	case 2:
+		ACS_Execute(2, 0);
+		break;
+	default:
+		// Arguments to specials are untyped, so this avoids a string.
+		counter = CheckActorClass(0, 5) ? -1 : 1;
+	}
+
+	HudMessage(s: "Hi"; 0, 1, 0, 0.5, 0.5, 2.0);
+}
+
+script "Other" (int a) NET {
+	restart;
+}
+"#;
+
+	let tree = ParseTree::new(parse(SOURCE).unwrap());
+	let object = super::compile(&tree, &[]).unwrap();
+	let obj = behavior::Object::read(&object).unwrap();
+
+	assert_eq!(obj.format, behavior::Format::Enhanced);
+	assert_eq!(obj.scripts.len(), 2);
+	assert_eq!(obj.scripts[1].name.as_deref(), Some("Other"));
+	assert_eq!(obj.scripts[1].flags, 1);
+	assert_eq!(obj.functions[0].name.as_deref(), Some("Sum"));
+	assert!(obj.functions[0].returns);
+	assert_eq!(obj.strings, ["alpha", "beta", "Hi"]);
+	assert_eq!(obj.map_vars[0].init, [7]);
+	assert_eq!(obj.map_vars[1].size, Some(2));
+	assert!(obj.map_vars[1].is_str);
+
+	let listing = obj.listing().unwrap();
+	assert!(listing.contains("; string 2: \"Hi\""));
+	assert!(listing.contains("function 0 Sum (2 arg(s), 0 local(s), returns)"));
+
+	let source = obj.decompile().unwrap();
+	assert!(!source.contains("Could not decompile"), "{source}");
+
+	let redone = ParseTree::new(parse_recov(&source).unwrap());
+	assert_no_errors(&redone);
+
+	// Decompiling loses names, but not behavior.
+	let recompiled = super::compile(&redone, &[]).unwrap();
+	let obj2 = behavior::Object::read(&recompiled).unwrap();
+
+	for (s1, s2) in obj.scripts.iter().zip(&obj2.scripts) {
+		assert_eq!(
+			obj.disassemble(s1.address).unwrap(),
+			obj2.disassemble(s2.address).unwrap(),
+			"{source}"
+		);
+	}
+}
+
+#[test]
+fn behavior_hexen() {
+	let mut object = b"ACS\0\0\0\0\0".to_vec();
+
+	for n in [
+		pcode::Pcode::PushNumber as i32,
+		35,
+		pcode::Pcode::Delay as i32,
+		pcode::Pcode::BeginPrint as i32,
+		pcode::Pcode::PushNumber as i32,
+		0,
+		pcode::Pcode::PrintString as i32,
+		pcode::Pcode::EndPrint as i32,
+		pcode::Pcode::Terminate as i32,
+	] {
+		object.extend(n.to_le_bytes());
+	}
+
+	let dir = object.len() as u32;
+	object[4..8].copy_from_slice(&dir.to_le_bytes());
+	// One `OPEN` script numbered 2, taking no arguments, starting at 8.
+	for n in [1, 1002, 8, 0, 1, dir + 24] {
+		object.extend(u32::to_le_bytes(n));
+	}
+	object.extend(b"Hexen\0");
+
+	let obj = behavior::Object::read(&object).unwrap();
+	assert_eq!(obj.format, behavior::Format::Hexen);
+	assert_eq!((obj.scripts[0].number, obj.scripts[0].kind), (2, 1));
+	assert_eq!(obj.strings, ["Hexen"]);
+
+	let source = obj.decompile().unwrap();
+	assert!(
+		source.contains("script 2 OPEN {\n\tDelay(35);\n\tPrint(s: \"Hexen\");\n}"),
+		"{source}"
+	);
+	assert_no_errors(&parse_recov(&source).unwrap());
+}
+
+#[test]
+fn behavior_malformed() {
+	/// An `ACSE` object with the given code, followed by one chunk.
+	fn object(code: &[u8], id: &[u8; 4], data: &[u8]) -> Vec<u8> {
+		let mut ret = b"ACSE".to_vec();
+		ret.extend(u32::to_le_bytes(8 + code.len() as u32));
+		ret.extend(code);
+		ret.extend(id);
+		ret.extend(u32::to_le_bytes(data.len() as u32));
+		ret.extend(data);
+		ret
+	}
+
+	let short_aini = object(&[], b"AINI", &[0, 0]);
+	assert_eq!(
+		behavior::Object::read(&short_aini).unwrap_err(),
+		behavior::ReadError::Truncated { offset: 0 }
+	);
+
+	let mut mini = u32::MAX.to_le_bytes().to_vec();
+	mini.extend(i32::to_le_bytes(1));
+	mini.extend(i32::to_le_bytes(2));
+	assert_eq!(
+		behavior::Object::read(&object(&[], b"MINI", &mini)).unwrap_err(),
+		behavior::ReadError::Overflow { offset: 16 }
+	);
+
+	let mut code = (pcode::Pcode::CaseGotoSorted as i32).to_le_bytes().to_vec();
+	code.extend(i32::MAX.to_le_bytes());
+	let obj = behavior::Object::read(&object(&code, b"SPTR", &[])).unwrap();
+	assert_eq!(
+		obj.instruction(8).unwrap_err(),
+		behavior::ReadError::Overflow { offset: 12 }
+	);
+
+	// A sorted case list claiming more entries than there is data.
+	let mut code = (pcode::Pcode::CaseGotoSorted as i32).to_le_bytes().to_vec();
+	code.extend(1000_i32.to_le_bytes());
+	let obj = behavior::Object::read(&object(&code, b"SPTR", &[])).unwrap();
+	assert!(matches!(
+		obj.instruction(8),
+		Err(behavior::ReadError::Truncated { .. })
+	));
+}
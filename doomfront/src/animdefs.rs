@@ -0,0 +1,25 @@
+//! Parser, syntax trees, and animation playback for
+//! [ANIMDEFS](https://zdoom.org/wiki/ANIMDEFS), the lump which ZDoom-family
+//! source ports use to define animated textures and flats, switches, warping
+//! surfaces, camera textures, animated doors, and sky offsets.
+//!
+//! ANIMDEFS ignores line breaks; each definition starts with a keyword and runs
+//! until the next one. [`Animation`] models a texture or flat animation, and its
+//! [`Timeline`] tells which frame is shown at any given game tic.
+
+pub mod ast;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+mod timeline;
+
+pub use parse::*;
+pub use syn::*;
+pub use timeline::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,433 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Anim(AnimDef),
+	AnimatedDoor(AnimatedDoorDef),
+	CameraTexture(CameraTextureDef),
+	SkyOffset(SkyOffsetDef),
+	Switch(SwitchDef),
+	Warp(WarpDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::AnimDef
+				| Syn::AnimatedDoorDef
+				| Syn::CameraTextureDef
+				| Syn::SkyOffsetDef
+				| Syn::SwitchDef
+				| Syn::WarpDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::AnimDef => Some(Self::Anim(AnimDef(node))),
+			Syn::AnimatedDoorDef => Some(Self::AnimatedDoor(AnimatedDoorDef(node))),
+			Syn::CameraTextureDef => Some(Self::CameraTexture(CameraTextureDef(node))),
+			Syn::SkyOffsetDef => Some(Self::SkyOffset(SkyOffsetDef(node))),
+			Syn::SwitchDef => Some(Self::Switch(SwitchDef(node))),
+			Syn::WarpDef => Some(Self::Warp(WarpDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Anim(inner) => &inner.0,
+			Self::AnimatedDoor(inner) => &inner.0,
+			Self::CameraTexture(inner) => &inner.0,
+			Self::SkyOffset(inner) => &inner.0,
+			Self::Switch(inner) => &inner.0,
+			Self::Warp(inner) => &inner.0,
+		}
+	}
+}
+
+/// Every direct child token of `node` which is not trivia.
+fn tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.filter(|token| !matches!(token.kind(), Syn::Whitespace | Syn::Comment))
+}
+
+/// The first name among the direct children of `node`.
+fn name(node: &SyntaxNode) -> Name {
+	tokens(node).find_map(Name::new).unwrap()
+}
+
+/// The token right after the first direct child token of `node` tagged `kw`.
+fn after(node: &SyntaxNode, kw: Syn) -> Option<SyntaxToken> {
+	tokens(node).skip_while(|t| t.kind() != kw).nth(1)
+}
+
+/// Returns `true` if `node` has a direct child token tagged `kw`.
+fn has(node: &SyntaxNode, kw: Syn) -> bool {
+	tokens(node).any(|t| t.kind() == kw)
+}
+
+fn frames(node: &SyntaxNode) -> impl Iterator<Item = FrameDef> {
+	node.children().filter_map(FrameDef::cast)
+}
+
+/// Wraps a node tagged [`Syn::AnimDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AnimDef(SyntaxNode);
+
+simple_astnode!(Syn, AnimDef, Syn::AnimDef);
+
+impl AnimDef {
+	/// Either [`Syn::KwFlat`] or [`Syn::KwTexture`].
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `true` if the keyword `optional` follows the kind, in which case
+	/// a missing base texture is not an error.
+	#[must_use]
+	pub fn is_optional(&self) -> bool {
+		has(&self.0, Syn::KwOptional)
+	}
+
+	/// The base texture or flat, which is also the first frame.
+	#[must_use]
+	pub fn name(&self) -> Name {
+		name(&self.0)
+	}
+
+	/// Empty if this animation is defined using `range`.
+	pub fn frames(&self) -> impl Iterator<Item = FrameDef> {
+		frames(&self.0)
+	}
+
+	#[must_use]
+	pub fn range(&self) -> Option<RangeDef> {
+		self.0.children().find_map(RangeDef::cast)
+	}
+
+	#[must_use]
+	pub fn is_oscillating(&self) -> bool {
+		has(&self.0, Syn::KwOscillate)
+	}
+
+	#[must_use]
+	pub fn allows_decals(&self) -> bool {
+		has(&self.0, Syn::KwAllowDecals)
+	}
+}
+
+/// Wraps a node tagged [`Syn::FrameDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FrameDef(SyntaxNode);
+
+simple_astnode!(Syn, FrameDef, Syn::FrameDef);
+
+impl FrameDef {
+	/// Either a token tagged [`Syn::LitInt`], counting textures from the base
+	/// one (which is `1`), or a name.
+	#[must_use]
+	pub fn pic(&self) -> SyntaxToken {
+		after(&self.0, Syn::KwPic).unwrap()
+	}
+
+	/// `None` for the frames of an animated door, which have no duration.
+	#[must_use]
+	pub fn duration(&self) -> Option<Duration> {
+		duration(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::RangeDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RangeDef(SyntaxNode);
+
+simple_astnode!(Syn, RangeDef, Syn::RangeDef);
+
+impl RangeDef {
+	/// The final texture of the range. Every texture defined between it and
+	/// the base one becomes a frame.
+	#[must_use]
+	pub fn last(&self) -> Name {
+		name(&self.0)
+	}
+
+	/// The duration of every frame in the range.
+	#[must_use]
+	pub fn duration(&self) -> Duration {
+		duration(&self.0).unwrap()
+	}
+}
+
+/// How long a frame is shown, as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Duration {
+	/// `tics N`.
+	Tics(SyntaxToken),
+	/// `rand MIN MAX`.
+	Rand(SyntaxToken, SyntaxToken),
+}
+
+fn duration(node: &SyntaxNode) -> Option<Duration> {
+	let mut tokens = tokens(node).skip_while(|t| !matches!(t.kind(), Syn::KwTics | Syn::KwRand));
+	let keyword = tokens.next()?;
+
+	if keyword.kind() == Syn::KwTics {
+		Some(Duration::Tics(tokens.next()?))
+	} else {
+		Some(Duration::Rand(tokens.next()?, tokens.next()?))
+	}
+}
+
+/// Wraps a node tagged [`Syn::SwitchDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SwitchDef(SyntaxNode);
+
+simple_astnode!(Syn, SwitchDef, Syn::SwitchDef);
+
+impl SwitchDef {
+	/// e.g. `doom` or `any`, if given.
+	#[must_use]
+	pub fn game(&self) -> Option<SyntaxToken> {
+		let mut names = tokens(&self.0).filter(|t| Name::new(t.clone()).is_some());
+		let first = names.next()?;
+		names.next().is_some().then_some(first)
+	}
+
+	/// The number following the game (e.g. the `1` in `switch doom 1`), if any.
+	#[must_use]
+	pub fn game_number(&self) -> Option<SyntaxToken> {
+		tokens(&self.0).find(|t| t.kind() == Syn::LitInt)
+	}
+
+	/// The texture which the switch starts out as.
+	#[must_use]
+	pub fn name(&self) -> Name {
+		tokens(&self.0).filter_map(Name::new).last().unwrap()
+	}
+
+	/// The frames shown when the switch gets activated.
+	#[must_use]
+	pub fn on(&self) -> SwitchState {
+		self.0.children().find_map(SwitchState::cast).unwrap()
+	}
+
+	/// The frames shown when the switch returns to its original state, if given.
+	#[must_use]
+	pub fn off(&self) -> Option<SwitchState> {
+		self.0.children().filter_map(SwitchState::cast).nth(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::SwitchState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SwitchState(SyntaxNode);
+
+simple_astnode!(Syn, SwitchState, Syn::SwitchState);
+
+impl SwitchState {
+	/// Either [`Syn::KwOn`] or [`Syn::KwOff`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// If more than one sound is given, the last one takes precedence.
+	#[must_use]
+	pub fn sound(&self) -> Option<Name> {
+		let mut tokens = tokens(&self.0);
+		let mut ret = None;
+
+		while tokens.any(|t| t.kind() == Syn::KwSound) {
+			ret = tokens.next().and_then(Name::new).or(ret);
+		}
+
+		ret
+	}
+
+	pub fn frames(&self) -> impl Iterator<Item = FrameDef> {
+		frames(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::WarpDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct WarpDef(SyntaxNode);
+
+simple_astnode!(Syn, WarpDef, Syn::WarpDef);
+
+impl WarpDef {
+	/// Either [`Syn::KwWarp`] or [`Syn::KwWarp2`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Either [`Syn::KwFlat`] or [`Syn::KwTexture`].
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		tokens(&self.0).nth(1).unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> Name {
+		name(&self.0)
+	}
+
+	/// A token tagged [`Syn::LitInt`] or [`Syn::LitFloat`], if given.
+	#[must_use]
+	pub fn speed(&self) -> Option<SyntaxToken> {
+		after(&self.0, Syn::KwSpeed)
+	}
+
+	#[must_use]
+	pub fn allows_decals(&self) -> bool {
+		has(&self.0, Syn::KwAllowDecals)
+	}
+}
+
+/// Wraps a node tagged [`Syn::CameraTextureDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CameraTextureDef(SyntaxNode);
+
+simple_astnode!(Syn, CameraTextureDef, Syn::CameraTextureDef);
+
+impl CameraTextureDef {
+	#[must_use]
+	pub fn name(&self) -> Name {
+		name(&self.0)
+	}
+
+	/// The width and height of the rendered view, in pixels.
+	#[must_use]
+	pub fn size(&self) -> (SyntaxToken, SyntaxToken) {
+		let mut ints = tokens(&self.0).filter(|t| t.kind() == Syn::LitInt);
+		(ints.next().unwrap(), ints.next().unwrap())
+	}
+
+	/// The width and height to scale the view to, if given.
+	#[must_use]
+	pub fn fit(&self) -> Option<(SyntaxToken, SyntaxToken)> {
+		let mut ints = tokens(&self.0)
+			.skip_while(|t| t.kind() != Syn::KwFit)
+			.filter(|t| t.kind() == Syn::LitInt);
+
+		Some((ints.next()?, ints.next()?))
+	}
+
+	#[must_use]
+	pub fn world_panning(&self) -> bool {
+		has(&self.0, Syn::KwWorldPanning)
+	}
+}
+
+/// Wraps a node tagged [`Syn::AnimatedDoorDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AnimatedDoorDef(SyntaxNode);
+
+simple_astnode!(Syn, AnimatedDoorDef, Syn::AnimatedDoorDef);
+
+impl AnimatedDoorDef {
+	#[must_use]
+	pub fn name(&self) -> Name {
+		name(&self.0)
+	}
+
+	#[must_use]
+	pub fn open_sound(&self) -> Option<Name> {
+		after(&self.0, Syn::KwOpenSound).and_then(Name::new)
+	}
+
+	#[must_use]
+	pub fn close_sound(&self) -> Option<Name> {
+		after(&self.0, Syn::KwCloseSound).and_then(Name::new)
+	}
+
+	/// The frames shown while opening, in order; closing plays them backwards.
+	/// None of these have a duration.
+	pub fn frames(&self) -> impl Iterator<Item = FrameDef> {
+		frames(&self.0)
+	}
+
+	#[must_use]
+	pub fn allows_decals(&self) -> bool {
+		has(&self.0, Syn::KwAllowDecals)
+	}
+}
+
+/// Wraps a node tagged [`Syn::SkyOffsetDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SkyOffsetDef(SyntaxNode);
+
+simple_astnode!(Syn, SkyOffsetDef, Syn::SkyOffsetDef);
+
+impl SkyOffsetDef {
+	#[must_use]
+	pub fn name(&self) -> Name {
+		name(&self.0)
+	}
+
+	/// The token tagged [`Syn::LitInt`] giving the vertical offset.
+	#[must_use]
+	pub fn offset(&self) -> SyntaxToken {
+		tokens(&self.0).find(|t| t.kind() == Syn::LitInt).unwrap()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] tagged [`Syn::Ident`] or [`Syn::LitString`],
+/// with convenience functions for interpreting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Name(SyntaxToken);
+
+impl Name {
+	#[must_use]
+	pub(super) fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(token.kind(), Syn::Ident | Syn::LitString).then_some(Self(token))
+	}
+
+	/// A string literal's content without its quotation marks,
+	/// or a name verbatim.
+	#[must_use]
+	pub fn text(&self) -> &str {
+		let text = self.0.text();
+
+		if self.0.kind() == Syn::LitString {
+			&text[1..(text.len() - 1)]
+		} else {
+			text
+		}
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
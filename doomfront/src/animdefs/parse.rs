@@ -0,0 +1,341 @@
+use chumsky::{primitive, recovery, Error, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line. All skipped input gets wrapped into
+/// a token tagged [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		anim_def(src),
+		switch_def(src),
+		warp_def(src),
+		camera_texture_def(src),
+		animated_door_def(src),
+		sky_offset_def(src),
+	))
+}
+
+fn anim_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let item = primitive::choice((
+		frame_def(src),
+		range_def(src),
+		comb::kw_word::<Syn, _>(word(), "oscillate", Syn::KwOscillate, src),
+		comb::kw_word::<Syn, _>(word(), "allowdecals", Syn::KwAllowDecals, src),
+	));
+
+	flat_or_texture(src)
+		.start_vec()
+		.chain_append(
+			spaced(
+				src,
+				comb::kw_word::<Syn, _>(word(), "optional", Syn::KwOptional, src),
+			)
+			.or_not()
+			.flatten(),
+		)
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, item).repeated().flatten())
+		.collect_n::<Syn, { Syn::AnimDef as u16 }>()
+		.labelled("animation definition")
+}
+
+/// `pic PIC` followed by a duration.
+fn frame_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	pic(src)
+		.chain_append(duration(src))
+		.collect_n::<Syn, { Syn::FrameDef as u16 }>()
+		.labelled("frame")
+}
+
+fn range_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw_word::<Syn, _>(word(), "range", Syn::KwRange, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(duration(src))
+		.collect_n::<Syn, { Syn::RangeDef as u16 }>()
+		.labelled("range")
+}
+
+/// `pic` followed by a frame number or a name.
+fn pic(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	comb::kw_word::<Syn, _>(word(), "pic", Syn::KwPic, src)
+		.start_vec()
+		.chain_append(spaced(src, primitive::choice((int_lit(src), name(src)))))
+}
+
+/// `tics N` or `rand MIN MAX`, preceded by trivia.
+fn duration(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let tics = comb::kw_word::<Syn, _>(word(), "tics", Syn::KwTics, src)
+		.start_vec()
+		.chain_append(spaced(src, int_lit(src)));
+
+	let rand = comb::kw_word::<Syn, _>(word(), "rand", Syn::KwRand, src)
+		.start_vec()
+		.chain_append(spaced(src, int_lit(src)))
+		.chain_append(spaced(src, int_lit(src)));
+
+	trivia(src).chain_append(primitive::choice((tics, rand)))
+}
+
+fn switch_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	const GAMES: &[&str] = &["any", "doom", "heretic", "hexen", "strife"];
+
+	let game = word()
+		.try_map(|word, span| {
+			if GAMES.iter().any(|g| g.eq_ignore_ascii_case(&word)) {
+				Ok(word)
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	// Like GZDoom, accept a sound anywhere among the frames.
+	let sound = spaced(
+		src,
+		comb::kw_word::<Syn, _>(word(), "sound", Syn::KwSound, src),
+	)
+	.chain_append(spaced(src, name(src)));
+
+	let state = move |keyword, syn| {
+		comb::kw_word::<Syn, _>(word(), keyword, syn, src)
+			.start_vec()
+			.chain_append(
+				primitive::choice((sound.clone(), spaced(src, frame_def(src))))
+					.repeated()
+					.flatten(),
+			)
+			.collect_n::<Syn, { Syn::SwitchState as u16 }>()
+	};
+
+	// The game may be followed by a number, which must not be mistaken for
+	// the name of the texture.
+	let header = primitive::choice((
+		spaced(src, game.clone())
+			.chain_append(spaced(src, int_lit(src)))
+			.chain_append(spaced(src, name(src))),
+		spaced(src, game).chain_append(spaced(src, name(src))),
+		spaced(src, name(src)),
+	));
+
+	comb::kw_word::<Syn, _>(word(), "switch", Syn::KwSwitch, src)
+		.start_vec()
+		.chain_append(header)
+		.chain_append(spaced(src, state("on", Syn::KwOn)))
+		.chain_append(spaced(src, state("off", Syn::KwOff)).or_not().flatten())
+		.collect_n::<Syn, { Syn::SwitchDef as u16 }>()
+		.labelled("switch definition")
+}
+
+fn warp_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let speed = comb::kw_word::<Syn, _>(word(), "speed", Syn::KwSpeed, src)
+		.start_vec()
+		.chain_append(spaced(src, number(src)));
+
+	let item = primitive::choice((
+		speed,
+		comb::kw_word::<Syn, _>(word(), "allowdecals", Syn::KwAllowDecals, src).start_vec(),
+	));
+
+	primitive::choice((
+		comb::kw_word::<Syn, _>(word(), "warp", Syn::KwWarp, src),
+		comb::kw_word::<Syn, _>(word(), "warp2", Syn::KwWarp2, src),
+	))
+	.start_vec()
+	.chain_append(spaced(src, flat_or_texture(src)))
+	.chain_append(spaced(src, name(src)))
+	.chain_append(trivia(src).chain_append(item).repeated().flatten())
+	.collect_n::<Syn, { Syn::WarpDef as u16 }>()
+	.labelled("warp definition")
+}
+
+fn camera_texture_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let fit = comb::kw_word::<Syn, _>(word(), "fit", Syn::KwFit, src)
+		.start_vec()
+		.chain_append(spaced(src, int_lit(src)))
+		.chain_append(spaced(src, int_lit(src)));
+
+	comb::kw_word::<Syn, _>(word(), "cameratexture", Syn::KwCameraTexture, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, int_lit(src)))
+		.chain_append(spaced(src, int_lit(src)))
+		.chain_append(trivia(src).chain_append(fit).or_not().flatten())
+		.chain_append(
+			spaced(
+				src,
+				comb::kw_word::<Syn, _>(word(), "worldpanning", Syn::KwWorldPanning, src),
+			)
+			.or_not()
+			.flatten(),
+		)
+		.collect_n::<Syn, { Syn::CameraTextureDef as u16 }>()
+		.labelled("camera texture definition")
+}
+
+fn animated_door_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let sound = |keyword, syn| {
+		comb::kw_word::<Syn, _>(word(), keyword, syn, src)
+			.start_vec()
+			.chain_append(spaced(src, name(src)))
+	};
+
+	let item = primitive::choice((
+		sound("opensound", Syn::KwOpenSound),
+		sound("closesound", Syn::KwCloseSound),
+		pic(src)
+			.collect_n::<Syn, { Syn::FrameDef as u16 }>()
+			.start_vec(),
+		comb::kw_word::<Syn, _>(word(), "allowdecals", Syn::KwAllowDecals, src).start_vec(),
+	));
+
+	comb::kw_word::<Syn, _>(word(), "animateddoor", Syn::KwAnimatedDoor, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(trivia(src).chain_append(item).repeated().flatten())
+		.collect_n::<Syn, { Syn::AnimatedDoorDef as u16 }>()
+		.labelled("animated door definition")
+}
+
+fn sky_offset_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw_word::<Syn, _>(word(), "skyoffset", Syn::KwSkyOffset, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, int_lit(src)))
+		.collect_n::<Syn, { Syn::SkyOffsetDef as u16 }>()
+		.labelled("sky offset definition")
+}
+
+fn flat_or_texture(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::kw_word::<Syn, _>(word(), "flat", Syn::KwFlat, src),
+		comb::kw_word::<Syn, _>(word(), "texture", Syn::KwTexture, src),
+	))
+}
+
+/// Trivia, then `parser`.
+fn spaced<'i, P>(
+	src: &'i str,
+	parser: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	trivia(src).chain_push(parser)
+}
+
+/// Anything up to the next whitespace or quotation mark, other than the start
+/// of a comment. Every token besides strings and trivia starts out as one.
+fn word() -> impl Parser<char, String, Error = ParseError> + Clone {
+	primitive::none_of(" \t\r\n\"")
+		.repeated()
+		.at_least(1)
+		.collect::<String>()
+		.try_map(|word, span| {
+			if word.starts_with("//") || word.starts_with("/*") {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(word)
+			}
+		})
+}
+
+fn name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let string = primitive::just('"')
+		.then(primitive::none_of("\"").repeated())
+		.then(primitive::just('"'))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitString))
+		.labelled("string literal");
+
+	primitive::choice((
+		string,
+		word()
+			.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+			.labelled("name"),
+	))
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	word()
+		.try_map(|word, span| {
+			if word.parse::<i64>().is_ok() {
+				Ok(word)
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+/// An integer or floating-point literal.
+fn number(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let float = word()
+		.try_map(|word, span| {
+			if word.contains('.') && word.parse::<f64>().is_ok() {
+				Ok(word)
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal");
+
+	primitive::choice((int_lit(src), float))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,105 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// ANIMDEFS syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `flat NAME ...` or `texture NAME ...`, followed by frames or a range.
+	AnimDef,
+	/// `animateddoor NAME ...`.
+	AnimatedDoorDef,
+	/// `cameratexture NAME WIDTH HEIGHT [fit WIDTH HEIGHT] [worldpanning]`.
+	CameraTextureDef,
+	/// `pic PIC tics N`, `pic PIC rand MIN MAX`, or just `pic PIC` in an
+	/// animated door.
+	FrameDef,
+	/// `range LAST tics N` or `range LAST rand MIN MAX`.
+	RangeDef,
+	/// `skyoffset NAME OFFSET`.
+	SkyOffsetDef,
+	/// `switch [GAME [N]] NAME on ... [off ...]`.
+	SwitchDef,
+	/// `on FRAMES...` or `off FRAMES...` within a switch definition,
+	/// where `sound SOUND` may come before, between, or after the frames.
+	SwitchState,
+	/// `warp <flat|texture> NAME [speed N] [allowdecals]`, or `warp2`.
+	WarpDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwAllowDecals,
+	KwAnimatedDoor,
+	KwCameraTexture,
+	KwCloseSound,
+	KwFit,
+	KwFlat,
+	KwOff,
+	KwOn,
+	KwOpenSound,
+	KwOptional,
+	KwOscillate,
+	KwPic,
+	KwRand,
+	KwRange,
+	KwSkyOffset,
+	KwSound,
+	KwSpeed,
+	KwSwitch,
+	KwTexture,
+	KwTics,
+	KwWarp,
+	KwWarp2,
+	KwWorldPanning,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// ANIMDEFS comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Unquoted texture, flat, and sound names, and the game name in a switch
+	/// definition. May contain any character other than whitespace and `"`.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by ANIMDEFS.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,224 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Doom's own animations, written out long-hand.
+flat NUKAGE1
+	pic 1 tics 8
+	pic 2 tics 8
+	pic NUKAGE3 rand 4 12
+
+texture optional "SLADRIP1" range SLADRIP3 tics 8 allowdecals
+Texture BLODGR1 pic 1 tics 4 pic 2 tics 4 pic 3 tics 4 oscillate
+
+switch doom 1 SW1BRCOM on sound switches/normbutn pic SW2BRCOM tics 0
+switch hexen SW_1_UP
+	on sound Switch1 pic SW_1_MD tics 6 pic SW_1_DN tics 0
+	off pic SW_1_UP tics 0
+switch SW1STON on pic SW2STON tics 0
+
+warp flat FWATER1 speed 2
+warp2 texture "FIREWALL" speed 1.5 allowdecals
+cameratexture CAMTEX 256 128 fit 64 32 worldpanning
+animateddoor DOORTRAK opensound doors/open closesound doors/close
+	pic DOORTRK2 pic DOORTRK3 allowdecals
+skyoffset SKY1 -20
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 11);
+
+	let ast::Root::Anim(nukage) = &roots[0] else {
+		panic!("expected an animation, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(nukage.kind().kind(), Syn::KwFlat);
+	assert_eq!(nukage.name().text(), "NUKAGE1");
+	assert_eq!(nukage.frames().count(), 3);
+
+	let ast::Root::Anim(sladrip) = &roots[1] else {
+		panic!("expected an animation, found: {:#?}", roots[1]);
+	};
+
+	assert!(sladrip.is_optional());
+	assert!(sladrip.allows_decals());
+	assert_eq!(sladrip.name().text(), "SLADRIP1");
+	assert_eq!(sladrip.range().unwrap().last().text(), "SLADRIP3");
+
+	let ast::Root::Switch(brcom) = &roots[3] else {
+		panic!("expected a switch, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(brcom.game().unwrap().text(), "doom");
+	assert_eq!(brcom.game_number().unwrap().text(), "1");
+	assert_eq!(brcom.name().text(), "SW1BRCOM");
+	assert_eq!(brcom.on().sound().unwrap().text(), "switches/normbutn");
+	assert!(brcom.off().is_none());
+
+	let ast::Root::Switch(hexen) = &roots[4] else {
+		panic!("expected a switch, found: {:#?}", roots[4]);
+	};
+
+	assert_eq!(hexen.game().unwrap().text(), "hexen");
+	assert!(hexen.game_number().is_none());
+	assert_eq!(hexen.on().frames().count(), 2);
+	assert_eq!(hexen.off().unwrap().frames().count(), 1);
+
+	let ast::Root::Switch(ston) = &roots[5] else {
+		panic!("expected a switch, found: {:#?}", roots[5]);
+	};
+
+	assert!(ston.game().is_none());
+	assert_eq!(ston.name().text(), "SW1STON");
+
+	let ast::Root::Warp(warp2) = &roots[7] else {
+		panic!("expected a warp, found: {:#?}", roots[7]);
+	};
+
+	assert_eq!(warp2.keyword().kind(), Syn::KwWarp2);
+	assert_eq!(warp2.kind().kind(), Syn::KwTexture);
+	assert_eq!(warp2.name().text(), "FIREWALL");
+	assert_eq!(warp2.speed().unwrap().text(), "1.5");
+	assert!(warp2.allows_decals());
+
+	let ast::Root::CameraTexture(camera) = &roots[8] else {
+		panic!("expected a camera texture, found: {:#?}", roots[8]);
+	};
+
+	assert_eq!(camera.size().0.text(), "256");
+	assert_eq!(camera.fit().unwrap().1.text(), "32");
+	assert!(camera.world_panning());
+
+	let ast::Root::AnimatedDoor(door) = &roots[9] else {
+		panic!("expected an animated door, found: {:#?}", roots[9]);
+	};
+
+	assert_eq!(door.open_sound().unwrap().text(), "doors/open");
+	assert_eq!(door.close_sound().unwrap().text(), "doors/close");
+	assert_eq!(door.frames().count(), 2);
+	assert!(door.frames().all(|f| f.duration().is_none()));
+	assert!(door.allows_decals());
+
+	let ast::Root::SkyOffset(sky) = &roots[10] else {
+		panic!("expected a sky offset, found: {:#?}", roots[10]);
+	};
+
+	assert_eq!(sky.offset().text(), "-20");
+}
+
+#[test]
+fn timeline() {
+	let pt = ParseTree::new(parse_recov(SOURCE).unwrap());
+
+	let anims: Vec<_> = pt
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Anim(anim) => Animation::from_ast(&anim),
+			_ => None,
+		})
+		.collect();
+
+	assert_eq!(anims.len(), 3);
+	assert!(anims[0].flat);
+
+	let Frames::Pics(frames) = &anims[0].frames else {
+		panic!("expected frames given one by one");
+	};
+
+	assert_eq!(frames[0].pic, Pic::Number(1));
+	assert_eq!(frames[2].pic, Pic::Name("NUKAGE3".to_string()));
+	assert_eq!(frames[2].duration, Duration::Rand(4, 12));
+
+	// Random durations are drawn the same way every time.
+	let nukage = anims[0].timeline(0);
+	let shown: Vec<_> = (0..64).map(|tic| nukage.frame_at(tic)).collect();
+	assert_eq!(
+		shown,
+		(0..64).map(|tic| nukage.frame_at(tic)).collect::<Vec<_>>()
+	);
+	assert_eq!(
+		&shown[..17],
+		[0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2]
+	);
+
+	// Random durations repeat, so far-off tics are found without delay.
+	assert!(nukage.frame_at(u64::MAX) < 3);
+
+	// A range of three textures, eight tics each.
+	let sladrip = anims[1].timeline(3);
+	assert_eq!(sladrip.len(), 3);
+	assert_eq!(sladrip.frame_at(0), 0);
+	assert_eq!(sladrip.frame_at(8), 1);
+	assert_eq!(sladrip.frame_at(23), 2);
+	assert_eq!(sladrip.frame_at(24), 0);
+	assert_eq!(sladrip.frame_at(24_000_017), 2);
+
+	// Oscillating: 0 1 2 1 0 1 2 1 ...
+	let blodgr = anims[2].timeline(0);
+
+	let shown: Vec<_> = (0..10).map(|i| blodgr.frame_at(i * 4)).collect();
+	assert_eq!(shown, [0, 1, 2, 1, 0, 1, 2, 1, 0, 1]);
+}
+
+#[test]
+fn switch_sound_order() {
+	const SOURCE: &str = r#"
+switch SW1BRCOM on pic SW2BRCOM tics 0 sound switches/normbutn
+switch SW1GARG
+	on pic SW2GARG tics 4 sound switches/exitbutn pic SW3GARG tics 0
+	off sound switches/normbutn pic SW1GARG tics 0 sound switches/exitbutn
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let switches: Vec<_> = pt
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Switch(switch) => Some(switch),
+			_ => None,
+		})
+		.collect();
+
+	assert_eq!(switches.len(), 2);
+
+	let brcom = switches[0].on();
+	assert_eq!(brcom.sound().unwrap().text(), "switches/normbutn");
+	assert_eq!(brcom.frames().count(), 1);
+
+	let garg = switches[1].on();
+	assert_eq!(garg.sound().unwrap().text(), "switches/exitbutn");
+	assert_eq!(garg.frames().count(), 2);
+
+	let off = switches[1].off().unwrap();
+	assert_eq!(off.sound().unwrap().text(), "switches/exitbutn");
+	assert_eq!(off.frames().count(), 1);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+flat FINE pic 1 tics 8 pic 2 tics 8
+switch BROKEN off
+texture ALSOFINE range ALSOFINE4 rand 2 4
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
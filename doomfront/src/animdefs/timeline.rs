@@ -0,0 +1,258 @@
+//! A typed model of texture animations, and deterministic playback thereof.
+
+use super::{ast, Syn};
+
+/// Everything a `flat` or `texture` animation definition specifies.
+///
+/// [`Animation::from_ast`] reads a parsed definition, and
+/// [`Animation::timeline`] builds a [`Timeline`] for previewing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Animation {
+	/// `true` for `flat`, `false` for `texture`.
+	pub flat: bool,
+	/// The base texture or flat.
+	pub name: String,
+	pub optional: bool,
+	pub frames: Frames,
+	pub oscillate: bool,
+	pub allow_decals: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Frames {
+	/// Given one by one with `pic`.
+	Pics(Vec<Frame>),
+	/// Given with `range`. Which textures lie between the base and `last`
+	/// depends on the order in which they are defined, and so is not known
+	/// from ANIMDEFS alone.
+	Range { last: String, duration: Duration },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Frame {
+	pub pic: Pic,
+	pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Pic {
+	/// Counting textures from the base one, which is `1`.
+	Number(u32),
+	Name(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Duration {
+	/// `tics N`.
+	Tics(u32),
+	/// `rand MIN MAX`; a new duration in this range (inclusive) is picked
+	/// each time the frame is shown.
+	Rand(u32, u32),
+}
+
+impl Animation {
+	/// Returns `None` if any frame's picture or duration is malformed, or if
+	/// there are no frames at all.
+	#[must_use]
+	pub fn from_ast(anim: &ast::AnimDef) -> Option<Self> {
+		let frames = if let Some(range) = anim.range() {
+			Frames::Range {
+				last: range.last().text().to_string(),
+				duration: Duration::from_ast(&range.duration())?,
+			}
+		} else {
+			let frames = anim
+				.frames()
+				.map(|frame| {
+					let pic = frame.pic();
+
+					let pic = if pic.kind() == Syn::LitInt {
+						Pic::Number(pic.text().parse().ok()?)
+					} else {
+						Pic::Name(ast::Name::new(pic)?.text().to_string())
+					};
+
+					Some(Frame {
+						pic,
+						duration: Duration::from_ast(&frame.duration()?)?,
+					})
+				})
+				.collect::<Option<Vec<_>>>()?;
+
+			if frames.is_empty() {
+				return None;
+			}
+
+			Frames::Pics(frames)
+		};
+
+		Some(Self {
+			flat: anim.kind().kind() == Syn::KwFlat,
+			name: anim.name().text().to_string(),
+			optional: anim.is_optional(),
+			frames,
+			oscillate: anim.is_oscillating(),
+			allow_decals: anim.allows_decals(),
+		})
+	}
+
+	/// `range_len` is how many textures a `range` animation spans, counting
+	/// both the base and the last; it is ignored if frames are given with `pic`.
+	/// [`Timeline::frame_at`] then yields indices into [`Frames::Pics`], or
+	/// offsets from the base texture of a range.
+	#[must_use]
+	pub fn timeline(&self, range_len: usize) -> Timeline {
+		let durations = match &self.frames {
+			Frames::Pics(frames) => frames.iter().map(|f| f.duration).collect(),
+			Frames::Range { duration, .. } => vec![*duration; range_len.max(1)],
+		};
+
+		Timeline::new(durations, self.oscillate)
+	}
+}
+
+impl Duration {
+	#[must_use]
+	fn from_ast(duration: &ast::Duration) -> Option<Self> {
+		match duration {
+			ast::Duration::Tics(tics) => Some(Self::Tics(tics.text().parse().ok()?)),
+			ast::Duration::Rand(min, max) => {
+				let min: u32 = min.text().parse().ok()?;
+				let max: u32 = max.text().parse().ok()?;
+				Some(Self::Rand(min.min(max), min.max(max)))
+			}
+		}
+	}
+}
+
+/// Plays an animation back deterministically, such that the same timeline
+/// always shows the same frame at the same tic.
+///
+/// Random durations are drawn from a seeded generator, so they are
+/// reproducible but do not match what any source port would show. The drawn
+/// durations repeat every [`Timeline::RANDOM_CYCLES`] cycles, so that any tic
+/// can be looked up without playing everything before it.
+/// Durations of zero tics are treated as one tic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+	durations: Vec<Duration>,
+	oscillate: bool,
+	seed: u32,
+	/// For every frame shown over one period, the tic (counting from the
+	/// period's start) on which it stops being shown, and its index.
+	schedule: Vec<(u64, usize)>,
+}
+
+impl Timeline {
+	/// How many cycles of an animation with random durations are played
+	/// before the same durations get drawn again.
+	pub const RANDOM_CYCLES: usize = 64;
+
+	/// # Panics
+	/// If `durations` is empty.
+	#[must_use]
+	pub fn new(durations: Vec<Duration>, oscillate: bool) -> Self {
+		assert!(!durations.is_empty(), "a timeline needs at least one frame");
+
+		let mut ret = Self {
+			durations,
+			oscillate,
+			seed: 0x2545_F491,
+			schedule: vec![],
+		};
+
+		ret.schedule = ret.schedule();
+		ret
+	}
+
+	/// Changes which random durations get picked. The seed zero is replaced
+	/// with a non-zero one, since the generator can not start from zero.
+	#[must_use]
+	pub fn with_seed(mut self, seed: u32) -> Self {
+		self.seed = if seed == 0 { 0x2545_F491 } else { seed };
+		self.schedule = self.schedule();
+		self
+	}
+
+	/// How many frames the animation has.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.durations.len()
+	}
+
+	/// Always `false`, since a timeline can not be built without frames.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.durations.is_empty()
+	}
+
+	/// The index of the frame shown at `tic`, counting from the tic on which
+	/// the animation started (i.e. `0` always yields the first frame).
+	#[must_use]
+	pub fn frame_at(&self, tic: u64) -> usize {
+		let period = self.schedule.last().unwrap().0;
+		let tic = tic % period;
+		let pos = self.schedule.partition_point(|&(end, _)| end <= tic);
+		self.schedule[pos].1
+	}
+
+	/// Without random durations, every cycle is the same, and so the period
+	/// is only one cycle long.
+	fn schedule(&self) -> Vec<(u64, usize)> {
+		let cycles = if self
+			.durations
+			.iter()
+			.all(|d| matches!(d, Duration::Tics(_)))
+		{
+			1
+		} else {
+			Self::RANDOM_CYCLES
+		};
+
+		let order = self.order();
+		let mut ret = Vec::with_capacity(order.len() * cycles);
+		let mut rng = self.seed;
+		let mut elapsed = 0;
+
+		for &i in order.iter().cycle().take(order.len() * cycles) {
+			elapsed += self.tics(i, &mut rng);
+			ret.push((elapsed, i));
+		}
+
+		ret
+	}
+
+	/// Frame indices in the order they are shown over one cycle. Oscillating
+	/// animations go back down without repeating either end, as in
+	/// `0 1 2 1 0 1 2 1 ...`.
+	fn order(&self) -> Vec<usize> {
+		let len = self.durations.len();
+		let mut ret: Vec<usize> = (0..len).collect();
+
+		if self.oscillate && len > 2 {
+			ret.extend((1..(len - 1)).rev());
+		}
+
+		ret
+	}
+
+	fn tics(&self, index: usize, rng: &mut u32) -> u64 {
+		let tics = match self.durations[index] {
+			Duration::Tics(tics) => tics,
+			Duration::Rand(min, max) => {
+				// xorshift32
+				*rng ^= *rng << 13;
+				*rng ^= *rng >> 17;
+				*rng ^= *rng << 5;
+				min + (u64::from(*rng) % (u64::from(max - min) + 1)) as u32
+			}
+		};
+
+		u64::from(tics.max(1))
+	}
+}
@@ -45,6 +45,43 @@ pub fn just_nc(string: &'static str) -> impl Parser<char, (), Error = ParseError
 	})
 }
 
+/// A case-insensitive keyword, matched via [`just_nc`].
+/// Emits a [`GreenToken`] wrapped in a [`rowan::NodeOrToken::Token`].
+pub fn kw<'src, L>(
+	string: &'static str,
+	syn: L::Kind,
+	src: &'src str,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'src
+where
+	L: rowan::Language,
+	L::Kind: Into<SyntaxKind> + 'static,
+{
+	just_nc(string).map_with_span(help::map_tok::<L, _>(src, syn))
+}
+
+/// Like [`kw`], but for languages whose tokens are delimited by whitespace
+/// rather than by the end of a C identifier. The whole of `word`'s output
+/// must match `string`, ignoring ASCII case.
+pub fn kw_word<'src, L, P>(
+	word: P,
+	string: &'static str,
+	syn: L::Kind,
+	src: &'src str,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'src
+where
+	L: rowan::Language,
+	L::Kind: Into<SyntaxKind> + 'static,
+	P: Parser<char, String, Error = ParseError> + Clone + 'src,
+{
+	word.try_map(move |word, span| {
+		word.eq_ignore_ascii_case(string)
+			.then_some(())
+			.ok_or_else(|| ParseError::expected_input_found(span, None, None))
+	})
+	.map_with_span(help::map_tok::<L, _>(src, syn))
+	.labelled("keyword")
+}
+
 /// The most common kind of whitespace;
 /// spaces, carriage returns, newlines, and/or tabs, repeated one or more times.
 pub fn wsp<L>(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_
@@ -199,3 +236,25 @@ where
 
 	primitive::choice((no_point, l_opt, r_opt))
 }
+
+/// Double-quoted string literals, wherein a backslash escapes any character.
+/// Escape sequences are left as written.
+pub fn string_lit<L>(
+	src: &str,
+	syn: L::Kind,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_
+where
+	L: rowan::Language,
+	L::Kind: Into<SyntaxKind> + 'static,
+{
+	primitive::just('"')
+		.then(
+			primitive::none_of("\\\"")
+				.ignored()
+				.or(primitive::just('\\').then(primitive::any()).ignored())
+				.repeated(),
+		)
+		.then(primitive::just('"'))
+		.map_with_span(help::map_tok::<L, _>(src, syn))
+		.labelled("string literal")
+}
@@ -0,0 +1,26 @@
+//! Parser and syntax trees for [DECALDEF](https://zdoom.org/wiki/DECALDEF),
+//! the lump which ZDoom-family source ports use to define decals.
+//!
+//! Decals draw a lump onto walls, and may be gathered into randomly-picked
+//! groups. Animators (faders, stretchers, sliders, color changers, and
+//! combiners thereof) change decals over time, and generators bind actor
+//! classes to the decals they leave behind. [`Decals`] resolves names across
+//! any number of files, and [`check_decals`] reports those which can not be
+//! resolved, as well as generators naming unknown actors.
+
+pub mod ast;
+mod parse;
+mod resolve;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use parse::*;
+pub use resolve::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
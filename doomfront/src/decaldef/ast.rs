@@ -0,0 +1,357 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Animator(AnimatorDef),
+	Decal(DecalDef),
+	DecalGroup(DecalGroupDef),
+	Generator(GeneratorDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::AnimatorDef | Syn::DecalDef | Syn::DecalGroupDef | Syn::GeneratorDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::AnimatorDef => Some(Self::Animator(AnimatorDef(node))),
+			Syn::DecalDef => Some(Self::Decal(DecalDef(node))),
+			Syn::DecalGroupDef => Some(Self::DecalGroup(DecalGroupDef(node))),
+			Syn::GeneratorDef => Some(Self::Generator(GeneratorDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Animator(inner) => &inner.0,
+			Self::Decal(inner) => &inner.0,
+			Self::DecalGroup(inner) => &inner.0,
+			Self::Generator(inner) => &inner.0,
+		}
+	}
+}
+
+/// The first token of `node` tagged `syn`, looking only at direct children.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// Every name in `node` before its opening brace, if it has one.
+fn header(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.take_while(|t| t.kind() != Syn::BraceL)
+		.filter(|t| t.kind() == Syn::Ident)
+}
+
+fn properties(node: &SyntaxNode) -> impl Iterator<Item = Property> {
+	node.children().filter_map(Property::cast)
+}
+
+/// Finds a property by case-insensitive key. If the key is given more than
+/// once, the last occurrence is returned, since it takes precedence.
+fn property(node: &SyntaxNode, key: &str) -> Option<Property> {
+	properties(node)
+		.filter(|prop| prop.key().text().eq_ignore_ascii_case(key))
+		.last()
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::DecalDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DecalDef(SyntaxNode);
+
+simple_astnode!(Syn, DecalDef, Syn::DecalDef);
+
+impl DecalDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The number by which ACS and line specials can refer to this decal.
+	#[must_use]
+	pub fn id(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::LitInt)
+	}
+
+	/// e.g. `pic`, `shade`, `x-scale`, `randomflipx`, `animator`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Keys are compared case-insensitively.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		property(&self.0, key)
+	}
+
+	/// The lump drawn by this decal, given by `pic`.
+	#[must_use]
+	pub fn pic(&self) -> Option<Value> {
+		self.property("pic").and_then(|prop| prop.value())
+	}
+
+	/// The decal drawn underneath this one, given by `lowerdecal`.
+	#[must_use]
+	pub fn lower_decal(&self) -> Option<Value> {
+		self.property("lowerdecal").and_then(|prop| prop.value())
+	}
+
+	/// The animator applied to this decal, given by `animator`.
+	#[must_use]
+	pub fn animator(&self) -> Option<Value> {
+		self.property("animator").and_then(|prop| prop.value())
+	}
+}
+
+/// Wraps a node tagged [`Syn::DecalGroupDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DecalGroupDef(SyntaxNode);
+
+simple_astnode!(Syn, DecalGroupDef, Syn::DecalGroupDef);
+
+impl DecalGroupDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn id(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::LitInt)
+	}
+
+	pub fn entries(&self) -> impl Iterator<Item = GroupEntry> {
+		self.0.children().filter_map(GroupEntry::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::GroupEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GroupEntry(SyntaxNode);
+
+simple_astnode!(Syn, GroupEntry, Syn::GroupEntry);
+
+impl GroupEntry {
+	/// A decal, or another decal group.
+	#[must_use]
+	pub fn decal(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// How likely this entry is to be picked, relative to the others.
+	#[must_use]
+	pub fn weight(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitInt).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::GeneratorDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GeneratorDef(SyntaxNode);
+
+simple_astnode!(Syn, GeneratorDef, Syn::GeneratorDef);
+
+impl GeneratorDef {
+	/// The actor class which leaves decals behind.
+	#[must_use]
+	pub fn actor(&self) -> SyntaxToken {
+		header(&self.0).next().unwrap()
+	}
+
+	/// A decal or decal group, or `none` to stop the actor leaving decals.
+	#[must_use]
+	pub fn decal(&self) -> SyntaxToken {
+		header(&self.0).nth(1).unwrap()
+	}
+
+	#[must_use]
+	pub fn is_none(&self) -> bool {
+		self.decal().text().eq_ignore_ascii_case("none")
+	}
+}
+
+/// Wraps a node tagged [`Syn::AnimatorDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AnimatorDef(SyntaxNode);
+
+simple_astnode!(Syn, AnimatorDef, Syn::AnimatorDef);
+
+impl AnimatorDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwFader`]
+	/// - [`Syn::KwStretcher`]
+	/// - [`Syn::KwSlider`]
+	/// - [`Syn::KwColorChanger`]
+	/// - [`Syn::KwCombiner`]
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		header(&self.0).next().unwrap()
+	}
+
+	/// e.g. `DecayStart`, `GoalX`, `SlideTime`. Always empty for combiners.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Keys are compared case-insensitively.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		property(&self.0, key)
+	}
+
+	/// The animators which a combiner runs at once. Always empty for any
+	/// other kind of animator.
+	pub fn combined(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.skip_while(|t| t.kind() != Syn::BraceL)
+			.filter(|t| t.kind() == Syn::Ident)
+	}
+}
+
+// Properties //////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Property(SyntaxNode);
+
+simple_astnode!(Syn, Property, Syn::Property);
+
+impl Property {
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Every value following the key, in order.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+
+	/// Shorthand for `self.values().next()`.
+	#[must_use]
+	pub fn value(&self) -> Option<Value> {
+		self.values().next()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a value, with convenience functions
+/// for interpreting it. Its tag is one of [`Syn::LitString`], [`Syn::LitInt`],
+/// [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// Also accepts integer literals, since DECALDEF does wherever it expects
+	/// a floating-point number.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		matches!(self.0.kind(), Syn::LitFloat | Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as a decal or lump name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,243 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any). All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		decal_def(src),
+		decal_group_def(src),
+		generator_def(src),
+		animator_def(src),
+	))
+}
+
+fn decal_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("decal", Syn::KwDecal, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.chain_append(trivia(src).chain_push(int_lit(src)).or_not().flatten())
+		.chain_append(block(src, property(src)))
+		.collect_n::<Syn, { Syn::DecalDef as u16 }>()
+		.labelled("decal definition")
+}
+
+fn decal_group_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let entry = name(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(int_lit(src))
+		.collect_n::<Syn, { Syn::GroupEntry as u16 }>();
+
+	comb::kw::<Syn>("decalgroup", Syn::KwDecalGroup, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.chain_append(trivia(src).chain_push(int_lit(src)).or_not().flatten())
+		.chain_append(block(src, entry))
+		.collect_n::<Syn, { Syn::DecalGroupDef as u16 }>()
+		.labelled("decal group definition")
+}
+
+fn generator_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("generator", Syn::KwGenerator, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.collect_n::<Syn, { Syn::GeneratorDef as u16 }>()
+		.labelled("generator definition")
+}
+
+fn animator_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let with_props = primitive::choice((
+		comb::kw::<Syn>("fader", Syn::KwFader, src),
+		comb::kw::<Syn>("stretcher", Syn::KwStretcher, src),
+		comb::kw::<Syn>("slider", Syn::KwSlider, src),
+		comb::kw::<Syn>("colorchanger", Syn::KwColorChanger, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(name(src))
+	.chain_append(block(src, property(src)));
+
+	let combiner = comb::kw::<Syn>("combiner", Syn::KwCombiner, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.chain_append(block(src, name(src)));
+
+	primitive::choice((with_props, combiner))
+		.collect_n::<Syn, { Syn::AnimatorDef as u16 }>()
+		.labelled("animator definition")
+}
+
+/// Keys which are followed by a name rather than by numbers or strings.
+/// `shade` is also among them, since it takes either a string or `BloodDefault`.
+const NAMED_KEYS: &[&str] = &["animator", "lowerdecal", "pic", "shade"];
+
+fn property(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let value = primitive::choice((
+		float_lit(src),
+		int_lit(src),
+		comb::string_lit::<Syn>(src, Syn::LitString),
+	));
+
+	// Keys such as `x-scale` contain hyphens.
+	let key = primitive::filter(|c: &char| c.is_ascii_alphabetic() || *c == '_')
+		.chain(
+			primitive::filter(|c: &char| c.is_ascii_alphanumeric() || "_-".contains(*c)).repeated(),
+		)
+		.collect::<String>();
+
+	let named_key = key
+		.try_map(|key, span| {
+			if NAMED_KEYS.iter().any(|k| k.eq_ignore_ascii_case(&key)) {
+				Ok(key)
+			} else {
+				Err(ParseError::custom(span, "expected a key taking a name"))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	let named = named_key
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((
+			comb::string_lit::<Syn>(src, Syn::LitString),
+			name(src),
+		)));
+
+	let key = key.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	primitive::choice((
+		named,
+		key.start_vec()
+			.chain_append(trivia(src).chain_push(value).repeated().flatten()),
+	))
+	.collect_n::<Syn, { Syn::Property as u16 }>()
+	.labelled("property")
+}
+
+/// Trivia, then a brace-delimited list of `item`s and trivia.
+fn block<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	trivia(src)
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), item)).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src))
+}
+
+/// Unlike identifiers, the names of decals and lumps may start with a digit
+/// and contain some of the punctuation allowed in lump names.
+fn name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || "_-.[]\\^/!".contains(*c))
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("name")
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('-')
+		.or_not()
+		.then(text::digits(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+/// Either side of the decimal point may be empty, but not both.
+fn float_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let leading = text::digits::<char, ParseError>(10)
+		.then(primitive::just('.'))
+		.then(text::digits(10).or_not())
+		.ignored();
+
+	let trailing = primitive::just('.').then(text::digits(10)).ignored();
+
+	primitive::just('-')
+		.or_not()
+		.then(primitive::choice((leading, trailing)))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
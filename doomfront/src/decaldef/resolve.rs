@@ -0,0 +1,284 @@
+//! Resolving the names which decal definitions refer to, both within DECALDEF
+//! and outside of it, and reporting those which can not be resolved.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{zscript, Issue};
+
+use super::{ast, ParseTree, SyntaxToken};
+
+/// Everything defined by one or more DECALDEF lumps. All names are stored
+/// uppercase, since source ports compare them case-insensitively.
+///
+/// Lumps loaded later override earlier ones, so trees should be added in
+/// load order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Decals {
+	decals: HashSet<String>,
+	/// Each entry's decal, along with its weight.
+	groups: HashMap<String, Vec<(String, u32)>>,
+	animators: HashSet<String>,
+	/// Actor classes to the decal or group they leave behind; `None` if the
+	/// actor was given `none`.
+	generators: HashMap<String, Option<String>>,
+}
+
+impl Decals {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Declares every definition in `tree`.
+	pub fn add_decaldef(&mut self, tree: &ParseTree) {
+		for root in tree.ast() {
+			match root {
+				ast::Root::Decal(decal) => {
+					self.decals.insert(decal.name().text().to_ascii_uppercase());
+				}
+				ast::Root::DecalGroup(group) => {
+					let entries = group
+						.entries()
+						.map(|entry| {
+							(
+								entry.decal().text().to_ascii_uppercase(),
+								entry.weight().text().parse().unwrap_or(0),
+							)
+						})
+						.collect();
+
+					self.groups
+						.insert(group.name().text().to_ascii_uppercase(), entries);
+				}
+				ast::Root::Animator(animator) => {
+					self.animators
+						.insert(animator.name().text().to_ascii_uppercase());
+				}
+				ast::Root::Generator(generator) => {
+					let decal = (!generator.is_none())
+						.then(|| generator.decal().text().to_ascii_uppercase());
+
+					self.generators
+						.insert(generator.actor().text().to_ascii_uppercase(), decal);
+				}
+			}
+		}
+	}
+
+	/// `true` for decals and decal groups alike.
+	#[must_use]
+	pub fn contains_decal(&self, name: &str) -> bool {
+		let name = name.to_ascii_uppercase();
+		self.decals.contains(&name) || self.groups.contains_key(&name)
+	}
+
+	#[must_use]
+	pub fn contains_animator(&self, name: &str) -> bool {
+		self.animators.contains(&name.to_ascii_uppercase())
+	}
+
+	/// The decal or group which `actor` leaves behind, in uppercase. Returns
+	/// `None` if no generator names `actor`, and `Some(None)` if its generator
+	/// is `none`.
+	#[must_use]
+	pub fn generator(&self, actor: &str) -> Option<Option<&str>> {
+		self.generators
+			.get(&actor.to_ascii_uppercase())
+			.map(Option::as_deref)
+	}
+
+	/// Every decal which `name` may stand for, in uppercase, along with the
+	/// chance of each being picked. Groups are expanded recursively, and each
+	/// entry's chance is its weight's share of its group's total.
+	///
+	/// Names which are neither decals nor groups (and groups which contain
+	/// themselves) resolve to nothing, as do entries weighted zero.
+	#[must_use]
+	pub fn resolve(&self, name: &str) -> Vec<(String, f64)> {
+		let mut ret = vec![];
+		self.resolve_into(&name.to_ascii_uppercase(), 1.0, &mut vec![], &mut ret);
+		ret
+	}
+
+	fn resolve_into(
+		&self,
+		name: &str,
+		chance: f64,
+		visiting: &mut Vec<String>,
+		out: &mut Vec<(String, f64)>,
+	) {
+		if self.decals.contains(name) {
+			match out.iter_mut().find(|(decal, _)| decal == name) {
+				Some((_, c)) => *c += chance,
+				None => out.push((name.to_string(), chance)),
+			}
+
+			return;
+		}
+
+		let Some(entries) = self.groups.get(name) else {
+			return;
+		};
+
+		if visiting.iter().any(|v| v == name) {
+			return;
+		}
+
+		let total: u64 = entries.iter().map(|(_, weight)| u64::from(*weight)).sum();
+
+		if total == 0 {
+			return;
+		}
+
+		visiting.push(name.to_string());
+
+		for (decal, weight) in entries.iter().filter(|(_, weight)| *weight > 0) {
+			let share = chance * f64::from(*weight) / total as f64;
+			self.resolve_into(decal, share, visiting, out);
+		}
+
+		visiting.pop();
+	}
+}
+
+/// The names which DECALDEF refers to but does not define: actor classes, and
+/// the lumps which decals draw. All names are stored uppercase.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Externals {
+	actors: HashSet<String>,
+	/// `None` until any lump is added, in which case `pic`s are not checked.
+	lumps: Option<HashSet<String>>,
+}
+
+impl Externals {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Declares an actor class, e.g. one read out of DECORATE.
+	pub fn add_actor(&mut self, class: &str) {
+		self.actors.insert(class.to_ascii_uppercase());
+	}
+
+	/// Declares every class in `tree`.
+	pub fn add_zscript(&mut self, tree: &zscript::ParseTree) {
+		for root in tree.ast() {
+			if let zscript::ast::Root::Class(classdef) = root {
+				self.add_actor(classdef.name().text());
+			}
+		}
+	}
+
+	/// Declares a lump (or texture) which a decal's `pic` may name.
+	pub fn add_lump(&mut self, name: &str) {
+		self.lumps
+			.get_or_insert_with(HashSet::new)
+			.insert(name.to_ascii_uppercase());
+	}
+
+	/// Compared case-insensitively.
+	#[must_use]
+	pub fn contains_actor(&self, class: &str) -> bool {
+		self.actors.contains(&class.to_ascii_uppercase())
+	}
+
+	/// Compared case-insensitively. Always `true` if no lumps were added.
+	#[must_use]
+	pub fn contains_lump(&self, name: &str) -> bool {
+		self.lumps
+			.as_ref()
+			.is_none_or(|lumps| lumps.contains(&name.to_ascii_uppercase()))
+	}
+}
+
+/// Checks every definition in `tree` against `decals` and `externals`.
+///
+/// Raises an error for each decal without a `pic`, and warnings for:
+/// - `pic`s naming lumps which do not exist, if any lumps are known
+/// - `animator`s, and combiners' entries, naming undefined animators
+/// - `lowerdecal`s, group entries, and generators naming undefined decals
+/// - generators naming unknown actor classes
+///
+/// Definitions made by `tree` itself count as defined, whether or not it was
+/// added to `decals` beforehand.
+#[must_use]
+pub fn check_decals(tree: &ParseTree, decals: &Decals, externals: &Externals) -> Vec<Issue> {
+	let mut issues = vec![];
+
+	let mut local = Decals::new();
+	local.add_decaldef(tree);
+
+	let has_decal = |name: &str| decals.contains_decal(name) || local.contains_decal(name);
+	let has_animator = |name: &str| decals.contains_animator(name) || local.contains_animator(name);
+
+	for root in tree.ast() {
+		match root {
+			ast::Root::Decal(decal) => {
+				match decal.pic() {
+					Some(pic) => {
+						if !externals.contains_lump(&pic.text()) {
+							issues.push(Issue::warning(
+								pic.syntax().text_range(),
+								format!("lump `{}` does not exist", pic.text()),
+							));
+						}
+					}
+					None => issues.push(Issue::error(
+						decal.name().text_range(),
+						format!("decal `{}` has no `pic`", decal.name().text()),
+					)),
+				}
+
+				if let Some(lower) = decal.lower_decal() {
+					if !has_decal(&lower.text()) {
+						issues.push(undefined(lower.syntax(), "decal"));
+					}
+				}
+
+				if let Some(animator) = decal.animator() {
+					if !has_animator(&animator.text()) {
+						issues.push(undefined(animator.syntax(), "animator"));
+					}
+				}
+			}
+			ast::Root::DecalGroup(group) => {
+				for entry in group.entries() {
+					if !has_decal(entry.decal().text()) {
+						issues.push(undefined(&entry.decal(), "decal"));
+					}
+				}
+			}
+			ast::Root::Animator(animator) => {
+				for combined in animator.combined() {
+					if !has_animator(combined.text()) {
+						issues.push(undefined(&combined, "animator"));
+					}
+				}
+			}
+			ast::Root::Generator(generator) => {
+				let actor = generator.actor();
+
+				if !externals.contains_actor(actor.text()) {
+					issues.push(Issue::warning(
+						actor.text_range(),
+						format!("`{}` is not a known actor class", actor.text()),
+					));
+				}
+
+				if !generator.is_none() && !has_decal(generator.decal().text()) {
+					issues.push(undefined(&generator.decal(), "decal"));
+				}
+			}
+		}
+	}
+
+	issues
+}
+
+fn undefined(token: &SyntaxToken, what: &str) -> Issue {
+	Issue::warning(
+		token.text_range(),
+		format!("{what} `{}` is not defined", token.text()),
+	)
+}
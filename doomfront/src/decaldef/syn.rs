@@ -0,0 +1,88 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// DECALDEF syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `fader NAME { ... }`, `stretcher NAME { ... }`, `slider NAME { ... }`,
+	/// `colorchanger NAME { ... }`, or `combiner NAME { ... }`.
+	AnimatorDef,
+	/// `decal NAME [ID] { ... }`.
+	DecalDef,
+	/// `decalgroup NAME [ID] { ... }`.
+	DecalGroupDef,
+	/// `generator ACTOR DECAL`.
+	GeneratorDef,
+	/// `DECAL WEIGHT`, within a decal group.
+	GroupEntry,
+	/// A key followed by its values, e.g. `x-scale 0.5` or `DecayTime 1.0`.
+	Property,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwColorChanger,
+	KwCombiner,
+	KwDecal,
+	KwDecalGroup,
+	KwFader,
+	KwGenerator,
+	KwSlider,
+	KwStretcher,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// DECALDEF comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Property keys, and the names of decals, animators, actors, and lumps.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by DECALDEF.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,242 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Adapted from GZDoom's own DECALDEF.
+decal BulletChip1 1
+{
+	pic CHIP1
+	shade "00 00 00"
+	x-scale 0.3 y-scale 0.3
+	randomflipx
+	randomflipy
+}
+
+decal BulletChip2
+{
+	pic CHIP2 shade "00 00 00" randomflipx
+	lowerdecal BulletChip1
+}
+
+decal PlasmaScorch
+{
+	pic PLASMA1
+	add 1.0
+	fullbright
+	animator GoAway
+}
+
+decalgroup BulletChip
+{
+	BulletChip1 3
+	BulletChip2 1
+}
+
+fader GoAway
+{
+	DecayStart 0.5
+	DecayTime 1.0
+}
+
+stretcher GrowUp { GoalX 1.0 StretchTime 0.5 }
+combiner GoAwayGrowing { GoAway GrowUp }
+
+generator BulletPuff BulletChip
+generator PlasmaBall PlasmaScorch
+generator Rocket none
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 10);
+
+	let ast::Root::Decal(chip1) = &roots[0] else {
+		panic!("expected a decal, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(chip1.name().text(), "BulletChip1");
+	assert_eq!(chip1.id().unwrap().text(), "1");
+	assert_eq!(chip1.pic().unwrap().ident(), Some("CHIP1"));
+	assert_eq!(
+		chip1.property("shade").unwrap().value().unwrap().string(),
+		Some("00 00 00".to_string())
+	);
+	assert_eq!(
+		chip1.property("X-SCALE").unwrap().value().unwrap().float(),
+		Some(0.3)
+	);
+	assert_eq!(chip1.properties().count(), 6);
+
+	let ast::Root::Decal(chip2) = &roots[1] else {
+		panic!("expected a decal, found: {:#?}", roots[1]);
+	};
+
+	assert!(chip2.id().is_none());
+	assert_eq!(chip2.properties().count(), 4);
+	assert_eq!(chip2.lower_decal().unwrap().text(), "BulletChip1");
+
+	let ast::Root::DecalGroup(group) = &roots[3] else {
+		panic!("expected a decal group, found: {:#?}", roots[3]);
+	};
+
+	let entries: Vec<_> = group.entries().collect();
+	assert_eq!(entries.len(), 2);
+	assert_eq!(entries[1].decal().text(), "BulletChip2");
+	assert_eq!(entries[1].weight().text(), "1");
+
+	let ast::Root::Animator(fader) = &roots[4] else {
+		panic!("expected an animator, found: {:#?}", roots[4]);
+	};
+
+	assert_eq!(fader.kind().kind(), Syn::KwFader);
+	assert_eq!(fader.name().text(), "GoAway");
+	assert_eq!(
+		fader
+			.property("decaytime")
+			.unwrap()
+			.value()
+			.unwrap()
+			.float(),
+		Some(1.0)
+	);
+	assert_eq!(fader.combined().count(), 0);
+
+	let ast::Root::Animator(combiner) = &roots[6] else {
+		panic!("expected an animator, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(combiner.kind().kind(), Syn::KwCombiner);
+	assert_eq!(
+		combiner
+			.combined()
+			.map(|t| t.text().to_string())
+			.collect::<Vec<_>>(),
+		["GoAway", "GrowUp"]
+	);
+
+	let ast::Root::Generator(rocket) = &roots[9] else {
+		panic!("expected a generator, found: {:#?}", roots[9]);
+	};
+
+	assert_eq!(rocket.actor().text(), "Rocket");
+	assert!(rocket.is_none());
+}
+
+#[test]
+fn resolution() {
+	const MOD: &str = r#"
+decal BigChip
+{
+	pic BIGCHIP
+	animator Vanish
+}
+
+decal NoPic { shade "ff 00 00" }
+
+decalgroup BulletChip
+{
+	BulletChip 2
+	BigChip 2
+	PlasmaScorch 0
+	BloodSplat 1
+}
+
+combiner Shrinker { GoAway Shrink }
+
+generator BulletPuff BulletChip
+generator MyRifleShot BigChip
+generator NotAnActor NoSuchDecal
+"#;
+
+	let mut decals = Decals::new();
+	decals.add_decaldef(&ParseTree::new(parse(SOURCE).unwrap()));
+
+	let modded = ParseTree::new(parse_recov(MOD).unwrap());
+	assert_no_errors(&modded);
+	decals.add_decaldef(&modded);
+
+	// Later definitions override earlier ones. The shares of entries which
+	// lead back into the group or to nothing at all are lost.
+	assert_eq!(decals.resolve("bulletchip"), [("BIGCHIP".to_string(), 0.4)]);
+	assert_eq!(
+		decals.resolve("BulletChip1"),
+		[("BULLETCHIP1".to_string(), 1.0)]
+	);
+	assert!(decals.resolve("GoAway").is_empty());
+
+	let heavy = ParseTree::new(
+		parse("decal A { pic A }\ndecal B { pic B }\ndecalgroup G { A 3000000000 B 3000000000 }")
+			.unwrap(),
+	);
+	decals.add_decaldef(&heavy);
+	assert_eq!(
+		decals.resolve("G"),
+		[("A".to_string(), 0.5), ("B".to_string(), 0.5)]
+	);
+
+	assert_eq!(decals.generator("bulletpuff"), Some(Some("BULLETCHIP")));
+	assert_eq!(decals.generator("Rocket"), Some(None));
+	assert_eq!(decals.generator("Imp"), None);
+
+	let zscript = crate::zscript::ParseTree::new(
+		crate::zscript::parse_recov("class MyRifleShot : BulletPuff {}").unwrap(),
+	);
+
+	let mut externals = Externals::new();
+	externals.add_actor("BulletPuff");
+	externals.add_zscript(&zscript);
+	assert!(externals.contains_lump("ANYTHING"));
+	externals.add_lump("CHIP1");
+
+	let issues: Vec<_> = check_decals(&modded, &decals, &externals)
+		.into_iter()
+		.map(|issue| (&MOD[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		issues,
+		[
+			("BIGCHIP", "lump `BIGCHIP` does not exist".to_string()),
+			("Vanish", "animator `Vanish` is not defined".to_string()),
+			("NoPic", "decal `NoPic` has no `pic`".to_string()),
+			(
+				"BloodSplat",
+				"decal `BloodSplat` is not defined".to_string()
+			),
+			("Shrink", "animator `Shrink` is not defined".to_string()),
+			(
+				"NotAnActor",
+				"`NotAnActor` is not a known actor class".to_string()
+			),
+			(
+				"NoSuchDecal",
+				"decal `NoSuchDecal` is not defined".to_string()
+			),
+		]
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+decal Fine { pic CHIP1 }
+decalgroup Broken { Fine }
+generator BulletPuff Fine
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
@@ -0,0 +1,39 @@
+//! Parser and syntax trees for [DeHackEd](https://doomwiki.org/wiki/DeHackEd)
+//! patches (`.deh` files and `DEHACKED` lumps), including the extensions
+//! introduced by [BEX](https://doomwiki.org/wiki/Boom_extensions_to_DeHackEd).
+//!
+//! The format is line-based: a patch is a sequence of blocks such as
+//! `Thing 1 (Player)`, each followed by `Key = Value` lines, and BEX sections
+//! such as `[STRINGS]`. Patches in the wild are frequently malformed, so the
+//! parser accepts anything the usual loaders would, and [`parse_recov`]
+//! sets aside every line it does not understand rather than giving up.
+//!
+//! [`Tables`] models the game data which patches modify, starting from that of
+//! vanilla Doom or, for patches using the [MBF21](https://github.com/kraflab/mbf21)
+//! extensions, an MBF21 source port. [`Tables::apply`] applies a parsed patch
+//! to it, and [`Tables::diff`] shows what the patch changed. [`to_actors`]
+//! expresses those changes as DECORATE or ZScript, for porting to GZDoom.
+
+mod apply;
+pub mod ast;
+mod convert;
+mod mbf21;
+mod parse;
+mod syn;
+mod tables;
+#[cfg(test)]
+mod test;
+mod vanilla;
+
+pub use convert::*;
+pub use parse::*;
+pub use syn::*;
+pub use tables::*;
+
+pub type FileParseTree = crate::repr::FileParseTree<Syn>;
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type IncludeTree = crate::repr::IncludeTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,951 @@
+//! Applying a parsed patch to a set of [`Tables`].
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::Issue;
+
+use super::{
+	ast::{self, keys_match, BlockKind, SectionKind},
+	AmmoInfo, Misc, Par, ParseTree, StateInfo, Tables, ThingInfo, WeaponInfo, CODE_POINTERS,
+	MBF21_CODE_POINTERS, MBF21_FRAME_FLAGS, MBF21_THING_FLAGS, MBF21_WEAPON_FLAGS,
+	MBF_CODE_POINTERS, THING_FLAGS,
+};
+
+impl Tables {
+	/// Applies every block, section, and top-level field of `tree` to these
+	/// tables in order, as a source port loading the patch would.
+	///
+	/// Lines which can not be applied (e.g. because they refer to a thing or
+	/// state that does not exist) are skipped, and reported in the returned
+	/// issues along with anything else that source ports would ignore.
+	/// `INCLUDE` directives are not followed; to apply a whole [`IncludeTree`],
+	/// apply each of its files in turn.
+	///
+	/// MBF21 features are only valid in patches which declare
+	/// `Doom version = 2021`, and are reported otherwise. Such patches usually
+	/// refer to things and states which only exist in [`Tables::mbf21`].
+	///
+	/// [`IncludeTree`]: super::IncludeTree
+	pub fn apply(&mut self, tree: &ParseTree) -> Vec<Issue> {
+		let actions = self.states.iter().map(|s| s.action).collect();
+
+		let mut ctx = Context {
+			tables: self,
+			actions,
+			mbf21: false,
+			patch_format: None,
+			args: vec![],
+			issues: vec![],
+		};
+
+		for root in tree.ast() {
+			match root {
+				ast::Root::Block(block) => ctx.block(&block),
+				ast::Root::Field(field) => ctx.top_field(&field),
+				ast::Root::Section(section) => ctx.section(&section),
+				ast::Root::Text(text) => ctx.text(&text),
+				ast::Root::Header(_) | ast::Root::Include(_) => {}
+			}
+		}
+
+		ctx.finish();
+		ctx.issues
+	}
+}
+
+/// What a field's value is an index into, if anything, so that it can be
+/// checked against the length of that table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ref {
+	None,
+	Ammo,
+	/// A thing number, where 0 means "none".
+	Thing,
+	Sound,
+	Sprite,
+	State,
+}
+
+type Accessor<T> = fn(&mut T) -> &mut i32;
+type FieldDef<T> = (&'static str, Ref, Accessor<T>);
+
+const THING_FIELDS: &[FieldDef<ThingInfo>] = &[
+	("ID #", Ref::None, |t| &mut t.doomednum),
+	("Initial frame", Ref::State, |t| &mut t.spawn_state),
+	("Hit points", Ref::None, |t| &mut t.spawn_health),
+	("First moving frame", Ref::State, |t| &mut t.see_state),
+	("Alert sound", Ref::Sound, |t| &mut t.see_sound),
+	("Reaction time", Ref::None, |t| &mut t.reaction_time),
+	("Attack sound", Ref::Sound, |t| &mut t.attack_sound),
+	("Injury frame", Ref::State, |t| &mut t.pain_state),
+	("Pain chance", Ref::None, |t| &mut t.pain_chance),
+	("Pain sound", Ref::Sound, |t| &mut t.pain_sound),
+	("Close attack frame", Ref::State, |t| &mut t.melee_state),
+	("Far attack frame", Ref::State, |t| &mut t.missile_state),
+	("Death frame", Ref::State, |t| &mut t.death_state),
+	("Exploding frame", Ref::State, |t| &mut t.xdeath_state),
+	("Death sound", Ref::Sound, |t| &mut t.death_sound),
+	("Speed", Ref::None, |t| &mut t.speed),
+	("Width", Ref::None, |t| &mut t.radius),
+	("Height", Ref::None, |t| &mut t.height),
+	("Mass", Ref::None, |t| &mut t.mass),
+	("Missile damage", Ref::None, |t| &mut t.damage),
+	("Action sound", Ref::Sound, |t| &mut t.active_sound),
+	("Respawn frame", Ref::State, |t| &mut t.raise_state),
+];
+
+const MBF21_THING_FIELDS: &[FieldDef<ThingInfo>] = &[
+	("Infighting group", Ref::None, |t| &mut t.infighting_group),
+	("Projectile group", Ref::None, |t| &mut t.projectile_group),
+	("Splash group", Ref::None, |t| &mut t.splash_group),
+	("Fast speed", Ref::None, |t| &mut t.fast_speed),
+	("Melee range", Ref::None, |t| &mut t.melee_range),
+	("Rip sound", Ref::Sound, |t| &mut t.rip_sound),
+	("Dropped item", Ref::Thing, |t| &mut t.dropped_item),
+];
+
+const FRAME_FIELDS: &[FieldDef<StateInfo>] = &[
+	("Sprite number", Ref::Sprite, |s| &mut s.sprite),
+	("Sprite subnumber", Ref::None, |s| &mut s.frame),
+	("Duration", Ref::None, |s| &mut s.tics),
+	("Next frame", Ref::State, |s| &mut s.next_state),
+	("Unknown 1", Ref::None, |s| &mut s.misc1),
+	("Unknown 2", Ref::None, |s| &mut s.misc2),
+];
+
+const MBF21_FRAME_FIELDS: &[FieldDef<StateInfo>] = &[
+	("Args1", Ref::None, |s| &mut s.args[0]),
+	("Args2", Ref::None, |s| &mut s.args[1]),
+	("Args3", Ref::None, |s| &mut s.args[2]),
+	("Args4", Ref::None, |s| &mut s.args[3]),
+	("Args5", Ref::None, |s| &mut s.args[4]),
+	("Args6", Ref::None, |s| &mut s.args[5]),
+	("Args7", Ref::None, |s| &mut s.args[6]),
+	("Args8", Ref::None, |s| &mut s.args[7]),
+];
+
+const WEAPON_FIELDS: &[FieldDef<WeaponInfo>] = &[
+	("Ammo type", Ref::Ammo, |w| &mut w.ammo),
+	("Deselect frame", Ref::State, |w| &mut w.up_state),
+	("Select frame", Ref::State, |w| &mut w.down_state),
+	("Bobbing frame", Ref::State, |w| &mut w.ready_state),
+	("Shooting frame", Ref::State, |w| &mut w.attack_state),
+	("Firing frame", Ref::State, |w| &mut w.flash_state),
+];
+
+const MBF21_WEAPON_FIELDS: &[FieldDef<WeaponInfo>] =
+	&[("Ammo per shot", Ref::None, |w| &mut w.ammo_per_shot)];
+
+const AMMO_FIELDS: &[FieldDef<AmmoInfo>] = &[
+	("Max ammo", Ref::None, |a| &mut a.max),
+	("Per ammo", Ref::None, |a| &mut a.per_pickup),
+];
+
+const MISC_FIELDS: &[FieldDef<Misc>] = &[
+	("Initial Health", Ref::None, |m| &mut m.initial_health),
+	("Initial Bullets", Ref::None, |m| &mut m.initial_bullets),
+	("Max Health", Ref::None, |m| &mut m.max_health),
+	("Max Armor", Ref::None, |m| &mut m.max_armor),
+	("Green Armor Class", Ref::None, |m| &mut m.green_armor_class),
+	("Blue Armor Class", Ref::None, |m| &mut m.blue_armor_class),
+	("Max Soulsphere", Ref::None, |m| &mut m.max_soulsphere),
+	("Soulsphere Health", Ref::None, |m| &mut m.soulsphere_health),
+	("Megasphere Health", Ref::None, |m| &mut m.megasphere_health),
+	("God Mode Health", Ref::None, |m| &mut m.god_mode_health),
+	("IDFA Armor", Ref::None, |m| &mut m.idfa_armor),
+	("IDFA Armor Class", Ref::None, |m| &mut m.idfa_armor_class),
+	("IDKFA Armor", Ref::None, |m| &mut m.idkfa_armor),
+	("IDKFA Armor Class", Ref::None, |m| &mut m.idkfa_armor_class),
+	("BFG Cells/Shot", Ref::None, |m| &mut m.bfg_cells_per_shot),
+];
+
+/// Fields of `Sound` blocks which held pointers into the executable's memory.
+/// Source ports accept and ignore them.
+const SOUND_IGNORED: &[&str] = &[
+	"Offset",
+	"Zero 1",
+	"Zero 2",
+	"Zero 3",
+	"Zero 4",
+	"Neg. One 1",
+	"Neg. One 2",
+];
+
+struct Context<'t> {
+	tables: &'t mut Tables,
+	/// Every state's code pointer as it was before applying the patch,
+	/// since that is what `Codep Frame` refers to.
+	actions: Vec<Option<&'static str>>,
+	/// Whether the patch has declared `Doom version = 2021`.
+	mbf21: bool,
+	patch_format: Option<(i32, TextRange)>,
+	/// The state, argument number (starting at 1), and field range of every
+	/// `ArgsN` field, to be checked against the state's final code pointer.
+	args: Vec<(usize, usize, TextRange)>,
+	issues: Vec<Issue>,
+}
+
+impl Context<'_> {
+	fn block(&mut self, block: &ast::Block) {
+		match block.kind() {
+			BlockKind::Thing => {
+				let Some(i) = self.index(block, "thing", self.tables.things.len(), 1) else {
+					return;
+				};
+
+				for field in block.fields() {
+					let key = field.key();
+
+					if keys_match(key.text(), "Bits") {
+						if let Some(bits) = self.bits(&field, THING_FLAGS) {
+							self.tables.things[i].flags = bits;
+						}
+					} else if keys_match(key.text(), "MBF21 Bits") {
+						self.require_mbf21(&field);
+
+						if let Some(bits) = self.bits(&field, MBF21_THING_FLAGS) {
+							self.tables.things[i].mbf21_flags = bits;
+						}
+					} else if let Some((accessor, value)) =
+						self.lookup(&field, THING_FIELDS, MBF21_THING_FIELDS)
+					{
+						*accessor(&mut self.tables.things[i]) = value;
+					}
+				}
+			}
+			BlockKind::Frame => {
+				let Some(i) = self.index(block, "frame", self.tables.states.len(), 0) else {
+					return;
+				};
+
+				for field in block.fields() {
+					let key = field.key();
+
+					if keys_match(key.text(), "MBF21 Bits") {
+						self.require_mbf21(&field);
+
+						if let Some(bits) = self.bits(&field, MBF21_FRAME_FLAGS) {
+							self.tables.states[i].mbf21_flags = bits;
+						}
+
+						continue;
+					}
+
+					let Some((accessor, value)) =
+						self.lookup(&field, FRAME_FIELDS, MBF21_FRAME_FIELDS)
+					else {
+						continue;
+					};
+
+					*accessor(&mut self.tables.states[i]) = value;
+
+					let arg = key
+						.text()
+						.trim()
+						.get(4..)
+						.and_then(|n| n.parse::<usize>().ok());
+
+					if let Some(arg) = arg {
+						self.args.push((i, arg, field.syntax().text_range()));
+					}
+				}
+			}
+			BlockKind::Weapon => {
+				let Some(i) = self.index(block, "weapon", self.tables.weapons.len(), 0) else {
+					return;
+				};
+
+				for field in block.fields() {
+					if keys_match(field.key().text(), "MBF21 Bits") {
+						self.require_mbf21(&field);
+
+						if let Some(bits) = self.bits(&field, MBF21_WEAPON_FLAGS) {
+							self.tables.weapons[i].mbf21_flags = bits;
+						}
+					} else if let Some((accessor, value)) =
+						self.lookup(&field, WEAPON_FIELDS, MBF21_WEAPON_FIELDS)
+					{
+						*accessor(&mut self.tables.weapons[i]) = value;
+					}
+				}
+			}
+			BlockKind::Ammo => {
+				let Some(i) = self.index(block, "ammo type", self.tables.ammo.len(), 0) else {
+					return;
+				};
+
+				for field in block.fields() {
+					if let Some((accessor, value)) = self.lookup(&field, AMMO_FIELDS, &[]) {
+						*accessor(&mut self.tables.ammo[i]) = value;
+					}
+				}
+			}
+			BlockKind::Misc => {
+				for field in block.fields() {
+					if keys_match(field.key().text(), "Monsters Infight") {
+						match field.int() {
+							Some(202) => self.tables.misc.monsters_infight = false,
+							Some(221) => self.tables.misc.monsters_infight = true,
+							_ => self.issues.push(Issue::error(
+								value_range(&field),
+								"`Monsters Infight` expects 202 (off) or 221 (on)",
+							)),
+						}
+					} else if let Some((accessor, value)) = self.lookup(&field, MISC_FIELDS, &[]) {
+						*accessor(&mut self.tables.misc) = value;
+					}
+				}
+			}
+			BlockKind::Pointer => self.pointer(block),
+			BlockKind::Cheat => self.cheat(block),
+			BlockKind::Sound => {
+				let Some(i) = self.index(block, "sound", self.tables.sounds.len(), 0) else {
+					return;
+				};
+
+				for field in block.fields() {
+					let key = field.key();
+
+					if keys_match(key.text(), "Value") {
+						if let Some(priority) = self.int(&field, Ref::None) {
+							self.tables.sounds[i].priority = priority;
+						}
+					} else if keys_match(key.text(), "Zero/One") {
+						if let Some(singular) = self.int(&field, Ref::None) {
+							self.tables.sounds[i].singular = singular != 0;
+						}
+					} else if !SOUND_IGNORED.iter().any(|k| keys_match(key.text(), k)) {
+						self.unknown_key(&field);
+					}
+				}
+			}
+			BlockKind::Sprite => {
+				if self
+					.index(block, "sprite", self.tables.sprites.len(), 0)
+					.is_none()
+				{
+					return;
+				}
+
+				for field in block.fields() {
+					if keys_match(field.key().text(), "Offset") {
+						self.issues.push(Issue::warning(
+							field.syntax().text_range(),
+							"sprite offsets can not be applied; \
+							use a `Text` block or a `[SPRITES]` section to rename sprites",
+						));
+					} else {
+						self.unknown_key(&field);
+					}
+				}
+			}
+		}
+	}
+
+	fn pointer(&mut self, block: &ast::Block) {
+		// DeHackEd writes `Pointer N (Frame M)`, where N counts only the states
+		// which originally had a code pointer. Source ports trust M over N.
+		let frame = block.name().and_then(|name| {
+			let mut words = name.split_whitespace();
+			let kw = words.next()?;
+			let num = words.next()?;
+			kw.eq_ignore_ascii_case("Frame")
+				.then(|| num.parse::<usize>().ok())
+				.flatten()
+		});
+
+		let i = match frame {
+			Some(i) if i < self.tables.states.len() => i,
+			Some(i) => {
+				self.issues.push(Issue::error(
+					block.name_token().unwrap().text_range(),
+					format!(
+						"frame {i} is out of range (the last is {})",
+						self.tables.states.len() - 1
+					),
+				));
+
+				return;
+			}
+			None => {
+				let nth = block
+					.number()
+					.and_then(|n| usize::try_from(n).ok())
+					.and_then(|n| {
+						self.actions
+							.iter()
+							.enumerate()
+							.filter(|(_, a)| a.is_some())
+							.nth(n)
+					});
+
+				let Some((i, _)) = nth else {
+					self.issues.push(Issue::error(
+						block.number_token().text_range(),
+						format!(
+							"pointer number out of range (the last is {})",
+							self.actions.iter().filter(|a| a.is_some()).count() - 1
+						),
+					));
+
+					return;
+				};
+
+				i
+			}
+		};
+
+		for field in block.fields() {
+			if !keys_match(field.key().text(), "Codep Frame") {
+				self.unknown_key(&field);
+				continue;
+			}
+
+			if let Some(source) = self.int(&field, Ref::State) {
+				self.tables.states[i].action = self.actions[source as usize];
+			}
+		}
+	}
+
+	fn cheat(&mut self, block: &ast::Block) {
+		for field in block.fields() {
+			let key = field.key();
+
+			let Some(cheat) = self
+				.tables
+				.cheats
+				.iter_mut()
+				.find(|c| keys_match(key.text(), c.name))
+			else {
+				self.unknown_key(&field);
+				continue;
+			};
+
+			cheat.sequence = field.value_text().trim().to_string();
+		}
+	}
+
+	fn top_field(&mut self, field: &ast::Field) {
+		let key = field.key();
+
+		if keys_match(key.text(), "Doom version") {
+			if let Some(version) = self.int(field, Ref::None) {
+				self.mbf21 = version == 2021;
+			}
+		} else if keys_match(key.text(), "Patch format") {
+			if let Some(format) = self.int(field, Ref::None) {
+				self.patch_format = Some((format, value_range(field)));
+			}
+		} else {
+			self.issues.push(Issue::warning(
+				field.syntax().text_range(),
+				format!("`{}` is not valid outside of a block", key.text().trim()),
+			));
+		}
+	}
+
+	fn text(&mut self, text: &ast::TextBlock) {
+		let old = text.old_text();
+		let new = text.new_text();
+		let range = text.syntax().text_range();
+
+		if text.new_len().is_some_and(|len| new.chars().count() < len) {
+			self.issues.push(Issue::warning(
+				range,
+				"the patch ends before the end of this text replacement",
+			));
+		}
+
+		// Sprite and sound names take precedence, as in DeHackEd itself.
+		if old.len() == 4 {
+			if let Some(sprite) = self
+				.tables
+				.sprites
+				.iter_mut()
+				.find(|s| s.eq_ignore_ascii_case(&old))
+			{
+				*sprite = new;
+				return;
+			}
+		}
+
+		if let Some(sound) = self
+			.tables
+			.sounds
+			.iter_mut()
+			.skip(1)
+			.find(|s| s.name.eq_ignore_ascii_case(&old))
+		{
+			sound.name = new;
+			return;
+		}
+
+		let mut found = false;
+
+		for string in self.tables.strings.iter_mut().filter(|s| s.text == old) {
+			string.text.clone_from(&new);
+			found = true;
+		}
+
+		if !found {
+			self.issues.push(Issue::warning(
+				range,
+				format!(
+					"no sprite, sound, or string matches `{}`",
+					old.lines().next().unwrap_or_default()
+				),
+			));
+		}
+	}
+
+	fn section(&mut self, section: &ast::Section) {
+		match section.kind() {
+			SectionKind::Strings => {
+				for field in section.fields() {
+					let key = field.key();
+					let mnemonic = key.text().trim();
+
+					let Some(string) = self
+						.tables
+						.strings
+						.iter_mut()
+						.find(|s| s.mnemonic.eq_ignore_ascii_case(mnemonic))
+					else {
+						self.issues.push(Issue::warning(
+							key.text_range(),
+							format!("`{mnemonic}` is not a known string mnemonic"),
+						));
+
+						continue;
+					};
+
+					string.text = field.string();
+				}
+			}
+			SectionKind::Pars => {
+				for entry in section.pars() {
+					let episode = entry.episode().and_then(|e| i32::try_from(e).ok());
+					let map = entry.map().and_then(|m| i32::try_from(m).ok());
+					let seconds = entry.seconds().and_then(|s| i32::try_from(s).ok());
+
+					let (Some(map), Some(seconds)) = (map, seconds) else {
+						self.issues.push(Issue::error(
+							entry.syntax().text_range(),
+							"expected `par [episode] <map> <seconds>`",
+						));
+
+						continue;
+					};
+
+					match self
+						.tables
+						.pars
+						.iter_mut()
+						.find(|p| p.episode == episode && p.map == map)
+					{
+						Some(par) => par.seconds = seconds,
+						None => self.tables.pars.push(Par {
+							episode,
+							map,
+							seconds,
+						}),
+					}
+				}
+			}
+			SectionKind::CodePtr => {
+				for field in section.fields() {
+					self.code_pointer(&field);
+				}
+			}
+			SectionKind::Sprites => {
+				for field in section.fields() {
+					let Some(i) = self.name_index(&field, Ref::Sprite) else {
+						continue;
+					};
+
+					let new = field.value_text().trim().to_string();
+
+					if new.len() != 4 {
+						self.issues.push(Issue::warning(
+							value_range(&field),
+							"sprite names should be exactly 4 characters long",
+						));
+					}
+
+					self.tables.sprites[i] = new;
+				}
+			}
+			SectionKind::Sounds => {
+				for field in section.fields() {
+					let Some(i) = self.name_index(&field, Ref::Sound) else {
+						continue;
+					};
+
+					let new = field.value_text().trim().to_string();
+
+					if new.is_empty() || new.len() > 6 {
+						self.issues.push(Issue::warning(
+							value_range(&field),
+							"sound names should be between 1 and 6 characters long",
+						));
+					}
+
+					self.tables.sounds[i].name = new;
+				}
+			}
+			SectionKind::Helper => {
+				for field in section.fields() {
+					if !keys_match(field.key().text(), "Type") {
+						self.unknown_key(&field);
+						continue;
+					}
+
+					let Some(num) = self.int(&field, Ref::None) else {
+						continue;
+					};
+
+					if num < 1 || num as usize > self.tables.things.len() {
+						self.issues.push(Issue::error(
+							value_range(&field),
+							format!(
+								"thing {num} is out of range (expected 1 to {})",
+								self.tables.things.len()
+							),
+						));
+
+						continue;
+					}
+
+					self.tables.helper = Some(num);
+				}
+			}
+		}
+	}
+
+	/// Handles `FRAME N = Name` in a `[CODEPTR]` section.
+	fn code_pointer(&mut self, field: &ast::Field) {
+		let key = field.key();
+		let mut words = key.text().split_whitespace();
+
+		let frame = match (words.next(), words.next(), words.next()) {
+			(Some(kw), Some(num), None) if kw.eq_ignore_ascii_case("FRAME") => {
+				num.parse::<usize>().ok()
+			}
+			_ => None,
+		};
+
+		let Some(frame) = frame else {
+			self.issues
+				.push(Issue::error(key.text_range(), "expected `FRAME <number>`"));
+
+			return;
+		};
+
+		if frame >= self.tables.states.len() {
+			self.issues.push(Issue::error(
+				key.text_range(),
+				format!(
+					"frame {frame} is out of range (the last is {})",
+					self.tables.states.len() - 1
+				),
+			));
+
+			return;
+		}
+
+		let text = field.value_text();
+		let name = text.trim();
+
+		if name.eq_ignore_ascii_case("NULL") {
+			self.tables.states[frame].action = None;
+			return;
+		}
+
+		let bare = strip_action_prefix(name);
+		let matches = |cp: &&str| strip_action_prefix(cp).eq_ignore_ascii_case(bare);
+
+		let action = CODE_POINTERS
+			.iter()
+			.chain(MBF_CODE_POINTERS)
+			.copied()
+			.find(matches);
+
+		let action = match action {
+			Some(a) => a,
+			None => match MBF21_CODE_POINTERS.iter().map(|(cp, _)| *cp).find(matches) {
+				Some(a) => {
+					self.require_mbf21(field);
+					a
+				}
+				None => {
+					self.issues.push(Issue::error(
+						value_range(field),
+						format!("`{name}` is not a known code pointer"),
+					));
+
+					return;
+				}
+			},
+		};
+
+		self.tables.states[frame].action = Some(action);
+	}
+
+	/// Resolves the key of a `[SPRITES]` or `[SOUNDS]` entry, which is either
+	/// a name or an index.
+	fn name_index(&mut self, field: &ast::Field, kind: Ref) -> Option<usize> {
+		let key = field.key();
+		let text = key.text().trim();
+
+		let found = if let Ok(i) = text.parse::<usize>() {
+			let len = match kind {
+				Ref::Sprite => self.tables.sprites.len(),
+				_ => self.tables.sounds.len(),
+			};
+
+			(i < len).then_some(i)
+		} else {
+			match kind {
+				Ref::Sprite => self
+					.tables
+					.sprites
+					.iter()
+					.position(|s| s.eq_ignore_ascii_case(text)),
+				_ => self
+					.tables
+					.sounds
+					.iter()
+					.position(|s| s.name.eq_ignore_ascii_case(text)),
+			}
+		};
+
+		if found.is_none() {
+			let what = if kind == Ref::Sprite {
+				"sprite"
+			} else {
+				"sound"
+			};
+
+			self.issues.push(Issue::error(
+				key.text_range(),
+				format!("`{text}` is not a known {what}"),
+			));
+		}
+
+		found
+	}
+
+	/// Checks that a block's number is within `base..(base + len)`,
+	/// returning it as an index into the corresponding table.
+	fn index(&mut self, block: &ast::Block, what: &str, len: usize, base: i64) -> Option<usize> {
+		let num = block.number();
+
+		let index = num
+			.and_then(|n| usize::try_from(n - base).ok())
+			.filter(|i| *i < len);
+
+		if index.is_none() {
+			self.issues.push(Issue::error(
+				block.number_token().text_range(),
+				format!(
+					"{what} {} is out of range (expected {base} to {})",
+					block.number_token().text(),
+					len as i64 - 1 + base,
+				),
+			));
+		}
+
+		index
+	}
+
+	/// Finds `field`'s definition among `defs` or `mbf21_defs` and checks its value.
+	fn lookup<T>(
+		&mut self,
+		field: &ast::Field,
+		defs: &[FieldDef<T>],
+		mbf21_defs: &[FieldDef<T>],
+	) -> Option<(Accessor<T>, i32)> {
+		let key = field.key();
+		let matches = |(k, ..): &&FieldDef<T>| keys_match(key.text(), k);
+
+		let (_, kind, accessor) = match defs.iter().find(matches) {
+			Some(def) => def,
+			None => {
+				let Some(def) = mbf21_defs.iter().find(matches) else {
+					self.unknown_key(field);
+					return None;
+				};
+
+				self.require_mbf21(field);
+				def
+			}
+		};
+
+		self.int(field, *kind).map(|value| (*accessor, value))
+	}
+
+	/// Reads `field`'s value as an integer, checking that it is a valid index
+	/// if `kind` is not [`Ref::None`].
+	fn int(&mut self, field: &ast::Field, kind: Ref) -> Option<i32> {
+		let Some(value) = field.int().and_then(|i| i32::try_from(i).ok()) else {
+			self.issues.push(Issue::error(
+				value_range(field),
+				format!("`{}` expects an integer", field.key().text().trim()),
+			));
+
+			return None;
+		};
+
+		let (what, len) = match kind {
+			Ref::None => return Some(value),
+			Ref::Ammo => {
+				// 5 is `am_noammo`, which is valid despite being past the end.
+				if value == 5 {
+					return Some(value);
+				}
+
+				("ammo type", self.tables.ammo.len())
+			}
+			Ref::Thing => {
+				let len = self.tables.things.len();
+
+				if value < 0 || value as usize > len {
+					self.issues.push(Issue::error(
+						value_range(field),
+						format!("thing {value} is out of range (expected 0 to {len})"),
+					));
+
+					return None;
+				}
+
+				return Some(value);
+			}
+			Ref::Sound => ("sound", self.tables.sounds.len()),
+			Ref::Sprite => ("sprite", self.tables.sprites.len()),
+			Ref::State => ("frame", self.tables.states.len()),
+		};
+
+		if value < 0 || value as usize >= len {
+			self.issues.push(Issue::error(
+				value_range(field),
+				format!("{what} {value} is out of range (the last is {})", len - 1),
+			));
+
+			return None;
+		}
+
+		Some(value)
+	}
+
+	/// Reads the value of a `Bits` field, which is either a number or
+	/// mnemonics from `flags` joined by `+`, `|`, `,`, or whitespace.
+	fn bits(&mut self, field: &ast::Field, flags: &[(&str, u32)]) -> Option<u32> {
+		let text = field.value_text();
+		let text = text.trim();
+
+		if let Ok(num) = text.parse::<i64>() {
+			// Patches written for 32-bit executables may give bit 31 as negative.
+			return Some(num as u32);
+		}
+
+		let mut ret = 0;
+
+		for part in text
+			.split(|c: char| c == '+' || c == '|' || c == ',' || c.is_whitespace())
+			.filter(|p| !p.is_empty())
+		{
+			if let Ok(num) = part.parse::<u32>() {
+				ret |= num;
+				continue;
+			}
+
+			let Some((_, bit)) = flags
+				.iter()
+				.find(|(name, _)| name.eq_ignore_ascii_case(part))
+			else {
+				self.issues.push(Issue::error(
+					value_range(field),
+					format!(
+						"`{part}` is not a known flag for `{}`",
+						field.key().text().trim()
+					),
+				));
+
+				return None;
+			};
+
+			ret |= bit;
+		}
+
+		Some(ret)
+	}
+
+	fn require_mbf21(&mut self, field: &ast::Field) {
+		if !self.mbf21 {
+			self.issues.push(Issue::warning(
+				field.syntax().text_range(),
+				"this is an MBF21 feature, but the patch does not declare `Doom version = 2021`",
+			));
+		}
+	}
+
+	/// Checks what can only be checked once the whole patch has been applied.
+	fn finish(&mut self) {
+		if let Some((format, range)) = self.patch_format {
+			if self.mbf21 && format != 6 {
+				self.issues.push(Issue::error(
+					range,
+					format!("MBF21 patches must be of patch format 6, not {format}"),
+				));
+			}
+		}
+
+		for (state, arg, range) in std::mem::take(&mut self.args) {
+			let action = self.tables.states[state].action;
+
+			let arity = action.and_then(|a| {
+				MBF21_CODE_POINTERS
+					.iter()
+					.find(|(cp, _)| *cp == a)
+					.map(|(_, arity)| *arity)
+			});
+
+			let message = match (action, arity) {
+				(Some(action), Some(arity)) if arg > arity => {
+					format!("`Args{arg}` has no effect; `{action}` takes {arity} argument(s)")
+				}
+				(_, Some(_)) => continue,
+				(Some(action), None) => {
+					format!("`Args{arg}` has no effect; `{action}` is not an MBF21 code pointer")
+				}
+				(None, None) => {
+					format!("`Args{arg}` has no effect; frame {state} has no code pointer")
+				}
+			};
+
+			self.issues.push(Issue::warning(range, message));
+		}
+	}
+
+	fn unknown_key(&mut self, field: &ast::Field) {
+		let key = field.key();
+
+		self.issues.push(Issue::warning(
+			key.text_range(),
+			format!("unknown field `{}`", key.text().trim()),
+		));
+	}
+}
+
+fn value_range(field: &ast::Field) -> TextRange {
+	field
+		.value()
+		.map_or_else(|| field.syntax().text_range(), |v| v.text_range())
+}
+
+fn strip_action_prefix(name: &str) -> &str {
+	match name.get(..2) {
+		Some(prefix) if prefix.eq_ignore_ascii_case("A_") => &name[2..],
+		_ => name,
+	}
+}
@@ -0,0 +1,531 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Block(Block),
+	/// Fields outside of any block, such as `Doom version = 19`.
+	Field(Field),
+	Header(PatchHeader),
+	Include(IncludeDirective),
+	Section(Section),
+	Text(TextBlock),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		Block::can_cast(kind)
+			|| Section::can_cast(kind)
+			|| matches!(
+				kind,
+				Syn::Field | Syn::PatchHeader | Syn::IncludeDirective | Syn::TextBlock
+			)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::Field => Some(Self::Field(Field(node))),
+			Syn::PatchHeader => Some(Self::Header(PatchHeader(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::TextBlock => Some(Self::Text(TextBlock(node))),
+			other if Block::can_cast(other) => Some(Self::Block(Block(node))),
+			other if Section::can_cast(other) => Some(Self::Section(Section(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Block(inner) => &inner.0,
+			Self::Field(inner) => &inner.0,
+			Self::Header(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::Section(inner) => &inner.0,
+			Self::Text(inner) => &inner.0,
+		}
+	}
+}
+
+/// The first token of `node` tagged `syn`, looking only at direct children.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// Every token of `node` tagged `syn`, looking only at direct children.
+fn tokens(node: &SyntaxNode, syn: Syn) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(move |n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// Parses the leading integer of `text` the way C's `atoi` would, so that
+/// trailing garbage (which is common in real patches) is ignored.
+fn leading_int(text: &str) -> Option<i64> {
+	let text = text.trim_start();
+
+	let (negative, unsigned) = match text.as_bytes().first() {
+		Some(b'-') => (true, &text[1..]),
+		Some(b'+') => (false, &text[1..]),
+		_ => (false, text),
+	};
+
+	let end = unsigned
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(unsigned.len());
+
+	let magnitude = unsigned[..end].parse::<i64>().ok()?;
+	Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Field keys are compared case-insensitively, and runs of whitespace are
+/// considered equal, since patches are inconsistent about both.
+#[must_use]
+pub fn keys_match(a: &str, b: &str) -> bool {
+	let mut a = a.split_whitespace();
+	let mut b = b.split_whitespace();
+
+	loop {
+		match (a.next(), b.next()) {
+			(Some(wa), Some(wb)) if wa.eq_ignore_ascii_case(wb) => {}
+			(None, None) => return true,
+			_ => return false,
+		}
+	}
+}
+
+// Top-level items /////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::PatchHeader`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PatchHeader(SyntaxNode);
+
+simple_astnode!(Syn, PatchHeader, Syn::PatchHeader);
+
+impl PatchHeader {
+	/// e.g. `Patch File for DeHackEd v3.0`.
+	#[must_use]
+	pub fn text(&self) -> SyntaxToken {
+		token(&self.0, Syn::HeaderText).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	/// Returns `true` if the directive is `INCLUDE NOTEXT`, which skips
+	/// text blocks in the included patch.
+	#[must_use]
+	pub fn is_no_text(&self) -> bool {
+		token(&self.0, Syn::KwNoText).is_some()
+	}
+
+	/// Returns `None` if the directive is missing its path.
+	#[must_use]
+	pub fn path_token(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::Path)
+	}
+
+	/// The path as written, or an empty string if there is none.
+	#[must_use]
+	pub fn path(&self) -> String {
+		self.path_token()
+			.map(|t| t.text().to_string())
+			.unwrap_or_default()
+	}
+}
+
+/// What a [`Block`] modifies, according to its keyword.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum BlockKind {
+	Ammo,
+	Cheat,
+	Frame,
+	Misc,
+	Pointer,
+	Sound,
+	Sprite,
+	Thing,
+	Weapon,
+}
+
+/// Wraps a node tagged [`Syn::AmmoBlock`], [`Syn::CheatBlock`],
+/// [`Syn::FrameBlock`], [`Syn::MiscBlock`], [`Syn::PointerBlock`],
+/// [`Syn::SoundBlock`], [`Syn::SpriteBlock`], [`Syn::ThingBlock`],
+/// or [`Syn::WeaponBlock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Block(SyntaxNode);
+
+impl AstNode for Block {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::AmmoBlock
+				| Syn::CheatBlock
+				| Syn::FrameBlock
+				| Syn::MiscBlock
+				| Syn::PointerBlock
+				| Syn::SoundBlock
+				| Syn::SpriteBlock
+				| Syn::ThingBlock
+				| Syn::WeaponBlock
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		Self::can_cast(node.kind()).then(|| Self(node))
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		&self.0
+	}
+}
+
+impl Block {
+	#[must_use]
+	pub fn kind(&self) -> BlockKind {
+		match self.0.kind() {
+			Syn::AmmoBlock => BlockKind::Ammo,
+			Syn::CheatBlock => BlockKind::Cheat,
+			Syn::FrameBlock => BlockKind::Frame,
+			Syn::MiscBlock => BlockKind::Misc,
+			Syn::PointerBlock => BlockKind::Pointer,
+			Syn::SoundBlock => BlockKind::Sound,
+			Syn::SpriteBlock => BlockKind::Sprite,
+			Syn::ThingBlock => BlockKind::Thing,
+			Syn::WeaponBlock => BlockKind::Weapon,
+			_ => unreachable!(),
+		}
+	}
+
+	/// e.g. `Thing` in `Thing 1 (Player)`.
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// e.g. `1` in `Thing 1 (Player)`.
+	#[must_use]
+	pub fn number_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitInt).unwrap()
+	}
+
+	/// Returns `None` if the number is out of range.
+	#[must_use]
+	pub fn number(&self) -> Option<i64> {
+		leading_int(self.number_token().text())
+	}
+
+	/// e.g. `(Player)` in `Thing 1 (Player)`. Source ports ignore this.
+	#[must_use]
+	pub fn name_token(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::BlockName)
+	}
+
+	/// The content of [`Self::name_token`] with parentheses stripped away.
+	#[must_use]
+	pub fn name(&self) -> Option<String> {
+		self.name_token().map(|token| {
+			let text = token.text();
+			let text = text.strip_prefix('(').unwrap_or(text);
+			text.strip_suffix(')').unwrap_or(text).trim().to_string()
+		})
+	}
+
+	pub fn fields(&self) -> impl Iterator<Item = Field> {
+		self.0.children().filter_map(Field::cast)
+	}
+
+	/// Keys are compared via [`keys_match`]. If the key is given more than
+	/// once, the last occurrence is returned, since that is the one which
+	/// takes effect.
+	#[must_use]
+	pub fn field(&self, key: &str) -> Option<Field> {
+		self.fields()
+			.filter(|f| keys_match(f.key().text(), key))
+			.last()
+	}
+}
+
+/// Wraps a node tagged [`Syn::TextBlock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TextBlock(SyntaxNode);
+
+simple_astnode!(Syn, TextBlock, Syn::TextBlock);
+
+impl TextBlock {
+	/// The length of the text to replace, as written in the header.
+	#[must_use]
+	pub fn old_len(&self) -> Option<usize> {
+		tokens(&self.0, Syn::LitInt).next()?.text().parse().ok()
+	}
+
+	/// The length of the replacement text, as written in the header.
+	#[must_use]
+	pub fn new_len(&self) -> Option<usize> {
+		tokens(&self.0, Syn::LitInt).nth(1)?.text().parse().ok()
+	}
+
+	/// The text to replace, with carriage returns removed.
+	#[must_use]
+	pub fn old_text(&self) -> String {
+		self.content()
+			.chars()
+			.take(self.old_len().unwrap_or(0))
+			.collect()
+	}
+
+	/// The replacement text, with carriage returns removed. May be shorter
+	/// than [`Self::new_len`] if the patch ended early.
+	#[must_use]
+	pub fn new_text(&self) -> String {
+		self.content()
+			.chars()
+			.skip(self.old_len().unwrap_or(0))
+			.collect()
+	}
+
+	fn content(&self) -> String {
+		tokens(&self.0, Syn::TextContent)
+			.flat_map(|token| {
+				token
+					.text()
+					.chars()
+					.filter(|c| *c != '\r')
+					.collect::<Vec<_>>()
+			})
+			.collect()
+	}
+}
+
+/// What a [`Section`] modifies, according to its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum SectionKind {
+	CodePtr,
+	Helper,
+	Pars,
+	Sounds,
+	Sprites,
+	Strings,
+}
+
+/// Wraps a node tagged [`Syn::CodePtrSection`], [`Syn::HelperSection`],
+/// [`Syn::ParsSection`], [`Syn::SoundsSection`], [`Syn::SpritesSection`],
+/// or [`Syn::StringsSection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Section(SyntaxNode);
+
+impl AstNode for Section {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::CodePtrSection
+				| Syn::HelperSection
+				| Syn::ParsSection
+				| Syn::SoundsSection
+				| Syn::SpritesSection
+				| Syn::StringsSection
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		Self::can_cast(node.kind()).then(|| Self(node))
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		&self.0
+	}
+}
+
+impl Section {
+	#[must_use]
+	pub fn kind(&self) -> SectionKind {
+		match self.0.kind() {
+			Syn::CodePtrSection => SectionKind::CodePtr,
+			Syn::HelperSection => SectionKind::Helper,
+			Syn::ParsSection => SectionKind::Pars,
+			Syn::SoundsSection => SectionKind::Sounds,
+			Syn::SpritesSection => SectionKind::Sprites,
+			Syn::StringsSection => SectionKind::Strings,
+			_ => unreachable!(),
+		}
+	}
+
+	/// e.g. `[STRINGS]`.
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Always empty for `[PARS]` sections.
+	pub fn fields(&self) -> impl Iterator<Item = Field> {
+		self.0.children().filter_map(Field::cast)
+	}
+
+	/// Always empty for sections other than `[PARS]`.
+	pub fn pars(&self) -> impl Iterator<Item = ParEntry> {
+		self.0.children().filter_map(ParEntry::cast)
+	}
+}
+
+// Block and section contents //////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Field`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Field(SyntaxNode);
+
+simple_astnode!(Syn, Field, Syn::Field);
+
+impl Field {
+	/// e.g. `Initial frame`, or `FRAME 123` in a `[CODEPTR]` section.
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		token(&self.0, Syn::FieldKey).unwrap()
+	}
+
+	/// Returns `None` if nothing follows the `=`.
+	#[must_use]
+	pub fn value(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::FieldValue)
+	}
+
+	/// The value verbatim, or an empty string if there is none.
+	#[must_use]
+	pub fn value_text(&self) -> String {
+		self.value()
+			.map(|t| t.text().to_string())
+			.unwrap_or_default()
+	}
+
+	/// Interprets the value as C's `atoi` would, ignoring anything after the
+	/// leading integer. Returns `None` if there are no leading digits.
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		leading_int(self.value()?.text())
+	}
+
+	/// Interprets the value as a string in a `[STRINGS]` section: continued
+	/// lines are joined (with leading whitespace on each continuation removed),
+	/// and the escape sequences `\n`, `\t`, `\"`, and `\\` are processed.
+	#[must_use]
+	pub fn string(&self) -> String {
+		let Some(value) = self.value() else {
+			return String::new();
+		};
+
+		let mut joined = String::new();
+		let mut lines = value.text().split('\n').peekable();
+		let mut first = true;
+
+		while let Some(line) = lines.next() {
+			let mut line = line.trim_end_matches('\r');
+
+			if !first {
+				line = line.trim_start();
+			}
+
+			if lines.peek().is_some() {
+				// The parser only continues a value after a trailing backslash.
+				line = line.trim_end_matches([' ', '\t']);
+				line = line.strip_suffix('\\').unwrap_or(line);
+			}
+
+			joined.push_str(line);
+			first = false;
+		}
+
+		let mut ret = String::with_capacity(joined.len());
+		let mut chars = joined.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		ret
+	}
+}
+
+/// Wraps a node tagged [`Syn::ParEntry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParEntry(SyntaxNode);
+
+simple_astnode!(Syn, ParEntry, Syn::ParEntry);
+
+impl ParEntry {
+	fn numbers(&self) -> Vec<Option<i64>> {
+		tokens(&self.0, Syn::LitInt)
+			.map(|t| leading_int(t.text()))
+			.collect()
+	}
+
+	/// Only present for `par <episode> <map> <seconds>`, as used by Doom 1
+	/// maps; Doom 2 maps use `par <map> <seconds>`.
+	#[must_use]
+	pub fn episode(&self) -> Option<i64> {
+		let nums = self.numbers();
+		(nums.len() == 3).then(|| nums[0]).flatten()
+	}
+
+	#[must_use]
+	pub fn map(&self) -> Option<i64> {
+		let nums = self.numbers();
+		nums[nums.len() - 2]
+	}
+
+	#[must_use]
+	pub fn seconds(&self) -> Option<i64> {
+		self.numbers().last().copied().flatten()
+	}
+}
@@ -0,0 +1,928 @@
+//! Generation of DECORATE or ZScript actors from the changes made by a patch.
+
+use std::{collections::HashMap, fmt::Write};
+
+use super::{
+	vanilla, StateInfo, Tables, ThingInfo, WeaponInfo, MBF21_CODE_POINTERS, MBF21_THING_FLAGS,
+	THING_FLAGS,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActorSyntax {
+	Decorate,
+	ZScript,
+}
+
+/// Something changed by a patch which [`to_actors`] could not express.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Unmapped {
+	/// What was changed, named as a patch would name it, e.g. `Thing 12`.
+	pub subject: String,
+	pub reason: String,
+}
+
+/// Writes an actor for every thing and weapon in `tables` which differs from
+/// its counterpart in `base`, including through the states it uses. Changes to
+/// states which would not alter how they are written out (e.g. arguments which
+/// their code pointers ignore) do not count.
+///
+/// Things and weapons from vanilla Doom become subclasses of their GZDoom
+/// counterparts which replace them, overriding only what changed; others
+/// become new classes named after their thing numbers. Sounds are referred to
+/// by lump name (e.g. `DSPISTOL`) rather than by SNDINFO name.
+///
+/// Changes which can not be expressed by actor definitions (e.g. those to
+/// strings or par times), or which have no equivalent in GZDoom, are returned
+/// alongside the source text.
+#[must_use]
+pub fn to_actors(tables: &Tables, base: &Tables, syntax: ActorSyntax) -> (String, Vec<Unmapped>) {
+	let diff = tables.diff(base);
+
+	// Comparing sprite names means that renaming a sprite changes every state
+	// which uses it.
+	let changed = (0..tables.states.len())
+		.map(|i| i >= base.states.len() || written(tables, i) != written(base, i))
+		.collect();
+
+	let mut gen = Generator {
+		tables,
+		base,
+		syntax,
+		output: String::new(),
+		unmapped: vec![],
+		covered: vec![false; tables.states.len()],
+		changed,
+	};
+
+	for (i, thing) in tables.things.iter().enumerate() {
+		let old = base.things.get(i).copied().unwrap_or(vanilla::THING);
+
+		let labels: Vec<_> = THING_LABELS
+			.iter()
+			.filter(|(_, getter)| gen.label_changed(getter(thing), getter(&old)))
+			.map(|(name, getter)| (*name, getter(thing)))
+			.collect();
+
+		if diff.things.contains(&i) || !labels.is_empty() {
+			gen.thing(i, &labels);
+		}
+	}
+
+	for (i, weapon) in tables.weapons.iter().enumerate() {
+		let Some(old) = base.weapons.get(i) else {
+			continue;
+		};
+
+		let labels: Vec<_> = WEAPON_LABELS
+			.iter()
+			.filter(|(_, getter)| gen.label_changed(getter(weapon), getter(old)))
+			.map(|(name, getter)| (*name, getter(weapon)))
+			.collect();
+
+		if diff.weapons.contains(&i) || !labels.is_empty() {
+			gen.weapon(i, &labels);
+		}
+	}
+
+	for i in diff.states.iter().copied() {
+		if gen.covered[i] {
+			continue;
+		}
+
+		if gen.changed[i] {
+			gen.unmapped(
+				format!("Frame {i}"),
+				"it is not used by any thing or weapon, so no actor includes it",
+			);
+		} else {
+			gen.unmapped(
+				format!("Frame {i}"),
+				"none of its changes affect how GZDoom's actors are written",
+			);
+		}
+	}
+
+	for i in diff.ammo {
+		gen.unmapped(
+			format!("Ammo {i}"),
+			"ammo capacities belong to GZDoom's ammo classes, which can not be replaced",
+		);
+	}
+
+	for i in diff.sounds {
+		gen.unmapped(
+			format!("Sound {i}"),
+			"renaming a sound affects unchanged actors only through SNDINFO",
+		);
+	}
+
+	for mnemonic in diff.strings {
+		gen.unmapped(mnemonic.to_string(), "strings belong in a LANGUAGE lump");
+	}
+
+	for name in diff.cheats {
+		gen.unmapped(
+			format!("Cheat `{name}`"),
+			"GZDoom's cheats can not be redefined",
+		);
+	}
+
+	if diff.misc {
+		gen.unmapped(
+			"Misc".to_string(),
+			"these settings belong to the player class and GAMEINFO, if anywhere",
+		);
+	}
+
+	if diff.pars {
+		gen.unmapped("[PARS]".to_string(), "par times belong in MAPINFO");
+	}
+
+	if diff.helper {
+		gen.unmapped("[HELPER]".to_string(), "GZDoom has no helper dogs");
+	}
+
+	(gen.output, gen.unmapped)
+}
+
+type Getter<T> = fn(&T) -> i32;
+
+const THING_LABELS: &[(&str, Getter<ThingInfo>)] = &[
+	("Spawn", |t| t.spawn_state),
+	("See", |t| t.see_state),
+	("Pain", |t| t.pain_state),
+	("Melee", |t| t.melee_state),
+	("Missile", |t| t.missile_state),
+	("Death", |t| t.death_state),
+	("XDeath", |t| t.xdeath_state),
+	("Raise", |t| t.raise_state),
+];
+
+const WEAPON_LABELS: &[(&str, Getter<WeaponInfo>)] = &[
+	("Select", |w| w.up_state),
+	("Deselect", |w| w.down_state),
+	("Ready", |w| w.ready_state),
+	("Fire", |w| w.attack_state),
+	("Flash", |w| w.flash_state),
+];
+
+/// GZDoom's class for each of vanilla's things, in order.
+const THING_CLASSES: [&str; 137] = [
+	"DoomPlayer",
+	"ZombieMan",
+	"ShotgunGuy",
+	"Archvile",
+	"ArchvileFire",
+	"Revenant",
+	"RevenantTracer",
+	"RevenantTracerSmoke",
+	"Fatso",
+	"FatShot",
+	"ChaingunGuy",
+	"DoomImp",
+	"Demon",
+	"Spectre",
+	"Cacodemon",
+	"BaronOfHell",
+	"BaronBall",
+	"HellKnight",
+	"LostSoul",
+	"SpiderMastermind",
+	"Arachnotron",
+	"Cyberdemon",
+	"PainElemental",
+	"WolfensteinSS",
+	"CommanderKeen",
+	"BossBrain",
+	"BossEye",
+	"BossTarget",
+	"SpawnShot",
+	"SpawnFire",
+	"ExplosiveBarrel",
+	"DoomImpBall",
+	"CacodemonBall",
+	"Rocket",
+	"PlasmaBall",
+	"BFGBall",
+	"ArachnotronPlasma",
+	"BulletPuff",
+	"Blood",
+	"TeleportFog",
+	"ItemFog",
+	"TeleportDest",
+	"BFGExtra",
+	"GreenArmor",
+	"BlueArmor",
+	"HealthBonus",
+	"ArmorBonus",
+	"BlueCard",
+	"RedCard",
+	"YellowCard",
+	"YellowSkull",
+	"RedSkull",
+	"BlueSkull",
+	"Stimpack",
+	"Medikit",
+	"Soulsphere",
+	"InvulnerabilitySphere",
+	"Berserk",
+	"BlurSphere",
+	"RadSuit",
+	"Allmap",
+	"Infrared",
+	"Megasphere",
+	"Clip",
+	"ClipBox",
+	"RocketAmmo",
+	"RocketBox",
+	"Cell",
+	"CellPack",
+	"Shell",
+	"ShellBox",
+	"Backpack",
+	"BFG9000",
+	"Chaingun",
+	"Chainsaw",
+	"RocketLauncher",
+	"PlasmaRifle",
+	"Shotgun",
+	"SuperShotgun",
+	"TechLamp",
+	"TechLamp2",
+	"Column",
+	"TallGreenColumn",
+	"ShortGreenColumn",
+	"TallRedColumn",
+	"ShortRedColumn",
+	"SkullColumn",
+	"HeartColumn",
+	"EvilEye",
+	"FloatingSkull",
+	"TorchTree",
+	"BlueTorch",
+	"GreenTorch",
+	"RedTorch",
+	"ShortBlueTorch",
+	"ShortGreenTorch",
+	"ShortRedTorch",
+	"Stalagtite",
+	"TechPillar",
+	"Candlestick",
+	"Candelabra",
+	"BloodyTwitch",
+	"Meat2",
+	"Meat3",
+	"Meat4",
+	"Meat5",
+	"NonsolidMeat2",
+	"NonsolidMeat4",
+	"NonsolidMeat3",
+	"NonsolidMeat5",
+	"NonsolidTwitch",
+	"DeadCacodemon",
+	"DeadMarine",
+	"DeadZombieMan",
+	"DeadDemon",
+	"DeadLostSoul",
+	"DeadDoomImp",
+	"DeadShotgunGuy",
+	"GibbedMarine",
+	"GibbedMarineExtra",
+	"HeadsOnAStick",
+	"Gibs",
+	"HeadOnAStick",
+	"HeadCandles",
+	"DeadStick",
+	"LiveStick",
+	"BigTree",
+	"BurningBarrel",
+	"HangNoGuts",
+	"HangBNoBrain",
+	"HangTLookingDown",
+	"HangTSkull",
+	"HangTLookingUp",
+	"HangTNoBrain",
+	"ColonGibs",
+	"SmallBloodPool",
+	"BrainStem",
+];
+
+const WEAPON_CLASSES: [&str; 9] = [
+	"Fist",
+	"Pistol",
+	"Shotgun",
+	"Chaingun",
+	"RocketLauncher",
+	"PlasmaRifle",
+	"BFG9000",
+	"Chainsaw",
+	"SuperShotgun",
+];
+
+/// In the order of [`Tables::ammo`].
+const AMMO_CLASSES: [&str; 4] = ["Clip", "Shell", "Cell", "RocketAmmo"];
+
+/// Code pointers which GZDoom only allows in weapon states.
+const WEAPON_POINTERS: &[&str] = &[
+	"A_WeaponReady",
+	"A_Lower",
+	"A_Raise",
+	"A_Punch",
+	"A_ReFire",
+	"A_FirePistol",
+	"A_FireShotgun",
+	"A_FireShotgun2",
+	"A_CheckReload",
+	"A_OpenShotgun2",
+	"A_LoadShotgun2",
+	"A_CloseShotgun2",
+	"A_FireCGun",
+	"A_GunFlash",
+	"A_FireMissile",
+	"A_Saw",
+	"A_FirePlasma",
+	"A_FireBFG",
+	"A_FireOldBFG",
+];
+
+const FRACUNIT: i32 = 1 << 16;
+
+struct Generator<'t> {
+	tables: &'t Tables,
+	base: &'t Tables,
+	syntax: ActorSyntax,
+	output: String,
+	unmapped: Vec<Unmapped>,
+	/// Which states some generated actor has included.
+	covered: Vec<bool>,
+	/// Which states would be written differently than in the base tables;
+	/// see [`written`].
+	changed: Vec<bool>,
+}
+
+impl Generator<'_> {
+	/// Whether a state label needs to be written out, because the state it
+	/// leads to differs or any state in the sequence following it has changed.
+	fn label_changed(&self, new: i32, old: i32) -> bool {
+		new != old || sequence(&self.tables.states, new).any(|i| self.changed[i])
+	}
+
+	fn thing(&mut self, i: usize, labels: &[(&str, i32)]) {
+		let subject = format!("Thing {}", i + 1);
+		let new = self.tables.things[i];
+		let old = self.base.things.get(i).copied().unwrap_or(vanilla::THING);
+		let parent = THING_CLASSES.get(i).copied();
+
+		let name = match parent {
+			Some(class) => format!("Dehacked{class}"),
+			None => format!("DehackedThing{}", i + 1),
+		};
+
+		if i == 0 {
+			self.unmapped(
+				subject.clone(),
+				"player classes can not be replaced; use MAPINFO's `PlayerClasses` instead",
+			);
+		}
+
+		let doomednum =
+			(new.doomednum != old.doomednum && new.doomednum >= 0).then_some(new.doomednum);
+
+		if doomednum.is_some() && self.syntax == ActorSyntax::ZScript {
+			self.unmapped(
+				subject.clone(),
+				"editor numbers must be assigned in MAPINFO's `DoomEdNums` block",
+			);
+		}
+
+		self.header(&name, parent, i != 0, doomednum);
+		self.default_block_start();
+
+		let int_props: [(&str, Getter<ThingInfo>); 5] = [
+			("Health", |t| t.spawn_health),
+			("ReactionTime", |t| t.reaction_time),
+			("PainChance", |t| t.pain_chance),
+			("Mass", |t| t.mass),
+			("Damage", |t| t.damage),
+		];
+
+		for (prop, getter) in int_props {
+			if getter(&new) != getter(&old) {
+				self.property(prop, getter(&new));
+			}
+		}
+
+		let is_missile = (new.flags & vanilla::MF_MISSILE) != 0;
+
+		let speed_props: [(&str, Getter<ThingInfo>); 2] =
+			[("Speed", |t| t.speed), ("FastSpeed", |t| t.fast_speed)];
+
+		for (prop, getter) in speed_props {
+			if getter(&new) != getter(&old) {
+				// Projectile speeds are in fixed point; monster speeds are not.
+				if is_missile {
+					self.property(prop, fixed(getter(&new)));
+				} else {
+					self.property(prop, getter(&new));
+				}
+			}
+		}
+
+		let fixed_props: [(&str, Getter<ThingInfo>); 3] = [
+			("Radius", |t| t.radius),
+			("Height", |t| t.height),
+			("MeleeRange", |t| t.melee_range),
+		];
+
+		for (prop, getter) in fixed_props {
+			if getter(&new) != getter(&old) {
+				self.property(prop, fixed(getter(&new)));
+			}
+		}
+
+		let sound_props: [(&str, Getter<ThingInfo>); 6] = [
+			("SeeSound", |t| t.see_sound),
+			("AttackSound", |t| t.attack_sound),
+			("PainSound", |t| t.pain_sound),
+			("DeathSound", |t| t.death_sound),
+			("ActiveSound", |t| t.active_sound),
+			("RipSound", |t| t.rip_sound),
+		];
+
+		for (prop, getter) in sound_props {
+			if getter(&new) != getter(&old) {
+				let sound = self.sound(getter(&new));
+				self.property(prop, format!("\"{sound}\""));
+			}
+		}
+
+		if new.dropped_item != old.dropped_item {
+			match self.thing_class(new.dropped_item) {
+				Some(class) => self.property("DropItem", format!("\"{class}\"")),
+				None => self.unmapped(
+					subject.clone(),
+					"an inherited `DropItem` can not be removed",
+				),
+			}
+		}
+
+		for (group, new, old) in [
+			("infighting", new.infighting_group, old.infighting_group),
+			("projectile", new.projectile_group, old.projectile_group),
+			("splash", new.splash_group, old.splash_group),
+		] {
+			if new != old {
+				self.unmapped(
+					subject.clone(),
+					format!("GZDoom has no equivalent to MBF21's {group} groups"),
+				);
+			}
+		}
+
+		self.flags(&subject, new.flags, old.flags);
+		self.mbf21_flags(&subject, new.mbf21_flags, old.mbf21_flags);
+		self.default_block_end();
+		self.states(labels, Some(i));
+		self.output.push_str("}\n");
+	}
+
+	fn weapon(&mut self, i: usize, labels: &[(&str, i32)]) {
+		let subject = format!("Weapon {i}");
+		let new = self.tables.weapons[i];
+		let old = self.base.weapons[i];
+		let class = WEAPON_CLASSES[i];
+
+		self.header(&format!("Dehacked{class}"), Some(class), true, None);
+		self.default_block_start();
+
+		if new.ammo != old.ammo {
+			match AMMO_CLASSES.get(new.ammo as usize) {
+				Some(ammo) => self.property("Weapon.AmmoType", format!("\"{ammo}\"")),
+				None => self.unmapped(
+					subject.clone(),
+					"an inherited `Weapon.AmmoType` can not be removed",
+				),
+			}
+		}
+
+		if new.ammo_per_shot != old.ammo_per_shot {
+			self.property("Weapon.AmmoUse", new.ammo_per_shot);
+		}
+
+		if new.mbf21_flags != old.mbf21_flags {
+			self.unmapped(
+				subject.clone(),
+				"GZDoom has no equivalent to MBF21's weapon flags",
+			);
+		}
+
+		self.default_block_end();
+		self.states(labels, None);
+		self.output.push_str("}\n");
+	}
+
+	fn header(&mut self, name: &str, parent: Option<&str>, replaces: bool, doomednum: Option<i32>) {
+		if !self.output.is_empty() {
+			self.output.push('\n');
+		}
+
+		let _ = match self.syntax {
+			ActorSyntax::Decorate => write!(self.output, "actor {name}"),
+			ActorSyntax::ZScript => write!(self.output, "class {name}"),
+		};
+
+		match (parent, self.syntax) {
+			(Some(parent), _) => {
+				let _ = write!(self.output, " : {parent}");
+
+				if replaces {
+					let _ = write!(self.output, " replaces {parent}");
+				}
+			}
+			(None, ActorSyntax::ZScript) => self.output.push_str(" : Actor"),
+			(None, ActorSyntax::Decorate) => {}
+		}
+
+		if let (Some(num), ActorSyntax::Decorate) = (doomednum, self.syntax) {
+			let _ = write!(self.output, " {num}");
+		}
+
+		self.output.push_str("\n{\n");
+	}
+
+	fn default_block_start(&mut self) {
+		if self.syntax == ActorSyntax::ZScript {
+			self.output.push_str("\tDefault\n\t{\n");
+		}
+	}
+
+	fn default_block_end(&mut self) {
+		if self.syntax == ActorSyntax::ZScript {
+			self.output.push_str("\t}\n");
+		}
+	}
+
+	fn property(&mut self, name: &str, value: impl std::fmt::Display) {
+		let _ = match self.syntax {
+			ActorSyntax::Decorate => writeln!(self.output, "\t{name} {value}"),
+			ActorSyntax::ZScript => writeln!(self.output, "\t\t{name} {value};"),
+		};
+	}
+
+	fn flag(&mut self, name: &str, set: bool) {
+		let sign = if set { '+' } else { '-' };
+
+		let _ = match self.syntax {
+			ActorSyntax::Decorate => writeln!(self.output, "\t{sign}{name}"),
+			ActorSyntax::ZScript => writeln!(self.output, "\t\t{sign}{name}"),
+		};
+	}
+
+	fn flags(&mut self, subject: &str, new: u32, old: u32) {
+		for (i, (mnemonic, bit)) in THING_FLAGS.iter().enumerate() {
+			// `TRANSLATION` is an alias of `TRANSLATION1`.
+			if (new ^ old) & bit == 0 || THING_FLAGS[..i].iter().any(|(_, b)| b == bit) {
+				continue;
+			}
+
+			let set = (new & bit) != 0;
+
+			let name = match *mnemonic {
+				"SPECIAL" => {
+					self.unmapped(
+						subject.to_string(),
+						"whether something can be picked up depends on its class in GZDoom",
+					);
+
+					continue;
+				}
+				"TRANSLATION" | "TRANSLATION1" | "TRANSLATION2" => {
+					self.unmapped(
+						subject.to_string(),
+						format!("`{mnemonic}` needs a `Translation` to be defined"),
+					);
+
+					continue;
+				}
+				"TRANSLUCENT" => {
+					if set {
+						self.property("RenderStyle", "\"Translucent\"");
+						self.property("Alpha", 0.666);
+					} else {
+						self.property("RenderStyle", "\"Normal\"");
+					}
+
+					continue;
+				}
+				"SLIDE" => "SLIDESONWALLS",
+				"BOUNCES" => "MBFBOUNCER",
+				"FRIEND" => "FRIENDLY",
+				other => other,
+			};
+
+			self.flag(name, set);
+		}
+	}
+
+	fn mbf21_flags(&mut self, subject: &str, new: u32, old: u32) {
+		for (mnemonic, bit) in MBF21_THING_FLAGS {
+			if (new ^ old) & bit == 0 {
+				continue;
+			}
+
+			let set = (new & bit) != 0;
+
+			let name = match *mnemonic {
+				"LOGRAV" => {
+					self.property("Gravity", if set { 0.125 } else { 1.0 });
+					continue;
+				}
+				"NORADIUSDMG" | "FORCERADIUSDMG" | "BOSS" => *mnemonic,
+				"RANGEHALF" => "MISSILEMORE",
+				"NOTHRESHOLD" => "QUICKTORETALIATE",
+				"RIP" => "RIPPER",
+				_ => {
+					self.unmapped(
+						subject.to_string(),
+						format!("the MBF21 flag `{mnemonic}` has no equivalent GZDoom flag"),
+					);
+
+					continue;
+				}
+			};
+
+			self.flag(name, set);
+		}
+	}
+
+	/// Writes a `States` block with every label in `labels`. `thing` is the
+	/// index of the thing being written, or `None` for a weapon.
+	fn states(&mut self, labels: &[(&str, i32)], thing: Option<usize>) {
+		if labels.is_empty() {
+			return;
+		}
+
+		self.output.push_str("\tStates\n\t{\n");
+
+		// Where each state already written can be found, as a label and offset.
+		let mut written = HashMap::new();
+
+		for (label, start) in labels.iter().copied() {
+			let _ = writeln!(self.output, "\t{label}:");
+
+			if start == 0 {
+				self.keyword("Stop");
+				continue;
+			}
+
+			if let Some(target) = written.get(&(start as usize)) {
+				let target = goto(*target);
+				self.keyword(&target);
+				continue;
+			}
+
+			let mut i = start as usize;
+			let mut offset = 0;
+
+			loop {
+				written.insert(i, (label, offset));
+				self.covered[i] = true;
+				self.state(i, thing);
+
+				let state = self.tables.states[i];
+
+				if state.tics == -1 || state.next_state == 0 {
+					self.keyword("Stop");
+					break;
+				}
+
+				let next = state.next_state as usize;
+
+				match written.get(&next) {
+					Some((l, 0)) if *l == label => {
+						self.keyword("Loop");
+						break;
+					}
+					Some(target) => {
+						let target = goto(*target);
+						self.keyword(&target);
+						break;
+					}
+					None => {
+						i = next;
+						offset += 1;
+					}
+				}
+			}
+		}
+
+		self.output.push_str("\t}\n");
+	}
+
+	fn state(&mut self, i: usize, thing: Option<usize>) {
+		let state = self.tables.states[i];
+
+		let frame = state.frame_char().unwrap_or_else(|| {
+			self.unmapped(
+				format!("Frame {i}"),
+				format!("frame number {} has no letter", state.frame & 0x7FFF),
+			);
+
+			'A'
+		});
+
+		let sprite = &self.tables.sprites[state.sprite as usize];
+		let _ = write!(self.output, "\t\t{sprite} {frame} {}", state.tics);
+
+		if state.is_fullbright() {
+			self.output.push_str(" Bright");
+		}
+
+		if let Some(call) = self.action(i, &state, thing) {
+			let _ = write!(self.output, " {call}");
+		}
+
+		if self.syntax == ActorSyntax::ZScript {
+			self.output.push(';');
+		}
+
+		self.output.push('\n');
+	}
+
+	/// The call to write for a state's code pointer, if it has one which GZDoom
+	/// can express.
+	fn action(&mut self, i: usize, state: &StateInfo, thing: Option<usize>) -> Option<String> {
+		let action = state.action?;
+		let subject = format!("Frame {i}");
+		let (misc1, misc2) = (state.misc1, state.misc2);
+
+		if thing.is_some() && WEAPON_POINTERS.contains(&action) {
+			self.unmapped(
+				subject,
+				format!("`{action}` can only be called by weapons in GZDoom"),
+			);
+
+			return None;
+		}
+
+		let call = match action {
+			"A_Spawn" => {
+				let Some(class) = self.thing_class(misc1) else {
+					self.unmapped(subject, format!("`A_Spawn` names thing {misc1}"));
+					return None;
+				};
+
+				format!("A_SpawnItem(\"{class}\", 0, {misc2})")
+			}
+			"A_Turn" => format!("A_SetAngle(angle + {misc1})"),
+			"A_Face" => format!("A_SetAngle({misc1})"),
+			"A_Scratch" if misc2 == 0 => format!("A_CustomMeleeAttack({misc1})"),
+			"A_Scratch" => format!("A_CustomMeleeAttack({misc1}, \"{}\")", self.sound(misc2)),
+			"A_PlaySound" if misc2 == 0 => format!("A_PlaySound(\"{}\")", self.sound(misc1)),
+			"A_PlaySound" => format!(
+				"A_PlaySound(\"{}\", CHAN_BODY, 1, false, ATTN_NONE)",
+				self.sound(misc1)
+			),
+			"A_Detonate" => match thing {
+				Some(t) => {
+					let damage = self.tables.things[t].damage;
+					format!("A_Explode({damage}, {damage})")
+				}
+				None => {
+					self.unmapped(
+						subject,
+						"`A_Detonate` uses the damage of the thing calling it",
+					);
+
+					return None;
+				}
+			},
+			"A_RandomJump" | "A_LineEffect" => {
+				self.unmapped(
+					subject,
+					format!("`{action}` refers to its targets by number"),
+				);
+
+				return None;
+			}
+			"A_NoiseAlert" | "A_WeaponAlert" => "A_AlertMonsters".to_string(),
+			"A_ClearTracer" => "A_ClearTracer".to_string(),
+			"A_RadiusDamage" => format!("A_Explode({}, {})", state.args[0], state.args[1]),
+			_ if MBF21_CODE_POINTERS.iter().any(|(cp, _)| *cp == action) => {
+				self.unmapped(
+					subject,
+					format!("`{action}` has no GZDoom equivalent taking the same arguments"),
+				);
+
+				return None;
+			}
+			_ => action.to_string(),
+		};
+
+		Some(call)
+	}
+
+	fn keyword(&mut self, keyword: &str) {
+		let _ = match self.syntax {
+			ActorSyntax::Decorate => writeln!(self.output, "\t\t{keyword}"),
+			ActorSyntax::ZScript => writeln!(self.output, "\t\t{keyword};"),
+		};
+	}
+
+	/// The lump name of a sound, or an empty string for sound 0.
+	fn sound(&self, index: i32) -> String {
+		match self.tables.sounds.get(index as usize) {
+			Some(sound) if index > 0 => format!("DS{}", sound.name.to_ascii_uppercase()),
+			_ => String::new(),
+		}
+	}
+
+	/// The class which a generated actor would give to a thing number.
+	fn thing_class(&self, num: i32) -> Option<String> {
+		let i = usize::try_from(num).ok()?.checked_sub(1)?;
+
+		if i >= self.tables.things.len() {
+			return None;
+		}
+
+		let replaced =
+			self.tables.things[i] != self.base.things.get(i).copied().unwrap_or(vanilla::THING);
+
+		Some(match THING_CLASSES.get(i) {
+			Some(class) if replaced => format!("Dehacked{class}"),
+			Some(class) => class.to_string(),
+			None => format!("DehackedThing{num}"),
+		})
+	}
+
+	fn unmapped(&mut self, subject: String, reason: impl Into<String>) {
+		self.unmapped.push(Unmapped {
+			subject,
+			reason: reason.into(),
+		});
+	}
+}
+
+/// Everything about state `i` which [`Generator::state`] and
+/// [`Generator::states`] would write out; only the parameters of code pointers
+/// whose calls make use of them are included.
+fn written(tables: &Tables, i: usize) -> impl PartialEq + '_ {
+	let state = tables.states[i];
+
+	let params = match state.action {
+		Some("A_Spawn" | "A_Turn" | "A_Face" | "A_Scratch" | "A_PlaySound") => {
+			[state.misc1, state.misc2]
+		}
+		Some("A_RadiusDamage") => [state.args[0], state.args[1]],
+		_ => [0, 0],
+	};
+
+	(
+		tables.sprites.get(state.sprite as usize),
+		state.frame,
+		state.tics,
+		(state.tics != -1).then_some(state.next_state),
+		state.action,
+		params,
+	)
+}
+
+/// Every state from `start` onward until the sequence stops or repeats.
+fn sequence(states: &[StateInfo], start: i32) -> impl Iterator<Item = usize> + '_ {
+	let mut seen = vec![];
+	let mut next = Some(start as usize);
+
+	std::iter::from_fn(move || {
+		let i = next.take()?;
+
+		if i == 0 || i >= states.len() || seen.contains(&i) {
+			return None;
+		}
+
+		seen.push(i);
+
+		if states[i].tics != -1 {
+			next = Some(states[i].next_state as usize);
+		}
+
+		Some(i)
+	})
+}
+
+fn goto((label, offset): (&str, usize)) -> String {
+	if offset == 0 {
+		format!("Goto {label}")
+	} else {
+		format!("Goto {label}+{offset}")
+	}
+}
+
+/// Formats a 16.16 fixed point number in map units.
+fn fixed(value: i32) -> String {
+	if value % FRACUNIT == 0 {
+		(value / FRACUNIT).to_string()
+	} else {
+		(f64::from(value) / f64::from(FRACUNIT)).to_string()
+	}
+}
@@ -0,0 +1,105 @@
+//! Data for the extensions to DeHackEd made by MBF, DEHEXTRA, and MBF21.
+//!
+//! DEHEXTRA reserves blocks of things, states, sprites, and sounds past the
+//! ends of those which Boom and MBF add to vanilla's tables, free for patches
+//! to define. MBF21 builds upon DEHEXTRA.
+
+/// Thing numbers go up to this, since DEHEXTRA's `MT_EXTRA00` to `MT_EXTRA99`
+/// are the 151st to 250th things.
+pub(super) const THING_COUNT: usize = 250;
+/// States 1089 to 3999 are DEHEXTRA's.
+pub(super) const STATE_COUNT: usize = 4000;
+pub(super) const SPRITE_COUNT: usize = 245;
+pub(super) const SOUND_COUNT: usize = 700;
+
+/// Sprites added by Boom and MBF, in order after vanilla's.
+pub(super) const SPRITES: [&str; 7] = ["TNT1", "DOGS", "PLS1", "PLS2", "BON3", "BON4", "BLD2"];
+/// The invisible sprite, which DEHEXTRA's states use by default.
+pub(super) const SPR_TNT1: i32 = 138;
+/// The index of `SP00`, the first of DEHEXTRA's sprites.
+pub(super) const FIRST_FREE_SPRITE: usize = 145;
+
+/// Sounds added by MBF, in order after vanilla's.
+pub(super) const SOUNDS: [&str; 5] = ["dgsit", "dgatk", "dgact", "dgdth", "dgpain"];
+/// The index of `fre000`, the first of DEHEXTRA's sounds.
+pub(super) const FIRST_FREE_SOUND: usize = 500;
+
+pub(super) const MBF_CODE_POINTERS: [&str; 13] = [
+	"A_Detonate",
+	"A_Mushroom",
+	"A_Die",
+	"A_Spawn",
+	"A_Turn",
+	"A_Face",
+	"A_Scratch",
+	"A_PlaySound",
+	"A_RandomJump",
+	"A_LineEffect",
+	"A_FireOldBFG",
+	"A_BetaSkullAttack",
+	"A_Stop",
+];
+
+pub(super) const MBF21_CODE_POINTERS: [(&str, usize); 28] = [
+	("A_SpawnObject", 8),
+	("A_MonsterProjectile", 5),
+	("A_MonsterBulletAttack", 5),
+	("A_MonsterMeleeAttack", 4),
+	("A_RadiusDamage", 2),
+	("A_NoiseAlert", 0),
+	("A_HealChase", 2),
+	("A_SeekTracer", 2),
+	("A_FindTracer", 2),
+	("A_ClearTracer", 0),
+	("A_JumpIfHealthBelow", 2),
+	("A_JumpIfTargetInSight", 2),
+	("A_JumpIfTargetCloser", 2),
+	("A_JumpIfTracerInSight", 2),
+	("A_JumpIfTracerCloser", 2),
+	("A_JumpIfFlagsSet", 3),
+	("A_AddFlags", 2),
+	("A_RemoveFlags", 2),
+	("A_WeaponProjectile", 5),
+	("A_WeaponBulletAttack", 5),
+	("A_WeaponMeleeAttack", 5),
+	("A_WeaponSound", 2),
+	("A_WeaponAlert", 0),
+	("A_WeaponJump", 2),
+	("A_ConsumeAmmo", 1),
+	("A_CheckAmmo", 2),
+	("A_RefireTo", 2),
+	("A_GunFlashTo", 2),
+];
+
+pub(super) const MF2_LOGRAV: u32 = 0x1;
+pub(super) const MF2_SHORTMRANGE: u32 = 0x2;
+pub(super) const MF2_DMGIGNORED: u32 = 0x4;
+pub(super) const MF2_NORADIUSDMG: u32 = 0x8;
+pub(super) const MF2_FORCERADIUSDMG: u32 = 0x10;
+pub(super) const MF2_HIGHERMPROB: u32 = 0x20;
+pub(super) const MF2_RANGEHALF: u32 = 0x40;
+pub(super) const MF2_NOTHRESHOLD: u32 = 0x80;
+pub(super) const MF2_LONGMELEE: u32 = 0x100;
+pub(super) const MF2_BOSS: u32 = 0x200;
+pub(super) const MF2_MAP07BOSS1: u32 = 0x400;
+pub(super) const MF2_MAP07BOSS2: u32 = 0x800;
+pub(super) const MF2_E1M8BOSS: u32 = 0x1000;
+pub(super) const MF2_E2M8BOSS: u32 = 0x2000;
+pub(super) const MF2_E3M8BOSS: u32 = 0x4000;
+pub(super) const MF2_E4M6BOSS: u32 = 0x8000;
+pub(super) const MF2_E4M8BOSS: u32 = 0x10000;
+pub(super) const MF2_RIP: u32 = 0x20000;
+pub(super) const MF2_FULLVOLSOUNDS: u32 = 0x40000;
+
+pub(super) const WPF_NOTHRUST: u32 = 0x1;
+pub(super) const WPF_SILENT: u32 = 0x2;
+pub(super) const WPF_NOAUTOFIRE: u32 = 0x4;
+pub(super) const WPF_FLEEMELEE: u32 = 0x8;
+pub(super) const WPF_AUTOSWITCHFROM: u32 = 0x10;
+pub(super) const WPF_NOAUTOSWITCHTO: u32 = 0x20;
+
+pub(super) const STATEF_SKILL5FAST: u32 = 0x1;
+
+/// The projectile group shared by Barons and Hell Knights, whose projectiles
+/// do not hurt each other.
+pub(super) const PG_BARON: i32 = 1;
@@ -0,0 +1,456 @@
+use std::ops::Range;
+
+use chumsky::{primitive, recovery, text, Error, Parser};
+use rowan::{ast::AstNode, GreenNode, GreenToken, SyntaxKind};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{ast, IncludeTree, RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`], since few patches in the wild are entirely
+/// well-formed and the source ports which load them are lenient.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), item(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// Any line which can not be understood in its context raises an error and
+/// gets wrapped into a token tagged [`Syn::Unknown`], after which parsing
+/// resumes on the next line. Within a block or section, such a line does not
+/// end the block; only the start of another block or section does.
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		item(source).recover_with(recovery::skip_parser(unknown_line(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Parses `root` with [`parse_recov`], and then every file it includes via
+/// BEX's `INCLUDE` directive. `fetch` retrieves a file's source given a path
+/// as written in the directive; see [`IncludeTree::new`] for details.
+///
+/// Returns `None` if `fetch` fails to retrieve `root`.
+///
+/// [`IncludeTree::new`]: crate::repr::IncludeTree::new
+#[must_use]
+pub fn parse_include_tree<F>(root: &str, fetch: F) -> Option<IncludeTree>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	IncludeTree::new(
+		root,
+		fetch,
+		|source| {
+			parse_recov(source)
+				.unwrap_or_else(|| RawParseTree::new(GreenNode::new(Syn::Root.into(), []), vec![]))
+		},
+		|zipper| {
+			zipper
+				.children()
+				.filter_map(ast::IncludeDirective::cast)
+				.map(|directive| (directive.path(), directive.syntax().text_range()))
+				.collect()
+		},
+	)
+}
+
+/// The words which open a block when followed by a number.
+const BLOCK_KEYWORDS: &[&str] = &[
+	"ammo", "cheat", "frame", "misc", "pointer", "sound", "sprite", "text", "thing", "weapon",
+];
+
+/// Returns `true` if `line` starts a block, a BEX section, an include
+/// directive, or is a patch header. Such lines end any block before them.
+fn is_opening_line(line: &str) -> bool {
+	let line = line.trim_start();
+
+	if line.starts_with('[') {
+		return true;
+	}
+
+	let mut words = line.split_whitespace();
+
+	let Some(first) = words.next() else {
+		return false;
+	};
+
+	if first.eq_ignore_ascii_case("include") {
+		return true;
+	}
+
+	let second = words.next();
+
+	if first.eq_ignore_ascii_case("patch") {
+		return second.is_some_and(|w| w.eq_ignore_ascii_case("file"));
+	}
+
+	BLOCK_KEYWORDS
+		.iter()
+		.any(|kw| first.eq_ignore_ascii_case(kw))
+		&& second.is_some_and(|w| w.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+// Top-level items /////////////////////////////////////////////////////////////
+
+fn item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		patch_header(src),
+		include_directive(src),
+		text_block(src),
+		block(src, "thing", Syn::KwThing, Syn::ThingBlock),
+		block(src, "frame", Syn::KwFrame, Syn::FrameBlock),
+		block(src, "weapon", Syn::KwWeapon, Syn::WeaponBlock),
+		block(src, "ammo", Syn::KwAmmo, Syn::AmmoBlock),
+		block(src, "sound", Syn::KwSound, Syn::SoundBlock),
+		block(src, "pointer", Syn::KwPointer, Syn::PointerBlock),
+		block(src, "sprite", Syn::KwSprite, Syn::SpriteBlock),
+		block(src, "cheat", Syn::KwCheat, Syn::CheatBlock),
+		block(src, "misc", Syn::KwMisc, Syn::MiscBlock),
+		section(src),
+		field(src, line_value(src)),
+	))
+	.labelled("DeHackEd block")
+}
+
+fn patch_header(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	line()
+		.try_map(move |span, _| {
+			let mut words = src[span.clone()].split_whitespace();
+
+			if words
+				.next()
+				.is_some_and(|w| w.eq_ignore_ascii_case("patch"))
+				&& words.next().is_some_and(|w| w.eq_ignore_ascii_case("file"))
+			{
+				Ok(trim_end(src, span, Syn::HeaderText))
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.collect_n::<Syn, { Syn::PatchHeader as u16 }>()
+}
+
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("include", Syn::KwInclude, src)
+		.start_vec()
+		.chain_push(inline_wsp(src))
+		.chain_append(opt(comb::kw::<Syn>("notext", Syn::KwNoText, src)
+			.start_vec()
+			.chain_push(inline_wsp(src))))
+		.chain_append(line().map(move |span| trim_end(src, span, Syn::Path)))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+}
+
+/// A block's header line (a keyword, a number, and optionally a name),
+/// followed by any number of fields.
+fn block<'i>(
+	src: &'i str,
+	keyword: &'static str,
+	kw_syn: Syn,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	let name = inline_wsp(src)
+		.or_not()
+		.map(|wsp| wsp.into_iter().collect::<Vec<_>>())
+		.chain_append(line().map(move |span| trim_end(src, span, Syn::BlockName)));
+
+	comb::kw::<Syn>(keyword, kw_syn, src)
+		.start_vec()
+		.chain_push(inline_wsp(src))
+		.chain_push(int_lit(src))
+		.chain_append(opt(name))
+		.chain_append(body(src, field(src, line_value(src))))
+		.map(move |elems| node(syn, elems))
+}
+
+/// `Text <old length> <new length>`, then a line break, and then that many
+/// characters of old and new text, which may span lines. As with the original
+/// DeHackEd, carriage returns are not counted. If input ends before the given
+/// lengths are reached, the text block ends with it.
+fn text_block(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let header = comb::kw::<Syn>("text", Syn::KwText, src)
+		.start_vec()
+		.chain_push(inline_wsp(src))
+		.chain_push(int_lit(src))
+		.chain_push(inline_wsp(src))
+		.chain_push(int_lit(src))
+		.chain_push(
+			primitive::one_of(" \t")
+				.repeated()
+				.then(primitive::just('\r').or_not())
+				.then(primitive::just('\n'))
+				.map_with_span(help::map_tok::<Syn, _>(src, Syn::Whitespace)),
+		);
+
+	header
+		.then_with(move |elems: Vec<ParseOut>| {
+			let mut lengths = elems.iter().filter_map(|elem| match elem {
+				ParseOut::Token(token) if token.kind() == Syn::LitInt.into() => {
+					token.text().parse::<usize>().ok()
+				}
+				_ => None,
+			});
+
+			let old_len = lengths.next().unwrap_or(0);
+			let new_len = lengths.next().unwrap_or(0);
+
+			text_content(src, old_len)
+				.chain_append(text_content(src, new_len))
+				.map(move |content| {
+					let mut elems = elems.clone();
+					elems.extend(content);
+					elems
+				})
+		})
+		.collect_n::<Syn, { Syn::TextBlock as u16 }>()
+}
+
+/// Up to `len` characters, not counting carriage returns.
+fn text_content(
+	src: &str,
+	len: usize,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	primitive::just('\r')
+		.repeated()
+		.then(primitive::any())
+		.repeated()
+		.at_most(len)
+		.map_with_span(move |_, span: Range<usize>| {
+			if span.is_empty() {
+				vec![]
+			} else {
+				vec![token(Syn::TextContent, &src[span])]
+			}
+		})
+}
+
+// BEX sections ////////////////////////////////////////////////////////////////
+
+fn section(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		section_of(
+			src,
+			"strings",
+			Syn::StringsSection,
+			field(src, string_value(src)),
+		),
+		section_of(src, "pars", Syn::ParsSection, par_entry(src)),
+		section_of(
+			src,
+			"codeptr",
+			Syn::CodePtrSection,
+			field(src, line_value(src)),
+		),
+		section_of(
+			src,
+			"sprites",
+			Syn::SpritesSection,
+			field(src, line_value(src)),
+		),
+		section_of(
+			src,
+			"sounds",
+			Syn::SoundsSection,
+			field(src, line_value(src)),
+		),
+		section_of(
+			src,
+			"helper",
+			Syn::HelperSection,
+			field(src, line_value(src)),
+		),
+	))
+}
+
+fn section_of<'i, P>(
+	src: &'i str,
+	name: &'static str,
+	syn: Syn,
+	item: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	primitive::just('[')
+		.then(comb::just_nc(name))
+		.then(primitive::just(']'))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::SectionName))
+		.start_vec()
+		.chain_append(body(src, item))
+		.map(move |elems| node(syn, elems))
+}
+
+fn par_entry(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let number = inline_wsp(src).start_vec().chain_push(int_lit(src));
+
+	comb::kw::<Syn>("par", Syn::KwPar, src)
+		.start_vec()
+		.chain_append(number.repeated().at_least(2).at_most(3).flatten())
+		.collect_n::<Syn, { Syn::ParEntry as u16 }>()
+}
+
+// Block and section contents //////////////////////////////////////////////////
+
+/// Any number of `item`s, comments, and whitespace, up until the start of the
+/// next block or section. Lines which are not `item`s raise an error.
+fn body<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	let bad_line = line().try_map(move |span, _| {
+		if is_opening_line(&src[span.clone()]) {
+			Err(ParseError::expected_input_found(span, None, None))
+		} else {
+			Ok(token(Syn::Unknown, &src[span]))
+		}
+	});
+
+	primitive::choice((
+		wsp_ext(src),
+		item.recover_with(recovery::skip_parser(bad_line)),
+	))
+	.repeated()
+}
+
+/// `key = value`, where `value` is a parser for everything after the `=`
+/// and any whitespace following it.
+fn field<'i, P>(
+	src: &'i str,
+	value: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i,
+{
+	primitive::none_of("=\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(move |_, span| trim_end(src, span, Syn::FieldKey))
+		.chain_push(comb::just::<Syn, _>('=', Syn::Eq, src))
+		.chain_append(opt(inline_wsp(src).start_vec()))
+		.chain_append(value)
+		.collect_n::<Syn, { Syn::Field as u16 }>()
+}
+
+/// The rest of the line, if there is any.
+fn line_value(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	opt(line().map(move |span| trim_end(src, span, Syn::FieldValue)))
+}
+
+/// Like [`line_value`], but a backslash at the end of a line continues the
+/// value onto the next one.
+fn string_value(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let continuation = primitive::just('\\')
+		.then(primitive::one_of(" \t").repeated())
+		.then(primitive::just('\r').or_not())
+		.then(primitive::just('\n'))
+		.ignored();
+
+	let part = primitive::choice((continuation, primitive::none_of("\r\n").ignored()));
+
+	opt(part
+		.repeated()
+		.at_least(1)
+		.map_with_span(move |_, span| trim_end(src, span, Syn::FieldValue)))
+}
+
+// Common combinators //////////////////////////////////////////////////////////
+
+fn node(syn: Syn, elems: Vec<ParseOut>) -> ParseOut {
+	ParseOut::Node(GreenNode::new(SyntaxKind::from(syn), elems))
+}
+
+fn token(syn: Syn, text: &str) -> ParseOut {
+	ParseOut::Token(GreenToken::new(SyntaxKind::from(syn), text))
+}
+
+/// Tags `src[span]` as `syn`, except for trailing spaces and tabs,
+/// which become a separate whitespace token.
+fn trim_end(src: &str, span: Range<usize>, syn: Syn) -> Vec<ParseOut> {
+	let text = &src[span];
+	let trimmed = text.trim_end_matches([' ', '\t']);
+	let mut ret = vec![];
+
+	if !trimmed.is_empty() {
+		ret.push(token(syn, trimmed));
+	}
+
+	if trimmed.len() < text.len() {
+		ret.push(token(Syn::Whitespace, &text[trimmed.len()..]));
+	}
+
+	ret
+}
+
+/// The span of one or more characters up until the end of the line.
+fn line() -> impl Parser<char, Range<usize>, Error = ParseError> + Clone {
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(|_, span| span)
+}
+
+/// Skips the rest of the line.
+fn unknown_line(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	line().map(move |span| token(Syn::Unknown, &src[span]))
+}
+
+/// Lines starting with `#`.
+fn comment(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('#')
+		.then(primitive::none_of("\r\n").repeated())
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Comment))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comment(src), src)
+}
+
+/// Spaces and tabs, but not line breaks.
+fn inline_wsp(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::one_of(" \t")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Whitespace))
+}
+
+/// Wraps the output of `parser` in a `Vec` if it succeeds, or returns an
+/// empty `Vec` otherwise. For optional sequences of syntax elements.
+fn opt<'s, P>(parser: P) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's,
+{
+	parser.or_not().map(Option::unwrap_or_default)
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('-')
+		.or_not()
+		.then(text::digits::<char, ParseError>(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer")
+}
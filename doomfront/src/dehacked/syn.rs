@@ -0,0 +1,149 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// DeHackEd syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes: blocks ///////////////////////////////////////////////////////////
+	/// `Ammo <number> [(<name>)]` and the fields following it.
+	AmmoBlock,
+	/// `Cheat 0` and the fields following it.
+	CheatBlock,
+	/// `Frame <number>` and the fields following it.
+	FrameBlock,
+	/// `Misc 0` and the fields following it.
+	MiscBlock,
+	/// `Pointer <number> (Frame <number>)` and the fields following it.
+	PointerBlock,
+	/// `Sound <number>` and the fields following it.
+	SoundBlock,
+	/// `Sprite <number>` and the fields following it. Only used by DeHackEd
+	/// versions prior to 2.3, but still accepted by most source ports.
+	SpriteBlock,
+	/// `Text <old length> <new length>`, followed by the old and new text.
+	TextBlock,
+	/// `Thing <number> [(<name>)]` and the fields following it.
+	ThingBlock,
+	/// `Weapon <number> [(<name>)]` and the fields following it.
+	WeaponBlock,
+
+	// Nodes: BEX sections /////////////////////////////////////////////////////
+	/// `[CODEPTR]`, followed by `Frame <number> = <pointer>` fields.
+	CodePtrSection,
+	/// `[HELPER]`, followed by fields.
+	HelperSection,
+	/// `[PARS]`, followed by `par` lines.
+	ParsSection,
+	/// `[SOUNDS]`, followed by `<old name> = <new name>` fields.
+	SoundsSection,
+	/// `[SPRITES]`, followed by `<old name> = <new name>` fields.
+	SpritesSection,
+	/// `[STRINGS]`, followed by `<mnemonic> = <text>` fields. A value ending
+	/// in a backslash continues onto the next line.
+	StringsSection,
+
+	// Nodes: miscellaneous ////////////////////////////////////////////////////
+	/// `<key> = <value>`. Keys may contain spaces (e.g. `Initial frame`),
+	/// and the value is the rest of the line, which may be empty.
+	Field,
+	/// BEX's `INCLUDE [NOTEXT] <path>`.
+	IncludeDirective,
+	/// `par [<episode>] <map> <seconds>`, within a `[PARS]` section.
+	ParEntry,
+	/// The `Patch File for DeHackEd v3.0` line opening most patches.
+	PatchHeader,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	/// May have a leading minus sign.
+	LitInt,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	/// The keyword `Ammo`.
+	KwAmmo,
+	/// The keyword `Cheat`.
+	KwCheat,
+	/// The keyword `Frame`.
+	KwFrame,
+	/// The keyword `INCLUDE`.
+	KwInclude,
+	/// The keyword `Misc`.
+	KwMisc,
+	/// The keyword `NOTEXT`, only used in include directives.
+	KwNoText,
+	/// The keyword `par`, only used in `[PARS]` sections.
+	KwPar,
+	/// The keyword `Pointer`.
+	KwPointer,
+	/// The keyword `Sound`.
+	KwSound,
+	/// The keyword `Sprite`.
+	KwSprite,
+	/// The keyword `Text`.
+	KwText,
+	/// The keyword `Thing`.
+	KwThing,
+	/// The keyword `Weapon`.
+	KwWeapon,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `=`
+	Eq,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// Anything following the numbers on a block's header line,
+	/// such as `(Player)` in `Thing 1 (Player)`.
+	BlockName,
+	/// Lines starting with `#` are comments, unless they are inside a text block.
+	Comment,
+	/// Everything to the left of a field's `=`, without trailing whitespace.
+	FieldKey,
+	/// Everything to the right of a field's `=`, without surrounding whitespace.
+	FieldValue,
+	/// The entire text of a [`Syn::PatchHeader`] line.
+	HeaderText,
+	/// The path in an include directive.
+	Path,
+	/// A BEX section header, brackets included, such as `[STRINGS]`.
+	SectionName,
+	/// The raw replaced or replacement text in a text block.
+	TextContent,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
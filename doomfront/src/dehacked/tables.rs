@@ -0,0 +1,469 @@
+//! A model of the game data which DeHackEd patches modify.
+
+use super::{mbf21, vanilla};
+
+/// One entry of the `mobjinfo` table, describing a type of map object.
+///
+/// Fields ending in `_state` are indices into [`Tables::states`], and fields
+/// ending in `_sound` are indices into [`Tables::sounds`]. `radius` and `height`
+/// are in 16.16 fixed point, as is `speed` for projectiles; monsters move by
+/// whole map units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct ThingInfo {
+	/// The editor number by which maps place this thing, or -1 if there is none.
+	pub doomednum: i32,
+	pub spawn_state: i32,
+	pub spawn_health: i32,
+	pub see_state: i32,
+	pub see_sound: i32,
+	pub reaction_time: i32,
+	pub attack_sound: i32,
+	pub pain_state: i32,
+	/// Out of 256.
+	pub pain_chance: i32,
+	pub pain_sound: i32,
+	pub melee_state: i32,
+	pub missile_state: i32,
+	pub death_state: i32,
+	/// Entered instead of `death_state` when the thing is gibbed.
+	pub xdeath_state: i32,
+	pub death_sound: i32,
+	pub speed: i32,
+	pub radius: i32,
+	pub height: i32,
+	pub mass: i32,
+	pub damage: i32,
+	pub active_sound: i32,
+	/// See [`THING_FLAGS`] for the meaning of each bit.
+	pub flags: u32,
+	/// Entered when an Arch-vile resurrects this thing.
+	pub raise_state: i32,
+	/// See [`MBF21_THING_FLAGS`] for the meaning of each bit.
+	pub mbf21_flags: u32,
+	/// Things in the same non-zero group do not retaliate against each other.
+	pub infighting_group: i32,
+	/// Things in the same non-zero group are immune to each other's
+	/// projectiles; -1 means no immunity at all, even to the same type.
+	pub projectile_group: i32,
+	/// Things in the same non-zero group are immune to each other's splash damage.
+	pub splash_group: i32,
+	/// The speed on nightmare difficulty or with `-fast`; 0 means `speed`.
+	pub fast_speed: i32,
+	/// In 16.16 fixed point.
+	pub melee_range: i32,
+	/// Played when this thing rips through another, if it has `RIP` set.
+	pub rip_sound: i32,
+	/// The number of the thing dropped upon death, as in `Thing` blocks;
+	/// 0 if there is none.
+	pub dropped_item: i32,
+}
+
+/// One entry of the `states` table, also known as a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct StateInfo {
+	/// An index into [`Tables::sprites`].
+	pub sprite: i32,
+	/// 0 for `A`, 1 for `B`, and so on. Adding `0x8000` makes the frame fullbright.
+	pub frame: i32,
+	/// -1 means this state lasts forever.
+	pub tics: i32,
+	/// The name of the action function called upon entering this state,
+	/// e.g. `A_Look`. Always one of [`CODE_POINTERS`], [`MBF_CODE_POINTERS`],
+	/// or [`MBF21_CODE_POINTERS`].
+	pub action: Option<&'static str>,
+	/// An index into [`Tables::states`].
+	pub next_state: i32,
+	pub misc1: i32,
+	pub misc2: i32,
+	/// See [`MBF21_FRAME_FLAGS`] for the meaning of each bit.
+	pub mbf21_flags: u32,
+	/// Arguments to MBF21 code pointers; see [`MBF21_CODE_POINTERS`].
+	pub args: [i32; 8],
+}
+
+impl StateInfo {
+	/// Returns `true` if the state is rendered without regard to sector light.
+	#[must_use]
+	pub fn is_fullbright(&self) -> bool {
+		(self.frame & 0x8000) != 0
+	}
+
+	/// The frame letter, e.g. `A`, ignoring the fullbright bit. Returns `None`
+	/// if the frame number has no corresponding letter.
+	#[must_use]
+	pub fn frame_char(&self) -> Option<char> {
+		let index = u8::try_from(self.frame & 0x7FFF).ok()?;
+		(index < 29).then(|| char::from(b'A' + index))
+	}
+}
+
+/// One entry of the `weaponinfo` table. Fields ending in `_state` are indices
+/// into [`Tables::states`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct WeaponInfo {
+	/// An index into [`Tables::ammo`], or 5 if the weapon uses no ammunition.
+	pub ammo: i32,
+	/// Called `Deselect frame` by DeHackEd.
+	pub up_state: i32,
+	/// Called `Select frame` by DeHackEd.
+	pub down_state: i32,
+	/// Called `Bobbing frame` by DeHackEd.
+	pub ready_state: i32,
+	/// Called `Shooting frame` by DeHackEd.
+	pub attack_state: i32,
+	/// Called `Firing frame` by DeHackEd.
+	pub flash_state: i32,
+	pub ammo_per_shot: i32,
+	/// See [`MBF21_WEAPON_FLAGS`] for the meaning of each bit.
+	pub mbf21_flags: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct AmmoInfo {
+	/// The most the player can carry without a backpack.
+	pub max: i32,
+	/// How much a small pickup (e.g. a clip) gives.
+	pub per_pickup: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct SoundInfo {
+	/// The lump name, without the `DS` (or `DP`) prefix.
+	pub name: String,
+	pub priority: i32,
+	pub singular: bool,
+}
+
+/// Values set by a `Misc` block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Misc {
+	pub initial_health: i32,
+	pub initial_bullets: i32,
+	pub max_health: i32,
+	pub max_armor: i32,
+	pub green_armor_class: i32,
+	pub blue_armor_class: i32,
+	pub max_soulsphere: i32,
+	pub soulsphere_health: i32,
+	pub megasphere_health: i32,
+	pub god_mode_health: i32,
+	pub idfa_armor: i32,
+	pub idfa_armor_class: i32,
+	pub idkfa_armor: i32,
+	pub idkfa_armor_class: i32,
+	pub bfg_cells_per_shot: i32,
+	/// Whether monsters of the same species attack each other.
+	pub monsters_infight: bool,
+}
+
+/// A string shown by the game, such as a pickup message or intermission text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct GameString {
+	/// The name by which BEX `[STRINGS]` sections refer to this string,
+	/// e.g. `GOTARMOR`.
+	pub mnemonic: &'static str,
+	pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Cheat {
+	/// The name by which `Cheat` blocks refer to this cheat, e.g. `God mode`.
+	pub name: &'static str,
+	/// What the player types, e.g. `iddqd`.
+	pub sequence: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Par {
+	/// `None` for Doom II maps, which are not divided into episodes.
+	pub episode: Option<i32>,
+	pub map: i32,
+	pub seconds: i32,
+}
+
+/// Everything a DeHackEd patch can change. [`Tables::vanilla`] gives the data
+/// built into Doom v1.9, and [`Tables::apply`] modifies it according to a patch.
+///
+/// Each table is indexed as DeHackEd indexes it, except that `things[0]` is
+/// `Thing 1`, since DeHackEd's thing numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Tables {
+	pub things: Vec<ThingInfo>,
+	pub states: Vec<StateInfo>,
+	pub weapons: Vec<WeaponInfo>,
+	pub ammo: Vec<AmmoInfo>,
+	/// Four-character sprite name prefixes, e.g. `TROO`.
+	pub sprites: Vec<String>,
+	/// `sounds[0]` is a placeholder for "no sound".
+	pub sounds: Vec<SoundInfo>,
+	pub strings: Vec<GameString>,
+	pub cheats: Vec<Cheat>,
+	pub misc: Misc,
+	pub pars: Vec<Par>,
+	/// The thing number set by a BEX `[HELPER]` section, if any. MBF spawns
+	/// this type of thing for its "helper dogs" command-line option.
+	pub helper: Option<i32>,
+}
+
+impl Tables {
+	#[must_use]
+	pub fn vanilla() -> Self {
+		Self {
+			things: vanilla::THINGS.to_vec(),
+			states: vanilla::STATES.to_vec(),
+			weapons: vanilla::WEAPONS.to_vec(),
+			ammo: vanilla::AMMO.to_vec(),
+			sprites: vanilla::SPRITES.iter().map(|s| s.to_string()).collect(),
+			sounds: vanilla::SOUNDS
+				.iter()
+				.map(|(name, priority)| SoundInfo {
+					name: name.to_string(),
+					priority: *priority,
+					singular: false,
+				})
+				.collect(),
+			strings: vanilla::STRINGS
+				.iter()
+				.map(|(mnemonic, text)| GameString {
+					mnemonic,
+					text: text.to_string(),
+				})
+				.collect(),
+			cheats: vanilla::CHEATS
+				.iter()
+				.map(|(name, sequence)| Cheat {
+					name,
+					sequence: sequence.to_string(),
+				})
+				.collect(),
+			misc: vanilla::MISC,
+			pars: vanilla::pars(),
+			helper: None,
+		}
+	}
+
+	/// The tables of an MBF21 source port: [`Tables::vanilla`], extended to the
+	/// limits set by DEHEXTRA.
+	///
+	/// The things and states which Boom and MBF add are left blank, as are
+	/// DEHEXTRA's own; they only need to exist for patches to fill in. Blank
+	/// states show the invisible `TNT1` sprite forever.
+	#[must_use]
+	pub fn mbf21() -> Self {
+		let mut ret = Self::vanilla();
+
+		ret.things.resize(mbf21::THING_COUNT, vanilla::THING);
+
+		let first_state = ret.states.len();
+
+		ret.states
+			.extend((first_state..mbf21::STATE_COUNT).map(|i| StateInfo {
+				sprite: mbf21::SPR_TNT1,
+				frame: 0,
+				tics: -1,
+				action: None,
+				next_state: i as i32,
+				misc1: 0,
+				misc2: 0,
+				mbf21_flags: 0,
+				args: [0; 8],
+			}));
+
+		ret.sprites
+			.extend(mbf21::SPRITES.iter().map(|s| s.to_string()));
+		ret.sprites.extend(
+			(0..(mbf21::SPRITE_COUNT - mbf21::FIRST_FREE_SPRITE)).map(|i| format!("SP{i:02}")),
+		);
+
+		let sound = |name: String| SoundInfo {
+			name,
+			priority: 127,
+			singular: false,
+		};
+
+		ret.sounds
+			.extend(mbf21::SOUNDS.iter().map(|s| sound(s.to_string())));
+		ret.sounds
+			.resize_with(mbf21::FIRST_FREE_SOUND, || sound(String::new()));
+		ret.sounds.extend(
+			(0..(mbf21::SOUND_COUNT - mbf21::FIRST_FREE_SOUND))
+				.map(|i| sound(format!("fre{i:03}"))),
+		);
+
+		ret
+	}
+
+	/// Look up a string by its BEX mnemonic, ignoring case.
+	#[must_use]
+	pub fn string(&self, mnemonic: &str) -> Option<&str> {
+		self.strings
+			.iter()
+			.find(|s| s.mnemonic.eq_ignore_ascii_case(mnemonic))
+			.map(|s| s.text.as_str())
+	}
+
+	/// Everything in `self` that differs from `base`; typically, `base` is
+	/// [`Tables::vanilla`] and `self` is the result of applying a patch to it.
+	#[must_use]
+	pub fn diff(&self, base: &Self) -> TableDiff {
+		TableDiff {
+			things: changed(&self.things, &base.things),
+			states: changed(&self.states, &base.states),
+			weapons: changed(&self.weapons, &base.weapons),
+			ammo: changed(&self.ammo, &base.ammo),
+			sprites: changed(&self.sprites, &base.sprites),
+			sounds: changed(&self.sounds, &base.sounds),
+			strings: self
+				.strings
+				.iter()
+				.filter(|s| base.string(s.mnemonic) != Some(s.text.as_str()))
+				.map(|s| s.mnemonic)
+				.collect(),
+			cheats: self
+				.cheats
+				.iter()
+				.filter(|c| !base.cheats.contains(c))
+				.map(|c| c.name)
+				.collect(),
+			misc: self.misc != base.misc,
+			pars: self.pars != base.pars,
+			helper: self.helper != base.helper,
+		}
+	}
+}
+
+impl Default for Tables {
+	fn default() -> Self {
+		Self::vanilla()
+	}
+}
+
+/// The parts of one [`Tables`] which differ from another, as returned by
+/// [`Tables::diff`]. Each `Vec<usize>` holds indices into the corresponding
+/// table, in ascending order; entries past the end of the base table count
+/// as changed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct TableDiff {
+	pub things: Vec<usize>,
+	pub states: Vec<usize>,
+	pub weapons: Vec<usize>,
+	pub ammo: Vec<usize>,
+	pub sprites: Vec<usize>,
+	pub sounds: Vec<usize>,
+	/// Mnemonics of every changed string.
+	pub strings: Vec<&'static str>,
+	/// Names of every changed cheat.
+	pub cheats: Vec<&'static str>,
+	pub misc: bool,
+	pub pars: bool,
+	pub helper: bool,
+}
+
+impl TableDiff {
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+fn changed<T: PartialEq>(new: &[T], old: &[T]) -> Vec<usize> {
+	new.iter()
+		.enumerate()
+		.filter(|(i, entry)| old.get(*i) != Some(*entry))
+		.map(|(i, _)| i)
+		.collect()
+}
+
+/// The mnemonics accepted by BEX for each bit of [`ThingInfo::flags`].
+pub const THING_FLAGS: &[(&str, u32)] = &[
+	("SPECIAL", vanilla::MF_SPECIAL),
+	("SOLID", vanilla::MF_SOLID),
+	("SHOOTABLE", vanilla::MF_SHOOTABLE),
+	("NOSECTOR", vanilla::MF_NOSECTOR),
+	("NOBLOCKMAP", vanilla::MF_NOBLOCKMAP),
+	("AMBUSH", vanilla::MF_AMBUSH),
+	("JUSTHIT", vanilla::MF_JUSTHIT),
+	("JUSTATTACKED", vanilla::MF_JUSTATTACKED),
+	("SPAWNCEILING", vanilla::MF_SPAWNCEILING),
+	("NOGRAVITY", vanilla::MF_NOGRAVITY),
+	("DROPOFF", vanilla::MF_DROPOFF),
+	("PICKUP", vanilla::MF_PICKUP),
+	("NOCLIP", vanilla::MF_NOCLIP),
+	("SLIDE", vanilla::MF_SLIDE),
+	("FLOAT", vanilla::MF_FLOAT),
+	("TELEPORT", vanilla::MF_TELEPORT),
+	("MISSILE", vanilla::MF_MISSILE),
+	("DROPPED", vanilla::MF_DROPPED),
+	("SHADOW", vanilla::MF_SHADOW),
+	("NOBLOOD", vanilla::MF_NOBLOOD),
+	("CORPSE", vanilla::MF_CORPSE),
+	("INFLOAT", vanilla::MF_INFLOAT),
+	("COUNTKILL", vanilla::MF_COUNTKILL),
+	("COUNTITEM", vanilla::MF_COUNTITEM),
+	("SKULLFLY", vanilla::MF_SKULLFLY),
+	("NOTDMATCH", vanilla::MF_NOTDMATCH),
+	("TRANSLATION", vanilla::MF_TRANSLATION1),
+	("TRANSLATION1", vanilla::MF_TRANSLATION1),
+	("TRANSLATION2", vanilla::MF_TRANSLATION2),
+	("TRANSLUCENT", vanilla::MF_TRANSLUCENT),
+	("TOUCHY", vanilla::MF_TOUCHY),
+	("BOUNCES", vanilla::MF_BOUNCES),
+	("FRIEND", vanilla::MF_FRIEND),
+];
+
+/// The mnemonics accepted by MBF21 for each bit of [`ThingInfo::mbf21_flags`].
+pub const MBF21_THING_FLAGS: &[(&str, u32)] = &[
+	("LOGRAV", mbf21::MF2_LOGRAV),
+	("SHORTMRANGE", mbf21::MF2_SHORTMRANGE),
+	("DMGIGNORED", mbf21::MF2_DMGIGNORED),
+	("NORADIUSDMG", mbf21::MF2_NORADIUSDMG),
+	("FORCERADIUSDMG", mbf21::MF2_FORCERADIUSDMG),
+	("HIGHERMPROB", mbf21::MF2_HIGHERMPROB),
+	("RANGEHALF", mbf21::MF2_RANGEHALF),
+	("NOTHRESHOLD", mbf21::MF2_NOTHRESHOLD),
+	("LONGMELEE", mbf21::MF2_LONGMELEE),
+	("BOSS", mbf21::MF2_BOSS),
+	("MAP07BOSS1", mbf21::MF2_MAP07BOSS1),
+	("MAP07BOSS2", mbf21::MF2_MAP07BOSS2),
+	("E1M8BOSS", mbf21::MF2_E1M8BOSS),
+	("E2M8BOSS", mbf21::MF2_E2M8BOSS),
+	("E3M8BOSS", mbf21::MF2_E3M8BOSS),
+	("E4M6BOSS", mbf21::MF2_E4M6BOSS),
+	("E4M8BOSS", mbf21::MF2_E4M8BOSS),
+	("RIP", mbf21::MF2_RIP),
+	("FULLVOLSOUNDS", mbf21::MF2_FULLVOLSOUNDS),
+];
+
+/// The mnemonics accepted by MBF21 for each bit of [`StateInfo::mbf21_flags`].
+pub const MBF21_FRAME_FLAGS: &[(&str, u32)] = &[("SKILL5FAST", mbf21::STATEF_SKILL5FAST)];
+
+/// The mnemonics accepted by MBF21 for each bit of [`WeaponInfo::mbf21_flags`].
+pub const MBF21_WEAPON_FLAGS: &[(&str, u32)] = &[
+	("NOTHRUST", mbf21::WPF_NOTHRUST),
+	("SILENT", mbf21::WPF_SILENT),
+	("NOAUTOFIRE", mbf21::WPF_NOAUTOFIRE),
+	("FLEEMELEE", mbf21::WPF_FLEEMELEE),
+	("AUTOSWITCHFROM", mbf21::WPF_AUTOSWITCHFROM),
+	("NOAUTOSWITCHTO", mbf21::WPF_NOAUTOSWITCHTO),
+];
+
+/// The action functions which a state's code pointer can be set to.
+pub const CODE_POINTERS: &[&str] = &vanilla::CODE_POINTERS;
+
+/// The action functions added by MBF.
+pub const MBF_CODE_POINTERS: &[&str] = &mbf21::MBF_CODE_POINTERS;
+
+/// The action functions added by MBF21, each with how many of
+/// [`StateInfo::args`] it takes.
+pub const MBF21_CODE_POINTERS: &[(&str, usize)] = &mbf21::MBF21_CODE_POINTERS;
@@ -0,0 +1,492 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::{ast, *};
+
+#[test]
+fn smoke() {
+	const SOURCE: &str = "Patch File for DeHackEd v3.0\r
+# Created with WhackEd4\r
+Doom version = 19\r
+Patch format = 6\r
+\r
+Thing 1 (Player)\r
+Hit points = 150\r
+Initial frame  = 149\r
+Bits = SOLID+SHOOTABLE\r
+\r
+Frame 12\r
+Sprite number = 2\r
+Next frame = 13\r
+\r
+Pointer 1 (Frame 13)\r
+Codep Frame = 14\r
+\r
+Weapon 1 (Pistol)\r
+Ammo type = 0\r
+\r
+Ammo 0 (Bullets)\r
+Max ammo = 300\r
+\r
+Sound 1\r
+Zero/One = 0\r
+\r
+Cheat 0\r
+Change music = idtune\r
+\r
+Misc 0\r
+Initial Health = 150\r
+\r
+Text 4 5\r
+TROOCYBR\r
+\r
+Text 10 12
+E1M1: Hang\nE1M1: Bang!
+INCLUDE NOTEXT more.deh
+
+[STRINGS]
+HUSTR_1 = level 1: entryway \\
+   of doom
+GOTARMOR = Picked up \\\"armor\\\".
+
+[PARS]
+par 1 1 30
+par 31 120
+
+[CODEPTR]
+FRAME 174 = Look
+
+[SPRITES]
+TROO = SARG
+
+[SOUNDS]
+PISTOL = SHOTGN
+
+[HELPER]
+Type = 140
+";
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 20, "{roots:#?}");
+
+	let ast::Root::Header(header) = &roots[0] else {
+		panic!("expected a patch header, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(header.text().text(), "Patch File for DeHackEd v3.0");
+
+	let ast::Root::Field(version) = &roots[1] else {
+		panic!("expected a field, found: {:#?}", roots[1]);
+	};
+
+	assert_eq!(version.key().text(), "Doom version");
+	assert_eq!(version.int(), Some(19));
+
+	let ast::Root::Block(thing) = &roots[3] else {
+		panic!("expected a block, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(thing.kind(), ast::BlockKind::Thing);
+	assert_eq!(thing.number(), Some(1));
+	assert_eq!(thing.name().unwrap(), "Player");
+	assert_eq!(thing.fields().count(), 3);
+	assert_eq!(thing.field("initial FRAME").unwrap().int(), Some(149));
+	assert_eq!(thing.field("bits").unwrap().value_text(), "SOLID+SHOOTABLE");
+
+	let ast::Root::Block(pointer) = &roots[5] else {
+		panic!("expected a block, found: {:#?}", roots[5]);
+	};
+
+	assert_eq!(pointer.kind(), ast::BlockKind::Pointer);
+	assert_eq!(pointer.name().unwrap(), "Frame 13");
+
+	let ast::Root::Text(text) = &roots[11] else {
+		panic!("expected a text block, found: {:#?}", roots[11]);
+	};
+
+	assert_eq!(text.old_text(), "TROO");
+	assert_eq!(text.new_text(), "CYBR\n");
+
+	let ast::Root::Text(text) = &roots[12] else {
+		panic!("expected a text block, found: {:#?}", roots[12]);
+	};
+
+	assert_eq!(text.old_text(), "E1M1: Hang");
+	assert_eq!(text.new_text(), "\nE1M1: Bang!");
+
+	let ast::Root::Include(include) = &roots[13] else {
+		panic!("expected an include directive, found: {:#?}", roots[13]);
+	};
+
+	assert!(include.is_no_text());
+	assert_eq!(include.path(), "more.deh");
+
+	let ast::Root::Section(strings) = &roots[14] else {
+		panic!("expected a section, found: {:#?}", roots[14]);
+	};
+
+	assert_eq!(strings.kind(), ast::SectionKind::Strings);
+	let fields: Vec<_> = strings.fields().collect();
+	assert_eq!(fields[0].string(), "level 1: entryway of doom");
+	assert_eq!(fields[1].string(), "Picked up \"armor\".");
+
+	let ast::Root::Section(pars) = &roots[15] else {
+		panic!("expected a section, found: {:#?}", roots[15]);
+	};
+
+	let pars: Vec<_> = pars.pars().collect();
+	assert_eq!(pars[0].episode(), Some(1));
+	assert_eq!(pars[0].map(), Some(1));
+	assert_eq!(pars[0].seconds(), Some(30));
+	assert_eq!(pars[1].episode(), None);
+	assert_eq!(pars[1].map(), Some(31));
+	assert_eq!(pars[1].seconds(), Some(120));
+
+	let ast::Root::Section(codeptr) = &roots[16] else {
+		panic!("expected a section, found: {:#?}", roots[16]);
+	};
+
+	assert_eq!(codeptr.kind(), ast::SectionKind::CodePtr);
+	assert_eq!(codeptr.fields().next().unwrap().key().text(), "FRAME 174");
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+Thing 3001 (Imp)
+Hit points = 60abc
+This line is garbage
+Speed = 8
+
+Frame 5
+= 3
+Duration = 4
+
+[NOTASECTION]
+Something else
+
+Misc 0
+Max Health = 250
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert!(pt.any_errors());
+	assert!(parse(SOURCE).is_err());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let blocks: Vec<_> = pt
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Block(block) => Some(block),
+			_ => None,
+		})
+		.collect();
+
+	assert_eq!(blocks.len(), 3);
+
+	// Garbage lines do not end a block.
+	assert_eq!(blocks[0].field("hit points").unwrap().int(), Some(60));
+	assert_eq!(blocks[0].field("speed").unwrap().int(), Some(8));
+	assert_eq!(blocks[1].field("duration").unwrap().int(), Some(4));
+	assert_eq!(blocks[2].kind(), ast::BlockKind::Misc);
+	assert_eq!(blocks[2].field("max health").unwrap().int(), Some(250));
+}
+
+#[test]
+fn include_tree() {
+	let tree = parse_include_tree("DEHACKED", |path| match path {
+		"DEHACKED" => Some("INCLUDE extra.bex\nThing 1\nSpeed = 1\n".to_string()),
+		"extra.bex" => Some("[CODEPTR]\nFrame 1 = Look\n".to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	assert_eq!(tree.files.len(), 2);
+	assert_eq!(tree.files[1].path(), "extra.bex");
+
+	for file in &tree.files {
+		assert!(!file.any_errors());
+	}
+}
+
+#[test]
+fn vanilla_tables() {
+	let tables = Tables::vanilla();
+
+	assert_eq!(tables.things.len(), 137);
+	assert_eq!(tables.states.len(), 967);
+	assert_eq!(tables.sprites.len(), 138);
+	assert_eq!(tables.sounds.len(), 109);
+
+	// Spot-check against the numbers which DeHackEd shows for vanilla data.
+	let imp = &tables.things[11];
+	assert_eq!(imp.doomednum, 3001);
+	assert_eq!(imp.spawn_state, 442);
+	assert_eq!(tables.states[442].action, Some("A_Look"));
+	assert_eq!(tables.sprites[tables.states[442].sprite as usize], "TROO");
+	assert_eq!(tables.sounds[imp.see_sound as usize].name, "bgsit1");
+	assert_eq!(tables.things[0].spawn_state, 149);
+	assert_eq!(tables.things[21].spawn_state, 674);
+	assert_eq!(tables.things[136].doomednum, 81);
+
+	for (i, state) in tables.states.iter().enumerate() {
+		assert!(
+			(state.next_state as usize) < tables.states.len(),
+			"state {i} has an invalid next state"
+		);
+	}
+
+	for thing in &tables.things {
+		assert!((thing.spawn_state as usize) < tables.states.len());
+	}
+
+	assert!(tables.diff(&Tables::vanilla()).is_empty());
+}
+
+#[test]
+fn apply() {
+	const SOURCE: &str = "Patch File for DeHackEd v3.0
+Doom version = 19
+Patch format = 6
+
+Thing 12 (Imp)
+Hit points = 120
+Bits = SOLID+SHOOTABLE+COUNTKILL+SHADOW
+Injury frame = 9999
+
+Thing 200 (Nothing)
+Hit points = 1
+
+Frame 442
+Duration = 5
+Next frame = 443
+
+Pointer 0 (Frame 1)
+Codep Frame = 174
+
+Weapon 1 (Pistol)
+Ammo type = 1
+
+Text 4 4
+TROOSARG
+
+[STRINGS]
+GOTARMOR = Got some armor.
+NOTAMNEMONIC = Whatever
+
+[CODEPTR]
+FRAME 443 = NULL
+FRAME 444 = A_Scream
+FRAME 445 = NotAnAction
+";
+
+	let pt = ParseTree::new(parse(SOURCE).unwrap());
+	let base = Tables::vanilla();
+	let mut tables = base.clone();
+	let issues = tables.apply(&pt);
+
+	let imp = &tables.things[11];
+	assert_eq!(imp.spawn_health, 120);
+	assert_eq!(imp.flags, 0x2 | 0x4 | 0x400000 | 0x40000);
+	// The out-of-range frame was not applied.
+	assert_eq!(imp.pain_state, base.things[11].pain_state);
+
+	assert_eq!(tables.states[442].tics, 5);
+	assert_eq!(tables.states[1].action, Some("A_Look"));
+	assert_eq!(tables.states[443].action, None);
+	assert_eq!(tables.states[444].action, Some("A_Scream"));
+	assert_eq!(tables.weapons[1].ammo, 1);
+	assert_eq!(tables.sprites[0], "SARG");
+	assert_eq!(tables.string("gotarmor"), Some("Got some armor."));
+
+	let diff = tables.diff(&base);
+	assert_eq!(diff.things, [11]);
+	assert_eq!(diff.states, [1, 442, 443, 444]);
+	assert_eq!(diff.weapons, [1]);
+	assert_eq!(diff.sprites, [0]);
+	assert_eq!(diff.strings, ["GOTARMOR"]);
+	assert!(!diff.misc);
+
+	let messages: Vec<_> = issues.iter().map(|i| i.message.as_str()).collect();
+
+	assert_eq!(
+		messages,
+		[
+			"frame 9999 is out of range (the last is 966)",
+			"thing 200 is out of range (expected 1 to 137)",
+			"`NOTAMNEMONIC` is not a known string mnemonic",
+			"`NotAnAction` is not a known code pointer",
+		]
+	);
+}
+
+#[test]
+fn apply_mbf21() {
+	const SOURCE: &str = "Doom version = 2021
+Patch format = 6
+
+Thing 151 (Extra)
+ID # = 20000
+Bits = SOLID+SHOOTABLE+FRIEND
+MBF21 Bits = LOGRAV|RIP
+Projectile group = -1
+Dropped item = 64
+Initial frame = 1089
+
+Frame 1089
+Sprite number = 145
+Args1 = 2
+Args2 = 16
+Args3 = 7
+
+Frame 1090
+Args1 = 5
+
+[CODEPTR]
+FRAME 1089 = HealChase
+FRAME 1090 = Detonate
+
+[SPRITES]
+SP00 = NEWS
+";
+
+	let pt = ParseTree::new(parse(SOURCE).unwrap());
+	let base = Tables::mbf21();
+	let mut tables = base.clone();
+	let issues = tables.apply(&pt);
+
+	assert_eq!(base.things.len(), 250);
+	assert_eq!(base.states.len(), 4000);
+	assert_eq!(base.sprites[145], "SP00");
+	assert_eq!(base.sounds[500].name, "fre000");
+
+	let extra = &tables.things[150];
+	assert_eq!(extra.doomednum, 20000);
+	assert_eq!(extra.flags, 0x2 | 0x4 | 0x40000000);
+	assert_eq!(extra.mbf21_flags, 0x1 | 0x20000);
+	assert_eq!(extra.projectile_group, -1);
+	assert_eq!(extra.dropped_item, 64);
+	assert_eq!(tables.states[1089].action, Some("A_HealChase"));
+	assert_eq!(tables.states[1089].args[..3], [2, 16, 7]);
+	assert_eq!(tables.states[1090].action, Some("A_Detonate"));
+	assert_eq!(tables.sprites[145], "NEWS");
+
+	let diff = tables.diff(&base);
+	assert_eq!(diff.things, [150]);
+	assert_eq!(diff.states, [1089, 1090]);
+
+	let messages: Vec<_> = issues.iter().map(|i| i.message.as_str()).collect();
+
+	assert_eq!(
+		messages,
+		[
+			"`Args3` has no effect; `A_HealChase` takes 2 argument(s)",
+			"`Args1` has no effect; `A_Detonate` is not an MBF21 code pointer",
+		]
+	);
+
+	// The same features are reported in a patch for vanilla Doom...
+	let pt = ParseTree::new(
+		parse("Doom version = 19\nThing 1\nMBF21 Bits = LOGRAV\nFast speed = 10\n").unwrap(),
+	);
+	let issues = Tables::vanilla().apply(&pt);
+	assert_eq!(issues.len(), 2);
+
+	// ...and MBF21 patches must declare patch format 6.
+	let pt = ParseTree::new(parse("Doom version = 2021\nPatch format = 5\n").unwrap());
+	let issues = Tables::mbf21().apply(&pt);
+	assert_eq!(
+		issues[0].message,
+		"MBF21 patches must be of patch format 6, not 5"
+	);
+}
+
+#[test]
+fn actor_generation() {
+	const SOURCE: &str = "Patch File for DeHackEd v3.0
+Doom version = 19
+Patch format = 6
+
+Thing 12 (Imp)
+Hit points = 120
+Width = 1572864
+Bits = SOLID+SHOOTABLE+COUNTKILL+SHADOW
+
+Frame 442
+Duration = 5
+
+Weapon 1 (Pistol)
+Ammo type = 1
+
+[CODEPTR]
+FRAME 443 = FirePistol
+
+[STRINGS]
+GOTARMOR = Got some armor.
+";
+
+	let pt = ParseTree::new(parse(SOURCE).unwrap());
+	let base = Tables::vanilla();
+	let mut tables = base.clone();
+	assert!(tables.apply(&pt).is_empty());
+
+	let (decorate, unmapped) = to_actors(&tables, &base, ActorSyntax::Decorate);
+
+	assert_eq!(
+		decorate,
+		r#"actor DehackedDoomImp : DoomImp replaces DoomImp
+{
+	Health 120
+	Radius 24
+	+SHADOW
+	States
+	{
+	Spawn:
+		TROO A 5 A_Look
+		TROO B 10
+		Loop
+	}
+}
+
+actor DehackedPistol : Pistol replaces Pistol
+{
+	Weapon.AmmoType "Shell"
+}
+"#
+	);
+
+	let subjects: Vec<_> = unmapped.iter().map(|u| u.subject.as_str()).collect();
+	assert_eq!(subjects, ["Frame 443", "GOTARMOR"]);
+
+	let (zscript, _) = to_actors(&tables, &base, ActorSyntax::ZScript);
+	assert!(crate::zscript::parse(&zscript).is_ok(), "{zscript}");
+
+	// Every weapon's flash ends on frame 1, but `A_Light0` ignores arguments,
+	// so no weapon needs replacing.
+	let mut tables = base.clone();
+	tables.states[1].args[0] = 1;
+	let (decorate, unmapped) = to_actors(&tables, &base, ActorSyntax::Decorate);
+	assert!(decorate.is_empty(), "{decorate}");
+	assert_eq!(unmapped.len(), 1);
+	assert_eq!(unmapped[0].subject, "Frame 1");
+
+	let mut tables = base.clone();
+	tables.things[11].mbf21_flags |= 0x1; // `LOGRAV`
+	let (decorate, unmapped) = to_actors(&tables, &base, ActorSyntax::Decorate);
+	assert!(unmapped.is_empty());
+
+	assert_eq!(
+		decorate,
+		r#"actor DehackedDoomImp : DoomImp replaces DoomImp
+{
+	Gravity 0.125
+}
+"#
+	);
+}
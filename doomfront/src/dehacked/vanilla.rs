@@ -0,0 +1,3304 @@
+//! Vanilla Doom's info tables, as found in the v1.9 executables.
+//!
+//! States, sprites, and sounds are listed in the order of their indices, which
+//! are what DeHackEd patches refer to them by.
+//!
+//! The fields added by MBF21 hold the values which MBF21 specifies for
+//! vanilla's entries, since those reproduce vanilla's hardcoded behavior.
+
+use super::{
+	mbf21::{
+		MF2_DMGIGNORED, MF2_E1M8BOSS, MF2_E2M8BOSS, MF2_E3M8BOSS, MF2_E4M6BOSS, MF2_E4M8BOSS,
+		MF2_FULLVOLSOUNDS, MF2_HIGHERMPROB, MF2_LONGMELEE, MF2_MAP07BOSS1, MF2_MAP07BOSS2,
+		MF2_NORADIUSDMG, MF2_NOTHRESHOLD, MF2_RANGEHALF, MF2_SHORTMRANGE, PG_BARON,
+		WPF_AUTOSWITCHFROM, WPF_FLEEMELEE, WPF_NOAUTOFIRE, WPF_NOAUTOSWITCHTO, WPF_NOTHRUST,
+	},
+	AmmoInfo, Misc, Par, StateInfo, ThingInfo, WeaponInfo,
+};
+
+const FRACUNIT: i32 = 1 << 16;
+
+const fn state(
+	sprite: i32,
+	frame: i32,
+	tics: i32,
+	action: Option<&'static str>,
+	next_state: i32,
+) -> StateInfo {
+	StateInfo {
+		sprite,
+		frame,
+		tics,
+		action,
+		next_state,
+		misc1: 0,
+		misc2: 0,
+		mbf21_flags: 0,
+		args: [0; 8],
+	}
+}
+
+pub(super) const MF_SPECIAL: u32 = 0x1;
+pub(super) const MF_SOLID: u32 = 0x2;
+pub(super) const MF_SHOOTABLE: u32 = 0x4;
+pub(super) const MF_NOSECTOR: u32 = 0x8;
+pub(super) const MF_NOBLOCKMAP: u32 = 0x10;
+pub(super) const MF_AMBUSH: u32 = 0x20;
+pub(super) const MF_JUSTHIT: u32 = 0x40;
+pub(super) const MF_JUSTATTACKED: u32 = 0x80;
+pub(super) const MF_SPAWNCEILING: u32 = 0x100;
+pub(super) const MF_NOGRAVITY: u32 = 0x200;
+pub(super) const MF_DROPOFF: u32 = 0x400;
+pub(super) const MF_PICKUP: u32 = 0x800;
+pub(super) const MF_NOCLIP: u32 = 0x1000;
+pub(super) const MF_SLIDE: u32 = 0x2000;
+pub(super) const MF_FLOAT: u32 = 0x4000;
+pub(super) const MF_TELEPORT: u32 = 0x8000;
+pub(super) const MF_MISSILE: u32 = 0x10000;
+pub(super) const MF_DROPPED: u32 = 0x20000;
+pub(super) const MF_SHADOW: u32 = 0x40000;
+pub(super) const MF_NOBLOOD: u32 = 0x80000;
+pub(super) const MF_CORPSE: u32 = 0x100000;
+pub(super) const MF_INFLOAT: u32 = 0x200000;
+pub(super) const MF_COUNTKILL: u32 = 0x400000;
+pub(super) const MF_COUNTITEM: u32 = 0x800000;
+pub(super) const MF_SKULLFLY: u32 = 0x1000000;
+pub(super) const MF_NOTDMATCH: u32 = 0x2000000;
+pub(super) const MF_TRANSLATION1: u32 = 0x4000000;
+pub(super) const MF_TRANSLATION2: u32 = 0x8000000;
+/// Added by MBF.
+pub(super) const MF_TOUCHY: u32 = 0x10000000;
+/// Added by MBF.
+pub(super) const MF_BOUNCES: u32 = 0x20000000;
+/// Added by MBF.
+pub(super) const MF_FRIEND: u32 = 0x40000000;
+/// Added by Boom.
+pub(super) const MF_TRANSLUCENT: u32 = 0x80000000;
+
+/// `am_noammo`; the ammo type of the fist and chainsaw.
+const NO_AMMO: i32 = 5;
+
+pub(super) const WEAPONS: [WeaponInfo; 9] = [
+	// Fist
+	WeaponInfo {
+		ammo: NO_AMMO,
+		up_state: 4,
+		down_state: 3,
+		ready_state: 2,
+		attack_state: 5,
+		flash_state: 0,
+		ammo_per_shot: 0,
+		mbf21_flags: WPF_FLEEMELEE | WPF_AUTOSWITCHFROM | WPF_NOAUTOSWITCHTO,
+	},
+	// Pistol
+	WeaponInfo {
+		ammo: 0,
+		up_state: 12,
+		down_state: 11,
+		ready_state: 10,
+		attack_state: 13,
+		flash_state: 17,
+		ammo_per_shot: 1,
+		mbf21_flags: WPF_AUTOSWITCHFROM,
+	},
+	// Shotgun
+	WeaponInfo {
+		ammo: 1,
+		up_state: 20,
+		down_state: 19,
+		ready_state: 18,
+		attack_state: 21,
+		flash_state: 30,
+		ammo_per_shot: 1,
+		mbf21_flags: 0,
+	},
+	// Chaingun
+	WeaponInfo {
+		ammo: 0,
+		up_state: 51,
+		down_state: 50,
+		ready_state: 49,
+		attack_state: 52,
+		flash_state: 55,
+		ammo_per_shot: 1,
+		mbf21_flags: 0,
+	},
+	// Rocket launcher
+	WeaponInfo {
+		ammo: 3,
+		up_state: 59,
+		down_state: 58,
+		ready_state: 57,
+		attack_state: 60,
+		flash_state: 63,
+		ammo_per_shot: 1,
+		mbf21_flags: WPF_NOAUTOFIRE,
+	},
+	// Plasma rifle
+	WeaponInfo {
+		ammo: 2,
+		up_state: 76,
+		down_state: 75,
+		ready_state: 74,
+		attack_state: 77,
+		flash_state: 79,
+		ammo_per_shot: 1,
+		mbf21_flags: 0,
+	},
+	// BFG 9000
+	WeaponInfo {
+		ammo: 2,
+		up_state: 83,
+		down_state: 82,
+		ready_state: 81,
+		attack_state: 84,
+		flash_state: 88,
+		ammo_per_shot: 40,
+		mbf21_flags: WPF_NOAUTOFIRE,
+	},
+	// Chainsaw
+	WeaponInfo {
+		ammo: NO_AMMO,
+		up_state: 70,
+		down_state: 69,
+		ready_state: 67,
+		attack_state: 71,
+		flash_state: 0,
+		ammo_per_shot: 0,
+		mbf21_flags: WPF_NOTHRUST | WPF_FLEEMELEE | WPF_NOAUTOSWITCHTO,
+	},
+	// Super shotgun
+	WeaponInfo {
+		ammo: 1,
+		up_state: 34,
+		down_state: 33,
+		ready_state: 32,
+		attack_state: 35,
+		flash_state: 47,
+		ammo_per_shot: 2,
+		mbf21_flags: 0,
+	},
+];
+
+/// Bullets, shells, cells, and rockets, in that order.
+pub(super) const AMMO: [AmmoInfo; 4] = [
+	AmmoInfo {
+		max: 200,
+		per_pickup: 10,
+	},
+	AmmoInfo {
+		max: 50,
+		per_pickup: 4,
+	},
+	AmmoInfo {
+		max: 300,
+		per_pickup: 20,
+	},
+	AmmoInfo {
+		max: 50,
+		per_pickup: 1,
+	},
+];
+
+pub(super) const MISC: Misc = Misc {
+	initial_health: 100,
+	initial_bullets: 50,
+	max_health: 200,
+	max_armor: 200,
+	green_armor_class: 1,
+	blue_armor_class: 2,
+	max_soulsphere: 200,
+	soulsphere_health: 100,
+	megasphere_health: 200,
+	god_mode_health: 100,
+	idfa_armor: 200,
+	idfa_armor_class: 2,
+	idkfa_armor: 200,
+	idkfa_armor_class: 2,
+	bfg_cells_per_shot: 40,
+	monsters_infight: false,
+};
+
+/// Each cheat's name as DeHackEd knows it, and its default key sequence.
+pub(super) const CHEATS: [(&str, &str); 17] = [
+	("Change music", "idmus"),
+	("Chainsaw", "idchoppers"),
+	("God mode", "iddqd"),
+	("Ammo & Keys", "idkfa"),
+	("Ammo", "idfa"),
+	("No Clipping 1", "idspispopd"),
+	("No Clipping 2", "idclip"),
+	("Invincibility", "idbeholdv"),
+	("Berserk", "idbeholds"),
+	("Invisibility", "idbeholdi"),
+	("Radiation Suit", "idbeholdr"),
+	("Auto-map", "idbeholda"),
+	("Lite-Amp Goggles", "idbeholdl"),
+	("BEHOLD menu", "idbehold"),
+	("Level Warp", "idclev"),
+	("Player Position", "idmypos"),
+	("Map cheat", "iddt"),
+];
+
+/// Par times in seconds for episodes 1 to 3 of Doom, one row per episode.
+const EPISODE_PARS: [[i32; 9]; 3] = [
+	[30, 75, 120, 90, 165, 180, 180, 30, 165],
+	[90, 90, 90, 120, 90, 360, 240, 30, 170],
+	[90, 45, 90, 150, 90, 90, 165, 30, 135],
+];
+
+/// Par times in seconds for the 32 maps of Doom II.
+const MAP_PARS: [i32; 32] = [
+	30, 90, 120, 120, 90, 150, 120, 120, 270, 90, 210, 150, 150, 150, 210, 150, 420, 150, 210, 150,
+	240, 150, 180, 150, 150, 300, 330, 420, 300, 180, 120, 30,
+];
+
+pub(super) fn pars() -> Vec<Par> {
+	let episodic = EPISODE_PARS.iter().enumerate().flat_map(|(e, maps)| {
+		maps.iter().enumerate().map(move |(m, &seconds)| Par {
+			episode: Some(e as i32 + 1),
+			map: m as i32 + 1,
+			seconds,
+		})
+	});
+
+	let doom2 = MAP_PARS.iter().enumerate().map(|(m, &seconds)| Par {
+		episode: None,
+		map: m as i32 + 1,
+		seconds,
+	});
+
+	episodic.chain(doom2).collect()
+}
+
+/// Every action function in the executable, in the order in which states
+/// first use them.
+pub(super) const CODE_POINTERS: [&str; 74] = [
+	"A_Light0",
+	"A_WeaponReady",
+	"A_Lower",
+	"A_Raise",
+	"A_Punch",
+	"A_ReFire",
+	"A_FirePistol",
+	"A_Light1",
+	"A_FireShotgun",
+	"A_Light2",
+	"A_FireShotgun2",
+	"A_CheckReload",
+	"A_OpenShotgun2",
+	"A_LoadShotgun2",
+	"A_CloseShotgun2",
+	"A_FireCGun",
+	"A_GunFlash",
+	"A_FireMissile",
+	"A_Saw",
+	"A_FirePlasma",
+	"A_BFGsound",
+	"A_FireBFG",
+	"A_BFGSpray",
+	"A_Explode",
+	"A_Pain",
+	"A_PlayerScream",
+	"A_Fall",
+	"A_XScream",
+	"A_Look",
+	"A_Chase",
+	"A_FaceTarget",
+	"A_PosAttack",
+	"A_Scream",
+	"A_SPosAttack",
+	"A_VileChase",
+	"A_VileStart",
+	"A_VileTarget",
+	"A_VileAttack",
+	"A_StartFire",
+	"A_Fire",
+	"A_FireCrackle",
+	"A_Tracer",
+	"A_SkelWhoosh",
+	"A_SkelFist",
+	"A_SkelMissile",
+	"A_FatRaise",
+	"A_FatAttack1",
+	"A_FatAttack2",
+	"A_FatAttack3",
+	"A_BossDeath",
+	"A_CPosAttack",
+	"A_CPosRefire",
+	"A_TroopAttack",
+	"A_SargAttack",
+	"A_HeadAttack",
+	"A_BruisAttack",
+	"A_SkullAttack",
+	"A_Metal",
+	"A_SpidRefire",
+	"A_BabyMetal",
+	"A_BspiAttack",
+	"A_Hoof",
+	"A_CyberAttack",
+	"A_PainAttack",
+	"A_PainDie",
+	"A_KeenDie",
+	"A_BrainPain",
+	"A_BrainScream",
+	"A_BrainDie",
+	"A_BrainAwake",
+	"A_BrainSpit",
+	"A_SpawnSound",
+	"A_SpawnFly",
+	"A_BrainExplode",
+];
+
+/// Added to a state's frame number to make it fullbright.
+const FF_FULLBRIGHT: i32 = 0x8000;
+
+const SPR_TROO: i32 = 0;
+const SPR_SHTG: i32 = 1;
+const SPR_PUNG: i32 = 2;
+const SPR_PISG: i32 = 3;
+const SPR_PISF: i32 = 4;
+const SPR_SHTF: i32 = 5;
+const SPR_SHT2: i32 = 6;
+const SPR_CHGG: i32 = 7;
+const SPR_CHGF: i32 = 8;
+const SPR_MISG: i32 = 9;
+const SPR_MISF: i32 = 10;
+const SPR_SAWG: i32 = 11;
+const SPR_PLSG: i32 = 12;
+const SPR_PLSF: i32 = 13;
+const SPR_BFGG: i32 = 14;
+const SPR_BFGF: i32 = 15;
+const SPR_BLUD: i32 = 16;
+const SPR_PUFF: i32 = 17;
+const SPR_BAL1: i32 = 18;
+const SPR_BAL2: i32 = 19;
+const SPR_PLSS: i32 = 20;
+const SPR_PLSE: i32 = 21;
+const SPR_MISL: i32 = 22;
+const SPR_BFS1: i32 = 23;
+const SPR_BFE1: i32 = 24;
+const SPR_BFE2: i32 = 25;
+const SPR_TFOG: i32 = 26;
+const SPR_IFOG: i32 = 27;
+const SPR_PLAY: i32 = 28;
+const SPR_POSS: i32 = 29;
+const SPR_SPOS: i32 = 30;
+const SPR_VILE: i32 = 31;
+const SPR_FIRE: i32 = 32;
+const SPR_FATB: i32 = 33;
+const SPR_FBXP: i32 = 34;
+const SPR_SKEL: i32 = 35;
+const SPR_MANF: i32 = 36;
+const SPR_FATT: i32 = 37;
+const SPR_CPOS: i32 = 38;
+const SPR_SARG: i32 = 39;
+const SPR_HEAD: i32 = 40;
+const SPR_BAL7: i32 = 41;
+const SPR_BOSS: i32 = 42;
+const SPR_BOS2: i32 = 43;
+const SPR_SKUL: i32 = 44;
+const SPR_SPID: i32 = 45;
+const SPR_BSPI: i32 = 46;
+const SPR_APLS: i32 = 47;
+const SPR_APBX: i32 = 48;
+const SPR_CYBR: i32 = 49;
+const SPR_PAIN: i32 = 50;
+const SPR_SSWV: i32 = 51;
+const SPR_KEEN: i32 = 52;
+const SPR_BBRN: i32 = 53;
+const SPR_BOSF: i32 = 54;
+const SPR_ARM1: i32 = 55;
+const SPR_ARM2: i32 = 56;
+const SPR_BAR1: i32 = 57;
+const SPR_BEXP: i32 = 58;
+const SPR_FCAN: i32 = 59;
+const SPR_BON1: i32 = 60;
+const SPR_BON2: i32 = 61;
+const SPR_BKEY: i32 = 62;
+const SPR_RKEY: i32 = 63;
+const SPR_YKEY: i32 = 64;
+const SPR_BSKU: i32 = 65;
+const SPR_RSKU: i32 = 66;
+const SPR_YSKU: i32 = 67;
+const SPR_STIM: i32 = 68;
+const SPR_MEDI: i32 = 69;
+const SPR_SOUL: i32 = 70;
+const SPR_PINV: i32 = 71;
+const SPR_PSTR: i32 = 72;
+const SPR_PINS: i32 = 73;
+const SPR_MEGA: i32 = 74;
+const SPR_SUIT: i32 = 75;
+const SPR_PMAP: i32 = 76;
+const SPR_PVIS: i32 = 77;
+const SPR_CLIP: i32 = 78;
+const SPR_AMMO: i32 = 79;
+const SPR_ROCK: i32 = 80;
+const SPR_BROK: i32 = 81;
+const SPR_CELL: i32 = 82;
+const SPR_CELP: i32 = 83;
+const SPR_SHEL: i32 = 84;
+const SPR_SBOX: i32 = 85;
+const SPR_BPAK: i32 = 86;
+const SPR_BFUG: i32 = 87;
+const SPR_MGUN: i32 = 88;
+const SPR_CSAW: i32 = 89;
+const SPR_LAUN: i32 = 90;
+const SPR_PLAS: i32 = 91;
+const SPR_SHOT: i32 = 92;
+const SPR_SGN2: i32 = 93;
+const SPR_COLU: i32 = 94;
+const SPR_SMT2: i32 = 95;
+const SPR_GOR1: i32 = 96;
+const SPR_POL2: i32 = 97;
+const SPR_POL5: i32 = 98;
+const SPR_POL4: i32 = 99;
+const SPR_POL3: i32 = 100;
+const SPR_POL1: i32 = 101;
+const SPR_POL6: i32 = 102;
+const SPR_GOR2: i32 = 103;
+const SPR_GOR3: i32 = 104;
+const SPR_GOR4: i32 = 105;
+const SPR_GOR5: i32 = 106;
+const SPR_SMIT: i32 = 107;
+const SPR_COL1: i32 = 108;
+const SPR_COL2: i32 = 109;
+const SPR_COL3: i32 = 110;
+const SPR_COL4: i32 = 111;
+const SPR_CAND: i32 = 112;
+const SPR_CBRA: i32 = 113;
+const SPR_COL6: i32 = 114;
+const SPR_TRE1: i32 = 115;
+const SPR_TRE2: i32 = 116;
+const SPR_ELEC: i32 = 117;
+const SPR_CEYE: i32 = 118;
+const SPR_FSKU: i32 = 119;
+const SPR_COL5: i32 = 120;
+const SPR_TBLU: i32 = 121;
+const SPR_TGRN: i32 = 122;
+const SPR_TRED: i32 = 123;
+const SPR_SMBT: i32 = 124;
+const SPR_SMGT: i32 = 125;
+const SPR_SMRT: i32 = 126;
+const SPR_HDB1: i32 = 127;
+const SPR_HDB2: i32 = 128;
+const SPR_HDB3: i32 = 129;
+const SPR_HDB4: i32 = 130;
+const SPR_HDB5: i32 = 131;
+const SPR_HDB6: i32 = 132;
+const SPR_POB1: i32 = 133;
+const SPR_POB2: i32 = 134;
+const SPR_BRS1: i32 = 135;
+const SPR_TLMP: i32 = 136;
+const SPR_TLP2: i32 = 137;
+
+pub(super) const SPRITES: [&str; 138] = [
+	"TROO", "SHTG", "PUNG", "PISG", "PISF", "SHTF", "SHT2", "CHGG", "CHGF", "MISG", "MISF", "SAWG",
+	"PLSG", "PLSF", "BFGG", "BFGF", "BLUD", "PUFF", "BAL1", "BAL2", "PLSS", "PLSE", "MISL", "BFS1",
+	"BFE1", "BFE2", "TFOG", "IFOG", "PLAY", "POSS", "SPOS", "VILE", "FIRE", "FATB", "FBXP", "SKEL",
+	"MANF", "FATT", "CPOS", "SARG", "HEAD", "BAL7", "BOSS", "BOS2", "SKUL", "SPID", "BSPI", "APLS",
+	"APBX", "CYBR", "PAIN", "SSWV", "KEEN", "BBRN", "BOSF", "ARM1", "ARM2", "BAR1", "BEXP", "FCAN",
+	"BON1", "BON2", "BKEY", "RKEY", "YKEY", "BSKU", "RSKU", "YSKU", "STIM", "MEDI", "SOUL", "PINV",
+	"PSTR", "PINS", "MEGA", "SUIT", "PMAP", "PVIS", "CLIP", "AMMO", "ROCK", "BROK", "CELL", "CELP",
+	"SHEL", "SBOX", "BPAK", "BFUG", "MGUN", "CSAW", "LAUN", "PLAS", "SHOT", "SGN2", "COLU", "SMT2",
+	"GOR1", "POL2", "POL5", "POL4", "POL3", "POL1", "POL6", "GOR2", "GOR3", "GOR4", "GOR5", "SMIT",
+	"COL1", "COL2", "COL3", "COL4", "CAND", "CBRA", "COL6", "TRE1", "TRE2", "ELEC", "CEYE", "FSKU",
+	"COL5", "TBLU", "TGRN", "TRED", "SMBT", "SMGT", "SMRT", "HDB1", "HDB2", "HDB3", "HDB4", "HDB5",
+	"HDB6", "POB1", "POB2", "BRS1", "TLMP", "TLP2",
+];
+
+pub(super) static STATES: [StateInfo; 967] = [
+	state(SPR_TROO, 0, -1, None, 0),                         // 0: S_NULL
+	state(SPR_SHTG, 4, 0, Some("A_Light0"), 0),              // 1: S_LIGHTDONE
+	state(SPR_PUNG, 0, 1, Some("A_WeaponReady"), 2),         // 2: S_PUNCH
+	state(SPR_PUNG, 0, 1, Some("A_Lower"), 3),               // 3: S_PUNCHDOWN
+	state(SPR_PUNG, 0, 1, Some("A_Raise"), 4),               // 4: S_PUNCHUP
+	state(SPR_PUNG, 1, 4, None, 6),                          // 5: S_PUNCH1
+	state(SPR_PUNG, 2, 4, Some("A_Punch"), 7),               // 6: S_PUNCH2
+	state(SPR_PUNG, 3, 5, None, 8),                          // 7: S_PUNCH3
+	state(SPR_PUNG, 2, 4, None, 9),                          // 8: S_PUNCH4
+	state(SPR_PUNG, 1, 5, Some("A_ReFire"), 2),              // 9: S_PUNCH5
+	state(SPR_PISG, 0, 1, Some("A_WeaponReady"), 10),        // 10: S_PISTOL
+	state(SPR_PISG, 0, 1, Some("A_Lower"), 11),              // 11: S_PISTOLDOWN
+	state(SPR_PISG, 0, 1, Some("A_Raise"), 12),              // 12: S_PISTOLUP
+	state(SPR_PISG, 0, 4, None, 14),                         // 13: S_PISTOL1
+	state(SPR_PISG, 1, 6, Some("A_FirePistol"), 15),         // 14: S_PISTOL2
+	state(SPR_PISG, 2, 4, None, 16),                         // 15: S_PISTOL3
+	state(SPR_PISG, 1, 5, Some("A_ReFire"), 10),             // 16: S_PISTOL4
+	state(SPR_PISF, FF_FULLBRIGHT, 7, Some("A_Light1"), 1),  // 17: S_PISTOLFLASH
+	state(SPR_SHTG, 0, 1, Some("A_WeaponReady"), 18),        // 18: S_SGUN
+	state(SPR_SHTG, 0, 1, Some("A_Lower"), 19),              // 19: S_SGUNDOWN
+	state(SPR_SHTG, 0, 1, Some("A_Raise"), 20),              // 20: S_SGUNUP
+	state(SPR_SHTG, 0, 3, None, 22),                         // 21: S_SGUN1
+	state(SPR_SHTG, 0, 7, Some("A_FireShotgun"), 23),        // 22: S_SGUN2
+	state(SPR_SHTG, 1, 5, None, 24),                         // 23: S_SGUN3
+	state(SPR_SHTG, 2, 5, None, 25),                         // 24: S_SGUN4
+	state(SPR_SHTG, 3, 4, None, 26),                         // 25: S_SGUN5
+	state(SPR_SHTG, 2, 5, None, 27),                         // 26: S_SGUN6
+	state(SPR_SHTG, 1, 5, None, 28),                         // 27: S_SGUN7
+	state(SPR_SHTG, 0, 3, None, 29),                         // 28: S_SGUN8
+	state(SPR_SHTG, 0, 7, Some("A_ReFire"), 18),             // 29: S_SGUN9
+	state(SPR_SHTF, FF_FULLBRIGHT, 4, Some("A_Light1"), 31), // 30: S_SGUNFLASH1
+	state(SPR_SHTF, FF_FULLBRIGHT | 1, 3, Some("A_Light2"), 1), // 31: S_SGUNFLASH2
+	state(SPR_SHT2, 0, 1, Some("A_WeaponReady"), 32),        // 32: S_DSGUN
+	state(SPR_SHT2, 0, 1, Some("A_Lower"), 33),              // 33: S_DSGUNDOWN
+	state(SPR_SHT2, 0, 1, Some("A_Raise"), 34),              // 34: S_DSGUNUP
+	state(SPR_SHT2, 0, 3, None, 36),                         // 35: S_DSGUN1
+	state(SPR_SHT2, 0, 7, Some("A_FireShotgun2"), 37),       // 36: S_DSGUN2
+	state(SPR_SHT2, 1, 7, None, 38),                         // 37: S_DSGUN3
+	state(SPR_SHT2, 2, 7, Some("A_CheckReload"), 39),        // 38: S_DSGUN4
+	state(SPR_SHT2, 3, 7, Some("A_OpenShotgun2"), 40),       // 39: S_DSGUN5
+	state(SPR_SHT2, 4, 7, None, 41),                         // 40: S_DSGUN6
+	state(SPR_SHT2, 5, 7, Some("A_LoadShotgun2"), 42),       // 41: S_DSGUN7
+	state(SPR_SHT2, 6, 6, None, 43),                         // 42: S_DSGUN8
+	state(SPR_SHT2, 7, 6, Some("A_CloseShotgun2"), 44),      // 43: S_DSGUN9
+	state(SPR_SHT2, 0, 5, Some("A_ReFire"), 32),             // 44: S_DSGUN10
+	state(SPR_SHT2, 1, 7, None, 46),                         // 45: S_DSNR1
+	state(SPR_SHT2, 0, 3, None, 33),                         // 46: S_DSNR2
+	state(SPR_SHT2, FF_FULLBRIGHT | 8, 5, Some("A_Light1"), 48), // 47: S_DSGUNFLASH1
+	state(SPR_SHT2, FF_FULLBRIGHT | 9, 4, Some("A_Light2"), 1), // 48: S_DSGUNFLASH2
+	state(SPR_CHGG, 0, 1, Some("A_WeaponReady"), 49),        // 49: S_CHAIN
+	state(SPR_CHGG, 0, 1, Some("A_Lower"), 50),              // 50: S_CHAINDOWN
+	state(SPR_CHGG, 0, 1, Some("A_Raise"), 51),              // 51: S_CHAINUP
+	state(SPR_CHGG, 0, 4, Some("A_FireCGun"), 53),           // 52: S_CHAIN1
+	state(SPR_CHGG, 1, 4, Some("A_FireCGun"), 54),           // 53: S_CHAIN2
+	state(SPR_CHGG, 1, 0, Some("A_ReFire"), 49),             // 54: S_CHAIN3
+	state(SPR_CHGF, FF_FULLBRIGHT, 5, Some("A_Light1"), 1),  // 55: S_CHAINFLASH1
+	state(SPR_CHGF, FF_FULLBRIGHT | 1, 5, Some("A_Light2"), 1), // 56: S_CHAINFLASH2
+	state(SPR_MISG, 0, 1, Some("A_WeaponReady"), 57),        // 57: S_MISSILE
+	state(SPR_MISG, 0, 1, Some("A_Lower"), 58),              // 58: S_MISSILEDOWN
+	state(SPR_MISG, 0, 1, Some("A_Raise"), 59),              // 59: S_MISSILEUP
+	state(SPR_MISG, 1, 8, Some("A_GunFlash"), 61),           // 60: S_MISSILE1
+	state(SPR_MISG, 1, 12, Some("A_FireMissile"), 62),       // 61: S_MISSILE2
+	state(SPR_MISG, 1, 0, Some("A_ReFire"), 57),             // 62: S_MISSILE3
+	state(SPR_MISF, FF_FULLBRIGHT, 3, Some("A_Light1"), 64), // 63: S_MISSILEFLASH1
+	state(SPR_MISF, FF_FULLBRIGHT | 1, 4, None, 65),         // 64: S_MISSILEFLASH2
+	state(SPR_MISF, FF_FULLBRIGHT | 2, 4, Some("A_Light2"), 66), // 65: S_MISSILEFLASH3
+	state(SPR_MISF, FF_FULLBRIGHT | 3, 4, Some("A_Light2"), 1), // 66: S_MISSILEFLASH4
+	state(SPR_SAWG, 2, 4, Some("A_WeaponReady"), 68),        // 67: S_SAW
+	state(SPR_SAWG, 3, 4, Some("A_WeaponReady"), 67),        // 68: S_SAWB
+	state(SPR_SAWG, 2, 1, Some("A_Lower"), 69),              // 69: S_SAWDOWN
+	state(SPR_SAWG, 2, 1, Some("A_Raise"), 70),              // 70: S_SAWUP
+	state(SPR_SAWG, 0, 4, Some("A_Saw"), 72),                // 71: S_SAW1
+	state(SPR_SAWG, 1, 4, Some("A_Saw"), 73),                // 72: S_SAW2
+	state(SPR_SAWG, 1, 0, Some("A_ReFire"), 67),             // 73: S_SAW3
+	state(SPR_PLSG, 0, 1, Some("A_WeaponReady"), 74),        // 74: S_PLASMA
+	state(SPR_PLSG, 0, 1, Some("A_Lower"), 75),              // 75: S_PLASMADOWN
+	state(SPR_PLSG, 0, 1, Some("A_Raise"), 76),              // 76: S_PLASMAUP
+	state(SPR_PLSG, 0, 3, Some("A_FirePlasma"), 78),         // 77: S_PLASMA1
+	state(SPR_PLSG, 1, 20, Some("A_ReFire"), 74),            // 78: S_PLASMA2
+	state(SPR_PLSF, FF_FULLBRIGHT, 4, Some("A_Light1"), 1),  // 79: S_PLASMAFLASH1
+	state(SPR_PLSF, FF_FULLBRIGHT | 1, 4, Some("A_Light1"), 1), // 80: S_PLASMAFLASH2
+	state(SPR_BFGG, 0, 1, Some("A_WeaponReady"), 81),        // 81: S_BFG
+	state(SPR_BFGG, 0, 1, Some("A_Lower"), 82),              // 82: S_BFGDOWN
+	state(SPR_BFGG, 0, 1, Some("A_Raise"), 83),              // 83: S_BFGUP
+	state(SPR_BFGG, 0, 20, Some("A_BFGsound"), 85),          // 84: S_BFG1
+	state(SPR_BFGG, 1, 10, Some("A_GunFlash"), 86),          // 85: S_BFG2
+	state(SPR_BFGG, 1, 10, Some("A_FireBFG"), 87),           // 86: S_BFG3
+	state(SPR_BFGG, 1, 20, Some("A_ReFire"), 81),            // 87: S_BFG4
+	state(SPR_BFGF, FF_FULLBRIGHT, 11, Some("A_Light1"), 89), // 88: S_BFGFLASH1
+	state(SPR_BFGF, FF_FULLBRIGHT | 1, 6, Some("A_Light2"), 1), // 89: S_BFGFLASH2
+	state(SPR_BLUD, 2, 8, None, 91),                         // 90: S_BLOOD1
+	state(SPR_BLUD, 1, 8, None, 92),                         // 91: S_BLOOD2
+	state(SPR_BLUD, 0, 8, None, 0),                          // 92: S_BLOOD3
+	state(SPR_PUFF, FF_FULLBRIGHT, 4, None, 94),             // 93: S_PUFF1
+	state(SPR_PUFF, 1, 4, None, 95),                         // 94: S_PUFF2
+	state(SPR_PUFF, 2, 4, None, 96),                         // 95: S_PUFF3
+	state(SPR_PUFF, 3, 4, None, 0),                          // 96: S_PUFF4
+	state(SPR_BAL1, FF_FULLBRIGHT, 4, None, 98),             // 97: S_TBALL1
+	state(SPR_BAL1, FF_FULLBRIGHT | 1, 4, None, 97),         // 98: S_TBALL2
+	state(SPR_BAL1, FF_FULLBRIGHT | 2, 6, None, 100),        // 99: S_TBALLX1
+	state(SPR_BAL1, FF_FULLBRIGHT | 3, 6, None, 101),        // 100: S_TBALLX2
+	state(SPR_BAL1, FF_FULLBRIGHT | 4, 6, None, 0),          // 101: S_TBALLX3
+	state(SPR_BAL2, FF_FULLBRIGHT, 4, None, 103),            // 102: S_RBALL1
+	state(SPR_BAL2, FF_FULLBRIGHT | 1, 4, None, 102),        // 103: S_RBALL2
+	state(SPR_BAL2, FF_FULLBRIGHT | 2, 6, None, 105),        // 104: S_RBALLX1
+	state(SPR_BAL2, FF_FULLBRIGHT | 3, 6, None, 106),        // 105: S_RBALLX2
+	state(SPR_BAL2, FF_FULLBRIGHT | 4, 6, None, 0),          // 106: S_RBALLX3
+	state(SPR_PLSS, FF_FULLBRIGHT, 6, None, 108),            // 107: S_PLASBALL
+	state(SPR_PLSS, FF_FULLBRIGHT | 1, 6, None, 107),        // 108: S_PLASBALL2
+	state(SPR_PLSE, FF_FULLBRIGHT, 4, None, 110),            // 109: S_PLASEXP
+	state(SPR_PLSE, FF_FULLBRIGHT | 1, 4, None, 111),        // 110: S_PLASEXP2
+	state(SPR_PLSE, FF_FULLBRIGHT | 2, 4, None, 112),        // 111: S_PLASEXP3
+	state(SPR_PLSE, FF_FULLBRIGHT | 3, 4, None, 113),        // 112: S_PLASEXP4
+	state(SPR_PLSE, FF_FULLBRIGHT | 4, 4, None, 0),          // 113: S_PLASEXP5
+	state(SPR_MISL, FF_FULLBRIGHT, 1, None, 114),            // 114: S_ROCKET
+	state(SPR_BFS1, FF_FULLBRIGHT, 4, None, 116),            // 115: S_BFGSHOT
+	state(SPR_BFS1, FF_FULLBRIGHT | 1, 4, None, 115),        // 116: S_BFGSHOT2
+	state(SPR_BFE1, FF_FULLBRIGHT, 8, None, 118),            // 117: S_BFGLAND
+	state(SPR_BFE1, FF_FULLBRIGHT | 1, 8, None, 119),        // 118: S_BFGLAND2
+	state(SPR_BFE1, FF_FULLBRIGHT | 2, 8, Some("A_BFGSpray"), 120), // 119: S_BFGLAND3
+	state(SPR_BFE1, FF_FULLBRIGHT | 3, 8, None, 121),        // 120: S_BFGLAND4
+	state(SPR_BFE1, FF_FULLBRIGHT | 4, 8, None, 122),        // 121: S_BFGLAND5
+	state(SPR_BFE1, FF_FULLBRIGHT | 5, 8, None, 0),          // 122: S_BFGLAND6
+	state(SPR_BFE2, FF_FULLBRIGHT, 8, None, 124),            // 123: S_BFGEXP
+	state(SPR_BFE2, FF_FULLBRIGHT | 1, 8, None, 125),        // 124: S_BFGEXP2
+	state(SPR_BFE2, FF_FULLBRIGHT | 2, 8, None, 126),        // 125: S_BFGEXP3
+	state(SPR_BFE2, FF_FULLBRIGHT | 3, 8, None, 0),          // 126: S_BFGEXP4
+	state(SPR_MISL, FF_FULLBRIGHT | 1, 8, Some("A_Explode"), 128), // 127: S_EXPLODE1
+	state(SPR_MISL, FF_FULLBRIGHT | 2, 6, None, 129),        // 128: S_EXPLODE2
+	state(SPR_MISL, FF_FULLBRIGHT | 3, 4, None, 0),          // 129: S_EXPLODE3
+	state(SPR_TFOG, FF_FULLBRIGHT, 6, None, 131),            // 130: S_TFOG
+	state(SPR_TFOG, FF_FULLBRIGHT | 1, 6, None, 132),        // 131: S_TFOG01
+	state(SPR_TFOG, FF_FULLBRIGHT, 6, None, 133),            // 132: S_TFOG02
+	state(SPR_TFOG, FF_FULLBRIGHT | 1, 6, None, 134),        // 133: S_TFOG2
+	state(SPR_TFOG, FF_FULLBRIGHT | 2, 6, None, 135),        // 134: S_TFOG3
+	state(SPR_TFOG, FF_FULLBRIGHT | 3, 6, None, 136),        // 135: S_TFOG4
+	state(SPR_TFOG, FF_FULLBRIGHT | 4, 6, None, 137),        // 136: S_TFOG5
+	state(SPR_TFOG, FF_FULLBRIGHT | 5, 6, None, 138),        // 137: S_TFOG6
+	state(SPR_TFOG, FF_FULLBRIGHT | 6, 6, None, 139),        // 138: S_TFOG7
+	state(SPR_TFOG, FF_FULLBRIGHT | 7, 6, None, 140),        // 139: S_TFOG8
+	state(SPR_TFOG, FF_FULLBRIGHT | 8, 6, None, 141),        // 140: S_TFOG9
+	state(SPR_TFOG, FF_FULLBRIGHT | 9, 6, None, 0),          // 141: S_TFOG10
+	state(SPR_IFOG, FF_FULLBRIGHT, 6, None, 143),            // 142: S_IFOG
+	state(SPR_IFOG, FF_FULLBRIGHT | 1, 6, None, 144),        // 143: S_IFOG01
+	state(SPR_IFOG, FF_FULLBRIGHT, 6, None, 145),            // 144: S_IFOG02
+	state(SPR_IFOG, FF_FULLBRIGHT | 1, 6, None, 146),        // 145: S_IFOG2
+	state(SPR_IFOG, FF_FULLBRIGHT | 2, 6, None, 147),        // 146: S_IFOG3
+	state(SPR_IFOG, FF_FULLBRIGHT | 3, 6, None, 148),        // 147: S_IFOG4
+	state(SPR_IFOG, FF_FULLBRIGHT | 4, 6, None, 0),          // 148: S_IFOG5
+	state(SPR_PLAY, 0, -1, None, 0),                         // 149: S_PLAY
+	state(SPR_PLAY, 0, 4, None, 151),                        // 150: S_PLAY_RUN1
+	state(SPR_PLAY, 1, 4, None, 152),                        // 151: S_PLAY_RUN2
+	state(SPR_PLAY, 2, 4, None, 153),                        // 152: S_PLAY_RUN3
+	state(SPR_PLAY, 3, 4, None, 150),                        // 153: S_PLAY_RUN4
+	state(SPR_PLAY, 4, 12, None, 149),                       // 154: S_PLAY_ATK1
+	state(SPR_PLAY, FF_FULLBRIGHT | 5, 6, None, 154),        // 155: S_PLAY_ATK2
+	state(SPR_PLAY, 6, 4, None, 157),                        // 156: S_PLAY_PAIN
+	state(SPR_PLAY, 6, 4, Some("A_Pain"), 149),              // 157: S_PLAY_PAIN2
+	state(SPR_PLAY, 7, 10, None, 159),                       // 158: S_PLAY_DIE1
+	state(SPR_PLAY, 8, 10, Some("A_PlayerScream"), 160),     // 159: S_PLAY_DIE2
+	state(SPR_PLAY, 9, 10, Some("A_Fall"), 161),             // 160: S_PLAY_DIE3
+	state(SPR_PLAY, 10, 10, None, 162),                      // 161: S_PLAY_DIE4
+	state(SPR_PLAY, 11, 10, None, 163),                      // 162: S_PLAY_DIE5
+	state(SPR_PLAY, 12, 10, None, 164),                      // 163: S_PLAY_DIE6
+	state(SPR_PLAY, 13, -1, None, 0),                        // 164: S_PLAY_DIE7
+	state(SPR_PLAY, 14, 5, None, 166),                       // 165: S_PLAY_XDIE1
+	state(SPR_PLAY, 15, 5, Some("A_XScream"), 167),          // 166: S_PLAY_XDIE2
+	state(SPR_PLAY, 16, 5, Some("A_Fall"), 168),             // 167: S_PLAY_XDIE3
+	state(SPR_PLAY, 17, 5, None, 169),                       // 168: S_PLAY_XDIE4
+	state(SPR_PLAY, 18, 5, None, 170),                       // 169: S_PLAY_XDIE5
+	state(SPR_PLAY, 19, 5, None, 171),                       // 170: S_PLAY_XDIE6
+	state(SPR_PLAY, 20, 5, None, 172),                       // 171: S_PLAY_XDIE7
+	state(SPR_PLAY, 21, 5, None, 173),                       // 172: S_PLAY_XDIE8
+	state(SPR_PLAY, 22, -1, None, 0),                        // 173: S_PLAY_XDIE9
+	state(SPR_POSS, 0, 10, Some("A_Look"), 175),             // 174: S_POSS_STND
+	state(SPR_POSS, 1, 10, Some("A_Look"), 174),             // 175: S_POSS_STND2
+	state(SPR_POSS, 0, 4, Some("A_Chase"), 177),             // 176: S_POSS_RUN1
+	state(SPR_POSS, 0, 4, Some("A_Chase"), 178),             // 177: S_POSS_RUN2
+	state(SPR_POSS, 1, 4, Some("A_Chase"), 179),             // 178: S_POSS_RUN3
+	state(SPR_POSS, 1, 4, Some("A_Chase"), 180),             // 179: S_POSS_RUN4
+	state(SPR_POSS, 2, 4, Some("A_Chase"), 181),             // 180: S_POSS_RUN5
+	state(SPR_POSS, 2, 4, Some("A_Chase"), 182),             // 181: S_POSS_RUN6
+	state(SPR_POSS, 3, 4, Some("A_Chase"), 183),             // 182: S_POSS_RUN7
+	state(SPR_POSS, 3, 4, Some("A_Chase"), 176),             // 183: S_POSS_RUN8
+	state(SPR_POSS, 4, 10, Some("A_FaceTarget"), 185),       // 184: S_POSS_ATK1
+	state(SPR_POSS, 5, 8, Some("A_PosAttack"), 186),         // 185: S_POSS_ATK2
+	state(SPR_POSS, 4, 8, None, 176),                        // 186: S_POSS_ATK3
+	state(SPR_POSS, 6, 3, None, 188),                        // 187: S_POSS_PAIN
+	state(SPR_POSS, 6, 3, Some("A_Pain"), 176),              // 188: S_POSS_PAIN2
+	state(SPR_POSS, 7, 5, None, 190),                        // 189: S_POSS_DIE1
+	state(SPR_POSS, 8, 5, Some("A_Scream"), 191),            // 190: S_POSS_DIE2
+	state(SPR_POSS, 9, 5, Some("A_Fall"), 192),              // 191: S_POSS_DIE3
+	state(SPR_POSS, 10, 5, None, 193),                       // 192: S_POSS_DIE4
+	state(SPR_POSS, 11, -1, None, 0),                        // 193: S_POSS_DIE5
+	state(SPR_POSS, 12, 5, None, 195),                       // 194: S_POSS_XDIE1
+	state(SPR_POSS, 13, 5, Some("A_XScream"), 196),          // 195: S_POSS_XDIE2
+	state(SPR_POSS, 14, 5, Some("A_Fall"), 197),             // 196: S_POSS_XDIE3
+	state(SPR_POSS, 15, 5, None, 198),                       // 197: S_POSS_XDIE4
+	state(SPR_POSS, 16, 5, None, 199),                       // 198: S_POSS_XDIE5
+	state(SPR_POSS, 17, 5, None, 200),                       // 199: S_POSS_XDIE6
+	state(SPR_POSS, 18, 5, None, 201),                       // 200: S_POSS_XDIE7
+	state(SPR_POSS, 19, 5, None, 202),                       // 201: S_POSS_XDIE8
+	state(SPR_POSS, 20, -1, None, 0),                        // 202: S_POSS_XDIE9
+	state(SPR_POSS, 10, 5, None, 204),                       // 203: S_POSS_RAISE1
+	state(SPR_POSS, 9, 5, None, 205),                        // 204: S_POSS_RAISE2
+	state(SPR_POSS, 8, 5, None, 206),                        // 205: S_POSS_RAISE3
+	state(SPR_POSS, 7, 5, None, 176),                        // 206: S_POSS_RAISE4
+	state(SPR_SPOS, 0, 10, Some("A_Look"), 208),             // 207: S_SPOS_STND
+	state(SPR_SPOS, 1, 10, Some("A_Look"), 207),             // 208: S_SPOS_STND2
+	state(SPR_SPOS, 0, 3, Some("A_Chase"), 210),             // 209: S_SPOS_RUN1
+	state(SPR_SPOS, 0, 3, Some("A_Chase"), 211),             // 210: S_SPOS_RUN2
+	state(SPR_SPOS, 1, 3, Some("A_Chase"), 212),             // 211: S_SPOS_RUN3
+	state(SPR_SPOS, 1, 3, Some("A_Chase"), 213),             // 212: S_SPOS_RUN4
+	state(SPR_SPOS, 2, 3, Some("A_Chase"), 214),             // 213: S_SPOS_RUN5
+	state(SPR_SPOS, 2, 3, Some("A_Chase"), 215),             // 214: S_SPOS_RUN6
+	state(SPR_SPOS, 3, 3, Some("A_Chase"), 216),             // 215: S_SPOS_RUN7
+	state(SPR_SPOS, 3, 3, Some("A_Chase"), 209),             // 216: S_SPOS_RUN8
+	state(SPR_SPOS, 4, 10, Some("A_FaceTarget"), 218),       // 217: S_SPOS_ATK1
+	state(SPR_SPOS, FF_FULLBRIGHT | 5, 10, Some("A_SPosAttack"), 219), // 218: S_SPOS_ATK2
+	state(SPR_SPOS, 4, 10, None, 209),                       // 219: S_SPOS_ATK3
+	state(SPR_SPOS, 6, 3, None, 221),                        // 220: S_SPOS_PAIN
+	state(SPR_SPOS, 6, 3, Some("A_Pain"), 209),              // 221: S_SPOS_PAIN2
+	state(SPR_SPOS, 7, 5, None, 223),                        // 222: S_SPOS_DIE1
+	state(SPR_SPOS, 8, 5, Some("A_Scream"), 224),            // 223: S_SPOS_DIE2
+	state(SPR_SPOS, 9, 5, Some("A_Fall"), 225),              // 224: S_SPOS_DIE3
+	state(SPR_SPOS, 10, 5, None, 226),                       // 225: S_SPOS_DIE4
+	state(SPR_SPOS, 11, -1, None, 0),                        // 226: S_SPOS_DIE5
+	state(SPR_SPOS, 12, 5, None, 228),                       // 227: S_SPOS_XDIE1
+	state(SPR_SPOS, 13, 5, Some("A_XScream"), 229),          // 228: S_SPOS_XDIE2
+	state(SPR_SPOS, 14, 5, Some("A_Fall"), 230),             // 229: S_SPOS_XDIE3
+	state(SPR_SPOS, 15, 5, None, 231),                       // 230: S_SPOS_XDIE4
+	state(SPR_SPOS, 16, 5, None, 232),                       // 231: S_SPOS_XDIE5
+	state(SPR_SPOS, 17, 5, None, 233),                       // 232: S_SPOS_XDIE6
+	state(SPR_SPOS, 18, 5, None, 234),                       // 233: S_SPOS_XDIE7
+	state(SPR_SPOS, 19, 5, None, 235),                       // 234: S_SPOS_XDIE8
+	state(SPR_SPOS, 20, -1, None, 0),                        // 235: S_SPOS_XDIE9
+	state(SPR_SPOS, 11, 5, None, 237),                       // 236: S_SPOS_RAISE1
+	state(SPR_SPOS, 10, 5, None, 238),                       // 237: S_SPOS_RAISE2
+	state(SPR_SPOS, 9, 5, None, 239),                        // 238: S_SPOS_RAISE3
+	state(SPR_SPOS, 8, 5, None, 240),                        // 239: S_SPOS_RAISE4
+	state(SPR_SPOS, 7, 5, None, 209),                        // 240: S_SPOS_RAISE5
+	state(SPR_VILE, 0, 10, Some("A_Look"), 242),             // 241: S_VILE_STND
+	state(SPR_VILE, 1, 10, Some("A_Look"), 241),             // 242: S_VILE_STND2
+	state(SPR_VILE, 0, 2, Some("A_VileChase"), 244),         // 243: S_VILE_RUN1
+	state(SPR_VILE, 0, 2, Some("A_VileChase"), 245),         // 244: S_VILE_RUN2
+	state(SPR_VILE, 1, 2, Some("A_VileChase"), 246),         // 245: S_VILE_RUN3
+	state(SPR_VILE, 1, 2, Some("A_VileChase"), 247),         // 246: S_VILE_RUN4
+	state(SPR_VILE, 2, 2, Some("A_VileChase"), 248),         // 247: S_VILE_RUN5
+	state(SPR_VILE, 2, 2, Some("A_VileChase"), 249),         // 248: S_VILE_RUN6
+	state(SPR_VILE, 3, 2, Some("A_VileChase"), 250),         // 249: S_VILE_RUN7
+	state(SPR_VILE, 3, 2, Some("A_VileChase"), 251),         // 250: S_VILE_RUN8
+	state(SPR_VILE, 4, 2, Some("A_VileChase"), 252),         // 251: S_VILE_RUN9
+	state(SPR_VILE, 4, 2, Some("A_VileChase"), 253),         // 252: S_VILE_RUN10
+	state(SPR_VILE, 5, 2, Some("A_VileChase"), 254),         // 253: S_VILE_RUN11
+	state(SPR_VILE, 5, 2, Some("A_VileChase"), 243),         // 254: S_VILE_RUN12
+	state(SPR_VILE, FF_FULLBRIGHT | 6, 0, Some("A_VileStart"), 256), // 255: S_VILE_ATK1
+	state(SPR_VILE, FF_FULLBRIGHT | 6, 10, Some("A_FaceTarget"), 257), // 256: S_VILE_ATK2
+	state(SPR_VILE, FF_FULLBRIGHT | 7, 8, Some("A_VileTarget"), 258), // 257: S_VILE_ATK3
+	state(SPR_VILE, FF_FULLBRIGHT | 8, 8, Some("A_FaceTarget"), 259), // 258: S_VILE_ATK4
+	state(SPR_VILE, FF_FULLBRIGHT | 9, 8, Some("A_FaceTarget"), 260), // 259: S_VILE_ATK5
+	state(SPR_VILE, FF_FULLBRIGHT | 10, 8, Some("A_FaceTarget"), 261), // 260: S_VILE_ATK6
+	state(SPR_VILE, FF_FULLBRIGHT | 11, 8, Some("A_FaceTarget"), 262), // 261: S_VILE_ATK7
+	state(SPR_VILE, FF_FULLBRIGHT | 12, 8, Some("A_FaceTarget"), 263), // 262: S_VILE_ATK8
+	state(SPR_VILE, FF_FULLBRIGHT | 13, 8, Some("A_FaceTarget"), 264), // 263: S_VILE_ATK9
+	state(SPR_VILE, FF_FULLBRIGHT | 14, 8, Some("A_VileAttack"), 265), // 264: S_VILE_ATK10
+	state(SPR_VILE, FF_FULLBRIGHT | 15, 20, None, 243),      // 265: S_VILE_ATK11
+	state(SPR_VILE, FF_FULLBRIGHT | 26, 10, None, 267),      // 266: S_VILE_HEAL1
+	state(SPR_VILE, FF_FULLBRIGHT | 27, 10, None, 268),      // 267: S_VILE_HEAL2
+	state(SPR_VILE, FF_FULLBRIGHT | 28, 10, None, 243),      // 268: S_VILE_HEAL3
+	state(SPR_VILE, 16, 5, None, 270),                       // 269: S_VILE_PAIN
+	state(SPR_VILE, 16, 5, Some("A_Pain"), 243),             // 270: S_VILE_PAIN2
+	state(SPR_VILE, 16, 7, None, 272),                       // 271: S_VILE_DIE1
+	state(SPR_VILE, 17, 7, Some("A_Scream"), 273),           // 272: S_VILE_DIE2
+	state(SPR_VILE, 18, 7, Some("A_Fall"), 274),             // 273: S_VILE_DIE3
+	state(SPR_VILE, 19, 7, None, 275),                       // 274: S_VILE_DIE4
+	state(SPR_VILE, 20, 7, None, 276),                       // 275: S_VILE_DIE5
+	state(SPR_VILE, 21, 7, None, 277),                       // 276: S_VILE_DIE6
+	state(SPR_VILE, 22, 7, None, 278),                       // 277: S_VILE_DIE7
+	state(SPR_VILE, 23, 5, None, 279),                       // 278: S_VILE_DIE8
+	state(SPR_VILE, 24, 5, None, 280),                       // 279: S_VILE_DIE9
+	state(SPR_VILE, 25, -1, None, 0),                        // 280: S_VILE_DIE10
+	state(SPR_FIRE, FF_FULLBRIGHT, 2, Some("A_StartFire"), 282), // 281: S_FIRE1
+	state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Some("A_Fire"), 283), // 282: S_FIRE2
+	state(SPR_FIRE, FF_FULLBRIGHT, 2, Some("A_Fire"), 284),  // 283: S_FIRE3
+	state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Some("A_Fire"), 285), // 284: S_FIRE4
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Some("A_FireCrackle"), 286), // 285: S_FIRE5
+	state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Some("A_Fire"), 287), // 286: S_FIRE6
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Some("A_Fire"), 288), // 287: S_FIRE7
+	state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Some("A_Fire"), 289), // 288: S_FIRE8
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Some("A_Fire"), 290), // 289: S_FIRE9
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Some("A_Fire"), 291), // 290: S_FIRE10
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Some("A_Fire"), 292), // 291: S_FIRE11
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Some("A_Fire"), 293), // 292: S_FIRE12
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Some("A_Fire"), 294), // 293: S_FIRE13
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Some("A_Fire"), 295), // 294: S_FIRE14
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Some("A_Fire"), 296), // 295: S_FIRE15
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Some("A_Fire"), 297), // 296: S_FIRE16
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Some("A_Fire"), 298), // 297: S_FIRE17
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Some("A_Fire"), 299), // 298: S_FIRE18
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Some("A_FireCrackle"), 300), // 299: S_FIRE19
+	state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Some("A_Fire"), 301), // 300: S_FIRE20
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Some("A_Fire"), 302), // 301: S_FIRE21
+	state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Some("A_Fire"), 303), // 302: S_FIRE22
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Some("A_Fire"), 304), // 303: S_FIRE23
+	state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Some("A_Fire"), 305), // 304: S_FIRE24
+	state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Some("A_Fire"), 306), // 305: S_FIRE25
+	state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Some("A_Fire"), 307), // 306: S_FIRE26
+	state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Some("A_Fire"), 308), // 307: S_FIRE27
+	state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Some("A_Fire"), 309), // 308: S_FIRE28
+	state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Some("A_Fire"), 310), // 309: S_FIRE29
+	state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Some("A_Fire"), 0), // 310: S_FIRE30
+	state(SPR_PUFF, 1, 4, None, 312),                        // 311: S_SMOKE1
+	state(SPR_PUFF, 2, 4, None, 313),                        // 312: S_SMOKE2
+	state(SPR_PUFF, 1, 4, None, 314),                        // 313: S_SMOKE3
+	state(SPR_PUFF, 2, 4, None, 315),                        // 314: S_SMOKE4
+	state(SPR_PUFF, 3, 4, None, 0),                          // 315: S_SMOKE5
+	state(SPR_FATB, FF_FULLBRIGHT, 2, Some("A_Tracer"), 317), // 316: S_TRACER
+	state(SPR_FATB, FF_FULLBRIGHT | 1, 2, Some("A_Tracer"), 316), // 317: S_TRACER2
+	state(SPR_FBXP, FF_FULLBRIGHT, 8, None, 319),            // 318: S_TRACEEXP1
+	state(SPR_FBXP, FF_FULLBRIGHT | 1, 6, None, 320),        // 319: S_TRACEEXP2
+	state(SPR_FBXP, FF_FULLBRIGHT | 2, 4, None, 0),          // 320: S_TRACEEXP3
+	state(SPR_SKEL, 0, 10, Some("A_Look"), 322),             // 321: S_SKEL_STND
+	state(SPR_SKEL, 1, 10, Some("A_Look"), 321),             // 322: S_SKEL_STND2
+	state(SPR_SKEL, 0, 2, Some("A_Chase"), 324),             // 323: S_SKEL_RUN1
+	state(SPR_SKEL, 0, 2, Some("A_Chase"), 325),             // 324: S_SKEL_RUN2
+	state(SPR_SKEL, 1, 2, Some("A_Chase"), 326),             // 325: S_SKEL_RUN3
+	state(SPR_SKEL, 1, 2, Some("A_Chase"), 327),             // 326: S_SKEL_RUN4
+	state(SPR_SKEL, 2, 2, Some("A_Chase"), 328),             // 327: S_SKEL_RUN5
+	state(SPR_SKEL, 2, 2, Some("A_Chase"), 329),             // 328: S_SKEL_RUN6
+	state(SPR_SKEL, 3, 2, Some("A_Chase"), 330),             // 329: S_SKEL_RUN7
+	state(SPR_SKEL, 3, 2, Some("A_Chase"), 331),             // 330: S_SKEL_RUN8
+	state(SPR_SKEL, 4, 2, Some("A_Chase"), 332),             // 331: S_SKEL_RUN9
+	state(SPR_SKEL, 4, 2, Some("A_Chase"), 333),             // 332: S_SKEL_RUN10
+	state(SPR_SKEL, 5, 2, Some("A_Chase"), 334),             // 333: S_SKEL_RUN11
+	state(SPR_SKEL, 5, 2, Some("A_Chase"), 323),             // 334: S_SKEL_RUN12
+	state(SPR_SKEL, 6, 0, Some("A_FaceTarget"), 336),        // 335: S_SKEL_FIST1
+	state(SPR_SKEL, 6, 6, Some("A_SkelWhoosh"), 337),        // 336: S_SKEL_FIST2
+	state(SPR_SKEL, 7, 6, Some("A_FaceTarget"), 338),        // 337: S_SKEL_FIST3
+	state(SPR_SKEL, 8, 6, Some("A_SkelFist"), 323),          // 338: S_SKEL_FIST4
+	state(SPR_SKEL, FF_FULLBRIGHT | 9, 0, Some("A_FaceTarget"), 340), // 339: S_SKEL_MISS1
+	state(SPR_SKEL, FF_FULLBRIGHT | 9, 10, Some("A_FaceTarget"), 341), // 340: S_SKEL_MISS2
+	state(SPR_SKEL, 10, 10, Some("A_SkelMissile"), 342),     // 341: S_SKEL_MISS3
+	state(SPR_SKEL, 10, 10, Some("A_FaceTarget"), 323),      // 342: S_SKEL_MISS4
+	state(SPR_SKEL, 11, 5, None, 344),                       // 343: S_SKEL_PAIN
+	state(SPR_SKEL, 11, 5, Some("A_Pain"), 323),             // 344: S_SKEL_PAIN2
+	state(SPR_SKEL, 11, 7, None, 346),                       // 345: S_SKEL_DIE1
+	state(SPR_SKEL, 12, 7, None, 347),                       // 346: S_SKEL_DIE2
+	state(SPR_SKEL, 13, 7, Some("A_Scream"), 348),           // 347: S_SKEL_DIE3
+	state(SPR_SKEL, 14, 7, Some("A_Fall"), 349),             // 348: S_SKEL_DIE4
+	state(SPR_SKEL, 15, 7, None, 350),                       // 349: S_SKEL_DIE5
+	state(SPR_SKEL, 16, -1, None, 0),                        // 350: S_SKEL_DIE6
+	state(SPR_SKEL, 16, 5, None, 352),                       // 351: S_SKEL_RAISE1
+	state(SPR_SKEL, 15, 5, None, 353),                       // 352: S_SKEL_RAISE2
+	state(SPR_SKEL, 14, 5, None, 354),                       // 353: S_SKEL_RAISE3
+	state(SPR_SKEL, 13, 5, None, 355),                       // 354: S_SKEL_RAISE4
+	state(SPR_SKEL, 12, 5, None, 356),                       // 355: S_SKEL_RAISE5
+	state(SPR_SKEL, 11, 5, None, 323),                       // 356: S_SKEL_RAISE6
+	state(SPR_MANF, FF_FULLBRIGHT, 4, None, 358),            // 357: S_FATSHOT1
+	state(SPR_MANF, FF_FULLBRIGHT | 1, 4, None, 357),        // 358: S_FATSHOT2
+	state(SPR_MISL, FF_FULLBRIGHT | 1, 8, None, 360),        // 359: S_FATSHOTX1
+	state(SPR_MISL, FF_FULLBRIGHT | 2, 6, None, 361),        // 360: S_FATSHOTX2
+	state(SPR_MISL, FF_FULLBRIGHT | 3, 4, None, 0),          // 361: S_FATSHOTX3
+	state(SPR_FATT, 0, 15, Some("A_Look"), 363),             // 362: S_FATT_STND
+	state(SPR_FATT, 1, 15, Some("A_Look"), 362),             // 363: S_FATT_STND2
+	state(SPR_FATT, 0, 4, Some("A_Chase"), 365),             // 364: S_FATT_RUN1
+	state(SPR_FATT, 0, 4, Some("A_Chase"), 366),             // 365: S_FATT_RUN2
+	state(SPR_FATT, 1, 4, Some("A_Chase"), 367),             // 366: S_FATT_RUN3
+	state(SPR_FATT, 1, 4, Some("A_Chase"), 368),             // 367: S_FATT_RUN4
+	state(SPR_FATT, 2, 4, Some("A_Chase"), 369),             // 368: S_FATT_RUN5
+	state(SPR_FATT, 2, 4, Some("A_Chase"), 370),             // 369: S_FATT_RUN6
+	state(SPR_FATT, 3, 4, Some("A_Chase"), 371),             // 370: S_FATT_RUN7
+	state(SPR_FATT, 3, 4, Some("A_Chase"), 372),             // 371: S_FATT_RUN8
+	state(SPR_FATT, 4, 4, Some("A_Chase"), 373),             // 372: S_FATT_RUN9
+	state(SPR_FATT, 4, 4, Some("A_Chase"), 374),             // 373: S_FATT_RUN10
+	state(SPR_FATT, 5, 4, Some("A_Chase"), 375),             // 374: S_FATT_RUN11
+	state(SPR_FATT, 5, 4, Some("A_Chase"), 364),             // 375: S_FATT_RUN12
+	state(SPR_FATT, 6, 20, Some("A_FatRaise"), 377),         // 376: S_FATT_ATK1
+	state(SPR_FATT, FF_FULLBRIGHT | 7, 10, Some("A_FatAttack1"), 378), // 377: S_FATT_ATK2
+	state(SPR_FATT, 8, 5, Some("A_FaceTarget"), 379),        // 378: S_FATT_ATK3
+	state(SPR_FATT, 6, 5, Some("A_FaceTarget"), 380),        // 379: S_FATT_ATK4
+	state(SPR_FATT, FF_FULLBRIGHT | 7, 10, Some("A_FatAttack2"), 381), // 380: S_FATT_ATK5
+	state(SPR_FATT, 8, 5, Some("A_FaceTarget"), 382),        // 381: S_FATT_ATK6
+	state(SPR_FATT, 6, 5, Some("A_FaceTarget"), 383),        // 382: S_FATT_ATK7
+	state(SPR_FATT, FF_FULLBRIGHT | 7, 10, Some("A_FatAttack3"), 384), // 383: S_FATT_ATK8
+	state(SPR_FATT, 8, 5, Some("A_FaceTarget"), 385),        // 384: S_FATT_ATK9
+	state(SPR_FATT, 6, 5, Some("A_FaceTarget"), 364),        // 385: S_FATT_ATK10
+	state(SPR_FATT, 9, 3, None, 387),                        // 386: S_FATT_PAIN
+	state(SPR_FATT, 9, 3, Some("A_Pain"), 364),              // 387: S_FATT_PAIN2
+	state(SPR_FATT, 10, 6, None, 389),                       // 388: S_FATT_DIE1
+	state(SPR_FATT, 11, 6, Some("A_Scream"), 390),           // 389: S_FATT_DIE2
+	state(SPR_FATT, 12, 6, Some("A_Fall"), 391),             // 390: S_FATT_DIE3
+	state(SPR_FATT, 13, 6, None, 392),                       // 391: S_FATT_DIE4
+	state(SPR_FATT, 14, 6, None, 393),                       // 392: S_FATT_DIE5
+	state(SPR_FATT, 15, 6, None, 394),                       // 393: S_FATT_DIE6
+	state(SPR_FATT, 16, 6, None, 395),                       // 394: S_FATT_DIE7
+	state(SPR_FATT, 17, 6, None, 396),                       // 395: S_FATT_DIE8
+	state(SPR_FATT, 18, 6, None, 397),                       // 396: S_FATT_DIE9
+	state(SPR_FATT, 19, -1, Some("A_BossDeath"), 0),         // 397: S_FATT_DIE10
+	state(SPR_FATT, 17, 5, None, 399),                       // 398: S_FATT_RAISE1
+	state(SPR_FATT, 16, 5, None, 400),                       // 399: S_FATT_RAISE2
+	state(SPR_FATT, 15, 5, None, 401),                       // 400: S_FATT_RAISE3
+	state(SPR_FATT, 14, 5, None, 402),                       // 401: S_FATT_RAISE4
+	state(SPR_FATT, 13, 5, None, 403),                       // 402: S_FATT_RAISE5
+	state(SPR_FATT, 12, 5, None, 404),                       // 403: S_FATT_RAISE6
+	state(SPR_FATT, 11, 5, None, 405),                       // 404: S_FATT_RAISE7
+	state(SPR_FATT, 10, 5, None, 364),                       // 405: S_FATT_RAISE8
+	state(SPR_CPOS, 0, 10, Some("A_Look"), 407),             // 406: S_CPOS_STND
+	state(SPR_CPOS, 1, 10, Some("A_Look"), 406),             // 407: S_CPOS_STND2
+	state(SPR_CPOS, 0, 3, Some("A_Chase"), 409),             // 408: S_CPOS_RUN1
+	state(SPR_CPOS, 0, 3, Some("A_Chase"), 410),             // 409: S_CPOS_RUN2
+	state(SPR_CPOS, 1, 3, Some("A_Chase"), 411),             // 410: S_CPOS_RUN3
+	state(SPR_CPOS, 1, 3, Some("A_Chase"), 412),             // 411: S_CPOS_RUN4
+	state(SPR_CPOS, 2, 3, Some("A_Chase"), 413),             // 412: S_CPOS_RUN5
+	state(SPR_CPOS, 2, 3, Some("A_Chase"), 414),             // 413: S_CPOS_RUN6
+	state(SPR_CPOS, 3, 3, Some("A_Chase"), 415),             // 414: S_CPOS_RUN7
+	state(SPR_CPOS, 3, 3, Some("A_Chase"), 408),             // 415: S_CPOS_RUN8
+	state(SPR_CPOS, 4, 10, Some("A_FaceTarget"), 417),       // 416: S_CPOS_ATK1
+	state(SPR_CPOS, FF_FULLBRIGHT | 5, 4, Some("A_CPosAttack"), 418), // 417: S_CPOS_ATK2
+	state(SPR_CPOS, FF_FULLBRIGHT | 4, 4, Some("A_CPosAttack"), 419), // 418: S_CPOS_ATK3
+	state(SPR_CPOS, 5, 1, Some("A_CPosRefire"), 417),        // 419: S_CPOS_ATK4
+	state(SPR_CPOS, 6, 3, None, 421),                        // 420: S_CPOS_PAIN
+	state(SPR_CPOS, 6, 3, Some("A_Pain"), 408),              // 421: S_CPOS_PAIN2
+	state(SPR_CPOS, 7, 5, None, 423),                        // 422: S_CPOS_DIE1
+	state(SPR_CPOS, 8, 5, Some("A_Scream"), 424),            // 423: S_CPOS_DIE2
+	state(SPR_CPOS, 9, 5, Some("A_Fall"), 425),              // 424: S_CPOS_DIE3
+	state(SPR_CPOS, 10, 5, None, 426),                       // 425: S_CPOS_DIE4
+	state(SPR_CPOS, 11, 5, None, 427),                       // 426: S_CPOS_DIE5
+	state(SPR_CPOS, 12, 5, None, 428),                       // 427: S_CPOS_DIE6
+	state(SPR_CPOS, 13, -1, None, 0),                        // 428: S_CPOS_DIE7
+	state(SPR_CPOS, 14, 5, None, 430),                       // 429: S_CPOS_XDIE1
+	state(SPR_CPOS, 15, 5, Some("A_XScream"), 431),          // 430: S_CPOS_XDIE2
+	state(SPR_CPOS, 16, 5, Some("A_Fall"), 432),             // 431: S_CPOS_XDIE3
+	state(SPR_CPOS, 17, 5, None, 433),                       // 432: S_CPOS_XDIE4
+	state(SPR_CPOS, 18, 5, None, 434),                       // 433: S_CPOS_XDIE5
+	state(SPR_CPOS, 19, -1, None, 0),                        // 434: S_CPOS_XDIE6
+	state(SPR_CPOS, 13, 5, None, 436),                       // 435: S_CPOS_RAISE1
+	state(SPR_CPOS, 12, 5, None, 437),                       // 436: S_CPOS_RAISE2
+	state(SPR_CPOS, 11, 5, None, 438),                       // 437: S_CPOS_RAISE3
+	state(SPR_CPOS, 10, 5, None, 439),                       // 438: S_CPOS_RAISE4
+	state(SPR_CPOS, 9, 5, None, 440),                        // 439: S_CPOS_RAISE5
+	state(SPR_CPOS, 8, 5, None, 441),                        // 440: S_CPOS_RAISE6
+	state(SPR_CPOS, 7, 5, None, 408),                        // 441: S_CPOS_RAISE7
+	state(SPR_TROO, 0, 10, Some("A_Look"), 443),             // 442: S_TROO_STND
+	state(SPR_TROO, 1, 10, Some("A_Look"), 442),             // 443: S_TROO_STND2
+	state(SPR_TROO, 0, 3, Some("A_Chase"), 445),             // 444: S_TROO_RUN1
+	state(SPR_TROO, 0, 3, Some("A_Chase"), 446),             // 445: S_TROO_RUN2
+	state(SPR_TROO, 1, 3, Some("A_Chase"), 447),             // 446: S_TROO_RUN3
+	state(SPR_TROO, 1, 3, Some("A_Chase"), 448),             // 447: S_TROO_RUN4
+	state(SPR_TROO, 2, 3, Some("A_Chase"), 449),             // 448: S_TROO_RUN5
+	state(SPR_TROO, 2, 3, Some("A_Chase"), 450),             // 449: S_TROO_RUN6
+	state(SPR_TROO, 3, 3, Some("A_Chase"), 451),             // 450: S_TROO_RUN7
+	state(SPR_TROO, 3, 3, Some("A_Chase"), 444),             // 451: S_TROO_RUN8
+	state(SPR_TROO, 4, 8, Some("A_FaceTarget"), 453),        // 452: S_TROO_ATK1
+	state(SPR_TROO, 5, 8, Some("A_FaceTarget"), 454),        // 453: S_TROO_ATK2
+	state(SPR_TROO, 6, 6, Some("A_TroopAttack"), 444),       // 454: S_TROO_ATK3
+	state(SPR_TROO, 7, 2, None, 456),                        // 455: S_TROO_PAIN
+	state(SPR_TROO, 7, 2, Some("A_Pain"), 444),              // 456: S_TROO_PAIN2
+	state(SPR_TROO, 8, 8, None, 458),                        // 457: S_TROO_DIE1
+	state(SPR_TROO, 9, 8, Some("A_Scream"), 459),            // 458: S_TROO_DIE2
+	state(SPR_TROO, 10, 6, None, 460),                       // 459: S_TROO_DIE3
+	state(SPR_TROO, 11, 6, Some("A_Fall"), 461),             // 460: S_TROO_DIE4
+	state(SPR_TROO, 12, -1, None, 0),                        // 461: S_TROO_DIE5
+	state(SPR_TROO, 13, 5, None, 463),                       // 462: S_TROO_XDIE1
+	state(SPR_TROO, 14, 5, Some("A_XScream"), 464),          // 463: S_TROO_XDIE2
+	state(SPR_TROO, 15, 5, None, 465),                       // 464: S_TROO_XDIE3
+	state(SPR_TROO, 16, 5, Some("A_Fall"), 466),             // 465: S_TROO_XDIE4
+	state(SPR_TROO, 17, 5, None, 467),                       // 466: S_TROO_XDIE5
+	state(SPR_TROO, 18, 5, None, 468),                       // 467: S_TROO_XDIE6
+	state(SPR_TROO, 19, 5, None, 469),                       // 468: S_TROO_XDIE7
+	state(SPR_TROO, 20, -1, None, 0),                        // 469: S_TROO_XDIE8
+	state(SPR_TROO, 12, 8, None, 471),                       // 470: S_TROO_RAISE1
+	state(SPR_TROO, 11, 8, None, 472),                       // 471: S_TROO_RAISE2
+	state(SPR_TROO, 10, 6, None, 473),                       // 472: S_TROO_RAISE3
+	state(SPR_TROO, 9, 6, None, 474),                        // 473: S_TROO_RAISE4
+	state(SPR_TROO, 8, 6, None, 444),                        // 474: S_TROO_RAISE5
+	state(SPR_SARG, 0, 10, Some("A_Look"), 476),             // 475: S_SARG_STND
+	state(SPR_SARG, 1, 10, Some("A_Look"), 475),             // 476: S_SARG_STND2
+	state(SPR_SARG, 0, 2, Some("A_Chase"), 478),             // 477: S_SARG_RUN1
+	state(SPR_SARG, 0, 2, Some("A_Chase"), 479),             // 478: S_SARG_RUN2
+	state(SPR_SARG, 1, 2, Some("A_Chase"), 480),             // 479: S_SARG_RUN3
+	state(SPR_SARG, 1, 2, Some("A_Chase"), 481),             // 480: S_SARG_RUN4
+	state(SPR_SARG, 2, 2, Some("A_Chase"), 482),             // 481: S_SARG_RUN5
+	state(SPR_SARG, 2, 2, Some("A_Chase"), 483),             // 482: S_SARG_RUN6
+	state(SPR_SARG, 3, 2, Some("A_Chase"), 484),             // 483: S_SARG_RUN7
+	state(SPR_SARG, 3, 2, Some("A_Chase"), 477),             // 484: S_SARG_RUN8
+	state(SPR_SARG, 4, 8, Some("A_FaceTarget"), 486),        // 485: S_SARG_ATK1
+	state(SPR_SARG, 5, 8, Some("A_FaceTarget"), 487),        // 486: S_SARG_ATK2
+	state(SPR_SARG, 6, 8, Some("A_SargAttack"), 477),        // 487: S_SARG_ATK3
+	state(SPR_SARG, 7, 2, None, 489),                        // 488: S_SARG_PAIN
+	state(SPR_SARG, 7, 2, Some("A_Pain"), 477),              // 489: S_SARG_PAIN2
+	state(SPR_SARG, 8, 8, None, 491),                        // 490: S_SARG_DIE1
+	state(SPR_SARG, 9, 8, Some("A_Scream"), 492),            // 491: S_SARG_DIE2
+	state(SPR_SARG, 10, 4, None, 493),                       // 492: S_SARG_DIE3
+	state(SPR_SARG, 11, 4, Some("A_Fall"), 494),             // 493: S_SARG_DIE4
+	state(SPR_SARG, 12, 4, None, 495),                       // 494: S_SARG_DIE5
+	state(SPR_SARG, 13, -1, None, 0),                        // 495: S_SARG_DIE6
+	state(SPR_SARG, 13, 5, None, 497),                       // 496: S_SARG_RAISE1
+	state(SPR_SARG, 12, 5, None, 498),                       // 497: S_SARG_RAISE2
+	state(SPR_SARG, 11, 5, None, 499),                       // 498: S_SARG_RAISE3
+	state(SPR_SARG, 10, 5, None, 500),                       // 499: S_SARG_RAISE4
+	state(SPR_SARG, 9, 5, None, 501),                        // 500: S_SARG_RAISE5
+	state(SPR_SARG, 8, 5, None, 477),                        // 501: S_SARG_RAISE6
+	state(SPR_HEAD, 0, 10, Some("A_Look"), 502),             // 502: S_HEAD_STND
+	state(SPR_HEAD, 0, 3, Some("A_Chase"), 503),             // 503: S_HEAD_RUN1
+	state(SPR_HEAD, 1, 5, Some("A_FaceTarget"), 505),        // 504: S_HEAD_ATK1
+	state(SPR_HEAD, 2, 5, Some("A_FaceTarget"), 506),        // 505: S_HEAD_ATK2
+	state(SPR_HEAD, FF_FULLBRIGHT | 3, 5, Some("A_HeadAttack"), 503), // 506: S_HEAD_ATK3
+	state(SPR_HEAD, 4, 3, None, 508),                        // 507: S_HEAD_PAIN
+	state(SPR_HEAD, 4, 3, Some("A_Pain"), 509),              // 508: S_HEAD_PAIN2
+	state(SPR_HEAD, 5, 6, None, 503),                        // 509: S_HEAD_PAIN3
+	state(SPR_HEAD, 6, 8, None, 511),                        // 510: S_HEAD_DIE1
+	state(SPR_HEAD, 7, 8, Some("A_Scream"), 512),            // 511: S_HEAD_DIE2
+	state(SPR_HEAD, 8, 8, None, 513),                        // 512: S_HEAD_DIE3
+	state(SPR_HEAD, 9, 8, None, 514),                        // 513: S_HEAD_DIE4
+	state(SPR_HEAD, 10, 8, Some("A_Fall"), 515),             // 514: S_HEAD_DIE5
+	state(SPR_HEAD, 11, -1, None, 0),                        // 515: S_HEAD_DIE6
+	state(SPR_HEAD, 11, 8, None, 517),                       // 516: S_HEAD_RAISE1
+	state(SPR_HEAD, 10, 8, None, 518),                       // 517: S_HEAD_RAISE2
+	state(SPR_HEAD, 9, 8, None, 519),                        // 518: S_HEAD_RAISE3
+	state(SPR_HEAD, 8, 8, None, 520),                        // 519: S_HEAD_RAISE4
+	state(SPR_HEAD, 7, 8, None, 521),                        // 520: S_HEAD_RAISE5
+	state(SPR_HEAD, 6, 8, None, 503),                        // 521: S_HEAD_RAISE6
+	state(SPR_BAL7, FF_FULLBRIGHT, 4, None, 523),            // 522: S_BRBALL1
+	state(SPR_BAL7, FF_FULLBRIGHT | 1, 4, None, 522),        // 523: S_BRBALL2
+	state(SPR_BAL7, FF_FULLBRIGHT | 2, 6, None, 525),        // 524: S_BRBALLX1
+	state(SPR_BAL7, FF_FULLBRIGHT | 3, 6, None, 526),        // 525: S_BRBALLX2
+	state(SPR_BAL7, FF_FULLBRIGHT | 4, 6, None, 0),          // 526: S_BRBALLX3
+	state(SPR_BOSS, 0, 10, Some("A_Look"), 528),             // 527: S_BOSS_STND
+	state(SPR_BOSS, 1, 10, Some("A_Look"), 527),             // 528: S_BOSS_STND2
+	state(SPR_BOSS, 0, 3, Some("A_Chase"), 530),             // 529: S_BOSS_RUN1
+	state(SPR_BOSS, 0, 3, Some("A_Chase"), 531),             // 530: S_BOSS_RUN2
+	state(SPR_BOSS, 1, 3, Some("A_Chase"), 532),             // 531: S_BOSS_RUN3
+	state(SPR_BOSS, 1, 3, Some("A_Chase"), 533),             // 532: S_BOSS_RUN4
+	state(SPR_BOSS, 2, 3, Some("A_Chase"), 534),             // 533: S_BOSS_RUN5
+	state(SPR_BOSS, 2, 3, Some("A_Chase"), 535),             // 534: S_BOSS_RUN6
+	state(SPR_BOSS, 3, 3, Some("A_Chase"), 536),             // 535: S_BOSS_RUN7
+	state(SPR_BOSS, 3, 3, Some("A_Chase"), 529),             // 536: S_BOSS_RUN8
+	state(SPR_BOSS, 4, 8, Some("A_FaceTarget"), 538),        // 537: S_BOSS_ATK1
+	state(SPR_BOSS, 5, 8, Some("A_FaceTarget"), 539),        // 538: S_BOSS_ATK2
+	state(SPR_BOSS, 6, 8, Some("A_BruisAttack"), 529),       // 539: S_BOSS_ATK3
+	state(SPR_BOSS, 7, 2, None, 541),                        // 540: S_BOSS_PAIN
+	state(SPR_BOSS, 7, 2, Some("A_Pain"), 529),              // 541: S_BOSS_PAIN2
+	state(SPR_BOSS, 8, 8, None, 543),                        // 542: S_BOSS_DIE1
+	state(SPR_BOSS, 9, 8, Some("A_Scream"), 544),            // 543: S_BOSS_DIE2
+	state(SPR_BOSS, 10, 8, None, 545),                       // 544: S_BOSS_DIE3
+	state(SPR_BOSS, 11, 8, Some("A_Fall"), 546),             // 545: S_BOSS_DIE4
+	state(SPR_BOSS, 12, 8, None, 547),                       // 546: S_BOSS_DIE5
+	state(SPR_BOSS, 13, 8, None, 548),                       // 547: S_BOSS_DIE6
+	state(SPR_BOSS, 14, -1, Some("A_BossDeath"), 0),         // 548: S_BOSS_DIE7
+	state(SPR_BOSS, 14, 8, None, 550),                       // 549: S_BOSS_RAISE1
+	state(SPR_BOSS, 13, 8, None, 551),                       // 550: S_BOSS_RAISE2
+	state(SPR_BOSS, 12, 8, None, 552),                       // 551: S_BOSS_RAISE3
+	state(SPR_BOSS, 11, 8, None, 553),                       // 552: S_BOSS_RAISE4
+	state(SPR_BOSS, 10, 8, None, 554),                       // 553: S_BOSS_RAISE5
+	state(SPR_BOSS, 9, 8, None, 555),                        // 554: S_BOSS_RAISE6
+	state(SPR_BOSS, 8, 8, None, 529),                        // 555: S_BOSS_RAISE7
+	state(SPR_BOS2, 0, 10, Some("A_Look"), 557),             // 556: S_BOS2_STND
+	state(SPR_BOS2, 1, 10, Some("A_Look"), 556),             // 557: S_BOS2_STND2
+	state(SPR_BOS2, 0, 3, Some("A_Chase"), 559),             // 558: S_BOS2_RUN1
+	state(SPR_BOS2, 0, 3, Some("A_Chase"), 560),             // 559: S_BOS2_RUN2
+	state(SPR_BOS2, 1, 3, Some("A_Chase"), 561),             // 560: S_BOS2_RUN3
+	state(SPR_BOS2, 1, 3, Some("A_Chase"), 562),             // 561: S_BOS2_RUN4
+	state(SPR_BOS2, 2, 3, Some("A_Chase"), 563),             // 562: S_BOS2_RUN5
+	state(SPR_BOS2, 2, 3, Some("A_Chase"), 564),             // 563: S_BOS2_RUN6
+	state(SPR_BOS2, 3, 3, Some("A_Chase"), 565),             // 564: S_BOS2_RUN7
+	state(SPR_BOS2, 3, 3, Some("A_Chase"), 558),             // 565: S_BOS2_RUN8
+	state(SPR_BOS2, 4, 8, Some("A_FaceTarget"), 567),        // 566: S_BOS2_ATK1
+	state(SPR_BOS2, 5, 8, Some("A_FaceTarget"), 568),        // 567: S_BOS2_ATK2
+	state(SPR_BOS2, 6, 8, Some("A_BruisAttack"), 558),       // 568: S_BOS2_ATK3
+	state(SPR_BOS2, 7, 2, None, 570),                        // 569: S_BOS2_PAIN
+	state(SPR_BOS2, 7, 2, Some("A_Pain"), 558),              // 570: S_BOS2_PAIN2
+	state(SPR_BOS2, 8, 8, None, 572),                        // 571: S_BOS2_DIE1
+	state(SPR_BOS2, 9, 8, Some("A_Scream"), 573),            // 572: S_BOS2_DIE2
+	state(SPR_BOS2, 10, 8, None, 574),                       // 573: S_BOS2_DIE3
+	state(SPR_BOS2, 11, 8, Some("A_Fall"), 575),             // 574: S_BOS2_DIE4
+	state(SPR_BOS2, 12, 8, None, 576),                       // 575: S_BOS2_DIE5
+	state(SPR_BOS2, 13, 8, None, 577),                       // 576: S_BOS2_DIE6
+	state(SPR_BOS2, 14, -1, None, 0),                        // 577: S_BOS2_DIE7
+	state(SPR_BOS2, 14, 8, None, 579),                       // 578: S_BOS2_RAISE1
+	state(SPR_BOS2, 13, 8, None, 580),                       // 579: S_BOS2_RAISE2
+	state(SPR_BOS2, 12, 8, None, 581),                       // 580: S_BOS2_RAISE3
+	state(SPR_BOS2, 11, 8, None, 582),                       // 581: S_BOS2_RAISE4
+	state(SPR_BOS2, 10, 8, None, 583),                       // 582: S_BOS2_RAISE5
+	state(SPR_BOS2, 9, 8, None, 584),                        // 583: S_BOS2_RAISE6
+	state(SPR_BOS2, 8, 8, None, 558),                        // 584: S_BOS2_RAISE7
+	state(SPR_SKUL, FF_FULLBRIGHT, 10, Some("A_Look"), 586), // 585: S_SKULL_STND
+	state(SPR_SKUL, FF_FULLBRIGHT | 1, 10, Some("A_Look"), 585), // 586: S_SKULL_STND2
+	state(SPR_SKUL, FF_FULLBRIGHT, 6, Some("A_Chase"), 588), // 587: S_SKULL_RUN1
+	state(SPR_SKUL, FF_FULLBRIGHT | 1, 6, Some("A_Chase"), 587), // 588: S_SKULL_RUN2
+	state(SPR_SKUL, FF_FULLBRIGHT | 2, 10, Some("A_FaceTarget"), 590), // 589: S_SKULL_ATK1
+	state(SPR_SKUL, FF_FULLBRIGHT | 3, 4, Some("A_SkullAttack"), 591), // 590: S_SKULL_ATK2
+	state(SPR_SKUL, FF_FULLBRIGHT | 2, 4, None, 592),        // 591: S_SKULL_ATK3
+	state(SPR_SKUL, FF_FULLBRIGHT | 3, 4, None, 591),        // 592: S_SKULL_ATK4
+	state(SPR_SKUL, FF_FULLBRIGHT | 4, 3, None, 594),        // 593: S_SKULL_PAIN
+	state(SPR_SKUL, FF_FULLBRIGHT | 4, 6, Some("A_Pain"), 587), // 594: S_SKULL_PAIN2
+	state(SPR_SKUL, FF_FULLBRIGHT | 5, 6, None, 596),        // 595: S_SKULL_DIE1
+	state(SPR_SKUL, FF_FULLBRIGHT | 6, 6, Some("A_Scream"), 597), // 596: S_SKULL_DIE2
+	state(SPR_SKUL, FF_FULLBRIGHT | 7, 6, None, 598),        // 597: S_SKULL_DIE3
+	state(SPR_SKUL, FF_FULLBRIGHT | 8, 6, Some("A_Fall"), 599), // 598: S_SKULL_DIE4
+	state(SPR_SKUL, 9, 6, None, 600),                        // 599: S_SKULL_DIE5
+	state(SPR_SKUL, 10, 6, None, 0),                         // 600: S_SKULL_DIE6
+	state(SPR_SPID, 0, 10, Some("A_Look"), 602),             // 601: S_SPID_STND
+	state(SPR_SPID, 1, 10, Some("A_Look"), 601),             // 602: S_SPID_STND2
+	state(SPR_SPID, 0, 3, Some("A_Metal"), 604),             // 603: S_SPID_RUN1
+	state(SPR_SPID, 0, 3, Some("A_Chase"), 605),             // 604: S_SPID_RUN2
+	state(SPR_SPID, 1, 3, Some("A_Chase"), 606),             // 605: S_SPID_RUN3
+	state(SPR_SPID, 1, 3, Some("A_Chase"), 607),             // 606: S_SPID_RUN4
+	state(SPR_SPID, 2, 3, Some("A_Metal"), 608),             // 607: S_SPID_RUN5
+	state(SPR_SPID, 2, 3, Some("A_Chase"), 609),             // 608: S_SPID_RUN6
+	state(SPR_SPID, 3, 3, Some("A_Chase"), 610),             // 609: S_SPID_RUN7
+	state(SPR_SPID, 3, 3, Some("A_Chase"), 611),             // 610: S_SPID_RUN8
+	state(SPR_SPID, 4, 3, Some("A_Metal"), 612),             // 611: S_SPID_RUN9
+	state(SPR_SPID, 4, 3, Some("A_Chase"), 613),             // 612: S_SPID_RUN10
+	state(SPR_SPID, 5, 3, Some("A_Chase"), 614),             // 613: S_SPID_RUN11
+	state(SPR_SPID, 5, 3, Some("A_Chase"), 603),             // 614: S_SPID_RUN12
+	state(SPR_SPID, FF_FULLBRIGHT, 20, Some("A_FaceTarget"), 616), // 615: S_SPID_ATK1
+	state(SPR_SPID, FF_FULLBRIGHT | 6, 4, Some("A_SPosAttack"), 617), // 616: S_SPID_ATK2
+	state(SPR_SPID, FF_FULLBRIGHT | 7, 4, Some("A_SPosAttack"), 618), // 617: S_SPID_ATK3
+	state(SPR_SPID, FF_FULLBRIGHT | 7, 1, Some("A_SpidRefire"), 616), // 618: S_SPID_ATK4
+	state(SPR_SPID, 8, 3, None, 620),                        // 619: S_SPID_PAIN
+	state(SPR_SPID, 8, 3, Some("A_Pain"), 603),              // 620: S_SPID_PAIN2
+	state(SPR_SPID, 9, 20, Some("A_Scream"), 622),           // 621: S_SPID_DIE1
+	state(SPR_SPID, 10, 10, Some("A_Fall"), 623),            // 622: S_SPID_DIE2
+	state(SPR_SPID, 11, 10, None, 624),                      // 623: S_SPID_DIE3
+	state(SPR_SPID, 12, 10, None, 625),                      // 624: S_SPID_DIE4
+	state(SPR_SPID, 13, 10, None, 626),                      // 625: S_SPID_DIE5
+	state(SPR_SPID, 14, 10, None, 627),                      // 626: S_SPID_DIE6
+	state(SPR_SPID, 15, 10, None, 628),                      // 627: S_SPID_DIE7
+	state(SPR_SPID, 16, 10, None, 629),                      // 628: S_SPID_DIE8
+	state(SPR_SPID, 17, 10, None, 630),                      // 629: S_SPID_DIE9
+	state(SPR_SPID, 18, 30, None, 631),                      // 630: S_SPID_DIE10
+	state(SPR_SPID, 18, -1, Some("A_BossDeath"), 0),         // 631: S_SPID_DIE11
+	state(SPR_BSPI, 0, 10, Some("A_Look"), 633),             // 632: S_BSPI_STND
+	state(SPR_BSPI, 1, 10, Some("A_Look"), 632),             // 633: S_BSPI_STND2
+	state(SPR_BSPI, 0, 20, None, 635),                       // 634: S_BSPI_SIGHT
+	state(SPR_BSPI, 0, 3, Some("A_BabyMetal"), 636),         // 635: S_BSPI_RUN1
+	state(SPR_BSPI, 0, 3, Some("A_Chase"), 637),             // 636: S_BSPI_RUN2
+	state(SPR_BSPI, 1, 3, Some("A_Chase"), 638),             // 637: S_BSPI_RUN3
+	state(SPR_BSPI, 1, 3, Some("A_Chase"), 639),             // 638: S_BSPI_RUN4
+	state(SPR_BSPI, 2, 3, Some("A_Chase"), 640),             // 639: S_BSPI_RUN5
+	state(SPR_BSPI, 2, 3, Some("A_Chase"), 641),             // 640: S_BSPI_RUN6
+	state(SPR_BSPI, 3, 3, Some("A_BabyMetal"), 642),         // 641: S_BSPI_RUN7
+	state(SPR_BSPI, 3, 3, Some("A_Chase"), 643),             // 642: S_BSPI_RUN8
+	state(SPR_BSPI, 4, 3, Some("A_Chase"), 644),             // 643: S_BSPI_RUN9
+	state(SPR_BSPI, 4, 3, Some("A_Chase"), 645),             // 644: S_BSPI_RUN10
+	state(SPR_BSPI, 5, 3, Some("A_Chase"), 646),             // 645: S_BSPI_RUN11
+	state(SPR_BSPI, 5, 3, Some("A_Chase"), 635),             // 646: S_BSPI_RUN12
+	state(SPR_BSPI, FF_FULLBRIGHT, 20, Some("A_FaceTarget"), 648), // 647: S_BSPI_ATK1
+	state(SPR_BSPI, FF_FULLBRIGHT | 6, 4, Some("A_BspiAttack"), 649), // 648: S_BSPI_ATK2
+	state(SPR_BSPI, FF_FULLBRIGHT | 7, 4, None, 650),        // 649: S_BSPI_ATK3
+	state(SPR_BSPI, FF_FULLBRIGHT | 7, 1, Some("A_SpidRefire"), 648), // 650: S_BSPI_ATK4
+	state(SPR_BSPI, 8, 3, None, 652),                        // 651: S_BSPI_PAIN
+	state(SPR_BSPI, 8, 3, Some("A_Pain"), 635),              // 652: S_BSPI_PAIN2
+	state(SPR_BSPI, 9, 20, Some("A_Scream"), 654),           // 653: S_BSPI_DIE1
+	state(SPR_BSPI, 10, 7, Some("A_Fall"), 655),             // 654: S_BSPI_DIE2
+	state(SPR_BSPI, 11, 7, None, 656),                       // 655: S_BSPI_DIE3
+	state(SPR_BSPI, 12, 7, None, 657),                       // 656: S_BSPI_DIE4
+	state(SPR_BSPI, 13, 7, None, 658),                       // 657: S_BSPI_DIE5
+	state(SPR_BSPI, 14, 7, None, 659),                       // 658: S_BSPI_DIE6
+	state(SPR_BSPI, 15, -1, Some("A_BossDeath"), 0),         // 659: S_BSPI_DIE7
+	state(SPR_BSPI, 15, 5, None, 661),                       // 660: S_BSPI_RAISE1
+	state(SPR_BSPI, 14, 5, None, 662),                       // 661: S_BSPI_RAISE2
+	state(SPR_BSPI, 13, 5, None, 663),                       // 662: S_BSPI_RAISE3
+	state(SPR_BSPI, 12, 5, None, 664),                       // 663: S_BSPI_RAISE4
+	state(SPR_BSPI, 11, 5, None, 665),                       // 664: S_BSPI_RAISE5
+	state(SPR_BSPI, 10, 5, None, 666),                       // 665: S_BSPI_RAISE6
+	state(SPR_BSPI, 9, 5, None, 635),                        // 666: S_BSPI_RAISE7
+	state(SPR_APLS, FF_FULLBRIGHT, 5, None, 668),            // 667: S_ARACH_PLAZ
+	state(SPR_APLS, FF_FULLBRIGHT | 1, 5, None, 667),        // 668: S_ARACH_PLAZ2
+	state(SPR_APBX, FF_FULLBRIGHT, 5, None, 670),            // 669: S_ARACH_PLEX
+	state(SPR_APBX, FF_FULLBRIGHT | 1, 5, None, 671),        // 670: S_ARACH_PLEX2
+	state(SPR_APBX, FF_FULLBRIGHT | 2, 5, None, 672),        // 671: S_ARACH_PLEX3
+	state(SPR_APBX, FF_FULLBRIGHT | 3, 5, None, 673),        // 672: S_ARACH_PLEX4
+	state(SPR_APBX, FF_FULLBRIGHT | 4, 5, None, 0),          // 673: S_ARACH_PLEX5
+	state(SPR_CYBR, 0, 10, Some("A_Look"), 675),             // 674: S_CYBER_STND
+	state(SPR_CYBR, 1, 10, Some("A_Look"), 674),             // 675: S_CYBER_STND2
+	state(SPR_CYBR, 0, 3, Some("A_Hoof"), 677),              // 676: S_CYBER_RUN1
+	state(SPR_CYBR, 0, 3, Some("A_Chase"), 678),             // 677: S_CYBER_RUN2
+	state(SPR_CYBR, 1, 3, Some("A_Chase"), 679),             // 678: S_CYBER_RUN3
+	state(SPR_CYBR, 1, 3, Some("A_Chase"), 680),             // 679: S_CYBER_RUN4
+	state(SPR_CYBR, 2, 3, Some("A_Chase"), 681),             // 680: S_CYBER_RUN5
+	state(SPR_CYBR, 2, 3, Some("A_Chase"), 682),             // 681: S_CYBER_RUN6
+	state(SPR_CYBR, 3, 3, Some("A_Metal"), 683),             // 682: S_CYBER_RUN7
+	state(SPR_CYBR, 3, 3, Some("A_Chase"), 676),             // 683: S_CYBER_RUN8
+	state(SPR_CYBR, 4, 6, Some("A_FaceTarget"), 685),        // 684: S_CYBER_ATK1
+	state(SPR_CYBR, 5, 12, Some("A_CyberAttack"), 686),      // 685: S_CYBER_ATK2
+	state(SPR_CYBR, 4, 12, Some("A_FaceTarget"), 687),       // 686: S_CYBER_ATK3
+	state(SPR_CYBR, 5, 12, Some("A_CyberAttack"), 688),      // 687: S_CYBER_ATK4
+	state(SPR_CYBR, 4, 12, Some("A_FaceTarget"), 689),       // 688: S_CYBER_ATK5
+	state(SPR_CYBR, 5, 12, Some("A_CyberAttack"), 676),      // 689: S_CYBER_ATK6
+	state(SPR_CYBR, 6, 10, Some("A_Pain"), 676),             // 690: S_CYBER_PAIN
+	state(SPR_CYBR, 7, 10, None, 692),                       // 691: S_CYBER_DIE1
+	state(SPR_CYBR, 8, 10, Some("A_Scream"), 693),           // 692: S_CYBER_DIE2
+	state(SPR_CYBR, 9, 10, None, 694),                       // 693: S_CYBER_DIE3
+	state(SPR_CYBR, 10, 10, None, 695),                      // 694: S_CYBER_DIE4
+	state(SPR_CYBR, 11, 10, None, 696),                      // 695: S_CYBER_DIE5
+	state(SPR_CYBR, 12, 10, Some("A_Fall"), 697),            // 696: S_CYBER_DIE6
+	state(SPR_CYBR, 13, 10, None, 698),                      // 697: S_CYBER_DIE7
+	state(SPR_CYBR, 14, 10, None, 699),                      // 698: S_CYBER_DIE8
+	state(SPR_CYBR, 15, 30, None, 700),                      // 699: S_CYBER_DIE9
+	state(SPR_CYBR, 15, -1, Some("A_BossDeath"), 0),         // 700: S_CYBER_DIE10
+	state(SPR_PAIN, 0, 10, Some("A_Look"), 701),             // 701: S_PAIN_STND
+	state(SPR_PAIN, 0, 3, Some("A_Chase"), 703),             // 702: S_PAIN_RUN1
+	state(SPR_PAIN, 0, 3, Some("A_Chase"), 704),             // 703: S_PAIN_RUN2
+	state(SPR_PAIN, 1, 3, Some("A_Chase"), 705),             // 704: S_PAIN_RUN3
+	state(SPR_PAIN, 1, 3, Some("A_Chase"), 706),             // 705: S_PAIN_RUN4
+	state(SPR_PAIN, 2, 3, Some("A_Chase"), 707),             // 706: S_PAIN_RUN5
+	state(SPR_PAIN, 2, 3, Some("A_Chase"), 702),             // 707: S_PAIN_RUN6
+	state(SPR_PAIN, 3, 5, Some("A_FaceTarget"), 709),        // 708: S_PAIN_ATK1
+	state(SPR_PAIN, 4, 5, Some("A_FaceTarget"), 710),        // 709: S_PAIN_ATK2
+	state(SPR_PAIN, FF_FULLBRIGHT | 5, 5, Some("A_FaceTarget"), 711), // 710: S_PAIN_ATK3
+	state(SPR_PAIN, FF_FULLBRIGHT | 5, 0, Some("A_PainAttack"), 702), // 711: S_PAIN_ATK4
+	state(SPR_PAIN, 6, 6, None, 713),                        // 712: S_PAIN_PAIN
+	state(SPR_PAIN, 6, 6, Some("A_Pain"), 702),              // 713: S_PAIN_PAIN2
+	state(SPR_PAIN, FF_FULLBRIGHT | 7, 8, None, 715),        // 714: S_PAIN_DIE1
+	state(SPR_PAIN, FF_FULLBRIGHT | 8, 8, Some("A_Scream"), 716), // 715: S_PAIN_DIE2
+	state(SPR_PAIN, FF_FULLBRIGHT | 9, 8, None, 717),        // 716: S_PAIN_DIE3
+	state(SPR_PAIN, FF_FULLBRIGHT | 10, 8, None, 718),       // 717: S_PAIN_DIE4
+	state(SPR_PAIN, FF_FULLBRIGHT | 11, 8, Some("A_PainDie"), 719), // 718: S_PAIN_DIE5
+	state(SPR_PAIN, FF_FULLBRIGHT | 12, 8, None, 0),         // 719: S_PAIN_DIE6
+	state(SPR_PAIN, 12, 8, None, 721),                       // 720: S_PAIN_RAISE1
+	state(SPR_PAIN, 11, 8, None, 722),                       // 721: S_PAIN_RAISE2
+	state(SPR_PAIN, 10, 8, None, 723),                       // 722: S_PAIN_RAISE3
+	state(SPR_PAIN, 9, 8, None, 724),                        // 723: S_PAIN_RAISE4
+	state(SPR_PAIN, 8, 8, None, 725),                        // 724: S_PAIN_RAISE5
+	state(SPR_PAIN, 7, 8, None, 702),                        // 725: S_PAIN_RAISE6
+	state(SPR_SSWV, 0, 10, Some("A_Look"), 727),             // 726: S_SSWV_STND
+	state(SPR_SSWV, 1, 10, Some("A_Look"), 726),             // 727: S_SSWV_STND2
+	state(SPR_SSWV, 0, 3, Some("A_Chase"), 729),             // 728: S_SSWV_RUN1
+	state(SPR_SSWV, 0, 3, Some("A_Chase"), 730),             // 729: S_SSWV_RUN2
+	state(SPR_SSWV, 1, 3, Some("A_Chase"), 731),             // 730: S_SSWV_RUN3
+	state(SPR_SSWV, 1, 3, Some("A_Chase"), 732),             // 731: S_SSWV_RUN4
+	state(SPR_SSWV, 2, 3, Some("A_Chase"), 733),             // 732: S_SSWV_RUN5
+	state(SPR_SSWV, 2, 3, Some("A_Chase"), 734),             // 733: S_SSWV_RUN6
+	state(SPR_SSWV, 3, 3, Some("A_Chase"), 735),             // 734: S_SSWV_RUN7
+	state(SPR_SSWV, 3, 3, Some("A_Chase"), 728),             // 735: S_SSWV_RUN8
+	state(SPR_SSWV, 4, 10, Some("A_FaceTarget"), 737),       // 736: S_SSWV_ATK1
+	state(SPR_SSWV, 5, 10, Some("A_FaceTarget"), 738),       // 737: S_SSWV_ATK2
+	state(SPR_SSWV, FF_FULLBRIGHT | 6, 4, Some("A_CPosAttack"), 739), // 738: S_SSWV_ATK3
+	state(SPR_SSWV, 5, 6, Some("A_FaceTarget"), 740),        // 739: S_SSWV_ATK4
+	state(SPR_SSWV, FF_FULLBRIGHT | 6, 4, Some("A_CPosAttack"), 741), // 740: S_SSWV_ATK5
+	state(SPR_SSWV, 5, 1, Some("A_CPosRefire"), 737),        // 741: S_SSWV_ATK6
+	state(SPR_SSWV, 7, 3, None, 743),                        // 742: S_SSWV_PAIN
+	state(SPR_SSWV, 7, 3, Some("A_Pain"), 728),              // 743: S_SSWV_PAIN2
+	state(SPR_SSWV, 8, 5, None, 745),                        // 744: S_SSWV_DIE1
+	state(SPR_SSWV, 9, 5, Some("A_Scream"), 746),            // 745: S_SSWV_DIE2
+	state(SPR_SSWV, 10, 5, Some("A_Fall"), 747),             // 746: S_SSWV_DIE3
+	state(SPR_SSWV, 11, 5, None, 748),                       // 747: S_SSWV_DIE4
+	state(SPR_SSWV, 12, -1, None, 0),                        // 748: S_SSWV_DIE5
+	state(SPR_SSWV, 13, 5, None, 750),                       // 749: S_SSWV_XDIE1
+	state(SPR_SSWV, 14, 5, Some("A_XScream"), 751),          // 750: S_SSWV_XDIE2
+	state(SPR_SSWV, 15, 5, Some("A_Fall"), 752),             // 751: S_SSWV_XDIE3
+	state(SPR_SSWV, 16, 5, None, 753),                       // 752: S_SSWV_XDIE4
+	state(SPR_SSWV, 17, 5, None, 754),                       // 753: S_SSWV_XDIE5
+	state(SPR_SSWV, 18, 5, None, 755),                       // 754: S_SSWV_XDIE6
+	state(SPR_SSWV, 19, 5, None, 756),                       // 755: S_SSWV_XDIE7
+	state(SPR_SSWV, 20, 5, None, 757),                       // 756: S_SSWV_XDIE8
+	state(SPR_SSWV, 21, -1, None, 0),                        // 757: S_SSWV_XDIE9
+	state(SPR_SSWV, 12, 5, None, 759),                       // 758: S_SSWV_RAISE1
+	state(SPR_SSWV, 11, 5, None, 760),                       // 759: S_SSWV_RAISE2
+	state(SPR_SSWV, 10, 5, None, 761),                       // 760: S_SSWV_RAISE3
+	state(SPR_SSWV, 9, 5, None, 762),                        // 761: S_SSWV_RAISE4
+	state(SPR_SSWV, 8, 5, None, 728),                        // 762: S_SSWV_RAISE5
+	state(SPR_KEEN, 0, -1, None, 763),                       // 763: S_KEENSTND
+	state(SPR_KEEN, 0, 6, None, 765),                        // 764: S_COMMKEEN
+	state(SPR_KEEN, 1, 6, None, 766),                        // 765: S_COMMKEEN2
+	state(SPR_KEEN, 2, 6, Some("A_Scream"), 767),            // 766: S_COMMKEEN3
+	state(SPR_KEEN, 3, 6, None, 768),                        // 767: S_COMMKEEN4
+	state(SPR_KEEN, 4, 6, None, 769),                        // 768: S_COMMKEEN5
+	state(SPR_KEEN, 5, 6, None, 770),                        // 769: S_COMMKEEN6
+	state(SPR_KEEN, 6, 6, None, 771),                        // 770: S_COMMKEEN7
+	state(SPR_KEEN, 7, 6, None, 772),                        // 771: S_COMMKEEN8
+	state(SPR_KEEN, 8, 6, None, 773),                        // 772: S_COMMKEEN9
+	state(SPR_KEEN, 9, 6, None, 774),                        // 773: S_COMMKEEN10
+	state(SPR_KEEN, 10, 6, Some("A_KeenDie"), 775),          // 774: S_COMMKEEN11
+	state(SPR_KEEN, 11, -1, None, 0),                        // 775: S_COMMKEEN12
+	state(SPR_KEEN, 12, 4, None, 777),                       // 776: S_KEENPAIN
+	state(SPR_KEEN, 12, 8, Some("A_Pain"), 763),             // 777: S_KEENPAIN2
+	state(SPR_BBRN, 0, -1, None, 0),                         // 778: S_BRAIN
+	state(SPR_BBRN, 1, 36, Some("A_BrainPain"), 778),        // 779: S_BRAIN_PAIN
+	state(SPR_BBRN, 0, 100, Some("A_BrainScream"), 781),     // 780: S_BRAIN_DIE1
+	state(SPR_BBRN, 0, 10, None, 782),                       // 781: S_BRAIN_DIE2
+	state(SPR_BBRN, 0, 10, None, 783),                       // 782: S_BRAIN_DIE3
+	state(SPR_BBRN, 0, -1, Some("A_BrainDie"), 0),           // 783: S_BRAIN_DIE4
+	state(SPR_SSWV, 0, 10, Some("A_Look"), 784),             // 784: S_BRAINEYE
+	state(SPR_SSWV, 0, 181, Some("A_BrainAwake"), 786),      // 785: S_BRAINEYESEE
+	state(SPR_SSWV, 0, 150, Some("A_BrainSpit"), 786),       // 786: S_BRAINEYE1
+	state(SPR_BOSF, FF_FULLBRIGHT, 3, Some("A_SpawnSound"), 788), // 787: S_SPAWN1
+	state(SPR_BOSF, FF_FULLBRIGHT | 1, 3, Some("A_SpawnFly"), 789), // 788: S_SPAWN2
+	state(SPR_BOSF, FF_FULLBRIGHT | 2, 3, Some("A_SpawnFly"), 790), // 789: S_SPAWN3
+	state(SPR_BOSF, FF_FULLBRIGHT | 3, 3, Some("A_SpawnFly"), 787), // 790: S_SPAWN4
+	state(SPR_FIRE, FF_FULLBRIGHT, 4, Some("A_Fire"), 792),  // 791: S_SPAWNFIRE1
+	state(SPR_FIRE, FF_FULLBRIGHT | 1, 4, Some("A_Fire"), 793), // 792: S_SPAWNFIRE2
+	state(SPR_FIRE, FF_FULLBRIGHT | 2, 4, Some("A_Fire"), 794), // 793: S_SPAWNFIRE3
+	state(SPR_FIRE, FF_FULLBRIGHT | 3, 4, Some("A_Fire"), 795), // 794: S_SPAWNFIRE4
+	state(SPR_FIRE, FF_FULLBRIGHT | 4, 4, Some("A_Fire"), 796), // 795: S_SPAWNFIRE5
+	state(SPR_FIRE, FF_FULLBRIGHT | 5, 4, Some("A_Fire"), 797), // 796: S_SPAWNFIRE6
+	state(SPR_FIRE, FF_FULLBRIGHT | 6, 4, Some("A_Fire"), 798), // 797: S_SPAWNFIRE7
+	state(SPR_FIRE, FF_FULLBRIGHT | 7, 4, Some("A_Fire"), 0), // 798: S_SPAWNFIRE8
+	state(SPR_MISL, FF_FULLBRIGHT | 1, 10, None, 800),       // 799: S_BRAINEXPLODE1
+	state(SPR_MISL, FF_FULLBRIGHT | 2, 10, None, 801),       // 800: S_BRAINEXPLODE2
+	state(SPR_MISL, FF_FULLBRIGHT | 3, 10, Some("A_BrainExplode"), 0), // 801: S_BRAINEXPLODE3
+	state(SPR_ARM1, 0, 6, None, 803),                        // 802: S_ARM1
+	state(SPR_ARM1, FF_FULLBRIGHT | 1, 7, None, 802),        // 803: S_ARM1A
+	state(SPR_ARM2, 0, 6, None, 805),                        // 804: S_ARM2
+	state(SPR_ARM2, FF_FULLBRIGHT | 1, 6, None, 804),        // 805: S_ARM2A
+	state(SPR_BAR1, 0, 6, None, 807),                        // 806: S_BAR1
+	state(SPR_BAR1, 1, 6, None, 806),                        // 807: S_BAR2
+	state(SPR_BEXP, FF_FULLBRIGHT, 5, None, 809),            // 808: S_BEXP
+	state(SPR_BEXP, FF_FULLBRIGHT | 1, 5, Some("A_Scream"), 810), // 809: S_BEXP2
+	state(SPR_BEXP, FF_FULLBRIGHT | 2, 5, None, 811),        // 810: S_BEXP3
+	state(SPR_BEXP, FF_FULLBRIGHT | 3, 10, Some("A_Explode"), 812), // 811: S_BEXP4
+	state(SPR_BEXP, FF_FULLBRIGHT | 4, 10, None, 0),         // 812: S_BEXP5
+	state(SPR_FCAN, FF_FULLBRIGHT, 4, None, 814),            // 813: S_BBAR1
+	state(SPR_FCAN, FF_FULLBRIGHT | 1, 4, None, 815),        // 814: S_BBAR2
+	state(SPR_FCAN, FF_FULLBRIGHT | 2, 4, None, 813),        // 815: S_BBAR3
+	state(SPR_BON1, 0, 6, None, 817),                        // 816: S_BON1
+	state(SPR_BON1, 1, 6, None, 818),                        // 817: S_BON1A
+	state(SPR_BON1, 2, 6, None, 819),                        // 818: S_BON1B
+	state(SPR_BON1, 3, 6, None, 820),                        // 819: S_BON1C
+	state(SPR_BON1, 2, 6, None, 821),                        // 820: S_BON1D
+	state(SPR_BON1, 1, 6, None, 816),                        // 821: S_BON1E
+	state(SPR_BON2, 0, 6, None, 823),                        // 822: S_BON2
+	state(SPR_BON2, 1, 6, None, 824),                        // 823: S_BON2A
+	state(SPR_BON2, 2, 6, None, 825),                        // 824: S_BON2B
+	state(SPR_BON2, 3, 6, None, 826),                        // 825: S_BON2C
+	state(SPR_BON2, 2, 6, None, 827),                        // 826: S_BON2D
+	state(SPR_BON2, 1, 6, None, 822),                        // 827: S_BON2E
+	state(SPR_BKEY, 0, 10, None, 829),                       // 828: S_BKEY
+	state(SPR_BKEY, FF_FULLBRIGHT | 1, 10, None, 828),       // 829: S_BKEY2
+	state(SPR_RKEY, 0, 10, None, 831),                       // 830: S_RKEY
+	state(SPR_RKEY, FF_FULLBRIGHT | 1, 10, None, 830),       // 831: S_RKEY2
+	state(SPR_YKEY, 0, 10, None, 833),                       // 832: S_YKEY
+	state(SPR_YKEY, FF_FULLBRIGHT | 1, 10, None, 832),       // 833: S_YKEY2
+	state(SPR_BSKU, 0, 10, None, 835),                       // 834: S_BSKULL
+	state(SPR_BSKU, FF_FULLBRIGHT | 1, 10, None, 834),       // 835: S_BSKULL2
+	state(SPR_RSKU, 0, 10, None, 837),                       // 836: S_RSKULL
+	state(SPR_RSKU, FF_FULLBRIGHT | 1, 10, None, 836),       // 837: S_RSKULL2
+	state(SPR_YSKU, 0, 10, None, 839),                       // 838: S_YSKULL
+	state(SPR_YSKU, FF_FULLBRIGHT | 1, 10, None, 838),       // 839: S_YSKULL2
+	state(SPR_STIM, 0, -1, None, 0),                         // 840: S_STIM
+	state(SPR_MEDI, 0, -1, None, 0),                         // 841: S_MEDI
+	state(SPR_SOUL, FF_FULLBRIGHT, 6, None, 843),            // 842: S_SOUL
+	state(SPR_SOUL, FF_FULLBRIGHT | 1, 6, None, 844),        // 843: S_SOUL2
+	state(SPR_SOUL, FF_FULLBRIGHT | 2, 6, None, 845),        // 844: S_SOUL3
+	state(SPR_SOUL, FF_FULLBRIGHT | 3, 6, None, 846),        // 845: S_SOUL4
+	state(SPR_SOUL, FF_FULLBRIGHT | 2, 6, None, 847),        // 846: S_SOUL5
+	state(SPR_SOUL, FF_FULLBRIGHT | 1, 6, None, 842),        // 847: S_SOUL6
+	state(SPR_PINV, FF_FULLBRIGHT, 6, None, 849),            // 848: S_PINV
+	state(SPR_PINV, FF_FULLBRIGHT | 1, 6, None, 850),        // 849: S_PINV2
+	state(SPR_PINV, FF_FULLBRIGHT | 2, 6, None, 851),        // 850: S_PINV3
+	state(SPR_PINV, FF_FULLBRIGHT | 3, 6, None, 848),        // 851: S_PINV4
+	state(SPR_PSTR, FF_FULLBRIGHT, -1, None, 0),             // 852: S_PSTR
+	state(SPR_PINS, FF_FULLBRIGHT, 6, None, 854),            // 853: S_PINS
+	state(SPR_PINS, FF_FULLBRIGHT | 1, 6, None, 855),        // 854: S_PINS2
+	state(SPR_PINS, FF_FULLBRIGHT | 2, 6, None, 856),        // 855: S_PINS3
+	state(SPR_PINS, FF_FULLBRIGHT | 3, 6, None, 853),        // 856: S_PINS4
+	state(SPR_MEGA, FF_FULLBRIGHT, 6, None, 858),            // 857: S_MEGA
+	state(SPR_MEGA, FF_FULLBRIGHT | 1, 6, None, 859),        // 858: S_MEGA2
+	state(SPR_MEGA, FF_FULLBRIGHT | 2, 6, None, 860),        // 859: S_MEGA3
+	state(SPR_MEGA, FF_FULLBRIGHT | 3, 6, None, 857),        // 860: S_MEGA4
+	state(SPR_SUIT, FF_FULLBRIGHT, -1, None, 0),             // 861: S_SUIT
+	state(SPR_PMAP, FF_FULLBRIGHT, 6, None, 863),            // 862: S_PMAP
+	state(SPR_PMAP, FF_FULLBRIGHT | 1, 6, None, 864),        // 863: S_PMAP2
+	state(SPR_PMAP, FF_FULLBRIGHT | 2, 6, None, 865),        // 864: S_PMAP3
+	state(SPR_PMAP, FF_FULLBRIGHT | 3, 6, None, 866),        // 865: S_PMAP4
+	state(SPR_PMAP, FF_FULLBRIGHT | 2, 6, None, 867),        // 866: S_PMAP5
+	state(SPR_PMAP, FF_FULLBRIGHT | 1, 6, None, 862),        // 867: S_PMAP6
+	state(SPR_PVIS, FF_FULLBRIGHT, 6, None, 869),            // 868: S_PVIS
+	state(SPR_PVIS, 1, 6, None, 868),                        // 869: S_PVIS2
+	state(SPR_CLIP, 0, -1, None, 0),                         // 870: S_CLIP
+	state(SPR_AMMO, 0, -1, None, 0),                         // 871: S_AMMO
+	state(SPR_ROCK, 0, -1, None, 0),                         // 872: S_ROCK
+	state(SPR_BROK, 0, -1, None, 0),                         // 873: S_BROK
+	state(SPR_CELL, 0, -1, None, 0),                         // 874: S_CELL
+	state(SPR_CELP, 0, -1, None, 0),                         // 875: S_CELP
+	state(SPR_SHEL, 0, -1, None, 0),                         // 876: S_SHEL
+	state(SPR_SBOX, 0, -1, None, 0),                         // 877: S_SBOX
+	state(SPR_BPAK, 0, -1, None, 0),                         // 878: S_BPAK
+	state(SPR_BFUG, 0, -1, None, 0),                         // 879: S_BFUG
+	state(SPR_MGUN, 0, -1, None, 0),                         // 880: S_MGUN
+	state(SPR_CSAW, 0, -1, None, 0),                         // 881: S_CSAW
+	state(SPR_LAUN, 0, -1, None, 0),                         // 882: S_LAUN
+	state(SPR_PLAS, 0, -1, None, 0),                         // 883: S_PLAS
+	state(SPR_SHOT, 0, -1, None, 0),                         // 884: S_SHOT
+	state(SPR_SGN2, 0, -1, None, 0),                         // 885: S_SHOT2
+	state(SPR_COLU, FF_FULLBRIGHT, -1, None, 0),             // 886: S_COLU
+	state(SPR_SMT2, 0, -1, None, 0),                         // 887: S_STALAG
+	state(SPR_GOR1, 0, 10, None, 889),                       // 888: S_BLOODYTWITCH1
+	state(SPR_GOR1, 1, 15, None, 890),                       // 889: S_BLOODYTWITCH2
+	state(SPR_GOR1, 2, 8, None, 891),                        // 890: S_BLOODYTWITCH3
+	state(SPR_GOR1, 1, 6, None, 888),                        // 891: S_BLOODYTWITCH4
+	state(SPR_PLAY, 13, -1, None, 0),                        // 892: S_DEADTORSO
+	state(SPR_PLAY, 18, -1, None, 0),                        // 893: S_DEADBOTTOM
+	state(SPR_POL2, 0, -1, None, 0),                         // 894: S_HEADSONSTICK
+	state(SPR_POL5, 0, -1, None, 0),                         // 895: S_GIBS
+	state(SPR_POL4, 0, -1, None, 0),                         // 896: S_HEADONASTICK
+	state(SPR_POL3, FF_FULLBRIGHT, 6, None, 898),            // 897: S_HEADCANDLES
+	state(SPR_POL3, FF_FULLBRIGHT | 1, 6, None, 897),        // 898: S_HEADCANDLES2
+	state(SPR_POL1, 0, -1, None, 0),                         // 899: S_DEADSTICK
+	state(SPR_POL6, 0, 6, None, 901),                        // 900: S_LIVESTICK
+	state(SPR_POL6, 1, 8, None, 900),                        // 901: S_LIVESTICK2
+	state(SPR_GOR2, 0, -1, None, 0),                         // 902: S_MEAT2
+	state(SPR_GOR3, 0, -1, None, 0),                         // 903: S_MEAT3
+	state(SPR_GOR4, 0, -1, None, 0),                         // 904: S_MEAT4
+	state(SPR_GOR5, 0, -1, None, 0),                         // 905: S_MEAT5
+	state(SPR_SMIT, 0, -1, None, 0),                         // 906: S_STALAGTITE
+	state(SPR_COL1, 0, -1, None, 0),                         // 907: S_TALLGRNCOL
+	state(SPR_COL2, 0, -1, None, 0),                         // 908: S_SHRTGRNCOL
+	state(SPR_COL3, 0, -1, None, 0),                         // 909: S_TALLREDCOL
+	state(SPR_COL4, 0, -1, None, 0),                         // 910: S_SHRTREDCOL
+	state(SPR_CAND, FF_FULLBRIGHT, -1, None, 0),             // 911: S_CANDLESTIK
+	state(SPR_CBRA, FF_FULLBRIGHT, -1, None, 0),             // 912: S_CANDELABRA
+	state(SPR_COL6, 0, -1, None, 0),                         // 913: S_SKULLCOL
+	state(SPR_TRE1, 0, -1, None, 0),                         // 914: S_TORCHTREE
+	state(SPR_TRE2, 0, -1, None, 0),                         // 915: S_BIGTREE
+	state(SPR_ELEC, 0, -1, None, 0),                         // 916: S_TECHPILLAR
+	state(SPR_CEYE, FF_FULLBRIGHT, 6, None, 918),            // 917: S_EVILEYE
+	state(SPR_CEYE, FF_FULLBRIGHT | 1, 6, None, 919),        // 918: S_EVILEYE2
+	state(SPR_CEYE, FF_FULLBRIGHT | 2, 6, None, 920),        // 919: S_EVILEYE3
+	state(SPR_CEYE, FF_FULLBRIGHT | 1, 6, None, 917),        // 920: S_EVILEYE4
+	state(SPR_FSKU, FF_FULLBRIGHT, 6, None, 922),            // 921: S_FLOATSKULL
+	state(SPR_FSKU, FF_FULLBRIGHT | 1, 6, None, 923),        // 922: S_FLOATSKULL2
+	state(SPR_FSKU, FF_FULLBRIGHT | 2, 6, None, 921),        // 923: S_FLOATSKULL3
+	state(SPR_COL5, 0, 14, None, 925),                       // 924: S_HEARTCOL
+	state(SPR_COL5, 1, 14, None, 924),                       // 925: S_HEARTCOL2
+	state(SPR_TBLU, FF_FULLBRIGHT, 4, None, 927),            // 926: S_BLUETORCH
+	state(SPR_TBLU, FF_FULLBRIGHT | 1, 4, None, 928),        // 927: S_BLUETORCH2
+	state(SPR_TBLU, FF_FULLBRIGHT | 2, 4, None, 929),        // 928: S_BLUETORCH3
+	state(SPR_TBLU, FF_FULLBRIGHT | 3, 4, None, 926),        // 929: S_BLUETORCH4
+	state(SPR_TGRN, FF_FULLBRIGHT, 4, None, 931),            // 930: S_GREENTORCH
+	state(SPR_TGRN, FF_FULLBRIGHT | 1, 4, None, 932),        // 931: S_GREENTORCH2
+	state(SPR_TGRN, FF_FULLBRIGHT | 2, 4, None, 933),        // 932: S_GREENTORCH3
+	state(SPR_TGRN, FF_FULLBRIGHT | 3, 4, None, 930),        // 933: S_GREENTORCH4
+	state(SPR_TRED, FF_FULLBRIGHT, 4, None, 935),            // 934: S_REDTORCH
+	state(SPR_TRED, FF_FULLBRIGHT | 1, 4, None, 936),        // 935: S_REDTORCH2
+	state(SPR_TRED, FF_FULLBRIGHT | 2, 4, None, 937),        // 936: S_REDTORCH3
+	state(SPR_TRED, FF_FULLBRIGHT | 3, 4, None, 934),        // 937: S_REDTORCH4
+	state(SPR_SMBT, FF_FULLBRIGHT, 4, None, 939),            // 938: S_BTORCHSHRT
+	state(SPR_SMBT, FF_FULLBRIGHT | 1, 4, None, 940),        // 939: S_BTORCHSHRT2
+	state(SPR_SMBT, FF_FULLBRIGHT | 2, 4, None, 941),        // 940: S_BTORCHSHRT3
+	state(SPR_SMBT, FF_FULLBRIGHT | 3, 4, None, 938),        // 941: S_BTORCHSHRT4
+	state(SPR_SMGT, FF_FULLBRIGHT, 4, None, 943),            // 942: S_GTORCHSHRT
+	state(SPR_SMGT, FF_FULLBRIGHT | 1, 4, None, 944),        // 943: S_GTORCHSHRT2
+	state(SPR_SMGT, FF_FULLBRIGHT | 2, 4, None, 945),        // 944: S_GTORCHSHRT3
+	state(SPR_SMGT, FF_FULLBRIGHT | 3, 4, None, 942),        // 945: S_GTORCHSHRT4
+	state(SPR_SMRT, FF_FULLBRIGHT, 4, None, 947),            // 946: S_RTORCHSHRT
+	state(SPR_SMRT, FF_FULLBRIGHT | 1, 4, None, 948),        // 947: S_RTORCHSHRT2
+	state(SPR_SMRT, FF_FULLBRIGHT | 2, 4, None, 949),        // 948: S_RTORCHSHRT3
+	state(SPR_SMRT, FF_FULLBRIGHT | 3, 4, None, 946),        // 949: S_RTORCHSHRT4
+	state(SPR_HDB1, 0, -1, None, 0),                         // 950: S_HANGNOGUTS
+	state(SPR_HDB2, 0, -1, None, 0),                         // 951: S_HANGBNOBRAIN
+	state(SPR_HDB3, 0, -1, None, 0),                         // 952: S_HANGTLOOKDN
+	state(SPR_HDB4, 0, -1, None, 0),                         // 953: S_HANGTSKULL
+	state(SPR_HDB5, 0, -1, None, 0),                         // 954: S_HANGTLOOKUP
+	state(SPR_HDB6, 0, -1, None, 0),                         // 955: S_HANGTNOBRAIN
+	state(SPR_POB1, 0, -1, None, 0),                         // 956: S_COLONGIBS
+	state(SPR_POB2, 0, -1, None, 0),                         // 957: S_SMALLPOOL
+	state(SPR_BRS1, 0, -1, None, 0),                         // 958: S_BRAINSTEM
+	state(SPR_TLMP, FF_FULLBRIGHT, 4, None, 960),            // 959: S_TECHLAMP
+	state(SPR_TLMP, FF_FULLBRIGHT | 1, 4, None, 961),        // 960: S_TECHLAMP2
+	state(SPR_TLMP, FF_FULLBRIGHT | 2, 4, None, 962),        // 961: S_TECHLAMP3
+	state(SPR_TLMP, FF_FULLBRIGHT | 3, 4, None, 959),        // 962: S_TECHLAMP4
+	state(SPR_TLP2, FF_FULLBRIGHT, 4, None, 964),            // 963: S_TECH2LAMP
+	state(SPR_TLP2, FF_FULLBRIGHT | 1, 4, None, 965),        // 964: S_TECH2LAMP2
+	state(SPR_TLP2, FF_FULLBRIGHT | 2, 4, None, 966),        // 965: S_TECH2LAMP3
+	state(SPR_TLP2, FF_FULLBRIGHT | 3, 4, None, 963),        // 966: S_TECH2LAMP4
+];
+
+/// Each sound's lump name (without the `DS` prefix) and priority.
+pub(super) const SOUNDS: [(&str, i32); 109] = [
+	("none", 0),
+	("pistol", 64),
+	("shotgn", 64),
+	("sgcock", 64),
+	("dshtgn", 64),
+	("dbopn", 64),
+	("dbcls", 64),
+	("dbload", 64),
+	("plasma", 64),
+	("bfg", 64),
+	("sawup", 64),
+	("sawidl", 118),
+	("sawful", 64),
+	("sawhit", 64),
+	("rlaunc", 64),
+	("rxplod", 70),
+	("firsht", 70),
+	("firxpl", 70),
+	("pstart", 100),
+	("pstop", 100),
+	("doropn", 100),
+	("dorcls", 100),
+	("stnmov", 119),
+	("swtchn", 78),
+	("swtchx", 78),
+	("plpain", 96),
+	("dmpain", 96),
+	("popain", 96),
+	("vipain", 96),
+	("mnpain", 96),
+	("pepain", 96),
+	("slop", 78),
+	("itemup", 78),
+	("wpnup", 78),
+	("oof", 96),
+	("telept", 32),
+	("posit1", 98),
+	("posit2", 98),
+	("posit3", 98),
+	("bgsit1", 98),
+	("bgsit2", 98),
+	("sgtsit", 98),
+	("cacsit", 98),
+	("brssit", 94),
+	("cybsit", 92),
+	("spisit", 90),
+	("bspsit", 90),
+	("kntsit", 90),
+	("vilsit", 90),
+	("mansit", 90),
+	("pesit", 90),
+	("sklatk", 70),
+	("sgtatk", 70),
+	("skepch", 70),
+	("vilatk", 70),
+	("claw", 70),
+	("skeswg", 70),
+	("pldeth", 32),
+	("pdiehi", 32),
+	("podth1", 70),
+	("podth2", 70),
+	("podth3", 70),
+	("bgdth1", 70),
+	("bgdth2", 70),
+	("sgtdth", 70),
+	("cacdth", 70),
+	("skldth", 70),
+	("brsdth", 32),
+	("cybdth", 32),
+	("spidth", 32),
+	("bspdth", 32),
+	("vildth", 32),
+	("kntdth", 32),
+	("pedth", 32),
+	("skedth", 32),
+	("posact", 120),
+	("bgact", 120),
+	("dmact", 120),
+	("bspact", 100),
+	("bspwlk", 100),
+	("vilact", 100),
+	("noway", 78),
+	("barexp", 60),
+	("punch", 64),
+	("hoof", 70),
+	("metal", 70),
+	("chgun", 64),
+	("tink", 60),
+	("bdopn", 100),
+	("bdcls", 100),
+	("itmbk", 100),
+	("flame", 32),
+	("flamst", 32),
+	("getpow", 60),
+	("bospit", 70),
+	("boscub", 70),
+	("bossit", 70),
+	("bospn", 70),
+	("bosdth", 70),
+	("manatk", 70),
+	("mandth", 70),
+	("sssit", 70),
+	("ssdth", 70),
+	("keenpn", 70),
+	("keendt", 70),
+	("skeact", 70),
+	("skesit", 70),
+	("skeatk", 70),
+	("radio", 60),
+];
+
+/// Field values shared by most entries of [`THINGS`].
+pub(super) const THING: ThingInfo = ThingInfo {
+	doomednum: -1,
+	spawn_state: 0,
+	spawn_health: 1000,
+	see_state: 0,
+	see_sound: 0,
+	reaction_time: 8,
+	attack_sound: 0,
+	pain_state: 0,
+	pain_chance: 0,
+	pain_sound: 0,
+	melee_state: 0,
+	missile_state: 0,
+	death_state: 0,
+	xdeath_state: 0,
+	death_sound: 0,
+	speed: 0,
+	radius: 20 * FRACUNIT,
+	height: 16 * FRACUNIT,
+	mass: 100,
+	damage: 0,
+	active_sound: 0,
+	flags: 0,
+	raise_state: 0,
+	mbf21_flags: 0,
+	infighting_group: 0,
+	projectile_group: 0,
+	splash_group: 0,
+	fast_speed: 0,
+	melee_range: 64 * FRACUNIT,
+	rip_sound: 0,
+	dropped_item: 0,
+};
+
+pub(super) static THINGS: [ThingInfo; 137] = [
+	// 1: MT_PLAYER
+	ThingInfo {
+		spawn_state: 149, // S_PLAY
+		spawn_health: 100,
+		see_state: 150, // S_PLAY_RUN1
+		reaction_time: 0,
+		pain_state: 156, // S_PLAY_PAIN
+		pain_chance: 255,
+		pain_sound: 25,     // sfx_plpain
+		missile_state: 154, // S_PLAY_ATK1
+		death_state: 158,   // S_PLAY_DIE1
+		xdeath_state: 165,  // S_PLAY_XDIE1
+		death_sound: 57,    // sfx_pldeth
+		radius: 16 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		flags: MF_SOLID | MF_SHOOTABLE | MF_DROPOFF | MF_PICKUP | MF_NOTDMATCH,
+		..THING
+	},
+	// 2: MT_POSSESSED
+	ThingInfo {
+		doomednum: 3004,
+		spawn_state: 174, // S_POSS_STND
+		spawn_health: 20,
+		see_state: 176,  // S_POSS_RUN1
+		see_sound: 36,   // sfx_posit1
+		attack_sound: 1, // sfx_pistol
+		pain_state: 187, // S_POSS_PAIN
+		pain_chance: 200,
+		pain_sound: 27,     // sfx_popain
+		missile_state: 184, // S_POSS_ATK1
+		death_state: 189,   // S_POSS_DIE1
+		xdeath_state: 194,  // S_POSS_XDIE1
+		death_sound: 59,    // sfx_podth1
+		speed: 8,
+		height: 56 * FRACUNIT,
+		active_sound: 75, // sfx_posact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 203, // S_POSS_RAISE1
+		dropped_item: 64, // MT_CLIP
+		..THING
+	},
+	// 3: MT_SHOTGUY
+	ThingInfo {
+		doomednum: 9,
+		spawn_state: 207, // S_SPOS_STND
+		spawn_health: 30,
+		see_state: 209,  // S_SPOS_RUN1
+		see_sound: 37,   // sfx_posit2
+		pain_state: 220, // S_SPOS_PAIN
+		pain_chance: 170,
+		pain_sound: 27,     // sfx_popain
+		missile_state: 217, // S_SPOS_ATK1
+		death_state: 222,   // S_SPOS_DIE1
+		xdeath_state: 227,  // S_SPOS_XDIE1
+		death_sound: 60,    // sfx_podth2
+		speed: 8,
+		height: 56 * FRACUNIT,
+		active_sound: 75, // sfx_posact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 236, // S_SPOS_RAISE1
+		dropped_item: 78, // MT_SHOTGUN
+		..THING
+	},
+	// 4: MT_VILE
+	ThingInfo {
+		doomednum: 64,
+		spawn_state: 241, // S_VILE_STND
+		spawn_health: 700,
+		see_state: 243,  // S_VILE_RUN1
+		see_sound: 48,   // sfx_vilsit
+		pain_state: 269, // S_VILE_PAIN
+		pain_chance: 10,
+		pain_sound: 28,     // sfx_vipain
+		missile_state: 255, // S_VILE_ATK1
+		death_state: 271,   // S_VILE_DIE1
+		death_sound: 71,    // sfx_vildth
+		speed: 15,
+		height: 56 * FRACUNIT,
+		mass: 500,
+		active_sound: 80, // sfx_vilact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		mbf21_flags: MF2_SHORTMRANGE | MF2_DMGIGNORED | MF2_NOTHRESHOLD,
+		..THING
+	},
+	// 5: MT_FIRE
+	ThingInfo {
+		spawn_state: 281, // S_FIRE1
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 6: MT_UNDEAD
+	ThingInfo {
+		doomednum: 66,
+		spawn_state: 321, // S_SKEL_STND
+		spawn_health: 300,
+		see_state: 323,  // S_SKEL_RUN1
+		see_sound: 106,  // sfx_skesit
+		pain_state: 343, // S_SKEL_PAIN
+		pain_chance: 100,
+		pain_sound: 27,     // sfx_popain
+		melee_state: 335,   // S_SKEL_FIST1
+		missile_state: 339, // S_SKEL_MISS1
+		death_state: 345,   // S_SKEL_DIE1
+		death_sound: 74,    // sfx_skedth
+		speed: 10,
+		height: 56 * FRACUNIT,
+		mass: 500,
+		active_sound: 105, // sfx_skeact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 351, // S_SKEL_RAISE1
+		mbf21_flags: MF2_LONGMELEE | MF2_RANGEHALF,
+		..THING
+	},
+	// 7: MT_TRACER
+	ThingInfo {
+		spawn_state: 316, // S_TRACER
+		see_sound: 107,   // sfx_skeatk
+		death_state: 318, // S_TRACEEXP1
+		death_sound: 82,  // sfx_barexp
+		speed: 10 * FRACUNIT,
+		radius: 11 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 10,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 8: MT_SMOKE
+	ThingInfo {
+		spawn_state: 311, // S_SMOKE1
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 9: MT_FATSO
+	ThingInfo {
+		doomednum: 67,
+		spawn_state: 362, // S_FATT_STND
+		spawn_health: 600,
+		see_state: 364,  // S_FATT_RUN1
+		see_sound: 49,   // sfx_mansit
+		pain_state: 386, // S_FATT_PAIN
+		pain_chance: 80,
+		pain_sound: 29,     // sfx_mnpain
+		missile_state: 376, // S_FATT_ATK1
+		death_state: 388,   // S_FATT_DIE1
+		death_sound: 100,   // sfx_mandth
+		speed: 8,
+		radius: 48 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		mass: 1000,
+		active_sound: 75, // sfx_posact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 398, // S_FATT_RAISE1
+		mbf21_flags: MF2_MAP07BOSS1,
+		..THING
+	},
+	// 10: MT_FATSHOT
+	ThingInfo {
+		spawn_state: 357, // S_FATSHOT1
+		see_sound: 16,    // sfx_firsht
+		death_state: 359, // S_FATSHOTX1
+		death_sound: 17,  // sfx_firxpl
+		speed: 20 * FRACUNIT,
+		radius: 6 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 8,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 11: MT_CHAINGUY
+	ThingInfo {
+		doomednum: 65,
+		spawn_state: 406, // S_CPOS_STND
+		spawn_health: 70,
+		see_state: 408,  // S_CPOS_RUN1
+		see_sound: 37,   // sfx_posit2
+		pain_state: 420, // S_CPOS_PAIN
+		pain_chance: 170,
+		pain_sound: 27,     // sfx_popain
+		missile_state: 416, // S_CPOS_ATK1
+		death_state: 422,   // S_CPOS_DIE1
+		xdeath_state: 429,  // S_CPOS_XDIE1
+		death_sound: 60,    // sfx_podth2
+		speed: 8,
+		height: 56 * FRACUNIT,
+		active_sound: 75, // sfx_posact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 435, // S_CPOS_RAISE1
+		dropped_item: 74, // MT_CHAINGUN
+		..THING
+	},
+	// 12: MT_TROOP
+	ThingInfo {
+		doomednum: 3001,
+		spawn_state: 442, // S_TROO_STND
+		spawn_health: 60,
+		see_state: 444,  // S_TROO_RUN1
+		see_sound: 39,   // sfx_bgsit1
+		pain_state: 455, // S_TROO_PAIN
+		pain_chance: 200,
+		pain_sound: 27,     // sfx_popain
+		melee_state: 452,   // S_TROO_ATK1
+		missile_state: 452, // S_TROO_ATK1
+		death_state: 457,   // S_TROO_DIE1
+		xdeath_state: 462,  // S_TROO_XDIE1
+		death_sound: 62,    // sfx_bgdth1
+		speed: 8,
+		height: 56 * FRACUNIT,
+		active_sound: 76, // sfx_bgact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 470, // S_TROO_RAISE1
+		..THING
+	},
+	// 13: MT_SERGEANT
+	ThingInfo {
+		doomednum: 3002,
+		spawn_state: 475, // S_SARG_STND
+		spawn_health: 150,
+		see_state: 477,   // S_SARG_RUN1
+		see_sound: 41,    // sfx_sgtsit
+		attack_sound: 52, // sfx_sgtatk
+		pain_state: 488,  // S_SARG_PAIN
+		pain_chance: 180,
+		pain_sound: 26,   // sfx_dmpain
+		melee_state: 485, // S_SARG_ATK1
+		death_state: 490, // S_SARG_DIE1
+		death_sound: 64,  // sfx_sgtdth
+		speed: 10,
+		radius: 30 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		mass: 400,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 496, // S_SARG_RAISE1
+		..THING
+	},
+	// 14: MT_SHADOWS
+	ThingInfo {
+		doomednum: 58,
+		spawn_state: 475, // S_SARG_STND
+		spawn_health: 150,
+		see_state: 477,   // S_SARG_RUN1
+		see_sound: 41,    // sfx_sgtsit
+		attack_sound: 52, // sfx_sgtatk
+		pain_state: 488,  // S_SARG_PAIN
+		pain_chance: 180,
+		pain_sound: 26,   // sfx_dmpain
+		melee_state: 485, // S_SARG_ATK1
+		death_state: 490, // S_SARG_DIE1
+		death_sound: 64,  // sfx_sgtdth
+		speed: 10,
+		radius: 30 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		mass: 400,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_SHADOW | MF_COUNTKILL,
+		raise_state: 496, // S_SARG_RAISE1
+		..THING
+	},
+	// 15: MT_HEAD
+	ThingInfo {
+		doomednum: 3005,
+		spawn_state: 502, // S_HEAD_STND
+		spawn_health: 400,
+		see_state: 503,  // S_HEAD_RUN1
+		see_sound: 42,   // sfx_cacsit
+		pain_state: 507, // S_HEAD_PAIN
+		pain_chance: 128,
+		pain_sound: 26,     // sfx_dmpain
+		missile_state: 504, // S_HEAD_ATK1
+		death_state: 510,   // S_HEAD_DIE1
+		death_sound: 65,    // sfx_cacdth
+		speed: 8,
+		radius: 31 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		mass: 400,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+		raise_state: 516, // S_HEAD_RAISE1
+		..THING
+	},
+	// 16: MT_BRUISER
+	ThingInfo {
+		doomednum: 3003,
+		spawn_state: 527, // S_BOSS_STND
+		spawn_health: 1000,
+		see_state: 529,  // S_BOSS_RUN1
+		see_sound: 43,   // sfx_brssit
+		pain_state: 540, // S_BOSS_PAIN
+		pain_chance: 50,
+		pain_sound: 26,     // sfx_dmpain
+		melee_state: 537,   // S_BOSS_ATK1
+		missile_state: 537, // S_BOSS_ATK1
+		death_state: 542,   // S_BOSS_DIE1
+		death_sound: 67,    // sfx_brsdth
+		speed: 8,
+		radius: 24 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		mass: 1000,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 549, // S_BOSS_RAISE1
+		mbf21_flags: MF2_E1M8BOSS,
+		projectile_group: PG_BARON,
+		..THING
+	},
+	// 17: MT_BRUISERSHOT
+	ThingInfo {
+		spawn_state: 522, // S_BRBALL1
+		see_sound: 16,    // sfx_firsht
+		death_state: 524, // S_BRBALLX1
+		death_sound: 17,  // sfx_firxpl
+		speed: 15 * FRACUNIT,
+		radius: 6 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 8,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		fast_speed: 20 * FRACUNIT,
+		..THING
+	},
+	// 18: MT_KNIGHT
+	ThingInfo {
+		doomednum: 69,
+		spawn_state: 556, // S_BOS2_STND
+		spawn_health: 500,
+		see_state: 558,  // S_BOS2_RUN1
+		see_sound: 47,   // sfx_kntsit
+		pain_state: 569, // S_BOS2_PAIN
+		pain_chance: 50,
+		pain_sound: 26,     // sfx_dmpain
+		melee_state: 566,   // S_BOS2_ATK1
+		missile_state: 566, // S_BOS2_ATK1
+		death_state: 571,   // S_BOS2_DIE1
+		death_sound: 72,    // sfx_kntdth
+		speed: 8,
+		radius: 24 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		mass: 1000,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 578, // S_BOS2_RAISE1
+		projectile_group: PG_BARON,
+		..THING
+	},
+	// 19: MT_SKULL
+	ThingInfo {
+		doomednum: 3006,
+		spawn_state: 585, // S_SKULL_STND
+		spawn_health: 100,
+		see_state: 587,   // S_SKULL_RUN1
+		attack_sound: 51, // sfx_sklatk
+		pain_state: 593,  // S_SKULL_PAIN
+		pain_chance: 256,
+		pain_sound: 26,     // sfx_dmpain
+		missile_state: 589, // S_SKULL_ATK1
+		death_state: 595,   // S_SKULL_DIE1
+		death_sound: 17,    // sfx_firxpl
+		speed: 8,
+		radius: 16 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		mass: 50,
+		damage: 3,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY,
+		mbf21_flags: MF2_RANGEHALF,
+		..THING
+	},
+	// 20: MT_SPIDER
+	ThingInfo {
+		doomednum: 7,
+		spawn_state: 601, // S_SPID_STND
+		spawn_health: 3000,
+		see_state: 603,  // S_SPID_RUN1
+		see_sound: 45,   // sfx_spisit
+		attack_sound: 2, // sfx_shotgn
+		pain_state: 619, // S_SPID_PAIN
+		pain_chance: 40,
+		pain_sound: 26,     // sfx_dmpain
+		missile_state: 615, // S_SPID_ATK1
+		death_state: 621,   // S_SPID_DIE1
+		death_sound: 69,    // sfx_spidth
+		speed: 12,
+		radius: 128 * FRACUNIT,
+		height: 100 * FRACUNIT,
+		mass: 1000,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		mbf21_flags: MF2_NORADIUSDMG
+			| MF2_RANGEHALF
+			| MF2_FULLVOLSOUNDS
+			| MF2_E3M8BOSS
+			| MF2_E4M8BOSS,
+		..THING
+	},
+	// 21: MT_BABY
+	ThingInfo {
+		doomednum: 68,
+		spawn_state: 632, // S_BSPI_STND
+		spawn_health: 500,
+		see_state: 634,  // S_BSPI_SIGHT
+		see_sound: 46,   // sfx_bspsit
+		pain_state: 651, // S_BSPI_PAIN
+		pain_chance: 128,
+		pain_sound: 26,     // sfx_dmpain
+		missile_state: 647, // S_BSPI_ATK1
+		death_state: 653,   // S_BSPI_DIE1
+		death_sound: 70,    // sfx_bspdth
+		speed: 12,
+		radius: 64 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		mass: 600,
+		active_sound: 78, // sfx_bspact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 660, // S_BSPI_RAISE1
+		mbf21_flags: MF2_MAP07BOSS2,
+		..THING
+	},
+	// 22: MT_CYBORG
+	ThingInfo {
+		doomednum: 16,
+		spawn_state: 674, // S_CYBER_STND
+		spawn_health: 4000,
+		see_state: 676,  // S_CYBER_RUN1
+		see_sound: 44,   // sfx_cybsit
+		pain_state: 690, // S_CYBER_PAIN
+		pain_chance: 20,
+		pain_sound: 26,     // sfx_dmpain
+		missile_state: 684, // S_CYBER_ATK1
+		death_state: 691,   // S_CYBER_DIE1
+		death_sound: 68,    // sfx_cybdth
+		speed: 16,
+		radius: 40 * FRACUNIT,
+		height: 110 * FRACUNIT,
+		mass: 1000,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		mbf21_flags: MF2_NORADIUSDMG
+			| MF2_HIGHERMPROB
+			| MF2_RANGEHALF
+			| MF2_FULLVOLSOUNDS
+			| MF2_E2M8BOSS
+			| MF2_E4M6BOSS,
+		..THING
+	},
+	// 23: MT_PAIN
+	ThingInfo {
+		doomednum: 71,
+		spawn_state: 701, // S_PAIN_STND
+		spawn_health: 400,
+		see_state: 702,  // S_PAIN_RUN1
+		see_sound: 50,   // sfx_pesit
+		pain_state: 712, // S_PAIN_PAIN
+		pain_chance: 128,
+		pain_sound: 30,     // sfx_pepain
+		missile_state: 708, // S_PAIN_ATK1
+		death_state: 714,   // S_PAIN_DIE1
+		death_sound: 73,    // sfx_pedth
+		speed: 8,
+		radius: 31 * FRACUNIT,
+		height: 56 * FRACUNIT,
+		mass: 400,
+		active_sound: 77, // sfx_dmact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+		raise_state: 720, // S_PAIN_RAISE1
+		..THING
+	},
+	// 24: MT_WOLFSS
+	ThingInfo {
+		doomednum: 84,
+		spawn_state: 726, // S_SSWV_STND
+		spawn_health: 50,
+		see_state: 728,  // S_SSWV_RUN1
+		see_sound: 101,  // sfx_sssit
+		pain_state: 742, // S_SSWV_PAIN
+		pain_chance: 170,
+		pain_sound: 27,     // sfx_popain
+		missile_state: 736, // S_SSWV_ATK1
+		death_state: 744,   // S_SSWV_DIE1
+		xdeath_state: 749,  // S_SSWV_XDIE1
+		death_sound: 102,   // sfx_ssdth
+		speed: 8,
+		height: 56 * FRACUNIT,
+		active_sound: 75, // sfx_posact
+		flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+		raise_state: 758, // S_SSWV_RAISE1
+		dropped_item: 64, // MT_CLIP
+		..THING
+	},
+	// 25: MT_KEEN
+	ThingInfo {
+		doomednum: 72,
+		spawn_state: 763, // S_KEENSTND
+		spawn_health: 100,
+		pain_state: 776, // S_KEENPAIN
+		pain_chance: 256,
+		pain_sound: 103,  // sfx_keenpn
+		death_state: 764, // S_COMMKEEN
+		death_sound: 104, // sfx_keendt
+		radius: 16 * FRACUNIT,
+		height: 72 * FRACUNIT,
+		mass: 10000000,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY | MF_SHOOTABLE | MF_COUNTKILL,
+		..THING
+	},
+	// 26: MT_BOSSBRAIN
+	ThingInfo {
+		doomednum: 88,
+		spawn_state: 778, // S_BRAIN
+		spawn_health: 250,
+		pain_state: 779, // S_BRAIN_PAIN
+		pain_chance: 255,
+		pain_sound: 97,   // sfx_bospn
+		death_state: 780, // S_BRAIN_DIE1
+		death_sound: 98,  // sfx_bosdth
+		radius: 16 * FRACUNIT,
+		mass: 10000000,
+		flags: MF_SOLID | MF_SHOOTABLE,
+		..THING
+	},
+	// 27: MT_BOSSSPIT
+	ThingInfo {
+		doomednum: 89,
+		spawn_state: 784, // S_BRAINEYE
+		see_state: 785,   // S_BRAINEYESEE
+		height: 32 * FRACUNIT,
+		flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+		..THING
+	},
+	// 28: MT_BOSSTARGET
+	ThingInfo {
+		doomednum: 87,
+		height: 32 * FRACUNIT,
+		flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+		..THING
+	},
+	// 29: MT_SPAWNSHOT
+	ThingInfo {
+		spawn_state: 787, // S_SPAWN1
+		see_sound: 94,    // sfx_bospit
+		speed: 10 * FRACUNIT,
+		radius: 6 * FRACUNIT,
+		height: 32 * FRACUNIT,
+		damage: 3,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY | MF_NOCLIP,
+		..THING
+	},
+	// 30: MT_SPAWNFIRE
+	ThingInfo {
+		spawn_state: 791, // S_SPAWNFIRE1
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 31: MT_BARREL
+	ThingInfo {
+		doomednum: 2035,
+		spawn_state: 806, // S_BAR1
+		spawn_health: 20,
+		death_state: 808, // S_BEXP
+		death_sound: 82,  // sfx_barexp
+		radius: 10 * FRACUNIT,
+		height: 42 * FRACUNIT,
+		flags: MF_SOLID | MF_SHOOTABLE | MF_NOBLOOD,
+		..THING
+	},
+	// 32: MT_TROOPSHOT
+	ThingInfo {
+		spawn_state: 97, // S_TBALL1
+		see_sound: 16,   // sfx_firsht
+		death_state: 99, // S_TBALLX1
+		death_sound: 17, // sfx_firxpl
+		speed: 10 * FRACUNIT,
+		radius: 6 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 3,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		fast_speed: 20 * FRACUNIT,
+		..THING
+	},
+	// 33: MT_HEADSHOT
+	ThingInfo {
+		spawn_state: 102, // S_RBALL1
+		see_sound: 16,    // sfx_firsht
+		death_state: 104, // S_RBALLX1
+		death_sound: 17,  // sfx_firxpl
+		speed: 10 * FRACUNIT,
+		radius: 6 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 5,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		fast_speed: 20 * FRACUNIT,
+		..THING
+	},
+	// 34: MT_ROCKET
+	ThingInfo {
+		spawn_state: 114, // S_ROCKET
+		see_sound: 14,    // sfx_rlaunc
+		death_state: 127, // S_EXPLODE1
+		death_sound: 82,  // sfx_barexp
+		speed: 20 * FRACUNIT,
+		radius: 11 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 20,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 35: MT_PLASMA
+	ThingInfo {
+		spawn_state: 107, // S_PLASBALL
+		see_sound: 8,     // sfx_plasma
+		death_state: 109, // S_PLASEXP
+		death_sound: 17,  // sfx_firxpl
+		speed: 25 * FRACUNIT,
+		radius: 13 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 5,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 36: MT_BFG
+	ThingInfo {
+		spawn_state: 115, // S_BFGSHOT
+		death_state: 117, // S_BFGLAND
+		death_sound: 15,  // sfx_rxplod
+		speed: 25 * FRACUNIT,
+		radius: 13 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 100,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 37: MT_ARACHPLAZ
+	ThingInfo {
+		spawn_state: 667, // S_ARACH_PLAZ
+		see_sound: 8,     // sfx_plasma
+		death_state: 669, // S_ARACH_PLEX
+		death_sound: 17,  // sfx_firxpl
+		speed: 25 * FRACUNIT,
+		radius: 13 * FRACUNIT,
+		height: 8 * FRACUNIT,
+		damage: 5,
+		flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+		..THING
+	},
+	// 38: MT_PUFF
+	ThingInfo {
+		spawn_state: 93, // S_PUFF1
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 39: MT_BLOOD
+	ThingInfo {
+		spawn_state: 90, // S_BLOOD1
+		flags: MF_NOBLOCKMAP,
+		..THING
+	},
+	// 40: MT_TFOG
+	ThingInfo {
+		spawn_state: 130, // S_TFOG
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 41: MT_IFOG
+	ThingInfo {
+		spawn_state: 142, // S_IFOG
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 42: MT_TELEPORTMAN
+	ThingInfo {
+		doomednum: 14,
+		flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+		..THING
+	},
+	// 43: MT_EXTRABFG
+	ThingInfo {
+		spawn_state: 123, // S_BFGEXP
+		flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+		..THING
+	},
+	// 44: MT_MISC0
+	ThingInfo {
+		doomednum: 2018,
+		spawn_state: 802, // S_ARM1
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 45: MT_MISC1
+	ThingInfo {
+		doomednum: 2019,
+		spawn_state: 804, // S_ARM2
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 46: MT_MISC2
+	ThingInfo {
+		doomednum: 2014,
+		spawn_state: 816, // S_BON1
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 47: MT_MISC3
+	ThingInfo {
+		doomednum: 2015,
+		spawn_state: 822, // S_BON2
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 48: MT_MISC4
+	ThingInfo {
+		doomednum: 5,
+		spawn_state: 828, // S_BKEY
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 49: MT_MISC5
+	ThingInfo {
+		doomednum: 13,
+		spawn_state: 830, // S_RKEY
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 50: MT_MISC6
+	ThingInfo {
+		doomednum: 6,
+		spawn_state: 832, // S_YKEY
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 51: MT_MISC7
+	ThingInfo {
+		doomednum: 39,
+		spawn_state: 838, // S_YSKULL
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 52: MT_MISC8
+	ThingInfo {
+		doomednum: 38,
+		spawn_state: 836, // S_RSKULL
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 53: MT_MISC9
+	ThingInfo {
+		doomednum: 40,
+		spawn_state: 834, // S_BSKULL
+		flags: MF_SPECIAL | MF_NOTDMATCH,
+		..THING
+	},
+	// 54: MT_MISC10
+	ThingInfo {
+		doomednum: 2011,
+		spawn_state: 840, // S_STIM
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 55: MT_MISC11
+	ThingInfo {
+		doomednum: 2012,
+		spawn_state: 841, // S_MEDI
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 56: MT_MISC12
+	ThingInfo {
+		doomednum: 2013,
+		spawn_state: 842, // S_SOUL
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 57: MT_INV
+	ThingInfo {
+		doomednum: 2022,
+		spawn_state: 848, // S_PINV
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 58: MT_MISC13
+	ThingInfo {
+		doomednum: 2023,
+		spawn_state: 852, // S_PSTR
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 59: MT_INS
+	ThingInfo {
+		doomednum: 2024,
+		spawn_state: 853, // S_PINS
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 60: MT_MISC14
+	ThingInfo {
+		doomednum: 2025,
+		spawn_state: 861, // S_SUIT
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 61: MT_MISC15
+	ThingInfo {
+		doomednum: 2026,
+		spawn_state: 862, // S_PMAP
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 62: MT_MISC16
+	ThingInfo {
+		doomednum: 2045,
+		spawn_state: 868, // S_PVIS
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 63: MT_MEGA
+	ThingInfo {
+		doomednum: 83,
+		spawn_state: 857, // S_MEGA
+		flags: MF_SPECIAL | MF_COUNTITEM,
+		..THING
+	},
+	// 64: MT_CLIP
+	ThingInfo {
+		doomednum: 2007,
+		spawn_state: 870, // S_CLIP
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 65: MT_MISC17
+	ThingInfo {
+		doomednum: 2048,
+		spawn_state: 871, // S_AMMO
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 66: MT_MISC18
+	ThingInfo {
+		doomednum: 2010,
+		spawn_state: 872, // S_ROCK
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 67: MT_MISC19
+	ThingInfo {
+		doomednum: 2046,
+		spawn_state: 873, // S_BROK
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 68: MT_MISC20
+	ThingInfo {
+		doomednum: 2047,
+		spawn_state: 874, // S_CELL
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 69: MT_MISC21
+	ThingInfo {
+		doomednum: 17,
+		spawn_state: 875, // S_CELP
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 70: MT_MISC22
+	ThingInfo {
+		doomednum: 2008,
+		spawn_state: 876, // S_SHEL
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 71: MT_MISC23
+	ThingInfo {
+		doomednum: 2049,
+		spawn_state: 877, // S_SBOX
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 72: MT_MISC24
+	ThingInfo {
+		doomednum: 8,
+		spawn_state: 878, // S_BPAK
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 73: MT_MISC25
+	ThingInfo {
+		doomednum: 2006,
+		spawn_state: 879, // S_BFUG
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 74: MT_CHAINGUN
+	ThingInfo {
+		doomednum: 2002,
+		spawn_state: 880, // S_MGUN
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 75: MT_MISC26
+	ThingInfo {
+		doomednum: 2005,
+		spawn_state: 881, // S_CSAW
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 76: MT_MISC27
+	ThingInfo {
+		doomednum: 2003,
+		spawn_state: 882, // S_LAUN
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 77: MT_MISC28
+	ThingInfo {
+		doomednum: 2004,
+		spawn_state: 883, // S_PLAS
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 78: MT_SHOTGUN
+	ThingInfo {
+		doomednum: 2001,
+		spawn_state: 884, // S_SHOT
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 79: MT_SUPERSHOTGUN
+	ThingInfo {
+		doomednum: 82,
+		spawn_state: 885, // S_SHOT2
+		flags: MF_SPECIAL,
+		..THING
+	},
+	// 80: MT_MISC29
+	ThingInfo {
+		doomednum: 85,
+		spawn_state: 959, // S_TECHLAMP
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 81: MT_MISC30
+	ThingInfo {
+		doomednum: 86,
+		spawn_state: 963, // S_TECH2LAMP
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 82: MT_MISC31
+	ThingInfo {
+		doomednum: 2028,
+		spawn_state: 886, // S_COLU
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 83: MT_MISC32
+	ThingInfo {
+		doomednum: 30,
+		spawn_state: 907, // S_TALLGRNCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 84: MT_MISC33
+	ThingInfo {
+		doomednum: 31,
+		spawn_state: 908, // S_SHRTGRNCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 85: MT_MISC34
+	ThingInfo {
+		doomednum: 32,
+		spawn_state: 909, // S_TALLREDCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 86: MT_MISC35
+	ThingInfo {
+		doomednum: 33,
+		spawn_state: 910, // S_SHRTREDCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 87: MT_MISC36
+	ThingInfo {
+		doomednum: 37,
+		spawn_state: 913, // S_SKULLCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 88: MT_MISC37
+	ThingInfo {
+		doomednum: 36,
+		spawn_state: 924, // S_HEARTCOL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 89: MT_MISC38
+	ThingInfo {
+		doomednum: 41,
+		spawn_state: 917, // S_EVILEYE
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 90: MT_MISC39
+	ThingInfo {
+		doomednum: 42,
+		spawn_state: 921, // S_FLOATSKULL
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 91: MT_MISC40
+	ThingInfo {
+		doomednum: 43,
+		spawn_state: 914, // S_TORCHTREE
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 92: MT_MISC41
+	ThingInfo {
+		doomednum: 44,
+		spawn_state: 926, // S_BLUETORCH
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 93: MT_MISC42
+	ThingInfo {
+		doomednum: 45,
+		spawn_state: 930, // S_GREENTORCH
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 94: MT_MISC43
+	ThingInfo {
+		doomednum: 46,
+		spawn_state: 934, // S_REDTORCH
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 95: MT_MISC44
+	ThingInfo {
+		doomednum: 55,
+		spawn_state: 938, // S_BTORCHSHRT
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 96: MT_MISC45
+	ThingInfo {
+		doomednum: 56,
+		spawn_state: 942, // S_GTORCHSHRT
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 97: MT_MISC46
+	ThingInfo {
+		doomednum: 57,
+		spawn_state: 946, // S_RTORCHSHRT
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 98: MT_MISC47
+	ThingInfo {
+		doomednum: 47,
+		spawn_state: 906, // S_STALAGTITE
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 99: MT_MISC48
+	ThingInfo {
+		doomednum: 48,
+		spawn_state: 916, // S_TECHPILLAR
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 100: MT_MISC49
+	ThingInfo {
+		doomednum: 34,
+		spawn_state: 911, // S_CANDLESTIK
+		..THING
+	},
+	// 101: MT_MISC50
+	ThingInfo {
+		doomednum: 35,
+		spawn_state: 912, // S_CANDELABRA
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 102: MT_MISC51
+	ThingInfo {
+		doomednum: 49,
+		spawn_state: 888, // S_BLOODYTWITCH1
+		radius: 16 * FRACUNIT,
+		height: 68 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 103: MT_MISC52
+	ThingInfo {
+		doomednum: 50,
+		spawn_state: 902, // S_MEAT2
+		radius: 16 * FRACUNIT,
+		height: 84 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 104: MT_MISC53
+	ThingInfo {
+		doomednum: 51,
+		spawn_state: 903, // S_MEAT3
+		radius: 16 * FRACUNIT,
+		height: 84 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 105: MT_MISC54
+	ThingInfo {
+		doomednum: 52,
+		spawn_state: 904, // S_MEAT4
+		radius: 16 * FRACUNIT,
+		height: 68 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 106: MT_MISC55
+	ThingInfo {
+		doomednum: 53,
+		spawn_state: 905, // S_MEAT5
+		radius: 16 * FRACUNIT,
+		height: 52 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 107: MT_MISC56
+	ThingInfo {
+		doomednum: 59,
+		spawn_state: 902, // S_MEAT2
+		height: 84 * FRACUNIT,
+		flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 108: MT_MISC57
+	ThingInfo {
+		doomednum: 60,
+		spawn_state: 904, // S_MEAT4
+		height: 68 * FRACUNIT,
+		flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 109: MT_MISC58
+	ThingInfo {
+		doomednum: 61,
+		spawn_state: 903, // S_MEAT3
+		height: 52 * FRACUNIT,
+		flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 110: MT_MISC59
+	ThingInfo {
+		doomednum: 62,
+		spawn_state: 905, // S_MEAT5
+		height: 52 * FRACUNIT,
+		flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 111: MT_MISC60
+	ThingInfo {
+		doomednum: 63,
+		spawn_state: 888, // S_BLOODYTWITCH1
+		height: 68 * FRACUNIT,
+		flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 112: MT_MISC61
+	ThingInfo {
+		doomednum: 22,
+		spawn_state: 515, // S_HEAD_DIE6
+		..THING
+	},
+	// 113: MT_MISC62
+	ThingInfo {
+		doomednum: 15,
+		spawn_state: 164, // S_PLAY_DIE7
+		..THING
+	},
+	// 114: MT_MISC63
+	ThingInfo {
+		doomednum: 18,
+		spawn_state: 193, // S_POSS_DIE5
+		..THING
+	},
+	// 115: MT_MISC64
+	ThingInfo {
+		doomednum: 21,
+		spawn_state: 495, // S_SARG_DIE6
+		..THING
+	},
+	// 116: MT_MISC65
+	ThingInfo {
+		doomednum: 23,
+		spawn_state: 600, // S_SKULL_DIE6
+		..THING
+	},
+	// 117: MT_MISC66
+	ThingInfo {
+		doomednum: 20,
+		spawn_state: 461, // S_TROO_DIE5
+		..THING
+	},
+	// 118: MT_MISC67
+	ThingInfo {
+		doomednum: 19,
+		spawn_state: 226, // S_SPOS_DIE5
+		..THING
+	},
+	// 119: MT_MISC68
+	ThingInfo {
+		doomednum: 10,
+		spawn_state: 173, // S_PLAY_XDIE9
+		..THING
+	},
+	// 120: MT_MISC69
+	ThingInfo {
+		doomednum: 12,
+		spawn_state: 173, // S_PLAY_XDIE9
+		..THING
+	},
+	// 121: MT_MISC70
+	ThingInfo {
+		doomednum: 28,
+		spawn_state: 894, // S_HEADSONSTICK
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 122: MT_MISC71
+	ThingInfo {
+		doomednum: 24,
+		spawn_state: 895, // S_GIBS
+		..THING
+	},
+	// 123: MT_MISC72
+	ThingInfo {
+		doomednum: 27,
+		spawn_state: 896, // S_HEADONASTICK
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 124: MT_MISC73
+	ThingInfo {
+		doomednum: 29,
+		spawn_state: 897, // S_HEADCANDLES
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 125: MT_MISC74
+	ThingInfo {
+		doomednum: 25,
+		spawn_state: 899, // S_DEADSTICK
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 126: MT_MISC75
+	ThingInfo {
+		doomednum: 26,
+		spawn_state: 900, // S_LIVESTICK
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 127: MT_MISC76
+	ThingInfo {
+		doomednum: 54,
+		spawn_state: 915, // S_BIGTREE
+		radius: 32 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 128: MT_MISC77
+	ThingInfo {
+		doomednum: 70,
+		spawn_state: 813, // S_BBAR1
+		radius: 16 * FRACUNIT,
+		flags: MF_SOLID,
+		..THING
+	},
+	// 129: MT_MISC78
+	ThingInfo {
+		doomednum: 73,
+		spawn_state: 950, // S_HANGNOGUTS
+		radius: 16 * FRACUNIT,
+		height: 88 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 130: MT_MISC79
+	ThingInfo {
+		doomednum: 74,
+		spawn_state: 951, // S_HANGBNOBRAIN
+		radius: 16 * FRACUNIT,
+		height: 88 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 131: MT_MISC80
+	ThingInfo {
+		doomednum: 75,
+		spawn_state: 952, // S_HANGTLOOKDN
+		radius: 16 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 132: MT_MISC81
+	ThingInfo {
+		doomednum: 76,
+		spawn_state: 953, // S_HANGTSKULL
+		radius: 16 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 133: MT_MISC82
+	ThingInfo {
+		doomednum: 77,
+		spawn_state: 954, // S_HANGTLOOKUP
+		radius: 16 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 134: MT_MISC83
+	ThingInfo {
+		doomednum: 78,
+		spawn_state: 955, // S_HANGTNOBRAIN
+		radius: 16 * FRACUNIT,
+		height: 64 * FRACUNIT,
+		flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+		..THING
+	},
+	// 135: MT_MISC84
+	ThingInfo {
+		doomednum: 79,
+		spawn_state: 956, // S_COLONGIBS
+		flags: MF_NOBLOCKMAP,
+		..THING
+	},
+	// 136: MT_MISC85
+	ThingInfo {
+		doomednum: 80,
+		spawn_state: 957, // S_SMALLPOOL
+		flags: MF_NOBLOCKMAP,
+		..THING
+	},
+	// 137: MT_MISC86
+	ThingInfo {
+		doomednum: 81,
+		spawn_state: 958, // S_BRAINSTEM
+		flags: MF_NOBLOCKMAP,
+		..THING
+	},
+];
+
+pub(super) static STRINGS: [(&str, &str); 292] = [
+	("D_DEVSTR", "Development mode ON.\n"),
+	("D_CDROM", "CD-ROM Version: default.cfg from c:\\doomdata\n"),
+	("PRESSKEY", "press a key."),
+	("PRESSYN", "press y or n."),
+	("QUITMSG", "are you sure you want to\nquit this great game?"),
+	("LOADNET", "you can't do load while in a net game!\n\npress a key."),
+	("QLOADNET", "you can't quickload during a netgame!\n\npress a key."),
+	("QSAVESPOT", "you haven't picked a quicksave slot yet!\n\npress a key."),
+	("SAVEDEAD", "you can't save if you aren't playing!\n\npress a key."),
+	("QSPROMPT", "quicksave over your game named\n\n'%s'?\n\npress y or n."),
+	("QLPROMPT", "do you want to quickload the game named\n\n'%s'?\n\npress y or n."),
+	("NEWGAME", "you can't start a new game\nwhile in a network game.\n\npress a key."),
+	("NIGHTMARE", "are you sure? this skill level\nisn't even remotely fair.\n\npress y or n."),
+	("SWSTRING", "this is the shareware version of doom.\n\nyou need to order the entire trilogy.\n\npress a key."),
+	("MSGOFF", "Messages OFF"),
+	("MSGON", "Messages ON"),
+	("NETEND", "you can't end a netgame!\n\npress a key."),
+	("ENDGAME", "are you sure you want to end the game?\n\npress y or n."),
+	("DOSY", "(press y to quit)"),
+	("DETAILHI", "High detail"),
+	("DETAILLO", "Low detail"),
+	("GAMMALVL0", "Gamma correction OFF"),
+	("GAMMALVL1", "Gamma correction level 1"),
+	("GAMMALVL2", "Gamma correction level 2"),
+	("GAMMALVL3", "Gamma correction level 3"),
+	("GAMMALVL4", "Gamma correction level 4"),
+	("EMPTYSTRING", "empty slot"),
+	("GOTARMOR", "Picked up the armor."),
+	("GOTMEGA", "Picked up the MegaArmor!"),
+	("GOTHTHBONUS", "Picked up a health bonus."),
+	("GOTARMBONUS", "Picked up an armor bonus."),
+	("GOTSTIM", "Picked up a stimpack."),
+	("GOTMEDINEED", "Picked up a medikit that you REALLY need!"),
+	("GOTMEDIKIT", "Picked up a medikit."),
+	("GOTSUPER", "Supercharge!"),
+	("GOTBLUECARD", "Picked up a blue keycard."),
+	("GOTYELWCARD", "Picked up a yellow keycard."),
+	("GOTREDCARD", "Picked up a red keycard."),
+	("GOTBLUESKUL", "Picked up a blue skull key."),
+	("GOTYELWSKUL", "Picked up a yellow skull key."),
+	("GOTREDSKULL", "Picked up a red skull key."),
+	("GOTINVUL", "Invulnerability!"),
+	("GOTBERSERK", "Berserk!"),
+	("GOTINVIS", "Partial Invisibility"),
+	("GOTSUIT", "Radiation Shielding Suit"),
+	("GOTMAP", "Computer Area Map"),
+	("GOTVISOR", "Light Amplification Visor"),
+	("GOTMSPHERE", "MegaSphere!"),
+	("GOTCLIP", "Picked up a clip."),
+	("GOTCLIPBOX", "Picked up a box of bullets."),
+	("GOTROCKET", "Picked up a rocket."),
+	("GOTROCKBOX", "Picked up a box of rockets."),
+	("GOTCELL", "Picked up an energy cell."),
+	("GOTCELLBOX", "Picked up an energy cell pack."),
+	("GOTSHELLS", "Picked up 4 shotgun shells."),
+	("GOTSHELLBOX", "Picked up a box of shotgun shells."),
+	("GOTBACKPACK", "Picked up a backpack full of ammo!"),
+	("GOTBFG9000", "You got the BFG9000!  Oh, yes."),
+	("GOTCHAINGUN", "You got the chaingun!"),
+	("GOTCHAINSAW", "A chainsaw!  Find some meat!"),
+	("GOTLAUNCHER", "You got the rocket launcher!"),
+	("GOTPLASMA", "You got the plasma gun!"),
+	("GOTSHOTGUN", "You got the shotgun!"),
+	("GOTSHOTGUN2", "You got the super shotgun!"),
+	("PD_BLUEO", "You need a blue key to activate this object"),
+	("PD_REDO", "You need a red key to activate this object"),
+	("PD_YELLOWO", "You need a yellow key to activate this object"),
+	("PD_BLUEK", "You need a blue key to open this door"),
+	("PD_REDK", "You need a red key to open this door"),
+	("PD_YELLOWK", "You need a yellow key to open this door"),
+	("GGSAVED", "game saved."),
+	("HUSTR_MSGU", "[Message unsent]"),
+	("HUSTR_E1M1", "E1M1: Hangar"),
+	("HUSTR_E1M2", "E1M2: Nuclear Plant"),
+	("HUSTR_E1M3", "E1M3: Toxin Refinery"),
+	("HUSTR_E1M4", "E1M4: Command Control"),
+	("HUSTR_E1M5", "E1M5: Phobos Lab"),
+	("HUSTR_E1M6", "E1M6: Central Processing"),
+	("HUSTR_E1M7", "E1M7: Computer Station"),
+	("HUSTR_E1M8", "E1M8: Phobos Anomaly"),
+	("HUSTR_E1M9", "E1M9: Military Base"),
+	("HUSTR_E2M1", "E2M1: Deimos Anomaly"),
+	("HUSTR_E2M2", "E2M2: Containment Area"),
+	("HUSTR_E2M3", "E2M3: Refinery"),
+	("HUSTR_E2M4", "E2M4: Deimos Lab"),
+	("HUSTR_E2M5", "E2M5: Command Center"),
+	("HUSTR_E2M6", "E2M6: Halls of the Damned"),
+	("HUSTR_E2M7", "E2M7: Spawning Vats"),
+	("HUSTR_E2M8", "E2M8: Tower of Babel"),
+	("HUSTR_E2M9", "E2M9: Fortress of Mystery"),
+	("HUSTR_E3M1", "E3M1: Hell Keep"),
+	("HUSTR_E3M2", "E3M2: Slough of Despair"),
+	("HUSTR_E3M3", "E3M3: Pandemonium"),
+	("HUSTR_E3M4", "E3M4: House of Pain"),
+	("HUSTR_E3M5", "E3M5: Unholy Cathedral"),
+	("HUSTR_E3M6", "E3M6: Mt. Erebus"),
+	("HUSTR_E3M7", "E3M7: Limbo"),
+	("HUSTR_E3M8", "E3M8: Dis"),
+	("HUSTR_E3M9", "E3M9: Warrens"),
+	("HUSTR_E4M1", "E4M1: Hell Beneath"),
+	("HUSTR_E4M2", "E4M2: Perfect Hatred"),
+	("HUSTR_E4M3", "E4M3: Sever The Wicked"),
+	("HUSTR_E4M4", "E4M4: Unruly Evil"),
+	("HUSTR_E4M5", "E4M5: They Will Repent"),
+	("HUSTR_E4M6", "E4M6: Against Thee Wickedly"),
+	("HUSTR_E4M7", "E4M7: And Hell Followed"),
+	("HUSTR_E4M8", "E4M8: Unto The Cruel"),
+	("HUSTR_E4M9", "E4M9: Fear"),
+	("HUSTR_1", "level 1: entryway"),
+	("HUSTR_2", "level 2: underhalls"),
+	("HUSTR_3", "level 3: the gantlet"),
+	("HUSTR_4", "level 4: the focus"),
+	("HUSTR_5", "level 5: the waste tunnels"),
+	("HUSTR_6", "level 6: the crusher"),
+	("HUSTR_7", "level 7: dead simple"),
+	("HUSTR_8", "level 8: tricks and traps"),
+	("HUSTR_9", "level 9: the pit"),
+	("HUSTR_10", "level 10: refueling base"),
+	("HUSTR_11", "level 11: 'o' of destruction!"),
+	("HUSTR_12", "level 12: the factory"),
+	("HUSTR_13", "level 13: downtown"),
+	("HUSTR_14", "level 14: the inmost dens"),
+	("HUSTR_15", "level 15: industrial zone"),
+	("HUSTR_16", "level 16: suburbs"),
+	("HUSTR_17", "level 17: tenements"),
+	("HUSTR_18", "level 18: the courtyard"),
+	("HUSTR_19", "level 19: the citadel"),
+	("HUSTR_20", "level 20: gotcha!"),
+	("HUSTR_21", "level 21: nirvana"),
+	("HUSTR_22", "level 22: the catacombs"),
+	("HUSTR_23", "level 23: barrels o' fun"),
+	("HUSTR_24", "level 24: the chasm"),
+	("HUSTR_25", "level 25: bloodfalls"),
+	("HUSTR_26", "level 26: the abandoned mines"),
+	("HUSTR_27", "level 27: monster condo"),
+	("HUSTR_28", "level 28: the spirit world"),
+	("HUSTR_29", "level 29: the living end"),
+	("HUSTR_30", "level 30: icon of sin"),
+	("HUSTR_31", "level 31: wolfenstein"),
+	("HUSTR_32", "level 32: grosse"),
+	("PHUSTR_1", "level 1: congo"),
+	("PHUSTR_2", "level 2: well of souls"),
+	("PHUSTR_3", "level 3: aztec"),
+	("PHUSTR_4", "level 4: caged"),
+	("PHUSTR_5", "level 5: ghost town"),
+	("PHUSTR_6", "level 6: baron's lair"),
+	("PHUSTR_7", "level 7: caughtyard"),
+	("PHUSTR_8", "level 8: realm"),
+	("PHUSTR_9", "level 9: abattoire"),
+	("PHUSTR_10", "level 10: onslaught"),
+	("PHUSTR_11", "level 11: hunted"),
+	("PHUSTR_12", "level 12: speed"),
+	("PHUSTR_13", "level 13: the crypt"),
+	("PHUSTR_14", "level 14: genesis"),
+	("PHUSTR_15", "level 15: the twilight"),
+	("PHUSTR_16", "level 16: the omen"),
+	("PHUSTR_17", "level 17: compound"),
+	("PHUSTR_18", "level 18: neurosphere"),
+	("PHUSTR_19", "level 19: nme"),
+	("PHUSTR_20", "level 20: the death domain"),
+	("PHUSTR_21", "level 21: slayer"),
+	("PHUSTR_22", "level 22: impossible mission"),
+	("PHUSTR_23", "level 23: tombstone"),
+	("PHUSTR_24", "level 24: the final frontier"),
+	("PHUSTR_25", "level 25: the temple of darkness"),
+	("PHUSTR_26", "level 26: bunker"),
+	("PHUSTR_27", "level 27: anti-christ"),
+	("PHUSTR_28", "level 28: the sewers"),
+	("PHUSTR_29", "level 29: odyssey of noises"),
+	("PHUSTR_30", "level 30: the gateway of hell"),
+	("PHUSTR_31", "level 31: cyberden"),
+	("PHUSTR_32", "level 32: go 2 it"),
+	("THUSTR_1", "level 1: system control"),
+	("THUSTR_2", "level 2: human bbq"),
+	("THUSTR_3", "level 3: power control"),
+	("THUSTR_4", "level 4: wormhole"),
+	("THUSTR_5", "level 5: hanger"),
+	("THUSTR_6", "level 6: open season"),
+	("THUSTR_7", "level 7: prison"),
+	("THUSTR_8", "level 8: metal"),
+	("THUSTR_9", "level 9: stronghold"),
+	("THUSTR_10", "level 10: redemption"),
+	("THUSTR_11", "level 11: storage facility"),
+	("THUSTR_12", "level 12: crater"),
+	("THUSTR_13", "level 13: nukage processing"),
+	("THUSTR_14", "level 14: steel works"),
+	("THUSTR_15", "level 15: dead zone"),
+	("THUSTR_16", "level 16: deepest reaches"),
+	("THUSTR_17", "level 17: processing area"),
+	("THUSTR_18", "level 18: mill"),
+	("THUSTR_19", "level 19: shipping/respawning"),
+	("THUSTR_20", "level 20: central processing"),
+	("THUSTR_21", "level 21: administration center"),
+	("THUSTR_22", "level 22: habitat"),
+	("THUSTR_23", "level 23: lunar mining project"),
+	("THUSTR_24", "level 24: quarry"),
+	("THUSTR_25", "level 25: baron's den"),
+	("THUSTR_26", "level 26: ballistyx"),
+	("THUSTR_27", "level 27: mount pain"),
+	("THUSTR_28", "level 28: heck"),
+	("THUSTR_29", "level 29: river styx"),
+	("THUSTR_30", "level 30: last call"),
+	("THUSTR_31", "level 31: pharaoh"),
+	("THUSTR_32", "level 32: caribbean"),
+	("HUSTR_CHATMACRO1", "I'm ready to kick butt!"),
+	("HUSTR_CHATMACRO2", "I'm OK."),
+	("HUSTR_CHATMACRO3", "I'm not looking too good!"),
+	("HUSTR_CHATMACRO4", "Help!"),
+	("HUSTR_CHATMACRO5", "You suck!"),
+	("HUSTR_CHATMACRO6", "Next time, scumbag..."),
+	("HUSTR_CHATMACRO7", "Come here!"),
+	("HUSTR_CHATMACRO8", "I'll take care of it."),
+	("HUSTR_CHATMACRO9", "Yes"),
+	("HUSTR_CHATMACRO0", "No"),
+	("HUSTR_TALKTOSELF1", "You mumble to yourself"),
+	("HUSTR_TALKTOSELF2", "Who's there?"),
+	("HUSTR_TALKTOSELF3", "You scare yourself"),
+	("HUSTR_TALKTOSELF4", "You start to rave"),
+	("HUSTR_TALKTOSELF5", "You've lost it..."),
+	("HUSTR_MESSAGESENT", "[Message Sent]"),
+	("HUSTR_PLRGREEN", "Green: "),
+	("HUSTR_PLRINDIGO", "Indigo: "),
+	("HUSTR_PLRBROWN", "Brown: "),
+	("HUSTR_PLRRED", "Red: "),
+	("AMSTR_FOLLOWON", "Follow Mode ON"),
+	("AMSTR_FOLLOWOFF", "Follow Mode OFF"),
+	("AMSTR_GRIDON", "Grid ON"),
+	("AMSTR_GRIDOFF", "Grid OFF"),
+	("AMSTR_MARKEDSPOT", "Marked Spot"),
+	("AMSTR_MARKSCLEARED", "All Marks Cleared"),
+	("STSTR_MUS", "Music Change"),
+	("STSTR_NOMUS", "IMPOSSIBLE SELECTION"),
+	("STSTR_DQDON", "Degreelessness Mode On"),
+	("STSTR_DQDOFF", "Degreelessness Mode Off"),
+	("STSTR_KFAADDED", "Very Happy Ammo Added"),
+	("STSTR_FAADDED", "Ammo (no keys) Added"),
+	("STSTR_NCON", "No Clipping Mode ON"),
+	("STSTR_NCOFF", "No Clipping Mode OFF"),
+	("STSTR_BEHOLD", "inVuln, Str, Inviso, Rad, Allmap, or Lite-amp"),
+	("STSTR_BEHOLDX", "Power-up Toggled"),
+	("STSTR_CHOPPERS", "... doesn't suck - GM"),
+	("STSTR_CLEV", "Changing Level..."),
+	("E1TEXT", "Once you beat the big badasses and\nclean out the moon base you're supposed\nto win, aren't you? Aren't you? Where's\nyour fat reward and ticket home? What\nthe hell is this? It's not supposed to\nend this way!\n\nIt stinks like rotten meat, but looks\nlike the lost Deimos base.  Looks like\nyou're stuck on The Shores of Hell.\nThe only way out is through.\n\nTo continue the DOOM experience, play\nThe Shores of Hell and its amazing\nsequel, Inferno!\n"),
+	("E2TEXT", "You've done it! The hideous cyber-\ndemon lord that ruled the lost Deimos\nmoon base has been slain and you\nare triumphant! But ... where are\nyou? You clamber to the edge of the\nmoon and look down to see the awful\ntruth.\n\nDeimos floats above Hell itself!\nYou've never heard of anyone escaping\nfrom Hell, but you'll make the bastards\nsorry they ever heard of you! Quickly,\nyou rappel down to  the surface of\nHell.\n\nNow, it's on to the final chapter of\nDOOM! -- Inferno."),
+	("E3TEXT", "The loathsome spiderdemon that\nmasterminded the invasion of the moon\nbases and caused so much death has had\nits ass kicked for all time.\n\nA hidden doorway opens and you enter.\nYou've proven too tough for Hell to\ncontain, and now Hell at last plays\nfair -- for you emerge from the door\nto see the green fields of Earth!\nHome at last.\n\nYou wonder what's been happening on\nEarth while you were battling evil\nunleashed. It's good that no Hell-\nspawn could have come through that\ndoor with you ..."),
+	("E4TEXT", "the spider mastermind must have sent forth\nits legions of hellspawn before your\nfinal confrontation with that terrible\nbeast from hell.  but you stepped forward\nand brought forth eternal damnation and\nsuffering upon the horde as a true hero\nwould in the face of something so evil.\n\nbesides, someone was gonna pay for what\nhappened to daisy, your pet rabbit.\n\nbut now, you see spread before you more\npotential pain and gibbitude as a nation\nof demons run amok among our cities.\n\nnext stop, hell on earth!"),
+	("C1TEXT", "YOU HAVE ENTERED DEEPLY INTO THE INFESTED\nSTARPORT. BUT SOMETHING IS WRONG. THE\nMONSTERS HAVE BROUGHT THEIR OWN REALITY\nWITH THEM, AND THE STARPORT'S TECHNOLOGY\nIS BEING SUBVERTED BY THEIR PRESENCE.\n\nAHEAD, YOU SEE AN OUTPOST OF HELL, A\nFORTIFIED ZONE. IF YOU CAN GET PAST IT,\nYOU CAN PENETRATE INTO THE HAUNTED HEART\nOF THE STARBASE AND FIND THE CONTROLLING\nSWITCH WHICH HOLDS EARTH'S POPULATION\nHOSTAGE."),
+	("C2TEXT", "YOU HAVE WON! YOUR VICTORY HAS ENABLED\nHUMANKIND TO EVACUATE EARTH AND ESCAPE\nTHE NIGHTMARE.  NOW YOU ARE THE ONLY\nHUMAN LEFT ON THE FACE OF THE PLANET.\nCANNIBAL MUTATIONS, CARNIVOROUS ALIENS,\nAND EVIL SPIRITS ARE YOUR ONLY NEIGHBORS.\nYOU SIT BACK AND WAIT FOR DEATH, CONTENT\nTHAT YOU HAVE SAVED YOUR SPECIES.\n\nBUT THEN, EARTH CONTROL BEAMS DOWN A\nMESSAGE FROM SPACE: \"SENSORS HAVE LOCATED\nTHE SOURCE OF THE ALIEN INVASION. IF YOU\nGO THERE, YOU MAY BE ABLE TO BLOCK THEIR\nENTRY.  THE ALIEN BASE IS IN THE HEART OF\nYOUR OWN HOME CITY, NOT FAR FROM THE\nSTARPORT.\" SLOWLY AND PAINFULLY YOU GET\nUP AND RETURN TO THE FRAY."),
+	("C3TEXT", "YOU ARE AT THE CORRUPT HEART OF THE CITY,\nSURROUNDED BY THE CORPSES OF YOUR ENEMIES.\nYOU SEE NO WAY TO DESTROY THE CREATURES'\nENTRYWAY ON THIS SIDE, SO YOU CLENCH YOUR\nTEETH AND PLUNGE THROUGH IT.\n\nTHERE MUST BE A WAY TO CLOSE IT ON THE\nOTHER SIDE. WHAT DO YOU CARE IF YOU'VE\nGOT TO GO THROUGH HELL TO GET TO IT?"),
+	("C4TEXT", "THE HORRENDOUS VISAGE OF THE BIGGEST\nDEMON YOU'VE EVER SEEN CRUMBLES BEFORE\nYOU, AFTER YOU PUMP YOUR ROCKETS INTO\nHIS EXPOSED BRAIN. THE MONSTER SHRIVELS\nUP AND DIES, ITS THRASHING LIMBS\nDEVASTATING UNTOLD MILES OF HELL'S\nSURFACE.\n\nYOU'VE DONE IT. THE INVASION IS OVER.\nEARTH IS SAVED. HELL IS A WRECK. YOU\nWONDER WHERE BAD FOLKS WILL GO WHEN THEY\nDIE, NOW. WIPING THE SWEAT FROM YOUR\nFOREHEAD YOU BEGIN THE LONG TREK BACK\nHOME. REBUILDING EARTH OUGHT TO BE A\nLOT MORE FUN THAN RUINING IT WAS.\n"),
+	("C5TEXT", "CONGRATULATIONS, YOU'VE FOUND THE SECRET\nLEVEL! LOOKS LIKE IT'S BEEN BUILT BY\nHUMANS, RATHER THAN DEMONS. YOU WONDER\nWHO THE INMATES OF THIS CORNER OF HELL\nWILL BE."),
+	("C6TEXT", "CONGRATULATIONS, YOU'VE FOUND THE\nSUPER SECRET LEVEL!  YOU'D BETTER\nBLAZE THROUGH THIS ONE!\n"),
+	("P1TEXT", "You gloat over the steaming carcass of the\nGuardian.  With its death, you've wrested\nthe Accelerator from the stinking claws\nof Hell.  You relax and glance around the\nroom.  Damn!  There was supposed to be at\nleast one working prototype, but you can't\nsee it. The demons must have taken it.\n\nYou must find the prototype, or all your\nstruggles will have been wasted. Keep\nmoving, keep fighting, keep killing.\nOh yes, keep living, too."),
+	("P2TEXT", "Even the deadly Arch-Vile labyrinth could\nnot stop you, and you've gotten to the\nprototype Accelerator which is soon\nefficiently and permanently deactivated.\n\nYou're good at that kind of thing."),
+	("P3TEXT", "You've bashed and battered your way into\nthe heart of the devil-hive.  Time for a\nSearch-and-Destroy mission, aimed at the\nGatekeeper, whose foul offspring is\ncascading to Earth.  Yeah, he's bad. But\nyou know who's worse!\n\nGrinning evilly, you check your gear, and\nget ready to give the bastard a little Hell\nof your own making!"),
+	("P4TEXT", "The Gatekeeper's evil face is splattered\nall over the place.  As its tattered corpse\ncollapses, an inverted Gate forms and\nsucks down the shards of the last\nprototype Accelerator, not to mention the\nfew remaining demons.  You're done. Hell\nhas gone back to pounding bad dead folks \ninstead of good live ones.  Remember to\ntell your grandkids to put a rocket\nlauncher in your coffin. If you go to Hell\nwhen you die, you'll need it for some\nfinal cleaning-up ..."),
+	("P5TEXT", "You've found the second-hardest level we\ngot. Hope you have a saved game a level or\ntwo previous.  If not, be prepared to die\naplenty. For master marines only."),
+	("P6TEXT", "Betcha wondered just what WAS the hardest\nlevel we had ready for ya?  Now you know.\nNo one gets out alive."),
+	("T1TEXT", "You've fought your way out of the infested\nexperimental labs.   It seems that UAC has\nonce again gulped it down.  With their\nhigh turnover, it must be hard for poor\nold UAC to buy corporate health insurance\nnowadays..\n\nAhead lies the military complex, now\nswarming with diseased horrors hot to get\ntheir teeth into you. With luck, the\ncomplex still has some warlike ordnance\nlaying around."),
+	("T2TEXT", "You hear the grinding of heavy machinery\nahead.  You sure hope they're not stamping\nout new hellspawn, but you're ready to\nream out a whole herd if you have to.\nThey might be planning a blood feast, but\nyou feel about as mean as two thousand\nmaniacs packed into one mad killer.\n\nYou don't plan to go down easy."),
+	("T3TEXT", "The vista opening ahead looks real damn\nfamiliar. Smells familiar, too -- like\nfried excrement. You didn't like this\nplace before, and you sure as hell ain't\nplanning to like it now. The more you\nbrood on it, the madder you get.\nHefting your gun, an evil grin trickles\nonto your face. Time to take some names."),
+	("T4TEXT", "Suddenly, all is silent, from one horizon\nto the other. The agonizing echo of Hell\nfades away, the nightmare sky turns to\nblue, the heaps of monster corpses start \nto evaporate along with the evil stench \nthat filled the air. Jeeze, maybe you've\ndone it. Have you really won?\n\nSomething rumbles in the distance.\nA blue light begins to glow inside the\nruined skull of the demon-spitter."),
+	("T5TEXT", "What now? Looks totally different. Kind\nof like King Tut's condo. Well,\nwhatever's here can't be any worse\nthan usual. Can it?  Or maybe it's best\nto let sleeping gods lie.."),
+	("T6TEXT", "Time for a vacation. You've burst the\nbowels of hell and by golly you're ready\nfor a break. You've muttered to yourself,\nMaybe someone else can kick Hell's ass\nnext time around. Ahead lies a quiet town,\nwith peaceful flowing water, quaint\nbuildings, and presumably no Hellspawn.\n\nAs you step off the transport, you hear\nthe stomp of a cyberdemon's iron shoe."),
+	("CC_ZOMBIE", "ZOMBIEMAN"),
+	("CC_SHOTGUN", "SHOTGUN GUY"),
+	("CC_HEAVY", "HEAVY WEAPON DUDE"),
+	("CC_IMP", "IMP"),
+	("CC_DEMON", "DEMON"),
+	("CC_LOST", "LOST SOUL"),
+	("CC_CACO", "CACODEMON"),
+	("CC_HELL", "HELL KNIGHT"),
+	("CC_BARON", "BARON OF HELL"),
+	("CC_ARACH", "ARACHNOTRON"),
+	("CC_PAIN", "PAIN ELEMENTAL"),
+	("CC_REVEN", "REVENANT"),
+	("CC_MANCU", "MANCUBUS"),
+	("CC_ARCH", "ARCH-VILE"),
+	("CC_SPIDER", "THE SPIDER MASTERMIND"),
+	("CC_CYBER", "THE CYBERDEMON"),
+	("CC_HERO", "OUR HERO"),
+	("BGFLATE1", "FLOOR4_8"),
+	("BGFLATE2", "SFLR6_1"),
+	("BGFLATE3", "MFLR8_4"),
+	("BGFLATE4", "MFLR8_3"),
+	("BGFLAT06", "SLIME16"),
+	("BGFLAT11", "RROCK14"),
+	("BGFLAT20", "RROCK07"),
+	("BGFLAT30", "RROCK17"),
+	("BGFLAT15", "RROCK13"),
+	("BGFLAT31", "RROCK19"),
+	("BGCASTCALL", "BOSSBACK"),
+];
@@ -0,0 +1,26 @@
+//! Parser and syntax trees for [GLDEFS](https://zdoom.org/wiki/GLDEFS),
+//! the lump which GZDoom uses to define dynamic lights, brightmaps, glowing
+//! flats, skyboxes, and custom shaders.
+//!
+//! Light definitions (`pointlight`, `pulselight`, `flickerlight`,
+//! `flickerlight2`, and `sectorlight`) are bound to actors' sprite frames by
+//! `object` blocks. [`check_objects`] confirms that those blocks name actor
+//! classes and frames which exist, using the classes gathered into [`Actors`]
+//! from ZScript (or from any other source, such as DECORATE).
+
+pub mod ast;
+mod check;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use check::*;
+pub use parse::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,481 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Brightmap(BrightmapDef),
+	Glow(GlowDef),
+	Include(IncludeDirective),
+	Light(LightDef),
+	Object(ObjectDef),
+	Shader(ShaderDef),
+	Skybox(SkyboxDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::BrightmapDef
+				| Syn::GlowDef
+				| Syn::IncludeDirective
+				| Syn::LightDef
+				| Syn::ObjectDef
+				| Syn::ShaderDef
+				| Syn::SkyboxDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::BrightmapDef => Some(Self::Brightmap(BrightmapDef(node))),
+			Syn::GlowDef => Some(Self::Glow(GlowDef(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::LightDef => Some(Self::Light(LightDef(node))),
+			Syn::ObjectDef => Some(Self::Object(ObjectDef(node))),
+			Syn::ShaderDef => Some(Self::Shader(ShaderDef(node))),
+			Syn::SkyboxDef => Some(Self::Skybox(SkyboxDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Brightmap(inner) => &inner.0,
+			Self::Glow(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::Light(inner) => &inner.0,
+			Self::Object(inner) => &inner.0,
+			Self::Shader(inner) => &inner.0,
+			Self::Skybox(inner) => &inner.0,
+		}
+	}
+}
+
+/// The first token of `node` tagged `syn`, looking only at direct children.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// The first token of `node` tagged [`Syn::Ident`] or [`Syn::LitString`],
+/// looking only at direct children; i.e. a texture or sprite name, which may
+/// be quoted.
+fn name(node: &SyntaxNode) -> Option<Value> {
+	node.children_with_tokens().find_map(|n_or_t| {
+		n_or_t
+			.into_token()
+			.filter(|t| matches!(t.kind(), Syn::Ident | Syn::LitString))
+			.and_then(Value::new)
+	})
+}
+
+fn properties(node: &SyntaxNode) -> impl Iterator<Item = Property> {
+	node.children().filter_map(Property::cast)
+}
+
+/// Finds a property by case-insensitive key. If the key is given more than
+/// once, the last occurrence is returned, since it takes precedence.
+fn property(node: &SyntaxNode, key: &str) -> Option<Property> {
+	properties(node)
+		.filter(|prop| prop.key().text().eq_ignore_ascii_case(key))
+		.last()
+}
+
+/// The first token of `node` tagged [`Syn::KwTexture`], [`Syn::KwSprite`],
+/// or [`Syn::KwFlat`], looking only at direct children.
+fn texture_kind(node: &SyntaxNode) -> Option<SyntaxToken> {
+	node.children_with_tokens().find_map(|n_or_t| {
+		n_or_t
+			.into_token()
+			.filter(|t| matches!(t.kind(), Syn::KwTexture | Syn::KwSprite | Syn::KwFlat))
+	})
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::LightDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LightDef(SyntaxNode);
+
+simple_astnode!(Syn, LightDef, Syn::LightDef);
+
+impl LightDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwPointLight`]
+	/// - [`Syn::KwPulseLight`]
+	/// - [`Syn::KwFlickerLight`]
+	/// - [`Syn::KwFlickerLight2`]
+	/// - [`Syn::KwSectorLight`]
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// e.g. `color`, `size`, `offset`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Keys are compared case-insensitively.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		property(&self.0, key)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ObjectDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ObjectDef(SyntaxNode);
+
+simple_astnode!(Syn, ObjectDef, Syn::ObjectDef);
+
+impl ObjectDef {
+	/// The name of the actor class whose frames get bound to lights.
+	#[must_use]
+	pub fn class_name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	pub fn frames(&self) -> impl Iterator<Item = FrameDef> {
+		self.0.children().filter_map(FrameDef::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::FrameDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FrameDef(SyntaxNode);
+
+simple_astnode!(Syn, FrameDef, Syn::FrameDef);
+
+impl FrameDef {
+	/// A sprite name, optionally followed by a frame letter; e.g. `POSS`
+	/// binds every frame of sprite `POSS`, while `POSSF` binds only frame `F`.
+	/// Characters after the frame letter are ignored.
+	#[must_use]
+	pub fn name(&self) -> Value {
+		name(&self.0).unwrap()
+	}
+
+	/// The names of the lights given by every `light` property.
+	pub fn lights(&self) -> impl Iterator<Item = Value> {
+		properties(&self.0)
+			.filter(|prop| prop.key().text().eq_ignore_ascii_case("light"))
+			.filter_map(|prop| prop.value())
+	}
+
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::BrightmapDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BrightmapDef(SyntaxNode);
+
+simple_astnode!(Syn, BrightmapDef, Syn::BrightmapDef);
+
+impl BrightmapDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwTexture`]
+	/// - [`Syn::KwSprite`]
+	/// - [`Syn::KwFlat`]
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		texture_kind(&self.0).unwrap()
+	}
+
+	/// The texture, sprite, or flat to which the brightmap applies.
+	#[must_use]
+	pub fn name(&self) -> Value {
+		name(&self.0).unwrap()
+	}
+
+	/// e.g. `map`, `iwad`, `thiswad`, `disablefullbright`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Keys are compared case-insensitively.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		property(&self.0, key)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ShaderDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ShaderDef(SyntaxNode);
+
+simple_astnode!(Syn, ShaderDef, Syn::ShaderDef);
+
+impl ShaderDef {
+	/// Either [`Syn::KwHardwareShader`] or [`Syn::KwMaterial`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Only optional for `hardwareshader`, which defaults to textures.
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwTexture`]
+	/// - [`Syn::KwSprite`]
+	/// - [`Syn::KwFlat`]
+	#[must_use]
+	pub fn kind(&self) -> Option<SyntaxToken> {
+		texture_kind(&self.0)
+	}
+
+	#[must_use]
+	pub fn name(&self) -> Value {
+		name(&self.0).unwrap()
+	}
+
+	/// e.g. `shader`, `speed`, `texture`, `define`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Keys are compared case-insensitively.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		property(&self.0, key)
+	}
+}
+
+/// Wraps a node tagged [`Syn::GlowDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GlowDef(SyntaxNode);
+
+simple_astnode!(Syn, GlowDef, Syn::GlowDef);
+
+impl GlowDef {
+	/// e.g. `flats { ... }` and `walls { ... }`.
+	pub fn lists(&self) -> impl Iterator<Item = GlowList> {
+		self.0.children().filter_map(GlowList::cast)
+	}
+
+	/// Only `texture` is expected, as in `texture "FWATER1", 303030, 128`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::GlowList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GlowList(SyntaxNode);
+
+simple_astnode!(Syn, GlowList, Syn::GlowList);
+
+impl GlowList {
+	/// e.g. `flats`, `walls`, `subflats`, or `subwalls`.
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn textures(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+			.skip(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::SkyboxDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SkyboxDef(SyntaxNode);
+
+simple_astnode!(Syn, SkyboxDef, Syn::SkyboxDef);
+
+impl SkyboxDef {
+	#[must_use]
+	pub fn name(&self) -> Value {
+		name(&self.0).unwrap()
+	}
+
+	#[must_use]
+	pub fn is_fliptop(&self) -> bool {
+		self.header()
+			.skip(1)
+			.any(|t| t.text().eq_ignore_ascii_case("fliptop"))
+	}
+
+	/// Either three textures (the top, bottom, and the sides all at once),
+	/// or six (north, east, south, west, top, bottom).
+	pub fn textures(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.skip_while(|t| t.kind() != Syn::BraceL)
+			.filter_map(Value::new)
+	}
+
+	/// Every name before the opening brace.
+	fn header(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.take_while(|t| t.kind() != Syn::BraceL)
+			.filter(|t| matches!(t.kind(), Syn::Ident | Syn::LitString))
+	}
+}
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	#[must_use]
+	pub fn path_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The path without its quotation marks.
+	#[must_use]
+	pub fn path(&self) -> String {
+		Value(self.path_token()).string().unwrap_or_default()
+	}
+}
+
+// Properties //////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Property(SyntaxNode);
+
+simple_astnode!(Syn, Property, Syn::Property);
+
+impl Property {
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Every value following the key, in order, skipping `=` and commas.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+
+	/// Shorthand for `self.values().next()`.
+	#[must_use]
+	pub fn value(&self) -> Option<Value> {
+		self.values().next()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a value, with convenience functions
+/// for interpreting it. Its tag is one of [`Syn::LitString`], [`Syn::LitInt`],
+/// [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// Also accepts integer literals, since GLDEFS does wherever it expects
+	/// a floating-point number.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		matches!(self.0.kind(), Syn::LitFloat | Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as a light or texture name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
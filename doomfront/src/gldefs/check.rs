@@ -0,0 +1,214 @@
+//! Checking that `object` blocks bind lights to actors and frames that exist.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{zscript, Issue};
+
+use super::{ast, ParseTree};
+
+/// Every actor class known to a checker, along with the sprite frames used by
+/// each one's states. All names are stored uppercase, since source ports
+/// compare them case-insensitively.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Actors {
+	classes: HashMap<String, ActorInfo>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ActorInfo {
+	parent: Option<String>,
+	/// Each a sprite name followed by one frame letter, e.g. `POSSA`.
+	frames: HashSet<String>,
+}
+
+impl Actors {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Declares a class (e.g. one read out of DECORATE) with the given parent,
+	/// if any, and the frames its states use. Each of `states` is a sprite name
+	/// and the frame letters following it, as in `POSS AB`. Declaring a class
+	/// more than once merges the frames of each declaration.
+	pub fn insert<'s>(
+		&mut self,
+		class: &str,
+		parent: Option<&str>,
+		states: impl IntoIterator<Item = (&'s str, &'s str)>,
+	) {
+		let info = self.classes.entry(class.to_ascii_uppercase()).or_default();
+
+		if let Some(parent) = parent {
+			info.parent = Some(parent.to_ascii_uppercase());
+		}
+
+		for (sprite, letters) in states {
+			let sprite = sprite.trim_matches('"').to_ascii_uppercase();
+
+			// `####` and `----` keep whatever sprite the previous state had.
+			if sprite == "####" || sprite == "----" {
+				continue;
+			}
+
+			for letter in letters.trim_matches('"').chars() {
+				info.frames
+					.insert(format!("{sprite}{}", letter.to_ascii_uppercase()));
+			}
+		}
+	}
+
+	/// Declares every class in `tree`, including states added by `extend class`.
+	pub fn add_zscript(&mut self, tree: &zscript::ParseTree) {
+		for root in tree.ast() {
+			let (name, parent, innards): (_, _, Vec<_>) = match root {
+				zscript::ast::Root::Class(classdef) => (
+					classdef.name(),
+					classdef.parent(),
+					classdef.innards().collect(),
+				),
+				zscript::ast::Root::ClassExtend(extend) => {
+					(extend.name(), None, extend.innards().collect())
+				}
+				_ => continue,
+			};
+
+			let states: Vec<_> = innards
+				.into_iter()
+				.filter_map(|innard| match innard {
+					zscript::ast::ClassInnard::States(states) => Some(states),
+					_ => None,
+				})
+				.flat_map(|states| states.items())
+				.filter_map(|item| match item {
+					zscript::ast::StatesItem::State(state) => Some(state),
+					_ => None,
+				})
+				.map(|state| (state.sprite(), state.frames()))
+				.collect();
+
+			self.insert(
+				name.text(),
+				parent.as_ref().map(|p| p.text()),
+				states.iter().map(|(s, f)| (s.text(), f.text())),
+			);
+		}
+	}
+
+	/// Compared case-insensitively.
+	#[must_use]
+	pub fn contains(&self, class: &str) -> bool {
+		self.classes.contains_key(&class.to_ascii_uppercase())
+	}
+
+	/// Returns `None` if `class` or any of its ancestors are unknown, since the
+	/// frame may then come from an ancestor defined elsewhere. `Actor` itself
+	/// need not be declared, since it has no states worth binding lights to.
+	///
+	/// Only the first of `letters` names a frame, as in GZDoom; anything
+	/// after it (e.g. the rotation in `POSSA1`) is ignored.
+	fn has_frame(&self, class: &str, sprite: &str, letters: &str) -> Option<bool> {
+		let mut current = class.to_ascii_uppercase();
+		let mut visited = HashSet::new();
+
+		loop {
+			if current == "ACTOR" && !self.classes.contains_key(&current) {
+				return Some(false);
+			}
+
+			let info = self.classes.get(&current)?;
+
+			let found = match letters.chars().next() {
+				None => info.frames.iter().any(|f| f.starts_with(sprite)),
+				Some(l) => info.frames.contains(&format!("{sprite}{l}")),
+			};
+
+			if found {
+				return Some(true);
+			}
+
+			match &info.parent {
+				Some(parent) if visited.insert(current.clone()) => current = parent.clone(),
+				Some(_) => return None,
+				None => return Some(false),
+			}
+		}
+	}
+}
+
+/// Checks every `object` block in `tree` against `actors`, raising a warning
+/// for each that names an unknown class, and for each `frame` whose sprite
+/// (and frame letter, if given) the class never uses. Frames of a class whose
+/// ancestry is only partly known are not checked.
+///
+/// Also warns about `light` properties naming lights not defined in `tree`,
+/// unless `tree` has `#include` directives, since those may define them.
+#[must_use]
+pub fn check_objects(tree: &ParseTree, actors: &Actors) -> Vec<Issue> {
+	let mut issues = vec![];
+
+	let has_includes = tree.ast().any(|root| matches!(root, ast::Root::Include(_)));
+
+	let lights: HashSet<_> = tree
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Light(light) => Some(light.name().text().to_ascii_uppercase()),
+			_ => None,
+		})
+		.collect();
+
+	for root in tree.ast() {
+		let ast::Root::Object(object) = root else {
+			continue;
+		};
+
+		let class = object.class_name();
+
+		if !actors.contains(class.text()) {
+			issues.push(Issue::warning(
+				class.text_range(),
+				format!("`{}` is not a known actor class", class.text()),
+			));
+		}
+
+		for frame in object.frames() {
+			let name = frame.name();
+			let text = name.text().to_ascii_uppercase();
+
+			if text.len() < 4 {
+				issues.push(Issue::error(
+					name.syntax().text_range(),
+					format!("`{}` is too short to be a sprite name", name.text()),
+				));
+			} else if actors.contains(class.text()) {
+				let (sprite, letters) = text.split_at(4);
+
+				if actors.has_frame(class.text(), sprite, letters) == Some(false) {
+					issues.push(Issue::warning(
+						name.syntax().text_range(),
+						format!(
+							"`{}` has no state using frame `{}`",
+							class.text(),
+							name.text()
+						),
+					));
+				}
+			}
+
+			if has_includes {
+				continue;
+			}
+
+			for light in frame.lights() {
+				if !lights.contains(&light.text().to_ascii_uppercase()) {
+					issues.push(Issue::warning(
+						light.syntax().text_range(),
+						format!("light `{}` is not defined", light.text()),
+					));
+				}
+			}
+		}
+	}
+
+	issues
+}
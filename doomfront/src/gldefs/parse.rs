@@ -0,0 +1,327 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any), so that one malformed definition does not hide the
+/// ones after it. All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		light_def(src),
+		object_def(src),
+		brightmap_def(src),
+		shader_def(src),
+		glow_def(src),
+		skybox_def(src),
+		include_directive(src),
+	))
+}
+
+fn light_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::kw::<Syn>("pointlight", Syn::KwPointLight, src),
+		comb::kw::<Syn>("pulselight", Syn::KwPulseLight, src),
+		comb::kw::<Syn>("flickerlight2", Syn::KwFlickerLight2, src),
+		comb::kw::<Syn>("flickerlight", Syn::KwFlickerLight, src),
+		comb::kw::<Syn>("sectorlight", Syn::KwSectorLight, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(name(src))
+	.chain_append(block(src, property(src)))
+	.collect_n::<Syn, { Syn::LightDef as u16 }>()
+	.labelled("light definition")
+}
+
+fn object_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let frame_def = comb::kw::<Syn>("frame", Syn::KwFrame, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(texture_name(src))
+		.chain_append(block(src, property(src)))
+		.collect_n::<Syn, { Syn::FrameDef as u16 }>()
+		.labelled("frame binding");
+
+	comb::kw::<Syn>("object", Syn::KwObject, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(name(src))
+		.chain_append(block(src, primitive::choice((frame_def, property(src)))))
+		.collect_n::<Syn, { Syn::ObjectDef as u16 }>()
+		.labelled("object definition")
+}
+
+fn brightmap_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("brightmap", Syn::KwBrightmap, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(texture_kind(src))
+		.chain_append(trivia(src))
+		.chain_push(texture_name(src))
+		.chain_append(block(src, property(src)))
+		.collect_n::<Syn, { Syn::BrightmapDef as u16 }>()
+		.labelled("brightmap definition")
+}
+
+fn shader_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let with_kind = texture_kind(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(texture_name(src));
+
+	primitive::choice((
+		comb::kw::<Syn>("hardwareshader", Syn::KwHardwareShader, src),
+		comb::kw::<Syn>("material", Syn::KwMaterial, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_append(primitive::choice((
+		with_kind,
+		texture_name(src).start_vec(),
+	)))
+	.chain_append(block(src, property(src)))
+	.collect_n::<Syn, { Syn::ShaderDef as u16 }>()
+	.labelled("shader definition")
+}
+
+fn glow_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let glow_list = ident(src)
+		.start_vec()
+		.chain_append(name_block(src))
+		.collect_n::<Syn, { Syn::GlowList as u16 }>();
+
+	comb::kw::<Syn>("glow", Syn::KwGlow, src)
+		.start_vec()
+		.chain_append(block(src, primitive::choice((glow_list, property(src)))))
+		.collect_n::<Syn, { Syn::GlowDef as u16 }>()
+		.labelled("glow definition")
+}
+
+fn skybox_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("skybox", Syn::KwSkybox, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(texture_name(src))
+		.chain_append(trivia(src).chain_push(name(src)).repeated().flatten())
+		.chain_append(name_block(src))
+		.collect_n::<Syn, { Syn::SkyboxDef as u16 }>()
+		.labelled("skybox definition")
+}
+
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('#')
+		.then(comb::just_nc("include"))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwInclude))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+		.labelled("include directive")
+}
+
+/// Trivia, then a brace-delimited list of `item`s and trivia.
+fn block<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	trivia(src)
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), item)).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src))
+}
+
+/// Trivia, then a brace-delimited list of names or strings.
+fn name_block(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	block(src, texture_name(src))
+}
+
+/// Keys which are followed by a name (or a string) rather than by numbers:
+/// `map` in brightmaps, `light` in frame bindings, and `texture` and `define`
+/// in shaders and glows.
+const NAMED_KEYS: &[&str] = &["define", "light", "map", "texture"];
+
+fn property(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let value = primitive::choice((
+		float_lit(src),
+		int_lit(src),
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		comb::just::<Syn, _>('=', Syn::Eq, src),
+	));
+
+	// After a comma, a value may also be a bare word, as in
+	// `texture "FWATER1", 303030, 128, fullbright`.
+	let after_comma = comb::just::<Syn, _>(',', Syn::Comma, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((value.clone(), name(src))));
+
+	let key = text::ident::<char, ParseError>().try_map(|key: String, span| {
+		if key.eq_ignore_ascii_case("frame") {
+			Err(ParseError::custom(span, "`frame` is not a property"))
+		} else {
+			Ok(key)
+		}
+	});
+
+	let named_key = key
+		.try_map(|key, span| {
+			if NAMED_KEYS.iter().any(|k| k.eq_ignore_ascii_case(&key)) {
+				Ok(key)
+			} else {
+				Err(ParseError::custom(span, "expected a key taking a name"))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	let key = key.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	let named = named_key
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((
+			comb::string_lit::<Syn>(src, Syn::LitString),
+			name(src),
+		)));
+
+	primitive::choice((named, key.start_vec()))
+		.chain_append(
+			trivia(src)
+				.then(primitive::choice((value.start_vec(), after_comma)))
+				.map(|(mut trivia, values)| {
+					trivia.extend(values);
+					trivia
+				})
+				.repeated()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::Property as u16 }>()
+		.labelled("property")
+}
+
+fn texture_kind(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::kw::<Syn>("texture", Syn::KwTexture, src),
+		comb::kw::<Syn>("sprite", Syn::KwSprite, src),
+		comb::kw::<Syn>("flat", Syn::KwFlat, src),
+	))
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Unlike identifiers, the names of lights, frames, and textures may start
+/// with a digit and contain some of the punctuation allowed in lump names.
+fn name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || "_-.[]\\^/!".contains(*c))
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("name")
+}
+
+/// Texture and sprite names may be quoted, as full paths must be.
+fn texture_name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((comb::string_lit::<Syn>(src, Syn::LitString), name(src)))
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('-')
+		.or_not()
+		.then(text::digits(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+/// Either side of the decimal point may be empty, but not both.
+fn float_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let leading = text::digits::<char, ParseError>(10)
+		.then(primitive::just('.'))
+		.then(text::digits(10).or_not())
+		.ignored();
+
+	let trailing = primitive::just('.').then(text::digits(10)).ignored();
+
+	primitive::just('-')
+		.or_not()
+		.then(primitive::choice((leading, trailing)))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
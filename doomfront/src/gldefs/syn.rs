@@ -0,0 +1,116 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// GLDEFS syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `brightmap <texture|sprite|flat> NAME { ... }`.
+	BrightmapDef,
+	/// `frame NAME { ... }`, within an `object` block.
+	FrameDef,
+	/// `glow { ... }`.
+	GlowDef,
+	/// e.g. `flats { NUKAGE1 NUKAGE2 }`, within a `glow` block.
+	GlowList,
+	/// `#include "path"`.
+	IncludeDirective,
+	/// `pointlight NAME { ... }`, or any of the other kinds of light.
+	LightDef,
+	/// `object CLASS { ... }`.
+	ObjectDef,
+	/// A key followed by zero or more values, e.g. `color 1.0 0.5 0.0`.
+	Property,
+	/// `hardwareshader [texture|sprite|flat] NAME { ... }`
+	/// or `material <texture|sprite|flat> NAME { ... }`.
+	ShaderDef,
+	/// `skybox NAME [fliptop] { ... }`.
+	SkyboxDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwBrightmap,
+	/// The keyword `flat`, in a `brightmap`, `hardwareshader`,
+	/// or `material` header.
+	KwFlat,
+	KwFlickerLight,
+	KwFlickerLight2,
+	KwFrame,
+	KwGlow,
+	KwHardwareShader,
+	/// `#include`.
+	KwInclude,
+	KwMaterial,
+	KwObject,
+	KwPointLight,
+	KwPulseLight,
+	KwSectorLight,
+	KwSkybox,
+	/// The keyword `sprite`, in a `brightmap`, `hardwareshader`,
+	/// or `material` header.
+	KwSprite,
+	/// The keyword `texture`, in a `brightmap`, `hardwareshader`,
+	/// or `material` header. Elsewhere, `texture` is a property key.
+	KwTexture,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `,`
+	Comma,
+	/// `=`
+	Eq,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// GLDEFS comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Property keys, and the names of lights, actors, frames, and textures.
+	/// May start with a digit, and may contain some punctuation.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by GLDEFS.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
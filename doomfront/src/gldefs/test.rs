@@ -0,0 +1,318 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Lights for the zombieman.
+pointlight ZOMBIEATK
+{
+	color 1.0 0.8 0.2
+	size 48
+	offset 0 40 0
+	attenuate 1
+}
+
+pulselight BARREL_GLOW { color 0.2 1.0 0.2 size 24 secondarysize 32 interval 2.0 }
+flickerlight2 TORCH { color 1.0 0.5 0.0 size 40 secondarysize 44 interval 0.1 }
+FlickerLight SPARK { color 1 1 1 size 8 secondarysize 12 chance 0.3 }
+sectorlight LAVA { color 1.0 0.2 0.0 scale 0.5 }
+
+object ZombieMan
+{
+	frame POSSF { light ZOMBIEATK }
+	frame POSS { light "BARREL_GLOW" }
+}
+
+brightmap sprite POSSF1
+{
+	map "brightmaps/possf1.png"
+	iwad
+	disablefullbright
+}
+
+brightmap texture BIGDOOR1 { map bm_door thiswad }
+
+hardwareshader texture WATER1
+{
+	shader "shaders/water.fp"
+	speed 1.5
+	texture tex_noise "textures/noise.png"
+	define USE_FOAM = 1
+}
+
+material flat FLOOR4_8
+{
+	normal "normals/floor4_8.png"
+	specularlevel 1.0
+	glossiness 10.0
+	brightmap "brightmaps/floor4_8.png"
+}
+
+glow
+{
+	flats { NUKAGE1 NUKAGE2 FWATER1 }
+	walls { "LITE3" }
+	texture "LAVA1", ff4000, 128, fullbright
+}
+
+skybox SKY_SPACE fliptop
+{
+	space_n space_e space_s
+	space_w space_t space_b
+}
+
+#include "gldefs/extra.txt"
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 13);
+
+	let ast::Root::Light(light) = &roots[0] else {
+		panic!("expected a light, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(light.kind().kind(), Syn::KwPointLight);
+	assert_eq!(light.name().text(), "ZOMBIEATK");
+
+	let color: Vec<_> = light
+		.property("COLOR")
+		.unwrap()
+		.values()
+		.filter_map(|v| v.float())
+		.collect();
+
+	assert_eq!(color, [1.0, 0.8, 0.2]);
+	assert_eq!(
+		light.property("size").unwrap().value().unwrap().int(),
+		Some(48)
+	);
+
+	let ast::Root::Light(flicker2) = &roots[2] else {
+		panic!("expected a light, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(flicker2.kind().kind(), Syn::KwFlickerLight2);
+
+	let ast::Root::Object(object) = &roots[5] else {
+		panic!("expected an object, found: {:#?}", roots[5]);
+	};
+
+	assert_eq!(object.class_name().text(), "ZombieMan");
+	let frames: Vec<_> = object.frames().collect();
+	assert_eq!(frames[0].name().text(), "POSSF");
+	assert_eq!(frames[1].lights().next().unwrap().text(), "BARREL_GLOW");
+
+	let ast::Root::Brightmap(brightmap) = &roots[6] else {
+		panic!("expected a brightmap, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(brightmap.kind().kind(), Syn::KwSprite);
+	assert_eq!(brightmap.name().text(), "POSSF1");
+
+	assert_eq!(
+		brightmap.property("map").unwrap().value().unwrap().text(),
+		"brightmaps/possf1.png"
+	);
+
+	assert!(brightmap.property("disablefullbright").is_some());
+
+	let ast::Root::Shader(shader) = &roots[8] else {
+		panic!("expected a shader, found: {:#?}", roots[8]);
+	};
+
+	assert_eq!(shader.keyword().kind(), Syn::KwHardwareShader);
+	assert_eq!(shader.kind().unwrap().kind(), Syn::KwTexture);
+
+	let texture: Vec<_> = shader
+		.property("texture")
+		.unwrap()
+		.values()
+		.map(|v| v.text())
+		.collect();
+
+	assert_eq!(texture, ["tex_noise", "textures/noise.png"]);
+
+	let define: Vec<_> = shader
+		.property("define")
+		.unwrap()
+		.values()
+		.map(|v| v.text())
+		.collect();
+
+	assert_eq!(define, ["USE_FOAM", "1"]);
+
+	let ast::Root::Glow(glow) = &roots[10] else {
+		panic!("expected a glow definition, found: {:#?}", roots[10]);
+	};
+
+	let lists: Vec<_> = glow.lists().collect();
+	assert_eq!(lists[0].kind().text(), "flats");
+	assert_eq!(lists[0].textures().count(), 3);
+	assert_eq!(lists[1].textures().next().unwrap().text(), "LITE3");
+
+	let glow_tex: Vec<_> = glow
+		.properties()
+		.next()
+		.unwrap()
+		.values()
+		.map(|v| v.text())
+		.collect();
+
+	assert_eq!(glow_tex, ["LAVA1", "ff4000", "128", "fullbright"]);
+
+	let ast::Root::Skybox(skybox) = &roots[11] else {
+		panic!("expected a skybox, found: {:#?}", roots[11]);
+	};
+
+	assert_eq!(skybox.name().text(), "SKY_SPACE");
+	assert!(skybox.is_fliptop());
+	assert_eq!(skybox.textures().count(), 6);
+
+	let ast::Root::Include(include) = &roots[12] else {
+		panic!("expected an include directive, found: {:#?}", roots[12]);
+	};
+
+	assert_eq!(include.path(), "gldefs/extra.txt");
+}
+
+#[test]
+fn objects() {
+	const ZSCRIPT: &str = r#"
+class Imp2 : DoomImp {}
+
+class Trooper : Actor
+{
+	States
+	{
+	Spawn:
+		TROO AB 10;
+		Loop;
+	Missile:
+		TROO EF 8;
+		TROO "G" 6;
+		Goto Spawn;
+	}
+}
+
+class Stranger : SomewhereElse {}
+"#;
+
+	const GLDEFS: &str = r#"
+pointlight IMPBALL { color 1.0 0.5 0.0 size 32 }
+
+object Trooper
+{
+	frame TROOG { light IMPBALL }
+	frame TROOEF { light IMPBALL }
+	frame TROOH { light IMPBALL }
+	frame TROOA1 { light IMPBALL }
+	frame TROOI8 { light IMPBALL }
+	frame TRO { light IMPBALL }
+}
+
+object Imp2 { frame TROOA { light MISSING } }
+object Stranger { frame XXXXA { light IMPBALL } }
+object Nobody { frame NOBOA { light IMPBALL } }
+"#;
+
+	let zscript = ParseTree::new(crate::zscript::parse_recov(ZSCRIPT).unwrap());
+	let pt = parse_recov(GLDEFS).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+
+	let mut actors = Actors::new();
+	actors.add_zscript(&zscript);
+	// As if read out of DECORATE.
+	actors.insert("DoomImp", None, [("TROO", "A")]);
+
+	assert!(actors.contains("trooper"));
+
+	let issues = check_objects(&pt, &actors);
+
+	let found: Vec<_> = issues
+		.iter()
+		.map(|issue| (&GLDEFS[issue.range], issue.message.as_str()))
+		.collect();
+
+	assert_eq!(
+		found,
+		[
+			("TROOH", "`Trooper` has no state using frame `TROOH`"),
+			("TROOI8", "`Trooper` has no state using frame `TROOI8`"),
+			("TRO", "`TRO` is too short to be a sprite name"),
+			("MISSING", "light `MISSING` is not defined"),
+			("Nobody", "`Nobody` is not a known actor class"),
+		]
+	);
+}
+
+#[test]
+fn quoted_names() {
+	const SOURCE: &str = r#"
+brightmap texture "STARTAN2" { map "brightmaps/startan2.png" }
+hardwareshader texture "FOO" { shader "shaders/foo.fp" }
+material texture "textures/x.png" { normal "normals/x.png" }
+object ZombieMan { frame "POSSA" { light ZOMBIEATK } }
+skybox "SKY_SPACE" { "SKYN" "SKYE" "SKYS" }
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 5);
+
+	let ast::Root::Brightmap(brightmap) = &roots[0] else {
+		panic!("expected a brightmap, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(brightmap.name().text(), "STARTAN2");
+
+	let ast::Root::Shader(material) = &roots[2] else {
+		panic!("expected a shader, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(material.kind().unwrap().kind(), Syn::KwTexture);
+	assert_eq!(material.name().text(), "textures/x.png");
+
+	let ast::Root::Object(object) = &roots[3] else {
+		panic!("expected an object, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(object.frames().next().unwrap().name().text(), "POSSA");
+
+	let ast::Root::Skybox(skybox) = &roots[4] else {
+		panic!("expected a skybox, found: {:#?}", roots[4]);
+	};
+
+	assert_eq!(skybox.name().text(), "SKY_SPACE");
+	assert_eq!(skybox.textures().count(), 3);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+pointlight FINE { color 1 1 1 size 8 }
+bogus definition { with stuff { nested } }
+pointlight ALSOFINE { size 16 }
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
@@ -0,0 +1,47 @@
+//! Diagnostics emitted by semantic checkers, as opposed to parsers.
+
+use rowan::TextRange;
+
+/// A problem in a source file found by analyzing its syntax tree(s).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Issue {
+	/// The span of source text in which the problem lies.
+	pub range: TextRange,
+	pub level: IssueLevel,
+	pub message: String,
+}
+
+impl Issue {
+	#[must_use]
+	pub fn error(range: TextRange, message: impl Into<String>) -> Self {
+		Self {
+			range,
+			level: IssueLevel::Error,
+			message: message.into(),
+		}
+	}
+
+	#[must_use]
+	pub fn warning(range: TextRange, message: impl Into<String>) -> Self {
+		Self {
+			range,
+			level: IssueLevel::Warning,
+			message: message.into(),
+		}
+	}
+
+	#[must_use]
+	pub fn is_error(&self) -> bool {
+		self.level == IssueLevel::Error
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum IssueLevel {
+	/// The source port would refuse to load the file.
+	Error,
+	/// The source port would load the file, but likely not as intended.
+	Warning,
+}
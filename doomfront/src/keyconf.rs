@@ -0,0 +1,25 @@
+//! Parser and syntax trees for [KEYCONF](https://zdoom.org/wiki/KEYCONF),
+//! the lump which ZDoom-family source ports run as console commands at startup
+//! to set up key bindings, aliases, player classes, and weapon slots.
+//!
+//! Each command takes the rest of its line, or up to a semicolon. Only the
+//! commands which KEYCONF permits are accepted. [`WeaponSlots`] applies the
+//! slot commands in order on top of each player class' defaults, and
+//! [`check_slot_numbers`] compares the result to weapons' `Weapon.SlotNumber`.
+
+pub mod ast;
+mod parse;
+mod slots;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use parse::*;
+pub use slots::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
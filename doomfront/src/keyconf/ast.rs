@@ -0,0 +1,323 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file, each being one console command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	AddKeySection(AddKeySection),
+	AddMenuKey(AddMenuKey),
+	AddPlayerClass(AddPlayerClass),
+	AddSlotDefault(AddSlotDefault),
+	Alias(Alias),
+	ClearPlayerClasses(ClearPlayerClasses),
+	DefaultBind(DefaultBind),
+	SetSlot(SetSlot),
+	WeaponSection(WeaponSection),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::AddKeySection
+				| Syn::AddMenuKey
+				| Syn::AddPlayerClass
+				| Syn::AddSlotDefault
+				| Syn::Alias | Syn::ClearPlayerClasses
+				| Syn::DefaultBind
+				| Syn::SetSlot
+				| Syn::WeaponSection
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::AddKeySection => Some(Self::AddKeySection(AddKeySection(node))),
+			Syn::AddMenuKey => Some(Self::AddMenuKey(AddMenuKey(node))),
+			Syn::AddPlayerClass => Some(Self::AddPlayerClass(AddPlayerClass(node))),
+			Syn::AddSlotDefault => Some(Self::AddSlotDefault(AddSlotDefault(node))),
+			Syn::Alias => Some(Self::Alias(Alias(node))),
+			Syn::ClearPlayerClasses => Some(Self::ClearPlayerClasses(ClearPlayerClasses(node))),
+			Syn::DefaultBind => Some(Self::DefaultBind(DefaultBind(node))),
+			Syn::SetSlot => Some(Self::SetSlot(SetSlot(node))),
+			Syn::WeaponSection => Some(Self::WeaponSection(WeaponSection(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::AddKeySection(inner) => &inner.0,
+			Self::AddMenuKey(inner) => &inner.0,
+			Self::AddPlayerClass(inner) => &inner.0,
+			Self::AddSlotDefault(inner) => &inner.0,
+			Self::Alias(inner) => &inner.0,
+			Self::ClearPlayerClasses(inner) => &inner.0,
+			Self::DefaultBind(inner) => &inner.0,
+			Self::SetSlot(inner) => &inner.0,
+			Self::WeaponSection(inner) => &inner.0,
+		}
+	}
+}
+
+impl Root {
+	/// The command's name. Its kind will be one of the `Kw` tags of [`Syn`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.syntax().first_token().unwrap()
+	}
+}
+
+/// Every argument of the command `node`, in order.
+fn args(node: &SyntaxNode) -> impl Iterator<Item = Value> {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+}
+
+/// The argument of the command `node` at `index`.
+fn arg(node: &SyntaxNode, index: usize) -> Option<Value> {
+	args(node).nth(index)
+}
+
+// Commands ////////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::AddKeySection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AddKeySection(SyntaxNode);
+
+simple_astnode!(Syn, AddKeySection, Syn::AddKeySection);
+
+impl AddKeySection {
+	/// The heading shown above this section's keys in the controls menu.
+	#[must_use]
+	pub fn title(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	/// The name of the configuration file section in which bindings are saved.
+	#[must_use]
+	pub fn section(&self) -> Value {
+		arg(&self.0, 1).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::AddMenuKey`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AddMenuKey(SyntaxNode);
+
+simple_astnode!(Syn, AddMenuKey, Syn::AddMenuKey);
+
+impl AddMenuKey {
+	/// The label shown in the controls menu.
+	#[must_use]
+	pub fn title(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	/// The console command to bind, e.g. `+reload` or an alias.
+	#[must_use]
+	pub fn command(&self) -> Value {
+		arg(&self.0, 1).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::AddPlayerClass`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AddPlayerClass(SyntaxNode);
+
+simple_astnode!(Syn, AddPlayerClass, Syn::AddPlayerClass);
+
+impl AddPlayerClass {
+	#[must_use]
+	pub fn class_name(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	/// e.g. `nomenu`.
+	pub fn flags(&self) -> impl Iterator<Item = Value> {
+		args(&self.0).skip(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::AddSlotDefault`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AddSlotDefault(SyntaxNode);
+
+simple_astnode!(Syn, AddSlotDefault, Syn::AddSlotDefault);
+
+impl AddSlotDefault {
+	/// Tagged [`Syn::LitInt`].
+	#[must_use]
+	pub fn slot(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	#[must_use]
+	pub fn weapon(&self) -> Value {
+		arg(&self.0, 1).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Alias`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Alias(SyntaxNode);
+
+simple_astnode!(Syn, Alias, Syn::Alias);
+
+impl Alias {
+	#[must_use]
+	pub fn name(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	/// `None` if this removes the alias instead of defining it. Multiple
+	/// commands are separated by semicolons within the string.
+	#[must_use]
+	pub fn command(&self) -> Option<Value> {
+		arg(&self.0, 1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ClearPlayerClasses`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClearPlayerClasses(SyntaxNode);
+
+simple_astnode!(Syn, ClearPlayerClasses, Syn::ClearPlayerClasses);
+
+/// Wraps a node tagged [`Syn::DefaultBind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultBind(SyntaxNode);
+
+simple_astnode!(Syn, DefaultBind, Syn::DefaultBind);
+
+impl DefaultBind {
+	/// e.g. `r`, `mouse3`, `kp5`.
+	#[must_use]
+	pub fn key(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	#[must_use]
+	pub fn command(&self) -> Value {
+		arg(&self.0, 1).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::SetSlot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SetSlot(SyntaxNode);
+
+simple_astnode!(Syn, SetSlot, Syn::SetSlot);
+
+impl SetSlot {
+	/// Tagged [`Syn::LitInt`].
+	#[must_use]
+	pub fn slot(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+
+	/// May be empty, which clears the slot.
+	pub fn weapons(&self) -> impl Iterator<Item = Value> {
+		args(&self.0).skip(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::WeaponSection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct WeaponSection(SyntaxNode);
+
+simple_astnode!(Syn, WeaponSection, Syn::WeaponSection);
+
+impl WeaponSection {
+	/// Names the configuration file section in which the player's own slot
+	/// assignments are saved, so that they do not leak between mods.
+	#[must_use]
+	pub fn name(&self) -> Value {
+		arg(&self.0, 0).unwrap()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding an argument, with convenience
+/// functions for interpreting it. Its tag is one of [`Syn::LitString`],
+/// [`Syn::LitInt`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(token.kind(), Syn::LitString | Syn::LitInt | Syn::Ident).then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,194 @@
+use chumsky::{primitive, recovery, Error, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), semicolon(source), command(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips to the end of the line, wrapping everything skipped into a token
+/// tagged [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		semicolon(source),
+		command(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Any of the console commands which KEYCONF permits.
+fn command(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let add_key_section =
+		comb::kw_word::<Syn, _>(word(), "addkeysection", Syn::KwAddKeySection, src)
+			.start_vec()
+			.chain_append(spaced(src, name(src)))
+			.chain_append(spaced(src, name(src)))
+			.collect_n::<Syn, { Syn::AddKeySection as u16 }>();
+
+	let add_menu_key = comb::kw_word::<Syn, _>(word(), "addmenukey", Syn::KwAddMenuKey, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, name(src)))
+		.collect_n::<Syn, { Syn::AddMenuKey as u16 }>();
+
+	let add_player_class =
+		comb::kw_word::<Syn, _>(word(), "addplayerclass", Syn::KwAddPlayerClass, src)
+			.start_vec()
+			.chain_append(spaced(src, name(src)))
+			// e.g. `nomenu`.
+			.chain_append(spaced(src, name(src)).repeated().flatten())
+			.collect_n::<Syn, { Syn::AddPlayerClass as u16 }>();
+
+	let add_slot_default =
+		comb::kw_word::<Syn, _>(word(), "addslotdefault", Syn::KwAddSlotDefault, src)
+			.start_vec()
+			.chain_append(spaced(src, int_lit(src)))
+			.chain_append(spaced(src, name(src)))
+			.collect_n::<Syn, { Syn::AddSlotDefault as u16 }>();
+
+	// Without a command, an alias gets removed.
+	let alias = comb::kw_word::<Syn, _>(word(), "alias", Syn::KwAlias, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, name(src)).or_not().flatten())
+		.collect_n::<Syn, { Syn::Alias as u16 }>();
+
+	let clear_player_classes =
+		comb::kw_word::<Syn, _>(word(), "clearplayerclasses", Syn::KwClearPlayerClasses, src)
+			.start_vec()
+			.collect_n::<Syn, { Syn::ClearPlayerClasses as u16 }>();
+
+	let default_bind = comb::kw_word::<Syn, _>(word(), "defaultbind", Syn::KwDefaultBind, src)
+		.start_vec()
+		.chain_append(spaced(src, name(src)))
+		.chain_append(spaced(src, name(src)))
+		.collect_n::<Syn, { Syn::DefaultBind as u16 }>();
+
+	let set_slot = comb::kw_word::<Syn, _>(word(), "setslot", Syn::KwSetSlot, src)
+		.start_vec()
+		.chain_append(spaced(src, int_lit(src)))
+		.chain_append(spaced(src, name(src)).repeated().flatten())
+		.collect_n::<Syn, { Syn::SetSlot as u16 }>();
+
+	let weapon_section =
+		comb::kw_word::<Syn, _>(word(), "weaponsection", Syn::KwWeaponSection, src)
+			.start_vec()
+			.chain_append(spaced(src, name(src)))
+			.collect_n::<Syn, { Syn::WeaponSection as u16 }>();
+
+	primitive::choice((
+		add_key_section,
+		add_menu_key,
+		add_player_class,
+		add_slot_default,
+		alias,
+		clear_player_classes,
+		default_bind,
+		set_slot,
+		weapon_section,
+	))
+	.labelled("command")
+}
+
+/// Anything up to the next whitespace, quotation mark, or semicolon, other
+/// than the start of a comment. Every token besides strings and trivia starts
+/// out as one.
+fn word() -> impl Parser<char, String, Error = ParseError> + Clone {
+	primitive::none_of(" \t\r\n\";")
+		.repeated()
+		.at_least(1)
+		.collect::<String>()
+		.try_map(|word, span| {
+			if word.starts_with("//") || word.starts_with("/*") {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(word)
+			}
+		})
+}
+
+fn name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		int_lit(src),
+		word()
+			.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+			.labelled("name"),
+	))
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	word()
+		.try_map(|word, span| {
+			if word.parse::<i64>().is_ok() {
+				Ok(word)
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+fn semicolon(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::just::<Syn, _>(';', Syn::Semicolon, src)
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// `parser`, preceded by at least one space, tab, or block comment; a command's
+/// arguments must all be on the same line as it.
+fn spaced<'i, P>(
+	src: &'i str,
+	parser: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	let inline = primitive::choice((
+		primitive::one_of(" \t")
+			.repeated()
+			.at_least(1)
+			.map_with_span(help::map_tok::<Syn, _>(src, Syn::Whitespace)),
+		comb::c_comment::<Syn>(src),
+	));
+
+	inline.repeated().at_least(1).chain_push(parser)
+}
+
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
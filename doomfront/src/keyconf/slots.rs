@@ -0,0 +1,390 @@
+//! A model of weapon slot assignments, built from KEYCONF and player classes'
+//! defaults, and checking of weapons' own slot numbers against it.
+
+use std::collections::HashMap;
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::{zscript, Issue};
+
+use super::{ast, ParseTree};
+
+/// Slots are numbered from 0 to 9, as are the keys which select them.
+pub const SLOT_COUNT: usize = 10;
+
+/// The weapons in each slot of one player class, in the order in which
+/// repeatedly pressing the slot's key cycles through them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Slots([Vec<String>; SLOT_COUNT]);
+
+impl Slots {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// # Panics
+	/// If `slot` is not less than [`SLOT_COUNT`].
+	#[must_use]
+	pub fn get(&self, slot: usize) -> &[String] {
+		&self.0[slot]
+	}
+
+	/// Replaces the whole content of `slot`, as `setslot` does.
+	///
+	/// # Panics
+	/// If `slot` is not less than [`SLOT_COUNT`].
+	pub fn set<'w>(&mut self, slot: usize, weapons: impl IntoIterator<Item = &'w str>) {
+		self.0[slot] = weapons.into_iter().map(|w| w.to_string()).collect();
+	}
+
+	/// Appends `weapon` to `slot` unless it is already in any slot, as
+	/// `addslotdefault` does. Returns `true` if the weapon was added.
+	///
+	/// # Panics
+	/// If `slot` is not less than [`SLOT_COUNT`].
+	pub fn add_default(&mut self, slot: usize, weapon: &str) -> bool {
+		if self.locate(weapon).is_some() {
+			return false;
+		}
+
+		self.0[slot].push(weapon.to_string());
+		true
+	}
+
+	/// The slot holding `weapon` (compared case-insensitively), and the
+	/// weapon's position within it. If it is in more than one slot, the
+	/// lowest-numbered one is returned.
+	#[must_use]
+	pub fn locate(&self, weapon: &str) -> Option<(usize, usize)> {
+		self.0.iter().enumerate().find_map(|(slot, weapons)| {
+			weapons
+				.iter()
+				.position(|w| w.eq_ignore_ascii_case(weapon))
+				.map(|pos| (slot, pos))
+		})
+	}
+}
+
+/// Everything which decides the contents of each player class' slots.
+///
+/// Source ports start each player class from its `Player.WeaponSlot` defaults
+/// (inherited slot by slot from its ancestors), and then apply KEYCONF's
+/// `setslot` and `addslotdefault` commands in order, the same way for every
+/// class. [`Self::slots`] resolves a class' slots the same way.
+///
+/// Class names are compared case-insensitively.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WeaponSlots {
+	player_classes: Vec<String>,
+	classes: HashMap<String, ClassDefaults>,
+	commands: Vec<SlotCommand>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct ClassDefaults {
+	parent: Option<String>,
+	slots: HashMap<usize, Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SlotCommand {
+	Set(usize, Vec<String>),
+	AddDefault(usize, String),
+}
+
+impl WeaponSlots {
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Does nothing if `class` was already added. Use this for the classes
+	/// given by MAPINFO's `PlayerClasses`, for example.
+	pub fn add_player_class(&mut self, class: &str) {
+		if !self
+			.player_classes
+			.iter()
+			.any(|c| c.eq_ignore_ascii_case(class))
+		{
+			self.player_classes.push(class.to_string());
+		}
+	}
+
+	/// In the order in which they were added.
+	#[must_use]
+	pub fn player_classes(&self) -> &[String] {
+		&self.player_classes
+	}
+
+	/// Declares a class with the given parent, if any, so that it inherits
+	/// any slot defaults it does not set itself.
+	pub fn insert_class(&mut self, class: &str, parent: Option<&str>) {
+		let info = self.classes.entry(class.to_ascii_uppercase()).or_default();
+
+		if let Some(parent) = parent {
+			info.parent = Some(parent.to_ascii_uppercase());
+		}
+	}
+
+	/// Sets the default content of `slot` for `class`, as a `Player.WeaponSlot`
+	/// property does. Out-of-range slots are ignored.
+	pub fn set_default<'w>(
+		&mut self,
+		class: &str,
+		slot: usize,
+		weapons: impl IntoIterator<Item = &'w str>,
+	) {
+		if slot >= SLOT_COUNT {
+			return;
+		}
+
+		self.classes
+			.entry(class.to_ascii_uppercase())
+			.or_default()
+			.slots
+			.insert(slot, weapons.into_iter().map(|w| w.to_string()).collect());
+	}
+
+	/// Applies each of `tree`'s commands in order. `addplayerclass` and
+	/// `clearplayerclasses` change the list of player classes, while `setslot`
+	/// and `addslotdefault` get queued to be applied to every class' slots.
+	/// Slot commands with an out-of-range slot are ignored, as source ports do.
+	pub fn add_keyconf(&mut self, tree: &ParseTree) {
+		for root in tree.ast() {
+			match root {
+				ast::Root::AddPlayerClass(cmd) => self.add_player_class(&cmd.class_name().text()),
+				ast::Root::ClearPlayerClasses(_) => self.player_classes.clear(),
+				ast::Root::SetSlot(cmd) => {
+					let Some(slot) = slot_number(&cmd.slot()) else {
+						continue;
+					};
+
+					self.commands.push(SlotCommand::Set(
+						slot,
+						cmd.weapons().map(|w| w.text()).collect(),
+					));
+				}
+				ast::Root::AddSlotDefault(cmd) => {
+					let Some(slot) = slot_number(&cmd.slot()) else {
+						continue;
+					};
+
+					self.commands
+						.push(SlotCommand::AddDefault(slot, cmd.weapon().text()));
+				}
+				_ => {}
+			}
+		}
+	}
+
+	/// Declares every class in `tree`, along with the slot defaults given by
+	/// `Player.WeaponSlot` properties in their `default` blocks.
+	pub fn add_zscript(&mut self, tree: &zscript::ParseTree) {
+		for root in tree.ast() {
+			let zscript::ast::Root::Class(classdef) = root else {
+				continue;
+			};
+
+			let name = classdef.name();
+			let parent = classdef.parent();
+			self.insert_class(name.text(), parent.as_ref().map(|p| p.text()));
+
+			for prop in default_properties(&classdef) {
+				if !prop.name().eq_ignore_ascii_case("Player.WeaponSlot") {
+					continue;
+				}
+
+				let mut values = prop.values();
+
+				let Some(slot) = values
+					.next()
+					.and_then(|expr| int_expr(&expr))
+					.and_then(|int| usize::try_from(int).ok())
+				else {
+					continue;
+				};
+
+				let weapons: Vec<_> = values.filter_map(|expr| name_expr(&expr)).collect();
+				self.set_default(name.text(), slot, weapons.iter().map(String::as_str));
+			}
+		}
+	}
+
+	/// The slots of `class` once its defaults and every KEYCONF command have
+	/// been applied. An unknown class starts out with every slot empty.
+	#[must_use]
+	pub fn slots(&self, class: &str) -> Slots {
+		let mut ret = Slots::new();
+
+		for (slot, weapons) in ret.0.iter_mut().enumerate() {
+			if let Some(defaults) = self.default_slot(class, slot) {
+				weapons.clone_from(defaults);
+			}
+		}
+
+		for command in &self.commands {
+			match command {
+				SlotCommand::Set(slot, weapons) => {
+					ret.set(*slot, weapons.iter().map(String::as_str))
+				}
+				SlotCommand::AddDefault(slot, weapon) => {
+					ret.add_default(*slot, weapon);
+				}
+			}
+		}
+
+		ret
+	}
+
+	/// Looks for a default for `slot` in `class`, and then in its ancestors.
+	fn default_slot(&self, class: &str, slot: usize) -> Option<&Vec<String>> {
+		let mut current = class.to_ascii_uppercase();
+
+		// Guards against inheritance cycles.
+		for _ in 0..=self.classes.len() {
+			let info = self.classes.get(&current)?;
+
+			if let Some(weapons) = info.slots.get(&slot) {
+				return Some(weapons);
+			}
+
+			current = info.parent.clone()?;
+		}
+
+		None
+	}
+}
+
+/// Checks the `Weapon.SlotNumber` of every class in `tree` against `slots`.
+///
+/// A weapon's slot number only decides its slot if neither its player class'
+/// defaults nor KEYCONF have already put it in one, so for each player class
+/// (or, if there are none, for KEYCONF alone), a warning is raised wherever
+/// a weapon would end up in a different slot than the one it declares.
+/// Slot numbers out of range are errors.
+#[must_use]
+pub fn check_slot_numbers(tree: &zscript::ParseTree, slots: &WeaponSlots) -> Vec<Issue> {
+	let mut issues = vec![];
+
+	let resolved: Vec<_> = if slots.player_classes.is_empty() {
+		vec![(None, slots.slots(""))]
+	} else {
+		slots
+			.player_classes
+			.iter()
+			.map(|class| (Some(class.as_str()), slots.slots(class)))
+			.collect()
+	};
+
+	for root in tree.ast() {
+		let zscript::ast::Root::Class(classdef) = root else {
+			continue;
+		};
+
+		let weapon = classdef.name();
+
+		for prop in default_properties(&classdef) {
+			if !prop.name().eq_ignore_ascii_case("Weapon.SlotNumber") {
+				continue;
+			}
+
+			let Some(number) = prop.values().next().and_then(|expr| int_expr(&expr)) else {
+				continue;
+			};
+
+			let range = prop.syntax().text_range();
+
+			let Some(declared) = usize::try_from(number).ok().filter(|n| *n < SLOT_COUNT) else {
+				issues.push(Issue::error(
+					range,
+					format!("slot number {number} is not between 0 and 9"),
+				));
+				continue;
+			};
+
+			for (class, slots) in &resolved {
+				let Some((actual, _)) = slots.locate(weapon.text()) else {
+					continue;
+				};
+
+				if actual != declared {
+					issues.push(conflict(range, weapon.text(), declared, actual, *class));
+				}
+			}
+		}
+	}
+
+	issues
+}
+
+fn conflict(
+	range: TextRange,
+	weapon: &str,
+	declared: usize,
+	actual: usize,
+	class: Option<&str>,
+) -> Issue {
+	let message = match class {
+		Some(class) => {
+			format!("`{weapon}` has slot number {declared}, but `{class}` has it in slot {actual}")
+		}
+		None => {
+			format!("`{weapon}` has slot number {declared}, but KEYCONF puts it in slot {actual}")
+		}
+	};
+
+	Issue::warning(range, message)
+}
+
+fn slot_number(value: &ast::Value) -> Option<usize> {
+	value
+		.int()
+		.and_then(|int| usize::try_from(int).ok())
+		.filter(|slot| *slot < SLOT_COUNT)
+}
+
+fn default_properties(
+	classdef: &zscript::ast::ClassDef,
+) -> impl Iterator<Item = zscript::ast::DefaultProperty> {
+	classdef
+		.innards()
+		.filter_map(|innard| match innard {
+			zscript::ast::ClassInnard::Default(block) => Some(block),
+			_ => None,
+		})
+		.flat_map(|block| block.items())
+		.filter_map(|item| match item {
+			zscript::ast::DefaultItem::Property(prop) => Some(prop),
+			_ => None,
+		})
+}
+
+fn int_expr(expr: &zscript::ast::Expression) -> Option<i64> {
+	match expr {
+		zscript::ast::Expression::Literal(lit) => lit
+			.token()
+			.int()?
+			.ok()
+			.and_then(|int| i64::try_from(int).ok()),
+		zscript::ast::Expression::Prefix(prefix)
+			if prefix.operator().kind() == zscript::Syn::Minus =>
+		{
+			int_expr(&prefix.operand()).map(|int| -int)
+		}
+		_ => None,
+	}
+}
+
+/// A weapon class given as a string literal, a name literal, or an identifier.
+fn name_expr(expr: &zscript::ast::Expression) -> Option<String> {
+	match expr {
+		zscript::ast::Expression::Literal(lit) => {
+			let token = lit.token();
+			token.string().or_else(|| token.name()).map(str::to_string)
+		}
+		zscript::ast::Expression::Ident(ident) => Some(ident.token().text().to_string()),
+		_ => None,
+	}
+}
@@ -0,0 +1,92 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// KEYCONF syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `addkeysection "TITLE" SECTION`.
+	AddKeySection,
+	/// `addmenukey "TITLE" COMMAND`.
+	AddMenuKey,
+	/// `addplayerclass CLASS [FLAGS...]`.
+	AddPlayerClass,
+	/// `addslotdefault SLOT WEAPON`.
+	AddSlotDefault,
+	/// `alias NAME "COMMANDS"`.
+	Alias,
+	/// `clearplayerclasses`.
+	ClearPlayerClasses,
+	/// `defaultbind KEY COMMAND`.
+	DefaultBind,
+	/// `setslot SLOT [WEAPONS...]`.
+	SetSlot,
+	/// `weaponsection NAME`.
+	WeaponSection,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwAddKeySection,
+	KwAddMenuKey,
+	KwAddPlayerClass,
+	KwAddSlotDefault,
+	KwAlias,
+	KwClearPlayerClasses,
+	KwDefaultBind,
+	KwSetSlot,
+	KwWeaponSection,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `;`, which ends a command just like a newline does.
+	Semicolon,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// KEYCONF comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Any unquoted argument which is not an integer, such as a class name,
+	/// a key name, or a console command like `+attack`.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs. Newlines end commands.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
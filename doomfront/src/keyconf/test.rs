@@ -0,0 +1,194 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Adapted from a typical mod's KEYCONF.
+clearplayerclasses
+addplayerclass MarinePlayer
+AddPlayerClass ScoutPlayer nomenu
+
+weaponsection MyMod
+setslot 1 Fist Chainsaw
+setslot 3 "SuperShotgun" Shotgun; addslotdefault 3 Blaster
+addslotdefault 6 PlasmaRifle /* Already in a slot? */
+
+alias mymod_dash "+speed; wait 5; -speed"
+alias oldalias
+addkeysection "My Mod" MyModKeys
+addmenukey "Dash" mymod_dash
+defaultbind q mymod_dash
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 13);
+
+	assert_eq!(roots[0].keyword().kind(), Syn::KwClearPlayerClasses);
+
+	let ast::Root::AddPlayerClass(scout) = &roots[2] else {
+		panic!("expected `addplayerclass`, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(scout.class_name().text(), "ScoutPlayer");
+	assert_eq!(
+		scout.flags().map(|f| f.text()).collect::<Vec<_>>(),
+		["nomenu"]
+	);
+
+	let ast::Root::WeaponSection(section) = &roots[3] else {
+		panic!("expected `weaponsection`, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(section.name().text(), "MyMod");
+
+	let ast::Root::SetSlot(slot3) = &roots[5] else {
+		panic!("expected `setslot`, found: {:#?}", roots[5]);
+	};
+
+	assert_eq!(slot3.slot().int(), Some(3));
+	assert_eq!(
+		slot3.weapons().map(|w| w.text()).collect::<Vec<_>>(),
+		["SuperShotgun", "Shotgun"]
+	);
+
+	let ast::Root::AddSlotDefault(blaster) = &roots[6] else {
+		panic!("expected `addslotdefault`, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(blaster.weapon().text(), "Blaster");
+
+	let ast::Root::Alias(dash) = &roots[8] else {
+		panic!("expected `alias`, found: {:#?}", roots[8]);
+	};
+
+	assert_eq!(dash.name().text(), "mymod_dash");
+	assert_eq!(dash.command().unwrap().text(), "+speed; wait 5; -speed");
+
+	let ast::Root::Alias(old) = &roots[9] else {
+		panic!("expected `alias`, found: {:#?}", roots[9]);
+	};
+
+	assert!(old.command().is_none());
+
+	let ast::Root::AddKeySection(keys) = &roots[10] else {
+		panic!("expected `addkeysection`, found: {:#?}", roots[10]);
+	};
+
+	assert_eq!(keys.title().text(), "My Mod");
+	assert_eq!(keys.section().text(), "MyModKeys");
+
+	let ast::Root::DefaultBind(bind) = &roots[12] else {
+		panic!("expected `defaultbind`, found: {:#?}", roots[12]);
+	};
+
+	assert_eq!(bind.key().text(), "q");
+	assert_eq!(bind.command().text(), "mymod_dash");
+}
+
+#[test]
+fn weapon_slots() {
+	const ZSCRIPT: &str = r#"
+class MarinePlayer : DoomPlayer {
+	Default {
+		Player.WeaponSlot 6, "PlasmaRifle";
+		Player.WeaponSlot 7, 'BFG9000';
+	}
+}
+
+class ScoutPlayer : MarinePlayer {
+	Default {
+		Player.WeaponSlot 7, Blaster;
+	}
+}
+
+class SuperShotgun : Weapon {
+	Default {
+		Weapon.SlotNumber 4;
+	}
+}
+
+class BFG9000 : Weapon {
+	Default {
+		Weapon.SlotNumber 7;
+	}
+}
+
+class Blaster : Weapon {
+	Default {
+		Weapon.SlotNumber 12;
+	}
+}
+"#;
+
+	let zscript = crate::zscript::ParseTree::new(crate::zscript::parse_recov(ZSCRIPT).unwrap());
+	assert_no_errors(&zscript);
+
+	let mut slots = WeaponSlots::new();
+	slots.add_player_class("DoomPlayer");
+	slots.add_zscript(&zscript);
+	slots.add_keyconf(&ParseTree::new(parse(SOURCE).unwrap()));
+
+	// `clearplayerclasses` removed `DoomPlayer`.
+	assert_eq!(slots.player_classes(), ["MarinePlayer", "ScoutPlayer"]);
+
+	let marine = slots.slots("marineplayer");
+	assert_eq!(marine.get(1), ["Fist", "Chainsaw"]);
+	assert_eq!(marine.get(3), ["SuperShotgun", "Shotgun", "Blaster"]);
+	assert_eq!(marine.get(6), ["PlasmaRifle"]);
+	assert_eq!(marine.get(7), ["BFG9000"]);
+
+	let scout = slots.slots("ScoutPlayer");
+	// Already in slot 7, so `addslotdefault 3 Blaster` does nothing.
+	assert_eq!(scout.get(3), ["SuperShotgun", "Shotgun"]);
+	assert_eq!(scout.get(6), ["PlasmaRifle"]);
+	assert_eq!(scout.get(7), ["Blaster"]);
+	assert_eq!(scout.locate("blaster"), Some((7, 0)));
+
+	let issues: Vec<_> = check_slot_numbers(&zscript, &slots)
+		.into_iter()
+		.map(|issue| (&ZSCRIPT[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		issues,
+		[
+			(
+				"Weapon.SlotNumber 4;",
+				"`SuperShotgun` has slot number 4, but `MarinePlayer` has it in slot 3".to_string()
+			),
+			(
+				"Weapon.SlotNumber 4;",
+				"`SuperShotgun` has slot number 4, but `ScoutPlayer` has it in slot 3".to_string()
+			),
+			(
+				"Weapon.SlotNumber 12;",
+				"slot number 12 is not between 0 and 9".to_string()
+			),
+		]
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+setslot 1 Fist
+setslot one Pistol
+defaultbind x +use
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
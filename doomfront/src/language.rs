@@ -0,0 +1,28 @@
+//! Parser and syntax trees for [LANGUAGE](https://zdoom.org/wiki/LANGUAGE),
+//! the lump which ZDoom-family source ports use to define localized text.
+//!
+//! A file is a sequence of locale headers such as `[enu default]`, each
+//! followed by `KEY = "string";` entries; adjacent string literals are
+//! concatenated, and an entry may be prefixed with `$ifgame(...)` to apply only
+//! to certain games. [`StringTable`] merges any number of files and resolves
+//! `$KEY` references with locale fallback, and [`RefCheck`] finds references
+//! made by other lumps which it can not resolve.
+
+pub mod ast;
+mod check;
+mod parse;
+mod syn;
+mod table;
+#[cfg(test)]
+mod test;
+
+pub use check::*;
+pub use parse::*;
+pub use syn::*;
+pub use table::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
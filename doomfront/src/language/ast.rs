@@ -0,0 +1,189 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Header(Header),
+	Entry(Entry),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(kind, Syn::Header | Syn::Entry)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::Header => Some(Self::Header(Header(node))),
+			Syn::Entry => Some(Self::Entry(Entry(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Header(inner) => &inner.0,
+			Self::Entry(inner) => &inner.0,
+		}
+	}
+}
+
+/// Every token of `node` tagged `syn`, looking only at direct children.
+fn tokens(node: &SyntaxNode, syn: Syn) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(move |n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// Wraps a node tagged [`Syn::Header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Header(SyntaxNode);
+
+simple_astnode!(Syn, Header, Syn::Header);
+
+impl Header {
+	/// Each yielded token is tagged either [`Syn::Ident`] (a locale code such
+	/// as `enu` or `ptb`) or [`Syn::KwDefault`].
+	pub fn locales(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0.children_with_tokens().filter_map(|n_or_t| {
+			n_or_t
+				.into_token()
+				.filter(|t| matches!(t.kind(), Syn::Ident | Syn::KwDefault))
+		})
+	}
+
+	/// Returns `true` if `default` is among this header's locales, meaning
+	/// that the section's strings are used when no locale provides a string.
+	#[must_use]
+	pub fn is_default(&self) -> bool {
+		tokens(&self.0, Syn::KwDefault).next().is_some()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Entry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Entry(SyntaxNode);
+
+simple_astnode!(Syn, Entry, Syn::Entry);
+
+impl Entry {
+	#[must_use]
+	pub fn if_game(&self) -> Option<IfGame> {
+		self.0.children().find_map(IfGame::cast)
+	}
+
+	/// The identifier preceding the `=`, as written. Keys are compared
+	/// case-insensitively.
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		tokens(&self.0, Syn::Ident).next().unwrap()
+	}
+
+	/// Every string literal after the `=`, in order. Never empty.
+	pub fn strings(&self) -> impl Iterator<Item = SyntaxToken> {
+		tokens(&self.0, Syn::LitString)
+	}
+
+	/// The concatenation of every string literal after the `=`, with the
+	/// delimiting quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn value(&self) -> String {
+		let mut ret = String::new();
+
+		for lit in self.strings() {
+			let text = lit.text();
+			unescape(&text[1..(text.len() - 1)], &mut ret);
+		}
+
+		ret
+	}
+}
+
+/// Wraps a node tagged [`Syn::IfGame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IfGame(SyntaxNode);
+
+simple_astnode!(Syn, IfGame, Syn::IfGame);
+
+impl IfGame {
+	/// The names of the games (e.g. `doom`, `chex`) for which the entry applies.
+	pub fn games(&self) -> impl Iterator<Item = SyntaxToken> {
+		tokens(&self.0, Syn::Ident)
+	}
+
+	/// Game names are compared case-insensitively.
+	#[must_use]
+	pub fn matches(&self, game: &str) -> bool {
+		self.games().any(|g| g.text().eq_ignore_ascii_case(game))
+	}
+}
+
+/// Processes the same escape sequences as ZDoom: the usual C escapes,
+/// including octal and `\x` hexadecimal ones, and `\c`, which becomes the
+/// text color escape character (`\x1C`).
+fn unescape(inner: &str, out: &mut String) {
+	let mut chars = inner.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			out.push(c);
+			continue;
+		}
+
+		let Some(e) = chars.next() else {
+			break;
+		};
+
+		match e {
+			'n' => out.push('\n'),
+			't' => out.push('\t'),
+			'r' => out.push('\r'),
+			'a' => out.push('\x07'),
+			'b' => out.push('\x08'),
+			'f' => out.push('\x0C'),
+			'v' => out.push('\x0B'),
+			'c' => out.push('\x1C'),
+			'x' | 'X' => {
+				let mut value = 0;
+
+				while let Some(d) = chars.peek().and_then(|d| d.to_digit(16)) {
+					value = (value * 16 + d) & 0xFF;
+					chars.next();
+				}
+
+				out.push(char::from(value as u8));
+			}
+			'0'..='7' => {
+				let mut value = e.to_digit(8).unwrap();
+
+				for _ in 0..2 {
+					let Some(d) = chars.peek().and_then(|d| d.to_digit(8)) else {
+						break;
+					};
+
+					value = (value * 8 + d) & 0xFF;
+					chars.next();
+				}
+
+				out.push(char::from(value as u8));
+			}
+			other => out.push(other),
+		}
+	}
+}
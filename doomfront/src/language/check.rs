@@ -0,0 +1,201 @@
+//! Checking references to LANGUAGE strings made by other lumps.
+//!
+//! Wherever a source port expects text, it will usually also accept a string
+//! starting with `$`, which it replaces with the string of that key (e.g.
+//! `Tag "$TAG_SHOTGUN"` in DECORATE). If the key is missing, the reference is
+//! shown verbatim instead, so typos go unnoticed until someone sees them.
+
+use std::collections::HashSet;
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::{decorate, mapinfo, menudef, zscript, Issue};
+
+use super::{ast, ParseTree, StringTable};
+
+/// A use of a LANGUAGE key by another lump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringRef {
+	/// As written, without the leading `$` (if there was one).
+	pub key: String,
+	/// The span of the string literal holding the reference.
+	pub range: TextRange,
+}
+
+/// Collects `$KEY` strings, along with the keys given with `lookup`:
+/// map titles (`map MAP01 lookup "HUSTR_1"`), episode names
+/// (`lookup = "KEY"`), and cluster text (`exittext = lookup, "KEY"`).
+#[must_use]
+pub fn mapinfo_refs(tree: &mapinfo::ParseTree) -> Vec<StringRef> {
+	let mut ret = vec![];
+
+	for token in tree
+		.zipper()
+		.descendants_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.filter(|token| token.kind() == mapinfo::Syn::LitString)
+	{
+		let content = unquote(token.text());
+
+		if let Some(string_ref) = dollar_ref(content, token.text_range()) {
+			ret.push(string_ref);
+		} else if is_lookup(&token) {
+			ret.push(StringRef {
+				key: content.to_string(),
+				range: token.text_range(),
+			});
+		}
+	}
+
+	ret
+}
+
+/// Collects `$KEY` strings, such as those given to `Tag` and `Obituary`.
+#[must_use]
+pub fn decorate_refs(tree: &decorate::ParseTree) -> Vec<StringRef> {
+	dollar_refs(tree.zipper(), decorate::Syn::LitString)
+}
+
+/// Collects `$KEY` strings, such as item labels and option value texts.
+#[must_use]
+pub fn menudef_refs(tree: &menudef::ParseTree) -> Vec<StringRef> {
+	dollar_refs(tree.zipper(), menudef::Syn::LitString)
+}
+
+/// Collects `$KEY` strings, such as those passed to `StringTable.Localize`
+/// or given to the `Tag` default property.
+#[must_use]
+pub fn zscript_refs(tree: &zscript::ParseTree) -> Vec<StringRef> {
+	dollar_refs(tree.zipper(), zscript::Syn::LitString)
+}
+
+/// Checks references against a [`StringTable`], and remembers which keys were
+/// referenced so that [`Self::unused`] can report those which never were.
+#[derive(Debug)]
+pub struct RefCheck<'t> {
+	table: &'t StringTable,
+	locales: Vec<String>,
+	used: HashSet<String>,
+}
+
+impl<'t> RefCheck<'t> {
+	/// Keys defined for some locales but not for any of `locales` (without
+	/// falling back to `default`) are reported as untranslated.
+	#[must_use]
+	pub fn new(table: &'t StringTable, locales: &[&str]) -> Self {
+		Self {
+			table,
+			locales: locales.iter().map(|l| l.to_ascii_lowercase()).collect(),
+			used: HashSet::new(),
+		}
+	}
+
+	/// `refs` should all come from the same file, since the ranges of the
+	/// returned issues are only meaningful relative to it.
+	#[must_use]
+	pub fn check(&mut self, refs: &[StringRef]) -> Vec<Issue> {
+		let mut issues = vec![];
+
+		for string_ref in refs {
+			let key = string_ref.key.to_ascii_uppercase();
+
+			if !self.table.contains(&key) {
+				issues.push(Issue::warning(
+					string_ref.range,
+					format!("`{}` is not defined by any LANGUAGE lump", string_ref.key),
+				));
+
+				continue;
+			}
+
+			for locale in &self.locales {
+				if !self.table.defines(locale, &key) {
+					issues.push(Issue::warning(
+						string_ref.range,
+						format!("`{}` has no string for locale `{locale}`", string_ref.key),
+					));
+				}
+			}
+
+			self.used.insert(key);
+		}
+
+		issues
+	}
+
+	/// Reports every entry in `tree` whose key has not been referenced by any
+	/// of the references checked so far. Note that the engine itself, ACS, and
+	/// other lumps not checked here also use keys, so these are only hints.
+	#[must_use]
+	pub fn unused(&self, tree: &ParseTree) -> Vec<Issue> {
+		tree.ast()
+			.filter_map(|root| match root {
+				ast::Root::Entry(entry) => Some(entry.key()),
+				ast::Root::Header(_) => None,
+			})
+			.filter(|key| !self.used.contains(&key.text().to_ascii_uppercase()))
+			.map(|key| {
+				Issue::warning(
+					key.text_range(),
+					format!("`{}` is never referenced", key.text()),
+				)
+			})
+			.collect()
+	}
+}
+
+fn dollar_refs<L: rowan::Language>(root: &rowan::SyntaxNode<L>, lit: L::Kind) -> Vec<StringRef> {
+	root.descendants_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.filter(|token| token.kind() == lit)
+		.filter_map(|token| dollar_ref(unquote(token.text()), token.text_range()))
+		.collect()
+}
+
+/// Source ports take everything after the `$` as the key, but strings such as
+/// `"$ %d"` are clearly not meant as references, and so are not returned.
+fn dollar_ref(content: &str, range: TextRange) -> Option<StringRef> {
+	let key = content.strip_prefix('$')?;
+
+	if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+		return None;
+	}
+
+	Some(StringRef {
+		key: key.to_string(),
+		range,
+	})
+}
+
+fn unquote(text: &str) -> &str {
+	text.strip_prefix('"')
+		.and_then(|t| t.strip_suffix('"'))
+		.unwrap_or(text)
+}
+
+fn is_lookup(token: &mapinfo::SyntaxToken) -> bool {
+	let Some(parent) = token.parent() else {
+		return false;
+	};
+
+	if let Some(title) = mapinfo::ast::MapTitle::cast(parent.clone()) {
+		return title.is_lookup();
+	}
+
+	let Some(prop) = mapinfo::ast::Property::cast(parent) else {
+		return false;
+	};
+
+	if prop.key().text().eq_ignore_ascii_case("lookup") {
+		return true;
+	}
+
+	let values: Vec<_> = prop.values().collect();
+
+	values.windows(2).any(|pair| {
+		pair[1].syntax() == token
+			&& pair[0]
+				.ident()
+				.is_some_and(|ident| ident.eq_ignore_ascii_case("lookup"))
+	})
+}
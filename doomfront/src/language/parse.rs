@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use chumsky::{primitive, recovery, text, Parser, Stream};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserOpt, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), header(source), entry(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(stream(source))
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips up to and including the next semicolon on the same line, or else to
+/// the end of the line. All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		header(source),
+		entry(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(stream(source));
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Unlike most other languages, LANGUAGE files are full of non-ASCII text,
+/// so spans must be byte offsets rather than character indices for tokens
+/// to be sliced out of the source correctly.
+fn stream(
+	source: &str,
+) -> Stream<'_, char, Range<usize>, impl Iterator<Item = (char, Range<usize>)> + '_> {
+	Stream::from_iter(
+		source.len()..source.len(),
+		source
+			.char_indices()
+			.map(|(i, c)| (c, i..(i + c.len_utf8()))),
+	)
+}
+
+fn header(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let locale = primitive::choice((
+		comb::just_nc("default").map_with_span(help::map_tok::<Syn, _>(src, Syn::KwDefault)),
+		ident(src),
+	))
+	.labelled("locale code");
+
+	comb::just::<Syn, _>('[', Syn::BracketL, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(locale.clone())
+		.chain_append(
+			wsp_ext(src)
+				.repeated()
+				.at_least(1)
+				.chain_push(locale)
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(']', Syn::BracketR, src))
+		.collect_n::<Syn, { Syn::Header as u16 }>()
+		.labelled("locale header")
+}
+
+fn entry(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let rest = trivia(src).chain_push(comb::string_lit::<Syn>(src, Syn::LitString));
+
+	if_game(src)
+		.or_not()
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(key(src))
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>('=', Syn::Eq, src))
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.chain_append(rest.repeated().flatten())
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(';', Syn::Semicolon, src))
+		.collect_n::<Syn, { Syn::Entry as u16 }>()
+		.labelled("string entry")
+}
+
+fn if_game(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let rest = trivia(src)
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src));
+
+	primitive::just('$')
+		.then(comb::just_nc("ifgame"))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwIfGame))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>('(', Syn::ParenL, src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(rest.repeated().flatten())
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(')', Syn::ParenR, src))
+		.collect_n::<Syn, { Syn::IfGame as u16 }>()
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Unlike identifiers, keys may start with a digit.
+fn key(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("string key")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, stopping after a semicolon if there is one.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		primitive::none_of(";\r\n")
+			.repeated()
+			.at_least(1)
+			.then(primitive::just(';').or_not())
+			.ignored(),
+		primitive::just(';').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,85 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// LANGUAGE syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `[enu default]`; starts the section for one or more locales.
+	Header,
+	/// `KEY = "string" "continued";`, optionally preceded by an [`Syn::IfGame`].
+	Entry,
+	/// `$ifgame(doom, heretic)`, restricting the entry which it prefixes.
+	IfGame,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	/// The keyword `default`, only within a header.
+	KwDefault,
+	/// `$ifgame`.
+	KwIfGame,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `[`
+	BracketL,
+	/// `]`
+	BracketR,
+	/// `,`
+	Comma,
+	/// `=`
+	Eq,
+	/// `(`
+	ParenL,
+	/// `)`
+	ParenR,
+	/// `;`
+	Semicolon,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// LANGUAGE comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// String keys, locale codes, and game names.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by LANGUAGE.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,130 @@
+//! Merging string tables from multiple lumps and looking strings up.
+
+use std::collections::HashMap;
+
+use super::{ast, ParseTree};
+
+/// Every string defined by one or more LANGUAGE lumps, grouped by locale.
+///
+/// Lumps are added in load order; where two lumps (or two entries in one lump)
+/// define the same key for the same locale, the later definition wins.
+/// Entries preceding a file's first locale header belong to no locale, and so
+/// are ignored, as are entries whose `$ifgame` condition does not hold.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StringTable {
+	/// If `Some`, entries prefixed with `$ifgame` are only kept if this is
+	/// among their games. If `None`, all such entries are kept.
+	pub game: Option<String>,
+	/// Locale codes are lowercase, and keys are uppercase.
+	locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl StringTable {
+	#[must_use]
+	pub fn new(game: Option<&str>) -> Self {
+		Self {
+			game: game.map(str::to_string),
+			locales: HashMap::new(),
+		}
+	}
+
+	/// Merges every applicable entry in `tree` into this table.
+	pub fn add(&mut self, tree: &ParseTree) {
+		let mut locales: Vec<String> = vec![];
+
+		for root in tree.ast() {
+			match root {
+				ast::Root::Header(header) => {
+					locales = header
+						.locales()
+						.map(|token| token.text().to_ascii_lowercase())
+						.collect();
+				}
+				ast::Root::Entry(entry) => {
+					if let (Some(if_game), Some(game)) = (entry.if_game(), &self.game) {
+						if !if_game.matches(game) {
+							continue;
+						}
+					}
+
+					let key = entry.key().text().to_ascii_uppercase();
+					let value = entry.value();
+
+					for locale in &locales {
+						self.locales
+							.entry(locale.clone())
+							.or_default()
+							.insert(key.clone(), value.clone());
+					}
+				}
+			}
+		}
+	}
+
+	/// Looks `key` up case-insensitively, first in `locale` itself, then in
+	/// the language of a regional locale (e.g. `pt` for `ptb`), then in the
+	/// `default` section, and lastly in `enu`, as GZDoom does.
+	#[must_use]
+	pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+		self.find(locale, key, true)
+	}
+
+	/// Like [`Self::get`], but without falling back to the `default` section;
+	/// i.e. whether `key` has been translated for `locale`.
+	#[must_use]
+	pub fn defines(&self, locale: &str, key: &str) -> bool {
+		self.find(locale, key, false).is_some()
+	}
+
+	fn find(&self, locale: &str, key: &str, default: bool) -> Option<&str> {
+		let locale = locale.to_ascii_lowercase();
+		let key = key.to_ascii_uppercase();
+
+		let mut order = vec![locale.as_str()];
+
+		// Only three-letter codes are regional; `default` is not one.
+		if locale.len() == 3 && locale.bytes().all(|b| b.is_ascii_alphabetic()) {
+			order.push(&locale[..2]);
+		}
+
+		if default {
+			order.extend(["default", "enu"]);
+		}
+
+		order
+			.into_iter()
+			.find_map(|loc| self.locales.get(loc)?.get(&key))
+			.map(String::as_str)
+	}
+
+	/// Resolves a reference like `$HUSTR_1`, as used by MAPINFO, DECORATE, and
+	/// other lumps in place of literal text. Returns `None` if `reference` does
+	/// not start with `$` or if no string is found for it.
+	#[must_use]
+	pub fn lookup(&self, locale: &str, reference: &str) -> Option<&str> {
+		self.get(locale, reference.strip_prefix('$')?)
+	}
+
+	/// Returns `true` if any locale defines `key`, compared case-insensitively.
+	#[must_use]
+	pub fn contains(&self, key: &str) -> bool {
+		let key = key.to_ascii_uppercase();
+		self.locales
+			.values()
+			.any(|strings| strings.contains_key(&key))
+	}
+
+	/// Every locale with at least one string, in no particular order.
+	pub fn locales(&self) -> impl Iterator<Item = &str> {
+		self.locales.keys().map(String::as_str)
+	}
+
+	/// Every key defined by `locale` itself (without fallback), uppercase and
+	/// in no particular order.
+	pub fn keys(&self, locale: &str) -> impl Iterator<Item = &str> {
+		self.locales
+			.get(&locale.to_ascii_lowercase())
+			.into_iter()
+			.flat_map(|strings| strings.keys().map(String::as_str))
+	}
+}
@@ -0,0 +1,280 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Comments are allowed anywhere.
+[enu default]
+
+HUSTR_1 = "level 1: entryway";
+GOTREDCARD = "Picked up a red keycard.";
+E1TEXT =
+	"Once you beat the big badasses and\n"
+	/* Strings are concatenated. */ "clean out the moon base...";
+$ifgame(heretic, hexen) TXT_GOTBLUEKEY = "BLUE KEY";
+$IFGAME(doom) TXT_GOTBLUEKEY = "You got the blue key.";
+COLORED = "\cGRed\x41\101\"";
+
+[pt]
+HUSTR_1 = "nível 1: entrada";
+
+[ptb]
+GOTREDCARD = "Pegou um cartão vermelho.";
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 11);
+
+	let ast::Root::Header(header) = &roots[0] else {
+		panic!("expected a header, found: {:#?}", roots[0]);
+	};
+
+	assert!(header.is_default());
+	assert_eq!(
+		header
+			.locales()
+			.map(|t| t.text().to_string())
+			.collect::<Vec<_>>(),
+		["enu", "default"]
+	);
+
+	let ast::Root::Entry(e1text) = &roots[3] else {
+		panic!("expected an entry, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(e1text.key().text(), "E1TEXT");
+	assert_eq!(e1text.strings().count(), 2);
+	assert_eq!(
+		e1text.value(),
+		"Once you beat the big badasses and\nclean out the moon base..."
+	);
+
+	let ast::Root::Entry(bluekey) = &roots[4] else {
+		panic!("expected an entry, found: {:#?}", roots[4]);
+	};
+
+	let if_game = bluekey.if_game().unwrap();
+	assert_eq!(if_game.games().count(), 2);
+	assert!(if_game.matches("Hexen"));
+	assert!(!if_game.matches("doom"));
+
+	let ast::Root::Entry(colored) = &roots[6] else {
+		panic!("expected an entry, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(colored.value(), "\x1CGRedAA\"");
+}
+
+#[test]
+fn string_table() {
+	let pt = ParseTree::new(parse_recov(SOURCE).unwrap());
+	let patch = ParseTree::new(
+		parse_recov(
+			r#"
+[enu]
+HUSTR_1 = "level 1: the entryway";
+"#,
+		)
+		.unwrap(),
+	);
+
+	let mut table = StringTable::new(Some("doom"));
+	table.add(&pt);
+	table.add(&patch);
+
+	assert_eq!(
+		table.get("enu", "hustr_1"),
+		Some("level 1: the entryway"),
+		"later lumps should take precedence"
+	);
+	assert_eq!(table.get("default", "HUSTR_1"), Some("level 1: entryway"));
+	assert_eq!(table.get("ptb", "HUSTR_1"), Some("nível 1: entrada"));
+	assert_eq!(
+		table.get("ptb", "GOTREDCARD"),
+		Some("Pegou um cartão vermelho.")
+	);
+	assert_eq!(
+		table.get("pt", "GOTREDCARD"),
+		Some("Picked up a red keycard.")
+	);
+	assert_eq!(
+		table.lookup("fra", "$TXT_GOTBLUEKEY"),
+		Some("You got the blue key.")
+	);
+	assert_eq!(table.lookup("enu", "TXT_GOTBLUEKEY"), None);
+	assert_eq!(table.lookup("enu", "$NOPE"), None);
+	assert!(table.contains("e1text"));
+
+	let mut heretic = StringTable::new(Some("heretic"));
+	heretic.add(&pt);
+	assert_eq!(heretic.get("enu", "TXT_GOTBLUEKEY"), Some("BLUE KEY"));
+}
+
+#[test]
+fn locale_fallback() {
+	const SOURCE: &str = r#"
+[enu]
+GREETING = "Hello";
+FAREWELL = "Goodbye";
+
+[default]
+FAREWELL = "Bye";
+
+[de]
+GREETING = "Hallo";
+"#;
+
+	let mut table = StringTable::default();
+	table.add(&ParseTree::new(parse_recov(SOURCE).unwrap()));
+
+	// `default` is not a regional variant of `de`.
+	assert_eq!(table.get("default", "GREETING"), Some("Hello"));
+	assert_eq!(table.get("deu", "GREETING"), Some("Hallo"));
+	assert_eq!(table.get("fra", "GREETING"), Some("Hello"));
+	assert_eq!(table.get("fra", "FAREWELL"), Some("Bye"));
+	assert!(!table.defines("fra", "GREETING"));
+	assert!(table.defines("deu", "GREETING"));
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+[enu default]
+BROKEN = "missing semicolon"
+FINE = "still here";
+ALSO BROKEN;
+"#;
+
+	let rpt = parse_recov(SOURCE).unwrap();
+	assert_eq!(rpt.errors().len(), 2, "{:#?}", rpt.errors());
+	let pt = ParseTree::new(rpt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let mut table = StringTable::default();
+	table.add(&pt);
+	assert_eq!(table.get("enu", "FINE"), Some("still here"));
+}
+
+#[test]
+fn references() {
+	const MAPINFO: &str = r#"
+map MAP01 lookup "HUSTR_1" {
+	music = "$MUSIC_RUNNIN"
+}
+
+cluster 1 {
+	exittext = lookup, "E1TEXT"
+}
+
+skill baby {
+	Name = "$SKILL_BABBY"
+}
+"#;
+
+	const ZSCRIPT: &str = r#"
+class Test {
+	void Announce() {
+		Console.PrintF(StringTable.Localize("$GOTREDCARD") .. "$ %d");
+	}
+}
+"#;
+
+	let mut table = StringTable::default();
+	table.add(&ParseTree::new(parse_recov(SOURCE).unwrap()));
+
+	let mapinfo = ParseTree::new(crate::mapinfo::parse_recov(MAPINFO).unwrap());
+	let zscript = ParseTree::new(crate::zscript::parse_recov(ZSCRIPT).unwrap());
+
+	let mut check = RefCheck::new(&table, &["ptb"]);
+
+	let mapinfo_refs = mapinfo_refs(&mapinfo);
+	assert_eq!(
+		mapinfo_refs
+			.iter()
+			.map(|r| r.key.as_str())
+			.collect::<Vec<_>>(),
+		["HUSTR_1", "MUSIC_RUNNIN", "E1TEXT", "SKILL_BABBY"]
+	);
+
+	let found: Vec<_> = check
+		.check(&mapinfo_refs)
+		.into_iter()
+		.map(|issue| (&MAPINFO[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		found,
+		[
+			(
+				"\"$MUSIC_RUNNIN\"",
+				"`MUSIC_RUNNIN` is not defined by any LANGUAGE lump".to_string()
+			),
+			(
+				"\"E1TEXT\"",
+				"`E1TEXT` has no string for locale `ptb`".to_string()
+			),
+			(
+				"\"$SKILL_BABBY\"",
+				"`SKILL_BABBY` is not defined by any LANGUAGE lump".to_string()
+			),
+		]
+	);
+
+	let zscript_refs = zscript_refs(&zscript);
+	assert_eq!(zscript_refs.len(), 1);
+	assert!(check.check(&zscript_refs).is_empty());
+
+	// DECORATE has no parser yet, so this tree is built by hand.
+	let decorate = {
+		use crate::decorate::Syn;
+
+		let mut builder = rowan::GreenNodeBuilder::new();
+		builder.start_node(Syn::Root.into());
+
+		for (syn, text) in [
+			(Syn::Ident, "Tag"),
+			(Syn::Whitespace, " "),
+			(Syn::LitString, "\"$GOTREDCARD\""),
+			(Syn::Whitespace, "\n"),
+			(Syn::Ident, "Obituary"),
+			(Syn::Whitespace, " "),
+			(Syn::LitString, "\"$OB_NOPE\""),
+		] {
+			builder.token(syn.into(), text);
+		}
+
+		builder.finish_node();
+		crate::decorate::ParseTree::new(crate::decorate::RawParseTree::new(
+			builder.finish(),
+			vec![],
+		))
+	};
+
+	let decorate_refs = decorate_refs(&decorate);
+	assert_eq!(
+		decorate_refs
+			.iter()
+			.map(|r| r.key.as_str())
+			.collect::<Vec<_>>(),
+		["GOTREDCARD", "OB_NOPE"]
+	);
+	assert_eq!(check.check(&decorate_refs).len(), 1);
+
+	let language = ParseTree::new(parse_recov(SOURCE).unwrap());
+
+	let unused: Vec<_> = check
+		.unused(&language)
+		.into_iter()
+		.map(|issue| &SOURCE[issue.range])
+		.collect();
+
+	assert_eq!(unused, ["TXT_GOTBLUEKEY", "TXT_GOTBLUEKEY", "COLORED"]);
+}
@@ -23,17 +23,33 @@
 pub extern crate chumsky;
 pub extern crate rowan;
 
+pub mod acs;
+pub mod animdefs;
 pub mod cvarinfo;
+pub mod decaldef;
 pub mod decorate;
+pub mod dehacked;
+pub mod gldefs;
+pub mod keyconf;
+pub mod language;
+pub mod mapinfo;
+pub mod menudef;
+pub mod sbarinfo;
+pub mod sndinfo;
+pub mod textures;
+pub mod umapinfo;
+pub mod zscript;
 
 pub mod comb;
 pub mod ext;
 pub mod help;
+mod issue;
 mod repr;
 
 #[cfg(test)]
 mod test;
 
+pub use issue::*;
 pub use repr::*;
 
 /// Combinator parsers compose [green trees] manually instead of using the provided
@@ -0,0 +1,26 @@
+//! Parser and syntax trees for [MAPINFO](https://zdoom.org/wiki/MAPINFO),
+//! the lump which ZDoom-family source ports use to define maps, episodes,
+//! clusters, skills, and assorted game-wide settings.
+//!
+//! Both the "new" brace-delimited syntax and the legacy line-based syntax
+//! inherited from Hexen are supported; see [`parse_legacy`] for the latter and
+//! [`upgrade_legacy`] for converting it to the former.
+
+pub mod ast;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+mod upgrade;
+
+pub use parse::*;
+pub use syn::*;
+pub use upgrade::*;
+
+pub type FileParseTree = crate::repr::FileParseTree<Syn>;
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type IncludeTree = crate::repr::IncludeTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,595 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	AddDefaultMap(AddDefaultMapDef),
+	ClearEpisodes(ClearEpisodes),
+	Cluster(ClusterDef),
+	DefaultMap(DefaultMapDef),
+	DoomEdNums(DoomEdNumsDef),
+	Episode(EpisodeDef),
+	GameInfo(GameInfoDef),
+	Include(IncludeDirective),
+	Intermission(IntermissionDef),
+	Map(MapDef),
+	Skill(SkillDef),
+	SpawnNums(SpawnNumsDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::AddDefaultMapDef
+				| Syn::ClearEpisodes
+				| Syn::ClusterDef
+				| Syn::DefaultMapDef
+				| Syn::DoomEdNumsDef
+				| Syn::EpisodeDef
+				| Syn::GameInfoDef
+				| Syn::IncludeDirective
+				| Syn::IntermissionDef
+				| Syn::MapDef
+				| Syn::SkillDef
+				| Syn::SpawnNumsDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::AddDefaultMapDef => Some(Self::AddDefaultMap(AddDefaultMapDef(node))),
+			Syn::ClearEpisodes => Some(Self::ClearEpisodes(ClearEpisodes(node))),
+			Syn::ClusterDef => Some(Self::Cluster(ClusterDef(node))),
+			Syn::DefaultMapDef => Some(Self::DefaultMap(DefaultMapDef(node))),
+			Syn::DoomEdNumsDef => Some(Self::DoomEdNums(DoomEdNumsDef(node))),
+			Syn::EpisodeDef => Some(Self::Episode(EpisodeDef(node))),
+			Syn::GameInfoDef => Some(Self::GameInfo(GameInfoDef(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::IntermissionDef => Some(Self::Intermission(IntermissionDef(node))),
+			Syn::MapDef => Some(Self::Map(MapDef(node))),
+			Syn::SkillDef => Some(Self::Skill(SkillDef(node))),
+			Syn::SpawnNumsDef => Some(Self::SpawnNums(SpawnNumsDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::AddDefaultMap(inner) => &inner.0,
+			Self::ClearEpisodes(inner) => &inner.0,
+			Self::Cluster(inner) => &inner.0,
+			Self::DefaultMap(inner) => &inner.0,
+			Self::DoomEdNums(inner) => &inner.0,
+			Self::Episode(inner) => &inner.0,
+			Self::GameInfo(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::Intermission(inner) => &inner.0,
+			Self::Map(inner) => &inner.0,
+			Self::Skill(inner) => &inner.0,
+			Self::SpawnNums(inner) => &inner.0,
+		}
+	}
+}
+
+impl Root {
+	/// The brace-delimited body of this definition.
+	/// Only `None` for `clearepisodes` and `include`.
+	#[must_use]
+	pub fn block(&self) -> Option<Block> {
+		self.syntax().children().find_map(Block::cast)
+	}
+}
+
+/// The first token of `node` tagged `syn`, looking only at direct children.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// Every token of `node` tagged `syn`, looking only at direct children.
+fn tokens(node: &SyntaxNode, syn: Syn) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(move |n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::MapDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MapDef(SyntaxNode);
+
+simple_astnode!(Syn, MapDef, Syn::MapDef);
+
+impl MapDef {
+	/// The name of the map's marker lump, e.g. `MAP01` or `E1M1`.
+	#[must_use]
+	pub fn lump_name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn title(&self) -> Option<MapTitle> {
+		self.0.children().find_map(MapTitle::cast)
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::MapTitle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MapTitle(SyntaxNode);
+
+simple_astnode!(Syn, MapTitle, Syn::MapTitle);
+
+impl MapTitle {
+	/// If `true`, [`Self::text`] is a key into the LANGUAGE string table
+	/// rather than the title itself.
+	#[must_use]
+	pub fn is_lookup(&self) -> bool {
+		token(&self.0, Syn::KwLookup).is_some()
+	}
+
+	/// The content of the string literal, without quotation marks.
+	#[must_use]
+	pub fn text(&self) -> String {
+		Value(token(&self.0, Syn::LitString).unwrap())
+			.string()
+			.unwrap_or_default()
+	}
+}
+
+/// Wraps a node tagged [`Syn::DefaultMapDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultMapDef(SyntaxNode);
+
+simple_astnode!(Syn, DefaultMapDef, Syn::DefaultMapDef);
+
+impl DefaultMapDef {
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::AddDefaultMapDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AddDefaultMapDef(SyntaxNode);
+
+simple_astnode!(Syn, AddDefaultMapDef, Syn::AddDefaultMapDef);
+
+impl AddDefaultMapDef {
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::EpisodeDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EpisodeDef(SyntaxNode);
+
+simple_astnode!(Syn, EpisodeDef, Syn::EpisodeDef);
+
+impl EpisodeDef {
+	/// The lump name of the episode's first map.
+	#[must_use]
+	pub fn start_map(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The map used in place of [`Self::start_map`] by shareware versions.
+	#[must_use]
+	pub fn teaser(&self) -> Option<SyntaxToken> {
+		tokens(&self.0, Syn::Ident).nth(1)
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ClusterDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClusterDef(SyntaxNode);
+
+simple_astnode!(Syn, ClusterDef, Syn::ClusterDef);
+
+impl ClusterDef {
+	#[must_use]
+	pub fn number_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitInt).unwrap()
+	}
+
+	/// Returns `None` if the written number is out of range.
+	#[must_use]
+	pub fn number(&self) -> Option<i64> {
+		Value(self.number_token()).int()
+	}
+
+	/// Returns `true` if this was written with the legacy `clusterdef` keyword.
+	#[must_use]
+	pub fn is_legacy(&self) -> bool {
+		token(&self.0, Syn::KwClusterDef).is_some()
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::GameInfoDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct GameInfoDef(SyntaxNode);
+
+simple_astnode!(Syn, GameInfoDef, Syn::GameInfoDef);
+
+impl GameInfoDef {
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::SkillDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SkillDef(SyntaxNode);
+
+simple_astnode!(Syn, SkillDef, Syn::SkillDef);
+
+impl SkillDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ClearEpisodes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClearEpisodes(SyntaxNode);
+
+simple_astnode!(Syn, ClearEpisodes, Syn::ClearEpisodes);
+
+/// Wraps a node tagged [`Syn::DoomEdNumsDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DoomEdNumsDef(SyntaxNode);
+
+simple_astnode!(Syn, DoomEdNumsDef, Syn::DoomEdNumsDef);
+
+impl DoomEdNumsDef {
+	pub fn entries(&self) -> impl Iterator<Item = EdNum> {
+		self.0
+			.children()
+			.find_map(Block::cast)
+			.into_iter()
+			.flat_map(|block| block.0.children().filter_map(EdNum::cast))
+	}
+}
+
+/// Wraps a node tagged [`Syn::SpawnNumsDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SpawnNumsDef(SyntaxNode);
+
+simple_astnode!(Syn, SpawnNumsDef, Syn::SpawnNumsDef);
+
+impl SpawnNumsDef {
+	pub fn entries(&self) -> impl Iterator<Item = EdNum> {
+		self.0
+			.children()
+			.find_map(Block::cast)
+			.into_iter()
+			.flat_map(|block| block.0.children().filter_map(EdNum::cast))
+	}
+}
+
+/// Wraps a node tagged [`Syn::IntermissionDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IntermissionDef(SyntaxNode);
+
+simple_astnode!(Syn, IntermissionDef, Syn::IntermissionDef);
+
+impl IntermissionDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	#[must_use]
+	pub fn path_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The included path, without quotation marks.
+	#[must_use]
+	pub fn path(&self) -> String {
+		Value(self.path_token()).string().unwrap_or_default()
+	}
+}
+
+// Block contents //////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Block`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Block(SyntaxNode);
+
+simple_astnode!(Syn, Block, Syn::Block);
+
+impl Block {
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		self.0.children().filter_map(Property::cast)
+	}
+
+	/// Finds a property by case-insensitive key. If the key is given more
+	/// than once, the last occurrence is returned, since it takes precedence.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		self.properties()
+			.filter(|prop| prop.key().text().eq_ignore_ascii_case(key))
+			.last()
+	}
+
+	/// Only yields anything for the block of an [`IntermissionDef`].
+	pub fn actions(&self) -> impl Iterator<Item = IntermissionAction> {
+		self.0.children().filter_map(IntermissionAction::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Property(SyntaxNode);
+
+simple_astnode!(Syn, Property, Syn::Property);
+
+impl Property {
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `true` if the key is not followed by any values,
+	/// e.g. `nointermission`.
+	#[must_use]
+	pub fn is_flag(&self) -> bool {
+		self.values().next().is_none()
+	}
+
+	/// Every value following the `=`, in order, skipping the key itself.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+
+	/// Shorthand for `self.values().next()`.
+	#[must_use]
+	pub fn value(&self) -> Option<Value> {
+		self.values().next()
+	}
+
+	/// The nested properties following the values, if any; e.g. those of an
+	/// inline intermission like `next = endgame { pic = "..." }`.
+	#[must_use]
+	pub fn block(&self) -> Option<Block> {
+		self.0.children().find_map(Block::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::EdNum`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EdNum(SyntaxNode);
+
+simple_astnode!(Syn, EdNum, Syn::EdNum);
+
+impl EdNum {
+	#[must_use]
+	pub fn number_token(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `None` if the written number is out of range.
+	#[must_use]
+	pub fn number(&self) -> Option<i64> {
+		Value(self.number_token()).int()
+	}
+
+	/// The name of the actor class (or `none`) which the number is bound to.
+	#[must_use]
+	pub fn class_name(&self) -> Value {
+		self.values().next().unwrap()
+	}
+
+	/// Everything following the `=`; the class name, then any arguments.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+}
+
+/// Wraps a node tagged [`Syn::IntermissionAction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IntermissionAction(SyntaxNode);
+
+simple_astnode!(Syn, IntermissionAction, Syn::IntermissionAction);
+
+impl IntermissionAction {
+	/// The kind of action, e.g. `Image`, `Scroller`, `Cast`, or `TextScreen`.
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		self.0.children().find_map(Block::cast).unwrap()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a property value, with
+/// convenience functions for interpreting it. Its tag is one of
+/// [`Syn::LitString`], [`Syn::LitInt`], [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	/// Returns `None` if this is not an integer literal or is out of range.
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		if self.0.kind() != Syn::LitInt {
+			return None;
+		}
+
+		let text = self.0.text();
+
+		let (negative, unsigned) = match text.as_bytes()[0] {
+			b'-' => (true, &text[1..]),
+			b'+' => (false, &text[1..]),
+			_ => (false, text),
+		};
+
+		let digits = unsigned.trim_end_matches(['u', 'U', 'l', 'L']);
+
+		let magnitude =
+			if digits.len() > 2 && (digits.starts_with("0x") || digits.starts_with("0X")) {
+				i64::from_str_radix(&digits[2..], 16)
+			} else if digits.len() > 1 && digits.starts_with('0') {
+				i64::from_str_radix(&digits[1..], 8)
+			} else {
+				digits.parse::<i64>()
+			}
+			.ok()?;
+
+		Some(if negative { -magnitude } else { magnitude })
+	}
+
+	/// Also accepts integer literals, since MAPINFO does wherever it expects
+	/// a floating-point number.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		match self.0.kind() {
+			Syn::LitFloat => self
+				.0
+				.text()
+				.trim_end_matches(['f', 'F'])
+				.parse::<f64>()
+				.ok(),
+			Syn::LitInt => self.int().map(|i| i as f64),
+			_ => None,
+		}
+	}
+
+	/// If this is a bare word such as a lump name or class name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim. Useful for properties which accept either a quoted
+	/// string or a bare word, such as `next`.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,368 @@
+mod legacy;
+
+use chumsky::{primitive, recovery, recursive, text, Parser};
+use rowan::{ast::AstNode, GreenNode, SyntaxKind};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{ast, IncludeTree, RawParseTree, Syn};
+
+pub use self::legacy::*;
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any), so that one malformed definition does not hide the
+/// ones after it. All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Parses `root` (typically the `MAPINFO` or `ZMAPINFO` lump) with
+/// [`parse_recov`], and then every file it includes. `fetch` retrieves a
+/// file's source given a path as written in an `include` directive;
+/// see [`IncludeTree::new`] for details.
+///
+/// Returns `None` if `fetch` fails to retrieve `root`.
+///
+/// [`IncludeTree::new`]: crate::repr::IncludeTree::new
+#[must_use]
+pub fn parse_include_tree<F>(root: &str, fetch: F) -> Option<IncludeTree>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	IncludeTree::new(
+		root,
+		fetch,
+		|source| {
+			parse_recov(source)
+				.unwrap_or_else(|| RawParseTree::new(GreenNode::new(Syn::Root.into(), []), vec![]))
+		},
+		|zipper| {
+			zipper
+				.children()
+				.filter_map(ast::IncludeDirective::cast)
+				.map(|directive| (directive.path(), directive.syntax().text_range()))
+				.collect()
+		},
+	)
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		map_def(src),
+		episode_def(src),
+		cluster_def(src),
+		skill_def(src),
+		intermission_def(src),
+		bare_block_def(src, "defaultmap", Syn::KwDefaultMap, Syn::DefaultMapDef),
+		bare_block_def(
+			src,
+			"adddefaultmap",
+			Syn::KwAddDefaultMap,
+			Syn::AddDefaultMapDef,
+		),
+		bare_block_def(src, "gameinfo", Syn::KwGameInfo, Syn::GameInfoDef),
+		ednums_def(src, "doomednums", Syn::KwDoomEdNums, Syn::DoomEdNumsDef),
+		ednums_def(src, "spawnnums", Syn::KwSpawnNums, Syn::SpawnNumsDef),
+		comb::kw::<Syn>("clearepisodes", Syn::KwClearEpisodes, src)
+			.remap::<Syn, { Syn::ClearEpisodes as u16 }>(),
+		include_directive(src),
+	))
+	.labelled("top-level definition")
+}
+
+fn map_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let title = primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString).start_vec(),
+		comb::kw::<Syn>("lookup", Syn::KwLookup, src)
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(comb::string_lit::<Syn>(src, Syn::LitString)),
+	))
+	.collect_n::<Syn, { Syn::MapTitle as u16 }>();
+
+	comb::kw::<Syn>("map", Syn::KwMap, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(lump_name(src))
+		.chain_append(opt(trivia(src).chain_push(title)))
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.collect_n::<Syn, { Syn::MapDef as u16 }>()
+}
+
+fn episode_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let teaser = trivia(src)
+		.chain_push(comb::kw::<Syn>("teaser", Syn::KwTeaser, src))
+		.chain_append(trivia(src))
+		.chain_push(lump_name(src));
+
+	comb::kw::<Syn>("episode", Syn::KwEpisode, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(lump_name(src))
+		.chain_append(opt(teaser))
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.collect_n::<Syn, { Syn::EpisodeDef as u16 }>()
+}
+
+fn cluster_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("cluster", Syn::KwCluster, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(int_lit(src))
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.collect_n::<Syn, { Syn::ClusterDef as u16 }>()
+}
+
+fn skill_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("skill", Syn::KwSkill, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.collect_n::<Syn, { Syn::SkillDef as u16 }>()
+}
+
+fn intermission_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let action = ident(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.collect_n::<Syn, { Syn::IntermissionAction as u16 }>();
+
+	comb::kw::<Syn>("intermission", Syn::KwIntermission, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(block(src, primitive::choice((action, property(src)))))
+		.collect_n::<Syn, { Syn::IntermissionDef as u16 }>()
+}
+
+/// For `defaultmap`, `adddefaultmap`, and `gameinfo`,
+/// which are a keyword followed immediately by a block of properties.
+fn bare_block_def<'i>(
+	src: &'i str,
+	keyword: &'static str,
+	kw_syn: Syn,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	comb::kw::<Syn>(keyword, kw_syn, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(block(src, property(src)))
+		.map(move |elems| node(syn, elems))
+}
+
+/// For `doomednums` and `spawnnums`.
+fn ednums_def<'i>(
+	src: &'i str,
+	keyword: &'static str,
+	kw_syn: Syn,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	let ednum = int_lit(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "=", Syn::Eq))
+		.chain_append(trivia(src))
+		.chain_append(value_list(src))
+		.collect_n::<Syn, { Syn::EdNum as u16 }>();
+
+	comb::kw::<Syn>(keyword, kw_syn, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(block(src, ednum))
+		.map(move |elems| node(syn, elems))
+}
+
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("include", Syn::KwInclude, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+}
+
+// Block contents //////////////////////////////////////////////////////////////
+
+/// Braces around any number of `item`s, which may be separated by trivia.
+fn block<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	glyph(src, "{", Syn::BraceL)
+		.start_vec()
+		.chain_append(primitive::choice((wsp_ext(src), item)).repeated())
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.collect_n::<Syn, { Syn::Block as u16 }>()
+}
+
+/// The values may be followed by a block of nested properties, as in the
+/// inline intermission form `next = endgame { pic = "..." }`.
+fn property(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive::recursive(|property| {
+		let sub_block = trivia(src).chain_push(block(src, property));
+
+		let values = trivia(src)
+			.chain_push(glyph(src, "=", Syn::Eq))
+			.chain_append(trivia(src))
+			.chain_append(value_list(src))
+			.chain_append(opt(sub_block));
+
+		ident(src)
+			.start_vec()
+			.chain_append(opt(values))
+			.collect_n::<Syn, { Syn::Property as u16 }>()
+	})
+}
+
+/// One or more values, separated by commas.
+fn value_list(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let rest = trivia(src)
+		.chain_push(glyph(src, ",", Syn::Comma))
+		.chain_append(trivia(src))
+		.chain_push(value(src));
+
+	value(src)
+		.start_vec()
+		.chain_append(rest.repeated().flatten())
+}
+
+fn value(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		float_lit(src),
+		int_lit(src),
+		ident(src),
+	))
+	.labelled("value")
+}
+
+// Common combinators //////////////////////////////////////////////////////////
+
+fn node(syn: Syn, elems: Vec<ParseOut>) -> ParseOut {
+	ParseOut::Node(GreenNode::new(SyntaxKind::from(syn), elems))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Wraps the output of `parser` in a `Vec` if it succeeds, or returns an
+/// empty `Vec` otherwise. For optional sequences of syntax elements.
+fn opt<'s, P>(parser: P) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's,
+{
+	parser.or_not().map(Option::unwrap_or_default)
+}
+
+fn glyph<'i>(
+	src: &'i str,
+	string: &'static str,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	comb::just::<Syn, _>(string, syn, src)
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Unlike identifiers, lump names may start with a digit.
+fn lump_name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("lump name")
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::one_of("+-")
+		.or_not()
+		.then(comb::c_int::<Syn>(src, Syn::LitInt))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+fn float_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::one_of("+-")
+		.or_not()
+		.then(comb::c_float::<Syn>(src, Syn::LitFloat))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal")
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
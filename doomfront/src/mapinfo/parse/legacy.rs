@@ -0,0 +1,293 @@
+//! The line-based MAPINFO syntax inherited from Hexen, which ZDoom continued
+//! to extend before the introduction of the brace-delimited syntax.
+//!
+//! A definition begins with a header line (e.g. `map MAP01 "Entryway"`) and
+//! runs until the next header line. Each line in between holds one property:
+//! a key followed by its values, separated by whitespace.
+//!
+//! These produce the same node kinds as the modern syntax, so the same
+//! [AST](crate::mapinfo::ast) can be used to read either.
+
+use chumsky::{primitive, recovery, text, Error, Parser};
+use rowan::GreenToken;
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{glyph, lump_name, node, opt, RawParseTree, Syn};
+
+/// Words which begin a new definition, and so can never be property keys.
+const HEADERS: &[&str] = &[
+	"adddefaultmap",
+	"clearepisodes",
+	"clusterdef",
+	"defaultmap",
+	"episode",
+	"include",
+	"map",
+	"skill",
+];
+
+/// Hexen commands which stand alone at the top level rather than belonging
+/// to any definition.
+const HEXEN_GLOBALS: &[&str] = &[
+	"cd_end1_track",
+	"cd_end2_track",
+	"cd_end3_track",
+	"cd_intermission_track",
+	"cd_start_track",
+	"cd_title_track",
+];
+
+/// Like [`parse`](super::parse), but for the legacy syntax.
+pub fn parse_legacy(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// Like [`parse_recov`](super::parse_recov), but for the legacy syntax.
+/// Since definitions have no closing delimiter, recovery skips the offending
+/// line along with every line after it up until the next header line.
+#[must_use]
+pub fn parse_legacy_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let map_title = primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString).start_vec(),
+		comb::kw::<Syn>("lookup", Syn::KwLookup, src)
+			.start_vec()
+			.chain_append(inline_trivia(src))
+			.chain_push(comb::string_lit::<Syn>(src, Syn::LitString)),
+	))
+	.collect_n::<Syn, { Syn::MapTitle as u16 }>();
+
+	let map_def = comb::kw::<Syn>("map", Syn::KwMap, src)
+		.start_vec()
+		.chain_append(inline_trivia(src))
+		.chain_push(lump_name(src))
+		.chain_append(opt(inline_trivia(src).chain_push(map_title)))
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::MapDef as u16 }>();
+
+	let episode_def = comb::kw::<Syn>("episode", Syn::KwEpisode, src)
+		.start_vec()
+		.chain_append(inline_trivia(src))
+		.chain_push(lump_name(src))
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::EpisodeDef as u16 }>();
+
+	let cluster_def = comb::kw::<Syn>("clusterdef", Syn::KwClusterDef, src)
+		.start_vec()
+		.chain_append(inline_trivia(src))
+		.chain_push(word(src))
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::ClusterDef as u16 }>();
+
+	let skill_def = comb::kw::<Syn>("skill", Syn::KwSkill, src)
+		.start_vec()
+		.chain_append(inline_trivia(src))
+		.chain_push(lump_name(src))
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::SkillDef as u16 }>();
+
+	let default_map = comb::kw::<Syn>("defaultmap", Syn::KwDefaultMap, src)
+		.start_vec()
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::DefaultMapDef as u16 }>();
+
+	let add_default_map = comb::kw::<Syn>("adddefaultmap", Syn::KwAddDefaultMap, src)
+		.start_vec()
+		.chain_push(block(src))
+		.collect_n::<Syn, { Syn::AddDefaultMapDef as u16 }>();
+
+	let include = comb::kw::<Syn>("include", Syn::KwInclude, src)
+		.start_vec()
+		.chain_append(inline_trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>();
+
+	primitive::choice((
+		map_def,
+		episode_def,
+		cluster_def,
+		skill_def,
+		default_map,
+		add_default_map,
+		comb::kw::<Syn>("clearepisodes", Syn::KwClearEpisodes, src)
+			.remap::<Syn, { Syn::ClearEpisodes as u16 }>(),
+		include,
+		property(src, |key| {
+			HEXEN_GLOBALS.iter().any(|g| key.eq_ignore_ascii_case(g))
+		}),
+	))
+	.labelled("top-level definition")
+}
+
+/// Every property line following a definition's header, up until the next
+/// header. Trivia at the end of the block belongs to the block.
+fn block(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let prop = property(src, |key| {
+		!HEADERS
+			.iter()
+			.chain(HEXEN_GLOBALS)
+			.any(|h| key.eq_ignore_ascii_case(h))
+	});
+
+	primitive::choice((wsp_ext(src), prop))
+		.repeated()
+		.map(|elems| node(Syn::Block, elems))
+}
+
+/// A key accepted by `filter`, followed by values on the same line.
+/// Values may optionally be separated by commas.
+fn property<F>(src: &str, filter: F) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_
+where
+	F: Fn(&str) -> bool + Clone + 'static,
+{
+	let key = text::ident::<char, ParseError>()
+		.try_map(move |s, span| {
+			if filter(&s) {
+				Ok(())
+			} else {
+				Err(ParseError::expected_input_found(span, None, None))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	let value = inline_trivia(src)
+		.chain_append(opt(glyph(src, ",", Syn::Comma)
+			.start_vec()
+			.chain_append(inline_trivia(src))))
+		.chain_push(primitive::choice((
+			comb::string_lit::<Syn>(src, Syn::LitString),
+			word(src),
+		)));
+
+	key.start_vec()
+		.chain_append(value.repeated().flatten())
+		.collect_n::<Syn, { Syn::Property as u16 }>()
+}
+
+/// An unquoted value. Whether it is tagged as an integer, float,
+/// or identifier depends on its content.
+fn word(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| !c.is_whitespace() && !matches!(c, ';' | '"' | ',' | '{' | '}'))
+		.repeated()
+		.at_least(1)
+		.ignored()
+		.map_with_span(move |_, span: std::ops::Range<usize>| {
+			let text = &src[span];
+			ParseOut::Token(GreenToken::new(word_kind(text).into(), text))
+		})
+		.labelled("value")
+}
+
+fn word_kind(text: &str) -> Syn {
+	let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+
+	if unsigned.is_empty() {
+		return Syn::Ident;
+	}
+
+	let is_hex = unsigned.len() > 2
+		&& (unsigned.starts_with("0x") || unsigned.starts_with("0X"))
+		&& unsigned[2..].chars().all(|c| c.is_ascii_hexdigit());
+
+	if is_hex || unsigned.chars().all(|c| c.is_ascii_digit()) {
+		return Syn::LitInt;
+	}
+
+	let float_chars = unsigned
+		.chars()
+		.all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'));
+
+	if float_chars
+		&& unsigned.chars().any(|c| c.is_ascii_digit())
+		&& unsigned.parse::<f64>().is_ok()
+	{
+		return Syn::LitFloat;
+	}
+
+	Syn::Ident
+}
+
+fn comment(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::c_cpp_comment::<Syn>(src),
+		primitive::just(';')
+			.then(primitive::take_until(text::newline().or(primitive::end())))
+			.map_with_span(help::map_tok::<Syn, _>(src, Syn::Comment)),
+	))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comment(src), src)
+}
+
+/// Zero or more spaces, tabs, and block comments, i.e. trivia which can not
+/// end a line.
+fn inline_trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		primitive::one_of([' ', '\t'])
+			.repeated()
+			.at_least(1)
+			.ignored()
+			.map_with_span(help::map_tok::<Syn, _>(src, Syn::Whitespace)),
+		comb::c_comment::<Syn>(src),
+	))
+	.repeated()
+}
+
+/// Skips to the end of the line, and then past every line which does not
+/// start with a header keyword.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let line_start = primitive::choice((
+		text::ident::<char, ParseError>().try_map(|s, span| {
+			if HEADERS
+				.iter()
+				.chain(HEXEN_GLOBALS)
+				.any(|h| s.eq_ignore_ascii_case(h))
+			{
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(())
+			}
+		}),
+		primitive::filter(|c: &char| !c.is_whitespace() && !c.is_ascii_alphabetic() && *c != '_')
+			.ignored(),
+	));
+
+	let next_line = primitive::one_of([' ', '\t', '\r', '\n'])
+		.repeated()
+		.at_least(1)
+		.then(line_start)
+		.then(primitive::none_of("\r\n").repeated());
+
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.then(next_line.repeated())
+		.ignored()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
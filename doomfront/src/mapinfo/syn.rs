@@ -0,0 +1,152 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// MAPINFO syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes: top-level definitions ////////////////////////////////////////////
+	/// `adddefaultmap { ... }`, which amends the current map defaults.
+	AddDefaultMapDef,
+	/// `clearepisodes`, which discards all previously-defined episodes.
+	ClearEpisodes,
+	/// `cluster <number> { ... }`, or `clusterdef <number>` in the legacy syntax.
+	ClusterDef,
+	/// `defaultmap { ... }`, which replaces the current map defaults.
+	DefaultMapDef,
+	/// `doomednums { ... }`, mapping editor numbers to actor classes.
+	DoomEdNumsDef,
+	/// `episode <map> [teaser <map>] { ... }`.
+	EpisodeDef,
+	/// `gameinfo { ... }`.
+	GameInfoDef,
+	/// `include "path"`.
+	IncludeDirective,
+	/// `intermission <name> { ... }`.
+	IntermissionDef,
+	/// `map <lump> ["title" | lookup "key"] { ... }`.
+	MapDef,
+	/// `skill <name> { ... }`.
+	SkillDef,
+	/// `spawnnums { ... }`, mapping spawn numbers to actor classes.
+	SpawnNumsDef,
+
+	// Nodes: miscellaneous ////////////////////////////////////////////////////
+	/// Everything between and including a pair of braces. In the legacy
+	/// syntax, every property line up until the next definition.
+	Block,
+	/// A `<number> = <class> [, <arg>...]` pair in a `doomednums` or
+	/// `spawnnums` block.
+	EdNum,
+	/// A named sub-block within an `intermission` definition,
+	/// such as `Image { ... }` or `Cast { ... }`.
+	IntermissionAction,
+	/// The title following a map's lump name; either a string literal,
+	/// or the keyword `lookup` followed by a string literal.
+	MapTitle,
+	/// A key, optionally followed by `=` and a comma-separated list of values.
+	/// Keys given without values are flags. In the legacy syntax, the values
+	/// follow the key on the same line, separated by whitespace.
+	///
+	/// The values may be followed by a [`Syn::Block`] of nested properties,
+	/// as in `next = endgame { pic = "..." music = "..." }`.
+	///
+	/// In legacy trees, this can also appear at the top level for Hexen's
+	/// global CD track commands, such as `cd_start_track`.
+	Property,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	/// May have a leading sign.
+	LitFloat,
+	/// May have a leading sign.
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	/// The keyword `adddefaultmap`.
+	KwAddDefaultMap,
+	/// The keyword `clearepisodes`.
+	KwClearEpisodes,
+	/// The keyword `cluster`.
+	KwCluster,
+	/// The keyword `clusterdef`, which opens a cluster in the legacy syntax.
+	KwClusterDef,
+	/// The keyword `defaultmap`.
+	KwDefaultMap,
+	/// The keyword `doomednums`.
+	KwDoomEdNums,
+	/// The keyword `episode`.
+	KwEpisode,
+	/// The keyword `gameinfo`.
+	KwGameInfo,
+	/// The keyword `include`.
+	KwInclude,
+	/// The keyword `intermission`.
+	KwIntermission,
+	/// The keyword `lookup`, only used in map titles.
+	KwLookup,
+	/// The keyword `map`.
+	KwMap,
+	/// The keyword `skill`.
+	KwSkill,
+	/// The keyword `spawnnums`.
+	KwSpawnNums,
+	/// The keyword `teaser`, only used in episode headers.
+	KwTeaser,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `,`
+	Comma,
+	/// `=`
+	Eq,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// MAPINFO comments use C/C++ syntax and are treated like whitespace.
+	/// The legacy syntax also allows line comments starting with `;`.
+	Comment,
+	/// Property keys, lump names, class names, and other bare words.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by MAPINFO.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
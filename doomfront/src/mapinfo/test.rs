@@ -0,0 +1,424 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::{ast, *};
+
+#[test]
+fn smoke() {
+	const SOURCE: &str = r#"
+// Comments are allowed anywhere.
+include "mapinfo/episodes.txt"
+
+clearepisodes
+
+episode MAP01 teaser MAP01B {
+	name = "Hell on Earth"
+	key = "h"
+}
+
+defaultmap {
+	sky1 = "SKY1", 0.1
+	/* Block comments too. */
+	nojump
+}
+
+adddefaultmap { aircontrol = 0.25 }
+
+map MAP01 lookup "HUSTR_1" {
+	levelnum = 1
+	next = "MAP02"
+	secretnext = MAP31
+	par = 30
+	gravity = -800.0
+	music = "$MUSIC_RUNNIN"
+	nointermission
+}
+
+map 1SECRET "Secret Level" {}
+
+cluster 5 {
+	exittext = "Line one,",
+		"line two."
+	music = "D_READ_M"
+}
+
+skill baby {
+	AmmoFactor = 2
+	Name = "$SKILL_BABY"
+	MustConfirm
+}
+
+gameinfo {
+	playerclasses = "DoomPlayer", "Marine"
+}
+
+doomednums {
+	9001 = MapSpot
+	14001 = AmbientSound, 1
+}
+
+spawnnums {
+	1 = ShotgunGuy
+}
+
+intermission Doom_Cast {
+	Cast {
+		CastClass = "ZombieMan"
+		CastName = "$CC_ZOMBIE"
+	}
+	Link = Doom_Cast2
+}
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 13);
+
+	let ast::Root::Include(include) = &roots[0] else {
+		panic!("expected an include directive, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(include.path(), "mapinfo/episodes.txt");
+	assert!(matches!(roots[1], ast::Root::ClearEpisodes(_)));
+
+	let ast::Root::Episode(episode) = &roots[2] else {
+		panic!("expected an episode, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(episode.start_map().text(), "MAP01");
+	assert_eq!(episode.teaser().unwrap().text(), "MAP01B");
+
+	assert_eq!(
+		episode
+			.block()
+			.property("NAME")
+			.unwrap()
+			.value()
+			.unwrap()
+			.string()
+			.unwrap(),
+		"Hell on Earth"
+	);
+
+	let defaults = roots[3].block().unwrap();
+	let sky: Vec<_> = defaults.property("sky1").unwrap().values().collect();
+	assert_eq!(sky[0].string().unwrap(), "SKY1");
+	assert_eq!(sky[1].float().unwrap(), 0.1);
+	assert!(defaults.property("nojump").unwrap().is_flag());
+
+	let ast::Root::Map(map01) = &roots[5] else {
+		panic!("expected a map, found: {:#?}", roots[5]);
+	};
+
+	assert_eq!(map01.lump_name().text(), "MAP01");
+	let title = map01.title().unwrap();
+	assert!(title.is_lookup());
+	assert_eq!(title.text(), "HUSTR_1");
+
+	let block = map01.block();
+	assert_eq!(
+		block.property("levelnum").unwrap().value().unwrap().int(),
+		Some(1)
+	);
+	assert_eq!(
+		block.property("next").unwrap().value().unwrap().text(),
+		"MAP02"
+	);
+	assert_eq!(
+		block
+			.property("secretnext")
+			.unwrap()
+			.value()
+			.unwrap()
+			.ident(),
+		Some("MAP31")
+	);
+	assert_eq!(
+		block.property("gravity").unwrap().value().unwrap().float(),
+		Some(-800.0)
+	);
+	assert_eq!(
+		block.property("par").unwrap().value().unwrap().float(),
+		Some(30.0)
+	);
+	assert!(block.property("nointermission").unwrap().is_flag());
+
+	let ast::Root::Map(secret) = &roots[6] else {
+		panic!("expected a map, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(secret.lump_name().text(), "1SECRET");
+	assert!(!secret.title().unwrap().is_lookup());
+	assert_eq!(secret.block().properties().count(), 0);
+
+	let ast::Root::Cluster(cluster) = &roots[7] else {
+		panic!("expected a cluster, found: {:#?}", roots[7]);
+	};
+
+	assert_eq!(cluster.number(), Some(5));
+	assert_eq!(
+		cluster
+			.block()
+			.property("exittext")
+			.unwrap()
+			.values()
+			.count(),
+		2
+	);
+
+	let ast::Root::DoomEdNums(ednums) = &roots[10] else {
+		panic!("expected doomednums, found: {:#?}", roots[10]);
+	};
+
+	let entries: Vec<_> = ednums.entries().collect();
+	assert_eq!(entries[0].number(), Some(9001));
+	assert_eq!(entries[0].class_name().ident(), Some("MapSpot"));
+	assert_eq!(entries[1].values().nth(1).unwrap().int(), Some(1));
+
+	let ast::Root::Intermission(inter) = &roots[12] else {
+		panic!("expected an intermission, found: {:#?}", roots[12]);
+	};
+
+	assert_eq!(inter.name().text(), "Doom_Cast");
+	let action = inter.block().actions().next().unwrap();
+	assert_eq!(action.kind().text(), "Cast");
+	assert!(action.block().property("castclass").is_some());
+	assert_eq!(
+		inter
+			.block()
+			.property("link")
+			.unwrap()
+			.value()
+			.unwrap()
+			.ident(),
+		Some("Doom_Cast2")
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+map MAP01 "Entryway" {
+	levelnum = 1
+}
+
+map MAP02 "Underhalls" {
+	levelnum = = 2
+	sky1 = { "SKY1" }
+}
+
+cluster 1 { music = "D_READ_M" }
+
+this is not a definition
+
+map MAP03 "The Gantlet" {
+	levelnum = 3
+}
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert!(pt.any_errors());
+	assert!(parse(SOURCE).is_err());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let names: Vec<_> = pt
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Map(map) => Some(map.lump_name().text().to_string()),
+			ast::Root::Cluster(_) => Some("cluster".to_string()),
+			_ => None,
+		})
+		.collect();
+
+	assert_eq!(names, ["MAP01", "cluster", "MAP03"]);
+}
+
+#[test]
+fn inline_intermission() {
+	const SOURCE: &str = r#"
+map MAP30 "Icon of Sin" {
+	next = endgame {
+		pic = "CREDIT"
+		music = "D_VICTOR"
+	}
+	secretnext = MAP31
+}
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let Some(ast::Root::Map(map30)) = pt.ast().next() else {
+		panic!("expected a map");
+	};
+
+	let next = map30.block().property("next").unwrap();
+	assert_eq!(next.value().unwrap().ident(), Some("endgame"));
+	let endgame = next.block().unwrap();
+
+	assert_eq!(
+		endgame
+			.property("pic")
+			.unwrap()
+			.value()
+			.unwrap()
+			.string()
+			.unwrap(),
+		"CREDIT"
+	);
+
+	assert_eq!(
+		endgame
+			.property("music")
+			.unwrap()
+			.value()
+			.unwrap()
+			.string()
+			.unwrap(),
+		"D_VICTOR"
+	);
+
+	let secret = map30.block().property("secretnext").unwrap();
+	assert_eq!(secret.value().unwrap().ident(), Some("MAP31"));
+	assert!(secret.block().is_none());
+}
+
+#[test]
+fn include_tree() {
+	let tree = parse_include_tree("MAPINFO", |path| match path {
+		"MAPINFO" => Some("include \"mapinfo/maps.txt\"\ngameinfo {}".to_string()),
+		"mapinfo/maps.txt" => Some("map MAP01 \"One\" {}".to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	assert_eq!(tree.files.len(), 2);
+	assert_eq!(tree.files[1].path(), "mapinfo/maps.txt");
+
+	for file in &tree.files {
+		assert!(!file.any_errors());
+	}
+}
+
+#[test]
+fn legacy() {
+	const SOURCE: &str = r#"
+; Hexen-style MAPINFO.
+cd_start_track 2
+
+map 1 "Winnowing Hall"
+warptrans 1
+next 3
+secretnext 9
+cluster 1
+sky1 SKY2 1
+sky2 SKY3 0
+lightning
+nosoundclipping
+
+map MAP02 lookup "HUSTR_2"
+warptrans 3
+music D_STALKS ; Trailing comment.
+titlepatch CWILV01
+
+clusterdef 1
+exittext lookup "C1TEXT"
+hub
+
+episode MAP01
+lookup "EPI_1"
+key "k"
+"#;
+
+	let pt = parse_legacy_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse_legacy(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 4);
+
+	let ast::Root::Map(map1) = &roots[0] else {
+		panic!("expected a map, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(map1.lump_name().text(), "1");
+	assert_eq!(map1.title().unwrap().text(), "Winnowing Hall");
+	let block = map1.block();
+	assert_eq!(
+		block.property("next").unwrap().value().unwrap().int(),
+		Some(3)
+	);
+	let sky: Vec<_> = block.property("sky1").unwrap().values().collect();
+	assert_eq!(sky[0].ident(), Some("SKY2"));
+	assert_eq!(sky[1].int(), Some(1));
+	assert!(block.property("lightning").unwrap().is_flag());
+
+	let ast::Root::Cluster(cluster) = &roots[2] else {
+		panic!("expected a cluster, found: {:#?}", roots[2]);
+	};
+
+	assert!(cluster.is_legacy());
+	assert_eq!(cluster.number(), Some(1));
+
+	let (upgraded, issues) = upgrade_legacy(&pt);
+
+	assert_eq!(issues.len(), 4, "{issues:#?}");
+	assert!(issues.iter().all(|issue| !issue.is_error()));
+	assert!(issues[0].message.contains("cd_start_track"));
+	assert!(issues[1].message.contains("warptrans 9"));
+	assert!(issues[2].message.contains("scroll speed of `sky1`"));
+	assert!(issues[3].message.contains("nosoundclipping"));
+
+	assert_eq!(
+		upgraded,
+		r#"// Hexen-style MAPINFO.
+
+// cd_start_track 2
+
+map MAP01 "Winnowing Hall"
+{
+	warptrans = 1
+	next = "MAP02"
+	// secretnext 9
+	cluster = 1
+	sky1 = "SKY2" // Hexen scroll speed: 1
+	sky2 = "SKY3"
+	lightning
+	// nosoundclipping
+}
+
+map MAP02 lookup "HUSTR_2"
+{
+	warptrans = 3
+	music = "D_STALKS"
+	// Trailing comment.
+	titlepatch = "CWILV01"
+}
+
+cluster 1
+{
+	exittext = "$C1TEXT"
+	hub
+}
+
+episode MAP01
+{
+	name = "$EPI_1"
+	key = "k"
+}
+"#
+	);
+
+	let reparsed = parse(&upgraded).unwrap();
+	let reparsed = ParseTree::new(reparsed);
+	assert_eq!(reparsed.ast().count(), 4);
+}
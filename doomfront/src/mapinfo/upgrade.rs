@@ -0,0 +1,312 @@
+//! Conversion of legacy MAPINFO to the brace-delimited syntax.
+
+use std::{collections::HashMap, fmt::Write};
+
+use rowan::{ast::AstNode, NodeOrToken};
+
+use crate::Issue;
+
+use super::{ast, ParseTree, Syn, SyntaxNode, SyntaxToken};
+
+/// Legacy property keys which have no effect in ZDoom and no counterpart in
+/// the new syntax, along with the reason given to the user.
+const OBSOLETE: &[(&str, &str)] = &[
+	("nosoundclipping", "it has no effect in ZDoom"),
+	("cd_end1_track", "Hexen's CD audio commands are unsupported"),
+	("cd_end2_track", "Hexen's CD audio commands are unsupported"),
+	("cd_end3_track", "Hexen's CD audio commands are unsupported"),
+	(
+		"cd_intermission_track",
+		"Hexen's CD audio commands are unsupported",
+	),
+	(
+		"cd_start_track",
+		"Hexen's CD audio commands are unsupported",
+	),
+	(
+		"cd_title_track",
+		"Hexen's CD audio commands are unsupported",
+	),
+];
+
+/// Rewrites a tree produced by [`parse_legacy`] or [`parse_legacy_recov`]
+/// in the new brace-delimited syntax.
+///
+/// Properties without a direct equivalent are kept as comments, and a warning
+/// is issued for each of them. If any map is identified by number, the file
+/// is taken to be Hexen's, in which `next` and `secretnext` give `warptrans`
+/// numbers; these are rewritten into the lump names of the maps they refer to. Input which failed to parse is dropped.
+/// Comments are kept, but other formatting is not.
+///
+/// [`parse_legacy`]: super::parse_legacy
+/// [`parse_legacy_recov`]: super::parse_legacy_recov
+#[must_use]
+pub fn upgrade_legacy(tree: &ParseTree) -> (String, Vec<Issue>) {
+	let mut upgrader = Upgrader {
+		output: String::new(),
+		issues: vec![],
+		hexen: false,
+		warptrans: HashMap::new(),
+	};
+
+	for root in tree.ast() {
+		let ast::Root::Map(mapdef) = root else {
+			continue;
+		};
+
+		upgrader.hexen |= mapdef.lump_name().text().parse::<u8>().is_ok();
+
+		if let Some(num) = mapdef
+			.block()
+			.property("warptrans")
+			.and_then(|prop| prop.value())
+			.and_then(|value| value.int())
+		{
+			upgrader.warptrans.insert(num, map_lump(&mapdef));
+		}
+	}
+
+	for elem in tree.zipper().children_with_tokens() {
+		match elem {
+			NodeOrToken::Token(token) => {
+				if token.kind() == Syn::Comment {
+					upgrader.comment(&token, "");
+				}
+			}
+			NodeOrToken::Node(node) => upgrader.definition(node),
+		}
+	}
+
+	(upgrader.output, upgrader.issues)
+}
+
+struct Upgrader {
+	output: String,
+	issues: Vec<Issue>,
+	/// Whether any map is identified by number, as in Hexen.
+	hexen: bool,
+	/// Maps' `warptrans` numbers to their lump names.
+	warptrans: HashMap<i64, String>,
+}
+
+impl Upgrader {
+	fn definition(&mut self, node: SyntaxNode) {
+		if !self.output.is_empty() {
+			self.output.push('\n');
+		}
+
+		let Some(root) = ast::Root::cast(node.clone()) else {
+			// A Hexen global such as `cd_start_track`.
+			if let Some(prop) = ast::Property::cast(node) {
+				self.property(&prop, "");
+			}
+
+			return;
+		};
+
+		let header = match &root {
+			ast::Root::Include(include) => {
+				let _ = writeln!(self.output, "include {}", include.path_token().text());
+				return;
+			}
+			ast::Root::ClearEpisodes(_) => {
+				self.output.push_str("clearepisodes\n");
+				return;
+			}
+			ast::Root::Map(mapdef) => {
+				let lump = map_lump(mapdef);
+
+				match mapdef.title() {
+					Some(title) => format!("map {lump} {}", title.syntax().text()),
+					None => format!("map {lump}"),
+				}
+			}
+			ast::Root::Episode(episode) => format!("episode {}", episode.start_map().text()),
+			ast::Root::Cluster(cluster) => format!("cluster {}", cluster.number_token().text()),
+			ast::Root::Skill(skill) => format!("skill {}", skill.name().text()),
+			ast::Root::DefaultMap(_) => "defaultmap".to_string(),
+			ast::Root::AddDefaultMap(_) => "adddefaultmap".to_string(),
+			ast::Root::DoomEdNums(_)
+			| ast::Root::GameInfo(_)
+			| ast::Root::Intermission(_)
+			| ast::Root::SpawnNums(_) => {
+				// Already in the new syntax; nothing to convert.
+				let _ = writeln!(self.output, "{}", root.syntax().text());
+				return;
+			}
+		};
+
+		let _ = writeln!(self.output, "{header}\n{{");
+
+		if let Some(block) = root.block() {
+			for elem in block.syntax().children_with_tokens() {
+				match elem {
+					NodeOrToken::Token(token) => {
+						if token.kind() == Syn::Comment {
+							self.comment(&token, "\t");
+						}
+					}
+					NodeOrToken::Node(node) => {
+						if let Some(prop) = ast::Property::cast(node) {
+							self.property(&prop, "\t");
+						}
+					}
+				}
+			}
+		}
+
+		self.output.push_str("}\n");
+	}
+
+	fn property(&mut self, prop: &ast::Property, indent: &str) {
+		let key = prop.key();
+		let legacy_text = prop.syntax().text().to_string();
+
+		if let Some((_, reason)) = OBSOLETE
+			.iter()
+			.find(|(k, _)| key.text().eq_ignore_ascii_case(k))
+		{
+			self.unconvertible(&key, reason, indent, &legacy_text);
+			return;
+		}
+
+		let values: Vec<_> = prop.values().collect();
+		let is_any = |keys: &[&str]| keys.iter().any(|k| key.text().eq_ignore_ascii_case(k));
+
+		if self.hexen && is_any(&["next", "secretnext"]) {
+			if let Some(num) = values.first().and_then(|v| v.int()) {
+				match self.warptrans.get(&num) {
+					Some(lump) => {
+						let _ = writeln!(self.output, "{indent}{} = \"{lump}\"", key.text());
+					}
+					None => self.unconvertible(
+						&key,
+						&format!("no map in this file has `warptrans {num}`"),
+						indent,
+						&legacy_text,
+					),
+				}
+
+				return;
+			}
+		}
+
+		// Hexen's sky scrolling speeds are in different units, so only the
+		// texture is kept, and any non-zero speed is left in a comment.
+		if self.hexen && is_any(&["sky1", "sky2"]) && values.len() > 1 {
+			let speed = &values[1];
+
+			let _ = write!(
+				self.output,
+				"{indent}{} = {}",
+				key.text(),
+				value_text(values[0].syntax())
+			);
+
+			if speed.float() == Some(0.0) {
+				self.output.push('\n');
+			} else {
+				let _ = writeln!(
+					self.output,
+					" // Hexen scroll speed: {}",
+					speed.syntax().text()
+				);
+
+				self.issues.push(Issue::warning(
+					speed.syntax().text_range(),
+					format!(
+						"the scroll speed of `{}` is in different units in the new MAPINFO syntax, \
+						and so was left out",
+						key.text()
+					),
+				));
+			}
+
+			return;
+		}
+
+		// Episodes give their title's LANGUAGE key with `lookup "KEY"`.
+		let parent_kind = prop
+			.syntax()
+			.parent()
+			.and_then(|p| p.parent())
+			.map(|p| p.kind());
+
+		let (key_text, values) =
+			if key.text().eq_ignore_ascii_case("lookup") && parent_kind == Some(Syn::EpisodeDef) {
+				(
+					"name".to_string(),
+					values
+						.iter()
+						.map(|v| format!("\"${}\"", v.text()))
+						.collect(),
+				)
+			} else if values.len() == 2
+				&& values[0]
+					.ident()
+					.is_some_and(|i| i.eq_ignore_ascii_case("lookup"))
+			{
+				// e.g. `exittext lookup KEY`.
+				(
+					key.text().to_string(),
+					vec![format!("\"${}\"", values[1].text())],
+				)
+			} else {
+				(
+					key.text().to_string(),
+					values.iter().map(|v| value_text(v.syntax())).collect(),
+				)
+			};
+
+		if values.is_empty() {
+			let _ = writeln!(self.output, "{indent}{key_text}");
+		} else {
+			let _ = writeln!(self.output, "{indent}{key_text} = {}", values.join(", "));
+		}
+	}
+
+	/// Issues a warning about `key` and keeps its property as a comment.
+	fn unconvertible(&mut self, key: &SyntaxToken, reason: &str, indent: &str, legacy_text: &str) {
+		self.issues.push(Issue::warning(
+			key.text_range(),
+			format!(
+				"`{}` has no direct equivalent in the new MAPINFO syntax, since {reason}",
+				key.text()
+			),
+		));
+
+		let _ = writeln!(self.output, "{indent}// {legacy_text}");
+	}
+
+	fn comment(&mut self, token: &SyntaxToken, indent: &str) {
+		let text = token.text().trim_end();
+
+		match text.strip_prefix(';') {
+			Some(rest) => {
+				let _ = writeln!(self.output, "{indent}//{rest}");
+			}
+			None => {
+				let _ = writeln!(self.output, "{indent}{text}");
+			}
+		}
+	}
+}
+
+/// Hexen identifies maps by number.
+fn map_lump(mapdef: &ast::MapDef) -> String {
+	let lump = mapdef.lump_name();
+
+	match lump.text().parse::<u8>() {
+		Ok(num) => format!("MAP{num:02}"),
+		Err(_) => lump.text().to_string(),
+	}
+}
+
+/// Bare words (lump names, mostly) are quoted, since the new syntax does not
+/// allow identifiers to start with a digit or contain most punctuation.
+fn value_text(token: &SyntaxToken) -> String {
+	match token.kind() {
+		Syn::Ident => format!("\"{}\"", token.text().replace('\\', "\\\\")),
+		_ => token.text().to_string(),
+	}
+}
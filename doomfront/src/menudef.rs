@@ -0,0 +1,24 @@
+//! Parser and syntax trees for [MENUDEF](https://zdoom.org/wiki/MENUDEF),
+//! the lump which ZDoom-family source ports use to lay out their menus.
+//!
+//! List menus hold the items of the main menu and others like it, while option
+//! menus hold the settings bound to CVars. [`check_menus`] confirms that each
+//! `Submenu` leads to a menu which is defined and, given a list of CVars, that
+//! items refer to CVars which exist.
+
+pub mod ast;
+mod check;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use check::*;
+pub use parse::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,375 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Conditional(Conditional),
+	Defaults(DefaultsDef),
+	Include(IncludeDirective),
+	Menu(MenuDef),
+	OptionValues(OptionValuesDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::Conditional
+				| Syn::DefaultsDef
+				| Syn::IncludeDirective
+				| Syn::MenuDef
+				| Syn::OptionValuesDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::Conditional => Some(Self::Conditional(Conditional(node))),
+			Syn::DefaultsDef => Some(Self::Defaults(DefaultsDef(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::MenuDef => Some(Self::Menu(MenuDef(node))),
+			Syn::OptionValuesDef => Some(Self::OptionValues(OptionValuesDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Conditional(inner) => &inner.0,
+			Self::Defaults(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::Menu(inner) => &inner.0,
+			Self::OptionValues(inner) => &inner.0,
+		}
+	}
+}
+
+/// The first token of `node` tagged `syn`, looking only at direct children.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == syn))
+}
+
+/// The token naming a menu or option list, which may be quoted or not.
+fn name(node: &SyntaxNode) -> Option<SyntaxToken> {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.skip(1)
+		.find(|t| matches!(t.kind(), Syn::Ident | Syn::LitString))
+}
+
+/// Every item in `node`'s block, including those in conditional blocks.
+fn items(node: &SyntaxNode) -> impl Iterator<Item = Item> {
+	node.descendants().filter_map(Item::cast)
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::MenuDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MenuDef(SyntaxNode);
+
+simple_astnode!(Syn, MenuDef, Syn::MenuDef);
+
+impl MenuDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwListMenu`]
+	/// - [`Syn::KwOptionMenu`]
+	/// - [`Syn::KwAddListMenu`]
+	/// - [`Syn::KwAddOptionMenu`]
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// `true` for `addlistmenu` and `addoptionmenu`, which append items to
+	/// a menu defined elsewhere instead of defining a new one.
+	#[must_use]
+	pub fn is_addition(&self) -> bool {
+		matches!(
+			self.keyword().kind(),
+			Syn::KwAddListMenu | Syn::KwAddOptionMenu
+		)
+	}
+
+	#[must_use]
+	pub fn name(&self) -> Value {
+		Value(name(&self.0).unwrap())
+	}
+
+	/// Every word between the name and the opening brace, e.g. `protected`.
+	pub fn flags(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.take_while(|t| t.kind() != Syn::BraceL)
+			.filter(|t| t.kind() == Syn::Ident)
+			.skip(usize::from(self.name().0.kind() == Syn::Ident))
+	}
+
+	/// Every item in the menu, including those in conditional blocks.
+	pub fn items(&self) -> impl Iterator<Item = Item> {
+		items(&self.0)
+	}
+
+	/// Only the `ifgame` and `ifoption` blocks directly within this menu.
+	pub fn conditionals(&self) -> impl Iterator<Item = Conditional> {
+		self.0.children().filter_map(Conditional::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::DefaultsDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultsDef(SyntaxNode);
+
+simple_astnode!(Syn, DefaultsDef, Syn::DefaultsDef);
+
+impl DefaultsDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwDefaultListMenu`]
+	/// - [`Syn::KwDefaultOptionMenu`]
+	/// - [`Syn::KwOptionMenuSettings`]
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// e.g. `Font`, `Linespacing`, `Selector`.
+	pub fn items(&self) -> impl Iterator<Item = Item> {
+		items(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::OptionValuesDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct OptionValuesDef(SyntaxNode);
+
+simple_astnode!(Syn, OptionValuesDef, Syn::OptionValuesDef);
+
+impl OptionValuesDef {
+	/// Either [`Syn::KwOptionValue`] or [`Syn::KwOptionString`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> Value {
+		Value(name(&self.0).unwrap())
+	}
+
+	pub fn pairs(&self) -> impl Iterator<Item = OptionPair> {
+		self.0.children().filter_map(OptionPair::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::OptionPair`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct OptionPair(SyntaxNode);
+
+simple_astnode!(Syn, OptionPair, Syn::OptionPair);
+
+impl OptionPair {
+	/// What gets assigned to the CVar when this option is picked.
+	#[must_use]
+	pub fn value(&self) -> Value {
+		self.values().next().unwrap()
+	}
+
+	/// What gets shown in the menu; may be a `$`-prefixed LANGUAGE identifier.
+	#[must_use]
+	pub fn text(&self) -> Value {
+		self.values().nth(1).unwrap()
+	}
+
+	fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+}
+
+/// Wraps a node tagged [`Syn::Conditional`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Conditional(SyntaxNode);
+
+simple_astnode!(Syn, Conditional, Syn::Conditional);
+
+impl Conditional {
+	/// Either [`Syn::KwIfGame`] or [`Syn::KwIfOption`].
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Game names for `ifgame` (e.g. `doom`, `heretic`),
+	/// or option names for `ifoption` (e.g. `readthis`, `swapmenu`).
+	pub fn conditions(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.take_while(|t| t.kind() != Syn::ParenR)
+			.filter(|t| t.kind() == Syn::Ident)
+	}
+
+	/// Only non-empty for a conditional block at the top level of a file.
+	pub fn definitions(&self) -> impl Iterator<Item = Root> {
+		self.0.children().filter_map(Root::cast)
+	}
+
+	/// Every item in the block, including those in nested conditional blocks.
+	pub fn items(&self) -> impl Iterator<Item = Item> {
+		items(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	#[must_use]
+	pub fn path_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The path without its quotation marks.
+	#[must_use]
+	pub fn path(&self) -> String {
+		Value(self.path_token()).string().unwrap_or_default()
+	}
+}
+
+// Items ///////////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Item`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Item(SyntaxNode);
+
+simple_astnode!(Syn, Item, Syn::Item);
+
+impl Item {
+	/// e.g. `Option`, `Slider`, `Submenu`, `StaticText`, `Position`.
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Every argument following the key, in order, skipping commas.
+	pub fn args(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+
+	/// Shorthand for `self.args().nth(index)`.
+	#[must_use]
+	pub fn arg(&self, index: usize) -> Option<Value> {
+		self.args().nth(index)
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a value, with convenience functions
+/// for interpreting it. Its tag is one of [`Syn::LitString`], [`Syn::LitInt`],
+/// [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// Also accepts integer literals, e.g. for slider bounds.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		matches!(self.0.kind(), Syn::LitFloat | Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as a font or color name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,166 @@
+//! Checking that menu items refer to CVars and menus which exist.
+
+use std::collections::HashSet;
+
+use rowan::ast::AstNode;
+
+use crate::{cvarinfo, Issue};
+
+use super::{ast, ParseTree};
+
+/// Item keys whose second argument (after the label) names a CVar.
+const CVAR_ITEMS: &[&str] = &[
+	"colorpicker",
+	"flagoption",
+	"numberfield",
+	"option",
+	"scaleslider",
+	"slider",
+	"textfield",
+];
+
+/// Every CVar and menu known to a checker. All names are stored lowercase,
+/// since source ports compare them case-insensitively.
+///
+/// CVars are only checked once at least one has been declared, since the
+/// source port defines hundreds of its own. Callers opting in should declare
+/// those as well as any from CVARINFO.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbols {
+	cvars: Option<HashSet<String>>,
+	menus: HashSet<String>,
+}
+
+impl Default for Symbols {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Symbols {
+	/// Knows of no CVars or menus, and so does not check CVars at all.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			cvars: None,
+			menus: HashSet::new(),
+		}
+	}
+
+	/// Also enables checking of CVars.
+	pub fn add_cvar(&mut self, name: &str) {
+		self.cvars
+			.get_or_insert_with(HashSet::new)
+			.insert(name.to_ascii_lowercase());
+	}
+
+	/// Declares every CVar defined in `tree`.
+	pub fn add_cvarinfo(&mut self, tree: &cvarinfo::ParseTree) {
+		for cvar in tree.ast() {
+			self.add_cvar(cvar.name().text());
+		}
+	}
+
+	pub fn add_menu(&mut self, name: &str) {
+		self.menus.insert(name.to_ascii_lowercase());
+	}
+
+	/// Declares every `listmenu` and `optionmenu` in `tree`, including those in
+	/// conditional blocks. Menus of the base game's own MENUDEF (e.g.
+	/// `MainMenu` and `OptionsMenu`) should be added this way before checking
+	/// any files which refer to them.
+	pub fn add_menudef(&mut self, tree: &ParseTree) {
+		for menu in menus(tree) {
+			if !menu.is_addition() {
+				self.add_menu(&menu.name().text());
+			}
+		}
+	}
+
+	/// Returns `true` if any CVar has been declared.
+	#[must_use]
+	pub fn checks_cvars(&self) -> bool {
+		self.cvars.is_some()
+	}
+
+	/// Compared case-insensitively.
+	#[must_use]
+	pub fn has_cvar(&self, name: &str) -> bool {
+		self.cvars
+			.as_ref()
+			.is_some_and(|cvars| cvars.contains(&name.to_ascii_lowercase()))
+	}
+
+	/// Compared case-insensitively.
+	#[must_use]
+	pub fn has_menu(&self, name: &str) -> bool {
+		self.menus.contains(&name.to_ascii_lowercase())
+	}
+}
+
+/// Checks every menu in `tree` against `symbols`, raising a warning for each
+/// item bound to an unknown CVar (if `symbols` checks CVars at all), each
+/// `Submenu` leading to an undefined menu, and each `addlistmenu` or
+/// `addoptionmenu` extending an undefined menu.
+///
+/// Menus defined by `tree` itself count as defined, whether or not they were
+/// added to `symbols` beforehand.
+#[must_use]
+pub fn check_menus(tree: &ParseTree, symbols: &Symbols) -> Vec<Issue> {
+	let mut issues = vec![];
+
+	let local: HashSet<_> = menus(tree)
+		.filter(|menu| !menu.is_addition())
+		.map(|menu| menu.name().text().to_ascii_lowercase())
+		.collect();
+
+	let defined = |name: &str| symbols.has_menu(name) || local.contains(&name.to_ascii_lowercase());
+
+	for menu in menus(tree) {
+		if menu.is_addition() && !defined(&menu.name().text()) {
+			issues.push(Issue::warning(
+				menu.name().syntax().text_range(),
+				format!("menu `{}` is not defined", menu.name().text()),
+			));
+		}
+
+		for item in menu.items() {
+			let key = item.key().text().to_ascii_lowercase();
+
+			if CVAR_ITEMS.contains(&key.as_str()) {
+				if !symbols.checks_cvars() {
+					continue;
+				}
+
+				let Some(cvar) = item.arg(1) else {
+					continue;
+				};
+
+				if !symbols.has_cvar(&cvar.text()) {
+					issues.push(Issue::warning(
+						cvar.syntax().text_range(),
+						format!("`{}` is not a known CVar", cvar.text()),
+					));
+				}
+			} else if key == "submenu" {
+				let Some(target) = item.arg(1) else {
+					continue;
+				};
+
+				if !defined(&target.text()) {
+					issues.push(Issue::warning(
+						target.syntax().text_range(),
+						format!("menu `{}` is not defined", target.text()),
+					));
+				}
+			}
+		}
+	}
+
+	issues
+}
+
+/// Every menu in `tree`, including those in conditional blocks.
+fn menus(tree: &ParseTree) -> impl Iterator<Item = ast::MenuDef> + '_ {
+	tree.zipper().descendants().filter_map(ast::MenuDef::cast)
+}
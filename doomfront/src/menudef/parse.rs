@@ -0,0 +1,270 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any). All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Anything which may appear at the top level, including conditional blocks
+/// of top-level definitions.
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive::recursive(|definition| {
+		primitive::choice((
+			menu_def(src),
+			option_values_def(src),
+			defaults_def(src),
+			include_directive(src),
+			conditional(src, definition),
+		))
+	})
+}
+
+fn menu_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let kw = primitive::choice((
+		comb::kw::<Syn>("listmenu", Syn::KwListMenu, src),
+		comb::kw::<Syn>("optionmenu", Syn::KwOptionMenu, src),
+		comb::kw::<Syn>("addlistmenu", Syn::KwAddListMenu, src),
+		comb::kw::<Syn>("addoptionmenu", Syn::KwAddOptionMenu, src),
+	));
+
+	kw.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((
+			comb::string_lit::<Syn>(src, Syn::LitString),
+			ident(src),
+		)))
+		// e.g. `protected`.
+		.chain_append(trivia(src).chain_push(ident(src)).repeated().flatten())
+		.chain_append(block(src, menu_item(src)))
+		.collect_n::<Syn, { Syn::MenuDef as u16 }>()
+		.labelled("menu definition")
+}
+
+fn defaults_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		comb::kw::<Syn>("defaultlistmenu", Syn::KwDefaultListMenu, src),
+		comb::kw::<Syn>("defaultoptionmenu", Syn::KwDefaultOptionMenu, src),
+		comb::kw::<Syn>("optionmenusettings", Syn::KwOptionMenuSettings, src),
+	))
+	.start_vec()
+	.chain_append(block(src, menu_item(src)))
+	.collect_n::<Syn, { Syn::DefaultsDef as u16 }>()
+	.labelled("menu defaults")
+}
+
+fn option_values_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let pair = value(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(value(src))
+		.collect_n::<Syn, { Syn::OptionPair as u16 }>();
+
+	primitive::choice((
+		comb::kw::<Syn>("optionvalue", Syn::KwOptionValue, src),
+		comb::kw::<Syn>("optionstring", Syn::KwOptionString, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		ident(src),
+	)))
+	.chain_append(block(src, pair))
+	.collect_n::<Syn, { Syn::OptionValuesDef as u16 }>()
+	.labelled("option value list")
+}
+
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("include", Syn::KwInclude, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+		.labelled("include directive")
+}
+
+/// An item, or a conditional block of items.
+fn menu_item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive::recursive(|menu_item| primitive::choice((conditional(src, menu_item), item(src))))
+}
+
+/// A key, optionally followed by comma-separated arguments. The first argument
+/// can not be a bare word, lest it be mistaken for the key of the next item.
+fn item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let first = primitive::choice((number(src), comb::string_lit::<Syn>(src, Syn::LitString)));
+
+	let rest = trivia(src)
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(value(src));
+
+	ident(src)
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(first)
+				.chain_append(rest.repeated().flatten())
+				.or_not()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::Item as u16 }>()
+		.labelled("menu item")
+}
+
+/// `ifgame(...) { ... }` or `ifoption(...) { ... }`, with a block of `inner`.
+fn conditional<'i, P>(
+	src: &'i str,
+	inner: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	let cond = ident(src).start_vec().chain_append(
+		trivia(src)
+			.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+			.chain_append(trivia(src))
+			.chain_push(ident(src))
+			.repeated()
+			.flatten(),
+	);
+
+	primitive::choice((
+		comb::kw::<Syn>("ifgame", Syn::KwIfGame, src),
+		comb::kw::<Syn>("ifoption", Syn::KwIfOption, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.chain_push(comb::just::<Syn, _>('(', Syn::ParenL, src))
+	.chain_append(trivia(src))
+	.chain_append(cond)
+	.chain_append(trivia(src))
+	.chain_push(comb::just::<Syn, _>(')', Syn::ParenR, src))
+	.chain_append(block(src, inner))
+	.collect_n::<Syn, { Syn::Conditional as u16 }>()
+	.labelled("conditional block")
+}
+
+/// Trivia, then a brace-delimited list of `item`s and trivia.
+fn block<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	trivia(src)
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), item)).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src))
+}
+
+fn value(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		number(src),
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		ident(src),
+	))
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// An integer or floating-point literal, optionally negative.
+fn number(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let int = primitive::just('-')
+		.or_not()
+		.then(text::digits::<char, ParseError>(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal");
+
+	let leading = text::digits::<char, ParseError>(10)
+		.then(primitive::just('.'))
+		.then(text::digits(10).or_not())
+		.ignored();
+
+	let trailing = primitive::just('.').then(text::digits(10)).ignored();
+
+	let float = primitive::just('-')
+		.or_not()
+		.then(primitive::choice((leading, trailing)))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal");
+
+	primitive::choice((float, int))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,102 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// MENUDEF syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `ifgame(GAME, ...) { ... }` or `ifoption(OPTION, ...) { ... }`.
+	Conditional,
+	/// `defaultlistmenu { ... }`, `defaultoptionmenu { ... }`,
+	/// or `optionmenusettings { ... }`.
+	DefaultsDef,
+	/// `include "path"`.
+	IncludeDirective,
+	/// A key followed by comma-separated arguments, e.g.
+	/// `Option "Always Run", "cl_run", "OnOff"`.
+	Item,
+	/// `listmenu NAME { ... }` or `optionmenu NAME { ... }`,
+	/// or either with the `add` prefix.
+	MenuDef,
+	/// `VALUE, "TEXT"`, within an option value or option string list.
+	OptionPair,
+	/// `optionvalue NAME { ... }` or `optionstring NAME { ... }`.
+	OptionValuesDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwAddListMenu,
+	KwAddOptionMenu,
+	KwDefaultListMenu,
+	KwDefaultOptionMenu,
+	KwIfGame,
+	KwIfOption,
+	KwInclude,
+	KwListMenu,
+	KwOptionMenu,
+	KwOptionMenuSettings,
+	KwOptionString,
+	KwOptionValue,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `,`
+	Comma,
+	/// `(`
+	ParenL,
+	/// `)`
+	ParenR,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// MENUDEF comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Item keys, unquoted names, and flags such as `protected`.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by MENUDEF.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
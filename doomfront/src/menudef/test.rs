@@ -0,0 +1,271 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Adapted from GZDoom's own MENUDEF.
+DefaultListMenu
+{
+	Font "BigFont", "Untranslated"
+	Selector "M_SKULL1", -32, -5
+	Linespacing 16
+}
+
+ListMenu "MainMenu"
+{
+	StaticPatch 94, 2, "M_DOOM"
+	Position 97, 72
+	IfGame(Doom, Chex)
+	{
+		PatchItem "M_NGAME", "n", "PlayerclassMenu"
+	}
+	TextItem "$MNU_OPTIONS", "o", "OptionsMenu"
+}
+
+OptionValue "OnOff"
+{
+	0, "$TXT_NO"
+	1, "$TXT_YES"
+}
+
+OptionString LightingModes
+{
+	"Doom", "Doom"
+	"Bright", "$OPTVAL_BRIGHT"
+}
+
+OptionMenu "OptionsMenu" protected
+{
+	Title "$OPTMNU_TITLE"
+	Submenu "$OPTMNU_CONTROLS", "CustomizeControls"
+	Submenu "Mod settings", "ModOptions"
+	Option "Always run", "cl_run", "OnOff"
+	Slider "$MOUSEMNU_SENSITIVITY", "m_sensitivity", 0.5, 2.5, 0.1
+	StaticText " "
+	Control "Fire", "+attack"
+	ColorPicker "Dim color", "dimcolor"
+}
+
+IfOption(ReadThis)
+{
+	AddListMenu "MainMenu"
+	{
+		TextItem "Read This!", "r", "ReadThisMenu"
+	}
+}
+
+include "menudef.extra"
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 7);
+
+	let ast::Root::Defaults(defaults) = &roots[0] else {
+		panic!("expected menu defaults, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(defaults.keyword().kind(), Syn::KwDefaultListMenu);
+	let selector = defaults.items().nth(1).unwrap();
+	assert_eq!(selector.key().text(), "Selector");
+	assert_eq!(selector.arg(1).unwrap().int(), Some(-32));
+
+	let ast::Root::Menu(main) = &roots[1] else {
+		panic!("expected a menu, found: {:#?}", roots[1]);
+	};
+
+	assert_eq!(main.keyword().kind(), Syn::KwListMenu);
+	assert_eq!(main.name().text(), "MainMenu");
+	assert_eq!(main.items().count(), 4);
+	assert_eq!(main.flags().count(), 0);
+
+	let cond = main.conditionals().next().unwrap();
+	assert_eq!(cond.keyword().kind(), Syn::KwIfGame);
+	assert_eq!(
+		cond.conditions()
+			.map(|t| t.text().to_string())
+			.collect::<Vec<_>>(),
+		["Doom", "Chex"]
+	);
+	assert_eq!(cond.items().count(), 1);
+
+	let ast::Root::OptionValues(onoff) = &roots[2] else {
+		panic!("expected option values, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(onoff.keyword().kind(), Syn::KwOptionValue);
+	let pairs: Vec<_> = onoff.pairs().collect();
+	assert_eq!(pairs.len(), 2);
+	assert_eq!(pairs[1].value().int(), Some(1));
+	assert_eq!(pairs[1].text().text(), "$TXT_YES");
+
+	let ast::Root::OptionValues(modes) = &roots[3] else {
+		panic!("expected option values, found: {:#?}", roots[3]);
+	};
+
+	assert_eq!(modes.keyword().kind(), Syn::KwOptionString);
+	assert_eq!(modes.name().ident(), Some("LightingModes"));
+
+	let ast::Root::Menu(options) = &roots[4] else {
+		panic!("expected a menu, found: {:#?}", roots[4]);
+	};
+
+	assert_eq!(options.keyword().kind(), Syn::KwOptionMenu);
+	assert_eq!(
+		options
+			.flags()
+			.map(|t| t.text().to_string())
+			.collect::<Vec<_>>(),
+		["protected"]
+	);
+
+	let slider = options.items().nth(4).unwrap();
+	assert_eq!(slider.key().text(), "Slider");
+	assert_eq!(
+		slider.args().filter_map(|a| a.float()).collect::<Vec<_>>(),
+		[0.5, 2.5, 0.1]
+	);
+
+	let ast::Root::Conditional(readthis) = &roots[5] else {
+		panic!("expected a conditional block, found: {:#?}", roots[5]);
+	};
+
+	assert_eq!(readthis.keyword().kind(), Syn::KwIfOption);
+	let Some(ast::Root::Menu(addition)) = readthis.definitions().next() else {
+		panic!("expected a menu in a conditional block");
+	};
+	assert!(addition.is_addition());
+
+	let ast::Root::Include(include) = &roots[6] else {
+		panic!("expected an include directive, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(include.path(), "menudef.extra");
+}
+
+#[test]
+fn checks() {
+	const CVARINFO: &str = r#"
+user bool mymod_gore = true;
+server int MyMod_Difficulty = 2;
+"#;
+
+	const MOD: &str = r#"
+AddOptionMenu "OptionsMenu"
+{
+	Submenu "Mod settings", "ModOptions"
+}
+
+AddListMenu "NoSuchMenu" {}
+
+OptionMenu ModOptions
+{
+	Option "Gore", "MYMOD_GORE", "OnOff"
+	Slider "Difficulty", "mymod_difficulty", 0, 4, 1
+	NumberField "Lives", "mymod_lives", 1, 9
+	IfGame(Strife)
+	{
+		TextField "Name", "mymod_name"
+		Submenu "Back", "MainMenu"
+	}
+	Submenu "Credits", "ModCredits"
+	Command "Reset", "mymod_reset"
+}
+"#;
+
+	let pt = ParseTree::new(parse_recov(MOD).unwrap());
+	assert_no_errors(&pt);
+
+	// Without any CVars to go by, only menus are checked.
+	let mut symbols = Symbols::new();
+	symbols.add_menudef(&ParseTree::new(parse(SOURCE).unwrap()));
+	assert!(!symbols.checks_cvars());
+	assert_eq!(check_menus(&pt, &symbols).len(), 2);
+
+	symbols.add_cvar("cl_run");
+	symbols.add_cvarinfo(&crate::cvarinfo::ParseTree::new(
+		crate::cvarinfo::parse(CVARINFO).unwrap(),
+	));
+
+	assert!(symbols.checks_cvars());
+	assert!(symbols.has_cvar("Cl_Run"));
+	assert!(symbols.has_cvar("mymod_difficulty"));
+	assert!(symbols.has_menu("optionsmenu"));
+	// Only menus defined outright count, not those which are added to.
+	assert!(!symbols.has_menu("ReadThisMenu"));
+
+	let issues: Vec<_> = check_menus(&pt, &symbols)
+		.into_iter()
+		.map(|issue| (&MOD[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		issues,
+		[
+			(
+				"\"NoSuchMenu\"",
+				"menu `NoSuchMenu` is not defined".to_string()
+			),
+			(
+				"\"mymod_lives\"",
+				"`mymod_lives` is not a known CVar".to_string()
+			),
+			(
+				"\"mymod_name\"",
+				"`mymod_name` is not a known CVar".to_string()
+			),
+			(
+				"\"ModCredits\"",
+				"menu `ModCredits` is not defined".to_string()
+			),
+		]
+	);
+}
+
+#[test]
+fn language_refs() {
+	let pt = ParseTree::new(parse_recov(SOURCE).unwrap());
+
+	let refs: Vec<_> = crate::language::menudef_refs(&pt)
+		.into_iter()
+		.map(|r| r.key)
+		.collect();
+
+	assert_eq!(
+		refs,
+		[
+			"MNU_OPTIONS",
+			"TXT_NO",
+			"TXT_YES",
+			"OPTVAL_BRIGHT",
+			"OPTMNU_TITLE",
+			"OPTMNU_CONTROLS",
+			"MOUSEMNU_SENSITIVITY",
+		]
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+OptionValue Fine { 0, "Off" }
+OptionMenu Broken { Option "No closing brace" ,
+}
+ListMenu AlsoFine { Position 0, 0 }
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
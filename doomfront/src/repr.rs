@@ -2,7 +2,7 @@
 
 use std::marker::PhantomData;
 
-use rowan::{ast::AstNode, GreenNode, SyntaxNode};
+use rowan::{ast::AstNode, GreenNode, SyntaxNode, TextRange};
 
 use crate::{LangExt, ParseError};
 
@@ -11,7 +11,99 @@ use crate::{LangExt, ParseError};
 #[derive(Debug)]
 #[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
 pub struct IncludeTree<L: LangExt> {
-	pub files: Vec<ParseTree<L>>,
+	/// The root file is always first, followed by every file it includes
+	/// (transitively) in depth-first order of appearance.
+	pub files: Vec<FileParseTree<L>>,
+}
+
+impl<L: LangExt> IncludeTree<L> {
+	/// Parses the file at `root` and then every file it includes.
+	///
+	/// - `fetch` retrieves a file's source given a path as written in an
+	///   include directive, or returns `None` if no such file exists.
+	/// - `parse` turns source text into a raw tree.
+	/// - `includes` yields the path and text range of every include directive
+	///   in a file.
+	///
+	/// Paths are compared case-insensitively with backslashes treated as slashes,
+	/// so each file gets parsed once no matter how often it is included, and
+	/// inclusion cycles terminate. A path that `fetch` fails to resolve raises a
+	/// [`ParseError`] on the file that included it.
+	#[must_use]
+	pub fn new<F, P, I>(root: &str, mut fetch: F, parse: P, includes: I) -> Option<Self>
+	where
+		F: FnMut(&str) -> Option<String>,
+		P: Fn(&str) -> RawParseTree<L>,
+		I: Fn(&SyntaxNode<L>) -> Vec<(String, TextRange)>,
+	{
+		fn normalize(path: &str) -> String {
+			path.replace('\\', "/").to_ascii_lowercase()
+		}
+
+		let source = fetch(root)?;
+		let mut visited = vec![normalize(root)];
+		let mut files = vec![];
+		let mut stack = vec![(root.to_string(), source)];
+
+		while let Some((path, source)) = stack.pop() {
+			let raw = parse(&source);
+			let incs = includes(&SyntaxNode::new_root(raw.root.clone()));
+			let mut errors = raw.errors;
+			let mut children = vec![];
+
+			for (inc_path, range) in incs {
+				let normalized = normalize(&inc_path);
+
+				if visited.contains(&normalized) {
+					continue;
+				}
+
+				visited.push(normalized);
+
+				match fetch(&inc_path) {
+					Some(inc_src) => children.push((inc_path, inc_src)),
+					None => errors.push(ParseError::custom(
+						usize::from(range.start())..usize::from(range.end()),
+						format!("failed to find included file: `{inc_path}`"),
+					)),
+				}
+			}
+
+			// Reversed so that the first include gets popped first.
+			stack.extend(children.into_iter().rev());
+
+			files.push(FileParseTree {
+				path,
+				inner: ParseTree::new(RawParseTree::new(raw.root, errors)),
+			});
+		}
+
+		Some(Self { files })
+	}
+}
+
+/// A [`ParseTree`] paired with the path of the file it was parsed from.
+#[derive(Debug)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct FileParseTree<L: LangExt> {
+	path: String,
+	inner: ParseTree<L>,
+}
+
+impl<L: LangExt> FileParseTree<L> {
+	/// As written in the include directive (or as given, for the root).
+	#[must_use]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+}
+
+impl<L: LangExt> std::ops::Deref for FileParseTree<L> {
+	type Target = ParseTree<L>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
 }
 
 /// Represents a source string. It may not necessarily represent valid code; it
@@ -0,0 +1,26 @@
+//! Parser, syntax trees, and validation for
+//! [SBARINFO](https://zdoom.org/wiki/SBARINFO), the lump which ZDoom-family
+//! source ports use to define status bars.
+//!
+//! A file sets a few global options (e.g. `base`, `height`,
+//! `interpolatehealth`), defines mug shot states and popups, and lays out each
+//! kind of status bar with drawing commands nested in conditionals. The parser
+//! accepts any command of the general shape; [`validate`] then checks each one's
+//! name, arguments, and block against what source ports expect.
+
+pub mod ast;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+mod validate;
+
+pub use parse::*;
+pub use syn::*;
+pub use validate::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
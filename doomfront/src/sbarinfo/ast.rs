@@ -0,0 +1,335 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Directive(Directive),
+	MugShot(MugShotDef),
+	StatusBar(StatusBarDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(kind, Syn::Directive | Syn::MugShotDef | Syn::StatusBarDef)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::Directive => Some(Self::Directive(Directive(node))),
+			Syn::MugShotDef => Some(Self::MugShot(MugShotDef(node))),
+			Syn::StatusBarDef => Some(Self::StatusBar(StatusBarDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Directive(inner) => &inner.0,
+			Self::MugShot(inner) => &inner.0,
+			Self::StatusBar(inner) => &inner.0,
+		}
+	}
+}
+
+fn args(node: &SyntaxNode) -> impl Iterator<Item = Arg> {
+	node.children().filter_map(Arg::cast)
+}
+
+fn block(node: &SyntaxNode) -> Option<Block> {
+	node.children().find_map(Block::cast)
+}
+
+// Definitions /////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Directive`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Directive(SyntaxNode);
+
+simple_astnode!(Syn, Directive, Syn::Directive);
+
+impl Directive {
+	/// e.g. `base`, `height`, `interpolatehealth`, `createpopup`.
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn args(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatusBarDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatusBarDef(SyntaxNode);
+
+simple_astnode!(Syn, StatusBarDef, Syn::StatusBarDef);
+
+impl StatusBarDef {
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// The first argument names the kind of status bar (e.g. `normal`,
+	/// `fullscreen`, `automap`, `inventory`, `popuplog`); any following it
+	/// are flags such as `forcescaled` or `fullscreenoffsets`.
+	pub fn args(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0)
+	}
+
+	#[must_use]
+	pub fn block(&self) -> Block {
+		block(&self.0).unwrap()
+	}
+
+	/// Shorthand for `self.block().commands()`.
+	pub fn commands(&self) -> impl Iterator<Item = Command> {
+		self.block().commands()
+	}
+}
+
+/// Wraps a node tagged [`Syn::MugShotDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MugShotDef(SyntaxNode);
+
+simple_astnode!(Syn, MugShotDef, Syn::MugShotDef);
+
+impl MugShotDef {
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// The first argument names the state (e.g. `"Normal"`, `"Pain"`,
+	/// `"Death"`); any following it are flags such as `health` or `health2`.
+	pub fn args(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0)
+	}
+
+	pub fn frames(&self) -> impl Iterator<Item = MugShotFrame> {
+		block(&self.0)
+			.into_iter()
+			.flat_map(|b| b.0.children().filter_map(MugShotFrame::cast))
+	}
+}
+
+/// Wraps a node tagged [`Syn::MugShotFrame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MugShotFrame(SyntaxNode);
+
+simple_astnode!(Syn, MugShotFrame, Syn::MugShotFrame);
+
+impl MugShotFrame {
+	#[must_use]
+	pub fn sprite(&self) -> Value {
+		Value(self.0.first_token().unwrap())
+	}
+
+	#[must_use]
+	pub fn tics(&self) -> Value {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.filter_map(Value::new)
+			.nth(1)
+			.unwrap()
+	}
+}
+
+// Commands ////////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::Block`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Block(SyntaxNode);
+
+simple_astnode!(Syn, Block, Syn::Block);
+
+impl Block {
+	pub fn commands(&self) -> impl Iterator<Item = Command> {
+		self.0.children().filter_map(Command::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::Command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Command(SyntaxNode);
+
+simple_astnode!(Syn, Command, Syn::Command);
+
+impl Command {
+	/// e.g. `drawimage`, `drawnumber`, `ininventory`, `gamemode`.
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn args(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0)
+	}
+
+	/// Conditional commands (and a few others) have a block of commands
+	/// where other commands end with a semicolon.
+	#[must_use]
+	pub fn block(&self) -> Option<Block> {
+		block(&self.0)
+	}
+
+	#[must_use]
+	pub fn else_clause(&self) -> Option<ElseClause> {
+		self.0.children().find_map(ElseClause::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ElseClause`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ElseClause(SyntaxNode);
+
+simple_astnode!(Syn, ElseClause, Syn::ElseClause);
+
+impl ElseClause {
+	#[must_use]
+	pub fn block(&self) -> Block {
+		block(&self.0).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Arg`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Arg(SyntaxNode);
+
+simple_astnode!(Syn, Arg, Syn::Arg);
+
+impl Arg {
+	/// Every token other than trivia, including glyphs like `&&` and `(`.
+	pub fn tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.filter(|t| !matches!(t.kind(), Syn::Whitespace | Syn::Comment))
+	}
+
+	/// Only tokens holding values, skipping glyphs.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.tokens().filter_map(Value::new)
+	}
+
+	/// If this argument is a single token holding a value, returns it.
+	#[must_use]
+	pub fn value(&self) -> Option<Value> {
+		let mut tokens = self.tokens();
+		let ret = tokens.next().and_then(Value::new)?;
+		tokens.next().is_none().then_some(ret)
+	}
+
+	/// `true` if the first token is `not`, e.g. in `ininventory not RedCard`.
+	#[must_use]
+	pub fn is_negated(&self) -> bool {
+		self.tokens()
+			.next()
+			.is_some_and(|t| t.kind() == Syn::Ident && t.text().eq_ignore_ascii_case("not"))
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a value, with convenience functions
+/// for interpreting it. Its tag is one of [`Syn::LitString`], [`Syn::LitInt`],
+/// [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// Also accepts integer literals, e.g. for alpha values.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		matches!(self.0.kind(), Syn::LitFloat | Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as a font or flag name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,230 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), definition(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any). All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		definition(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((statusbar_def(src), mugshot_def(src), directive(src)))
+}
+
+fn statusbar_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("statusbar", Syn::KwStatusBar, src)
+		.start_vec()
+		.chain_append(args(src))
+		.chain_append(trivia(src))
+		.chain_push(block(src, command(src)))
+		.collect_n::<Syn, { Syn::StatusBarDef as u16 }>()
+		.labelled("status bar definition")
+}
+
+fn mugshot_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let frame = primitive::choice((comb::string_lit::<Syn>(src, Syn::LitString), ident(src)))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(number(src))
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(';', Syn::Semicolon, src))
+		.collect_n::<Syn, { Syn::MugShotFrame as u16 }>();
+
+	comb::kw::<Syn>("mugshot", Syn::KwMugShot, src)
+		.start_vec()
+		.chain_append(args(src))
+		.chain_append(trivia(src))
+		.chain_push(block(src, frame))
+		.collect_n::<Syn, { Syn::MugShotDef as u16 }>()
+		.labelled("mug shot definition")
+}
+
+/// e.g. `base Doom;`, `height 32;`, `interpolatehealth true, 8;`.
+fn directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	ident(src)
+		.start_vec()
+		.chain_append(args(src))
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>(';', Syn::Semicolon, src))
+		.collect_n::<Syn, { Syn::Directive as u16 }>()
+		.labelled("directive")
+}
+
+/// A command's name and arguments, followed by either a semicolon or a block,
+/// which may itself be followed by an `else` block.
+fn command(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive::recursive(|command| {
+		let else_clause = trivia(src)
+			.chain_push(
+				comb::kw::<Syn>("else", Syn::KwElse, src)
+					.start_vec()
+					.chain_append(trivia(src))
+					.chain_push(block(src, command.clone()))
+					.collect_n::<Syn, { Syn::ElseClause as u16 }>(),
+			)
+			.or_not()
+			.flatten();
+
+		let end = primitive::choice((
+			comb::just::<Syn, _>(';', Syn::Semicolon, src).start_vec(),
+			block(src, command).start_vec().chain_append(else_clause),
+		));
+
+		ident(src)
+			.start_vec()
+			.chain_append(args(src))
+			.chain_append(trivia(src))
+			.chain_append(end)
+			.collect_n::<Syn, { Syn::Command as u16 }>()
+			.labelled("command")
+	})
+}
+
+/// Brace-delimited `item`s and trivia.
+fn block<'i, P>(
+	src: &'i str,
+	item: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 'i,
+{
+	comb::just::<Syn, _>('{', Syn::BraceL, src)
+		.start_vec()
+		.chain_append(primitive::choice((wsp_ext(src), item)).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src))
+		.collect_n::<Syn, { Syn::Block as u16 }>()
+}
+
+/// Zero or more comma-separated arguments, each preceded by trivia.
+fn args(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let rest = trivia(src)
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(arg(src));
+
+	trivia(src)
+		.chain_push(arg(src))
+		.chain_append(rest.repeated().flatten())
+		.or_not()
+		.flatten()
+}
+
+/// One or more atoms, separated only by trivia.
+fn arg(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let atom = primitive::choice((
+		number(src),
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		ident(src),
+		comb::just::<Syn, _>("&&", Syn::Ampersand2, src),
+		comb::just::<Syn, _>("||", Syn::Pipe2, src),
+		comb::just::<Syn, _>('(', Syn::ParenL, src),
+		comb::just::<Syn, _>(')', Syn::ParenR, src),
+	));
+
+	atom.clone()
+		.start_vec()
+		.chain_append(trivia(src).chain_push(atom).repeated().flatten())
+		.collect_n::<Syn, { Syn::Arg as u16 }>()
+		.labelled("argument")
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// An integer or floating-point literal, optionally negative.
+fn number(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let int = primitive::just('-')
+		.or_not()
+		.then(text::digits::<char, ParseError>(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal");
+
+	let leading = text::digits::<char, ParseError>(10)
+		.then(primitive::just('.'))
+		.then(text::digits(10).or_not())
+		.ignored();
+
+	let trailing = primitive::just('.').then(text::digits(10)).ignored();
+
+	let float = primitive::just('-')
+		.or_not()
+		.then(primitive::choice((leading, trailing)))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal");
+
+	primitive::choice((float, int))
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
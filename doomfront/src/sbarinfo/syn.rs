@@ -0,0 +1,102 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// SBARINFO syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// One comma-separated argument, which may be more than one token;
+	/// e.g. `not RedCard`, `Clip && Shell`, or `interpolate(8)`.
+	Arg,
+	/// `{ ... }`, holding commands or mug shot frames.
+	Block,
+	/// A command within a status bar, such as `drawimage "MEDIA0", 20, -2;`
+	/// or `ininventory BlueCard { ... } else { ... }`.
+	Command,
+	/// A top-level command other than a status bar or mug shot definition,
+	/// such as `base Doom;` or `createpopup log, 0, 0;`.
+	Directive,
+	/// `else { ... }`, following a conditional command's block.
+	ElseClause,
+	/// `mugshot "NAME" [, FLAGS] { ... }`.
+	MugShotDef,
+	/// `SPRITE, TICS;`, within a mug shot definition.
+	MugShotFrame,
+	/// `statusbar NAME [, FLAGS] { ... }`.
+	StatusBarDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwElse,
+	KwMugShot,
+	KwStatusBar,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `&&`
+	Ampersand2,
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `,`
+	Comma,
+	/// `(`
+	ParenL,
+	/// `)`
+	ParenR,
+	/// `||`
+	Pipe2,
+	/// `;`
+	Semicolon,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// SBARINFO comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Command names, and unquoted arguments such as fonts, flags,
+	/// and inventory class names.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by SBARINFO.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,202 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Adapted from GZDoom's Doom status bar.
+base Doom;
+height 32;
+interpolatehealth true, 8;
+monospacefonts true, "0";
+resolution 320, 200;
+createpopup log, 0, 0, slideinbottom, 8;
+
+mugshot "Normal", health2
+{
+	STFST01, 17;
+	STFST00, 17;
+}
+
+statusbar normal, forcescaled
+{
+	drawimage "STBAR", 0, 168;
+	drawnumber 3, HUDFONT_DOOM, untranslated, health, interpolate(8), 90, 171;
+	drawmugshot "STF", 5, 143, 168;
+	drawbar "HORIZ1", "HORIZ2", health, horizontal, 0, 0;
+	drawgem wiggle, "CHAIN", "LIFEGEM2", 7, 5, 16, 2, 191;
+	ininventory not RedCard, 2
+	{
+		drawimage translatable "STKEYS2", 239, 171;
+	}
+	else
+	{
+		drawswitchableimage keyslot 2 && 5, "nullimage", "STKEYS0", "STKEYS3", 239, 171;
+	}
+	weaponammo Clip || Shell { drawimage "STYSNUM0", 0, 0; }
+	playerclass DoomPlayer, MarinePlayer {}
+	gamemode singleplayer, cooperative
+	{
+		drawselectedinventory alternateonempty, INDEXFONT, 287, 170 {}
+	}
+}
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 8);
+
+	let ast::Root::Directive(interpolate) = &roots[2] else {
+		panic!("expected a directive, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(interpolate.key().text(), "interpolatehealth");
+	let args: Vec<_> = interpolate.args().collect();
+	assert_eq!(args.len(), 2);
+	assert_eq!(args[0].value().unwrap().ident(), Some("true"));
+	assert_eq!(args[1].value().unwrap().int(), Some(8));
+
+	let ast::Root::MugShot(mugshot) = &roots[6] else {
+		panic!("expected a mug shot, found: {:#?}", roots[6]);
+	};
+
+	assert_eq!(
+		mugshot.args().next().unwrap().value().unwrap().string(),
+		Some("Normal".to_string())
+	);
+	let frames: Vec<_> = mugshot.frames().collect();
+	assert_eq!(frames.len(), 2);
+	assert_eq!(frames[1].sprite().text(), "STFST00");
+	assert_eq!(frames[1].tics().int(), Some(17));
+
+	let ast::Root::StatusBar(statusbar) = &roots[7] else {
+		panic!("expected a status bar, found: {:#?}", roots[7]);
+	};
+
+	assert_eq!(statusbar.args().count(), 2);
+	let commands: Vec<_> = statusbar.commands().collect();
+	assert_eq!(commands.len(), 9);
+
+	assert_eq!(commands[1].key().text(), "drawnumber");
+	let interpolate = commands[1].args().nth(4).unwrap();
+	assert_eq!(
+		interpolate.tokens().map(|t| t.kind()).collect::<Vec<_>>(),
+		[Syn::Ident, Syn::ParenL, Syn::LitInt, Syn::ParenR]
+	);
+	assert!(interpolate.value().is_none());
+
+	let ininventory = &commands[5];
+	let first = ininventory.args().next().unwrap();
+	assert!(first.is_negated());
+	assert_eq!(first.values().nth(1).unwrap().text(), "RedCard");
+	assert_eq!(ininventory.block().unwrap().commands().count(), 1);
+
+	let else_block = ininventory.else_clause().unwrap().block();
+	let switchable = else_block.commands().next().unwrap();
+	assert_eq!(
+		switchable
+			.args()
+			.next()
+			.unwrap()
+			.tokens()
+			.map(|t| t.kind())
+			.collect::<Vec<_>>(),
+		[Syn::Ident, Syn::LitInt, Syn::Ampersand2, Syn::LitInt]
+	);
+
+	assert!(commands[0].block().is_none());
+	assert!(commands[8].block().is_some());
+}
+
+#[test]
+fn validation() {
+	const SOURCE: &str = r#"
+base Quake;
+height tall;
+interpolatehealth true, 8, 2;
+statusbar normal;
+mugshot "Pain" { STFOUCH0, 2.5; }
+
+statusbar hud
+{
+	drawimage "MEDIA0", 20;
+	drawbar "A", "B", health, "sideways", 0, 0;
+	fillrect 0, 0, 10, 10;
+	ininventory BlueCard;
+	drawimage "X", 0, 0 { }
+	alpha 0.5 { } else { }
+	isselected Pistol
+	{
+		drawshader 10, 200, horizontal, 0, 0;
+	}
+}
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+
+	let issues: Vec<_> = validate(&pt)
+		.into_iter()
+		.map(|issue| (&SOURCE[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		issues,
+		[
+			("Quake", "`Quake` is not a known game".to_string()),
+			("tall", "expected an integer".to_string()),
+			(
+				"interpolatehealth",
+				"`interpolatehealth` expects 1 to 2 arguments, but got 3".to_string()
+			),
+			("statusbar", "`statusbar` needs a block".to_string()),
+			(
+				"2.5",
+				"mug shot frame durations are whole numbers of tics".to_string()
+			),
+			("hud", "`hud` is not a known status bar".to_string()),
+			(
+				"drawimage",
+				"`drawimage` expects 3 to 7 arguments, but got 2".to_string()
+			),
+			(
+				"\"sideways\"",
+				"expected a single unquoted name".to_string()
+			),
+			(
+				"fillrect",
+				"unknown command `fillrect` will be ignored".to_string()
+			),
+			("ininventory", "`ininventory` needs a block".to_string()),
+			("{ }", "`drawimage` can not have a block".to_string()),
+			(
+				"else { }",
+				"`alpha` is not a conditional, so it can not have an `else` block".to_string()
+			),
+		]
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+height 32;
+statusbar fullscreen { drawimage "MEDIA0", 20, -2 }
+base Doom;
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
@@ -0,0 +1,488 @@
+//! Checks which the grammar alone can not express, such as how many arguments
+//! each command takes and of which shape they are.
+
+use rowan::ast::AstNode;
+
+use crate::Issue;
+
+use super::{ast, ParseTree, SyntaxToken};
+
+/// What an argument must look like. Only checked for arguments whose meaning
+/// does not vary; an argument which may be preceded by flags (such as the image
+/// of `drawimage`, which may be `translatable "STFB0"`) is [`Shape::Any`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+	Any,
+	Bool,
+	Int,
+	Number,
+	String,
+	/// A single unquoted word, e.g. a font or flag name.
+	Word,
+}
+
+/// Whether a command ends with a block instead of a semicolon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockRule {
+	Never,
+	Optional,
+	Required,
+	/// Required, and may be followed by an `else` block.
+	Conditional,
+}
+
+#[derive(Debug)]
+struct Spec {
+	name: &'static str,
+	min: usize,
+	/// `None` if there is no limit.
+	max: Option<usize>,
+	/// Shapes of the leading arguments, in order. If there are more arguments
+	/// than shapes, the last shape is used for the rest.
+	shapes: &'static [Shape],
+	block: BlockRule,
+}
+
+const fn spec(
+	name: &'static str,
+	min: usize,
+	max: Option<usize>,
+	shapes: &'static [Shape],
+	block: BlockRule,
+) -> Spec {
+	Spec {
+		name,
+		min,
+		max,
+		shapes,
+		block,
+	}
+}
+
+/// Commands allowed at the top level of a file.
+const DIRECTIVES: &[Spec] = &[
+	spec("base", 1, Some(1), &[Shape::Word], BlockRule::Never),
+	spec(
+		"completeborder",
+		1,
+		Some(1),
+		&[Shape::Bool],
+		BlockRule::Never,
+	),
+	spec(
+		"createpopup",
+		3,
+		Some(6),
+		&[Shape::Word, Shape::Int, Shape::Int, Shape::Word, Shape::Int],
+		BlockRule::Never,
+	),
+	spec("height", 1, Some(1), &[Shape::Int], BlockRule::Never),
+	spec(
+		"interpolatearmor",
+		1,
+		Some(2),
+		&[Shape::Bool, Shape::Int],
+		BlockRule::Never,
+	),
+	spec(
+		"interpolatehealth",
+		1,
+		Some(2),
+		&[Shape::Bool, Shape::Int],
+		BlockRule::Never,
+	),
+	spec(
+		"lowerhealthcap",
+		1,
+		Some(1),
+		&[Shape::Bool],
+		BlockRule::Never,
+	),
+	spec(
+		"monospacefonts",
+		2,
+		Some(3),
+		&[Shape::Bool, Shape::String, Shape::Word],
+		BlockRule::Never,
+	),
+	spec(
+		"mugshot",
+		1,
+		None,
+		&[Shape::String, Shape::Word],
+		BlockRule::Required,
+	),
+	spec("resolution", 2, Some(2), &[Shape::Int], BlockRule::Never),
+	spec("statusbar", 1, None, &[Shape::Word], BlockRule::Required),
+];
+
+/// Commands allowed within a status bar.
+const COMMANDS: &[Spec] = &[
+	spec("alpha", 1, Some(1), &[Shape::Number], BlockRule::Required),
+	spec(
+		"aspectratio",
+		1,
+		Some(1),
+		&[Shape::String],
+		BlockRule::Conditional,
+	),
+	spec(
+		"drawbar",
+		6,
+		Some(8),
+		&[Shape::Any, Shape::Any, Shape::Any, Shape::Word, Shape::Any],
+		BlockRule::Never,
+	),
+	spec("drawgem", 7, Some(9), &[Shape::Any], BlockRule::Never),
+	spec("drawimage", 3, Some(7), &[Shape::Any], BlockRule::Never),
+	spec("drawinventorybar", 5, None, &[Shape::Any], BlockRule::Never),
+	spec(
+		"drawkeybar",
+		5,
+		None,
+		&[Shape::Int, Shape::Word, Shape::Any],
+		BlockRule::Never,
+	),
+	spec("drawmugshot", 3, Some(5), &[Shape::Any], BlockRule::Never),
+	spec(
+		"drawnumber",
+		6,
+		None,
+		&[Shape::Int, Shape::Any],
+		BlockRule::Never,
+	),
+	spec(
+		"drawselectedinventory",
+		3,
+		None,
+		&[Shape::Any],
+		BlockRule::Optional,
+	),
+	spec(
+		"drawshader",
+		5,
+		Some(6),
+		&[Shape::Int, Shape::Int, Shape::Word, Shape::Any],
+		BlockRule::Never,
+	),
+	spec(
+		"drawstring",
+		5,
+		None,
+		&[Shape::Any, Shape::Word, Shape::Any],
+		BlockRule::Never,
+	),
+	spec(
+		"drawswitchableimage",
+		5,
+		None,
+		&[Shape::Any],
+		BlockRule::Never,
+	),
+	spec("gamemode", 1, None, &[Shape::Word], BlockRule::Conditional),
+	spec(
+		"hasweaponpiece",
+		2,
+		Some(2),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec(
+		"ifcvarint",
+		2,
+		Some(2),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec(
+		"ifhealth",
+		1,
+		Some(1),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec("ifinvulnerable", 0, Some(0), &[], BlockRule::Conditional),
+	spec(
+		"ifwaterlevel",
+		1,
+		Some(1),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec(
+		"ininventory",
+		1,
+		Some(3),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec(
+		"inventorybarnotvisible",
+		0,
+		Some(0),
+		&[],
+		BlockRule::Conditional,
+	),
+	spec(
+		"isselected",
+		1,
+		Some(2),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+	spec(
+		"playerclass",
+		1,
+		None,
+		&[Shape::Word],
+		BlockRule::Conditional,
+	),
+	spec(
+		"usesammo",
+		0,
+		Some(1),
+		&[Shape::Word],
+		BlockRule::Conditional,
+	),
+	spec(
+		"usessecondaryammo",
+		0,
+		Some(1),
+		&[Shape::Word],
+		BlockRule::Conditional,
+	),
+	spec(
+		"weaponammo",
+		1,
+		Some(1),
+		&[Shape::Any],
+		BlockRule::Conditional,
+	),
+];
+
+/// The games whose status bars can be used as a `base`.
+const BASES: &[&str] = &["doom", "heretic", "hexen", "strife", "none"];
+
+/// The kinds of status bar which `statusbar` can define.
+const STATUS_BARS: &[&str] = &[
+	"normal",
+	"fullscreen",
+	"automap",
+	"inventory",
+	"inventoryfullscreen",
+	"popuplog",
+	"popupkeys",
+	"popupstatus",
+];
+
+/// Checks every command in `tree`, including those nested in blocks.
+///
+/// Errors are raised for commands with too few or too many arguments, for
+/// arguments of the wrong shape (e.g. `height` with anything but an integer),
+/// for blocks missing where a command needs one (or present where it can not
+/// have one), and for `else` blocks following anything but a conditional.
+/// Warnings are raised for unknown commands, unknown `base` games and status
+/// bar kinds, and mug shot frames with non-integer durations.
+#[must_use]
+pub fn validate(tree: &ParseTree) -> Vec<Issue> {
+	let mut issues = vec![];
+
+	for root in tree.ast() {
+		match root {
+			ast::Root::Directive(directive) => {
+				let key = directive.key();
+				let args: Vec<_> = directive.args().collect();
+
+				if let Some(spec) = find(DIRECTIVES, key.text()) {
+					validate_args(spec, &key, &args, &mut issues);
+
+					if spec.block != BlockRule::Never {
+						issues.push(Issue::error(
+							key.text_range(),
+							format!("`{}` needs a block", key.text()),
+						));
+					}
+				} else {
+					issues.push(unknown(&key));
+				}
+
+				if key.text().eq_ignore_ascii_case("base") {
+					expect_one_of(args.first(), BASES, "game", &mut issues);
+				}
+			}
+			ast::Root::StatusBar(statusbar) => {
+				let key = statusbar.keyword();
+				let args: Vec<_> = statusbar.args().collect();
+				validate_args(
+					find(DIRECTIVES, "statusbar").unwrap(),
+					&key,
+					&args,
+					&mut issues,
+				);
+				expect_one_of(args.first(), STATUS_BARS, "status bar", &mut issues);
+				validate_block(&statusbar.block(), &mut issues);
+			}
+			ast::Root::MugShot(mugshot) => {
+				let key = mugshot.keyword();
+				let args: Vec<_> = mugshot.args().collect();
+				validate_args(
+					find(DIRECTIVES, "mugshot").unwrap(),
+					&key,
+					&args,
+					&mut issues,
+				);
+
+				for frame in mugshot.frames() {
+					let tics = frame.tics();
+
+					if tics.int().is_none() {
+						issues.push(Issue::warning(
+							tics.syntax().text_range(),
+							"mug shot frame durations are whole numbers of tics",
+						));
+					}
+				}
+			}
+		}
+	}
+
+	issues
+}
+
+fn validate_block(block: &ast::Block, issues: &mut Vec<Issue>) {
+	for command in block.commands() {
+		let key = command.key();
+		let args: Vec<_> = command.args().collect();
+
+		let Some(spec) = find(COMMANDS, key.text()) else {
+			issues.push(unknown(&key));
+			continue;
+		};
+
+		validate_args(spec, &key, &args, issues);
+
+		match (spec.block, command.block()) {
+			(BlockRule::Required | BlockRule::Conditional, None) => {
+				issues.push(Issue::error(
+					key.text_range(),
+					format!("`{}` needs a block", key.text()),
+				));
+			}
+			(BlockRule::Never, Some(block)) => {
+				issues.push(Issue::error(
+					block.syntax().text_range(),
+					format!("`{}` can not have a block", key.text()),
+				));
+			}
+			_ => {}
+		}
+
+		if let Some(block) = command.block() {
+			validate_block(&block, issues);
+		}
+
+		if let Some(else_clause) = command.else_clause() {
+			if spec.block != BlockRule::Conditional {
+				issues.push(Issue::error(
+					else_clause.syntax().text_range(),
+					format!(
+						"`{}` is not a conditional, so it can not have an `else` block",
+						key.text()
+					),
+				));
+			}
+
+			validate_block(&else_clause.block(), issues);
+		}
+	}
+}
+
+fn validate_args(spec: &Spec, key: &SyntaxToken, args: &[ast::Arg], issues: &mut Vec<Issue>) {
+	let count_ok = args.len() >= spec.min && spec.max.is_none_or(|max| args.len() <= max);
+
+	if !count_ok {
+		let expected = match spec.max {
+			Some(max) if max == spec.min => format!("{max}"),
+			Some(max) => format!("{} to {max}", spec.min),
+			None => format!("at least {}", spec.min),
+		};
+
+		issues.push(Issue::error(
+			key.text_range(),
+			format!(
+				"`{}` expects {expected} argument{}, but got {}",
+				key.text(),
+				if expected == "1" { "" } else { "s" },
+				args.len()
+			),
+		));
+	}
+
+	for (i, arg) in args.iter().enumerate() {
+		let Some(shape) = spec.shapes.get(i).or(spec.shapes.last()) else {
+			break;
+		};
+
+		if let Some(expected) = check_shape(*shape, arg) {
+			issues.push(Issue::error(
+				arg.syntax().text_range(),
+				format!("expected {expected}"),
+			));
+		}
+	}
+}
+
+/// Returns a description of what was expected if `arg` is not of `shape`.
+fn check_shape(shape: Shape, arg: &ast::Arg) -> Option<&'static str> {
+	let value = arg.value();
+
+	let (ok, expected) = match shape {
+		Shape::Any => (true, ""),
+		Shape::Bool => (
+			value
+				.as_ref()
+				.and_then(ast::Value::ident)
+				.is_some_and(|v| v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false")),
+			"`true` or `false`",
+		),
+		Shape::Int => (value.and_then(|v| v.int()).is_some(), "an integer"),
+		Shape::Number => (value.and_then(|v| v.float()).is_some(), "a number"),
+		Shape::String => (value.and_then(|v| v.string()).is_some(), "a string"),
+		Shape::Word => (
+			value.as_ref().and_then(ast::Value::ident).is_some(),
+			"a single unquoted name",
+		),
+	};
+
+	(!ok).then_some(expected)
+}
+
+fn expect_one_of(arg: Option<&ast::Arg>, options: &[&str], what: &str, issues: &mut Vec<Issue>) {
+	let Some(value) = arg.and_then(ast::Arg::value) else {
+		return;
+	};
+
+	let Some(ident) = value.ident() else {
+		return;
+	};
+
+	if !options.iter().any(|o| o.eq_ignore_ascii_case(ident)) {
+		issues.push(Issue::warning(
+			value.syntax().text_range(),
+			format!("`{ident}` is not a known {what}"),
+		));
+	}
+}
+
+fn find(specs: &'static [Spec], name: &str) -> Option<&'static Spec> {
+	specs
+		.iter()
+		.find(|spec| spec.name.eq_ignore_ascii_case(name))
+}
+
+fn unknown(key: &SyntaxToken) -> Issue {
+	Issue::warning(
+		key.text_range(),
+		format!("unknown command `{}` will be ignored", key.text()),
+	)
+}
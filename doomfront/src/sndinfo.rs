@@ -0,0 +1,26 @@
+//! Parser and syntax trees for [SNDINFO](https://zdoom.org/wiki/SNDINFO),
+//! the lump which ZDoom-family source ports use to define sounds.
+//!
+//! Each line either maps a logical sound name to a lump, or starts with a
+//! `$directive`, most of which modify sounds defined elsewhere. `$random`
+//! is followed by a brace-delimited block, and the `$ifdoom`-style
+//! conditionals apply to everything up to the next `$endif`.
+//! [`resolve`] follows aliases and random lists to find out what every logical
+//! sound actually plays.
+
+pub mod ast;
+mod parse;
+mod resolve;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use parse::*;
+pub use resolve::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,203 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file, or of a conditional block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Definition(Definition),
+	Directive(Directive),
+	IfGame(IfGame),
+	Random(RandomDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::Definition | Syn::Directive | Syn::IfGame | Syn::RandomDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::Definition => Some(Self::Definition(Definition(node))),
+			Syn::Directive => Some(Self::Directive(Directive(node))),
+			Syn::IfGame => Some(Self::IfGame(IfGame(node))),
+			Syn::RandomDef => Some(Self::Random(RandomDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Definition(inner) => &inner.0,
+			Self::Directive(inner) => &inner.0,
+			Self::IfGame(inner) => &inner.0,
+			Self::Random(inner) => &inner.0,
+		}
+	}
+}
+
+/// Every word and string literal child of `node`, in order.
+fn args(node: &SyntaxNode) -> impl Iterator<Item = Arg> {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token().and_then(Arg::new))
+}
+
+/// Wraps a node tagged [`Syn::Definition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Definition(SyntaxNode);
+
+simple_astnode!(Syn, Definition, Syn::Definition);
+
+impl Definition {
+	/// The logical sound name, e.g. `weapons/pistol`.
+	#[must_use]
+	pub fn name(&self) -> Arg {
+		args(&self.0).next().unwrap()
+	}
+
+	/// The name of the lump (or path of the file) holding the sound data.
+	#[must_use]
+	pub fn lump(&self) -> Arg {
+		args(&self.0).nth(1).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Directive`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Directive(SyntaxNode);
+
+simple_astnode!(Syn, Directive, Syn::Directive);
+
+impl Directive {
+	/// The token tagged [`Syn::KwDirective`], including the `$`.
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `true` if this directive's keyword, without its `$`,
+	/// is `name`, compared case-insensitively.
+	#[must_use]
+	pub fn is(&self, name: &str) -> bool {
+		self.keyword().text()[1..].eq_ignore_ascii_case(name)
+	}
+
+	/// Every argument on the same line as the keyword, in order.
+	pub fn args(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::RandomDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RandomDef(SyntaxNode);
+
+simple_astnode!(Syn, RandomDef, Syn::RandomDef);
+
+impl RandomDef {
+	/// The logical sound name being defined.
+	#[must_use]
+	pub fn name(&self) -> Arg {
+		args(&self.0).next().unwrap()
+	}
+
+	/// The logical sound names between the braces, one of which gets picked
+	/// each time this sound is played.
+	pub fn sounds(&self) -> impl Iterator<Item = Arg> {
+		args(&self.0).skip(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::IfGame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IfGame(SyntaxNode);
+
+simple_astnode!(Syn, IfGame, Syn::IfGame);
+
+impl IfGame {
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `true` if `game` (e.g. `doom`) is the one this block is for,
+	/// compared case-insensitively.
+	#[must_use]
+	pub fn matches(&self, game: &str) -> bool {
+		self.keyword().text()[3..].eq_ignore_ascii_case(game)
+	}
+
+	/// `None` if the region runs to the end of input.
+	#[must_use]
+	pub fn end_if(&self) -> Option<SyntaxToken> {
+		self.0
+			.last_token()
+			.filter(|token| token.kind() == Syn::KwEndIf)
+	}
+
+	/// The contents of the region, which can not include other conditionals.
+	pub fn items(&self) -> impl Iterator<Item = Root> {
+		self.0.children().filter_map(Root::cast)
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] tagged [`Syn::Word`] or [`Syn::LitString`],
+/// with convenience functions for interpreting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Arg(SyntaxToken);
+
+impl Arg {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(token.kind(), Syn::Word | Syn::LitString).then_some(Self(token))
+	}
+
+	/// A string literal's content without its quotation marks,
+	/// or a word verbatim.
+	#[must_use]
+	pub fn text(&self) -> &str {
+		let text = self.0.text();
+
+		if self.0.kind() == Syn::LitString {
+			&text[1..(text.len() - 1)]
+		} else {
+			text
+		}
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		self.text().parse().ok()
+	}
+
+	/// Also accepts integers.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		self.text().parse().ok()
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,201 @@
+use chumsky::{primitive, recovery, recursive, text, Error, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), if_game(source), item(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any). All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		primitive::choice((if_game(source), item(source)))
+			.recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Anything which may appear both at the top level and in a conditional block.
+fn item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((random_def(src), directive(src), definition(src)))
+}
+
+fn definition(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	word(src)
+		.start_vec()
+		.chain_push(inline_wsp(src))
+		.chain_push(value(src))
+		.collect_n::<Syn, { Syn::Definition as u16 }>()
+		.labelled("sound definition")
+}
+
+/// Any directive other than `$random` and the conditionals. Directives'
+/// arities are checked later, so as to tolerate unknown directives.
+fn directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('$')
+		.then(text::ident().try_map(|ident: String, span| {
+			const RESERVED: &[&str] = &["endif", "ifdoom", "ifheretic", "ifhexen", "ifstrife"];
+
+			if RESERVED.iter().any(|r| ident.eq_ignore_ascii_case(r)) {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(ident)
+			}
+		}))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwDirective))
+		.start_vec()
+		.chain_append(
+			inline_wsp(src)
+				.start_vec()
+				.chain_push(value(src))
+				.repeated()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::Directive as u16 }>()
+		.labelled("directive")
+}
+
+fn random_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let member = trivia(src).chain_push(value(src));
+
+	primitive::just('$')
+		.then(comb::just_nc("random"))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwRandom))
+		.start_vec()
+		.chain_push(inline_wsp(src))
+		.chain_push(word(src))
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(member.repeated().flatten())
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src))
+		.collect_n::<Syn, { Syn::RandomDef as u16 }>()
+		.labelled("`$random` definition")
+}
+
+fn if_game(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let kw = primitive::just('$').then(primitive::choice((
+		comb::just_nc("ifdoom"),
+		comb::just_nc("ifheretic"),
+		comb::just_nc("ifhexen"),
+		comb::just_nc("ifstrife"),
+	)));
+
+	let end_if = primitive::just('$')
+		.then(comb::just_nc("endif"))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwEndIf));
+
+	kw.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwIfGame))
+		.start_vec()
+		.chain_append(primitive::choice((wsp_ext(src), item(src))).repeated())
+		.chain_push_opt(end_if.or_not())
+		.collect_n::<Syn, { Syn::IfGame as u16 }>()
+		.labelled("conditional region")
+}
+
+fn value(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let string = primitive::just('"')
+		.then(primitive::none_of("\"").repeated())
+		.then(primitive::just('"'))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitString))
+		.labelled("string literal");
+
+	primitive::choice((string, word(src)))
+}
+
+/// Words never start with `$`, so that directives are not mistaken for
+/// definitions, nor with the start of a comment.
+fn word(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	const DELIMS: &str = " \t\r\n{}\";";
+
+	primitive::none_of(DELIMS)
+		.repeated()
+		.at_least(1)
+		.collect::<String>()
+		.try_map(|word, span| {
+			if word.starts_with('$') || word.starts_with("//") || word.starts_with("/*") {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(())
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Word))
+		.labelled("word")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let semicolon_comment = primitive::just(';')
+		.then(primitive::none_of("\r\n").repeated())
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Comment));
+
+	comb::wsp_ext::<Syn, _>(
+		primitive::choice((comb::c_cpp_comment::<Syn>(src), semicolon_comment)),
+		src,
+	)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Spaces and tabs, but not newlines, which end a definition or directive.
+fn inline_wsp(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::one_of(" \t")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Whitespace))
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// An unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,347 @@
+//! Following aliases and random lists down to the lumps they play.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::Issue;
+
+use super::{ast, ParseTree};
+
+/// Every directive, with its minimum and maximum number of arguments.
+pub const DIRECTIVES: &[(&str, usize, usize)] = &[
+	("alias", 2, 2),
+	("ambient", 4, 8),
+	("archivepath", 1, 1),
+	("attenuation", 2, 2),
+	("edfoverride", 0, 0),
+	("limit", 2, 3),
+	("map", 2, 2),
+	("mididevice", 2, 3),
+	("musicalias", 2, 2),
+	("musicvolume", 2, 2),
+	("pitchshift", 2, 2),
+	("pitchshiftrange", 1, 1),
+	("playeralias", 4, 4),
+	("playercompat", 4, 4),
+	("playersound", 4, 4),
+	("playersounddup", 4, 4),
+	("registered", 0, 0),
+	("rolloff", 2, 4),
+	("singular", 1, 1),
+	("volume", 2, 2),
+];
+
+/// What every sound in a SNDINFO lump ends up playing.
+/// All names are lowercase, since source ports compare them case-insensitively.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Resolution {
+	/// Each logical sound name, mapped to every lump which may be played for
+	/// it. Only `$random` sounds (or aliases to them) have more than one.
+	pub sounds: BTreeMap<String, Vec<String>>,
+	/// The sounds defined by `$playersound`, `$playersounddup`,
+	/// and `$playeralias`.
+	pub player_sounds: BTreeMap<PlayerSound, Vec<String>>,
+	/// Each music name given to `$musicalias`, mapped to the music which is
+	/// played in its place after following every alias.
+	pub music: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct PlayerSound {
+	/// e.g. `player` or `fighter`.
+	pub class: String,
+	/// e.g. `male`, `female`, or `other`.
+	pub gender: String,
+	/// e.g. `*pain100`.
+	pub name: String,
+}
+
+/// Resolves every sound in `tree`, applying definitions in order such that
+/// later ones replace earlier ones of the same name. Conditional regions apply
+/// only if `game` is the one they are for, or if `game` is `None`.
+///
+/// Issues are raised for directives with the wrong number of arguments,
+/// unknown directives, references to undefined sounds, and cycles of aliases.
+/// Sounds in a cycle resolve to nothing and so are left out.
+#[must_use]
+pub fn resolve(tree: &ParseTree, game: Option<&str>) -> (Resolution, Vec<Issue>) {
+	let mut resolver = Resolver {
+		game,
+		defs: HashMap::new(),
+		music: BTreeMap::new(),
+		done: HashMap::new(),
+		stack: vec![],
+		in_cycle: HashSet::new(),
+		issues: vec![],
+	};
+
+	for root in tree.ast() {
+		resolver.root(root);
+	}
+
+	resolver.finish()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key {
+	Sound(String),
+	Player(PlayerSound),
+}
+
+impl std::fmt::Display for Key {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Sound(name) => write!(f, "{name}"),
+			Self::Player(ps) => write!(f, "{} ({} {})", ps.name, ps.class, ps.gender),
+		}
+	}
+}
+
+#[derive(Debug)]
+enum Def {
+	Lump(String),
+	/// To a logical sound or, from `$playersounddup`, to another player sound.
+	Alias(Key),
+	Random(Vec<String>),
+}
+
+#[derive(Debug)]
+struct Resolver<'g> {
+	game: Option<&'g str>,
+	defs: HashMap<Key, (Def, TextRange)>,
+	music: BTreeMap<String, (String, TextRange)>,
+	done: HashMap<Key, Option<Vec<String>>>,
+	stack: Vec<Key>,
+	in_cycle: HashSet<Key>,
+	issues: Vec<Issue>,
+}
+
+impl Resolver<'_> {
+	fn root(&mut self, root: ast::Root) {
+		let range = root.syntax().text_range();
+
+		match root {
+			ast::Root::Definition(def) => {
+				let key = Key::Sound(def.name().text().to_ascii_lowercase());
+				let lump = Def::Lump(def.lump().text().to_string());
+				self.defs.insert(key, (lump, range));
+			}
+			ast::Root::Random(random) => {
+				let key = Key::Sound(random.name().text().to_ascii_lowercase());
+
+				let sounds = random
+					.sounds()
+					.map(|arg| arg.text().to_ascii_lowercase())
+					.collect();
+
+				self.defs.insert(key, (Def::Random(sounds), range));
+			}
+			ast::Root::IfGame(if_game) => {
+				if self.game.is_none_or(|game| if_game.matches(game)) {
+					for item in if_game.items() {
+						self.root(item);
+					}
+				}
+			}
+			ast::Root::Directive(directive) => self.directive(&directive, range),
+		}
+	}
+
+	fn directive(&mut self, directive: &ast::Directive, range: TextRange) {
+		let keyword = directive.keyword();
+
+		let Some(&(name, min, max)) = DIRECTIVES.iter().find(|(name, _, _)| directive.is(name))
+		else {
+			self.issues.push(Issue::warning(
+				keyword.text_range(),
+				format!("unknown directive `{}`", keyword.text()),
+			));
+
+			return;
+		};
+
+		let args: Vec<_> = directive
+			.args()
+			.map(|arg| arg.text().to_ascii_lowercase())
+			.collect();
+
+		if args.len() < min || args.len() > max {
+			let expected = if min == max {
+				min.to_string()
+			} else {
+				format!("{min} to {max}")
+			};
+
+			self.issues.push(Issue::error(
+				range,
+				format!(
+					"`{}` takes {expected} argument(s), not {}",
+					keyword.text(),
+					args.len()
+				),
+			));
+
+			return;
+		}
+
+		let player_sound = || PlayerSound {
+			class: args[0].clone(),
+			gender: args[1].clone(),
+			name: args[2].clone(),
+		};
+
+		match name {
+			"alias" => {
+				let def = Def::Alias(Key::Sound(args[1].clone()));
+				self.defs.insert(Key::Sound(args[0].clone()), (def, range));
+			}
+			"playersound" => {
+				// Lump names are kept as written.
+				let lump = directive.args().nth(3).unwrap().text().to_string();
+				let key = Key::Player(player_sound());
+				self.defs.insert(key, (Def::Lump(lump), range));
+			}
+			"playersounddup" => {
+				let target = Key::Player(PlayerSound {
+					name: args[3].clone(),
+					..player_sound()
+				});
+
+				let key = Key::Player(player_sound());
+				self.defs.insert(key, (Def::Alias(target), range));
+			}
+			"playeralias" => {
+				let target = Key::Sound(args[3].clone());
+				let key = Key::Player(player_sound());
+				self.defs.insert(key, (Def::Alias(target), range));
+			}
+			"musicalias" => {
+				self.music.insert(args[0].clone(), (args[1].clone(), range));
+			}
+			_ => {}
+		}
+	}
+
+	fn finish(mut self) -> (Resolution, Vec<Issue>) {
+		let mut keys: Vec<_> = self.defs.keys().cloned().collect();
+		keys.sort_by_key(|key| self.defs[key].1.start());
+
+		let mut ret = Resolution::default();
+
+		for key in keys {
+			let Some(lumps) = self.visit(&key) else {
+				continue;
+			};
+
+			match key {
+				Key::Sound(name) => {
+					ret.sounds.insert(name, lumps);
+				}
+				Key::Player(ps) => {
+					ret.player_sounds.insert(ps, lumps);
+				}
+			}
+		}
+
+		let mut in_cycle = HashSet::new();
+
+		for (name, (_, range)) in &self.music {
+			let mut path = vec![name.as_str()];
+			let mut current = name.as_str();
+			let mut looped = false;
+
+			while let Some((target, _)) = self.music.get(current) {
+				looped = path.contains(&target.as_str());
+				path.push(target);
+				current = target;
+
+				if looped {
+					break;
+				}
+			}
+
+			if !looped {
+				ret.music.insert(name.clone(), current.to_string());
+				continue;
+			}
+
+			// Report each cycle once, rather than once per alias leading into it.
+			if current == name && path.iter().all(|n| !in_cycle.contains(n)) {
+				self.issues.push(Issue::error(
+					*range,
+					format!("music alias cycle: {}", path.join(" -> ")),
+				));
+
+				in_cycle.extend(path);
+			}
+		}
+
+		self.issues.sort_by_key(|issue| issue.range.start());
+		(ret, self.issues)
+	}
+
+	/// Returns `None` if `key` is undefined or part of a cycle.
+	fn visit(&mut self, key: &Key) -> Option<Vec<String>> {
+		if let Some(done) = self.done.get(key) {
+			return done.clone();
+		}
+
+		if let Some(pos) = self.stack.iter().position(|k| k == key) {
+			let cycle = &self.stack[pos..];
+
+			if !cycle.iter().any(|k| self.in_cycle.contains(k)) {
+				let path = cycle
+					.iter()
+					.chain(std::iter::once(key))
+					.map(Key::to_string)
+					.collect::<Vec<_>>();
+
+				self.issues.push(Issue::error(
+					self.defs[key].1,
+					format!("alias cycle: {}", path.join(" -> ")),
+				));
+			}
+
+			self.in_cycle.extend(cycle.iter().cloned());
+			return None;
+		}
+
+		let targets = match &self.defs.get(key)?.0 {
+			Def::Lump(lump) => {
+				let ret = Some(vec![lump.clone()]);
+				self.done.insert(key.clone(), ret.clone());
+				return ret;
+			}
+			Def::Alias(target) => vec![target.clone()],
+			Def::Random(sounds) => sounds.iter().cloned().map(Key::Sound).collect(),
+		};
+
+		let range = self.defs[key].1;
+		self.stack.push(key.clone());
+		let mut lumps = vec![];
+
+		for target in &targets {
+			if !self.defs.contains_key(target) {
+				self.issues
+					.push(Issue::warning(range, format!("`{target}` is not defined")));
+
+				continue;
+			}
+
+			for lump in self.visit(target).into_iter().flatten() {
+				if !lumps.contains(&lump) {
+					lumps.push(lump);
+				}
+			}
+		}
+
+		self.stack.pop();
+
+		let ret = (!lumps.is_empty() && !self.in_cycle.contains(key)).then_some(lumps);
+		self.done.insert(key.clone(), ret.clone());
+		ret
+	}
+}
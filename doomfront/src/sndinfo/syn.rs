@@ -0,0 +1,85 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// SNDINFO syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `logical/name LUMPNAME`, on one line.
+	Definition,
+	/// `$name` followed by arguments on the same line, e.g. `$alias a b`.
+	Directive,
+	/// `$ifdoom`, `$ifheretic`, `$ifhexen`, or `$ifstrife`, followed by
+	/// everything up to and including the next `$endif` (or the end of input).
+	IfGame,
+	/// `$random name { a b c }`.
+	RandomDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	/// The name of a directive including its `$`, e.g. `$alias`.
+	KwDirective,
+	/// `$endif`.
+	KwEndIf,
+	/// `$ifdoom`, `$ifheretic`, `$ifhexen`, or `$ifstrife`.
+	KwIfGame,
+	/// `$random`.
+	KwRandom,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// SNDINFO comments use C/C++ syntax, or start with `;` and run to the end
+	/// of the line, and are treated like whitespace.
+	Comment,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs. Newlines end definitions
+	/// and directives, but are otherwise ignored.
+	Whitespace,
+	/// Any unquoted run of characters other than whitespace, braces, and `;`.
+	/// Logical sound names, lump names, and numbers are all words.
+	Word,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,168 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+; Hexen-style comments are allowed, as are C and C++ ones.
+weapons/pistol		dspistol
+world/barrel		"sounds/barrel.ogg" // Trailing comment.
+grunt/sight1		dsposit1
+grunt/sight2		dsposit2
+
+$random grunt/sight
+{
+	grunt/sight1
+	grunt/sight2
+}
+
+$random misc/blip { weapons/pistol grunt/sight }
+$alias shotguy/sight grunt/sight
+$limit weapons/pistol 4
+$singular world/barrel
+$pitchshift weapons/pistol 3
+$volume world/barrel 0.5
+$attenuation world/barrel 1.5
+$rolloff world/barrel 200 1200
+$playersound player male *pain100 dsplpain
+$playersounddup player male *pain75 *pain100
+$playeralias player male *usefail grunt/sight1
+$ambient 1 world/barrel point 1.5 continuous 1.0
+$musicvolume D_RUNNIN 1.5
+$musicalias D_E1M1 D_RUNNIN
+$musicalias D_E1M2 D_E1M1
+$mididevice D_RUNNIN opl
+
+$ifheretic
+	weapons/pistol gldhit
+	misc/x dsy
+$endif
+
+$IFDOOM
+	world/barrel dsbarexp
+	misc/x dsx
+$ENDIF
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 23);
+
+	let ast::Root::Definition(barrel) = &roots[1] else {
+		panic!("expected a definition, found: {:#?}", roots[1]);
+	};
+
+	assert_eq!(barrel.name().text(), "world/barrel");
+	assert_eq!(barrel.lump().text(), "sounds/barrel.ogg");
+
+	let ast::Root::Random(random) = &roots[4] else {
+		panic!("expected `$random`, found: {:#?}", roots[4]);
+	};
+
+	assert_eq!(random.name().text(), "grunt/sight");
+	assert_eq!(random.sounds().count(), 2);
+
+	let ast::Root::Directive(limit) = &roots[7] else {
+		panic!("expected a directive, found: {:#?}", roots[7]);
+	};
+
+	assert!(limit.is("LIMIT"));
+	assert_eq!(limit.args().nth(1).unwrap().int(), Some(4));
+
+	let ast::Root::IfGame(if_doom) = &roots[22] else {
+		panic!("expected a conditional, found: {:#?}", roots[22]);
+	};
+
+	assert!(if_doom.matches("doom"));
+	assert_eq!(if_doom.items().count(), 2);
+	assert_eq!(if_doom.end_if().unwrap().text(), "$ENDIF");
+}
+
+#[test]
+fn resolution() {
+	let pt = ParseTree::new(parse_recov(SOURCE).unwrap());
+	let (res, issues) = resolve(&pt, Some("doom"));
+	assert!(issues.is_empty(), "{issues:#?}");
+
+	assert_eq!(res.sounds["weapons/pistol"], ["dspistol"]);
+	assert_eq!(res.sounds["world/barrel"], ["dsbarexp"]);
+	assert_eq!(res.sounds["misc/x"], ["dsx"]);
+	assert_eq!(res.sounds["shotguy/sight"], ["dsposit1", "dsposit2"]);
+	assert_eq!(
+		res.sounds["misc/blip"],
+		["dspistol", "dsposit1", "dsposit2"]
+	);
+
+	let pain75 = PlayerSound {
+		class: "player".to_string(),
+		gender: "male".to_string(),
+		name: "*pain75".to_string(),
+	};
+
+	assert_eq!(res.player_sounds[&pain75], ["dsplpain"]);
+	assert_eq!(res.music["d_e1m2"], "d_runnin");
+
+	let (heretic, _) = resolve(&pt, Some("heretic"));
+	assert_eq!(heretic.sounds["weapons/pistol"], ["gldhit"]);
+	assert_eq!(heretic.sounds["world/barrel"], ["sounds/barrel.ogg"]);
+	assert_eq!(heretic.sounds["misc/x"], ["dsy"]);
+}
+
+#[test]
+fn cycles() {
+	const SOURCE: &str = r#"
+a/b dsfirst
+$alias loop/one loop/two
+$alias loop/two loop/three
+$alias loop/three loop/one
+$alias into/loop loop/two
+$random mixed { a/b missing/sound }
+$musicalias D_A D_B
+$musicalias D_B D_A
+$alias too many args
+$bogus 1
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	let (res, issues) = resolve(&pt, None);
+
+	let found: Vec<_> = issues
+		.iter()
+		.map(|issue| (&SOURCE[issue.range], issue.message.as_str()))
+		.collect();
+
+	assert_eq!(
+		found,
+		[
+			(
+				"$alias loop/one loop/two",
+				"alias cycle: loop/one -> loop/two -> loop/three -> loop/one"
+			),
+			(
+				"$random mixed { a/b missing/sound }",
+				"`missing/sound` is not defined"
+			),
+			(
+				"$musicalias D_A D_B",
+				"music alias cycle: d_a -> d_b -> d_a"
+			),
+			(
+				"$alias too many args",
+				"`$alias` takes 2 argument(s), not 3"
+			),
+			("$bogus", "unknown directive `$bogus`"),
+		]
+	);
+
+	assert_eq!(res.sounds.len(), 2);
+	assert_eq!(res.sounds["mixed"], ["dsfirst"]);
+	assert!(res.music.is_empty());
+}
@@ -0,0 +1,26 @@
+//! Parser, syntax trees, and a composite texture model for
+//! [TEXTURES](https://zdoom.org/wiki/TEXTURES), the lump which ZDoom-family
+//! source ports use to define textures, flats, sprites, and graphics by
+//! drawing patches onto a canvas.
+//!
+//! Each definition gives a name and a canvas size, followed by a brace-delimited
+//! block of properties and layers. Each layer names a patch, graphic, or sprite
+//! and where to draw it, optionally followed by a block of transforms.
+//! [`CompositeTexture`] holds all of that as plain data.
+
+pub mod ast;
+mod model;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+
+pub use model::*;
+pub use parse::*;
+pub use syn::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
@@ -0,0 +1,256 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Texture(TextureDef),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		kind == Syn::TextureDef
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::TextureDef => Some(Self::Texture(TextureDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Texture(inner) => &inner.0,
+		}
+	}
+}
+
+/// The name and the two integers following it, which come before any block.
+fn header(node: &SyntaxNode) -> (Value, Option<SyntaxToken>, Option<SyntaxToken>) {
+	let mut tokens = node
+		.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.take_while(|token| token.kind() != Syn::BraceL);
+
+	let name = tokens
+		.by_ref()
+		.find_map(|token| Value::new(token).filter(|v| v.int().is_none()))
+		.unwrap();
+
+	let mut ints = tokens.filter(|token| token.kind() == Syn::LitInt);
+	(name, ints.next(), ints.next())
+}
+
+fn properties(node: &SyntaxNode) -> impl Iterator<Item = Property> {
+	node.children().filter_map(Property::cast)
+}
+
+/// Wraps a node tagged [`Syn::TextureDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TextureDef(SyntaxNode);
+
+simple_astnode!(Syn, TextureDef, Syn::TextureDef);
+
+impl TextureDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwTexture`]
+	/// - [`Syn::KwWallTexture`]
+	/// - [`Syn::KwFlat`]
+	/// - [`Syn::KwSprite`]
+	/// - [`Syn::KwGraphic`]
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Returns `true` if the keyword `optional` follows the kind, in which case
+	/// missing patches are not an error.
+	#[must_use]
+	pub fn is_optional(&self) -> bool {
+		self.0
+			.children_with_tokens()
+			.any(|n_or_t| n_or_t.kind() == Syn::KwOptional)
+	}
+
+	/// Either a string literal or an unquoted name.
+	#[must_use]
+	pub fn name(&self) -> Value {
+		header(&self.0).0
+	}
+
+	/// The token tagged [`Syn::LitInt`] giving the canvas width.
+	#[must_use]
+	pub fn width(&self) -> SyntaxToken {
+		header(&self.0).1.unwrap()
+	}
+
+	/// The token tagged [`Syn::LitInt`] giving the canvas height.
+	#[must_use]
+	pub fn height(&self) -> SyntaxToken {
+		header(&self.0).2.unwrap()
+	}
+
+	/// e.g. `xscale`, `offset`, `worldpanning`.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+
+	/// Layers in the order they get drawn; later ones go on top.
+	pub fn patches(&self) -> impl Iterator<Item = PatchDef> {
+		self.0.children().filter_map(PatchDef::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::PatchDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PatchDef(SyntaxNode);
+
+simple_astnode!(Syn, PatchDef, Syn::PatchDef);
+
+impl PatchDef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwPatch`]
+	/// - [`Syn::KwGraphic`]
+	/// - [`Syn::KwSprite`]
+	#[must_use]
+	pub fn kind(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Either a string literal or an unquoted name.
+	#[must_use]
+	pub fn name(&self) -> Value {
+		header(&self.0).0
+	}
+
+	/// The token tagged [`Syn::LitInt`] giving the horizontal position.
+	#[must_use]
+	pub fn x(&self) -> SyntaxToken {
+		header(&self.0).1.unwrap()
+	}
+
+	/// The token tagged [`Syn::LitInt`] giving the vertical position.
+	#[must_use]
+	pub fn y(&self) -> SyntaxToken {
+		header(&self.0).2.unwrap()
+	}
+
+	/// e.g. `flipx`, `rotate`, `translation`, `blend`, `alpha`, `style`.
+	/// Empty if this patch has no block.
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		properties(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Property(SyntaxNode);
+
+simple_astnode!(Syn, Property, Syn::Property);
+
+impl Property {
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Every value following the key, in order, skipping commas.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.skip(1)
+			.filter_map(|n_or_t| n_or_t.into_token().and_then(Value::new))
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a value, with convenience functions
+/// for interpreting it. Its tag is one of [`Syn::LitString`], [`Syn::LitInt`],
+/// [`Syn::LitFloat`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(
+			token.kind(),
+			Syn::LitString | Syn::LitInt | Syn::LitFloat | Syn::Ident
+		)
+		.then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+			} else if let Some(escaped) = chars.next() {
+				ret.push(escaped);
+			}
+		}
+
+		Some(ret)
+	}
+
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// Also accepts integer literals.
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		matches!(self.0.kind(), Syn::LitFloat | Syn::LitInt)
+			.then(|| self.0.text().parse().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as a render style, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// The value as text: a string literal's content, or any other token's
+	/// text verbatim.
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.string().unwrap_or_else(|| self.0.text().to_string())
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
@@ -0,0 +1,350 @@
+//! A typed model of composite textures.
+
+use super::{ast, ParseTree, Syn};
+
+/// Everything a TEXTURES definition specifies, resolved to plain data: the
+/// size of the canvas, how it gets scaled in the world, and the layers drawn
+/// onto it, bottom first.
+///
+/// [`CompositeTexture::from_ast`] reads a parsed definition; [`composites`]
+/// reads every definition in a file.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct CompositeTexture {
+	pub kind: TextureKind,
+	pub name: String,
+	/// If `true`, the source port skips this definition rather than raising
+	/// an error when any of its patches are missing.
+	pub optional: bool,
+	pub width: u32,
+	pub height: u32,
+	/// `xscale`; 1.0 if not given.
+	pub x_scale: f64,
+	/// `yscale`; 1.0 if not given.
+	pub y_scale: f64,
+	/// `offset`; only meaningful for sprites and graphics.
+	pub offset: (i32, i32),
+	/// `worldpanning`.
+	pub world_panning: bool,
+	/// `nodecals`.
+	pub no_decals: bool,
+	/// `nulltexture`.
+	pub null_texture: bool,
+	/// `notrim`.
+	pub no_trim: bool,
+	pub layers: Vec<Layer>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum TextureKind {
+	Texture,
+	WallTexture,
+	Flat,
+	Sprite,
+	Graphic,
+}
+
+/// One `patch`, `graphic`, or `sprite` drawn onto a composite texture's canvas.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Layer {
+	pub kind: LayerKind,
+	pub name: String,
+	/// Position of the layer's top-left corner on the canvas.
+	pub x: i32,
+	pub y: i32,
+	/// `flipx`.
+	pub flip_x: bool,
+	/// `flipy`.
+	pub flip_y: bool,
+	/// `rotate`, in degrees clockwise; always 0, 90, 180, or 270.
+	pub rotate: u16,
+	/// `useoffsets`; if `true`, the layer's own offsets shift its position.
+	pub use_offsets: bool,
+	pub translation: Option<Translation>,
+	pub blend: Option<Blend>,
+	/// `alpha`; 1.0 if not given.
+	pub alpha: f64,
+	pub style: Style,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum LayerKind {
+	Patch,
+	Graphic,
+	Sprite,
+}
+
+/// The argument(s) to a layer's `translation` property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Translation {
+	/// One of the built-in translations, e.g. `inverse`, `gold`, or `ice`.
+	/// Always lowercase.
+	Builtin(String),
+	/// `desaturate, amount`, where `amount` is between 1 and 31.
+	Desaturate(u8),
+	/// A list of palette remapping ranges, e.g. `"112:127=64:79"`.
+	Ranges(Vec<String>),
+}
+
+/// The argument(s) to a layer's `blend` property.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Blend {
+	pub color: BlendColor,
+	/// If not given, the layer gets colorized rather than blended.
+	pub alpha: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum BlendColor {
+	Rgb(u8, u8, u8),
+	/// A color name such as `red`, to be looked up in the source port's table.
+	Named(String),
+}
+
+/// The argument to a layer's `style` property.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Style {
+	#[default]
+	Copy,
+	Translucent,
+	Add,
+	Subtract,
+	ReverseSubtract,
+	Modulate,
+	CopyAlpha,
+	CopyNewAlpha,
+	Overlay,
+}
+
+impl Style {
+	/// Compared case-insensitively.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		const NAMES: &[(&str, Style)] = &[
+			("copy", Style::Copy),
+			("translucent", Style::Translucent),
+			("add", Style::Add),
+			("subtract", Style::Subtract),
+			("reversesubtract", Style::ReverseSubtract),
+			("modulate", Style::Modulate),
+			("copyalpha", Style::CopyAlpha),
+			("copynewalpha", Style::CopyNewAlpha),
+			("overlay", Style::Overlay),
+		];
+
+		NAMES
+			.iter()
+			.find_map(|(n, style)| n.eq_ignore_ascii_case(name).then_some(*style))
+	}
+}
+
+/// Every definition in `tree`, in order.
+#[must_use]
+pub fn composites(tree: &ParseTree) -> Vec<CompositeTexture> {
+	tree.ast()
+		.map(|root| match root {
+			ast::Root::Texture(def) => CompositeTexture::from_ast(&def),
+		})
+		.collect()
+}
+
+impl CompositeTexture {
+	/// Properties are applied in order, such that later ones override earlier
+	/// ones. Unknown keys and malformed values are skipped.
+	#[must_use]
+	pub fn from_ast(texdef: &ast::TextureDef) -> Self {
+		let kind = match texdef.kind().kind() {
+			Syn::KwWallTexture => TextureKind::WallTexture,
+			Syn::KwFlat => TextureKind::Flat,
+			Syn::KwSprite => TextureKind::Sprite,
+			Syn::KwGraphic => TextureKind::Graphic,
+			_ => TextureKind::Texture,
+		};
+
+		let mut ret = Self {
+			kind,
+			name: texdef.name().text(),
+			optional: texdef.is_optional(),
+			width: texdef.width().text().parse().unwrap_or_default(),
+			height: texdef.height().text().parse().unwrap_or_default(),
+			x_scale: 1.0,
+			y_scale: 1.0,
+			offset: (0, 0),
+			world_panning: false,
+			no_decals: false,
+			null_texture: false,
+			no_trim: false,
+			layers: texdef.patches().map(|p| Layer::from_ast(&p)).collect(),
+		};
+
+		for prop in texdef.properties() {
+			let values: Vec<_> = prop.values().collect();
+
+			match prop.key().text().to_ascii_lowercase().as_str() {
+				"xscale" => set(&mut ret.x_scale, single_float(&values)),
+				"yscale" => set(&mut ret.y_scale, single_float(&values)),
+				"offset" => {
+					if let [x, y] = values.as_slice() {
+						if let (Some(x), Some(y)) = (int(x), int(y)) {
+							ret.offset = (x, y);
+						}
+					}
+				}
+				"worldpanning" => ret.world_panning = true,
+				"nodecals" => ret.no_decals = true,
+				"nulltexture" => ret.null_texture = true,
+				"notrim" => ret.no_trim = true,
+				_ => {}
+			}
+		}
+
+		ret
+	}
+
+	/// The size of the texture in world units, after scaling.
+	#[must_use]
+	pub fn scaled_size(&self) -> (f64, f64) {
+		(
+			f64::from(self.width) / self.x_scale,
+			f64::from(self.height) / self.y_scale,
+		)
+	}
+}
+
+impl Layer {
+	/// Properties are applied in order, such that later ones override earlier
+	/// ones. Unknown keys and malformed values are skipped.
+	#[must_use]
+	pub fn from_ast(patch: &ast::PatchDef) -> Self {
+		let kind = match patch.kind().kind() {
+			Syn::KwGraphic => LayerKind::Graphic,
+			Syn::KwSprite => LayerKind::Sprite,
+			_ => LayerKind::Patch,
+		};
+
+		let mut ret = Self {
+			kind,
+			name: patch.name().text(),
+			x: patch.x().text().parse().unwrap_or_default(),
+			y: patch.y().text().parse().unwrap_or_default(),
+			flip_x: false,
+			flip_y: false,
+			rotate: 0,
+			use_offsets: false,
+			translation: None,
+			blend: None,
+			alpha: 1.0,
+			style: Style::Copy,
+		};
+
+		for prop in patch.properties() {
+			let values: Vec<_> = prop.values().collect();
+
+			match prop.key().text().to_ascii_lowercase().as_str() {
+				"flipx" => ret.flip_x = true,
+				"flipy" => ret.flip_y = true,
+				"useoffsets" => ret.use_offsets = true,
+				"rotate" => {
+					if let [v] = values.as_slice() {
+						// Anything other than a right angle is malformed.
+						if let Some(deg) = v.int().filter(|deg| deg % 90 == 0) {
+							ret.rotate = deg.rem_euclid(360) as u16;
+						}
+					}
+				}
+				"alpha" => set(&mut ret.alpha, single_float(&values)),
+				"style" => {
+					if let [v] = values.as_slice() {
+						set(&mut ret.style, Style::from_name(&v.text()));
+					}
+				}
+				"translation" => {
+					if let Some(t) = translation(&values) {
+						ret.translation = Some(t);
+					}
+				}
+				"blend" => {
+					if let Some(b) = blend(&values) {
+						ret.blend = Some(b);
+					}
+				}
+				_ => {}
+			}
+		}
+
+		ret
+	}
+}
+
+fn set<T>(field: &mut T, value: Option<T>) {
+	if let Some(v) = value {
+		*field = v;
+	}
+}
+
+fn single_float(values: &[ast::Value]) -> Option<f64> {
+	match values {
+		[v] => v.float(),
+		_ => None,
+	}
+}
+
+fn int(value: &ast::Value) -> Option<i32> {
+	value.int().and_then(|i| i32::try_from(i).ok())
+}
+
+fn translation(values: &[ast::Value]) -> Option<Translation> {
+	match values {
+		[first, amount] if first.text().eq_ignore_ascii_case("desaturate") => {
+			let amount = amount.int()?.clamp(1, 31);
+			Some(Translation::Desaturate(amount as u8))
+		}
+		[first] if first.ident().is_some() => {
+			Some(Translation::Builtin(first.text().to_ascii_lowercase()))
+		}
+		_ if !values.is_empty() => values
+			.iter()
+			.map(ast::Value::string)
+			.collect::<Option<Vec<_>>>()
+			.map(Translation::Ranges),
+		_ => None,
+	}
+}
+
+fn blend(values: &[ast::Value]) -> Option<Blend> {
+	let (color, alpha) = match values {
+		[r, g, b, rest @ ..] if r.int().is_some() => {
+			let channel = |v: &ast::Value| v.int().map(|i| i.clamp(0, 255) as u8);
+			(BlendColor::Rgb(channel(r)?, channel(g)?, channel(b)?), rest)
+		}
+		[color, rest @ ..] => {
+			let text = color.string()?;
+			let hex = text.strip_prefix('#').unwrap_or(&text);
+
+			let color = match u32::from_str_radix(hex, 16) {
+				Ok(rgb) if hex.len() == 6 => {
+					BlendColor::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+				}
+				_ => BlendColor::Named(text.to_ascii_lowercase()),
+			};
+
+			(color, rest)
+		}
+		[] => return None,
+	};
+
+	let alpha = match alpha {
+		[] => None,
+		[a] => Some(a.float()?),
+		_ => return None,
+	};
+
+	Some(Blend { color, alpha })
+}
@@ -0,0 +1,230 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), texture_def(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any). All skipped input gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		texture_def(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn texture_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let kind = primitive::choice((
+		comb::kw::<Syn>("texture", Syn::KwTexture, src),
+		comb::kw::<Syn>("walltexture", Syn::KwWallTexture, src),
+		comb::kw::<Syn>("flat", Syn::KwFlat, src),
+		comb::kw::<Syn>("sprite", Syn::KwSprite, src),
+		comb::kw::<Syn>("graphic", Syn::KwGraphic, src),
+	));
+
+	let optional = trivia(src)
+		.chain_push(comb::kw::<Syn>("optional", Syn::KwOptional, src))
+		.or_not()
+		.map(Option::unwrap_or_default);
+
+	let body = trivia(src)
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), patch_def(src), property(src))).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src));
+
+	kind.start_vec()
+		.chain_append(optional)
+		.chain_append(header(src))
+		.chain_append(body)
+		.collect_n::<Syn, { Syn::TextureDef as u16 }>()
+		.labelled("texture definition")
+}
+
+fn patch_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let kind = primitive::choice((
+		comb::kw::<Syn>("patch", Syn::KwPatch, src),
+		comb::kw::<Syn>("graphic", Syn::KwGraphic, src),
+		comb::kw::<Syn>("sprite", Syn::KwSprite, src),
+	));
+
+	let body = trivia(src)
+		.chain_push(comb::just::<Syn, _>('{', Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), property(src))).repeated())
+		.chain_push(comb::just::<Syn, _>('}', Syn::BraceR, src));
+
+	kind.start_vec()
+		.chain_append(header(src))
+		.chain_append(body.or_not().map(Option::unwrap_or_default))
+		.collect_n::<Syn, { Syn::PatchDef as u16 }>()
+		.labelled("patch")
+}
+
+/// `NAME, INT, INT`, preceded by trivia; a size for definitions, and a
+/// position for patches.
+fn header(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let comma_int = trivia(src)
+		.chain_push(comb::just::<Syn, _>(',', Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(int_lit(src));
+
+	trivia(src)
+		.chain_push(primitive::choice((
+			comb::string_lit::<Syn>(src, Syn::LitString),
+			name(src),
+		)))
+		.chain_append(comma_int.clone())
+		.chain_append(comma_int)
+}
+
+/// Keys whose first value may be a bare word, as in `style translucent`
+/// or `translation inverse`.
+const NAMED_KEYS: &[&str] = &["style", "translation"];
+
+fn property(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let value = primitive::choice((
+		float_lit(src),
+		int_lit(src),
+		comb::string_lit::<Syn>(src, Syn::LitString),
+	));
+
+	// After a comma, a value may also be a bare word, as in
+	// `translation desaturate, 10`.
+	let after_comma = comb::just::<Syn, _>(',', Syn::Comma, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((value.clone(), ident(src))));
+
+	let key = text::ident::<char, ParseError>();
+
+	let named = key
+		.try_map(|key: String, span| {
+			if NAMED_KEYS.iter().any(|k| k.eq_ignore_ascii_case(&key)) {
+				Ok(key)
+			} else {
+				Err(ParseError::custom(span, "expected a key taking a name"))
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src));
+
+	let key = key.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident));
+
+	primitive::choice((named, key.start_vec()))
+		.chain_append(
+			trivia(src)
+				.then(primitive::choice((value.start_vec(), after_comma)))
+				.map(|(mut trivia, values)| {
+					trivia.extend(values);
+					trivia
+				})
+				.repeated()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::Property as u16 }>()
+		.labelled("property")
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Unquoted texture and patch names may start with a digit, and may contain
+/// some of the punctuation allowed in lump names.
+fn name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || "_-[]\\^".contains(*c))
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("name")
+}
+
+fn int_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('-')
+		.or_not()
+		.then(text::digits(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal")
+}
+
+/// Either side of the decimal point may be empty, but not both.
+fn float_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let leading = text::digits::<char, ParseError>(10)
+		.then(primitive::just('.'))
+		.then(text::digits(10).or_not())
+		.ignored();
+
+	let trailing = primitive::just('.').then(text::digits(10)).ignored();
+
+	primitive::just('-')
+		.or_not()
+		.then(primitive::choice((leading, trailing)))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitFloat))
+		.labelled("floating-point literal")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// Nested braces are balanced; an unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,86 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast::Root;
+
+/// TEXTURES syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Nodes ///////////////////////////////////////////////////////////////////
+	/// `patch NAME, X, Y [{ ... }]`, or `graphic` or `sprite` in place of
+	/// `patch`, within a texture definition.
+	PatchDef,
+	/// A key followed by zero or more values, e.g. `offset 10, 20`.
+	Property,
+	/// `texture [optional] NAME, WIDTH, HEIGHT { ... }`, or any of the other
+	/// kinds of definition in place of `texture`.
+	TextureDef,
+
+	// Tokens: literals ////////////////////////////////////////////////////////
+	LitFloat,
+	LitInt,
+	/// Delimited by double quotes.
+	LitString,
+
+	// Tokens: keywords ////////////////////////////////////////////////////////
+	KwFlat,
+	/// Either a top-level definition or a layer within one.
+	KwGraphic,
+	KwOptional,
+	KwPatch,
+	/// Either a top-level definition or a layer within one.
+	KwSprite,
+	KwTexture,
+	KwWallTexture,
+
+	// Tokens: glyphs //////////////////////////////////////////////////////////
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `,`
+	Comma,
+
+	// Tokens: miscellaneous ///////////////////////////////////////////////////
+	/// TEXTURES comments use C/C++ syntax and are treated like whitespace.
+	Comment,
+	/// Property keys, property values such as render styles, and unquoted names.
+	Ident,
+	/// Input that the parser considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by TEXTURES.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
@@ -0,0 +1,145 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// A door with a glowing panel.
+WallTexture "BIGDOOR9", 128, 96
+{
+	XScale 2.0
+	YScale 2
+	WorldPanning
+	Patch "DOOR2_1", 0, 0
+	Patch DOOR2_4, 64, 0
+	{
+		FlipX
+		Rotate -90
+		Translation "112:127=64:79", "208:223=32:47"
+		Alpha 0.5
+		Style Translucent
+	}
+	Graphic "M_SKULL1", 40, -8 { Blend "FF8000", 0.25 UseOffsets }
+	Sprite TROOA1, 8, 8 { Translation Desaturate, 16 }
+	Patch "DOOR2_5", 0, 64 { Blend 255, 0, 0 Translation Inverse }
+}
+
+Texture optional "-NOFLAT-", 64, 64 { NullTexture }
+flat FLOORX, 64, 64 { Patch "FLAT5", 0, 0 }
+sprite TROOZ0, 41, 57 { Offset 19, 52 Patch TROOA1, 0, 0 { FlipY } }
+graphic "TITLEPIC", 320, 200 { NoTrim }
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+	assert_eq!(roots.len(), 5);
+
+	let ast::Root::Texture(door) = &roots[0];
+	assert_eq!(door.kind().kind(), Syn::KwWallTexture);
+	assert_eq!(door.name().text(), "BIGDOOR9");
+	assert_eq!(door.width().text(), "128");
+	assert_eq!(door.properties().count(), 3);
+	assert_eq!(door.patches().count(), 5);
+
+	let patch = door.patches().nth(1).unwrap();
+	assert_eq!(patch.name().text(), "DOOR2_4");
+	assert_eq!(patch.x().text(), "64");
+	assert_eq!(patch.properties().count(), 5);
+
+	let ast::Root::Texture(noflat) = &roots[1];
+	assert!(noflat.is_optional());
+	assert!(!door.is_optional());
+}
+
+#[test]
+fn model() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	let textures = composites(&pt);
+	assert_eq!(textures.len(), 5);
+
+	let door = &textures[0];
+	assert_eq!(door.kind, TextureKind::WallTexture);
+	assert_eq!((door.width, door.height), (128, 96));
+	assert_eq!(door.scaled_size(), (64.0, 48.0));
+	assert!(door.world_panning);
+
+	let layers = &door.layers;
+	assert_eq!(layers.len(), 5);
+	assert_eq!(layers[0].name, "DOOR2_1");
+	assert_eq!(layers[0].style, Style::Copy);
+	assert!((layers[0].alpha - 1.0).abs() < f64::EPSILON);
+
+	assert!(layers[1].flip_x);
+	assert_eq!(layers[1].rotate, 270);
+	assert_eq!(layers[1].style, Style::Translucent);
+	assert!((layers[1].alpha - 0.5).abs() < f64::EPSILON);
+
+	assert_eq!(
+		layers[1].translation,
+		Some(Translation::Ranges(vec![
+			"112:127=64:79".to_string(),
+			"208:223=32:47".to_string()
+		]))
+	);
+
+	assert_eq!(layers[2].kind, LayerKind::Graphic);
+	assert_eq!((layers[2].x, layers[2].y), (40, -8));
+	assert!(layers[2].use_offsets);
+
+	assert_eq!(
+		layers[2].blend,
+		Some(Blend {
+			color: BlendColor::Rgb(0xFF, 0x80, 0x00),
+			alpha: Some(0.25)
+		})
+	);
+
+	assert_eq!(layers[3].kind, LayerKind::Sprite);
+	assert_eq!(layers[3].translation, Some(Translation::Desaturate(16)));
+
+	assert_eq!(
+		layers[4].blend,
+		Some(Blend {
+			color: BlendColor::Rgb(255, 0, 0),
+			alpha: None
+		})
+	);
+
+	assert_eq!(
+		layers[4].translation,
+		Some(Translation::Builtin("inverse".to_string()))
+	);
+
+	assert!(textures[1].optional);
+	assert!(textures[1].null_texture);
+	assert_eq!(textures[1].name, "-NOFLAT-");
+	assert_eq!(textures[2].kind, TextureKind::Flat);
+	assert_eq!(textures[3].offset, (19, 52));
+	assert!(textures[3].layers[0].flip_y);
+	assert!(textures[4].no_trim);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+Texture FINE, 64, 64 { Patch "A", 0, 0 }
+Texture "MISSING_SIZE" { Patch "B", 0, 0 { FlipX } }
+Flat ALSOFINE, 64, 64 {}
+"#;
+
+	assert!(parse(SOURCE).is_err());
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_eq!(pt.errors().len(), 1);
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert_eq!(pt.ast().count(), 2);
+}
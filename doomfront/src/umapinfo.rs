@@ -0,0 +1,27 @@
+//! Parser, syntax trees, and validation for
+//! [UMAPINFO](https://doomwiki.org/wiki/UMAPINFO), the map information lump
+//! shared by limit-removing and Boom-compatible source ports.
+//!
+//! A file consists only of map entries, each being `MAP <name>` followed by a
+//! brace-delimited list of `key = value` pairs. [`validate`] checks a parse tree
+//! against the published specification, while [`MapEntry`] provides a typed model
+//! of a single entry which can be written back out as canonical text.
+
+pub mod ast;
+mod model;
+mod parse;
+mod syn;
+#[cfg(test)]
+mod test;
+mod validate;
+
+pub use model::*;
+pub use parse::*;
+pub use syn::*;
+pub use validate::*;
+
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
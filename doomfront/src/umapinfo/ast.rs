@@ -0,0 +1,160 @@
+//! Abstract syntax tree nodes.
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken};
+
+/// Wraps a node tagged [`Syn::MapDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MapDef(SyntaxNode);
+
+simple_astnode!(Syn, MapDef, Syn::MapDef);
+
+impl MapDef {
+	/// The name of the map's marker lump, e.g. `MAP01` or `E1M1`.
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		self.0
+			.children_with_tokens()
+			.find_map(|n_or_t| n_or_t.into_token().filter(|t| t.kind() == Syn::Ident))
+			.unwrap()
+	}
+
+	pub fn properties(&self) -> impl Iterator<Item = Property> {
+		self.0.children().filter_map(Property::cast)
+	}
+
+	/// Keys are compared case-insensitively. If the key is given more than
+	/// once, the last occurrence is returned, since that is the one which
+	/// takes effect.
+	#[must_use]
+	pub fn property(&self, key: &str) -> Option<Property> {
+		self.properties()
+			.filter(|prop| prop.key().text().eq_ignore_ascii_case(key))
+			.last()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Property(SyntaxNode);
+
+simple_astnode!(Syn, Property, Syn::Property);
+
+impl Property {
+	#[must_use]
+	pub fn key(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// Every value after the `=`, in order. Never empty.
+	pub fn values(&self) -> impl Iterator<Item = Value> {
+		self.0
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.skip_while(|token| token.kind() != Syn::Eq)
+			.filter_map(Value::new)
+	}
+
+	/// Shorthand for the first of [`Self::values`].
+	#[must_use]
+	pub fn value(&self) -> Value {
+		self.values().next().unwrap()
+	}
+
+	/// Returns `true` if this is `key = clear`.
+	#[must_use]
+	pub fn is_clear(&self) -> bool {
+		let mut values = self.values();
+		values.next().is_some_and(|v| v.is_clear()) && values.next().is_none()
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] holding a property value, with
+/// convenience functions for interpreting it. Its tag is one of
+/// [`Syn::LitString`], [`Syn::LitInt`], or [`Syn::Ident`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Value(SyntaxToken);
+
+impl Value {
+	#[must_use]
+	fn new(token: SyntaxToken) -> Option<Self> {
+		matches!(token.kind(), Syn::LitString | Syn::LitInt | Syn::Ident).then_some(Self(token))
+	}
+
+	/// If this is a string literal, returns its content with the delimiting
+	/// quotation marks stripped away and escape sequences processed.
+	#[must_use]
+	pub fn string(&self) -> Option<String> {
+		if self.0.kind() != Syn::LitString {
+			return None;
+		}
+
+		let text = self.0.text();
+		let inner = text.get(1..(text.len() - 1))?;
+		let mut ret = String::with_capacity(inner.len());
+		let mut chars = inner.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				ret.push(c);
+				continue;
+			}
+
+			match chars.next() {
+				Some('n') => ret.push('\n'),
+				Some('t') => ret.push('\t'),
+				Some(other) => ret.push(other),
+				None => {}
+			}
+		}
+
+		Some(ret)
+	}
+
+	/// Returns `None` if this is not an integer literal or is out of range.
+	#[must_use]
+	pub fn int(&self) -> Option<i64> {
+		(self.0.kind() == Syn::LitInt)
+			.then(|| self.0.text().parse::<i64>().ok())
+			.flatten()
+	}
+
+	/// If this is a bare word such as `true` or an actor class name, returns it.
+	#[must_use]
+	pub fn ident(&self) -> Option<&str> {
+		(self.0.kind() == Syn::Ident).then(|| self.0.text())
+	}
+
+	/// Returns `Some` if this is the bare word `true` or `false`,
+	/// compared case-insensitively.
+	#[must_use]
+	pub fn bool(&self) -> Option<bool> {
+		let ident = self.ident()?;
+
+		if ident.eq_ignore_ascii_case("true") {
+			Some(true)
+		} else if ident.eq_ignore_ascii_case("false") {
+			Some(false)
+		} else {
+			None
+		}
+	}
+
+	/// Returns `true` if this is the bare word `clear`, compared case-insensitively.
+	#[must_use]
+	pub fn is_clear(&self) -> bool {
+		self.ident()
+			.is_some_and(|ident| ident.eq_ignore_ascii_case("clear"))
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
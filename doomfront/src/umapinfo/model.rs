@@ -0,0 +1,350 @@
+//! A typed model of a single map entry.
+
+use std::fmt::Write;
+
+use super::ast;
+
+/// Everything a UMAPINFO map entry can specify. Fields left as `None` (or
+/// `false`, or empty) were not given, and so the source port's defaults apply.
+///
+/// [`MapEntry::from_ast`] reads a parsed entry, and the [`std::fmt::Display`]
+/// implementation writes it back out as canonical text, which `from_ast`
+/// reads back into an equal `MapEntry`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct MapEntry {
+	/// The name of the map's marker lump, e.g. `MAP01` or `E1M1`.
+	pub map: String,
+	/// `levelname`.
+	pub level_name: Option<String>,
+	/// `label`; [`Clearable::Clear`] removes the automap label entirely.
+	pub label: Option<Clearable<String>>,
+	/// `author`.
+	pub author: Option<String>,
+	/// `levelpic`; the patch showing the level's name on the intermission screen.
+	pub level_pic: Option<String>,
+	/// `next`.
+	pub next: Option<String>,
+	/// `nextsecret`.
+	pub next_secret: Option<String>,
+	/// `skytexture`.
+	pub sky_texture: Option<String>,
+	/// `music`.
+	pub music: Option<String>,
+	/// `exitpic`.
+	pub exit_pic: Option<String>,
+	/// `enterpic`.
+	pub enter_pic: Option<String>,
+	/// `partime`, in seconds.
+	pub par_time: Option<i64>,
+	/// `endgame`.
+	pub end_game: Option<bool>,
+	/// `endpic`.
+	pub end_pic: Option<String>,
+	/// `endbunny`.
+	pub end_bunny: Option<bool>,
+	/// `endcast`.
+	pub end_cast: Option<bool>,
+	/// `nointermission`.
+	pub no_intermission: Option<bool>,
+	/// `intertext`, one element per line.
+	/// [`Clearable::Clear`] disables the text screen.
+	pub inter_text: Option<Clearable<Vec<String>>>,
+	/// `intertextsecret`, one element per line.
+	/// [`Clearable::Clear`] disables the text screen.
+	pub inter_text_secret: Option<Clearable<Vec<String>>>,
+	/// `interbackdrop`.
+	pub inter_backdrop: Option<String>,
+	/// `intermusic`.
+	pub inter_music: Option<String>,
+	/// `episode = clear`, which removes every episode defined before this entry.
+	pub clear_episodes: bool,
+	/// `episode = "patch", "name", "key"`, which adds an episode starting
+	/// at this map.
+	pub episode: Option<Episode>,
+	/// `bossaction = clear`, which removes the map's default boss actions.
+	pub clear_boss_actions: bool,
+	/// Every `bossaction = thing, special, tag`, in order.
+	pub boss_actions: Vec<BossAction>,
+}
+
+/// A property value which may be replaced with the keyword `clear`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub enum Clearable<T> {
+	Clear,
+	Set(T),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Episode {
+	/// The name of the patch drawn in the episode selection menu.
+	pub patch: String,
+	/// Shown instead of `patch` if the port or the user prefers text.
+	pub name: String,
+	/// The key which selects this episode in the menu.
+	pub key: String,
+}
+
+/// When every actor of class `thing` has died, activate line `special`
+/// on sectors tagged `tag`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct BossAction {
+	pub thing: String,
+	pub special: i64,
+	pub tag: i64,
+}
+
+impl MapEntry {
+	#[must_use]
+	pub fn new(map: impl Into<String>) -> Self {
+		Self {
+			map: map.into(),
+			..Default::default()
+		}
+	}
+
+	/// Properties are applied in order, with the same semantics as the source
+	/// port's loader: later keys override earlier ones, and `clear` discards
+	/// the episode or boss actions before it. Unknown keys and malformed values
+	/// are skipped; use [`validate`](super::validate) to report them.
+	#[must_use]
+	pub fn from_ast(mapdef: &ast::MapDef) -> Self {
+		let mut ret = Self::new(mapdef.name().text());
+
+		for prop in mapdef.properties() {
+			let key = prop.key().text().to_ascii_lowercase();
+			let values: Vec<_> = prop.values().collect();
+			let clear = prop.is_clear();
+
+			let single_string = || match values.as_slice() {
+				[v] => v.string(),
+				_ => None,
+			};
+
+			let single_bool = || match values.as_slice() {
+				[v] => v.bool(),
+				_ => None,
+			};
+
+			let strings = || {
+				values
+					.iter()
+					.map(ast::Value::string)
+					.collect::<Option<Vec<_>>>()
+			};
+
+			match key.as_str() {
+				"levelname" => set(&mut ret.level_name, single_string()),
+				"author" => set(&mut ret.author, single_string()),
+				"levelpic" => set(&mut ret.level_pic, single_string()),
+				"next" => set(&mut ret.next, single_string()),
+				"nextsecret" => set(&mut ret.next_secret, single_string()),
+				"skytexture" => set(&mut ret.sky_texture, single_string()),
+				"music" => set(&mut ret.music, single_string()),
+				"exitpic" => set(&mut ret.exit_pic, single_string()),
+				"enterpic" => set(&mut ret.enter_pic, single_string()),
+				"endpic" => set(&mut ret.end_pic, single_string()),
+				"interbackdrop" => set(&mut ret.inter_backdrop, single_string()),
+				"intermusic" => set(&mut ret.inter_music, single_string()),
+				"endgame" => set(&mut ret.end_game, single_bool()),
+				"endbunny" => set(&mut ret.end_bunny, single_bool()),
+				"endcast" => set(&mut ret.end_cast, single_bool()),
+				"nointermission" => set(&mut ret.no_intermission, single_bool()),
+				"partime" => set(
+					&mut ret.par_time,
+					match values.as_slice() {
+						[v] => v.int().filter(|i| *i >= 0),
+						_ => None,
+					},
+				),
+				"label" => {
+					if clear {
+						ret.label = Some(Clearable::Clear);
+					} else {
+						set(&mut ret.label, single_string().map(Clearable::Set));
+					}
+				}
+				"intertext" | "intertextsecret" => {
+					let field = if key == "intertext" {
+						&mut ret.inter_text
+					} else {
+						&mut ret.inter_text_secret
+					};
+
+					if clear {
+						*field = Some(Clearable::Clear);
+					} else {
+						set(field, strings().map(Clearable::Set));
+					}
+				}
+				"episode" => {
+					if clear {
+						ret.clear_episodes = true;
+						ret.episode = None;
+					} else if let Some([patch, name, key]) =
+						strings().and_then(|s| <[String; 3]>::try_from(s).ok())
+					{
+						ret.episode = Some(Episode { patch, name, key });
+					}
+				}
+				"bossaction" => {
+					if clear {
+						ret.clear_boss_actions = true;
+						ret.boss_actions.clear();
+					} else if let [thing, special, tag] = values.as_slice() {
+						if let (Some(thing), Some(special), Some(tag)) =
+							(thing.ident(), special.int(), tag.int())
+						{
+							ret.boss_actions.push(BossAction {
+								thing: thing.to_string(),
+								special,
+								tag,
+							});
+						}
+					}
+				}
+				_ => {}
+			}
+		}
+
+		ret
+	}
+}
+
+fn set<T>(field: &mut Option<T>, value: Option<T>) {
+	if value.is_some() {
+		*field = value;
+	}
+}
+
+impl std::fmt::Display for MapEntry {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "MAP {}", self.map)?;
+		f.write_str("{\n")?;
+
+		let strings = [
+			("levelname", &self.level_name),
+			("author", &self.author),
+			("levelpic", &self.level_pic),
+			("next", &self.next),
+			("nextsecret", &self.next_secret),
+			("skytexture", &self.sky_texture),
+			("music", &self.music),
+			("exitpic", &self.exit_pic),
+			("enterpic", &self.enter_pic),
+		];
+
+		for (key, value) in strings {
+			if let Some(value) = value {
+				writeln!(f, "\t{key} = {}", quote(value))?;
+			}
+		}
+
+		match &self.label {
+			Some(Clearable::Clear) => f.write_str("\tlabel = clear\n")?,
+			Some(Clearable::Set(label)) => writeln!(f, "\tlabel = {}", quote(label))?,
+			None => {}
+		}
+
+		if let Some(par_time) = self.par_time {
+			writeln!(f, "\tpartime = {par_time}")?;
+		}
+
+		let bools = [
+			("endgame", self.end_game),
+			("endbunny", self.end_bunny),
+			("endcast", self.end_cast),
+			("nointermission", self.no_intermission),
+		];
+
+		for (key, value) in bools {
+			if let Some(value) = value {
+				writeln!(f, "\t{key} = {value}")?;
+			}
+		}
+
+		if let Some(end_pic) = &self.end_pic {
+			writeln!(f, "\tendpic = {}", quote(end_pic))?;
+		}
+
+		for (key, value) in [
+			("intertext", &self.inter_text),
+			("intertextsecret", &self.inter_text_secret),
+		] {
+			match value {
+				Some(Clearable::Clear) => writeln!(f, "\t{key} = clear")?,
+				Some(Clearable::Set(lines)) => {
+					let mut text = String::new();
+
+					for (i, line) in lines.iter().enumerate() {
+						if i > 0 {
+							text.push_str(",\n\t\t");
+						}
+
+						let _ = write!(text, "{}", quote(line));
+					}
+
+					writeln!(f, "\t{key} = {text}")?;
+				}
+				None => {}
+			}
+		}
+
+		if let Some(backdrop) = &self.inter_backdrop {
+			writeln!(f, "\tinterbackdrop = {}", quote(backdrop))?;
+		}
+
+		if let Some(music) = &self.inter_music {
+			writeln!(f, "\tintermusic = {}", quote(music))?;
+		}
+
+		if self.clear_episodes {
+			f.write_str("\tepisode = clear\n")?;
+		}
+
+		if let Some(episode) = &self.episode {
+			writeln!(
+				f,
+				"\tepisode = {}, {}, {}",
+				quote(&episode.patch),
+				quote(&episode.name),
+				quote(&episode.key)
+			)?;
+		}
+
+		if self.clear_boss_actions {
+			f.write_str("\tbossaction = clear\n")?;
+		}
+
+		for action in &self.boss_actions {
+			writeln!(
+				f,
+				"\tbossaction = {}, {}, {}",
+				action.thing, action.special, action.tag
+			)?;
+		}
+
+		f.write_str("}\n")
+	}
+}
+
+fn quote(string: &str) -> String {
+	let mut ret = String::with_capacity(string.len() + 2);
+	ret.push('"');
+
+	for c in string.chars() {
+		match c {
+			'"' => ret.push_str("\\\""),
+			'\\' => ret.push_str("\\\\"),
+			'\n' => ret.push_str("\\n"),
+			'\t' => ret.push_str("\\t"),
+			other => ret.push(other),
+		}
+	}
+
+	ret.push('"');
+	ret
+}
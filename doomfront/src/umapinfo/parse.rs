@@ -0,0 +1,137 @@
+use chumsky::{primitive, recovery, recursive, text, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), map_def(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then
+/// skips the rest of the offending line, along with the brace-delimited block
+/// following it (if any), so that one malformed entry does not hide the ones
+/// after it. All skipped input gets wrapped into a token tagged [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		map_def(source).recover_with(recovery::skip_parser(recover(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+fn map_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("map", Syn::KwMap, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(lump_name(src))
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>("{", Syn::BraceL, src))
+		.chain_append(primitive::choice((wsp_ext(src), property(src))).repeated())
+		.chain_push(comb::just::<Syn, _>("}", Syn::BraceR, src))
+		.collect_n::<Syn, { Syn::MapDef as u16 }>()
+		.labelled("map entry")
+}
+
+fn property(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let rest = trivia(src)
+		.chain_push(comb::just::<Syn, _>(",", Syn::Comma, src))
+		.chain_append(trivia(src))
+		.chain_push(value(src));
+
+	ident(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::just::<Syn, _>("=", Syn::Eq, src))
+		.chain_append(trivia(src))
+		.chain_push(value(src))
+		.chain_append(rest.repeated().flatten())
+		.collect_n::<Syn, { Syn::Property as u16 }>()
+}
+
+fn value(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let int = primitive::just('-')
+		.or_not()
+		.then(text::digits(10))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitInt))
+		.labelled("integer literal");
+
+	primitive::choice((
+		comb::string_lit::<Syn>(src, Syn::LitString),
+		int,
+		ident(src),
+	))
+	.labelled("value")
+}
+
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>()
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Map names are validated later; here, anything resembling a lump name
+/// (including those starting with a digit) is accepted.
+fn lump_name(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("map name")
+}
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Skips the rest of the line, along with a brace-delimited block following
+/// it on that line (if there is one), or else a lone brace-delimited block.
+/// An unclosed block runs to the end of input.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let balanced = recursive::recursive(|balanced| {
+		primitive::just('{')
+			.then(primitive::choice((primitive::none_of("{}").ignored(), balanced)).repeated())
+			.then(primitive::just('}').ignored().or(primitive::end()))
+			.ignored()
+	});
+
+	primitive::choice((
+		primitive::none_of("{}\r\n")
+			.repeated()
+			.at_least(1)
+			.then(balanced.clone().or_not())
+			.ignored(),
+		balanced,
+		primitive::just('}').ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,206 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::*;
+
+const SOURCE: &str = r#"
+// Comments are allowed anywhere.
+MAP MAP01
+{
+	levelname = "Entryway"
+	label = clear
+	author = "Sandy \"Tom\" Petersen"
+	next = "MAP02"
+	music = "D_RUNNIN"
+	partime = 30
+	intertext = "Line one,",
+		/* and */ "line two."
+	episode = clear
+	episode = "WIEPI1", "Hell on Earth", "h"
+	bossaction = clear
+	bossaction = Fatso, 23, 666
+	bossaction = Arachnotron, 30, 667
+}
+
+map e1m8 {
+	endgame = true
+	endbunny = FALSE
+	intertextsecret = clear
+}
+"#;
+
+#[test]
+fn smoke() {
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+	assert!(validate(&pt).is_empty(), "{:#?}", validate(&pt));
+
+	let maps: Vec<_> = pt.ast().collect();
+	assert_eq!(maps.len(), 2);
+	assert_eq!(maps[0].name().text(), "MAP01");
+	assert_eq!(maps[0].properties().count(), 12);
+	assert!(maps[0].property("LABEL").unwrap().is_clear());
+	assert_eq!(
+		maps[0]
+			.property("author")
+			.unwrap()
+			.value()
+			.string()
+			.unwrap(),
+		"Sandy \"Tom\" Petersen"
+	);
+	assert_eq!(maps[0].property("intertext").unwrap().values().count(), 2);
+	assert_eq!(
+		maps[0].property("bossaction").unwrap().value().ident(),
+		Some("Arachnotron")
+	);
+	assert_eq!(
+		maps[1].property("endbunny").unwrap().value().bool(),
+		Some(false)
+	);
+
+	let entry = MapEntry::from_ast(&maps[0]);
+	assert_eq!(entry.map, "MAP01");
+	assert_eq!(entry.level_name.as_deref(), Some("Entryway"));
+	assert_eq!(entry.label, Some(Clearable::Clear));
+	assert_eq!(entry.par_time, Some(30));
+	assert_eq!(
+		entry.inter_text,
+		Some(Clearable::Set(vec![
+			"Line one,".to_string(),
+			"line two.".to_string()
+		]))
+	);
+	assert!(entry.clear_episodes);
+	assert_eq!(entry.episode.as_ref().unwrap().key, "h");
+	assert!(entry.clear_boss_actions);
+	assert_eq!(entry.boss_actions.len(), 2);
+	assert_eq!(entry.boss_actions[1].tag, 667);
+}
+
+#[test]
+fn round_trip() {
+	let pt = ParseTree::new(parse(SOURCE).unwrap());
+
+	for mapdef in pt.ast() {
+		let entry = MapEntry::from_ast(&mapdef);
+		let text = entry.to_string();
+		let reparsed = parse(&text).unwrap();
+		let reparsed = ParseTree::new(reparsed);
+		assert!(validate(&reparsed).is_empty(), "{:#?}", validate(&reparsed));
+		let again = MapEntry::from_ast(&reparsed.ast().next().unwrap());
+		assert_eq!(entry, again, "{text}");
+		assert_eq!(again.to_string(), text);
+	}
+
+	let entry = MapEntry::from_ast(&pt.ast().next().unwrap());
+
+	assert_eq!(
+		entry.to_string(),
+		r#"MAP MAP01
+{
+	levelname = "Entryway"
+	author = "Sandy \"Tom\" Petersen"
+	next = "MAP02"
+	music = "D_RUNNIN"
+	label = clear
+	partime = 30
+	intertext = "Line one,",
+		"line two."
+	episode = clear
+	episode = "WIEPI1", "Hell on Earth", "h"
+	bossaction = clear
+	bossaction = Fatso, 23, 666
+	bossaction = Arachnotron, 30, 667
+}
+"#
+	);
+}
+
+#[test]
+fn validation() {
+	const SOURCE: &str = r#"
+MAP MAP1X {
+	levelname = Entryway
+	next = "MAP1"
+	skytexture = "SKYTOOLONG"
+	partime = -5
+	endgame = yes
+	intertext = "One", 2
+	episode = "WIEPI1", "Name"
+	bossaction = Fatso, 23, 666
+	bossaction = clear
+	bossaction = Cyberdemon, 23
+	bossaction = "Cyberdemon", -1, 0
+	bossaction = Zombieman, 11, 1
+	bossaction = Marine, 11, 1
+	music = "D_RUNNIN"
+	music = "D_STALKS"
+	foo = 1
+}
+
+MAP E1M1 { levelname = "Hangar" }
+MAP e1m1 { levelname = "Hangar, again" }
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+
+	let messages: Vec<_> = validate(&pt)
+		.into_iter()
+		.map(|issue| (issue.is_error(), issue.message))
+		.collect();
+
+	let expected: &[(bool, &str)] = &[
+		(
+			true,
+			"`MAP1X` is not a valid map name; expected `MAPxx` or `ExMy`",
+		),
+		(true, "expected a single string"),
+		(
+			true,
+			"`MAP1` is not a valid map name; expected `MAPxx` or `ExMy`",
+		),
+		(
+			true,
+			"`SKYTOOLONG` is longer than 8 characters, so it can not be a lump name",
+		),
+		(true, "`partime` expects a single non-negative integer"),
+		(true, "`endgame` expects `true` or `false`"),
+		(true, "`intertext` expects `clear` or one or more strings"),
+		(
+			true,
+			"`episode` expects `clear` or three strings: patch, name, and key",
+		),
+		(
+			false,
+			"`bossaction = clear` discards the boss actions defined earlier in this entry",
+		),
+		(
+			true,
+			"`bossaction` expects `clear` or an actor class, a line special, and a tag",
+		),
+		(true, "expected an unquoted actor class name"),
+		(true, "expected a non-negative integer line special"),
+		(true, "`Marine` is not a known actor class"),
+		(
+			false,
+			"`music` is given again later in this entry, which overrides it",
+		),
+		(false, "unknown key `foo` will be ignored"),
+		(false, "this entry for `E1M1` is overridden by a later one"),
+	];
+
+	for (i, (is_error, msg)) in expected.iter().enumerate() {
+		assert_eq!(
+			(&messages[i].0, messages[i].1.as_str()),
+			(is_error, *msg),
+			"issue {i}"
+		);
+	}
+
+	assert_eq!(messages.len(), expected.len(), "{messages:#?}");
+}
@@ -0,0 +1,419 @@
+//! Checks against the UMAPINFO specification which the grammar alone can not
+//! express, such as the shape of each key's value.
+
+use std::collections::HashMap;
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::Issue;
+
+use super::{ast, ParseTree};
+
+/// The class names accepted by `bossaction`; one for each of vanilla Doom's
+/// thing types, spelled as in ZDoom.
+pub const DOOM_ACTORS: &[&str] = &[
+	"DoomPlayer",
+	"ZombieMan",
+	"ShotgunGuy",
+	"Archvile",
+	"ArchvileFire",
+	"Revenant",
+	"RevenantTracer",
+	"RevenantTracerSmoke",
+	"Fatso",
+	"FatShot",
+	"ChaingunGuy",
+	"DoomImp",
+	"Demon",
+	"Spectre",
+	"Cacodemon",
+	"BaronOfHell",
+	"BaronBall",
+	"HellKnight",
+	"LostSoul",
+	"SpiderMastermind",
+	"Arachnotron",
+	"Cyberdemon",
+	"PainElemental",
+	"WolfensteinSS",
+	"CommanderKeen",
+	"BossBrain",
+	"BossEye",
+	"BossTarget",
+	"SpawnShot",
+	"SpawnFire",
+	"ExplosiveBarrel",
+	"DoomImpBall",
+	"CacodemonBall",
+	"Rocket",
+	"PlasmaBall",
+	"BFGBall",
+	"ArachnotronPlasma",
+	"BulletPuff",
+	"Blood",
+	"TeleportFog",
+	"ItemFog",
+	"TeleportDest",
+	"BFGExtra",
+	"GreenArmor",
+	"BlueArmor",
+	"HealthBonus",
+	"ArmorBonus",
+	"BlueCard",
+	"RedCard",
+	"YellowCard",
+	"YellowSkull",
+	"RedSkull",
+	"BlueSkull",
+	"Stimpack",
+	"Medikit",
+	"Soulsphere",
+	"InvulnerabilitySphere",
+	"Berserk",
+	"BlurSphere",
+	"RadSuit",
+	"Allmap",
+	"Infrared",
+	"Megasphere",
+	"Clip",
+	"ClipBox",
+	"RocketAmmo",
+	"RocketBox",
+	"Cell",
+	"CellPack",
+	"Shell",
+	"ShellBox",
+	"Backpack",
+	"BFG9000",
+	"Chaingun",
+	"Chainsaw",
+	"RocketLauncher",
+	"PlasmaRifle",
+	"Shotgun",
+	"SuperShotgun",
+	"TechLamp",
+	"TechLamp2",
+	"Column",
+	"TallGreenColumn",
+	"ShortGreenColumn",
+	"TallRedColumn",
+	"ShortRedColumn",
+	"SkullColumn",
+	"HeartColumn",
+	"EvilEye",
+	"FloatingSkull",
+	"TorchTree",
+	"BlueTorch",
+	"GreenTorch",
+	"RedTorch",
+	"ShortBlueTorch",
+	"ShortGreenTorch",
+	"ShortRedTorch",
+	"Stalagtite",
+	"TechPillar",
+	"CandleStick",
+	"Candelabra",
+	"BloodyTwitch",
+	"Meat2",
+	"Meat3",
+	"Meat4",
+	"Meat5",
+	"NonsolidMeat2",
+	"NonsolidMeat4",
+	"NonsolidMeat3",
+	"NonsolidMeat5",
+	"NonsolidTwitch",
+	"DeadCacodemon",
+	"DeadMarine",
+	"DeadZombieMan",
+	"DeadDemon",
+	"DeadLostSoul",
+	"DeadDoomImp",
+	"DeadShotgunGuy",
+	"GibbedMarine",
+	"GibbedMarineExtra",
+	"HeadsOnAStick",
+	"Gibs",
+	"HeadOnAStick",
+	"HeadCandles",
+	"DeadStick",
+	"LiveStick",
+	"BigTree",
+	"BurningBarrel",
+	"HangNoGuts",
+	"HangBNoBrain",
+	"HangTLookingDown",
+	"HangTSkull",
+	"HangTLookingUp",
+	"HangTNoBrain",
+	"ColonGibs",
+	"SmallBloodPool",
+	"BrainStem",
+];
+
+/// Returns `true` if `name` has the form `MAPxx` or `ExMy`, where `x` and `y`
+/// are decimal numbers, and fits in a lump name. Case-insensitive.
+#[must_use]
+pub fn is_valid_map_name(name: &str) -> bool {
+	fn digits(s: &str) -> bool {
+		!s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+	}
+
+	if name.len() > 8 || !name.is_ascii() {
+		return false;
+	}
+
+	let upper = name.to_ascii_uppercase();
+
+	if let Some(num) = upper.strip_prefix("MAP") {
+		return num.len() >= 2 && digits(num);
+	}
+
+	let Some(rest) = upper.strip_prefix('E') else {
+		return false;
+	};
+
+	match rest.split_once('M') {
+		Some((episode, map)) => digits(episode) && digits(map),
+		None => false,
+	}
+}
+
+/// Checks every map entry in `tree` against the UMAPINFO specification.
+///
+/// Errors are raised for invalid map names and for values of the wrong shape
+/// (e.g. `bossaction` with anything other than a known actor class and two
+/// non-negative integers). Warnings are raised for unknown keys, keys given
+/// more than once, map entries given more than once, and uses of `clear` which
+/// discard a definition made earlier in the same entry.
+#[must_use]
+pub fn validate(tree: &ParseTree) -> Vec<Issue> {
+	let mut issues = vec![];
+	let mut seen = HashMap::new();
+
+	for mapdef in tree.ast() {
+		let name = mapdef.name();
+
+		if !is_valid_map_name(name.text()) {
+			issues.push(Issue::error(
+				name.text_range(),
+				format!(
+					"`{}` is not a valid map name; expected `MAPxx` or `ExMy`",
+					name.text()
+				),
+			));
+		}
+
+		if let Some(prev) = seen.insert(name.text().to_ascii_uppercase(), name.clone()) {
+			issues.push(Issue::warning(
+				prev.text_range(),
+				format!(
+					"this entry for `{}` is overridden by a later one",
+					prev.text()
+				),
+			));
+		}
+
+		validate_map(&mapdef, &mut issues);
+	}
+
+	issues
+}
+
+fn validate_map(mapdef: &ast::MapDef, issues: &mut Vec<Issue>) {
+	let mut seen: HashMap<String, TextRange> = HashMap::new();
+	let mut boss_action = false;
+	let mut episode = false;
+
+	for prop in mapdef.properties() {
+		let key = prop.key();
+		let lkey = key.text().to_ascii_lowercase();
+		let values: Vec<_> = prop.values().collect();
+		let range = prop.syntax().text_range();
+
+		let accumulates = matches!(lkey.as_str(), "bossaction" | "episode");
+
+		if !accumulates {
+			if let Some(prev) = seen.insert(lkey.clone(), range) {
+				issues.push(Issue::warning(
+					prev,
+					format!(
+						"`{}` is given again later in this entry, which overrides it",
+						key.text()
+					),
+				));
+			}
+		}
+
+		match lkey.as_str() {
+			"levelname" | "author" => {
+				expect_string(&values, range, issues);
+			}
+			"label" => {
+				if !prop.is_clear() {
+					expect_string(&values, range, issues);
+				}
+			}
+			"levelpic" | "skytexture" | "music" | "exitpic" | "enterpic" | "endpic"
+			| "interbackdrop" | "intermusic" => {
+				if let Some(value) = expect_string(&values, range, issues) {
+					expect_lump_name(&values[0], &value, issues);
+				}
+			}
+			"next" | "nextsecret" => {
+				if let Some(value) = expect_string(&values, range, issues) {
+					if !is_valid_map_name(&value) {
+						issues.push(Issue::error(
+							values[0].syntax().text_range(),
+							format!(
+								"`{value}` is not a valid map name; expected `MAPxx` or `ExMy`"
+							),
+						));
+					}
+				}
+			}
+			"partime" => match values.as_slice() {
+				[v] if v.int().is_some_and(|i| i >= 0) => {}
+				_ => issues.push(Issue::error(
+					range,
+					"`partime` expects a single non-negative integer",
+				)),
+			},
+			"endgame" | "endbunny" | "endcast" | "nointermission" => match values.as_slice() {
+				[v] if v.bool().is_some() => {}
+				_ => issues.push(Issue::error(
+					range,
+					format!("`{}` expects `true` or `false`", key.text()),
+				)),
+			},
+			"intertext" | "intertextsecret" => {
+				if !prop.is_clear() && values.iter().any(|v| v.string().is_none()) {
+					issues.push(Issue::error(
+						range,
+						format!("`{}` expects `clear` or one or more strings", key.text()),
+					));
+				}
+			}
+			"episode" => {
+				if prop.is_clear() {
+					if episode {
+						issues.push(Issue::warning(
+							range,
+							"`episode = clear` discards the episode defined earlier in this entry",
+						));
+					}
+
+					episode = false;
+					continue;
+				}
+
+				let strings: Option<Vec<_>> = values.iter().map(ast::Value::string).collect();
+
+				let Some([patch, _, menu_key]) =
+					strings.and_then(|s| <[String; 3]>::try_from(s).ok())
+				else {
+					issues.push(Issue::error(
+						range,
+						"`episode` expects `clear` or three strings: patch, name, and key",
+					));
+					continue;
+				};
+
+				if episode {
+					issues.push(Issue::warning(
+						range,
+						"this entry already defines an episode, which this one replaces",
+					));
+				}
+
+				episode = true;
+				expect_lump_name(&values[0], &patch, issues);
+
+				if menu_key.chars().count() != 1 {
+					issues.push(Issue::warning(
+						values[2].syntax().text_range(),
+						"an episode's key should be a single character",
+					));
+				}
+			}
+			"bossaction" => {
+				if prop.is_clear() {
+					if boss_action {
+						issues.push(Issue::warning(
+							range,
+							"`bossaction = clear` discards the boss actions defined earlier in this entry",
+						));
+					}
+
+					boss_action = false;
+					continue;
+				}
+
+				boss_action = true;
+
+				let [thing, special, tag] = values.as_slice() else {
+					issues.push(Issue::error(
+						range,
+						"`bossaction` expects `clear` or an actor class, a line special, and a tag",
+					));
+					continue;
+				};
+
+				match thing.ident() {
+					Some(class) => {
+						if !DOOM_ACTORS.iter().any(|a| a.eq_ignore_ascii_case(class)) {
+							issues.push(Issue::error(
+								thing.syntax().text_range(),
+								format!("`{class}` is not a known actor class"),
+							));
+						}
+					}
+					None => issues.push(Issue::error(
+						thing.syntax().text_range(),
+						"expected an unquoted actor class name",
+					)),
+				}
+
+				for (v, what) in [(special, "line special"), (tag, "tag")] {
+					if v.int().filter(|i| *i >= 0).is_none() {
+						issues.push(Issue::error(
+							v.syntax().text_range(),
+							format!("expected a non-negative integer {what}"),
+						));
+					}
+				}
+			}
+			_ => issues.push(Issue::warning(
+				key.text_range(),
+				format!("unknown key `{}` will be ignored", key.text()),
+			)),
+		}
+	}
+}
+
+/// Raises an error and returns `None` unless `values` is a single string.
+fn expect_string(
+	values: &[ast::Value],
+	range: TextRange,
+	issues: &mut Vec<Issue>,
+) -> Option<String> {
+	let ret = match values {
+		[v] => v.string(),
+		_ => None,
+	};
+
+	if ret.is_none() {
+		issues.push(Issue::error(range, "expected a single string"));
+	}
+
+	ret
+}
+
+fn expect_lump_name(value: &ast::Value, text: &str, issues: &mut Vec<Issue>) {
+	if text.len() > 8 {
+		issues.push(Issue::error(
+			value.syntax().text_range(),
+			format!("`{text}` is longer than 8 characters, so it can not be a lump name"),
+		));
+	}
+}
@@ -0,0 +1,25 @@
+//! Parser and syntax trees for [ZScript](https://zdoom.org/wiki/ZScript),
+//! GZDoom's object-oriented scripting language.
+//!
+//! ZScript succeeds DECORATE as the means of defining actors, and additionally
+//! allows defining event handlers, menus, status bars, and more.
+
+pub mod ast;
+mod parse;
+pub mod sema;
+mod syn;
+#[cfg(test)]
+mod test;
+mod version;
+
+pub use parse::*;
+pub use syn::*;
+pub use version::*;
+
+pub type FileParseTree = crate::repr::FileParseTree<Syn>;
+pub type ParseTree = crate::repr::ParseTree<Syn>;
+pub type IncludeTree = crate::repr::IncludeTree<Syn>;
+pub type RawParseTree = crate::repr::RawParseTree<Syn>;
+pub type SyntaxNode = rowan::SyntaxNode<Syn>;
+pub type SyntaxToken = rowan::SyntaxToken<Syn>;
+pub type Token = rowan::SyntaxToken<Syn>;
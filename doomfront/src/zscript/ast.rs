@@ -0,0 +1,821 @@
+//! Abstract syntax tree nodes.
+
+mod actor;
+mod expr;
+mod lit;
+mod stat;
+
+use rowan::ast::AstNode;
+
+use crate::simple_astnode;
+
+use super::{Syn, SyntaxNode, SyntaxToken, Version};
+
+pub use self::{actor::*, expr::*, lit::*, stat::*};
+
+/// One of the top-level elements of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+	Class(ClassDef),
+	ClassExtend(ClassExtend),
+	Const(ConstDef),
+	Enum(EnumDef),
+	Include(IncludeDirective),
+	MixinClass(MixinClassDef),
+	Struct(StructDef),
+	StructExtend(StructExtend),
+	Version(VersionDirective),
+}
+
+impl AstNode for Root {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::ClassDef
+				| Syn::ClassExtend
+				| Syn::ConstDef
+				| Syn::EnumDef
+				| Syn::IncludeDirective
+				| Syn::MixinClassDef
+				| Syn::StructDef
+				| Syn::StructExtend
+				| Syn::VersionDirective
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::ClassDef => Some(Self::Class(ClassDef(node))),
+			Syn::ClassExtend => Some(Self::ClassExtend(ClassExtend(node))),
+			Syn::ConstDef => Some(Self::Const(ConstDef(node))),
+			Syn::EnumDef => Some(Self::Enum(EnumDef(node))),
+			Syn::IncludeDirective => Some(Self::Include(IncludeDirective(node))),
+			Syn::MixinClassDef => Some(Self::MixinClass(MixinClassDef(node))),
+			Syn::StructDef => Some(Self::Struct(StructDef(node))),
+			Syn::StructExtend => Some(Self::StructExtend(StructExtend(node))),
+			Syn::VersionDirective => Some(Self::Version(VersionDirective(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Class(inner) => &inner.0,
+			Self::ClassExtend(inner) => &inner.0,
+			Self::Const(inner) => &inner.0,
+			Self::Enum(inner) => &inner.0,
+			Self::Include(inner) => &inner.0,
+			Self::MixinClass(inner) => &inner.0,
+			Self::Struct(inner) => &inner.0,
+			Self::StructExtend(inner) => &inner.0,
+			Self::Version(inner) => &inner.0,
+		}
+	}
+}
+
+/// Anything which can be declared inside a class, struct, or mixin body.
+/// Struct bodies only contain constants, enums, static constants, fields,
+/// and functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassInnard {
+	Const(ConstDef),
+	Default(DefaultBlock),
+	Enum(EnumDef),
+	Field(FieldDecl),
+	FlagDef(FlagDef),
+	Function(FunctionDecl),
+	Mixin(MixinStat),
+	Property(PropertyDef),
+	States(StatesBlock),
+	StaticConst(StaticConstDef),
+	Struct(StructDef),
+}
+
+impl AstNode for ClassInnard {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::ConstDef
+				| Syn::DefaultBlock
+				| Syn::EnumDef
+				| Syn::FieldDecl
+				| Syn::FlagDef
+				| Syn::FunctionDecl
+				| Syn::MixinStat
+				| Syn::PropertyDef
+				| Syn::StatesBlock
+				| Syn::StaticConstDef
+				| Syn::StructDef
+		)
+	}
+
+	fn cast(node: rowan::SyntaxNode<Self::Language>) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::ConstDef => Some(Self::Const(ConstDef(node))),
+			Syn::DefaultBlock => Some(Self::Default(DefaultBlock(node))),
+			Syn::EnumDef => Some(Self::Enum(EnumDef(node))),
+			Syn::FieldDecl => Some(Self::Field(FieldDecl(node))),
+			Syn::FlagDef => Some(Self::FlagDef(FlagDef(node))),
+			Syn::FunctionDecl => Some(Self::Function(FunctionDecl(node))),
+			Syn::MixinStat => Some(Self::Mixin(MixinStat(node))),
+			Syn::PropertyDef => Some(Self::Property(PropertyDef(node))),
+			Syn::StatesBlock => Some(Self::States(StatesBlock(node))),
+			Syn::StaticConstDef => Some(Self::StaticConst(StaticConstDef(node))),
+			Syn::StructDef => Some(Self::Struct(StructDef(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Const(inner) => &inner.0,
+			Self::Default(inner) => &inner.0,
+			Self::Enum(inner) => &inner.0,
+			Self::Field(inner) => &inner.0,
+			Self::FlagDef(inner) => &inner.0,
+			Self::Function(inner) => &inner.0,
+			Self::Mixin(inner) => &inner.0,
+			Self::Property(inner) => &inner.0,
+			Self::States(inner) => &inner.0,
+			Self::StaticConst(inner) => &inner.0,
+			Self::Struct(inner) => &inner.0,
+		}
+	}
+}
+
+/// Finds the first token directly beneath `node` tagged `syn`.
+fn token(node: &SyntaxNode, syn: Syn) -> Option<SyntaxToken> {
+	node.children_with_tokens().find_map(|n_or_t| {
+		if n_or_t.kind() == syn {
+			n_or_t.into_token()
+		} else {
+			None
+		}
+	})
+}
+
+/// Yields every token directly beneath `node` tagged `syn`.
+fn tokens(node: &SyntaxNode, syn: Syn) -> impl Iterator<Item = SyntaxToken> {
+	node.children_with_tokens().filter_map(move |n_or_t| {
+		if n_or_t.kind() == syn {
+			n_or_t.into_token()
+		} else {
+			None
+		}
+	})
+}
+
+// Directives //////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::IncludeDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IncludeDirective(SyntaxNode);
+
+simple_astnode!(Syn, IncludeDirective, Syn::IncludeDirective);
+
+impl IncludeDirective {
+	/// The string literal token holding the path, with quotation marks.
+	#[must_use]
+	pub fn path_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// The included path with the delimiting quotation marks stripped away.
+	#[must_use]
+	pub fn path(&self) -> String {
+		let token = self.path_token();
+		let text = token.text();
+		text[1..(text.len() - 1)].to_string()
+	}
+}
+
+/// Wraps a node tagged [`Syn::VersionDirective`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VersionDirective(SyntaxNode);
+
+simple_astnode!(Syn, VersionDirective, Syn::VersionDirective);
+
+impl VersionDirective {
+	/// The string literal token holding the version, with quotation marks.
+	#[must_use]
+	pub fn version_token(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitString).unwrap()
+	}
+
+	/// Returns `None` if the string literal is not of the form `"x"`, `"x.y"`,
+	/// or `"x.y.z"` where each component is an unsigned integer.
+	#[must_use]
+	pub fn version(&self) -> Option<Version> {
+		let token = self.version_token();
+		let text = token.text();
+		text[1..(text.len() - 1)].parse().ok()
+	}
+}
+
+// Classes, structs, mixins ////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::ClassDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClassDef(SyntaxNode);
+
+simple_astnode!(Syn, ClassDef, Syn::ClassDef);
+
+impl ClassDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The identifier after the colon, if any.
+	#[must_use]
+	pub fn parent(&self) -> Option<SyntaxToken> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::InheritSpec)
+			.and_then(|node| token(&node, Syn::Ident))
+	}
+
+	/// The identifier after `replaces`, if any.
+	#[must_use]
+	pub fn replaces(&self) -> Option<SyntaxToken> {
+		self.qualifiers()
+			.0
+			.children()
+			.find(|node| node.kind() == Syn::ReplacesClause)
+			.and_then(|node| token(&node, Syn::Ident))
+	}
+
+	#[must_use]
+	pub fn qualifiers(&self) -> DeclQualifiers {
+		self.0.children().find_map(DeclQualifiers::cast).unwrap()
+	}
+
+	pub fn innards(&self) -> impl Iterator<Item = ClassInnard> {
+		self.0.children().filter_map(ClassInnard::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ClassExtend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClassExtend(SyntaxNode);
+
+simple_astnode!(Syn, ClassExtend, Syn::ClassExtend);
+
+impl ClassExtend {
+	/// The name of the class being extended.
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	pub fn innards(&self) -> impl Iterator<Item = ClassInnard> {
+		self.0.children().filter_map(ClassInnard::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::MixinClassDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MixinClassDef(SyntaxNode);
+
+simple_astnode!(Syn, MixinClassDef, Syn::MixinClassDef);
+
+impl MixinClassDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	pub fn innards(&self) -> impl Iterator<Item = ClassInnard> {
+		self.0.children().filter_map(ClassInnard::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::MixinStat`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MixinStat(SyntaxNode);
+
+simple_astnode!(Syn, MixinStat, Syn::MixinStat);
+
+impl MixinStat {
+	/// The name of the mixin class whose contents get copied.
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StructDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StructDef(SyntaxNode);
+
+simple_astnode!(Syn, StructDef, Syn::StructDef);
+
+impl StructDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn qualifiers(&self) -> DeclQualifiers {
+		self.0.children().find_map(DeclQualifiers::cast).unwrap()
+	}
+
+	pub fn innards(&self) -> impl Iterator<Item = ClassInnard> {
+		self.0.children().filter_map(ClassInnard::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StructExtend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StructExtend(SyntaxNode);
+
+simple_astnode!(Syn, StructExtend, Syn::StructExtend);
+
+impl StructExtend {
+	/// The name of the struct being extended.
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	pub fn innards(&self) -> impl Iterator<Item = ClassInnard> {
+		self.0.children().filter_map(ClassInnard::cast)
+	}
+}
+
+// Qualifiers //////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::DeclQualifiers`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DeclQualifiers(SyntaxNode);
+
+simple_astnode!(Syn, DeclQualifiers, Syn::DeclQualifiers);
+
+impl DeclQualifiers {
+	/// Every keyword qualifier, including the ones that begin
+	/// [`Syn::ActionQual`], [`Syn::DeprecationQual`], and [`Syn::VersionQual`].
+	/// A class's `replaces` clause is among its qualifiers, but is not one.
+	pub fn keywords(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0.descendants_with_tokens().filter_map(|n_or_t| {
+			let kind = n_or_t.kind();
+
+			if !kind.is_trivia()
+				&& !matches!(
+					kind,
+					Syn::Ident
+						| Syn::LitString
+						| Syn::ParenL
+						| Syn::ParenR
+						| Syn::Comma
+						| Syn::KwReplaces
+				) {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Returns `true` if the qualifier keyword tagged `syn` is present.
+	#[must_use]
+	pub fn contains(&self, syn: Syn) -> bool {
+		self.keywords().any(|token| token.kind() == syn)
+	}
+
+	/// The identifiers in `action(actor, overlay)`. Returns `None` if there is
+	/// no `action` qualifier, and an empty `Vec` if it has no parentheses.
+	#[must_use]
+	pub fn action(&self) -> Option<Vec<SyntaxToken>> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ActionQual)
+			.map(|node| tokens(&node, Syn::Ident).collect())
+	}
+
+	/// The version string's token from `deprecated("x.y.z", "message")`.
+	#[must_use]
+	pub fn deprecation(&self) -> Option<SyntaxToken> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::DeprecationQual)
+			.and_then(|node| token(&node, Syn::LitString))
+	}
+
+	/// The version string's token from `version("x.y.z")`.
+	#[must_use]
+	pub fn version(&self) -> Option<SyntaxToken> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::VersionQual)
+			.and_then(|node| token(&node, Syn::LitString))
+	}
+}
+
+// Types ///////////////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::TypeRef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TypeRef(SyntaxNode);
+
+simple_astnode!(Syn, TypeRef, Syn::TypeRef);
+
+impl TypeRef {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::Ident`] for primitives, named types, and qualified names
+	/// - [`Syn::KwLet`]
+	/// - [`Syn::KwClass`]
+	/// - [`Syn::KwArray`]
+	/// - [`Syn::KwMap`]
+	/// - [`Syn::KwMapIterator`]
+	/// - [`Syn::KwReadOnly`]
+	#[must_use]
+	pub fn first_token(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// For named types, the name as written (e.g. `int` or `Actor.Struct`).
+	/// Returns `None` for `let`, `class<...>`, and other generic types.
+	#[must_use]
+	pub fn name(&self) -> Option<String> {
+		if self.first_token().kind() != Syn::Ident {
+			return None;
+		}
+
+		Some(
+			self.0
+				.children_with_tokens()
+				.filter_map(|n_or_t| n_or_t.into_token())
+				.filter(|token| matches!(token.kind(), Syn::Ident | Syn::Period))
+				.map(|token| token.text().to_string())
+				.collect(),
+		)
+	}
+
+	/// The types between angle brackets, if any.
+	pub fn generic_args(&self) -> impl Iterator<Item = TypeRef> {
+		self.0.children().filter_map(TypeRef::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ArrayLen`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArrayLen(SyntaxNode);
+
+simple_astnode!(Syn, ArrayLen, Syn::ArrayLen);
+
+impl ArrayLen {
+	/// Returns `None` if the brackets are empty.
+	#[must_use]
+	pub fn expr(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+}
+
+// Constants, enums ////////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::ConstDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ConstDef(SyntaxNode);
+
+simple_astnode!(Syn, ConstDef, Syn::ConstDef);
+
+impl ConstDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::EnumDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EnumDef(SyntaxNode);
+
+simple_astnode!(Syn, EnumDef, Syn::EnumDef);
+
+impl EnumDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The underlying integral type, e.g. `uint8` in `enum E : uint8 {}`.
+	#[must_use]
+	pub fn type_spec(&self) -> Option<TypeRef> {
+		self.0.children().find_map(TypeRef::cast)
+	}
+
+	pub fn variants(&self) -> impl Iterator<Item = EnumVariant> {
+		self.0.children().filter_map(EnumVariant::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::EnumVariant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EnumVariant(SyntaxNode);
+
+simple_astnode!(Syn, EnumVariant, Syn::EnumVariant);
+
+impl EnumVariant {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn initializer(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StaticConstDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StaticConstDef(SyntaxNode);
+
+simple_astnode!(Syn, StaticConstDef, Syn::StaticConstDef);
+
+impl StaticConstDef {
+	#[must_use]
+	pub fn type_spec(&self) -> TypeRef {
+		self.0.children().find_map(TypeRef::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn init_list(&self) -> InitList {
+		self.0.children().find_map(InitList::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::InitList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct InitList(SyntaxNode);
+
+simple_astnode!(Syn, InitList, Syn::InitList);
+
+impl InitList {
+	pub fn exprs(&self) -> impl Iterator<Item = Expression> {
+		self.0.children().filter_map(Expression::cast)
+	}
+}
+
+// Actor properties and flags //////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::PropertyDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PropertyDef(SyntaxNode);
+
+simple_astnode!(Syn, PropertyDef, Syn::PropertyDef);
+
+impl PropertyDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The fields which this property sets, in order.
+	pub fn fields(&self) -> impl Iterator<Item = SyntaxToken> {
+		tokens(&self.0, Syn::Ident).skip(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::FlagDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FlagDef(SyntaxNode);
+
+simple_astnode!(Syn, FlagDef, Syn::FlagDef);
+
+impl FlagDef {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	/// The integral field backing this flag.
+	#[must_use]
+	pub fn field(&self) -> SyntaxToken {
+		tokens(&self.0, Syn::Ident).nth(1).unwrap()
+	}
+
+	#[must_use]
+	pub fn bit(&self) -> SyntaxToken {
+		token(&self.0, Syn::LitInt).unwrap()
+	}
+}
+
+// Fields and functions ////////////////////////////////////////////////////////
+
+/// Wraps a node tagged [`Syn::FieldDecl`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FieldDecl(SyntaxNode);
+
+simple_astnode!(Syn, FieldDecl, Syn::FieldDecl);
+
+impl FieldDecl {
+	#[must_use]
+	pub fn qualifiers(&self) -> DeclQualifiers {
+		self.0.children().find_map(DeclQualifiers::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn type_spec(&self) -> TypeRef {
+		self.0.children().find_map(TypeRef::cast).unwrap()
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = VarName> {
+		self.0.children().filter_map(VarName::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::VarName`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VarName(SyntaxNode);
+
+simple_astnode!(Syn, VarName, Syn::VarName);
+
+impl VarName {
+	#[must_use]
+	pub fn ident(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn array_lens(&self) -> impl Iterator<Item = ArrayLen> {
+		self.0.children().filter_map(ArrayLen::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::FunctionDecl`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FunctionDecl(SyntaxNode);
+
+simple_astnode!(Syn, FunctionDecl, Syn::FunctionDecl);
+
+impl FunctionDecl {
+	#[must_use]
+	pub fn qualifiers(&self) -> DeclQualifiers {
+		self.0.children().find_map(DeclQualifiers::cast).unwrap()
+	}
+
+	pub fn return_types(&self) -> impl Iterator<Item = TypeRef> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ReturnTypes)
+			.unwrap()
+			.children()
+			.filter_map(TypeRef::cast)
+	}
+
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn params(&self) -> ParamList {
+		self.0.children().find_map(ParamList::cast).unwrap()
+	}
+
+	/// Returns `true` if `const` follows the parameter list.
+	#[must_use]
+	pub fn is_const(&self) -> bool {
+		token(&self.0, Syn::KwConst).is_some()
+	}
+
+	/// Returns `None` if this function is only declared (e.g. it is `native`).
+	#[must_use]
+	pub fn body(&self) -> Option<StatCompound> {
+		self.0.children().find_map(StatCompound::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ParamList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParamList(SyntaxNode);
+
+simple_astnode!(Syn, ParamList, Syn::ParamList);
+
+impl ParamList {
+	pub fn iter(&self) -> impl Iterator<Item = Parameter> {
+		self.0.children().filter_map(Parameter::cast)
+	}
+
+	/// Returns `true` if the list ends with `...`.
+	#[must_use]
+	pub fn is_variadic(&self) -> bool {
+		token(&self.0, Syn::Ellipsis).is_some()
+	}
+}
+
+/// Wraps a node tagged [`Syn::Parameter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Parameter(SyntaxNode);
+
+simple_astnode!(Syn, Parameter, Syn::Parameter);
+
+impl Parameter {
+	#[must_use]
+	pub fn is_in(&self) -> bool {
+		token(&self.0, Syn::KwIn).is_some()
+	}
+
+	#[must_use]
+	pub fn is_out(&self) -> bool {
+		token(&self.0, Syn::KwOut).is_some()
+	}
+
+	#[must_use]
+	pub fn type_spec(&self) -> TypeRef {
+		self.0.children().find_map(TypeRef::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		token(&self.0, Syn::Ident).unwrap()
+	}
+
+	#[must_use]
+	pub fn default(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ArgList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArgList(SyntaxNode);
+
+simple_astnode!(Syn, ArgList, Syn::ArgList);
+
+impl ArgList {
+	pub fn iter(&self) -> impl Iterator<Item = Argument> {
+		self.0.children().filter_map(Argument::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::Argument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Argument(SyntaxNode);
+
+simple_astnode!(Syn, Argument, Syn::Argument);
+
+impl Argument {
+	/// The `name` in `name: expr`, if this is a named argument.
+	#[must_use]
+	pub fn name(&self) -> Option<SyntaxToken> {
+		token(&self.0, Syn::Ident)
+	}
+
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
@@ -0,0 +1,317 @@
+//! AST nodes for representing `default` and `states` blocks.
+
+use rowan::ast::AstNode;
+
+use crate::{
+	simple_astnode,
+	zscript::{Syn, SyntaxNode, SyntaxToken},
+};
+
+use super::{ArgList, Expression, StatCompound};
+
+/// Wraps a node tagged [`Syn::DefaultBlock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultBlock(pub(super) SyntaxNode);
+
+simple_astnode!(Syn, DefaultBlock, Syn::DefaultBlock);
+
+impl DefaultBlock {
+	pub fn items(&self) -> impl Iterator<Item = DefaultItem> {
+		self.0.children().filter_map(|node| match node.kind() {
+			Syn::DefaultFlag => Some(DefaultItem::Flag(DefaultFlag(node))),
+			Syn::DefaultProperty => Some(DefaultItem::Property(DefaultProperty(node))),
+			_ => None,
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefaultItem {
+	Flag(DefaultFlag),
+	Property(DefaultProperty),
+}
+
+/// Wraps a node tagged [`Syn::DefaultFlag`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultFlag(SyntaxNode);
+
+simple_astnode!(Syn, DefaultFlag, Syn::DefaultFlag);
+
+impl DefaultFlag {
+	/// Returns `true` if this is `+FLAG` and `false` if this is `-FLAG`.
+	#[must_use]
+	pub fn is_set(&self) -> bool {
+		self.0.first_token().unwrap().kind() == Syn::Plus
+	}
+
+	/// The flag's name, qualified or not (e.g. `SOLID` or `INVENTORY.QUIET`).
+	#[must_use]
+	pub fn name(&self) -> String {
+		dotted_text(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::DefaultProperty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct DefaultProperty(SyntaxNode);
+
+simple_astnode!(Syn, DefaultProperty, Syn::DefaultProperty);
+
+impl DefaultProperty {
+	/// The property's name, qualified or not (e.g. `Health` or `Inventory.Amount`).
+	#[must_use]
+	pub fn name(&self) -> String {
+		dotted_text(&self.0)
+	}
+
+	/// The first identifier in the name.
+	#[must_use]
+	pub fn name_token(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn values(&self) -> impl Iterator<Item = Expression> {
+		self.0.children().filter_map(Expression::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatesBlock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatesBlock(pub(super) SyntaxNode);
+
+simple_astnode!(Syn, StatesBlock, Syn::StatesBlock);
+
+impl StatesBlock {
+	/// The identifiers in `states(actor, overlay)`, if any were given.
+	pub fn usage(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::StatesUsage)
+			.into_iter()
+			.flat_map(|node| {
+				node.children_with_tokens().filter_map(|n_or_t| {
+					if n_or_t.kind() == Syn::Ident {
+						n_or_t.into_token()
+					} else {
+						None
+					}
+				})
+			})
+	}
+
+	pub fn items(&self) -> impl Iterator<Item = StatesItem> {
+		self.0.children().filter_map(|node| match node.kind() {
+			Syn::StateLabel => Some(StatesItem::Label(StateLabel(node))),
+			Syn::StateFlow => Some(StatesItem::Flow(StateFlow(node))),
+			Syn::StateDef => Some(StatesItem::State(StateDef(node))),
+			_ => None,
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatesItem {
+	Label(StateLabel),
+	Flow(StateFlow),
+	State(StateDef),
+}
+
+/// Wraps a node tagged [`Syn::StateLabel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateLabel(SyntaxNode);
+
+simple_astnode!(Syn, StateLabel, Syn::StateLabel);
+
+impl StateLabel {
+	/// e.g. `Spawn` or `Death.Fire`.
+	#[must_use]
+	pub fn name(&self) -> String {
+		dotted_text(&self.0)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StateFlow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateFlow(SyntaxNode);
+
+simple_astnode!(Syn, StateFlow, Syn::StateFlow);
+
+impl StateFlow {
+	/// The kind of the returned token will be one of the following:
+	/// - [`Syn::KwGoto`]
+	/// - [`Syn::KwStop`]
+	/// - [`Syn::KwLoop`]
+	/// - [`Syn::KwWait`]
+	/// - [`Syn::KwFail`]
+	#[must_use]
+	pub fn keyword(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	/// For `goto`, the target label as written (e.g. `Super::Spawn`),
+	/// without the offset.
+	#[must_use]
+	pub fn goto_target(&self) -> Option<String> {
+		if self.keyword().kind() != Syn::KwGoto {
+			return None;
+		}
+
+		Some(
+			self.0
+				.children_with_tokens()
+				.filter_map(|n_or_t| n_or_t.into_token())
+				.take_while(|token| token.kind() != Syn::Plus && token.kind() != Syn::Semicolon)
+				.filter(|token| matches!(token.kind(), Syn::Ident | Syn::Colon2 | Syn::Period))
+				.map(|token| token.text().to_string())
+				.collect(),
+		)
+	}
+
+	/// For `goto Label+N`, the token for `N`.
+	#[must_use]
+	pub fn goto_offset(&self) -> Option<SyntaxToken> {
+		self.0.children_with_tokens().find_map(|n_or_t| {
+			if n_or_t.kind() == Syn::LitInt {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+	}
+}
+
+/// Wraps a node tagged [`Syn::StateDef`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateDef(SyntaxNode);
+
+simple_astnode!(Syn, StateDef, Syn::StateDef);
+
+impl StateDef {
+	#[must_use]
+	pub fn sprite(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn frames(&self) -> SyntaxToken {
+		self.0
+			.children_with_tokens()
+			.find_map(|n_or_t| {
+				if n_or_t.kind() == Syn::StateFrames {
+					n_or_t.into_token()
+				} else {
+					None
+				}
+			})
+			.unwrap()
+	}
+
+	#[must_use]
+	pub fn duration(&self) -> Expression {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::StateDuration)
+			.and_then(|node| node.first_child())
+			.and_then(Expression::cast)
+			.unwrap()
+	}
+
+	/// The kinds of the yielded tokens (if any) will each be one of the following:
+	/// - [`Syn::KwBright`]
+	/// - [`Syn::KwFast`]
+	/// - [`Syn::KwSlow`]
+	/// - [`Syn::KwNoDelay`]
+	/// - [`Syn::KwCanRaise`]
+	pub fn qualifiers(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0.children_with_tokens().filter_map(|n_or_t| {
+			if matches!(
+				n_or_t.kind(),
+				Syn::KwBright | Syn::KwFast | Syn::KwSlow | Syn::KwNoDelay | Syn::KwCanRaise
+			) {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+	}
+
+	/// The string literal given to `light(...)`, if any.
+	#[must_use]
+	pub fn light(&self) -> Option<SyntaxToken> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::StateLight)
+			.and_then(|node| {
+				node.children_with_tokens().find_map(|n_or_t| {
+					if n_or_t.kind() == Syn::LitString {
+						n_or_t.into_token()
+					} else {
+						None
+					}
+				})
+			})
+	}
+
+	/// The two expressions given to `offset(...)`, if any.
+	#[must_use]
+	pub fn offset(&self) -> Option<(Expression, Expression)> {
+		let node = self
+			.0
+			.children()
+			.find(|node| node.kind() == Syn::StateOffset)?;
+		let mut exprs = node.children().filter_map(Expression::cast);
+		Some((exprs.next()?, exprs.next()?))
+	}
+
+	#[must_use]
+	pub fn action(&self) -> Option<StateActionKind> {
+		self.0.children().find_map(|node| match node.kind() {
+			Syn::StateAction => Some(StateActionKind::Call(StateAction(node))),
+			Syn::StatCompound => Some(StateActionKind::Block(StatCompound(node))),
+			_ => None,
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateActionKind {
+	Call(StateAction),
+	Block(StatCompound),
+}
+
+/// Wraps a node tagged [`Syn::StateAction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StateAction(SyntaxNode);
+
+simple_astnode!(Syn, StateAction, Syn::StateAction);
+
+impl StateAction {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn args(&self) -> Option<ArgList> {
+		self.0.children().find_map(ArgList::cast)
+	}
+}
+
+/// Concatenates the text of the first run of identifier and period tokens
+/// directly beneath `node`.
+fn dotted_text(node: &SyntaxNode) -> String {
+	node.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.skip_while(|token| !matches!(token.kind(), Syn::Ident))
+		.take_while(|token| matches!(token.kind(), Syn::Ident | Syn::Period))
+		.map(|token| token.text().to_string())
+		.collect()
+}
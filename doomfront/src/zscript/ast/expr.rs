@@ -0,0 +1,345 @@
+//! AST nodes for representing expressions.
+
+use rowan::ast::AstNode;
+
+use crate::{
+	simple_astnode,
+	zscript::{Syn, SyntaxNode, SyntaxToken},
+};
+
+use super::{ArgList, Literal, TypeRef};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+	Binary(ExprBinary),
+	Call(ExprCall),
+	ClassCast(ExprClassCast),
+	Group(ExprGroup),
+	Ident(ExprIdent),
+	Index(ExprIndex),
+	Literal(Literal),
+	Member(ExprMember),
+	Postfix(ExprPostfix),
+	Prefix(ExprPrefix),
+	Ternary(ExprTernary),
+	Vector(ExprVector),
+}
+
+impl AstNode for Expression {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::ExprBinary
+				| Syn::ExprCall
+				| Syn::ExprClassCast
+				| Syn::ExprGroup
+				| Syn::ExprIdent
+				| Syn::ExprIndex
+				| Syn::Literal
+				| Syn::ExprMember
+				| Syn::ExprPostfix
+				| Syn::ExprPrefix
+				| Syn::ExprTernary
+				| Syn::ExprVector
+		)
+	}
+
+	fn cast(node: SyntaxNode) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::ExprBinary => Some(Self::Binary(ExprBinary(node))),
+			Syn::ExprCall => Some(Self::Call(ExprCall(node))),
+			Syn::ExprClassCast => Some(Self::ClassCast(ExprClassCast(node))),
+			Syn::ExprGroup => Some(Self::Group(ExprGroup(node))),
+			Syn::ExprIdent => Some(Self::Ident(ExprIdent(node))),
+			Syn::ExprIndex => Some(Self::Index(ExprIndex(node))),
+			Syn::Literal => Some(Self::Literal(Literal(node))),
+			Syn::ExprMember => Some(Self::Member(ExprMember(node))),
+			Syn::ExprPostfix => Some(Self::Postfix(ExprPostfix(node))),
+			Syn::ExprPrefix => Some(Self::Prefix(ExprPrefix(node))),
+			Syn::ExprTernary => Some(Self::Ternary(ExprTernary(node))),
+			Syn::ExprVector => Some(Self::Vector(ExprVector(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::Binary(inner) => &inner.0,
+			Self::Call(inner) => &inner.0,
+			Self::ClassCast(inner) => &inner.0,
+			Self::Group(inner) => &inner.0,
+			Self::Ident(inner) => &inner.0,
+			Self::Index(inner) => &inner.0,
+			Self::Literal(inner) => &inner.0,
+			Self::Member(inner) => &inner.0,
+			Self::Postfix(inner) => &inner.0,
+			Self::Prefix(inner) => &inner.0,
+			Self::Ternary(inner) => &inner.0,
+			Self::Vector(inner) => &inner.0,
+		}
+	}
+}
+
+impl Expression {
+	#[must_use]
+	pub fn into_literal(self) -> Option<Literal> {
+		match self {
+			Self::Literal(lit) => Some(lit),
+			_ => None,
+		}
+	}
+}
+
+/// The first token which is not trivia and not inside a child node.
+fn operator(node: &SyntaxNode) -> SyntaxToken {
+	node.children_with_tokens()
+		.find_map(|n_or_t| {
+			if n_or_t.kind().is_trivia() {
+				None
+			} else {
+				n_or_t.into_token()
+			}
+		})
+		.unwrap()
+}
+
+/// Wraps a node tagged [`Syn::ExprBinary`].
+/// Note that assignments, compound assignments, and `is` use this node too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprBinary(SyntaxNode);
+
+simple_astnode!(Syn, ExprBinary, Syn::ExprBinary);
+
+impl ExprBinary {
+	#[must_use]
+	pub fn left(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		operator(&self.0)
+	}
+
+	#[must_use]
+	pub fn right(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+
+	/// Is the operator `=` or a compound assignment operator like `+=`?
+	#[must_use]
+	pub fn is_assign(&self) -> bool {
+		matches!(
+			self.operator().kind(),
+			Syn::Eq
+				| Syn::PlusEq
+				| Syn::MinusEq
+				| Syn::AsteriskEq
+				| Syn::SlashEq
+				| Syn::PercentEq
+				| Syn::AngleL2Eq
+				| Syn::AngleR2Eq
+				| Syn::AngleR3Eq
+				| Syn::PipeEq
+				| Syn::AmpersandEq
+				| Syn::CaretEq
+		)
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprCall`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprCall(SyntaxNode);
+
+simple_astnode!(Syn, ExprCall, Syn::ExprCall);
+
+impl ExprCall {
+	#[must_use]
+	pub fn callee(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn args(&self) -> ArgList {
+		self.0.children().find_map(ArgList::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprClassCast`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprClassCast(SyntaxNode);
+
+simple_astnode!(Syn, ExprClassCast, Syn::ExprClassCast);
+
+impl ExprClassCast {
+	#[must_use]
+	pub fn type_spec(&self) -> TypeRef {
+		self.0.children().find_map(TypeRef::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn operand(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprGroup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprGroup(SyntaxNode);
+
+simple_astnode!(Syn, ExprGroup, Syn::ExprGroup);
+
+impl ExprGroup {
+	#[must_use]
+	pub fn inner(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprIdent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprIdent(SyntaxNode);
+
+simple_astnode!(Syn, ExprIdent, Syn::ExprIdent);
+
+impl ExprIdent {
+	#[must_use]
+	pub fn token(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprIndex(SyntaxNode);
+
+simple_astnode!(Syn, ExprIndex, Syn::ExprIndex);
+
+impl ExprIndex {
+	#[must_use]
+	pub fn indexed(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn index(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprMember`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprMember(SyntaxNode);
+
+simple_astnode!(Syn, ExprMember, Syn::ExprMember);
+
+impl ExprMember {
+	#[must_use]
+	pub fn owner(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn member(&self) -> SyntaxToken {
+		self.0.last_token().unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprPostfix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprPostfix(SyntaxNode);
+
+simple_astnode!(Syn, ExprPostfix, Syn::ExprPostfix);
+
+impl ExprPostfix {
+	#[must_use]
+	pub fn operand(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		self.0.last_token().unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprPrefix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprPrefix(SyntaxNode);
+
+simple_astnode!(Syn, ExprPrefix, Syn::ExprPrefix);
+
+impl ExprPrefix {
+	#[must_use]
+	pub fn operator(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	#[must_use]
+	pub fn operand(&self) -> Expression {
+		Expression::cast(self.0.last_child().unwrap()).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprTernary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprTernary(SyntaxNode);
+
+simple_astnode!(Syn, ExprTernary, Syn::ExprTernary);
+
+impl ExprTernary {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn if_true(&self) -> Expression {
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.nth(1)
+			.unwrap()
+	}
+
+	#[must_use]
+	pub fn if_false(&self) -> Expression {
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.nth(2)
+			.unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::ExprVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ExprVector(SyntaxNode);
+
+simple_astnode!(Syn, ExprVector, Syn::ExprVector);
+
+impl ExprVector {
+	/// Yields either two or three expressions.
+	pub fn components(&self) -> impl Iterator<Item = Expression> {
+		self.0.children().filter_map(Expression::cast)
+	}
+}
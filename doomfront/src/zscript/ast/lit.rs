@@ -0,0 +1,158 @@
+//! AST nodes for representing literals.
+
+use std::num::ParseIntError;
+
+use rowan::ast::AstNode;
+
+use crate::{
+	simple_astnode,
+	zscript::{Syn, SyntaxNode, SyntaxToken},
+};
+
+/// Wraps a node tagged [`Syn::Literal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Literal(pub(super) SyntaxNode);
+
+simple_astnode!(Syn, Literal, Syn::Literal);
+
+impl Literal {
+	/// The first literal token. For string literals, use [`Self::strings`]
+	/// to account for adjacent literals, which ZScript concatenates.
+	#[must_use]
+	pub fn token(&self) -> LitToken {
+		LitToken(self.0.first_token().unwrap())
+	}
+
+	/// Each [`Syn::LitString`] token in this literal, in order of appearance.
+	pub fn strings(&self) -> impl Iterator<Item = LitToken> {
+		self.0.children_with_tokens().filter_map(|n_or_t| {
+			if n_or_t.kind() == Syn::LitString {
+				n_or_t.into_token().map(LitToken)
+			} else {
+				None
+			}
+		})
+	}
+
+	/// If this is a string literal, returns the content of all of its
+	/// [`Syn::LitString`] tokens concatenated together (with delimiting
+	/// quotation marks stripped away and no escape sequences processed).
+	#[must_use]
+	pub fn concat_string(&self) -> Option<String> {
+		if self.token().0.kind() != Syn::LitString {
+			return None;
+		}
+
+		Some(
+			self.strings()
+				.filter_map(|s| s.string().map(str::to_owned))
+				.collect(),
+		)
+	}
+}
+
+/// Wrapper around a [`SyntaxToken`] with convenience functions.
+/// See [`Syn::Literal`]'s documentation to see possible token tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LitToken(SyntaxToken);
+
+impl LitToken {
+	/// If this wraps a [`Syn::LitTrue`] or [`Syn::LitFalse`] token,
+	/// this returns the corresponding value. Otherwise this returns `None`.
+	#[must_use]
+	pub fn bool(&self) -> Option<bool> {
+		match self.0.kind() {
+			Syn::LitTrue => Some(true),
+			Syn::LitFalse => Some(false),
+			_ => None,
+		}
+	}
+
+	#[must_use]
+	pub fn float(&self) -> Option<f64> {
+		if !matches!(self.0.kind(), Syn::LitFloat) {
+			return None;
+		}
+
+		let text = self.0.text();
+
+		let end = text.len()
+			- text
+				.chars()
+				.rev()
+				.position(|c| !c.eq_ignore_ascii_case(&'f'))
+				.unwrap();
+
+		text[..end].parse::<f64>().ok()
+	}
+
+	/// Returns `None` if this is not tagged with [`Syn::LitInt`].
+	/// Returns `Some(Err)` if integer parsing fails,
+	/// such as if the written value is too large to fit into a `u64`.
+	#[must_use]
+	pub fn int(&self) -> Option<Result<u64, ParseIntError>> {
+		if !matches!(self.0.kind(), Syn::LitInt) {
+			return None;
+		}
+
+		let text = self.0.text();
+
+		let end = text.len()
+			- text
+				.chars()
+				.rev()
+				.position(|c| !(c.eq_ignore_ascii_case(&'u') || c.eq_ignore_ascii_case(&'l')))
+				.unwrap();
+
+		let digits = &text[..end];
+
+		Some(
+			if digits.len() > 2 && (digits.starts_with("0x") || digits.starts_with("0X")) {
+				u64::from_str_radix(&digits[2..], 16)
+			} else if digits.len() > 1 && digits.starts_with('0') {
+				u64::from_str_radix(&digits[1..], 8)
+			} else {
+				digits.parse::<u64>()
+			},
+		)
+	}
+
+	/// If this wraps a [`Syn::LitName`] token, this returns the string's
+	/// content with the delimiting single-quotation marks stripped away.
+	/// Otherwise this returns `None`.
+	#[must_use]
+	pub fn name(&self) -> Option<&str> {
+		if self.0.kind() == Syn::LitName {
+			let text = self.0.text();
+			text.get(1..(text.len() - 1))
+		} else {
+			None
+		}
+	}
+
+	/// Returns `true` if this wraps a [`Syn::LitNull`] token.
+	#[must_use]
+	pub fn is_null(&self) -> bool {
+		self.0.kind() == Syn::LitNull
+	}
+
+	/// If this wraps a [`Syn::LitString`] token, this returns the string's
+	/// content with the delimiting double-quotation marks stripped away.
+	/// Otherwise this returns `None`.
+	#[must_use]
+	pub fn string(&self) -> Option<&str> {
+		if self.0.kind() == Syn::LitString {
+			let text = self.0.text();
+			text.get(1..(text.len() - 1))
+		} else {
+			None
+		}
+	}
+
+	#[must_use]
+	pub fn syntax(&self) -> &SyntaxToken {
+		&self.0
+	}
+}
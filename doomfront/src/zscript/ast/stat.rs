@@ -0,0 +1,463 @@
+//! AST nodes for representing statements.
+
+use rowan::ast::AstNode;
+
+use crate::{
+	simple_astnode,
+	zscript::{Syn, SyntaxNode, SyntaxToken},
+};
+
+use super::{ArrayLen, Expression, InitList, StaticConstDef, TypeRef};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+	AssignMulti(StatAssignMulti),
+	Break(StatBreak),
+	Case(StatCase),
+	CaseDefault(StatCaseDefault),
+	Compound(StatCompound),
+	Continue(StatContinue),
+	DoWhile(StatDoWhile),
+	Empty(StatEmpty),
+	Expr(StatExpr),
+	For(StatFor),
+	ForEach(StatForEach),
+	If(StatIf),
+	LocalVar(StatLocalVar),
+	Return(StatReturn),
+	/// `static const Type name[] = { ... };`, as in a class body.
+	StaticConst(StaticConstDef),
+	Switch(StatSwitch),
+	Until(StatUntil),
+	While(StatWhile),
+}
+
+impl AstNode for Statement {
+	type Language = Syn;
+
+	fn can_cast(kind: <Self::Language as rowan::Language>::Kind) -> bool
+	where
+		Self: Sized,
+	{
+		matches!(
+			kind,
+			Syn::StatAssignMulti
+				| Syn::StatBreak
+				| Syn::StatCase
+				| Syn::StatCaseDefault
+				| Syn::StatCompound
+				| Syn::StatContinue
+				| Syn::StatDoWhile
+				| Syn::StatEmpty
+				| Syn::StatExpr
+				| Syn::StatFor
+				| Syn::StatForEach
+				| Syn::StatIf
+				| Syn::StatLocalVar
+				| Syn::StatReturn
+				| Syn::StaticConstDef
+				| Syn::StatSwitch
+				| Syn::StatUntil
+				| Syn::StatWhile
+		)
+	}
+
+	fn cast(node: SyntaxNode) -> Option<Self>
+	where
+		Self: Sized,
+	{
+		match node.kind() {
+			Syn::StatAssignMulti => Some(Self::AssignMulti(StatAssignMulti(node))),
+			Syn::StatBreak => Some(Self::Break(StatBreak(node))),
+			Syn::StatCase => Some(Self::Case(StatCase(node))),
+			Syn::StatCaseDefault => Some(Self::CaseDefault(StatCaseDefault(node))),
+			Syn::StatCompound => Some(Self::Compound(StatCompound(node))),
+			Syn::StatContinue => Some(Self::Continue(StatContinue(node))),
+			Syn::StatDoWhile => Some(Self::DoWhile(StatDoWhile(node))),
+			Syn::StatEmpty => Some(Self::Empty(StatEmpty(node))),
+			Syn::StatExpr => Some(Self::Expr(StatExpr(node))),
+			Syn::StatFor => Some(Self::For(StatFor(node))),
+			Syn::StatForEach => Some(Self::ForEach(StatForEach(node))),
+			Syn::StatIf => Some(Self::If(StatIf(node))),
+			Syn::StatLocalVar => Some(Self::LocalVar(StatLocalVar(node))),
+			Syn::StatReturn => Some(Self::Return(StatReturn(node))),
+			Syn::StaticConstDef => Some(Self::StaticConst(StaticConstDef::cast(node).unwrap())),
+			Syn::StatSwitch => Some(Self::Switch(StatSwitch(node))),
+			Syn::StatUntil => Some(Self::Until(StatUntil(node))),
+			Syn::StatWhile => Some(Self::While(StatWhile(node))),
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			Self::AssignMulti(inner) => &inner.0,
+			Self::Break(inner) => &inner.0,
+			Self::Case(inner) => &inner.0,
+			Self::CaseDefault(inner) => &inner.0,
+			Self::Compound(inner) => &inner.0,
+			Self::Continue(inner) => &inner.0,
+			Self::DoWhile(inner) => &inner.0,
+			Self::Empty(inner) => &inner.0,
+			Self::Expr(inner) => &inner.0,
+			Self::For(inner) => &inner.0,
+			Self::ForEach(inner) => &inner.0,
+			Self::If(inner) => &inner.0,
+			Self::LocalVar(inner) => &inner.0,
+			Self::Return(inner) => &inner.0,
+			Self::StaticConst(inner) => inner.syntax(),
+			Self::Switch(inner) => &inner.0,
+			Self::Until(inner) => &inner.0,
+			Self::While(inner) => &inner.0,
+		}
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatAssignMulti`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatAssignMulti(SyntaxNode);
+
+simple_astnode!(Syn, StatAssignMulti, Syn::StatAssignMulti);
+
+impl StatAssignMulti {
+	/// The expressions between the brackets.
+	pub fn targets(&self) -> impl Iterator<Item = Expression> {
+		let count = self.0.children().filter_map(Expression::cast).count();
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.take(count - 1)
+	}
+
+	#[must_use]
+	pub fn value(&self) -> Expression {
+		self.0
+			.children()
+			.filter_map(Expression::cast)
+			.last()
+			.unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatBreak`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatBreak(SyntaxNode);
+
+simple_astnode!(Syn, StatBreak, Syn::StatBreak);
+
+/// Wraps a node tagged [`Syn::StatCase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCase(SyntaxNode);
+
+simple_astnode!(Syn, StatCase, Syn::StatCase);
+
+impl StatCase {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatCaseDefault`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCaseDefault(SyntaxNode);
+
+simple_astnode!(Syn, StatCaseDefault, Syn::StatCaseDefault);
+
+/// Wraps a node tagged [`Syn::StatCompound`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatCompound(pub(super) SyntaxNode);
+
+simple_astnode!(Syn, StatCompound, Syn::StatCompound);
+
+impl StatCompound {
+	pub fn statements(&self) -> impl Iterator<Item = Statement> {
+		self.0.children().filter_map(Statement::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatContinue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatContinue(SyntaxNode);
+
+simple_astnode!(Syn, StatContinue, Syn::StatContinue);
+
+/// Wraps a node tagged [`Syn::StatDoWhile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatDoWhile(SyntaxNode);
+
+simple_astnode!(Syn, StatDoWhile, Syn::StatDoWhile);
+
+impl StatDoWhile {
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+
+	/// Returns `true` if this loop ends with `until` instead of `while`.
+	#[must_use]
+	pub fn is_until(&self) -> bool {
+		self.0
+			.children_with_tokens()
+			.any(|n_or_t| n_or_t.kind() == Syn::KwUntil)
+	}
+
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatEmpty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatEmpty(SyntaxNode);
+
+simple_astnode!(Syn, StatEmpty, Syn::StatEmpty);
+
+/// Wraps a node tagged [`Syn::StatExpr`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatExpr(SyntaxNode);
+
+simple_astnode!(Syn, StatExpr, Syn::StatExpr);
+
+impl StatExpr {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		Expression::cast(self.0.first_child().unwrap()).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatFor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatFor(SyntaxNode);
+
+simple_astnode!(Syn, StatFor, Syn::StatFor);
+
+impl StatFor {
+	/// Either local variable declarations or a list of expressions.
+	#[must_use]
+	pub fn init(&self) -> SyntaxNode {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForInit)
+			.unwrap()
+	}
+
+	/// Local variables declared by the initializer, if any.
+	pub fn init_vars(&self) -> impl Iterator<Item = LocalVar> {
+		self.init().children().filter_map(LocalVar::cast)
+	}
+
+	#[must_use]
+	pub fn condition(&self) -> Option<Expression> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForCond)
+			.unwrap()
+			.children()
+			.find_map(Expression::cast)
+	}
+
+	pub fn iteration(&self) -> impl Iterator<Item = Expression> {
+		self.0
+			.children()
+			.find(|node| node.kind() == Syn::ForIter)
+			.unwrap()
+			.children()
+			.filter_map(Expression::cast)
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatForEach`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatForEach(SyntaxNode);
+
+simple_astnode!(Syn, StatForEach, Syn::StatForEach);
+
+impl StatForEach {
+	/// The element variable, or the key and value variables when iterating
+	/// over a map.
+	pub fn bindings(&self) -> impl Iterator<Item = SyntaxToken> {
+		self.0.children_with_tokens().filter_map(|n_or_t| {
+			if n_or_t.kind() == Syn::Ident {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+	}
+
+	#[must_use]
+	pub fn collection(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatIf`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatIf(SyntaxNode);
+
+simple_astnode!(Syn, StatIf, Syn::StatIf);
+
+impl StatIf {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn then_branch(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn else_branch(&self) -> Option<Statement> {
+		self.0.children().filter_map(Statement::cast).nth(1)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatLocalVar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatLocalVar(SyntaxNode);
+
+simple_astnode!(Syn, StatLocalVar, Syn::StatLocalVar);
+
+impl StatLocalVar {
+	#[must_use]
+	pub fn type_spec(&self) -> TypeRef {
+		self.0.children().find_map(TypeRef::cast).unwrap()
+	}
+
+	pub fn vars(&self) -> impl Iterator<Item = LocalVar> {
+		self.0.children().filter_map(LocalVar::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::LocalVar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LocalVar(SyntaxNode);
+
+simple_astnode!(Syn, LocalVar, Syn::LocalVar);
+
+impl LocalVar {
+	#[must_use]
+	pub fn name(&self) -> SyntaxToken {
+		self.0.first_token().unwrap()
+	}
+
+	pub fn array_lens(&self) -> impl Iterator<Item = ArrayLen> {
+		self.0.children().filter_map(ArrayLen::cast)
+	}
+
+	#[must_use]
+	pub fn initializer(&self) -> Option<Expression> {
+		self.0.children().find_map(Expression::cast)
+	}
+
+	#[must_use]
+	pub fn init_list(&self) -> Option<InitList> {
+		self.0.children().find_map(InitList::cast)
+	}
+
+	/// The type written for the declaration containing this variable.
+	#[must_use]
+	pub fn type_spec(&self) -> Option<TypeRef> {
+		self.0
+			.parent()
+			.and_then(|parent| parent.children().find_map(TypeRef::cast))
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatReturn`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatReturn(SyntaxNode);
+
+simple_astnode!(Syn, StatReturn, Syn::StatReturn);
+
+impl StatReturn {
+	pub fn exprs(&self) -> impl Iterator<Item = Expression> {
+		self.0.children().filter_map(Expression::cast)
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatSwitch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatSwitch(SyntaxNode);
+
+simple_astnode!(Syn, StatSwitch, Syn::StatSwitch);
+
+impl StatSwitch {
+	#[must_use]
+	pub fn expr(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatUntil`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatUntil(SyntaxNode);
+
+simple_astnode!(Syn, StatUntil, Syn::StatUntil);
+
+impl StatUntil {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
+
+/// Wraps a node tagged [`Syn::StatWhile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StatWhile(SyntaxNode);
+
+simple_astnode!(Syn, StatWhile, Syn::StatWhile);
+
+impl StatWhile {
+	#[must_use]
+	pub fn condition(&self) -> Expression {
+		self.0.children().find_map(Expression::cast).unwrap()
+	}
+
+	#[must_use]
+	pub fn body(&self) -> Statement {
+		self.0.children().find_map(Statement::cast).unwrap()
+	}
+}
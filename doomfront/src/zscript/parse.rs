@@ -0,0 +1,236 @@
+mod actor;
+mod expr;
+mod stat;
+mod top;
+
+use chumsky::{primitive, recovery, text, Error, Parser};
+use rowan::{ast::AstNode, GreenNode, SyntaxKind};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{ast, IncludeTree, RawParseTree, Syn};
+
+/// Upon encountering any error, the parser will immediately stop.
+/// Prefer [`parse_recov`] since it allows providing the user with more
+/// actionable information, enabling more fixing of one's code at once.
+pub fn parse(source: &str) -> Result<RawParseTree, Vec<ParseError>> {
+	let parser = primitive::choice((wsp_ext(source), top::item(source)))
+		.repeated()
+		.then_ignore(primitive::end())
+		.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	parser
+		.parse(source)
+		.map(|root| RawParseTree::new(root, vec![]))
+}
+
+/// "Recoverable parse". Unless `source` has no tokens whatsoever, this always
+/// emits `Some`, although the returned tree may have errors attached.
+///
+/// When faced with unexpected input, the parser raises an error and then tries
+/// to skip ahead to the next semicolon, carriage return, or newline. This is
+/// done at the granularity of top-level definitions, class and struct members,
+/// `default` block items, state definitions, and statements, so one broken
+/// function body does not hide the rest of its class. All input between the
+/// error location and the next valid thing gets wrapped into a token tagged
+/// [`Syn::Unknown`].
+#[must_use]
+pub fn parse_recov(source: &str) -> Option<RawParseTree> {
+	let parser = primitive::choice((
+		wsp_ext(source),
+		top::item(source).recover_with(recovery::skip_parser(recover_top(source))),
+	))
+	.repeated()
+	.then_ignore(primitive::end())
+	.collect_g::<Syn, { Syn::Root as u16 }>();
+
+	let (root, errs) = parser.parse_recovery(source);
+
+	root.map(|r| RawParseTree::new(r, errs))
+}
+
+/// Parses `root` (typically the `ZSCRIPT` lump) with [`parse_recov`], and then
+/// every file it includes. `fetch` retrieves a file's source given a path as
+/// written in an `#include` directive; see [`IncludeTree::new`] for details.
+///
+/// Returns `None` if `fetch` fails to retrieve `root`.
+///
+/// [`IncludeTree::new`]: crate::repr::IncludeTree::new
+#[must_use]
+pub fn parse_include_tree<F>(root: &str, fetch: F) -> Option<IncludeTree>
+where
+	F: FnMut(&str) -> Option<String>,
+{
+	IncludeTree::new(
+		root,
+		fetch,
+		|source| {
+			parse_recov(source)
+				.unwrap_or_else(|| RawParseTree::new(GreenNode::new(Syn::Root.into(), []), vec![]))
+		},
+		|zipper| {
+			zipper
+				.children()
+				.filter_map(ast::IncludeDirective::cast)
+				.map(|directive| (directive.path(), directive.syntax().text_range()))
+				.collect()
+		},
+	)
+}
+
+// Common combinators /////////////////////////////////////////////////////////
+
+fn node(syn: Syn, elems: Vec<ParseOut>) -> ParseOut {
+	ParseOut::Node(GreenNode::new(SyntaxKind::from(syn), elems))
+}
+
+/// Keywords which can never be used as identifiers.
+/// Contextual keywords (e.g. `default`, `version`, `bright`) are omitted.
+const RESERVED: &[&str] = &[
+	"alignof", "break", "case", "continue", "cross", "do", "dot", "else", "false", "for",
+	"foreach", "if", "is", "let", "null", "return", "sizeof", "switch", "true", "until", "while",
+];
+
+fn wsp_ext(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::wsp_ext::<Syn, _>(comb::c_cpp_comment::<Syn>(src), src)
+}
+
+/// Zero or more whitespace and/or comment tokens.
+fn trivia(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	wsp_ext(src).repeated()
+}
+
+/// Wraps the output of `parser` in a `Vec` if it succeeds, or returns an
+/// empty `Vec` otherwise. For optional sequences of syntax elements.
+fn opt<'s, P>(parser: P) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + 's,
+{
+	parser.or_not().map(Option::unwrap_or_default)
+}
+
+/// A single- or multi-character glyph.
+fn glyph<'i>(
+	src: &'i str,
+	string: &'static str,
+	syn: Syn,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 'i {
+	comb::just::<Syn, _>(string, syn, src)
+}
+
+/// An identifier which is not one of the [reserved keywords](RESERVED).
+fn ident(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident()
+		.try_map(|s: String, span| {
+			if RESERVED.iter().any(|r| s.eq_ignore_ascii_case(r)) {
+				Err(ParseError::expected_input_found(span, None, None))
+			} else {
+				Ok(())
+			}
+		})
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+		.labelled("identifier")
+}
+
+/// Any identifier, reserved keywords included. Used for member names.
+fn ident_lax(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	text::ident::<char, ParseError>().map_with_span(help::map_tok::<Syn, _>(src, Syn::Ident))
+}
+
+/// e.g. `Inventory.Amount` or `Death.Fire`.
+/// Emits the identifiers and periods as flat tokens.
+fn dotted_ident(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	ident(src).start_vec().chain_append(
+		glyph(src, ".", Syn::Period)
+			.start_vec()
+			.chain_push(ident_lax(src))
+			.repeated()
+			.flatten(),
+	)
+}
+
+fn name_lit(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('\'')
+		.then(primitive::none_of("'\r\n").repeated())
+		.then(primitive::just('\''))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::LitName))
+		.labelled("name literal")
+}
+
+/// `int`, `Actor`, `Class<Actor>`, `Array<Name>`, `Map<Name, int>`, et cetera.
+fn type_ref(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	chumsky::recursive::recursive(|ty| {
+		let generic1 = |kwp| {
+			Parser1::start_vec(kwp)
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, "<", Syn::AngleL))
+				.chain_append(trivia(src))
+				.chain_push(ty.clone())
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, ">", Syn::AngleR))
+		};
+
+		let generic2 = |kwp| {
+			Parser1::start_vec(kwp)
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, "<", Syn::AngleL))
+				.chain_append(trivia(src))
+				.chain_push(ty.clone())
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(ty.clone())
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, ">", Syn::AngleR))
+		};
+
+		primitive::choice((
+			comb::kw::<Syn>("let", Syn::KwLet, src).start_vec(),
+			class_type(src),
+			generic2(comb::kw::<Syn>("mapiterator", Syn::KwMapIterator, src).boxed()),
+			generic2(comb::kw::<Syn>("map", Syn::KwMap, src).boxed()),
+			generic1(comb::kw::<Syn>("array", Syn::KwArray, src).boxed()),
+			generic1(comb::kw::<Syn>("readonly", Syn::KwReadOnly, src).boxed()),
+			dotted_ident(src),
+		))
+		.collect_n::<Syn, { Syn::TypeRef as u16 }>()
+		.labelled("type")
+		.boxed()
+	})
+}
+
+/// `class` or `class<Name>`, not wrapped in a node.
+fn class_type(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("class", Syn::KwClass, src)
+		.start_vec()
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, "<", Syn::AngleL))
+			.chain_append(trivia(src))
+			.chain_push(dotted_ident(src).collect_n::<Syn, { Syn::TypeRef as u16 }>())
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ">", Syn::AngleR))))
+}
+
+/// Skips input up to and including the next semicolon, or up to the next
+/// newline or closing brace, whichever comes first.
+fn recover(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("};\r\n")
+		.repeated()
+		.at_least(1)
+		.then(primitive::just(';').or_not())
+		.ignored()
+		.or(primitive::just(';').ignored())
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
+
+/// Like [`recover`], but also skips stray closing braces.
+fn recover_top(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::none_of("\r\n")
+		.repeated()
+		.at_least(1)
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::Unknown))
+}
@@ -0,0 +1,232 @@
+//! Parsers for the actor-specific `default` and `states` blocks.
+
+use chumsky::{primitive, recovery, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{
+	dotted_ident,
+	expr::{arg_list, expr},
+	glyph, ident, opt, recover, stat, trivia, wsp_ext, Syn,
+};
+
+/// `default { ... }`
+pub(super) fn default_block(
+	src: &str,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let flag = primitive::choice((glyph(src, "+", Syn::Plus), glyph(src, "-", Syn::Minus)))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(dotted_ident(src))
+		.chain_append(opt(trivia(src).chain_push(glyph(src, ";", Syn::Semicolon))))
+		.collect_n::<Syn, { Syn::DefaultFlag as u16 }>();
+
+	let property = dotted_ident(src)
+		.chain_append(opt(trivia(src).chain_push(expr(src)).chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(expr(src))
+				.repeated()
+				.flatten(),
+		)))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::DefaultProperty as u16 }>();
+
+	let item = primitive::choice((flag, property, glyph(src, ";", Syn::Semicolon)));
+
+	comb::kw::<Syn>("default", Syn::KwDefault, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "{", Syn::BraceL))
+		.chain_append(
+			primitive::choice((
+				wsp_ext(src),
+				item.recover_with(recovery::skip_parser(recover(src))),
+			))
+			.repeated(),
+		)
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.collect_n::<Syn, { Syn::DefaultBlock as u16 }>()
+		.boxed()
+}
+
+/// `states(actor, overlay) { ... }`
+pub(super) fn states_block(
+	src: &str,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let usage = glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(ident(src))
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::StatesUsage as u16 }>();
+
+	let item = primitive::choice((state_flow(src), state_label(src), state_def(src)));
+
+	comb::kw::<Syn>("states", Syn::KwStates, src)
+		.start_vec()
+		.chain_append(opt(trivia(src).chain_push(usage)))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "{", Syn::BraceL))
+		.chain_append(
+			primitive::choice((
+				wsp_ext(src),
+				item.recover_with(recovery::skip_parser(recover(src))),
+			))
+			.repeated(),
+		)
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.collect_n::<Syn, { Syn::StatesBlock as u16 }>()
+		.boxed()
+}
+
+/// `goto Label+offset;`, `stop;`, `loop;`, `wait;`, or `fail;`.
+fn state_flow(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let target = ident(src)
+		.start_vec()
+		.chain_append(opt(glyph(src, "::", Syn::Colon2)
+			.start_vec()
+			.chain_push(ident(src))))
+		.chain_append(
+			glyph(src, ".", Syn::Period)
+				.start_vec()
+				.chain_push(ident(src))
+				.repeated()
+				.flatten(),
+		);
+
+	let goto = comb::kw::<Syn>("goto", Syn::KwGoto, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(target)
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, "+", Syn::Plus))
+			.chain_append(trivia(src))
+			.chain_push(comb::c_int::<Syn>(src, Syn::LitInt))));
+
+	primitive::choice((
+		goto,
+		comb::kw::<Syn>("stop", Syn::KwStop, src).start_vec(),
+		comb::kw::<Syn>("loop", Syn::KwLoop, src).start_vec(),
+		comb::kw::<Syn>("wait", Syn::KwWait, src).start_vec(),
+		comb::kw::<Syn>("fail", Syn::KwFail, src).start_vec(),
+	))
+	.chain_append(trivia(src))
+	.chain_push(glyph(src, ";", Syn::Semicolon))
+	.collect_n::<Syn, { Syn::StateFlow as u16 }>()
+	.boxed()
+}
+
+/// `Label:` or `Death.Fire:`.
+fn state_label(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	dotted_ident(src)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.then_ignore(primitive::just(':').not().rewind())
+		.collect_n::<Syn, { Syn::StateLabel as u16 }>()
+		.boxed()
+}
+
+/// `SPRT ABCD duration qualifiers action;`
+fn state_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let sprite = primitive::choice((
+		primitive::filter(|c: &char| c.is_ascii_alphanumeric() || matches!(c, '_' | '#' | '-'))
+			.repeated()
+			.exactly(4)
+			.ignored(),
+		primitive::just('"')
+			.then(primitive::none_of("\"\r\n").repeated().exactly(4))
+			.then(primitive::just('"'))
+			.ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::StateSprite))
+	.labelled("sprite name");
+
+	let frames = primitive::choice((
+		primitive::filter(|c: &char| {
+			c.is_ascii_alphabetic() || matches!(c, '[' | ']' | '\\' | '#')
+		})
+		.repeated()
+		.at_least(1)
+		.ignored(),
+		primitive::just('"')
+			.then(primitive::none_of("\"\r\n").repeated().at_least(1))
+			.then(primitive::just('"'))
+			.ignored(),
+	))
+	.map_with_span(help::map_tok::<Syn, _>(src, Syn::StateFrames))
+	.labelled("frame characters");
+
+	let light = comb::kw::<Syn>("light", Syn::KwLight, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "(", Syn::ParenL))
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::StateLight as u16 }>();
+
+	let offset = comb::kw::<Syn>("offset", Syn::KwOffset, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "(", Syn::ParenL))
+		.chain_append(trivia(src))
+		.chain_push(expr(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ",", Syn::Comma))
+		.chain_append(trivia(src))
+		.chain_push(expr(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::StateOffset as u16 }>();
+
+	let qual = primitive::choice((
+		comb::kw::<Syn>("bright", Syn::KwBright, src),
+		comb::kw::<Syn>("fast", Syn::KwFast, src),
+		comb::kw::<Syn>("slow", Syn::KwSlow, src),
+		comb::kw::<Syn>("nodelay", Syn::KwNoDelay, src),
+		comb::kw::<Syn>("canraise", Syn::KwCanRaise, src),
+		light,
+		offset,
+	));
+
+	let action = primitive::choice((
+		stat::compound(src, stat::statement(src, expr(src))).start_vec(),
+		ident(src)
+			.start_vec()
+			.chain_append(opt(trivia(src).chain_push(arg_list(src, expr(src)))))
+			.collect_n::<Syn, { Syn::StateAction as u16 }>()
+			.start_vec()
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ";", Syn::Semicolon)),
+		glyph(src, ";", Syn::Semicolon).start_vec(),
+	));
+
+	sprite
+		.start_vec()
+		.chain_append(wsp_ext(src).repeated().at_least(1))
+		.chain_push(frames)
+		.chain_append(wsp_ext(src).repeated().at_least(1))
+		.chain_push(expr(src).remap::<Syn, { Syn::StateDuration as u16 }>())
+		.chain_append(trivia(src).chain_push(qual).repeated().flatten())
+		.chain_append(trivia(src))
+		.chain_append(action)
+		.collect_n::<Syn, { Syn::StateDef as u16 }>()
+		.boxed()
+}
@@ -0,0 +1,356 @@
+//! Expression parsers, from primaries up through assignments.
+//!
+//! Precedence and associativity follow GZDoom's own grammar, which (like Ruby)
+//! places the bitwise operators above the comparison operators.
+
+use chumsky::{primitive, recursive::recursive, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	ParseError, ParseOut,
+};
+
+use super::{class_type, glyph, ident, ident_lax, name_lit, node, opt, trivia, Syn};
+
+pub(super) fn expr(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	recursive(|expr| {
+		let primary = primitive::choice((
+			literal(src),
+			class_cast(src, expr.clone()),
+			group_or_vector(src, expr.clone()),
+			ident(src).remap::<Syn, { Syn::ExprIdent as u16 }>(),
+		))
+		.labelled("expression")
+		.boxed();
+
+		let postfix = primary
+			.then(postfix_suffix(src, expr.clone()).repeated())
+			.foldl(|lhs, (syn, mut elems)| {
+				elems.insert(0, lhs);
+				node(syn, elems)
+			})
+			.boxed();
+
+		let prefix_op = primitive::choice((
+			glyph(src, "++", Syn::Plus2),
+			glyph(src, "--", Syn::Minus2),
+			glyph(src, "+", Syn::Plus),
+			glyph(src, "-", Syn::Minus),
+			glyph(src, "!", Syn::Bang),
+			glyph(src, "~", Syn::Tilde),
+			comb::kw::<Syn>("sizeof", Syn::KwSizeOf, src),
+			comb::kw::<Syn>("alignof", Syn::KwAlignOf, src),
+		));
+
+		let prefix = prefix_op
+			.start_vec()
+			.chain_append(trivia(src))
+			.repeated()
+			.then(postfix)
+			.foldr(|mut elems, rhs| {
+				elems.push(rhs);
+				node(Syn::ExprPrefix, elems)
+			})
+			.boxed();
+
+		let pow = binary(src, prefix, glyph(src, "**", Syn::Asterisk2));
+
+		let mul = binary(
+			src,
+			pow,
+			primitive::choice((
+				glyph(src, "*", Syn::Asterisk),
+				glyph(src, "/", Syn::Slash),
+				glyph(src, "%", Syn::Percent),
+				comb::kw::<Syn>("cross", Syn::KwCross, src),
+				comb::kw::<Syn>("dot", Syn::KwDot, src),
+			)),
+		);
+
+		let add = binary(
+			src,
+			mul,
+			primitive::choice((glyph(src, "+", Syn::Plus), glyph(src, "-", Syn::Minus))),
+		);
+
+		let shift = binary(
+			src,
+			add,
+			primitive::choice((
+				glyph(src, ">>>", Syn::AngleR3),
+				glyph(src, ">>", Syn::AngleR2),
+				glyph(src, "<<", Syn::AngleL2),
+			)),
+		);
+
+		let bit_and = binary(src, shift, glyph(src, "&", Syn::Ampersand));
+		let bit_xor = binary(src, bit_and, glyph(src, "^", Syn::Caret));
+		let bit_or = binary(src, bit_xor, glyph(src, "|", Syn::Pipe));
+		let concat = binary(src, bit_or, glyph(src, "..", Syn::Period2));
+
+		let relational = binary(
+			src,
+			concat,
+			primitive::choice((
+				glyph(src, "<>=", Syn::AngleLAngleREq),
+				glyph(src, "<=", Syn::AngleLEq),
+				glyph(src, ">=", Syn::AngleREq),
+				glyph(src, "<", Syn::AngleL),
+				glyph(src, ">", Syn::AngleR),
+				comb::kw::<Syn>("is", Syn::KwIs, src),
+			)),
+		);
+
+		let equality = binary(
+			src,
+			relational,
+			primitive::choice((
+				glyph(src, "==", Syn::Eq2),
+				glyph(src, "!=", Syn::BangEq),
+				glyph(src, "~==", Syn::TildeEq2),
+			)),
+		);
+
+		let log_and = binary(src, equality, glyph(src, "&&", Syn::Ampersand2));
+		let log_or = binary(src, log_and, glyph(src, "||", Syn::Pipe2));
+
+		let ternary = log_or
+			.then(opt(trivia(src)
+				.chain_push(glyph(src, "?", Syn::Question))
+				.chain_append(trivia(src))
+				.chain_push(expr.clone())
+				.chain_append(trivia(src))
+				.chain_push(glyph(src, ":", Syn::Colon))
+				.chain_append(trivia(src))
+				.chain_push(expr.clone())))
+			.map(|(lhs, mut rest)| {
+				if rest.is_empty() {
+					lhs
+				} else {
+					rest.insert(0, lhs);
+					node(Syn::ExprTernary, rest)
+				}
+			})
+			.boxed();
+
+		let assign_op = primitive::choice((
+			glyph(src, "=", Syn::Eq),
+			glyph(src, "+=", Syn::PlusEq),
+			glyph(src, "-=", Syn::MinusEq),
+			glyph(src, "*=", Syn::AsteriskEq),
+			glyph(src, "/=", Syn::SlashEq),
+			glyph(src, "%=", Syn::PercentEq),
+			glyph(src, "<<=", Syn::AngleL2Eq),
+			glyph(src, ">>>=", Syn::AngleR3Eq),
+			glyph(src, ">>=", Syn::AngleR2Eq),
+			glyph(src, "|=", Syn::PipeEq),
+			glyph(src, "&=", Syn::AmpersandEq),
+			glyph(src, "^=", Syn::CaretEq),
+		));
+
+		ternary
+			.then(opt(trivia(src)
+				.chain_push(assign_op)
+				.chain_append(trivia(src))
+				.chain_push(expr)))
+			.map(|(lhs, mut rest)| {
+				if rest.is_empty() {
+					lhs
+				} else {
+					rest.insert(0, lhs);
+					node(Syn::ExprBinary, rest)
+				}
+			})
+			.boxed()
+	})
+}
+
+/// `(expr, name: expr, ...)`
+pub(super) fn arg_list<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	let arg = opt(ident_lax(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.then_ignore(primitive::just(':').not().rewind())
+		.chain_append(trivia(src)))
+	.chain_push(expr)
+	.collect_n::<Syn, { Syn::Argument as u16 }>();
+
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(arg
+			.clone()
+			.start_vec()
+			.chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(arg)
+					.repeated()
+					.flatten(),
+			)
+			.chain_append(trivia(src))))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::ArgList as u16 }>()
+		.boxed()
+}
+
+fn literal(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let strings = comb::string_lit::<Syn>(src, Syn::LitString)
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+				.repeated()
+				.flatten(),
+		);
+
+	primitive::choice((
+		comb::c_float::<Syn>(src, Syn::LitFloat)
+			.labelled("floating-point literal")
+			.start_vec(),
+		comb::c_int::<Syn>(src, Syn::LitInt)
+			.labelled("integer literal")
+			.start_vec(),
+		strings,
+		name_lit(src).start_vec(),
+		comb::kw::<Syn>("true", Syn::LitTrue, src).start_vec(),
+		comb::kw::<Syn>("false", Syn::LitFalse, src).start_vec(),
+		comb::kw::<Syn>("null", Syn::LitNull, src).start_vec(),
+	))
+	.collect_n::<Syn, { Syn::Literal as u16 }>()
+	.boxed()
+}
+
+/// `(class<Type>)(expr)`
+fn class_cast<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(class_type(src).collect_n::<Syn, { Syn::TypeRef as u16 }>())
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "(", Syn::ParenL))
+		.chain_append(trivia(src))
+		.chain_push(expr)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::ExprClassCast as u16 }>()
+		.boxed()
+}
+
+/// `(expr)`, or a vector literal of two or three components.
+fn group_or_vector<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(expr.clone())
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(expr)
+				.repeated()
+				.at_most(2)
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.map(|elems| {
+			let vector = elems
+				.iter()
+				.any(|elem| matches!(elem, ParseOut::Token(t) if t.kind() == Syn::Comma.into()));
+
+			node(
+				if vector {
+					Syn::ExprVector
+				} else {
+					Syn::ExprGroup
+				},
+				elems,
+			)
+		})
+		.boxed()
+}
+
+/// Call argument lists, index operators, member accesses, and
+/// postfix increments/decrements.
+fn postfix_suffix<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, (Syn, Vec<ParseOut>), Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	primitive::choice((
+		trivia(src)
+			.chain_push(arg_list(src, expr.clone()))
+			.map(|elems| (Syn::ExprCall, elems)),
+		trivia(src)
+			.chain_push(glyph(src, "[", Syn::BracketL))
+			.chain_append(trivia(src))
+			.chain_push(expr)
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, "]", Syn::BracketR))
+			.map(|elems| (Syn::ExprIndex, elems)),
+		trivia(src)
+			.chain_push(glyph(src, ".", Syn::Period))
+			.chain_append(trivia(src))
+			.chain_push(ident_lax(src))
+			.map(|elems| (Syn::ExprMember, elems)),
+		trivia(src)
+			.chain_push(primitive::choice((
+				glyph(src, "++", Syn::Plus2),
+				glyph(src, "--", Syn::Minus2),
+			)))
+			.map(|elems| (Syn::ExprPostfix, elems)),
+	))
+	.boxed()
+}
+
+/// A left-associative binary operator precedence level.
+fn binary<'s, P, O>(
+	src: &'s str,
+	operand: P,
+	op: O,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+	O: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	operand
+		.clone()
+		.then(
+			trivia(src)
+				.chain_push(op)
+				.chain_append(trivia(src))
+				.chain_push(operand)
+				.repeated(),
+		)
+		.foldl(|lhs, mut elems| {
+			elems.insert(0, lhs);
+			node(Syn::ExprBinary, elems)
+		})
+		.boxed()
+}
@@ -0,0 +1,586 @@
+//! Parsers for top-level definitions and the members of classes and structs.
+
+use chumsky::{primitive, recovery, Error, Parser};
+
+use crate::{
+	comb,
+	ext::{Parser1, ParserVec},
+	help, ParseError, ParseOut,
+};
+
+use super::{
+	actor, expr::expr, glyph, ident, node, opt, recover, stat, trivia, type_ref, wsp_ext, Syn,
+};
+
+/// One of the top-level elements of a file.
+pub(super) fn item(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::choice((
+		include_directive(src),
+		version_directive(src),
+		class_def(src),
+		class_extend(src),
+		struct_def(src),
+		struct_extend(src),
+		mixin_class_def(src),
+		enum_def(src),
+		const_def(src),
+	))
+	.boxed()
+}
+
+/// `#include "path"`
+fn include_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	primitive::just('#')
+		.then(comb::just_nc("include"))
+		.map_with_span(help::map_tok::<Syn, _>(src, Syn::KwInclude))
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::IncludeDirective as u16 }>()
+}
+
+/// `version "x.y.z"`
+fn version_directive(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("version", Syn::KwVersion, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.collect_n::<Syn, { Syn::VersionDirective as u16 }>()
+}
+
+fn class_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let inherit = glyph(src, ":", Syn::Colon)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.collect_n::<Syn, { Syn::InheritSpec as u16 }>();
+
+	let replaces = comb::kw::<Syn>("replaces", Syn::KwReplaces, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.collect_n::<Syn, { Syn::ReplacesClause as u16 }>();
+
+	// GZDoom takes these in any order.
+	let qual = primitive::choice((
+		replaces,
+		comb::kw::<Syn>("abstract", Syn::KwAbstract, src),
+		comb::kw::<Syn>("native", Syn::KwNative, src),
+		comb::kw::<Syn>("play", Syn::KwPlay, src),
+		comb::kw::<Syn>("ui", Syn::KwUi, src),
+		version_qual(src),
+	));
+
+	comb::kw::<Syn>("class", Syn::KwClass, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(opt(trivia(src).chain_push(inherit)))
+		.chain_push(
+			trivia(src)
+				.chain_push(qual)
+				.repeated()
+				.flatten()
+				.collect_n::<Syn, { Syn::DeclQualifiers as u16 }>(),
+		)
+		.chain_append(trivia(src))
+		.chain_append(class_body(src))
+		.collect_n::<Syn, { Syn::ClassDef as u16 }>()
+		.boxed()
+}
+
+fn class_extend(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("extend", Syn::KwExtend, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::kw::<Syn>("class", Syn::KwClass, src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_append(class_body(src))
+		.collect_n::<Syn, { Syn::ClassExtend as u16 }>()
+		.boxed()
+}
+
+fn mixin_class_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("mixin", Syn::KwMixin, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::kw::<Syn>("class", Syn::KwClass, src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_append(class_body(src))
+		.collect_n::<Syn, { Syn::MixinClassDef as u16 }>()
+		.boxed()
+}
+
+fn struct_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let qual = primitive::choice((
+		comb::kw::<Syn>("native", Syn::KwNative, src),
+		comb::kw::<Syn>("play", Syn::KwPlay, src),
+		comb::kw::<Syn>("ui", Syn::KwUi, src),
+		comb::kw::<Syn>("clearscope", Syn::KwClearScope, src),
+		version_qual(src),
+	));
+
+	comb::kw::<Syn>("struct", Syn::KwStruct, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_push(
+			trivia(src)
+				.chain_push(qual)
+				.repeated()
+				.flatten()
+				.collect_n::<Syn, { Syn::DeclQualifiers as u16 }>(),
+		)
+		.chain_append(trivia(src))
+		.chain_append(struct_body(src))
+		.chain_append(opt(trivia(src).chain_push(glyph(src, ";", Syn::Semicolon))))
+		.collect_n::<Syn, { Syn::StructDef as u16 }>()
+		.boxed()
+}
+
+fn struct_extend(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("extend", Syn::KwExtend, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::kw::<Syn>("struct", Syn::KwStruct, src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_append(struct_body(src))
+		.collect_n::<Syn, { Syn::StructExtend as u16 }>()
+		.boxed()
+}
+
+/// `{ ... }`, with recovery at the granularity of each member.
+fn class_body(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let innard = primitive::choice((
+		const_def(src),
+		enum_def(src),
+		struct_def(src),
+		static_const_def(src),
+		property_def(src),
+		flag_def(src),
+		actor::default_block(src),
+		actor::states_block(src),
+		mixin_stat(src),
+		member_decl(src),
+	));
+
+	glyph(src, "{", Syn::BraceL)
+		.start_vec()
+		.chain_append(
+			primitive::choice((
+				wsp_ext(src),
+				innard.recover_with(recovery::skip_parser(recover(src))),
+			))
+			.repeated(),
+		)
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.boxed()
+}
+
+/// `{ ... }`, with recovery at the granularity of each member.
+/// Structs can not hold nested structs, properties, flags, or actor blocks.
+fn struct_body(src: &str) -> impl Parser<char, Vec<ParseOut>, Error = ParseError> + Clone + '_ {
+	let innard = primitive::choice((
+		const_def(src),
+		enum_def(src),
+		static_const_def(src),
+		member_decl(src),
+	));
+
+	glyph(src, "{", Syn::BraceL)
+		.start_vec()
+		.chain_append(
+			primitive::choice((
+				wsp_ext(src),
+				innard.recover_with(recovery::skip_parser(recover(src))),
+			))
+			.repeated(),
+		)
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.boxed()
+}
+
+fn const_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("const", Syn::KwConst, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "=", Syn::Eq))
+		.chain_append(trivia(src))
+		.chain_push(expr(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::ConstDef as u16 }>()
+		.boxed()
+}
+
+fn enum_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let variant = ident(src)
+		.start_vec()
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, "=", Syn::Eq))
+			.chain_append(trivia(src))
+			.chain_push(expr(src))))
+		.collect_n::<Syn, { Syn::EnumVariant as u16 }>();
+
+	comb::kw::<Syn>("enum", Syn::KwEnum, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, ":", Syn::Colon))
+			.chain_append(trivia(src))
+			.chain_push(type_ref(src))))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "{", Syn::BraceL))
+		.chain_append(trivia(src))
+		.chain_append(opt(variant
+			.clone()
+			.start_vec()
+			.chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(variant)
+					.repeated()
+					.flatten(),
+			)
+			.chain_append(opt(trivia(src).chain_push(glyph(src, ",", Syn::Comma))))
+			.chain_append(trivia(src))))
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.chain_append(opt(trivia(src).chain_push(glyph(src, ";", Syn::Semicolon))))
+		.collect_n::<Syn, { Syn::EnumDef as u16 }>()
+		.boxed()
+}
+
+pub(super) fn static_const_def(
+	src: &str,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("static", Syn::KwStatic, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(comb::kw::<Syn>("const", Syn::KwConst, src))
+		.chain_append(trivia(src))
+		.chain_push(type_ref(src))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "[", Syn::BracketL))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "]", Syn::BracketR))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "=", Syn::Eq))
+		.chain_append(trivia(src))
+		.chain_push(init_list(src, expr(src)))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::StaticConstDef as u16 }>()
+		.boxed()
+}
+
+fn property_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("property", Syn::KwProperty, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(ident(src))
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::PropertyDef as u16 }>()
+		.boxed()
+}
+
+fn flag_def(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("flagdef", Syn::KwFlagDef, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ":", Syn::Colon))
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ",", Syn::Comma))
+		.chain_append(trivia(src))
+		.chain_push(comb::c_int::<Syn>(src, Syn::LitInt))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::FlagDef as u16 }>()
+		.boxed()
+}
+
+fn mixin_stat(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("mixin", Syn::KwMixin, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.collect_n::<Syn, { Syn::MixinStat as u16 }>()
+		.boxed()
+}
+
+/// A field or function declaration. Both start with qualifiers and types.
+fn member_decl(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let var_name = ident(src)
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(array_len(src, expr(src)))
+				.repeated()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::VarName as u16 }>();
+
+	let field = type_ref(src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(var_name.clone())
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(var_name)
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ";", Syn::Semicolon))
+		.map(|elems| (Syn::FieldDecl, elems));
+
+	let return_types = type_ref(src)
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(type_ref(src))
+				.repeated()
+				.flatten(),
+		)
+		.collect_n::<Syn, { Syn::ReturnTypes as u16 }>();
+
+	let function = return_types
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(ident(src))
+		.chain_append(trivia(src))
+		.chain_push(param_list(src))
+		.chain_append(opt(trivia(src).chain_push(comb::kw::<Syn>(
+			"const",
+			Syn::KwConst,
+			src,
+		))))
+		.chain_append(trivia(src))
+		.chain_push(primitive::choice((
+			stat::compound(src, stat::statement(src, expr(src))),
+			glyph(src, ";", Syn::Semicolon),
+		)))
+		.map(|elems| (Syn::FunctionDecl, elems));
+
+	decl_qualifiers(src)
+		.then(primitive::choice((function, field)))
+		.map(|(quals, (syn, mut elems))| {
+			elems.insert(0, quals);
+			node(syn, elems)
+		})
+		.boxed()
+}
+
+/// Zero or more qualifiers, each followed by trivia, wrapped in a node.
+fn decl_qualifiers(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let action = comb::kw::<Syn>("action", Syn::KwAction, src)
+		.start_vec()
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, "(", Syn::ParenL))
+			.chain_append(trivia(src))
+			.chain_push(ident(src))
+			.chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(ident(src))
+					.repeated()
+					.flatten(),
+			)
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, ")", Syn::ParenR))))
+		.collect_n::<Syn, { Syn::ActionQual as u16 }>();
+
+	let deprecation = comb::kw::<Syn>("deprecated", Syn::KwDeprecated, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "(", Syn::ParenL))
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, ",", Syn::Comma))
+			.chain_append(trivia(src))
+			.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::DeprecationQual as u16 }>();
+
+	// `readonly<T>` is a type, not a qualifier.
+	let readonly = comb::kw::<Syn>("readonly", Syn::KwReadOnly, src)
+		.then(trivia(src).then(primitive::just('<')).rewind().or_not())
+		.try_map(|(tok, generic), span| match generic {
+			Some(_) => Err(ParseError::expected_input_found(span, None, None)),
+			None => Ok(tok),
+		});
+
+	let qual = primitive::choice((
+		comb::kw::<Syn>("native", Syn::KwNative, src),
+		comb::kw::<Syn>("static", Syn::KwStatic, src),
+		comb::kw::<Syn>("private", Syn::KwPrivate, src),
+		comb::kw::<Syn>("protected", Syn::KwProtected, src),
+		comb::kw::<Syn>("latent", Syn::KwLatent, src),
+		comb::kw::<Syn>("final", Syn::KwFinal, src),
+		comb::kw::<Syn>("meta", Syn::KwMeta, src),
+		comb::kw::<Syn>("transient", Syn::KwTransient, src),
+		readonly,
+		comb::kw::<Syn>("internal", Syn::KwInternal, src),
+		comb::kw::<Syn>("virtualscope", Syn::KwVirtualScope, src),
+		comb::kw::<Syn>("virtual", Syn::KwVirtual, src),
+		comb::kw::<Syn>("override", Syn::KwOverride, src),
+		comb::kw::<Syn>("abstract", Syn::KwAbstract, src),
+		comb::kw::<Syn>("vararg", Syn::KwVarArg, src),
+		comb::kw::<Syn>("ui", Syn::KwUi, src),
+		comb::kw::<Syn>("play", Syn::KwPlay, src),
+		comb::kw::<Syn>("clearscope", Syn::KwClearScope, src),
+		action,
+		deprecation,
+		version_qual(src),
+	));
+
+	qual.start_vec()
+		.chain_append(trivia(src))
+		.repeated()
+		.flatten()
+		.collect_n::<Syn, { Syn::DeclQualifiers as u16 }>()
+		.boxed()
+}
+
+fn version_qual(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	comb::kw::<Syn>("version", Syn::KwVersion, src)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, "(", Syn::ParenL))
+		.chain_append(trivia(src))
+		.chain_push(comb::string_lit::<Syn>(src, Syn::LitString))
+		.chain_append(trivia(src))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::VersionQual as u16 }>()
+		.boxed()
+}
+
+fn param_list(src: &str) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + '_ {
+	let param = primitive::choice((
+		comb::kw::<Syn>("in", Syn::KwIn, src),
+		comb::kw::<Syn>("out", Syn::KwOut, src),
+	))
+	.start_vec()
+	.chain_append(trivia(src))
+	.repeated()
+	.flatten()
+	.chain_push(type_ref(src))
+	.chain_append(trivia(src))
+	.chain_push(ident(src))
+	.chain_append(opt(trivia(src)
+		.chain_push(glyph(src, "=", Syn::Eq))
+		.chain_append(trivia(src))
+		.chain_push(expr(src))))
+	.collect_n::<Syn, { Syn::Parameter as u16 }>();
+
+	let params = param
+		.clone()
+		.start_vec()
+		.chain_append(
+			trivia(src)
+				.chain_push(glyph(src, ",", Syn::Comma))
+				.chain_append(trivia(src))
+				.chain_push(param)
+				.repeated()
+				.flatten(),
+		)
+		.chain_append(opt(trivia(src)
+			.chain_push(glyph(src, ",", Syn::Comma))
+			.chain_append(trivia(src))
+			.chain_push(glyph(src, "...", Syn::Ellipsis))));
+
+	glyph(src, "(", Syn::ParenL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(primitive::choice((
+			glyph(src, "...", Syn::Ellipsis).start_vec(),
+			params,
+		))
+		.chain_append(trivia(src))))
+		.chain_push(glyph(src, ")", Syn::ParenR))
+		.collect_n::<Syn, { Syn::ParamList as u16 }>()
+		.boxed()
+}
+
+/// `[expr]` or `[]`.
+pub(super) fn array_len<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	glyph(src, "[", Syn::BracketL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(expr.start_vec().chain_append(trivia(src))))
+		.chain_push(glyph(src, "]", Syn::BracketR))
+		.collect_n::<Syn, { Syn::ArrayLen as u16 }>()
+		.boxed()
+}
+
+/// `{ expr, expr, ... }`, with an optional trailing comma.
+pub(super) fn init_list<'s, P>(
+	src: &'s str,
+	expr: P,
+) -> impl Parser<char, ParseOut, Error = ParseError> + Clone + 's
+where
+	P: Parser<char, ParseOut, Error = ParseError> + Clone + 's,
+{
+	glyph(src, "{", Syn::BraceL)
+		.start_vec()
+		.chain_append(trivia(src))
+		.chain_append(opt(expr
+			.clone()
+			.start_vec()
+			.chain_append(
+				trivia(src)
+					.chain_push(glyph(src, ",", Syn::Comma))
+					.chain_append(trivia(src))
+					.chain_push(expr)
+					.repeated()
+					.flatten(),
+			)
+			.chain_append(opt(trivia(src).chain_push(glyph(src, ",", Syn::Comma))))
+			.chain_append(trivia(src))))
+		.chain_push(glyph(src, "}", Syn::BraceR))
+		.collect_n::<Syn, { Syn::InitList as u16 }>()
+		.boxed()
+}
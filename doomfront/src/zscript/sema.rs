@@ -0,0 +1,354 @@
+//! A semantic database over one or more [`IncludeTree`]s.
+//!
+//! Building a [`Database`] declares every class, struct, mixin, enum, and
+//! constant; links classes to their parents, `extend` blocks, and mixins;
+//! determines the data scope (`play`, `ui`, `clearscope`) of every type and
+//! member; and then resolves the identifiers used in function bodies, type
+//! specifiers, `default` blocks, and state actions to their declarations.
+//! [`Database::check`] then infers the [`Type`] of every expression in every
+//! function body and reports errors the way GZDoom's compiler would.
+//!
+//! ZScript identifiers are case-insensitive, so all lookups here are too.
+
+mod check;
+mod decl;
+mod resolve;
+mod types;
+
+use std::collections::HashMap;
+
+use rowan::{TextRange, TextSize};
+
+use super::{FileParseTree, IncludeTree, SyntaxNode};
+
+pub use self::types::{Conversion, Type, TypeDisplay};
+
+/// A semantic database over one or more file trees. Pass GZDoom's own
+/// `gzdoom.pk3` tree first (if it is available) so that classes like `Actor`
+/// can be resolved.
+#[derive(Debug)]
+pub struct Database<'t> {
+	files: Vec<&'t FileParseTree>,
+	symbols: Vec<Symbol>,
+	types: HashMap<SymbolId, TypeInfo>,
+	/// Keys are lowercased.
+	globals: HashMap<String, SymbolId>,
+	/// Maps type definition nodes (and `extend` blocks) to the type they define.
+	type_nodes: HashMap<SyntaxNode, SymbolId>,
+	refs: HashMap<Location, SymbolId>,
+	unresolved: Vec<Location>,
+}
+
+impl<'t> Database<'t> {
+	#[must_use]
+	pub fn new(trees: &[&'t IncludeTree]) -> Self {
+		let mut ret = Self {
+			files: trees.iter().flat_map(|tree| tree.files.iter()).collect(),
+			symbols: vec![],
+			types: HashMap::default(),
+			globals: HashMap::default(),
+			type_nodes: HashMap::default(),
+			refs: HashMap::default(),
+			unresolved: vec![],
+		};
+
+		let mut pending = decl::Pending::default();
+
+		for file in 0..ret.files.len() {
+			ret.declare_file(file, &mut pending);
+		}
+
+		ret.link(pending);
+		ret.assign_scopes();
+
+		for file in 0..ret.files.len() {
+			ret.resolve_file(file);
+		}
+
+		ret
+	}
+
+	/// Every file from every tree passed to [`Self::new`], in order.
+	/// [`Location::file`] indexes into this.
+	#[must_use]
+	pub fn files(&self) -> &[&'t FileParseTree] {
+		&self.files
+	}
+
+	#[must_use]
+	pub fn symbol(&self, id: SymbolId) -> &Symbol {
+		&self.symbols[id.0 as usize]
+	}
+
+	pub fn symbols(&self) -> impl Iterator<Item = (SymbolId, &Symbol)> {
+		self.symbols
+			.iter()
+			.enumerate()
+			.map(|(i, sym)| (SymbolId(i as u32), sym))
+	}
+
+	/// Finds a class, struct, mixin class, enum, constant, or enum variant
+	/// declared at the top level of a file.
+	#[must_use]
+	pub fn lookup_global(&self, name: &str) -> Option<SymbolId> {
+		self.globals.get(&name.to_ascii_lowercase()).copied()
+	}
+
+	/// Like [`Self::lookup_global`] but only yields classes, structs, mixin
+	/// classes, and enums.
+	#[must_use]
+	pub fn lookup_type(&self, name: &str) -> Option<SymbolId> {
+		self.lookup_global(name)
+			.filter(|id| self.symbol(*id).kind.is_type())
+	}
+
+	/// The class which `ty` inherits from, if it is declared and known.
+	#[must_use]
+	pub fn parent_of(&self, ty: SymbolId) -> Option<SymbolId> {
+		self.types.get(&ty).and_then(|info| info.parent)
+	}
+
+	/// Yields `ty`'s parent, its parent's parent, and so on.
+	/// Stops early if an inheritance cycle is found.
+	pub fn ancestors(&self, ty: SymbolId) -> impl Iterator<Item = SymbolId> + '_ {
+		let mut seen = vec![ty];
+
+		std::iter::successors(self.parent_of(ty), move |cur| {
+			seen.push(*cur);
+			self.parent_of(*cur).filter(|next| !seen.contains(next))
+		})
+	}
+
+	/// Returns `true` if `ty` is `ancestor` or inherits from it.
+	#[must_use]
+	pub fn is_subclass_of(&self, ty: SymbolId, ancestor: SymbolId) -> bool {
+		ty == ancestor || self.ancestors(ty).any(|a| a == ancestor)
+	}
+
+	/// Returns `true` if `ty` or any of its ancestors names a parent class
+	/// which could not be found, in which case it may have members which this
+	/// database knows nothing about.
+	#[must_use]
+	pub fn has_unknown_ancestor(&self, ty: SymbolId) -> bool {
+		std::iter::once(ty)
+			.chain(self.ancestors(ty))
+			.any(|t| self.types.get(&t).is_some_and(|info| info.parent_unknown))
+	}
+
+	/// Searches `ty`, the mixins it uses, and then each of its ancestors (and
+	/// their mixins) for a constant, enum, variant, nested struct, field, or
+	/// function named `name`. The most derived declaration wins.
+	#[must_use]
+	pub fn lookup_member(&self, ty: SymbolId, name: &str) -> Option<SymbolId> {
+		let key = name.to_ascii_lowercase();
+		self.search_chain(ty, |info| info.members.get(&key).copied())
+	}
+
+	/// Like [`Self::lookup_member`], but for `property` definitions.
+	#[must_use]
+	pub fn lookup_property(&self, ty: SymbolId, name: &str) -> Option<SymbolId> {
+		let key = name.to_ascii_lowercase();
+		self.search_chain(ty, |info| info.properties.get(&key).copied())
+	}
+
+	/// Like [`Self::lookup_member`], but for `flagdef` definitions.
+	#[must_use]
+	pub fn lookup_flag(&self, ty: SymbolId, name: &str) -> Option<SymbolId> {
+		let key = name.to_ascii_lowercase();
+		self.search_chain(ty, |info| info.flags.get(&key).copied())
+	}
+
+	/// The symbols declared directly by `ty` or an `extend` block for it,
+	/// excluding properties and flags.
+	pub fn members(&self, ty: SymbolId) -> impl Iterator<Item = SymbolId> + '_ {
+		self.types
+			.get(&ty)
+			.into_iter()
+			.flat_map(|info| info.members.values().copied())
+	}
+
+	/// The declaration which the identifier at `location` refers to, if any.
+	#[must_use]
+	pub fn resolved(&self, location: Location) -> Option<SymbolId> {
+		self.refs.get(&location).copied()
+	}
+
+	/// Finds the symbol which is declared or referenced at `range` in `file`.
+	/// `range` may be empty (i.e. a cursor position) or cover a whole identifier.
+	#[must_use]
+	pub fn symbol_at(&self, file: usize, range: TextRange) -> Option<SymbolId> {
+		self.refs
+			.iter()
+			.find(|(loc, _)| loc.file == file && loc.range.contains_range(range))
+			.map(|(_, id)| *id)
+			.or_else(|| {
+				self.symbols().find_map(|(id, sym)| {
+					(sym.location.file == file && sym.location.range.contains_range(range))
+						.then_some(id)
+				})
+			})
+	}
+
+	/// Go-to-definition: where the symbol at `range` in `file` is declared.
+	#[must_use]
+	pub fn definition(&self, file: usize, range: TextRange) -> Option<Location> {
+		self.symbol_at(file, range)
+			.map(|id| self.symbol(id).location)
+	}
+
+	/// Find-references: every location referring to the symbol at `range`
+	/// in `file`, ordered by file and then position. The declaration itself
+	/// is not included.
+	#[must_use]
+	pub fn find_references(&self, file: usize, range: TextRange) -> Vec<Location> {
+		self.symbol_at(file, range)
+			.map(|id| self.references_to(id))
+			.unwrap_or_default()
+	}
+
+	/// Every location referring to `id`, ordered by file and then position.
+	#[must_use]
+	pub fn references_to(&self, id: SymbolId) -> Vec<Location> {
+		let mut ret: Vec<_> = self
+			.refs
+			.iter()
+			.filter_map(|(loc, target)| (*target == id).then_some(*loc))
+			.collect();
+
+		ret.sort_by_key(|loc| (loc.file, loc.range.start()));
+		ret
+	}
+
+	/// Identifiers and type names for which no declaration could be found.
+	/// This includes uses of anything built into the engine which was not
+	/// declared by any of the trees given to [`Self::new`].
+	#[must_use]
+	pub fn unresolved(&self) -> &[Location] {
+		&self.unresolved
+	}
+
+	fn search_chain<F>(&self, ty: SymbolId, mut f: F) -> Option<SymbolId>
+	where
+		F: FnMut(&TypeInfo) -> Option<SymbolId>,
+	{
+		for t in std::iter::once(ty).chain(self.ancestors(ty)) {
+			let Some(info) = self.types.get(&t) else {
+				continue;
+			};
+
+			if let Some(found) = f(info) {
+				return Some(found);
+			}
+
+			for mixin in &info.mixins {
+				if let Some(found) = self.types.get(mixin).and_then(&mut f) {
+					return Some(found);
+				}
+			}
+		}
+
+		None
+	}
+
+	fn add_symbol(&mut self, symbol: Symbol) -> SymbolId {
+		let id = SymbolId(self.symbols.len() as u32);
+		self.symbols.push(symbol);
+		id
+	}
+}
+
+/// An index into a [`Database`]'s symbol table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SymbolId(u32);
+
+/// A span of source text in one of the files of a [`Database`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Location {
+	/// Index into [`Database::files`].
+	pub file: usize,
+	pub range: TextRange,
+}
+
+impl Location {
+	#[must_use]
+	pub fn contains(&self, file: usize, offset: TextSize) -> bool {
+		self.file == file && self.range.contains_inclusive(offset)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+	/// As written at the declaration.
+	pub name: String,
+	pub kind: SymbolKind,
+	/// The declaration's name token.
+	pub location: Location,
+	/// The declaring node. For fields, this is the [`Syn::VarName`];
+	/// for foreach loop variables, this is the [`Syn::StatForEach`].
+	///
+	/// [`Syn::VarName`]: super::Syn::VarName
+	/// [`Syn::StatForEach`]: super::Syn::StatForEach
+	pub syntax: SyntaxNode,
+	/// The type which declares this symbol. `None` for top-level symbols,
+	/// parameters, and locals.
+	pub owner: Option<SymbolId>,
+	/// `None` if no scope applies (e.g. to locals) or it can not be determined
+	/// because an ancestor class is unknown.
+	pub scope: Option<DataScope>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+	Class,
+	Struct,
+	MixinClass,
+	Enum,
+	EnumVariant,
+	Const,
+	StaticConst,
+	Field,
+	Function,
+	Property,
+	FlagDef,
+	Parameter,
+	Local,
+}
+
+impl SymbolKind {
+	/// Is this a class, struct, mixin class, or enum?
+	#[must_use]
+	pub fn is_type(self) -> bool {
+		matches!(
+			self,
+			Self::Class | Self::Struct | Self::MixinClass | Self::Enum
+		)
+	}
+}
+
+/// Which parts of the engine may touch a piece of data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataScope {
+	/// `clearscope`; readable and writable from anywhere.
+	Clear,
+	/// `play`; the simulation. Read-only from UI code.
+	Play,
+	/// `ui`; menus, HUDs, and so on. Inaccessible from play code.
+	Ui,
+	/// `virtualscope`; a function which takes on the scope of its caller.
+	Virtual,
+}
+
+#[derive(Debug, Default)]
+struct TypeInfo {
+	parent: Option<SymbolId>,
+	/// `true` if a parent class was named but never declared.
+	parent_unknown: bool,
+	mixins: Vec<SymbolId>,
+	/// Keys are lowercased.
+	members: HashMap<String, SymbolId>,
+	/// Keys are lowercased.
+	properties: HashMap<String, SymbolId>,
+	/// Keys are lowercased.
+	flags: HashMap<String, SymbolId>,
+}
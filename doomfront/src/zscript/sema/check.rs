@@ -0,0 +1,993 @@
+//! Type inference and checking of function bodies.
+
+use rowan::{ast::AstNode, TextRange};
+
+use crate::{
+	zscript::{ast, Syn, SyntaxNode, SyntaxToken},
+	Issue,
+};
+
+use super::{
+	types::{self, Conversion, Type},
+	DataScope, Database, Location, SymbolId, SymbolKind,
+};
+
+/// What is known about the function whose body is being checked.
+#[derive(Debug)]
+struct FnCtx {
+	file: usize,
+	/// The class or struct which the function belongs to.
+	owner: Option<SymbolId>,
+	scope: Option<DataScope>,
+	is_const: bool,
+	returns: Vec<Type>,
+}
+
+impl Database<'_> {
+	/// Infers and checks the types of every expression in every function body.
+	/// Each issue is paired with an index into [`Self::files`].
+	///
+	/// Nothing is reported about expressions whose types depend on declarations
+	/// that no loaded tree provides, such as most members of `Actor` when
+	/// `gzdoom.pk3` was not loaded.
+	#[must_use]
+	pub fn check(&self) -> Vec<(usize, Issue)> {
+		let mut issues = vec![];
+
+		for (file, tree) in self.files.iter().enumerate() {
+			for node in tree.zipper().descendants() {
+				let Some(func) = ast::FunctionDecl::cast(node) else {
+					continue;
+				};
+
+				let Some(body) = func.body() else {
+					continue;
+				};
+
+				let Some(id) = self.resolved_decl(file, &func.name()) else {
+					continue;
+				};
+
+				let owner = self.context_of(func.syntax());
+
+				let ctx = FnCtx {
+					file,
+					owner,
+					scope: self.symbol(id).scope,
+					is_const: func.is_const(),
+					returns: func
+						.return_types()
+						.map(|r| self.type_from_ref(&r, owner))
+						.filter(|t| *t != Type::Void)
+						.collect(),
+				};
+
+				for n in body.syntax().descendants() {
+					self.check_node(&ctx, n, &mut issues);
+				}
+			}
+		}
+
+		issues
+	}
+
+	/// The type of `expr`, which appears in the code of `context`.
+	#[must_use]
+	pub fn type_of(&self, file: usize, expr: &ast::Expression, context: Option<SymbolId>) -> Type {
+		let ctx = FnCtx {
+			file,
+			owner: context,
+			scope: None,
+			is_const: false,
+			returns: vec![],
+		};
+
+		self.infer(&ctx, expr)
+	}
+
+	/// The type of a value-like symbol (a field, parameter, local, constant,
+	/// or enum variant). Yields [`Type::TypeName`] for types.
+	#[must_use]
+	pub fn symbol_value_type(&self, id: SymbolId) -> Type {
+		let sym = self.symbol(id);
+
+		let with_lens =
+			|ty: Type, lens: usize| (0..lens).fold(ty, |acc, _| Type::FixedArray(Box::new(acc)));
+
+		match sym.kind {
+			SymbolKind::Class | SymbolKind::Struct | SymbolKind::MixinClass | SymbolKind::Enum => {
+				Type::TypeName(id)
+			}
+			SymbolKind::Field => {
+				let Some(var) = ast::VarName::cast(sym.syntax.clone()) else {
+					return Type::Unknown;
+				};
+
+				let Some(field) = sym.syntax.parent().and_then(ast::FieldDecl::cast) else {
+					return Type::Unknown;
+				};
+
+				let ty = self.type_from_ref(&field.type_spec(), sym.owner);
+				with_lens(ty, var.array_lens().count())
+			}
+			SymbolKind::Parameter => match ast::Parameter::cast(sym.syntax.clone()) {
+				Some(param) => self.type_from_ref(&param.type_spec(), self.context_of(&sym.syntax)),
+				None => Type::Unknown,
+			},
+			SymbolKind::Local => {
+				if let Some(foreach) = ast::StatForEach::cast(sym.syntax.clone()) {
+					return self.foreach_binding_type(sym.location, &foreach);
+				}
+
+				let Some(local) = ast::LocalVar::cast(sym.syntax.clone()) else {
+					return Type::Unknown;
+				};
+
+				let Some(type_ref) = local.type_spec() else {
+					return Type::Unknown;
+				};
+
+				let context = self.context_of(&sym.syntax);
+
+				let ty = if type_ref.first_token().kind() == Syn::KwLet {
+					match local.initializer() {
+						Some(init) => self.type_of(sym.location.file, &init, context),
+						None => Type::Unknown,
+					}
+				} else {
+					self.type_from_ref(&type_ref, context)
+				};
+
+				with_lens(ty, local.array_lens().count())
+			}
+			SymbolKind::StaticConst => match ast::StaticConstDef::cast(sym.syntax.clone()) {
+				Some(sconst) => {
+					Type::FixedArray(Box::new(self.type_from_ref(&sconst.type_spec(), sym.owner)))
+				}
+				None => Type::Unknown,
+			},
+			SymbolKind::Const => {
+				// Only literals are considered, since constants may be
+				// defined in terms of each other (or, erroneously, themselves).
+				match ast::ConstDef::cast(sym.syntax.clone()).map(|c| c.expr()) {
+					Some(ast::Expression::Literal(lit)) => literal_type(&lit),
+					_ => Type::Unknown,
+				}
+			}
+			SymbolKind::EnumVariant => sym
+				.syntax
+				.parent()
+				.and_then(|p| self.type_nodes.get(&p).copied())
+				.map_or(Type::Int, Type::Enum),
+			SymbolKind::Function | SymbolKind::Property | SymbolKind::FlagDef => Type::Unknown,
+		}
+	}
+
+	fn foreach_binding_type(&self, location: Location, foreach: &ast::StatForEach) -> Type {
+		let index = foreach
+			.bindings()
+			.position(|b| b.text_range() == location.range)
+			.unwrap_or(0);
+
+		let context = self.context_of(foreach.syntax());
+
+		match self
+			.type_of(location.file, &foreach.collection(), context)
+			.unqualified()
+		{
+			Type::Array(elem) | Type::FixedArray(elem) => *elem.clone(),
+			Type::Map(k, v) => {
+				if index == 0 {
+					*k.clone()
+				} else {
+					*v.clone()
+				}
+			}
+			_ => Type::Unknown,
+		}
+	}
+
+	fn resolved_decl(&self, file: usize, name: &SyntaxToken) -> Option<SymbolId> {
+		self.symbols().find_map(|(id, sym)| {
+			(sym.location.file == file && sym.location.range == name.text_range()).then_some(id)
+		})
+	}
+
+	fn resolved_token(&self, ctx: &FnCtx, token: &SyntaxToken) -> Option<SymbolId> {
+		self.resolved(Location {
+			file: ctx.file,
+			range: token.text_range(),
+		})
+	}
+
+	// Inference ///////////////////////////////////////////////////////////////
+
+	fn infer(&self, ctx: &FnCtx, expr: &ast::Expression) -> Type {
+		match expr {
+			ast::Expression::Literal(lit) => literal_type(lit),
+			ast::Expression::Ident(e_id) => {
+				let token = e_id.token();
+
+				match token.text().to_ascii_lowercase().as_str() {
+					"self" | "invoker" => {
+						ctx.owner.map_or(Type::Unknown, |o| self.instance_type(o))
+					}
+					"super" => ctx
+						.owner
+						.and_then(|o| self.parent_of(o))
+						.map_or(Type::Unknown, Type::Object),
+					_ => self
+						.resolved_token(ctx, &token)
+						.map_or(Type::Unknown, |id| self.symbol_value_type(id)),
+				}
+			}
+			ast::Expression::Member(e_mem) => self.infer_member(ctx, e_mem),
+			ast::Expression::Call(e_call) => self.infer_call(ctx, e_call),
+			ast::Expression::Index(e_index) => match self.infer(ctx, &e_index.indexed()) {
+				Type::Array(elem) | Type::FixedArray(elem) => *elem,
+				Type::ReadOnly(inner) => match *inner {
+					Type::Array(elem) | Type::FixedArray(elem) => Type::ReadOnly(elem),
+					_ => Type::Unknown,
+				},
+				_ => Type::Unknown,
+			},
+			ast::Expression::Group(e_grp) => self.infer(ctx, &e_grp.inner()),
+			ast::Expression::ClassCast(e_cast) => {
+				match self.type_from_ref(&e_cast.type_spec(), ctx.owner) {
+					Type::Object(id) => Type::Class(Some(id)),
+					_ => Type::Class(None),
+				}
+			}
+			ast::Expression::Ternary(e_tern) => match self.infer(ctx, &e_tern.if_true()) {
+				Type::Null => self.infer(ctx, &e_tern.if_false()),
+				other => other,
+			},
+			ast::Expression::Vector(e_vec) => {
+				let comps: Vec<_> = e_vec.components().collect();
+
+				match comps.len() {
+					2 if self.infer(ctx, &comps[0]) == Type::Vector2 => Type::Vector3,
+					2 => Type::Vector2,
+					_ => Type::Vector3,
+				}
+			}
+			ast::Expression::Prefix(e_pre) => {
+				let operand = self.infer(ctx, &e_pre.operand());
+
+				match e_pre.operator().kind() {
+					Syn::Bang => Type::Bool,
+					Syn::Tilde if operand.is_integral() => operand.promote(&Type::Int),
+					Syn::KwSizeOf | Syn::KwAlignOf => Type::Int,
+					Syn::Minus | Syn::Plus | Syn::Minus2 | Syn::Plus2
+						if operand.is_numeric() || operand.is_vector() =>
+					{
+						operand
+					}
+					_ => Type::Unknown,
+				}
+			}
+			ast::Expression::Postfix(e_post) => self.infer(ctx, &e_post.operand()),
+			ast::Expression::Binary(e_bin) => {
+				let lhs = self.infer(ctx, &e_bin.left());
+				let rhs = self.infer(ctx, &e_bin.right());
+				binary_type(e_bin.operator().kind(), &lhs, &rhs).unwrap_or(Type::Unknown)
+			}
+		}
+	}
+
+	fn infer_member(&self, ctx: &FnCtx, e_mem: &ast::ExprMember) -> Type {
+		let member = e_mem.member();
+
+		if let Some(id) = self.resolved_token(ctx, &member) {
+			let ty = self.symbol_value_type(id);
+
+			// Members of a read-only object are themselves read-only.
+			if matches!(self.infer(ctx, &e_mem.owner()), Type::ReadOnly(_))
+				&& self.symbol(id).kind == SymbolKind::Field
+			{
+				return Type::ReadOnly(Box::new(ty));
+			}
+
+			return ty;
+		}
+
+		let owner = self.infer(ctx, &e_mem.owner());
+
+		match (
+			owner.unqualified(),
+			member.text().to_ascii_lowercase().as_str(),
+		) {
+			(Type::Vector2 | Type::Vector3, "x" | "y") => Type::Float,
+			(Type::Vector3, "z") => Type::Float,
+			(Type::Vector3, "xy") => Type::Vector2,
+			_ => Type::Unknown,
+		}
+	}
+
+	fn infer_call(&self, ctx: &FnCtx, e_call: &ast::ExprCall) -> Type {
+		let callee = e_call.callee();
+
+		let (owner, name) = match &callee {
+			ast::Expression::Ident(e_id) => (None, e_id.token()),
+			ast::Expression::Member(e_mem) => (Some(e_mem.owner()), e_mem.member()),
+			_ => return Type::Unknown,
+		};
+
+		if let Some(id) = self.resolved_token(ctx, &name) {
+			let sym = self.symbol(id);
+
+			return match sym.kind {
+				SymbolKind::Function => {
+					let Some(func) = ast::FunctionDecl::cast(sym.syntax.clone()) else {
+						return Type::Unknown;
+					};
+
+					func.return_types()
+						.next()
+						.map_or(Type::Void, |r| self.type_from_ref(&r, sym.owner))
+				}
+				// A cast, e.g. `Actor(mo)`.
+				SymbolKind::Class | SymbolKind::MixinClass => Type::Object(id),
+				_ => Type::Unknown,
+			};
+		}
+
+		let Some(owner) = owner else {
+			// Casts to primitive types, e.g. `int(x)` or `Name(str)`.
+			return types::primitive(name.text()).unwrap_or(Type::Unknown);
+		};
+
+		let method = name.text().to_ascii_lowercase();
+
+		if let ast::Expression::Ident(e_id) = &owner {
+			if e_id.token().text().eq_ignore_ascii_case("string")
+				&& matches!(method.as_str(), "format" | "join")
+			{
+				return Type::String;
+			}
+		}
+
+		match (self.infer(ctx, &owner).unqualified(), method.as_str()) {
+			(Type::Array(_), "size" | "find" | "push" | "max" | "reserve") => Type::UInt,
+			(Type::Array(_), "pop" | "delete" | "resize") => Type::Bool,
+			(Type::Array(_), _) => Type::Void,
+			(Type::Map(_, v), "get" | "getifexists") => *v.clone(),
+			(Type::Map(_, _), "checkkey" | "checkvalue") => Type::Bool,
+			(Type::Map(_, _), "countused") => Type::UInt,
+			(Type::Map(_, _), _) => Type::Void,
+			(Type::Vector2 | Type::Vector3, "length" | "lengthsquared" | "angle" | "pitch") => {
+				Type::Float
+			}
+			(v @ (Type::Vector2 | Type::Vector3), "unit") => v.clone(),
+			(Type::String, "length" | "codepointcount" | "indexof" | "rightindexof" | "toint") => {
+				Type::Int
+			}
+			(Type::String, "todouble") => Type::Float,
+			(Type::String, "left" | "mid" | "filter" | "makeupper" | "makelower") => Type::String,
+			_ => Type::Unknown,
+		}
+	}
+
+	// Checking ////////////////////////////////////////////////////////////////
+
+	fn check_node(&self, ctx: &FnCtx, node: SyntaxNode, issues: &mut Vec<(usize, Issue)>) {
+		let mut report = |issue: Issue| issues.push((ctx.file, issue));
+
+		match node.kind() {
+			Syn::ExprBinary => {
+				let e_bin = ast::ExprBinary::cast(node).unwrap();
+				let op = e_bin.operator();
+
+				if e_bin.is_assign() {
+					self.check_modifiable(ctx, &e_bin.left(), &mut report);
+
+					let lhs = self.infer(ctx, &e_bin.left());
+					let rhs = self.infer(ctx, &e_bin.right());
+
+					if op.kind() == Syn::Eq {
+						self.check_conversion(
+							&rhs,
+							&lhs,
+							e_bin.right().syntax().text_range(),
+							&mut report,
+						);
+					} else if let Some(bin_op) = compound_base(op.kind()) {
+						if binary_type(bin_op, &lhs, &rhs).is_none() {
+							report(Issue::error(
+								op.text_range(),
+								format!("Incompatible operands for {}", operation_name(bin_op)),
+							));
+						}
+					}
+
+					return;
+				}
+
+				let lhs = self.infer(ctx, &e_bin.left());
+				let rhs = self.infer(ctx, &e_bin.right());
+
+				if lhs.is_unknown() || rhs.is_unknown() {
+					return;
+				}
+
+				if binary_type(op.kind(), &lhs, &rhs).is_none() {
+					report(Issue::error(
+						op.text_range(),
+						format!("Incompatible operands for {}", operation_name(op.kind())),
+					));
+				}
+			}
+			Syn::ExprPrefix | Syn::ExprPostfix => {
+				let (operator, operand) = match ast::Expression::cast(node).unwrap() {
+					ast::Expression::Prefix(e) => (e.operator(), e.operand()),
+					ast::Expression::Postfix(e) => (e.operator(), e.operand()),
+					_ => unreachable!(),
+				};
+
+				if matches!(operator.kind(), Syn::Plus2 | Syn::Minus2) {
+					self.check_modifiable(ctx, &operand, &mut report);
+
+					let ty = self.infer(ctx, &operand);
+
+					if !ty.is_unknown() && !ty.unqualified().is_numeric() {
+						report(Issue::error(operator.text_range(), "Numeric type expected"));
+					}
+				}
+			}
+			Syn::ExprCall => self.check_call(ctx, &ast::ExprCall::cast(node).unwrap(), &mut report),
+			Syn::ExprMember => {
+				let e_mem = ast::ExprMember::cast(node).unwrap();
+				self.check_access(ctx, &e_mem.member(), false, &mut report);
+			}
+			Syn::ExprIdent => {
+				let e_id = ast::ExprIdent::cast(node).unwrap();
+				self.check_access(ctx, &e_id.token(), false, &mut report);
+			}
+			Syn::LocalVar => {
+				let local = ast::LocalVar::cast(node).unwrap();
+
+				let Some(init) = local.initializer() else {
+					return;
+				};
+
+				let Some(id) = self.resolved_decl(ctx.file, &local.name()) else {
+					return;
+				};
+
+				let declared = self.symbol_value_type(id);
+				let init_ty = self.infer(ctx, &init);
+
+				if init_ty == Type::Void {
+					report(Issue::error(
+						init.syntax().text_range(),
+						"Cannot initialize a variable with a void expression",
+					));
+				} else {
+					self.check_conversion(
+						&init_ty,
+						&declared,
+						init.syntax().text_range(),
+						&mut report,
+					);
+				}
+			}
+			Syn::StatReturn => {
+				let stat = ast::StatReturn::cast(node).unwrap();
+				let exprs: Vec<_> = stat.exprs().collect();
+
+				if exprs.len() != ctx.returns.len() {
+					report(Issue::error(
+						stat.syntax().text_range(),
+						"Return type mismatch",
+					));
+					return;
+				}
+
+				for (expr, ret) in exprs.iter().zip(&ctx.returns) {
+					let ty = self.infer(ctx, expr);
+					self.check_conversion(&ty, ret, expr.syntax().text_range(), &mut report);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn check_conversion(
+		&self,
+		from: &Type,
+		to: &Type,
+		range: TextRange,
+		report: &mut impl FnMut(Issue),
+	) {
+		match self.conversion(from, to) {
+			Conversion::Allowed => {}
+			Conversion::Truncating => {
+				report(Issue::warning(range, "Truncation of floating point value"));
+			}
+			Conversion::Forbidden => report(Issue::error(
+				range,
+				format!(
+					"Cannot convert {} to {}",
+					self.display_type(from),
+					self.display_type(to)
+				),
+			)),
+		}
+	}
+
+	/// Reports if `expr` can not be assigned to or incremented.
+	fn check_modifiable(
+		&self,
+		ctx: &FnCtx,
+		expr: &ast::Expression,
+		report: &mut impl FnMut(Issue),
+	) {
+		let range = expr.syntax().text_range();
+
+		let (token, through) = match expr {
+			ast::Expression::Ident(e_id) => (e_id.token(), None),
+			ast::Expression::Member(e_mem) => (e_mem.member(), Some(e_mem.owner())),
+			ast::Expression::Index(e_index) => {
+				if matches!(self.infer(ctx, &e_index.indexed()), Type::ReadOnly(_)) {
+					report(Issue::error(range, "Expression must be a modifiable value"));
+				} else {
+					self.check_modifiable(ctx, &e_index.indexed(), report);
+				}
+
+				return;
+			}
+			ast::Expression::Group(e_grp) => {
+				self.check_modifiable(ctx, &e_grp.inner(), report);
+				return;
+			}
+			_ => {
+				report(Issue::error(range, "Expression must be a modifiable value"));
+				return;
+			}
+		};
+
+		let Some(id) = self.resolved_token(ctx, &token) else {
+			return;
+		};
+
+		let sym = self.symbol(id);
+
+		let modifiable = match sym.kind {
+			SymbolKind::Parameter | SymbolKind::Local => true,
+			SymbolKind::Field => {
+				let readonly_field = sym
+					.syntax
+					.parent()
+					.and_then(ast::FieldDecl::cast)
+					.is_some_and(|field| field.qualifiers().contains(Syn::KwReadOnly));
+
+				let through_readonly = through
+					.as_ref()
+					.is_some_and(|owner| matches!(self.infer(ctx, owner), Type::ReadOnly(_)));
+
+				// In a const function, `self` is read-only.
+				let through_self = match &through {
+					None => true,
+					Some(ast::Expression::Ident(e_id)) => {
+						e_id.token().text().eq_ignore_ascii_case("self")
+					}
+					Some(_) => false,
+				};
+
+				!(readonly_field || through_readonly || (ctx.is_const && through_self))
+			}
+			_ => false,
+		};
+
+		if !modifiable {
+			report(Issue::error(range, "Expression must be a modifiable value"));
+			return;
+		}
+
+		if sym.kind == SymbolKind::Field {
+			self.check_access(ctx, &token, true, report);
+		}
+	}
+
+	/// Reports reads of `ui` fields from `play` code (and vice versa), and
+	/// writes to fields which the current scope may only read.
+	fn check_access(
+		&self,
+		ctx: &FnCtx,
+		token: &SyntaxToken,
+		write: bool,
+		report: &mut impl FnMut(Issue),
+	) {
+		let Some(id) = self.resolved_token(ctx, token) else {
+			return;
+		};
+
+		let sym = self.symbol(id);
+
+		if sym.kind != SymbolKind::Field {
+			return;
+		}
+
+		let (Some(outer), Some(inner)) = (ctx.scope, sym.scope) else {
+			return;
+		};
+
+		let allowed = match (outer, inner) {
+			(DataScope::Virtual, _) | (_, DataScope::Virtual) | (_, DataScope::Clear) => true,
+			(o, i) if o == i => true,
+			// Play data is read-only to UI and clearscope code.
+			(DataScope::Ui | DataScope::Clear, DataScope::Play) => !write,
+			// UI data is inaccessible to play code, and read-only to clearscope code.
+			(DataScope::Clear, DataScope::Ui) => !write,
+			_ => false,
+		};
+
+		if allowed {
+			return;
+		}
+
+		let message = if write {
+			format!(
+				"Can't modify {} field {} from {} context",
+				scope_name(inner),
+				sym.name,
+				scope_name(outer)
+			)
+		} else {
+			format!(
+				"Can't access {} field {} from {} context",
+				scope_name(inner),
+				sym.name,
+				scope_name(outer)
+			)
+		};
+
+		report(Issue::error(token.text_range(), message));
+	}
+
+	fn check_call(&self, ctx: &FnCtx, e_call: &ast::ExprCall, report: &mut impl FnMut(Issue)) {
+		let (owner, name) = match e_call.callee() {
+			ast::Expression::Ident(e_id) => (None, e_id.token()),
+			ast::Expression::Member(e_mem) => (Some(e_mem.owner()), e_mem.member()),
+			_ => return,
+		};
+
+		let args: Vec<_> = e_call.args().iter().collect();
+
+		let Some(id) = self.resolved_token(ctx, &name) else {
+			if let Some(owner) = owner {
+				self.check_builtin_method(ctx, &owner, &name, &args, report);
+			}
+
+			return;
+		};
+
+		let sym = self.symbol(id);
+
+		if sym.kind != SymbolKind::Function {
+			return;
+		}
+
+		let Some(func) = ast::FunctionDecl::cast(sym.syntax.clone()) else {
+			return;
+		};
+
+		// Scope.
+		if let (Some(outer), Some(inner)) = (ctx.scope, sym.scope) {
+			let allowed = matches!(
+				(outer, inner),
+				(DataScope::Virtual, _) | (_, DataScope::Virtual) | (_, DataScope::Clear)
+			) || outer == inner;
+
+			if !allowed {
+				report(Issue::error(
+					name.text_range(),
+					format!(
+						"Can't call {} function {} from {} context",
+						scope_name(inner),
+						sym.name,
+						scope_name(outer)
+					),
+				));
+			}
+		}
+
+		// Calling a non-const method on `self` from a const method.
+		if ctx.is_const && !func.is_const() && !func.qualifiers().contains(Syn::KwStatic) {
+			let on_self = match &owner {
+				None => true,
+				Some(ast::Expression::Ident(e_id)) => {
+					e_id.token().text().eq_ignore_ascii_case("self")
+				}
+				Some(_) => false,
+			};
+
+			if on_self && sym.owner.is_some() {
+				report(Issue::error(
+					name.text_range(),
+					format!(
+						"Cannot call non-const method {} from a const method",
+						sym.name
+					),
+				));
+			}
+		}
+
+		// Arity.
+		let params: Vec<_> = func.params().iter().collect();
+		let required = params.iter().filter(|p| p.default().is_none()).count();
+		let variadic = func.params().is_variadic();
+
+		if args.len() > params.len() && !variadic {
+			report(Issue::error(
+				e_call.args().syntax().text_range(),
+				format!("Too many arguments in call to {}", sym.name),
+			));
+			return;
+		}
+
+		if args.len() < required {
+			report(Issue::error(
+				e_call.args().syntax().text_range(),
+				format!("Insufficient arguments in call to {}", sym.name),
+			));
+			return;
+		}
+
+		// Argument types. Once a named argument appears, positions no longer
+		// correspond, so only names are considered from there on.
+		let mut positional = true;
+
+		for (i, arg) in args.iter().enumerate() {
+			let param = match arg.name() {
+				Some(arg_name) => {
+					positional = false;
+
+					let found = params
+						.iter()
+						.find(|p| p.name().text().eq_ignore_ascii_case(arg_name.text()));
+
+					let Some(found) = found else {
+						report(Issue::error(
+							arg_name.text_range(),
+							format!("Named argument {} not found", arg_name.text()),
+						));
+
+						continue;
+					};
+
+					found
+				}
+				None if positional => match params.get(i) {
+					Some(p) => p,
+					None => continue,
+				},
+				None => continue,
+			};
+
+			let param_ty = self.type_from_ref(&param.type_spec(), sym.owner);
+			let arg_expr = arg.expr();
+
+			if param.is_out() {
+				self.check_modifiable(ctx, &arg_expr, report);
+			}
+
+			let arg_ty = self.infer(ctx, &arg_expr);
+			self.check_conversion(&arg_ty, &param_ty, arg_expr.syntax().text_range(), report);
+		}
+	}
+
+	/// Checks calls to the methods of dynamic arrays and maps.
+	fn check_builtin_method(
+		&self,
+		ctx: &FnCtx,
+		owner: &ast::Expression,
+		name: &SyntaxToken,
+		args: &[ast::Argument],
+		report: &mut impl FnMut(Issue),
+	) {
+		let owner_ty = self.infer(ctx, owner);
+		let method = name.text().to_ascii_lowercase();
+
+		// (Minimum argument count, maximum argument count, parameter types).
+		let (min, max, params): (usize, usize, Vec<Type>) =
+			match (owner_ty.unqualified(), method.as_str()) {
+				(Type::Array(_), "size" | "clear" | "pop" | "shrinktofit" | "max") => {
+					(0, 0, vec![])
+				}
+				(Type::Array(elem), "push" | "find") => (1, 1, vec![*elem.clone()]),
+				(Type::Array(elem), "insert") => (2, 2, vec![Type::UInt, *elem.clone()]),
+				(Type::Array(_), "delete") => (1, 2, vec![Type::UInt, Type::UInt]),
+				(Type::Array(_), "reserve" | "resize" | "grow") => (1, 1, vec![Type::UInt]),
+				(Type::Array(_), "copy" | "move" | "append") => {
+					(1, 1, vec![owner_ty.unqualified().clone()])
+				}
+				(Type::Map(k, _), "get" | "checkkey" | "remove" | "getifexists") => {
+					(1, 1, vec![*k.clone()])
+				}
+				(Type::Map(k, v), "insert") => (2, 2, vec![*k.clone(), *v.clone()]),
+				(Type::Map(k, _), "insertnew") => (1, 1, vec![*k.clone()]),
+				(Type::Map(_, v), "checkvalue") => (1, 1, vec![*v.clone()]),
+				(Type::Map(_, _), "clear" | "countused") => (0, 0, vec![]),
+				(Type::Map(_, _), "copy" | "move" | "swap") => {
+					(1, 1, vec![owner_ty.unqualified().clone()])
+				}
+				(Type::Array(_) | Type::Map(_, _), _) => {
+					report(Issue::error(
+						name.text_range(),
+						format!("Unknown function {}", name.text()),
+					));
+
+					return;
+				}
+				_ => return,
+			};
+
+		let mutating = !matches!(
+			method.as_str(),
+			"size"
+				| "find" | "max"
+				| "get" | "checkkey"
+				| "checkvalue"
+				| "countused"
+				| "getifexists"
+		);
+
+		if mutating && matches!(owner_ty, Type::ReadOnly(_)) {
+			report(Issue::error(
+				owner.syntax().text_range(),
+				"Expression must be a modifiable value",
+			));
+		}
+
+		if args.len() > max {
+			report(Issue::error(
+				name.text_range(),
+				format!("Too many arguments in call to {}", name.text()),
+			));
+		} else if args.len() < min {
+			report(Issue::error(
+				name.text_range(),
+				format!("Insufficient arguments in call to {}", name.text()),
+			));
+		}
+
+		for (arg, param) in args.iter().zip(&params) {
+			let arg_ty = self.infer(ctx, &arg.expr());
+			self.check_conversion(&arg_ty, param, arg.expr().syntax().text_range(), report);
+		}
+	}
+}
+
+fn literal_type(lit: &ast::Literal) -> Type {
+	let token = lit.token();
+
+	match token.syntax().kind() {
+		Syn::LitInt => {
+			if token.syntax().text().to_ascii_lowercase().contains('u') {
+				Type::UInt
+			} else {
+				Type::Int
+			}
+		}
+		Syn::LitFloat => Type::Float,
+		Syn::LitString => Type::String,
+		Syn::LitName => Type::Name,
+		Syn::LitTrue | Syn::LitFalse => Type::Bool,
+		Syn::LitNull => Type::Null,
+		_ => Type::Unknown,
+	}
+}
+
+/// The result type of a non-assigning binary operation,
+/// or `None` if the operand types are incompatible.
+fn binary_type(op: Syn, lhs: &Type, rhs: &Type) -> Option<Type> {
+	if lhs.is_unknown() || rhs.is_unknown() {
+		return Some(Type::Unknown);
+	}
+
+	let (l, r) = (lhs.unqualified(), rhs.unqualified());
+
+	match op {
+		Syn::Pipe2 | Syn::Ampersand2 => Some(Type::Bool),
+		Syn::Eq2 | Syn::BangEq => {
+			let comparable = (l.is_numeric() && r.is_numeric())
+				|| l == r || matches!(
+				(l, r),
+				(Type::Null, _)
+					| (_, Type::Null)
+					| (Type::Object(_), Type::Object(_))
+					| (Type::Class(_), Type::Class(_))
+					| (Type::String | Type::Name, Type::String | Type::Name)
+					| (Type::Vector2, Type::Vector2)
+					| (Type::Vector3, Type::Vector3)
+			);
+
+			comparable.then_some(Type::Bool)
+		}
+		Syn::TildeEq2 => matches!(
+			(l, r),
+			(Type::String | Type::Name, Type::String | Type::Name)
+		)
+		.then_some(Type::Bool),
+		Syn::AngleL | Syn::AngleR | Syn::AngleLEq | Syn::AngleREq => {
+			((l.is_numeric() && r.is_numeric()) || (*l == Type::String && *r == Type::String))
+				.then_some(Type::Bool)
+		}
+		Syn::AngleLAngleREq => (l.is_numeric() && r.is_numeric()).then_some(Type::Int),
+		Syn::KwIs => Some(Type::Bool),
+		Syn::Period2 => Some(Type::String),
+		Syn::Plus | Syn::Minus => match (l, r) {
+			(a, b) if a.is_numeric() && b.is_numeric() => Some(a.promote(b)),
+			(Type::Vector2, Type::Vector2) => Some(Type::Vector2),
+			(Type::Vector3, Type::Vector3) => Some(Type::Vector3),
+			_ => None,
+		},
+		Syn::Asterisk => match (l, r) {
+			(a, b) if a.is_numeric() && b.is_numeric() => Some(a.promote(b)),
+			(v, n) | (n, v) if v.is_vector() && n.is_numeric() => Some(v.clone()),
+			_ => None,
+		},
+		Syn::Slash => match (l, r) {
+			(a, b) if a.is_numeric() && b.is_numeric() => Some(a.promote(b)),
+			(v, n) if v.is_vector() && n.is_numeric() => Some(v.clone()),
+			_ => None,
+		},
+		Syn::Percent => (l.is_numeric() && r.is_numeric()).then(|| l.promote(r)),
+		Syn::Asterisk2 => (l.is_numeric() && r.is_numeric()).then_some(Type::Float),
+		Syn::KwDot => (l.is_vector() && l == r).then_some(Type::Float),
+		Syn::KwCross => (*l == Type::Vector3 && *r == Type::Vector3).then_some(Type::Vector3),
+		Syn::Ampersand | Syn::Pipe | Syn::Caret | Syn::AngleL2 | Syn::AngleR2 | Syn::AngleR3 => {
+			(l.is_integral() && r.is_integral()).then(|| l.promote(r))
+		}
+		_ => Some(Type::Unknown),
+	}
+}
+
+/// For compound assignment operators, the underlying binary operator.
+fn compound_base(op: Syn) -> Option<Syn> {
+	let ret = match op {
+		Syn::PlusEq => Syn::Plus,
+		Syn::MinusEq => Syn::Minus,
+		Syn::AsteriskEq => Syn::Asterisk,
+		Syn::SlashEq => Syn::Slash,
+		Syn::PercentEq => Syn::Percent,
+		Syn::AngleL2Eq => Syn::AngleL2,
+		Syn::AngleR2Eq => Syn::AngleR2,
+		Syn::AngleR3Eq => Syn::AngleR3,
+		Syn::PipeEq => Syn::Pipe,
+		Syn::AmpersandEq => Syn::Ampersand,
+		Syn::CaretEq => Syn::Caret,
+		_ => return None,
+	};
+
+	Some(ret)
+}
+
+fn operation_name(op: Syn) -> &'static str {
+	match op {
+		Syn::Plus => "addition",
+		Syn::Minus => "subtraction",
+		Syn::Asterisk => "multiplication",
+		Syn::Slash => "division",
+		Syn::Percent => "modulus",
+		Syn::Asterisk2 => "exponentiation",
+		Syn::KwDot => "dot product",
+		Syn::KwCross => "cross product",
+		Syn::Eq2 | Syn::BangEq | Syn::TildeEq2 => "comparison",
+		Syn::AngleL | Syn::AngleR | Syn::AngleLEq | Syn::AngleREq | Syn::AngleLAngleREq => {
+			"relative comparison"
+		}
+		Syn::AngleL2 | Syn::AngleR2 | Syn::AngleR3 => "shift",
+		_ => "bitwise operation",
+	}
+}
+
+fn scope_name(scope: DataScope) -> &'static str {
+	match scope {
+		DataScope::Clear => "clearscope",
+		DataScope::Play => "play",
+		DataScope::Ui => "ui",
+		DataScope::Virtual => "virtualscope",
+	}
+}
@@ -0,0 +1,419 @@
+//! Declaring types and their members, and linking them together.
+
+use rowan::ast::AstNode;
+
+use crate::zscript::{ast, Syn, SyntaxToken};
+
+use super::{DataScope, Database, Location, Symbol, SymbolId, SymbolKind};
+
+/// Work which can only be done once every file's types have been declared.
+#[derive(Debug, Default)]
+pub(super) struct Pending {
+	/// Class, and the name of its parent.
+	parents: Vec<(SymbolId, String)>,
+	/// File index, and the `extend class` or `extend struct` block.
+	extends: Vec<(usize, ast::Root)>,
+	/// The type using the mixin, the mixin's name, and the statement's location.
+	mixins: Vec<(SymbolId, SyntaxToken, usize)>,
+}
+
+/// Classes from `gzdoom.pk3` which are commonly inherited from, and their
+/// data scopes. Used only when these are not declared by any loaded tree.
+const NATIVE_SCOPES: &[(&str, DataScope)] = &[
+	("actor", DataScope::Play),
+	("ammo", DataScope::Play),
+	("armor", DataScope::Play),
+	("basestatusbar", DataScope::Ui),
+	("custominventory", DataScope::Play),
+	("eventhandler", DataScope::Clear),
+	("health", DataScope::Play),
+	("hudmessagebase", DataScope::Ui),
+	("inventory", DataScope::Play),
+	("key", DataScope::Play),
+	("listmenuitem", DataScope::Ui),
+	("menu", DataScope::Ui),
+	("menuitembase", DataScope::Ui),
+	("optionmenuitem", DataScope::Ui),
+	("playerpawn", DataScope::Play),
+	("powerup", DataScope::Play),
+	("stateprovider", DataScope::Play),
+	("staticeventhandler", DataScope::Clear),
+	("statusbarcore", DataScope::Ui),
+	("thinker", DataScope::Play),
+	("weapon", DataScope::Play),
+];
+
+impl Database<'_> {
+	pub(super) fn declare_file(&mut self, file: usize, pending: &mut Pending) {
+		for root in self.files[file].ast() {
+			match root {
+				ast::Root::Class(classdef) => {
+					let id = self.declare_type(file, None, classdef.syntax(), SymbolKind::Class);
+
+					if let Some(parent) = classdef.parent() {
+						pending.parents.push((id, parent.text().to_string()));
+					}
+
+					for innard in classdef.innards() {
+						self.declare_innard(file, Some(id), innard, pending);
+					}
+				}
+				ast::Root::Struct(structdef) => {
+					let id = self.declare_type(file, None, structdef.syntax(), SymbolKind::Struct);
+
+					for innard in structdef.innards() {
+						self.declare_innard(file, Some(id), innard, pending);
+					}
+				}
+				ast::Root::MixinClass(mixindef) => {
+					let id =
+						self.declare_type(file, None, mixindef.syntax(), SymbolKind::MixinClass);
+
+					for innard in mixindef.innards() {
+						self.declare_innard(file, Some(id), innard, pending);
+					}
+				}
+				ast::Root::Enum(enumdef) => self.declare_enum(file, None, enumdef),
+				ast::Root::Const(constdef) => {
+					self.declare(
+						file,
+						None,
+						constdef.name(),
+						constdef.syntax(),
+						SymbolKind::Const,
+					);
+				}
+				ast::Root::ClassExtend(_) | ast::Root::StructExtend(_) => {
+					pending.extends.push((file, root));
+				}
+				ast::Root::Include(_) | ast::Root::Version(_) => {}
+			}
+		}
+	}
+
+	pub(super) fn link(&mut self, pending: Pending) {
+		for (id, parent_name) in pending.parents {
+			let parent = self
+				.lookup_global(&parent_name)
+				.filter(|p| self.symbol(*p).kind == SymbolKind::Class);
+
+			let info = self.types.entry(id).or_default();
+			info.parent = parent;
+			info.parent_unknown = parent.is_none();
+		}
+
+		for (file, root) in pending.extends {
+			let (name, innards): (_, Vec<_>) = match &root {
+				ast::Root::ClassExtend(ext) => (ext.name(), ext.innards().collect()),
+				ast::Root::StructExtend(ext) => (ext.name(), ext.innards().collect()),
+				_ => unreachable!(),
+			};
+
+			// The resolver reports the name of an unknown type.
+			let Some(target) = self.lookup_type(name.text()) else {
+				continue;
+			};
+
+			self.type_nodes.insert(root.syntax().clone(), target);
+			let mut nested = Pending::default();
+
+			for innard in innards {
+				self.declare_innard(file, Some(target), innard, &mut nested);
+			}
+
+			debug_assert!(nested.parents.is_empty() && nested.extends.is_empty());
+
+			for (ty, name, file) in nested.mixins {
+				self.link_mixin(ty, name, file);
+			}
+		}
+
+		for (ty, name, file) in pending.mixins {
+			self.link_mixin(ty, name, file);
+		}
+	}
+
+	/// Determines the data scope of every type, and then every member.
+	pub(super) fn assign_scopes(&mut self) {
+		let ids: Vec<_> = self.symbols().map(|(id, _)| id).collect();
+
+		for &id in &ids {
+			if self.symbol(id).kind.is_type() && self.symbol(id).kind != SymbolKind::Enum {
+				let scope = self.type_scope(id);
+				self.symbols[id.0 as usize].scope = scope;
+			}
+		}
+
+		for &id in &ids {
+			let sym = self.symbol(id);
+
+			if !matches!(
+				sym.kind,
+				SymbolKind::Field | SymbolKind::Function | SymbolKind::Struct
+			) {
+				continue;
+			}
+
+			let explicit = qualifier_scope(&sym.syntax);
+
+			let scope = match (explicit, sym.kind) {
+				(Some(s), _) => Some(s),
+				// Nested structs without qualifiers are determined like
+				// top-level ones, which never inherit from their outer class.
+				(None, SymbolKind::Struct) => sym.scope,
+				(None, _) => sym.owner.and_then(|o| self.symbol(o).scope),
+			};
+
+			self.symbols[id.0 as usize].scope = scope;
+		}
+	}
+
+	fn type_scope(&self, id: SymbolId) -> Option<DataScope> {
+		for t in std::iter::once(id).chain(self.ancestors(id)) {
+			if let Some(scope) = qualifier_scope(&self.symbol(t).syntax) {
+				return Some(scope);
+			}
+
+			let info = self.types.get(&t);
+
+			if info.is_some_and(|i| i.parent_unknown) {
+				let classdef = ast::ClassDef::cast(self.symbol(t).syntax.clone())?;
+				let parent = classdef.parent()?.text().to_ascii_lowercase();
+
+				return NATIVE_SCOPES
+					.iter()
+					.find_map(|(name, scope)| (*name == parent).then_some(*scope));
+			}
+		}
+
+		Some(DataScope::Clear)
+	}
+
+	fn link_mixin(&mut self, ty: SymbolId, name: SyntaxToken, file: usize) {
+		let location = Location {
+			file,
+			range: name.text_range(),
+		};
+
+		match self
+			.lookup_type(name.text())
+			.filter(|m| self.symbol(*m).kind == SymbolKind::MixinClass)
+		{
+			Some(mixin) => {
+				self.refs.insert(location, mixin);
+				self.types.entry(ty).or_default().mixins.push(mixin);
+			}
+			None => self.unresolved.push(location),
+		}
+	}
+
+	fn declare_innard(
+		&mut self,
+		file: usize,
+		owner: Option<SymbolId>,
+		innard: ast::ClassInnard,
+		pending: &mut Pending,
+	) {
+		match innard {
+			ast::ClassInnard::Const(constdef) => {
+				self.declare(
+					file,
+					owner,
+					constdef.name(),
+					constdef.syntax(),
+					SymbolKind::Const,
+				);
+			}
+			ast::ClassInnard::Enum(enumdef) => self.declare_enum(file, owner, enumdef),
+			ast::ClassInnard::Struct(structdef) => {
+				let id = self.declare_type(file, owner, structdef.syntax(), SymbolKind::Struct);
+
+				for innard in structdef.innards() {
+					self.declare_innard(file, Some(id), innard, pending);
+				}
+			}
+			ast::ClassInnard::StaticConst(sconst) => {
+				self.declare(
+					file,
+					owner,
+					sconst.name(),
+					sconst.syntax(),
+					SymbolKind::StaticConst,
+				);
+			}
+			ast::ClassInnard::Field(field) => {
+				for var in field.names() {
+					self.declare(file, owner, var.ident(), var.syntax(), SymbolKind::Field);
+				}
+			}
+			ast::ClassInnard::Function(func) => {
+				self.declare(
+					file,
+					owner,
+					func.name(),
+					func.syntax(),
+					SymbolKind::Function,
+				);
+			}
+			ast::ClassInnard::Property(prop) => {
+				let id = self.add_symbol(Symbol {
+					name: prop.name().text().to_string(),
+					kind: SymbolKind::Property,
+					location: Location {
+						file,
+						range: prop.name().text_range(),
+					},
+					syntax: prop.syntax().clone(),
+					owner,
+					scope: None,
+				});
+
+				if let Some(o) = owner {
+					self.types
+						.entry(o)
+						.or_default()
+						.properties
+						.entry(prop.name().text().to_ascii_lowercase())
+						.or_insert(id);
+				}
+			}
+			ast::ClassInnard::FlagDef(flagdef) => {
+				let id = self.add_symbol(Symbol {
+					name: flagdef.name().text().to_string(),
+					kind: SymbolKind::FlagDef,
+					location: Location {
+						file,
+						range: flagdef.name().text_range(),
+					},
+					syntax: flagdef.syntax().clone(),
+					owner,
+					scope: None,
+				});
+
+				if let Some(o) = owner {
+					self.types
+						.entry(o)
+						.or_default()
+						.flags
+						.entry(flagdef.name().text().to_ascii_lowercase())
+						.or_insert(id);
+				}
+			}
+			ast::ClassInnard::Mixin(mixin) => {
+				if let Some(o) = owner {
+					pending.mixins.push((o, mixin.name(), file));
+				}
+			}
+			ast::ClassInnard::Default(_) | ast::ClassInnard::States(_) => {}
+		}
+	}
+
+	fn declare_enum(&mut self, file: usize, owner: Option<SymbolId>, enumdef: ast::EnumDef) {
+		let id = self.declare_type(file, owner, enumdef.syntax(), SymbolKind::Enum);
+
+		for variant in enumdef.variants() {
+			// Variants are visible both through the enum and in its enclosing scope.
+			let v = self.declare(
+				file,
+				owner,
+				variant.name(),
+				variant.syntax(),
+				SymbolKind::EnumVariant,
+			);
+
+			self.types
+				.entry(id)
+				.or_default()
+				.members
+				.entry(variant.name().text().to_ascii_lowercase())
+				.or_insert(v);
+		}
+	}
+
+	fn declare_type(
+		&mut self,
+		file: usize,
+		owner: Option<SymbolId>,
+		node: &crate::zscript::SyntaxNode,
+		kind: SymbolKind,
+	) -> SymbolId {
+		let name = node
+			.children_with_tokens()
+			.find_map(|n_or_t| {
+				if n_or_t.kind() == Syn::Ident {
+					n_or_t.into_token()
+				} else {
+					None
+				}
+			})
+			.unwrap();
+
+		let id = self.declare(file, owner, name, node, kind);
+		self.types.entry(id).or_default();
+		self.type_nodes.insert(node.clone(), id);
+		id
+	}
+
+	/// Adds a symbol, and registers it with `owner` or as a global.
+	/// If the name is already taken, the earlier declaration stays visible.
+	fn declare(
+		&mut self,
+		file: usize,
+		owner: Option<SymbolId>,
+		name: SyntaxToken,
+		node: &crate::zscript::SyntaxNode,
+		kind: SymbolKind,
+	) -> SymbolId {
+		let id = self.add_symbol(Symbol {
+			name: name.text().to_string(),
+			kind,
+			location: Location {
+				file,
+				range: name.text_range(),
+			},
+			syntax: node.clone(),
+			owner,
+			scope: None,
+		});
+
+		let key = name.text().to_ascii_lowercase();
+
+		match owner {
+			Some(o) => {
+				self.types
+					.entry(o)
+					.or_default()
+					.members
+					.entry(key)
+					.or_insert(id);
+			}
+			None => {
+				self.globals.entry(key).or_insert(id);
+			}
+		}
+
+		id
+	}
+}
+
+/// The scope given by a `play`, `ui`, `clearscope`, or `virtualscope`
+/// qualifier directly on the declaration `node`, if any.
+fn qualifier_scope(node: &crate::zscript::SyntaxNode) -> Option<DataScope> {
+	let quals = node
+		.children()
+		.find_map(ast::DeclQualifiers::cast)
+		.or_else(|| {
+			// Fields keep their qualifiers on the parent declaration.
+			node.parent()
+				.filter(|p| p.kind() == Syn::FieldDecl)
+				.and_then(|p| p.children().find_map(ast::DeclQualifiers::cast))
+		})?;
+
+	quals.keywords().find_map(|kw| match kw.kind() {
+		Syn::KwPlay => Some(DataScope::Play),
+		Syn::KwUi => Some(DataScope::Ui),
+		Syn::KwClearScope => Some(DataScope::Clear),
+		Syn::KwVirtualScope => Some(DataScope::Virtual),
+		_ => None,
+	})
+}
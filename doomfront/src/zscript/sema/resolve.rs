@@ -0,0 +1,541 @@
+//! Resolving identifiers to declarations.
+
+use rowan::{ast::AstNode, WalkEvent};
+
+use crate::zscript::{ast, Syn, SyntaxNode, SyntaxToken};
+
+use super::{Database, Location, Symbol, SymbolId, SymbolKind};
+
+/// Type names built into the language, which have no declaration to resolve to.
+const PRIMITIVES: &[&str] = &[
+	"bool",
+	"byte",
+	"color",
+	"double",
+	"float",
+	"float32",
+	"float64",
+	"int",
+	"int16",
+	"int8",
+	"name",
+	"sbyte",
+	"short",
+	"sound",
+	"spriteid",
+	"state",
+	"statelabel",
+	"string",
+	"textureid",
+	"uint",
+	"uint16",
+	"uint8",
+	"ushort",
+	"vector2",
+	"vector3",
+	"void",
+	"voidptr",
+];
+
+impl Database<'_> {
+	pub(super) fn resolve_file(&mut self, file: usize) {
+		let root = self.files[file].zipper().clone();
+
+		let mut resolver = Resolver {
+			db: self,
+			file,
+			types: vec![],
+			locals: vec![],
+		};
+
+		for event in root.preorder() {
+			match event {
+				WalkEvent::Enter(node) => resolver.enter(node),
+				WalkEvent::Leave(node) => resolver.leave(node),
+			}
+		}
+	}
+
+	/// The class or struct which an expression evaluates to (an instance of),
+	/// as far as can be told from declarations alone. `context` is the type
+	/// whose code contains the expression, for resolving `self` and `super`.
+	///
+	/// Also yields the type itself for expressions which name a type, such
+	/// as the `Actor` in `Actor.GetDefaultByType(cls)`.
+	#[must_use]
+	pub fn expr_type(
+		&self,
+		file: usize,
+		expr: &ast::Expression,
+		context: Option<SymbolId>,
+	) -> Option<SymbolId> {
+		match expr {
+			ast::Expression::Ident(e_id) => {
+				let token = e_id.token();
+
+				match token.text().to_ascii_lowercase().as_str() {
+					"self" | "invoker" => context,
+					"super" => context.and_then(|c| self.parent_of(c)),
+					_ => self.token_type(file, &token),
+				}
+			}
+			ast::Expression::Member(e_mem) => self.token_type(file, &e_mem.member()),
+			ast::Expression::Call(e_call) => {
+				let callee = e_call.callee();
+
+				let token = match &callee {
+					ast::Expression::Ident(e_id) => e_id.token(),
+					ast::Expression::Member(e_mem) => e_mem.member(),
+					_ => return None,
+				};
+
+				let target = self.resolved(Location {
+					file,
+					range: token.text_range(),
+				})?;
+
+				let sym = self.symbol(target);
+
+				if sym.kind.is_type() {
+					// A cast, e.g. `Actor(mo)`.
+					return Some(target);
+				}
+
+				if sym.kind != SymbolKind::Function {
+					return None;
+				}
+
+				let func = ast::FunctionDecl::cast(sym.syntax.clone())?;
+				let ret = func.return_types().next()?;
+				self.type_ref_target(&ret, sym.owner, false)
+			}
+			ast::Expression::Group(e_grp) => self.expr_type(file, &e_grp.inner(), context),
+			ast::Expression::ClassCast(e_cast) => {
+				self.type_ref_target(&e_cast.type_spec(), context, false)
+			}
+			ast::Expression::Index(e_index) => {
+				let indexed = e_index.indexed();
+
+				let token = match &indexed {
+					ast::Expression::Ident(e_id) => e_id.token(),
+					ast::Expression::Member(e_mem) => e_mem.member(),
+					_ => return None,
+				};
+
+				let target = self.resolved(Location {
+					file,
+					range: token.text_range(),
+				})?;
+
+				self.symbol_type(target, true)
+			}
+			ast::Expression::Ternary(e_tern) => self.expr_type(file, &e_tern.if_true(), context),
+			ast::Expression::Binary(_)
+			| ast::Expression::Literal(_)
+			| ast::Expression::Postfix(_)
+			| ast::Expression::Prefix(_)
+			| ast::Expression::Vector(_) => None,
+		}
+	}
+
+	fn token_type(&self, file: usize, token: &SyntaxToken) -> Option<SymbolId> {
+		let target = self.resolved(Location {
+			file,
+			range: token.text_range(),
+		})?;
+
+		if self.symbol(target).kind.is_type() {
+			Some(target)
+		} else {
+			self.symbol_type(target, false)
+		}
+	}
+
+	/// The class or struct type of a variable-like symbol.
+	/// If `indexed`, the element type of an array is given instead.
+	fn symbol_type(&self, id: SymbolId, indexed: bool) -> Option<SymbolId> {
+		let sym = self.symbol(id);
+
+		let type_ref = match sym.kind {
+			SymbolKind::Field => sym
+				.syntax
+				.parent()
+				.and_then(ast::FieldDecl::cast)
+				.map(|field| field.type_spec()),
+			SymbolKind::Parameter => {
+				ast::Parameter::cast(sym.syntax.clone()).map(|p| p.type_spec())
+			}
+			SymbolKind::Local => {
+				let local = ast::LocalVar::cast(sym.syntax.clone())?;
+				let type_ref = local.type_spec()?;
+
+				if type_ref.first_token().kind() == Syn::KwLet {
+					let init = local.initializer()?;
+					let context = self.context_of(&sym.syntax);
+					return self.expr_type(sym.location.file, &init, context);
+				}
+
+				Some(type_ref)
+			}
+			SymbolKind::StaticConst => {
+				ast::StaticConstDef::cast(sym.syntax.clone()).map(|sc| sc.type_spec())
+			}
+			_ => None,
+		}?;
+
+		self.type_ref_target(&type_ref, sym.owner, indexed)
+	}
+
+	fn type_ref_target(
+		&self,
+		type_ref: &ast::TypeRef,
+		context: Option<SymbolId>,
+		indexed: bool,
+	) -> Option<SymbolId> {
+		match type_ref.first_token().kind() {
+			Syn::Ident => {
+				let last = type_ref
+					.syntax()
+					.children_with_tokens()
+					.filter_map(|n_or_t| n_or_t.into_token())
+					.filter(|token| token.kind() == Syn::Ident)
+					.last()?;
+
+				let file = self.file_of(type_ref.syntax())?;
+
+				self.resolved(Location {
+					file,
+					range: last.text_range(),
+				})
+				.or_else(|| self.resolve_type_name(type_ref, context))
+			}
+			Syn::KwReadOnly | Syn::KwClass => {
+				let inner = type_ref.generic_args().next()?;
+				self.type_ref_target(&inner, context, indexed)
+			}
+			Syn::KwArray if indexed => {
+				let inner = type_ref.generic_args().next()?;
+				self.type_ref_target(&inner, context, false)
+			}
+			_ => None,
+		}
+	}
+
+	/// Resolves a dotted type name like `Outer.Inner` without recording anything.
+	pub(super) fn resolve_type_name(
+		&self,
+		type_ref: &ast::TypeRef,
+		context: Option<SymbolId>,
+	) -> Option<SymbolId> {
+		let mut idents = type_ref
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.filter(|token| token.kind() == Syn::Ident);
+
+		let first = idents.next()?;
+		let mut cur = self.lookup_in_context(first.text(), context)?;
+
+		for ident in idents {
+			cur = self.lookup_member(cur, ident.text())?;
+		}
+
+		self.symbol(cur).kind.is_type().then_some(cur)
+	}
+
+	/// Searches `context`, its ancestors, and its enclosing types (for nested
+	/// structs), and then the top level.
+	fn lookup_in_context(&self, name: &str, context: Option<SymbolId>) -> Option<SymbolId> {
+		let mut ctx = context;
+
+		while let Some(c) = ctx {
+			if let Some(found) = self.lookup_member(c, name) {
+				return Some(found);
+			}
+
+			ctx = self.symbol(c).owner;
+		}
+
+		self.lookup_global(name)
+	}
+
+	/// The innermost type whose definition (or `extend` block) contains `node`.
+	pub(super) fn context_of(&self, node: &SyntaxNode) -> Option<SymbolId> {
+		node.ancestors()
+			.find_map(|anc| self.type_nodes.get(&anc).copied())
+	}
+
+	pub(super) fn file_of(&self, node: &SyntaxNode) -> Option<usize> {
+		let root = node.ancestors().last()?;
+		self.files.iter().position(|f| *f.zipper() == root)
+	}
+}
+
+struct Resolver<'db, 't> {
+	db: &'db mut Database<'t>,
+	file: usize,
+	/// The type definitions being walked through, innermost last.
+	/// `None` for `extend` blocks of unknown types.
+	types: Vec<Option<SymbolId>>,
+	/// Local variable scopes, innermost last. Keys are lowercased.
+	locals: Vec<Vec<(String, SymbolId)>>,
+}
+
+impl Resolver<'_, '_> {
+	fn enter(&mut self, node: SyntaxNode) {
+		match node.kind() {
+			Syn::ClassDef
+			| Syn::StructDef
+			| Syn::MixinClassDef
+			| Syn::ClassExtend
+			| Syn::StructExtend => {
+				self.types.push(self.db.type_nodes.get(&node).copied());
+
+				if matches!(node.kind(), Syn::ClassExtend | Syn::StructExtend) {
+					let name = ast_name(&node);
+					self.resolve_global_type(&name);
+				}
+			}
+			Syn::InheritSpec | Syn::ReplacesClause => {
+				let name = ast_name(&node);
+				self.resolve_global_type(&name);
+			}
+			Syn::FunctionDecl | Syn::StatCompound | Syn::StatFor => self.locals.push(vec![]),
+			Syn::StatForEach => {
+				self.locals.push(vec![]);
+				let stat = ast::StatForEach::cast(node).unwrap();
+
+				for binding in stat.bindings() {
+					self.declare_local(binding, stat.syntax(), SymbolKind::Local);
+				}
+			}
+			Syn::TypeRef => self.resolve_type_ref(&ast::TypeRef::cast(node).unwrap()),
+			Syn::ExprIdent => self.resolve_ident(&ast::ExprIdent::cast(node).unwrap().token()),
+			Syn::DefaultProperty => {
+				let prop = ast::DefaultProperty::cast(node).unwrap();
+				self.resolve_dotted(prop.syntax(), |db, ty, name| db.lookup_property(ty, name));
+			}
+			Syn::DefaultFlag => {
+				let flag = ast::DefaultFlag::cast(node).unwrap();
+				self.resolve_dotted(flag.syntax(), |db, ty, name| db.lookup_flag(ty, name));
+			}
+			Syn::StateAction => {
+				let action = ast::StateAction::cast(node).unwrap();
+				let name = action.name();
+
+				if let Some(Some(ty)) = self.types.last() {
+					match self.db.lookup_member(*ty, name.text()) {
+						Some(func) => self.record(&name, func),
+						None => self.unresolved(&name),
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn leave(&mut self, node: SyntaxNode) {
+		match node.kind() {
+			Syn::ClassDef
+			| Syn::StructDef
+			| Syn::MixinClassDef
+			| Syn::ClassExtend
+			| Syn::StructExtend => {
+				self.types.pop();
+			}
+			Syn::FunctionDecl | Syn::StatCompound | Syn::StatFor | Syn::StatForEach => {
+				self.locals.pop();
+			}
+			Syn::Parameter => {
+				let param = ast::Parameter::cast(node).unwrap();
+				self.declare_local(param.name(), param.syntax(), SymbolKind::Parameter);
+			}
+			Syn::LocalVar => {
+				let local = ast::LocalVar::cast(node).unwrap();
+				self.declare_local(local.name(), local.syntax(), SymbolKind::Local);
+			}
+			// Those in class and struct bodies are declared as members instead.
+			Syn::StaticConstDef if !self.locals.is_empty() => {
+				let sconst = ast::StaticConstDef::cast(node).unwrap();
+				self.declare_local(sconst.name(), sconst.syntax(), SymbolKind::StaticConst);
+			}
+			Syn::ExprMember => self.resolve_member(&ast::ExprMember::cast(node).unwrap()),
+			_ => {}
+		}
+	}
+
+	fn context(&self) -> Option<SymbolId> {
+		self.types.last().copied().flatten()
+	}
+
+	fn declare_local(&mut self, name: SyntaxToken, node: &SyntaxNode, kind: SymbolKind) {
+		let id = self.db.add_symbol(Symbol {
+			name: name.text().to_string(),
+			kind,
+			location: Location {
+				file: self.file,
+				range: name.text_range(),
+			},
+			syntax: node.clone(),
+			owner: None,
+			scope: None,
+		});
+
+		if let Some(scope) = self.locals.last_mut() {
+			scope.push((name.text().to_ascii_lowercase(), id));
+		}
+	}
+
+	fn resolve_ident(&mut self, token: &SyntaxToken) {
+		let key = token.text().to_ascii_lowercase();
+
+		if matches!(key.as_str(), "self" | "super" | "invoker") {
+			return;
+		}
+
+		let local = self
+			.locals
+			.iter()
+			.rev()
+			.flat_map(|scope| scope.iter().rev())
+			.find_map(|(name, id)| (*name == key).then_some(*id));
+
+		match local.or_else(|| self.db.lookup_in_context(&key, self.context())) {
+			Some(id) => self.record(token, id),
+			None => self.unresolved(token),
+		}
+	}
+
+	fn resolve_member(&mut self, e_mem: &ast::ExprMember) {
+		let member = e_mem.member();
+
+		let Some(owner) = self.db.expr_type(self.file, &e_mem.owner(), self.context()) else {
+			return;
+		};
+
+		match self.db.lookup_member(owner, member.text()) {
+			Some(id) => self.record(&member, id),
+			None => {
+				if !self.db.has_unknown_ancestor(owner) {
+					self.unresolved(&member);
+				}
+			}
+		}
+	}
+
+	fn resolve_type_ref(&mut self, type_ref: &ast::TypeRef) {
+		if type_ref.first_token().kind() != Syn::Ident {
+			return;
+		}
+
+		let mut idents = type_ref
+			.syntax()
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.filter(|token| token.kind() == Syn::Ident);
+
+		let first = idents.next().unwrap();
+
+		if PRIMITIVES
+			.iter()
+			.any(|p| first.text().eq_ignore_ascii_case(p))
+		{
+			return;
+		}
+
+		let Some(mut cur) = self
+			.db
+			.lookup_in_context(first.text(), self.context())
+			.filter(|id| self.db.symbol(*id).kind.is_type())
+		else {
+			self.unresolved(&first);
+			return;
+		};
+
+		self.record(&first, cur);
+
+		for ident in idents {
+			match self.db.lookup_member(cur, ident.text()) {
+				Some(id) => {
+					self.record(&ident, id);
+					cur = id;
+				}
+				None => {
+					self.unresolved(&ident);
+					return;
+				}
+			}
+		}
+	}
+
+	/// For `default` block items, like `Health` or `Inventory.Amount`.
+	/// Unqualified names are looked up through the class being defined.
+	fn resolve_dotted<F>(&mut self, node: &SyntaxNode, lookup: F)
+	where
+		F: Fn(&Database, SymbolId, &str) -> Option<SymbolId>,
+	{
+		let idents: Vec<_> = node
+			.children_with_tokens()
+			.filter_map(|n_or_t| n_or_t.into_token())
+			.filter(|token| token.kind() == Syn::Ident)
+			.collect();
+
+		let (ty, name) = match idents.as_slice() {
+			[name] => (self.context(), name),
+			[class, name] => {
+				let ty = self.db.lookup_type(class.text());
+
+				if let Some(t) = ty {
+					self.record(class, t);
+				}
+
+				(ty, name)
+			}
+			_ => return,
+		};
+
+		// Most properties and flags are native; only report what can be known.
+		if let Some(t) = ty {
+			if let Some(id) = lookup(self.db, t, name.text()) {
+				self.record(name, id);
+			}
+		}
+	}
+
+	fn resolve_global_type(&mut self, name: &SyntaxToken) {
+		match self.db.lookup_type(name.text()) {
+			Some(id) => self.record(name, id),
+			None => self.unresolved(name),
+		}
+	}
+
+	fn record(&mut self, token: &SyntaxToken, id: SymbolId) {
+		self.db.refs.insert(
+			Location {
+				file: self.file,
+				range: token.text_range(),
+			},
+			id,
+		);
+	}
+
+	fn unresolved(&mut self, token: &SyntaxToken) {
+		self.db.unresolved.push(Location {
+			file: self.file,
+			range: token.text_range(),
+		});
+	}
+}
+
+/// The first identifier directly beneath `node`.
+fn ast_name(node: &SyntaxNode) -> SyntaxToken {
+	node.children_with_tokens()
+		.find_map(|n_or_t| {
+			if n_or_t.kind() == Syn::Ident {
+				n_or_t.into_token()
+			} else {
+				None
+			}
+		})
+		.unwrap()
+}
@@ -0,0 +1,306 @@
+//! The types of ZScript values, and the conversions between them.
+
+use std::fmt;
+
+use rowan::ast::AstNode;
+
+use crate::zscript::{ast, Syn};
+
+use super::{Database, SymbolId, SymbolKind};
+
+/// The type of a ZScript value, as far as can be told statically.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+	/// Could not be determined, e.g. because it depends on a native declaration
+	/// which no loaded tree provides. Converts to and from anything, so that
+	/// one unknown does not cascade into many false diagnostics.
+	Unknown,
+	Void,
+	Bool,
+	/// Any signed integer: `int`, `int16`, `int8`, et cetera.
+	Int,
+	/// Any unsigned integer: `uint`, `uint16`, `uint8`, et cetera.
+	UInt,
+	/// `float` or `double`; ZScript performs all floating-point math in 64 bits.
+	Float,
+	String,
+	Name,
+	Sound,
+	Color,
+	SpriteId,
+	TextureId,
+	State,
+	StateLabel,
+	Vector2,
+	Vector3,
+	/// The type of the `null` literal.
+	Null,
+	/// A (nullable) reference to an instance of a class.
+	Object(SymbolId),
+	/// `class<T>`, or `class` if the inner type is `None`.
+	Class(Option<SymbolId>),
+	Struct(SymbolId),
+	Enum(SymbolId),
+	/// `Array<T>`.
+	Array(Box<Type>),
+	/// `T name[N]`.
+	FixedArray(Box<Type>),
+	/// `Map<K, V>`.
+	Map(Box<Type>, Box<Type>),
+	/// `MapIterator<K, V>`.
+	MapIterator(Box<Type>, Box<Type>),
+	/// `readonly<T>`.
+	ReadOnly(Box<Type>),
+	/// A class, struct, or enum named in expression position,
+	/// such as the `Actor` in `Actor.GetDefaultByType(cls)`.
+	TypeName(SymbolId),
+}
+
+impl Type {
+	/// Is this a boolean, integral, enum, color, or floating-point type?
+	#[must_use]
+	pub fn is_numeric(&self) -> bool {
+		self.is_integral() || matches!(self, Self::Float)
+	}
+
+	/// Is this a boolean, integral, enum, or color type?
+	#[must_use]
+	pub fn is_integral(&self) -> bool {
+		matches!(
+			self,
+			Self::Bool | Self::Int | Self::UInt | Self::Enum(_) | Self::Color
+		)
+	}
+
+	#[must_use]
+	pub fn is_vector(&self) -> bool {
+		matches!(self, Self::Vector2 | Self::Vector3)
+	}
+
+	#[must_use]
+	pub fn is_unknown(&self) -> bool {
+		matches!(self, Self::Unknown)
+	}
+
+	/// Strips away any `readonly<...>`.
+	#[must_use]
+	pub fn unqualified(&self) -> &Self {
+		match self {
+			Self::ReadOnly(inner) => inner.unqualified(),
+			other => other,
+		}
+	}
+
+	/// The result type of arithmetic between two numeric types.
+	#[must_use]
+	pub(super) fn promote(&self, other: &Self) -> Self {
+		if matches!(self, Self::Float) || matches!(other, Self::Float) {
+			Self::Float
+		} else if matches!(self, Self::UInt) || matches!(other, Self::UInt) {
+			Self::UInt
+		} else {
+			Self::Int
+		}
+	}
+}
+
+/// How a value of one type may be implicitly converted to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+	Allowed,
+	/// Allowed, but GZDoom warns since the fractional part is lost.
+	Truncating,
+	Forbidden,
+}
+
+impl Database<'_> {
+	/// Can a value of type `from` be implicitly converted to type `to`,
+	/// e.g. in an assignment, initializer, argument, or return statement?
+	#[must_use]
+	pub fn conversion(&self, from: &Type, to: &Type) -> Conversion {
+		use Conversion::*;
+
+		if from.is_unknown() || to.is_unknown() || from == to {
+			return Allowed;
+		}
+
+		match (from, to) {
+			(Type::ReadOnly(f), Type::ReadOnly(t)) => self.conversion(f, t),
+			(f, Type::ReadOnly(t)) => self.conversion(f, t),
+			// Casting away `readonly` is forbidden, except to `bool`.
+			(Type::ReadOnly(f), Type::Bool) => self.conversion(f, &Type::Bool),
+			(Type::ReadOnly(_), _) => Forbidden,
+			(Type::Float, t) if t.is_integral() && !matches!(t, Type::Bool) => Truncating,
+			(f, t) if f.is_numeric() && t.is_numeric() => Allowed,
+			(Type::Object(_) | Type::Class(_) | Type::Null | Type::State, Type::Bool) => Allowed,
+			(Type::String | Type::Name, Type::String | Type::Name) => Allowed,
+			(
+				Type::String | Type::Name,
+				Type::Sound | Type::StateLabel | Type::Class(_) | Type::Color,
+			) => Allowed,
+			(Type::Int | Type::UInt, Type::Sound | Type::SpriteId | Type::TextureId) => Allowed,
+			(Type::Null, Type::Object(_) | Type::Class(_) | Type::State | Type::StateLabel) => {
+				Allowed
+			}
+			(Type::Object(f), Type::Object(t)) | (Type::Class(Some(f)), Type::Class(Some(t))) => {
+				if self.is_subclass_of(*f, *t) || self.has_unknown_ancestor(*f) {
+					Allowed
+				} else {
+					Forbidden
+				}
+			}
+			(Type::Class(_), Type::Class(None)) => Allowed,
+			(Type::Array(f), Type::Array(t)) | (Type::FixedArray(f), Type::FixedArray(t)) => {
+				if self.conversion(f, t) == Allowed && self.conversion(t, f) == Allowed {
+					Allowed
+				} else {
+					Forbidden
+				}
+			}
+			_ => Forbidden,
+		}
+	}
+
+	/// Converts a written type specifier into a [`Type`].
+	/// `let` yields [`Type::Unknown`]; the caller must infer from an initializer.
+	#[must_use]
+	pub fn type_from_ref(&self, type_ref: &ast::TypeRef, context: Option<SymbolId>) -> Type {
+		let mut generics = type_ref.generic_args();
+
+		let mut next = |db: &Self| {
+			generics
+				.next()
+				.map_or(Type::Unknown, |g| db.type_from_ref(&g, context))
+		};
+
+		match type_ref.first_token().kind() {
+			Syn::KwLet => Type::Unknown,
+			Syn::KwClass => match type_ref.generic_args().next() {
+				Some(inner) => match self.type_from_ref(&inner, context) {
+					Type::Object(id) => Type::Class(Some(id)),
+					_ => Type::Class(None),
+				},
+				None => Type::Class(None),
+			},
+			Syn::KwArray => Type::Array(Box::new(next(self))),
+			Syn::KwMap => Type::Map(Box::new(next(self)), Box::new(next(self))),
+			Syn::KwMapIterator => Type::MapIterator(Box::new(next(self)), Box::new(next(self))),
+			Syn::KwReadOnly => Type::ReadOnly(Box::new(next(self))),
+			_ => {
+				let name = type_ref.name().unwrap_or_default();
+
+				if let Some(prim) = primitive(&name) {
+					return prim;
+				}
+
+				let file = self.file_of(type_ref.syntax());
+
+				let last = type_ref
+					.syntax()
+					.children_with_tokens()
+					.filter_map(|n_or_t| n_or_t.into_token())
+					.filter(|token| token.kind() == Syn::Ident)
+					.last();
+
+				let resolved = file
+					.zip(last)
+					.and_then(|(file, token)| {
+						self.resolved(super::Location {
+							file,
+							range: token.text_range(),
+						})
+					})
+					.or_else(|| self.resolve_type_name(type_ref, context));
+
+				match resolved {
+					Some(id) => self.instance_type(id),
+					None => Type::Unknown,
+				}
+			}
+		}
+	}
+
+	#[must_use]
+	pub fn display_type<'db>(&'db self, ty: &'db Type) -> TypeDisplay<'db> {
+		TypeDisplay { db: self, ty }
+	}
+
+	/// The type of an instance of the class, struct, or enum `id`.
+	pub(super) fn instance_type(&self, id: SymbolId) -> Type {
+		match self.symbol(id).kind {
+			SymbolKind::Class | SymbolKind::MixinClass => Type::Object(id),
+			SymbolKind::Struct => Type::Struct(id),
+			SymbolKind::Enum => Type::Enum(id),
+			_ => Type::Unknown,
+		}
+	}
+}
+
+/// Maps the name of a primitive type to a [`Type`], case-insensitively.
+#[must_use]
+pub(super) fn primitive(name: &str) -> Option<Type> {
+	let ret = match name.to_ascii_lowercase().as_str() {
+		"bool" => Type::Bool,
+		"int" | "int16" | "int8" | "sbyte" | "short" => Type::Int,
+		"uint" | "uint16" | "uint8" | "byte" | "ushort" => Type::UInt,
+		"float" | "double" | "float32" | "float64" => Type::Float,
+		"string" => Type::String,
+		"name" => Type::Name,
+		"sound" => Type::Sound,
+		"color" => Type::Color,
+		"spriteid" => Type::SpriteId,
+		"textureid" => Type::TextureId,
+		"state" => Type::State,
+		"statelabel" => Type::StateLabel,
+		"vector2" => Type::Vector2,
+		"vector3" => Type::Vector3,
+		"void" => Type::Void,
+		"voidptr" => Type::Unknown,
+		_ => return None,
+	};
+
+	Some(ret)
+}
+
+/// Formats types the way GZDoom's diagnostics name them.
+pub struct TypeDisplay<'db> {
+	db: &'db Database<'db>,
+	ty: &'db Type,
+}
+
+impl fmt::Display for TypeDisplay<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let sub = |ty| TypeDisplay { db: self.db, ty };
+
+		match self.ty {
+			Type::Unknown => write!(f, "<unknown>"),
+			Type::Void => write!(f, "void"),
+			Type::Bool => write!(f, "bool"),
+			Type::Int => write!(f, "int"),
+			Type::UInt => write!(f, "uint"),
+			Type::Float => write!(f, "double"),
+			Type::String => write!(f, "String"),
+			Type::Name => write!(f, "Name"),
+			Type::Sound => write!(f, "Sound"),
+			Type::Color => write!(f, "Color"),
+			Type::SpriteId => write!(f, "SpriteID"),
+			Type::TextureId => write!(f, "TextureID"),
+			Type::State => write!(f, "State"),
+			Type::StateLabel => write!(f, "StateLabel"),
+			Type::Vector2 => write!(f, "Vector2"),
+			Type::Vector3 => write!(f, "Vector3"),
+			Type::Null => write!(f, "null"),
+			Type::Object(id) => write!(f, "Pointer<{}>", self.db.symbol(*id).name),
+			Type::Class(Some(id)) => write!(f, "ClassPointer<{}>", self.db.symbol(*id).name),
+			Type::Class(None) => write!(f, "ClassPointer<Object>"),
+			Type::Struct(id) | Type::Enum(id) | Type::TypeName(id) => {
+				write!(f, "{}", self.db.symbol(*id).name)
+			}
+			Type::Array(elem) => write!(f, "DynArray<{}>", sub(elem)),
+			Type::FixedArray(elem) => write!(f, "Array<{}>", sub(elem)),
+			Type::Map(k, v) => write!(f, "Map<{}, {}>", sub(k), sub(v)),
+			Type::MapIterator(k, v) => write!(f, "MapIterator<{}, {}>", sub(k), sub(v)),
+			Type::ReadOnly(inner) => write!(f, "readonly<{}>", sub(inner)),
+		}
+	}
+}
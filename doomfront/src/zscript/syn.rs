@@ -0,0 +1,433 @@
+//! Syntax tags.
+
+use crate::{LangComment, LangExt};
+
+use super::ast;
+
+/// ZScript syntax nodes, from low-level primitives to high-level composites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+#[repr(u16)]
+pub enum Syn {
+	// Top-level and class-level definitions ///////////////////////////////////
+	/// `action` or `action(actor, overlay, ...)` in a set of qualifiers.
+	ActionQual,
+	/// `(expr, name: expr, ...)`; the arguments passed to a function call.
+	ArgList,
+	/// An expression in an [`Syn::ArgList`], optionally prefixed with `name:`.
+	Argument,
+	/// `[expr]` following a field or local variable name. The expression is optional.
+	ArrayLen,
+	/// `class Name : Parent replaces Other qualifiers { ... }`
+	ClassDef,
+	/// `extend class Name { ... }`
+	ClassExtend,
+	/// `const NAME = expr;`
+	ConstDef,
+	/// Any number of qualifier keywords preceding a field, function, or type.
+	DeclQualifiers,
+	/// `default { ... }` in a class definition.
+	DefaultBlock,
+	/// `+FLAG` or `-FLAG` in a [`Syn::DefaultBlock`], optionally with a trailing `;`.
+	DefaultFlag,
+	/// `Property.Name expr, expr;` in a [`Syn::DefaultBlock`].
+	DefaultProperty,
+	/// `deprecated("version", "message")` in a set of qualifiers.
+	DeprecationQual,
+	/// `enum Name : type { ... }`
+	EnumDef,
+	/// `NAME = expr` or just `NAME` in an [`Syn::EnumDef`].
+	EnumVariant,
+	/// `qualifiers type name[len], name;`
+	FieldDecl,
+	/// `flagdef Name: field, bit;`
+	FlagDef,
+	/// `qualifiers type, type name(params) const { ... }`
+	FunctionDecl,
+	/// `#include "path"`
+	IncludeDirective,
+	/// `: Parent` in a [`Syn::ClassDef`].
+	InheritSpec,
+	/// `{ expr, expr, ... }`; used by static constant arrays.
+	InitList,
+	/// `mixin class Name { ... }`
+	MixinClassDef,
+	/// `mixin Name;` inside a class definition.
+	MixinStat,
+	/// `in type name = expr` within a [`Syn::ParamList`].
+	Parameter,
+	/// `(param, param, ...)`, part of a [`Syn::FunctionDecl`].
+	ParamList,
+	/// `property Name: field, field;`
+	PropertyDef,
+	/// `replaces Other` in a [`Syn::ClassDef`].
+	ReplacesClause,
+	/// One or more comma-separated [`Syn::TypeRef`]s in a [`Syn::FunctionDecl`].
+	ReturnTypes,
+	/// `{ label; goto label; spritename frames duration qualifiers action; }`
+	StatesBlock,
+	/// `(actor, overlay, ...)` between `states` and the block's opening brace.
+	StatesUsage,
+	/// `static const type name[] = { ... };`
+	StaticConstDef,
+	/// `struct Name qualifiers { ... }`
+	StructDef,
+	/// `extend struct Name { ... }`
+	StructExtend,
+	/// A type specifier, e.g. `int`, `Class<Actor>`, or `Map<Name, int>`.
+	TypeRef,
+	/// `name` or `name[len]` in a [`Syn::FieldDecl`].
+	VarName,
+	/// `version "x.y.z"`, ahead of everything else in a file.
+	VersionDirective,
+	/// `version("x.y.z")` in a set of qualifiers.
+	VersionQual,
+
+	// States //////////////////////////////////////////////////////////////////
+	/// `A_Chase` or `A_Chase(args)` at the end of a [`Syn::StateDef`].
+	StateAction,
+	/// `spritename frames duration qualifiers action;`
+	StateDef,
+	/// Wraps the expression specifying how many tics a [`Syn::StateDef`] lasts.
+	StateDuration,
+	/// `goto label+offset;`, `stop;`, `loop;`, `wait;`, or `fail;`.
+	StateFlow,
+	/// `Label:`
+	StateLabel,
+	/// `light("name")` qualifier on a [`Syn::StateDef`].
+	StateLight,
+	/// `offset(x, y)` qualifier on a [`Syn::StateDef`].
+	StateOffset,
+
+	// Statements //////////////////////////////////////////////////////////////
+	/// `[a, b] = expr;`
+	StatAssignMulti,
+	/// `break;`
+	StatBreak,
+	/// `case expr:` inside a switch statement's block.
+	StatCase,
+	/// `default:` inside a switch statement's block.
+	StatCaseDefault,
+	/// `{ ... }`
+	StatCompound,
+	/// `continue;`
+	StatContinue,
+	/// `do stat while (expr);` or `do stat until (expr);`
+	StatDoWhile,
+	/// `;`
+	StatEmpty,
+	/// `expr;`
+	StatExpr,
+	/// `for (init; cond; iter) stat`
+	StatFor,
+	/// `foreach (name : expr) stat` or `foreach (key, val : expr) stat`
+	StatForEach,
+	/// `if (expr) stat` with an optional `else stat`.
+	StatIf,
+	/// `type name = expr, name;` or `let name = expr;`
+	StatLocalVar,
+	/// `return;` or `return expr, expr;`
+	StatReturn,
+	/// `switch (expr) stat`
+	StatSwitch,
+	/// `until (expr) stat`
+	StatUntil,
+	/// `while (expr) stat`
+	StatWhile,
+	/// The portion of a [`Syn::StatFor`] before the first semicolon.
+	ForInit,
+	/// The portion of a [`Syn::StatFor`] between the two semicolons.
+	ForCond,
+	/// The portion of a [`Syn::StatFor`] after the second semicolon.
+	ForIter,
+	/// `name = expr` or `name[len]` within a [`Syn::StatLocalVar`].
+	LocalVar,
+
+	// Expressions /////////////////////////////////////////////////////////////
+	/// e.g. `expr + expr`, `expr = expr`, or `expr is "Class"`.
+	ExprBinary,
+	/// `expr(args)`
+	ExprCall,
+	/// `(class<Type>)(expr)`
+	ExprClassCast,
+	/// `(expr)`
+	ExprGroup,
+	/// Syntax node with just a [`Syn::Ident`] token as a child.
+	ExprIdent,
+	/// `expr[expr]`; array element access.
+	ExprIndex,
+	/// `expr.name`
+	ExprMember,
+	/// e.g. `expr++` or `expr--`
+	ExprPostfix,
+	/// e.g. `-expr`, `!expr`, or `sizeof expr`
+	ExprPrefix,
+	/// `expr ? expr : expr`
+	ExprTernary,
+	/// `(expr, expr)` or `(expr, expr, expr)`
+	ExprVector,
+	/// Wraps one of the `Lit`- tokens. Adjacent string literals get concatenated,
+	/// so a literal node may hold multiple [`Syn::LitString`] tokens.
+	Literal,
+
+	// Keywords ////////////////////////////////////////////////////////////////
+	KwAbstract,
+	KwAction,
+	KwAlignOf,
+	KwArray,
+	KwBreak,
+	KwBright,
+	KwCanRaise,
+	KwCase,
+	KwClass,
+	KwClearScope,
+	KwConst,
+	KwContinue,
+	KwCross,
+	KwDefault,
+	KwDeprecated,
+	KwDo,
+	KwDot,
+	KwElse,
+	KwEnum,
+	KwExtend,
+	KwFail,
+	KwFast,
+	KwFinal,
+	KwFlagDef,
+	KwFor,
+	KwForEach,
+	KwGoto,
+	KwIf,
+	KwIn,
+	/// `#include`
+	KwInclude,
+	KwInternal,
+	KwIs,
+	KwLatent,
+	KwLet,
+	KwLight,
+	KwLoop,
+	KwMap,
+	KwMapIterator,
+	KwMeta,
+	KwMixin,
+	KwNative,
+	KwNoDelay,
+	KwOffset,
+	KwOut,
+	KwOverride,
+	KwPlay,
+	KwPrivate,
+	KwProperty,
+	KwProtected,
+	KwReadOnly,
+	KwReplaces,
+	KwReturn,
+	KwSizeOf,
+	KwSlow,
+	KwStates,
+	KwStatic,
+	KwStop,
+	KwStruct,
+	KwSwitch,
+	KwTransient,
+	KwUi,
+	KwUntil,
+	KwVarArg,
+	KwVersion,
+	KwVirtual,
+	KwVirtualScope,
+	KwWait,
+	KwWhile,
+
+	// Literals ////////////////////////////////////////////////////////////////
+	/// The exact string `false`.
+	LitFalse,
+	/// A (G)ZDoom (i.e. C/C++-style) floating-point literal.
+	LitFloat,
+	/// A (G)ZDoom (i.e. C/C++-style) integer literal.
+	LitInt,
+	/// A string delimited by single-quotes (`'`).
+	LitName,
+	/// The exact string `null`.
+	LitNull,
+	/// A string delimited by double-quotes (`"`).
+	LitString,
+	/// The exact string `true`.
+	LitTrue,
+
+	// Glyphs, composite glyphs, glyph-adjacent ////////////////////////////////
+	/// `&`
+	Ampersand,
+	/// `&&`
+	Ampersand2,
+	/// `&=`
+	AmpersandEq,
+	/// `<`
+	AngleL,
+	/// `<<`
+	AngleL2,
+	/// `<<=`
+	AngleL2Eq,
+	/// `<=`
+	AngleLEq,
+	/// `<>=`
+	AngleLAngleREq,
+	/// `>`
+	AngleR,
+	/// `>>`
+	AngleR2,
+	/// `>>=`
+	AngleR2Eq,
+	/// `>>>`
+	AngleR3,
+	/// `>>>=`
+	AngleR3Eq,
+	/// `>=`
+	AngleREq,
+	/// `*`
+	Asterisk,
+	/// `**`
+	Asterisk2,
+	/// `*=`
+	AsteriskEq,
+	/// `!`
+	Bang,
+	/// `!=`
+	BangEq,
+	/// `{`
+	BraceL,
+	/// `}`
+	BraceR,
+	/// `[`
+	BracketL,
+	/// `]`
+	BracketR,
+	/// `^`
+	Caret,
+	/// `^=`
+	CaretEq,
+	/// `:`
+	Colon,
+	/// `::`
+	Colon2,
+	/// `,`
+	Comma,
+	/// `...`
+	Ellipsis,
+	/// `=`
+	Eq,
+	/// `==`
+	Eq2,
+	/// `-`
+	Minus,
+	/// `--`
+	Minus2,
+	/// `-=`
+	MinusEq,
+	/// `(`
+	ParenL,
+	/// `)`
+	ParenR,
+	/// `%`
+	Percent,
+	/// `%=`
+	PercentEq,
+	/// `.`
+	Period,
+	/// `..`
+	Period2,
+	/// `|`
+	Pipe,
+	/// `||`
+	Pipe2,
+	/// `|=`
+	PipeEq,
+	/// `+`
+	Plus,
+	/// `++`
+	Plus2,
+	/// `+=`
+	PlusEq,
+	/// `?`
+	Question,
+	/// `;`
+	Semicolon,
+	/// `/`
+	Slash,
+	/// `/=`
+	SlashEq,
+	/// `~`
+	Tilde,
+	/// `~==`
+	TildeEq2,
+
+	// Miscellaneous ///////////////////////////////////////////////////////////
+	/// ZScript comments use C++ syntax and are treated like whitespace.
+	Comment,
+	/// C-style; an ASCII letter or underscore, then any number of ASCII letters,
+	/// ASCII digits, or underscores. Assigned only to tokens.
+	Ident,
+	/// The frame characters of a [`Syn::StateDef`], e.g. `ABCD`.
+	StateFrames,
+	/// The four-character sprite name of a [`Syn::StateDef`], e.g. `POSS`.
+	StateSprite,
+	/// Input that the lexer considered to be invalid.
+	Unknown,
+	/// Spaces, carriage returns, newlines, and tabs are ignored by ZScript.
+	Whitespace,
+	/// The top-level node, representing the whole file.
+	Root, // Ensure this is always the last variant!
+}
+
+impl From<Syn> for rowan::SyntaxKind {
+	fn from(value: Syn) -> Self {
+		Self(value as u16)
+	}
+}
+
+impl rowan::Language for Syn {
+	type Kind = Self;
+
+	fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
+		assert!(raw.0 <= Self::Root as u16);
+		unsafe { std::mem::transmute::<u16, Syn>(raw.0) }
+	}
+
+	fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
+		kind.into()
+	}
+}
+
+impl LangExt for Syn {
+	const SYN_WHITESPACE: Self::Kind = Self::Whitespace;
+	type AstRoot = ast::Root;
+}
+
+impl LangComment for Syn {
+	const SYN_COMMENT: Self::Kind = Self::Comment;
+}
+
+impl Syn {
+	/// Alternatively "is whitespace or comment".
+	#[must_use]
+	pub fn is_trivia(&self) -> bool {
+		matches!(self, Syn::Comment | Syn::Whitespace)
+	}
+
+	/// Is this one of the `Lit`- tokens?
+	#[must_use]
+	pub fn is_literal(&self) -> bool {
+		matches!(
+			self,
+			Syn::LitFalse
+				| Syn::LitFloat
+				| Syn::LitInt
+				| Syn::LitName
+				| Syn::LitNull
+				| Syn::LitString
+				| Syn::LitTrue
+		)
+	}
+}
@@ -0,0 +1,671 @@
+use crate::{test::assert_no_errors, ParseTree};
+
+use super::{ast, *};
+
+#[test]
+fn smoke() {
+	const SOURCE: &str = r#"
+
+const ANSWER = 42;
+
+enum Speed : uint8 {
+	SPEED_SLOW,
+	SPEED_FAST = 2,
+}
+
+class Acacias : Actor replaces DoomImp abstract play {
+	int egghead, roundabout[3];
+	readonly<Actor> owner_;
+	meta class<Actor> spawnType;
+	static const int TABLE[] = { 1, 2, 3 };
+
+	property Boneless: egghead;
+	flagdef Venture: roundabout, 4;
+
+	Default {
+		Health 100;
+		Radius 20;
+		+SOLID
+		-INVENTORY.QUIET
+		Obituary "%o was eaten.";
+	}
+
+	States {
+	Spawn:
+		TROO AB 10 A_Look;
+		Loop;
+	See:
+		TROO AABBCCDD 3 Bright Light("IMPLIGHT") { A_Chase(); }
+		Goto Super::See + 1;
+	Death:
+		TNT1 A 0 Offset(1, 2);
+		Stop;
+	}
+
+	override void Tick() {
+		super.Tick();
+	}
+
+	virtual clearscope int, Vector3 Accelerate(in int a, out double b = 1.0, ...) const;
+
+	action void A_Surge() {}
+
+	void Loops(Array<int> items, Map<Name, String> dict) {
+		for (int i = 0; i < items.Size(); i++) {
+			if (i % 2 == 0) continue;
+			else break;
+		}
+
+		while (false) {}
+
+		do {
+			egghead--;
+		} until (egghead <= 0);
+
+		switch (egghead) {
+		case 1:
+		default:
+			break;
+		}
+
+		foreach (k, v : dict) {}
+
+		int x, y;
+		[x, y] = Accelerate(1, b: 2.0);
+		let mo = Actor(Spawn("DoomImp", pos));
+		let cls = (class<Actor>)(spawnType);
+		Vector3 v3 = (1.0, 2.0, 3.0);
+		return;
+	}
+}
+
+struct Rue native {
+	native int count;
+	void Empty() {}
+}
+
+extend class Acacias {
+	mixin Surge;
+}
+
+mixin class Surge {
+	double acid;
+}
+
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+	let roots: Vec<_> = pt.ast().collect();
+
+	assert_eq!(roots.len(), 6);
+
+	let ast::Root::Class(classdef) = &roots[2] else {
+		panic!("Expected a class definition, found: {:#?}", roots[2]);
+	};
+
+	assert_eq!(classdef.name().text(), "Acacias");
+	assert_eq!(classdef.parent().unwrap().text(), "Actor");
+	assert_eq!(classdef.replaces().unwrap().text(), "DoomImp");
+	assert!(classdef.qualifiers().contains(Syn::KwAbstract));
+	assert!(classdef.qualifiers().contains(Syn::KwPlay));
+
+	let innards: Vec<_> = classdef.innards().collect();
+
+	let ast::ClassInnard::Field(field) = &innards[0] else {
+		panic!("Expected a field, found: {:#?}", innards[0]);
+	};
+
+	let names: Vec<_> = field.names().collect();
+	assert_eq!(names.len(), 2);
+	assert_eq!(names[1].ident().text(), "roundabout");
+	assert_eq!(names[1].array_lens().count(), 1);
+
+	let states = innards
+		.iter()
+		.find_map(|innard| match innard {
+			ast::ClassInnard::States(states) => Some(states),
+			_ => None,
+		})
+		.unwrap();
+
+	let items: Vec<_> = states.items().collect();
+	assert_eq!(items.len(), 9);
+
+	let ast::StatesItem::State(state) = &items[4] else {
+		panic!("Expected a state definition, found: {:#?}", items[4]);
+	};
+
+	assert_eq!(state.sprite().text(), "TROO");
+	assert_eq!(state.frames().text(), "AABBCCDD");
+	assert_eq!(state.light().unwrap().text(), "\"IMPLIGHT\"");
+	assert!(matches!(
+		state.action(),
+		Some(ast::StateActionKind::Block(_))
+	));
+
+	let ast::StatesItem::Flow(flow) = &items[5] else {
+		panic!("Expected a state flow statement, found: {:#?}", items[5]);
+	};
+
+	assert_eq!(flow.goto_target().unwrap(), "Super::See");
+	assert_eq!(flow.goto_offset().unwrap().text(), "1");
+
+	let func = innards
+		.iter()
+		.find_map(|innard| match innard {
+			ast::ClassInnard::Function(f) if f.name().text() == "Accelerate" => Some(f),
+			_ => None,
+		})
+		.unwrap();
+
+	assert_eq!(func.return_types().count(), 2);
+	assert!(func.is_const());
+	assert!(func.body().is_none());
+	assert!(func.params().is_variadic());
+
+	let params: Vec<_> = func.params().iter().collect();
+	assert!(params[0].is_in());
+	assert!(params[1].is_out());
+	assert!(params[1].default().is_some());
+}
+
+#[test]
+fn expr_precedence() {
+	const SOURCE: &str = r#"
+class Test {
+	void Func() {
+		x = a + b * c ** d;
+		y = !mo is "Actor" || cond ? (1, 2) : (3, 4, 5);
+	}
+}
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	let pt = ParseTree::new(pt);
+
+	let ast::Root::Class(classdef) = pt.ast().next().unwrap() else {
+		unreachable!()
+	};
+
+	let ast::ClassInnard::Function(func) = classdef.innards().next().unwrap() else {
+		unreachable!()
+	};
+
+	let stats: Vec<_> = func.body().unwrap().statements().collect();
+
+	let ast::Statement::Expr(stat) = &stats[0] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[0]);
+	};
+
+	let ast::Expression::Binary(assign) = stat.expr() else {
+		panic!()
+	};
+
+	assert!(assign.is_assign());
+
+	let ast::Expression::Binary(add) = assign.right() else {
+		panic!()
+	};
+
+	assert_eq!(add.operator().kind(), Syn::Plus);
+
+	let ast::Expression::Binary(mul) = add.right() else {
+		panic!()
+	};
+
+	assert_eq!(mul.operator().kind(), Syn::Asterisk);
+
+	let ast::Expression::Binary(pow) = mul.right() else {
+		panic!()
+	};
+
+	assert_eq!(pow.operator().kind(), Syn::Asterisk2);
+
+	let ast::Statement::Expr(stat) = &stats[1] else {
+		panic!("Expected an expression statement, found: {:#?}", stats[1]);
+	};
+
+	let ast::Expression::Binary(assign) = stat.expr() else {
+		panic!()
+	};
+
+	let ast::Expression::Ternary(ternary) = assign.right() else {
+		panic!()
+	};
+
+	let ast::Expression::Binary(or) = ternary.condition() else {
+		panic!()
+	};
+
+	assert_eq!(or.operator().kind(), Syn::Pipe2);
+
+	let ast::Expression::Vector(v2) = ternary.if_true() else {
+		panic!()
+	};
+
+	assert_eq!(v2.components().count(), 2);
+
+	let ast::Expression::Vector(v3) = ternary.if_false() else {
+		panic!()
+	};
+
+	assert_eq!(v3.components().count(), 3);
+}
+
+#[test]
+fn class_headers_and_local_consts() {
+	const SOURCE: &str = r#"
+class W : Actor native replaces Z {}
+class V replaces Y play abstract version("4.10") {}
+
+class Lookup {
+	int Pick(int i) {
+		static const int TABLE[] = { 1, 2, 3 };
+		return TABLE[i];
+	}
+}
+"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert_no_errors(&pt);
+	assert!(parse(SOURCE).is_ok());
+	let pt = ParseTree::new(pt);
+	assert_eq!(pt.zipper().text().to_string(), SOURCE);
+
+	let roots: Vec<_> = pt.ast().collect();
+
+	let ast::Root::Class(w) = &roots[0] else {
+		panic!("Expected a class definition, found: {:#?}", roots[0]);
+	};
+
+	assert_eq!(w.replaces().unwrap().text(), "Z");
+	assert!(w.qualifiers().contains(Syn::KwNative));
+	assert!(!w.qualifiers().contains(Syn::KwReplaces));
+
+	let ast::Root::Class(v) = &roots[1] else {
+		panic!("Expected a class definition, found: {:#?}", roots[1]);
+	};
+
+	assert_eq!(v.replaces().unwrap().text(), "Y");
+	assert!(v.qualifiers().contains(Syn::KwAbstract));
+	assert!(v.qualifiers().version().is_some());
+
+	let tree = parse_include_tree("zscript", |_| Some(SOURCE.to_string())).unwrap();
+	let db = sema::Database::new(&[&tree]);
+	let issues: Vec<_> = db
+		.check()
+		.into_iter()
+		.map(|(_, issue)| issue.message)
+		.collect();
+	assert!(
+		issues.iter().all(|msg| !msg.contains("TABLE")),
+		"{issues:#?}"
+	);
+}
+
+#[test]
+fn err_handling() {
+	const SOURCE: &str = r#"
+class Broken {
+	int a;
+	this is not valid;
+	void Func() {
+		let x = ;
+		x = 1;
+	}
+}
+
+class Fine {}
+	"#;
+
+	let pt = parse_recov(SOURCE).unwrap();
+	assert!(pt.any_errors());
+	let pt = ParseTree::new(pt);
+
+	let names: Vec<_> = pt
+		.ast()
+		.filter_map(|root| match root {
+			ast::Root::Class(classdef) => Some(classdef.name().text().to_string()),
+			_ => None,
+		})
+		.collect();
+
+	assert_eq!(names, ["Broken", "Fine"]);
+	assert!(pt.zipper().text().to_string().contains("this is not valid"));
+}
+
+#[test]
+fn include_tree() {
+	const ROOT: &str = r#"
+version "4.10"
+#include "zscript/actors.zs"
+#include "zscript/missing.zs"
+"#;
+
+	const ACTORS: &str = r#"
+#include "ZSCRIPT/ACTORS.ZS"
+#include "zscript/maps.zs"
+class Acacias : Actor {}
+"#;
+
+	const MAPS: &str = r#"
+class Roundabout {
+	Map<Name, int> lookup;
+}
+"#;
+
+	let tree = parse_include_tree("zscript.txt", |path| match path {
+		"zscript.txt" => Some(ROOT.to_string()),
+		"zscript/actors.zs" => Some(ACTORS.to_string()),
+		"zscript/maps.zs" => Some(MAPS.to_string()),
+		_ => None,
+	})
+	.unwrap();
+
+	let paths: Vec<_> = tree.files.iter().map(|file| file.path()).collect();
+	assert_eq!(
+		paths,
+		["zscript.txt", "zscript/actors.zs", "zscript/maps.zs"]
+	);
+
+	assert_eq!(tree.files[0].errors().len(), 1);
+	assert!(!tree.files[1].any_errors());
+	assert_eq!(tree.version(), Version::new(4, 10, 0));
+
+	assert!(tree.check_versions(Version::new(4, 10, 0)).is_empty());
+
+	let issues = tree.check_versions(Version::new(4, 8, 0));
+	assert_eq!(issues.len(), 1);
+	assert_eq!(issues[0].0, 0);
+	assert!(issues[0].1.message.starts_with("Version mismatch"));
+}
+
+#[test]
+fn version_gating() {
+	const SOURCE: &str = r#"
+class Acacias {
+	void Func(Map<Name, int> dict) {
+		foreach (k, v : dict) {}
+	}
+}
+
+version "4.10"
+"#;
+
+	let tree = parse_include_tree("zscript", |_| Some(SOURCE.to_string())).unwrap();
+	assert_no_errors(&tree.files[0]);
+
+	assert_eq!(
+		tree.files[0].version_directive().unwrap().version(),
+		Some(Version::new(4, 10, 0))
+	);
+
+	let issues = tree.check_versions(Version::new(4, 12, 0));
+	assert_eq!(issues.len(), 1);
+	assert!(issues[0].1.message.contains("must precede"));
+
+	let tree =
+		parse_include_tree("zscript", |_| Some(SOURCE.replace("version \"4.10\"", ""))).unwrap();
+	assert_eq!(tree.version(), Version::DEFAULT);
+
+	let issues = tree.check_versions(Version::new(4, 12, 0));
+	assert_eq!(issues.len(), 2);
+	assert!(issues.iter().all(|(_, issue)| issue.is_error()));
+
+	const QUALIFIED: &str = r#"
+version "4.5"
+
+class Fine version("4.5") {
+	version("4.2") void Old() {}
+	version("4.12") void New() {}
+	version("four") int bad;
+}
+"#;
+
+	let tree = parse_include_tree("zscript", |_| Some(QUALIFIED.to_string())).unwrap();
+	assert_no_errors(&tree.files[0]);
+
+	let issues: Vec<_> = tree
+		.check_versions(Version::new(4, 12, 0))
+		.into_iter()
+		.map(|(_, issue)| (&QUALIFIED[issue.range], issue.message))
+		.collect();
+
+	assert_eq!(
+		issues,
+		[
+			(
+				"\"4.12\"",
+				"Version mismatch. 4.12.0 expected but only 4.5.0 supported".to_string()
+			),
+			("\"four\"", "Invalid version string \"four\"".to_string()),
+		]
+	);
+}
+
+#[test]
+fn semantic_model() {
+	const SOURCE: &str = r#"
+version "4.10"
+
+const GLOBAL_LIMIT = 8;
+
+mixin class Surging {
+	double acid;
+}
+
+class Base play {
+	int counter;
+
+	virtual void Bump(int amount) {
+		counter += amount;
+	}
+}
+
+class Derived : Base {
+	mixin Surging;
+
+	struct Pair { int left; }
+	Pair pair;
+
+	override void Bump(int amount) {
+		super.Bump(amount);
+		int local = amount * GLOBAL_LIMIT;
+		counter = local + pair.left;
+		acid = 1.0;
+
+		for (int i = 0; i < local; i++) {
+			Derived other = self;
+			other.counter = i;
+		}
+	}
+
+	ui void Draw() {}
+}
+
+extend class Derived {
+	clearscope int Peek() const { return counter; }
+}
+
+class Menuish : Menu {}
+"#;
+
+	let tree = parse_include_tree("zscript", |_| Some(SOURCE.to_string())).unwrap();
+	assert_no_errors(&tree.files[0]);
+	let db = sema::Database::new(&[&tree]);
+
+	let base = db.lookup_type("base").unwrap();
+	let derived = db.lookup_type("DERIVED").unwrap();
+	assert_eq!(db.parent_of(derived), Some(base));
+	assert!(db.is_subclass_of(derived, base));
+	assert!(!db.has_unknown_ancestor(derived));
+
+	// Member lookup through parents, mixins, and `extend` blocks.
+	let counter = db.lookup_member(derived, "Counter").unwrap();
+	assert_eq!(db.symbol(counter).owner, Some(base));
+	assert!(db.lookup_member(derived, "acid").is_some());
+	let peek = db.lookup_member(derived, "peek").unwrap();
+
+	// The override shadows the parent's function.
+	let bump = db.lookup_member(derived, "bump").unwrap();
+	assert_eq!(db.symbol(bump).owner, Some(derived));
+
+	// Scopes.
+	use sema::DataScope;
+	assert_eq!(db.symbol(base).scope, Some(DataScope::Play));
+	assert_eq!(db.symbol(derived).scope, Some(DataScope::Play));
+	assert_eq!(db.symbol(counter).scope, Some(DataScope::Play));
+	assert_eq!(db.symbol(peek).scope, Some(DataScope::Clear));
+	let draw = db.lookup_member(derived, "draw").unwrap();
+	assert_eq!(db.symbol(draw).scope, Some(DataScope::Ui));
+	let menuish = db.lookup_type("menuish").unwrap();
+	assert_eq!(db.symbol(menuish).scope, Some(DataScope::Ui));
+	assert!(db.has_unknown_ancestor(menuish));
+
+	// Go-to-definition and find-references.
+	let offset_of = |needle: &str, nth: usize| -> rowan::TextRange {
+		let start = SOURCE.match_indices(needle).nth(nth).unwrap().0;
+		rowan::TextRange::at((start as u32).into(), (needle.len() as u32).into())
+	};
+
+	let def = db.definition(0, offset_of("counter", 2)).unwrap();
+	assert_eq!(def.range, offset_of("counter", 0));
+
+	let refs = db.find_references(0, offset_of("counter", 0));
+	assert_eq!(refs.len(), 4);
+
+	let def = db.definition(0, offset_of("local", 2)).unwrap();
+	assert_eq!(def.range, offset_of("local", 0));
+
+	let def = db.definition(0, offset_of("left", 1)).unwrap();
+	assert_eq!(def.range, offset_of("left", 0));
+
+	// Queries may also be given a cursor position.
+	let cursor = rowan::TextRange::empty(offset_of("Bump(amount)", 0).start());
+	let def = db.definition(0, cursor).unwrap();
+	assert_eq!(
+		db.symbol(db.symbol_at(0, def.range).unwrap()).owner,
+		Some(base)
+	);
+
+	let mixin_def = db.definition(0, offset_of("Surging", 1)).unwrap();
+	assert_eq!(mixin_def.range, offset_of("Surging", 0));
+
+	// `Menu` is native to GZDoom, and `gzdoom.pk3` was not loaded.
+	assert_eq!(db.unresolved().len(), 1, "{:#?}", db.unresolved());
+	assert_eq!(db.unresolved()[0].range, offset_of("Menu", 1));
+	assert!(db.check().is_empty(), "{:#?}", db.check());
+}
+
+#[test]
+fn type_checking() {
+	const SOURCE: &str = r#"
+enum Mode { MODE_A, MODE_B }
+
+class Holder play {
+	readonly int fixed;
+	int count;
+	ui int hudTicks;
+	Array<Holder> others;
+	Map<Name, int> tally;
+
+	int Add(int a, double b = 1.0) { return a; }
+	ui void Redraw() {}
+
+	void Run() {
+		Vector3 pos = (1.0, 2.0, 3.0);
+		Vector2 flat = pos.xy * 2.0;
+		double len = pos dot pos;
+		Vector3 bad = flat + pos;
+		int truncated = len;
+		String s = 'SomeName';
+		Name n = "A string";
+		Holder h = null;
+		bool b = h;
+		fixed = 4;
+		MODE_A = 2;
+		Add();
+		Add(1, 2.0, 3);
+		Add(1, "two");
+		Redraw();
+		hudTicks = 1;
+		others.Push(self);
+		others.Push(3);
+		tally.Insert('key', 1);
+		int notAnInt = s & 1;
+	}
+
+	int Peek() const {
+		count = 1;
+		return count;
+	}
+
+	int Missing() { return; }
+}
+"#;
+
+	let ptree = parse(SOURCE).unwrap();
+	assert_no_errors(&ptree);
+	let tree = parse_include_tree("zscript", |_| Some(SOURCE.to_string())).unwrap();
+	let db = sema::Database::new(&[&tree]);
+
+	let issues = db.check();
+	let found: Vec<_> = issues
+		.iter()
+		.map(|(_, issue)| {
+			let start: usize = issue.range.start().into();
+			let end: usize = issue.range.end().into();
+			(
+				issue.is_error(),
+				&SOURCE[start..end],
+				issue.message.as_str(),
+			)
+		})
+		.collect();
+
+	let expected = [
+		(true, "+", "Incompatible operands for addition"),
+		(false, "len", "Truncation of floating point value"),
+		(true, "fixed", "Expression must be a modifiable value"),
+		(true, "MODE_A", "Expression must be a modifiable value"),
+		(true, "()", "Insufficient arguments in call to Add"),
+		(true, "(1, 2.0, 3)", "Too many arguments in call to Add"),
+		(true, "\"two\"", "Cannot convert String to double"),
+		(
+			true,
+			"Redraw",
+			"Can't call ui function Redraw from play context",
+		),
+		(
+			true,
+			"hudTicks",
+			"Can't access ui field hudTicks from play context",
+		),
+		(
+			true,
+			"hudTicks",
+			"Can't modify ui field hudTicks from play context",
+		),
+		(true, "3", "Cannot convert int to Pointer<Holder>"),
+		(true, "&", "Incompatible operands for bitwise operation"),
+		(true, "count", "Expression must be a modifiable value"),
+		(true, "return;", "Return type mismatch"),
+	];
+
+	for exp in expected {
+		assert!(found.contains(&exp), "missing {exp:?} in {found:#?}");
+	}
+
+	assert_eq!(found.len(), expected.len(), "{found:#?}");
+
+	let pos = db
+		.lookup_member(db.lookup_type("holder").unwrap(), "others")
+		.unwrap();
+	assert_eq!(
+		db.display_type(&db.symbol_value_type(pos)).to_string(),
+		"DynArray<Pointer<Holder>>"
+	);
+}
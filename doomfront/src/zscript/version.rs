@@ -0,0 +1,218 @@
+//! ZScript's `version` directive, and checking that a file tree's use of
+//! version-gated syntax is permitted by the version it declares.
+//!
+//! Declarations' `version("x.y.z")` qualifiers are checked against the root
+//! file's declared version, as is the syntax introduced by GZDoom 4.10
+//! (`foreach`, `Map`, and `MapIterator`). Older gated features go unreported.
+
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use rowan::{ast::AstNode, NodeOrToken};
+
+use crate::Issue;
+
+use super::{ast, IncludeTree, ParseTree, Syn, SyntaxNode};
+
+/// A GZDoom release number, as given to a `version` directive or qualifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "ser_de", derive(serde::Serialize))]
+pub struct Version {
+	pub major: u16,
+	pub minor: u16,
+	pub rev: u16,
+}
+
+impl Version {
+	/// The version assumed by GZDoom for any root lump with no `version` directive.
+	pub const DEFAULT: Self = Self::new(2, 3, 0);
+
+	#[must_use]
+	pub const fn new(major: u16, minor: u16, rev: u16) -> Self {
+		Self { major, minor, rev }
+	}
+}
+
+impl Default for Version {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+impl FromStr for Version {
+	type Err = ParseIntError;
+
+	/// Accepts `x`, `x.y`, or `x.y.z`. Omitted components are zero.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.trim().splitn(3, '.');
+		let major = parts.next().unwrap_or_default().parse()?;
+		let minor = parts.next().map_or(Ok(0), str::parse)?;
+		let rev = parts.next().map_or(Ok(0), str::parse)?;
+
+		Ok(Self { major, minor, rev })
+	}
+}
+
+impl fmt::Display for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.rev)
+	}
+}
+
+/// Syntax which GZDoom only accepts from a certain ZScript version onwards,
+/// keyed by the token which introduces it. Not exhaustive; see the module
+/// documentation.
+const GATED: &[(Syn, &str, Version)] = &[
+	(Syn::KwForEach, "`foreach` loops", Version::new(4, 10, 0)),
+	(Syn::KwMap, "`Map`", Version::new(4, 10, 0)),
+	(Syn::KwMapIterator, "`MapIterator`", Version::new(4, 10, 0)),
+];
+
+impl ParseTree {
+	/// The first `version` directive in this file, if there is one.
+	#[must_use]
+	pub fn version_directive(&self) -> Option<ast::VersionDirective> {
+		self.ast().find_map(|root| match root {
+			ast::Root::Version(directive) => Some(directive),
+			_ => None,
+		})
+	}
+}
+
+impl IncludeTree {
+	/// The version declared by the root file, or [`Version::DEFAULT`] if the
+	/// root file has no (valid) `version` directive. Directives in included
+	/// files have no effect.
+	#[must_use]
+	pub fn version(&self) -> Version {
+		self.files
+			.first()
+			.and_then(|root| root.version_directive())
+			.and_then(|directive| directive.version())
+			.unwrap_or_default()
+	}
+
+	/// Checks every file's `version` directives, its `version("x.y.z")`
+	/// qualifiers (which may not exceed the root file's declared version), and
+	/// its use of `foreach`, `Map`, and `MapIterator` where the root file
+	/// declares a version older than 4.10, which introduced them. `target` is the
+	/// GZDoom release being linted against; declaring anything newer is an error.
+	///
+	/// Each issue is paired with an index into [`Self::files`].
+	#[must_use]
+	pub fn check_versions(&self, target: Version) -> Vec<(usize, Issue)> {
+		let declared = self.version();
+		let mut issues = vec![];
+
+		for (i, file) in self.files.iter().enumerate() {
+			let mut seen_other = false;
+			let mut seen_directive = false;
+
+			for root in file.ast() {
+				let ast::Root::Version(directive) = root else {
+					seen_other = true;
+					continue;
+				};
+
+				let range = directive.syntax().text_range();
+
+				if i != 0 {
+					issues.push((
+						i,
+						Issue::warning(range, "`version` directive in an included file is ignored"),
+					));
+
+					continue;
+				}
+
+				if seen_directive {
+					issues.push((i, Issue::error(range, "Multiple `version` directives")));
+					continue;
+				}
+
+				seen_directive = true;
+
+				if seen_other {
+					issues.push((
+						i,
+						Issue::error(range, "`version` must precede all other definitions"),
+					));
+				}
+
+				let token = directive.version_token();
+
+				match directive.version() {
+					None => issues.push((
+						i,
+						Issue::error(
+							token.text_range(),
+							format!("Invalid version string {}", token.text()),
+						),
+					)),
+					Some(v) if v > target => issues.push((
+						i,
+						Issue::error(
+							token.text_range(),
+							format!("Version mismatch. {v} expected but only {target} supported"),
+						),
+					)),
+					Some(_) => {}
+				}
+			}
+
+			for n_or_t in file.zipper().descendants_with_tokens() {
+				let token = match n_or_t {
+					NodeOrToken::Node(node) => {
+						if node.kind() == Syn::VersionQual {
+							if let Some(issue) = check_qualifier(&node, declared) {
+								issues.push((i, issue));
+							}
+						}
+
+						continue;
+					}
+					NodeOrToken::Token(token) => token,
+				};
+
+				let Some((_, what, required)) =
+					GATED.iter().find(|(syn, _, _)| *syn == token.kind())
+				else {
+					continue;
+				};
+
+				if *required > declared {
+					issues.push((
+						i,
+						Issue::error(
+							token.text_range(),
+							format!("{what} requires version {required} or higher (declared: {declared})"),
+						),
+					));
+				}
+			}
+		}
+
+		issues
+	}
+}
+
+/// `node` is tagged [`Syn::VersionQual`].
+fn check_qualifier(node: &SyntaxNode, declared: Version) -> Option<Issue> {
+	let token = node
+		.children_with_tokens()
+		.filter_map(|n_or_t| n_or_t.into_token())
+		.find(|token| token.kind() == Syn::LitString)?;
+
+	let text = token.text();
+
+	match text[1..(text.len() - 1)].parse::<Version>() {
+		Err(_) => Some(Issue::error(
+			token.text_range(),
+			format!("Invalid version string {text}"),
+		)),
+		Ok(v) if v > declared => Some(Issue::error(
+			token.text_range(),
+			format!("Version mismatch. {v} expected but only {declared} supported"),
+		)),
+		Ok(_) => None,
+	}
+}